@@ -1,24 +1,92 @@
 use codspeed_criterion_compat::{black_box, criterion_group, criterion_main, Criterion};
 
+use palette::chromatic_adaptation::AdaptIntoUnclamped;
+use palette::convert::{Convert, ConvertOnce, FromColorUnclamped, Matrix3};
 use palette::encoding;
-use palette::matrix::{matrix_inverse, multiply_3x3, rgb_to_xyz_matrix};
+use palette::matrix::{rgb_to_xyz_matrix, Mat3};
+use palette::rgb::LinProPhotoRgb;
+use palette::white_point::{D50, D65};
+use palette::{LinSrgb, Xyz};
 
 fn matrix(c: &mut Criterion) {
     let mut group = c.benchmark_group("Matrix functions");
 
-    let inp1 = [1.0f32, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0];
-    let inp2 = [4.0, 5.0, 6.0, 6.0, 5.0, 4.0, 4.0, 6.0, 5.0];
-    let inverse: [f32; 9] = [3.0, 0.0, 2.0, 2.0, 0.0, -2.0, 0.0, 1.0, 1.0];
+    let inp1 = Mat3::from_array([1.0f32, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0]);
+    let inp2 = Mat3::from_array([4.0, 5.0, 6.0, 6.0, 5.0, 4.0, 4.0, 6.0, 5.0]);
+    let inverse = Mat3::from_array([3.0f32, 0.0, 2.0, 2.0, 0.0, -2.0, 0.0, 1.0, 1.0]);
 
     group.bench_function("multiply_3x3", |b| {
-        b.iter(|| multiply_3x3(black_box(inp1), black_box(inp2)))
+        b.iter(|| black_box(inp1).mul_matrix(black_box(inp2)))
     });
     group.bench_with_input("matrix_inverse", &inverse, |b, inverse| {
-        b.iter(|| matrix_inverse(*inverse))
+        b.iter(|| inverse.invert())
     });
     group.bench_function("rgb_to_xyz_matrix", |b| {
         b.iter(rgb_to_xyz_matrix::<encoding::Srgb, f32>)
     });
+    group.finish();
+
+    let mut group = c.benchmark_group("Srgb to Xyz matrix");
+
+    let colors: Vec<LinSrgb<f32>> = (0..1000)
+        .map(|i| {
+            let i = i as f32 / 1000.0;
+            LinSrgb::new(i, 1.0 - i, (i * 7.0).fract())
+        })
+        .collect();
+
+    group.bench_with_input("precomputed", &colors, |b, colors| {
+        b.iter(|| {
+            colors
+                .iter()
+                .map(|&color| Xyz::from_color_unclamped(color))
+                .collect::<Vec<Xyz>>()
+        })
+    });
+    group.bench_with_input("dynamic", &colors, |b, colors| {
+        b.iter(|| {
+            colors
+                .iter()
+                .map(|&color| {
+                    let matrix = Matrix3::from_array(rgb_to_xyz_matrix::<encoding::Srgb, f32>());
+                    matrix.convert_once(color)
+                })
+                .collect::<Vec<Xyz>>()
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("ProPhoto to Srgb matrix");
+
+    let colors: Vec<LinProPhotoRgb<f32>> = (0..1000)
+        .map(|i| {
+            let i = i as f32 / 1000.0;
+            LinProPhotoRgb::new(i, 1.0 - i, (i * 7.0).fract())
+        })
+        .collect();
+
+    group.bench_with_input("multiple steps", &colors, |b, colors| {
+        b.iter(|| {
+            colors
+                .iter()
+                .map(|&color| {
+                    let xyz: Xyz<D65, f32> =
+                        Xyz::<D50, f32>::from_color_unclamped(color).adapt_into_unclamped();
+                    LinSrgb::from_color_unclamped(xyz)
+                })
+                .collect::<Vec<LinSrgb>>()
+        })
+    });
+    group.bench_with_input("combined matrix", &colors, |b, colors| {
+        let matrix = LinSrgb::matrix_from_rgb::<encoding::Linear<encoding::ProPhotoRgb>>();
+        b.iter(|| {
+            colors
+                .iter()
+                .map(|&color| matrix.convert(color))
+                .collect::<Vec<LinSrgb>>()
+        })
+    });
+    group.finish();
 }
 
 criterion_group!(benches, matrix);
@@ -6,8 +6,8 @@ use palette::{
     white_point::D65,
     Xyz,
 };
-use palette::{encoding, lms::BradfordLms};
-use palette::{Hsl, Hsv, Hwb, IntoColor, LinSrgb, Srgb};
+use palette::{encoding, lms::BradfordLms, luma::Luma, white_point::D50};
+use palette::{Hsl, Hsv, Hwb, IntoColor, LinLuma, LinSrgb, Srgb, SrgbLuma};
 
 type SrgbHsv = Hsv<encoding::Srgb>;
 type SrgbHsl = Hsl<encoding::Srgb>;
@@ -41,6 +41,10 @@ use data_color_mine::{load_data, ColorMine};
     - linsrgb to rgb
     - rgb_u8 to linsrgb_f32
     - linsrgb_f32 to rgb_u8
+    - luma_u8 to linluma_f32
+    - linluma_f32 to luma_u8
+    - srgb luma to d50 srgb luma
+    - srgb luma to rec709 luma
 */
 
 fn rgb_conversion(c: &mut Criterion) {
@@ -76,6 +80,11 @@ fn rgb_conversion(c: &mut Criterion) {
         .iter()
         .map(|x| x.linear_rgb.into_format())
         .collect();
+    let luma_u8: Vec<SrgbLuma<u8>> = rgb_u8.iter().map(|x| SrgbLuma::new(x.green)).collect();
+    let linluma_f32: Vec<LinLuma<D65, f32>> = colormine
+        .iter()
+        .map(|x| LinLuma::new(x.linear_rgb.green))
+        .collect();
 
     let linear_hsv: Vec<LinHsv> = colormine.iter().map(|x| x.hsv.into_color()).collect();
     let linear_hsl: Vec<LinHsl> = colormine.iter().map(|x| x.hsl.into_color()).collect();
@@ -318,6 +327,49 @@ fn rgb_conversion(c: &mut Criterion) {
         })
     });
 
+    group.bench_with_input("luma_u8 to linluma_f32", &luma_u8, |b, luma_u8| {
+        b.iter(|| {
+            for c in luma_u8 {
+                black_box(c.into_linear::<f32>());
+            }
+        })
+    });
+    group.bench_with_input("linluma_f32 to luma_u8", &linluma_f32, |b, linluma_f32| {
+        b.iter(|| {
+            for &c in linluma_f32 {
+                black_box(SrgbLuma::<u8>::from_linear(c));
+            }
+        })
+    });
+
+    // A larger buffer, similar to a second of high resolution samples.
+    let luma_samples: Vec<SrgbLuma<f32>> = (0..1_000_000)
+        .map(|i| SrgbLuma::new((i % 1000) as f32 / 999.0))
+        .collect();
+
+    group.bench_with_input(
+        "srgb luma to d50 srgb luma",
+        &luma_samples,
+        |b, luma_samples| {
+            b.iter(|| {
+                for &c in luma_samples {
+                    black_box(Luma::<(D50, encoding::Srgb), f32>::from_color_unclamped(c));
+                }
+            })
+        },
+    );
+    group.bench_with_input(
+        "srgb luma to rec709 luma",
+        &luma_samples,
+        |b, luma_samples| {
+            b.iter(|| {
+                for &c in luma_samples {
+                    black_box(Luma::<encoding::Rec709, f32>::from_color_unclamped(c));
+                }
+            })
+        },
+    );
+
     group.finish();
 }
 
@@ -0,0 +1,111 @@
+use std::path::Path;
+
+use codspeed_criterion_compat::{black_box, criterion_group, criterion_main, Criterion};
+use palette::{
+    encoding, Hsl, Hsv, Hsva, IntoColor, LightenAssign, LinSrgb, LinSrgba, SaturateAssign,
+    ShiftHueAssign, WithAlpha,
+};
+
+#[path = "../../integration_tests/tests/convert/data_color_mine.rs"]
+#[allow(dead_code)]
+mod data_color_mine;
+use data_color_mine::{load_data, ColorMine};
+
+type SrgbHsv = Hsv<encoding::Srgb>;
+type SrgbHsva = Hsva<encoding::Srgb>;
+type SrgbHsl = Hsl<encoding::Srgb>;
+
+/* Benches the following conversions:
+    - linsrgb from interleaved
+    - linsrgb into interleaved
+    - linsrgba from interleaved
+    - hsv from interleaved
+    - hsva into interleaved
+    - hsl lighten, saturate and shift hue, interleaved and planar
+*/
+
+fn struct_of_arrays(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Struct of arrays");
+    let colormine: Vec<ColorMine<f32>> = load_data(Some(Path::new(
+        "../integration_tests/tests/convert/data_color_mine.csv",
+    )));
+
+    let linsrgb: Vec<LinSrgb> = colormine.iter().map(|x| x.linear_rgb).collect();
+    let linsrgba: Vec<LinSrgba> = linsrgb.iter().map(|&x| x.with_alpha(0.5)).collect();
+    let hsv: Vec<SrgbHsv> = colormine.iter().map(|x| x.hsv.into_color()).collect();
+    let hsva: Vec<SrgbHsva> = hsv.iter().map(|&x| x.with_alpha(0.5)).collect();
+
+    let planar_linsrgb = LinSrgb::<Vec<f32>>::from_interleaved(&linsrgb);
+    let planar_hsva = Hsva::<encoding::Srgb, Vec<f32>>::from_interleaved(&hsva);
+    let hsl: Vec<SrgbHsl> = colormine.iter().map(|x| x.hsl.into_color()).collect();
+    let planar_hsl = Hsl::<encoding::Srgb, Vec<f32>>::from_interleaved(&hsl);
+
+    group.bench_with_input("linsrgb from interleaved", &linsrgb, |b, linsrgb| {
+        b.iter(|| black_box(LinSrgb::<Vec<f32>>::from_interleaved(linsrgb)))
+    });
+    group.bench_with_input("linsrgb from iterator", &linsrgb, |b, linsrgb| {
+        b.iter(|| black_box(linsrgb.iter().copied().collect::<LinSrgb<Vec<f32>>>()))
+    });
+    group.bench_with_input(
+        "linsrgb into interleaved",
+        &planar_linsrgb,
+        |b, planar_linsrgb| b.iter(|| black_box(planar_linsrgb.clone().into_interleaved())),
+    );
+    group.bench_with_input("linsrgba from interleaved", &linsrgba, |b, linsrgba| {
+        b.iter(|| black_box(LinSrgba::<Vec<f32>>::from_interleaved(linsrgba)))
+    });
+    group.bench_with_input("hsv from interleaved", &hsv, |b, hsv| {
+        b.iter(|| black_box(Hsv::<encoding::Srgb, Vec<f32>>::from_interleaved(hsv)))
+    });
+    group.bench_with_input("hsva into interleaved", &planar_hsva, |b, planar_hsva| {
+        b.iter(|| black_box(planar_hsva.clone().into_interleaved()))
+    });
+
+    group.bench_with_input("hsl lighten interleaved", &hsl, |b, hsl| {
+        b.iter(|| {
+            let mut hsl = hsl.clone();
+            hsl.lighten_assign(0.2);
+            black_box(hsl)
+        })
+    });
+    group.bench_with_input("hsl lighten planar", &planar_hsl, |b, planar_hsl| {
+        b.iter(|| {
+            let mut planar_hsl = planar_hsl.clone();
+            planar_hsl.lighten_assign(0.2);
+            black_box(planar_hsl)
+        })
+    });
+    group.bench_with_input("hsl saturate interleaved", &hsl, |b, hsl| {
+        b.iter(|| {
+            let mut hsl = hsl.clone();
+            hsl.saturate_assign(0.2);
+            black_box(hsl)
+        })
+    });
+    group.bench_with_input("hsl saturate planar", &planar_hsl, |b, planar_hsl| {
+        b.iter(|| {
+            let mut planar_hsl = planar_hsl.clone();
+            planar_hsl.saturate_assign(0.2);
+            black_box(planar_hsl)
+        })
+    });
+    group.bench_with_input("hsl shift hue interleaved", &hsl, |b, hsl| {
+        b.iter(|| {
+            let mut hsl = hsl.clone();
+            hsl.shift_hue_assign(30.0);
+            black_box(hsl)
+        })
+    });
+    group.bench_with_input("hsl shift hue planar", &planar_hsl, |b, planar_hsl| {
+        b.iter(|| {
+            let mut planar_hsl = planar_hsl.clone();
+            planar_hsl.shift_hue_assign(30.0);
+            black_box(planar_hsl)
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, struct_of_arrays);
+criterion_main!(benches);
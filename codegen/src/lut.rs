@@ -28,11 +28,14 @@ pub fn generate() -> Result<()> {
         LutEntryU8::new("p3", "P3_GAMMA", TransferFn::new_pure_gamma(2.6)),
     ];
 
-    let transfer_fn_u16 = vec![LutEntryU16::new(
-        "prophoto",
-        "PROPHOTO_RGB",
-        TransferFn::new_with_linear(16.0, 0.001953125, 1.8),
-    )];
+    let transfer_fn_u16 = vec![
+        LutEntryU16::new("srgb_u16", "SRGB", TransferFn::new_srgb()),
+        LutEntryU16::new(
+            "prophoto",
+            "PROPHOTO_RGB",
+            TransferFn::new_with_linear(16.0, 0.001953125, 1.8),
+        ),
+    ];
 
     for LutEntryU8 {
         module,
@@ -107,6 +110,25 @@ impl TransferFn {
         }
     }
 
+    /// The sRGB transfer function with the exact constants from the standard,
+    /// instead of the continuous `alpha` that `new_with_linear` calculates.
+    /// The `u16` table is large enough for the difference to show.
+    fn new_srgb() -> Self {
+        Self {
+            into_linear: Box::new(|encoded| {
+                if encoded <= 0.04045 {
+                    encoded / 12.92
+                } else {
+                    ((encoded + 0.055) / 1.055).powf(2.4)
+                }
+            }),
+            linear_scale: Some(12.92),
+            alpha: 1.055,
+            beta: 0.0031308,
+            gamma: 2.4,
+        }
+    }
+
     fn new_pure_gamma(gamma: f64) -> Self {
         Self {
             into_linear: Box::new(move |encoded| encoded.powf(gamma)),
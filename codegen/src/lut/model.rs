@@ -48,8 +48,15 @@ impl LinearModel {
 
         let (integral_y, integral_ty) = match linear_scale {
             Some(linear_scale) if start < beta_bits => {
-                let beta_t =
-                    (beta_bits << (9 + man_index_width)) as f64 * 2.0f64.powi(t_width as i32 - 32);
+                // The `u8` tables split the interval at the rounded bit
+                // pattern of `beta`, which is kept to not change them. It's
+                // too coarse for wider `t`, so the split point is calculated
+                // from the exact value for them.
+                let beta_t = if t_width <= 8 {
+                    (beta_bits << (9 + man_index_width)) as f64 * 2.0f64.powi(t_width as i32 - 32)
+                } else {
+                    (beta - start_x) / exp_scale
+                };
                 let int_linear =
                     integrate_linear((start_x, beta), (0.0, beta_t), linear_scale, exp_scale);
                 let int_exponential =
@@ -3,10 +3,16 @@ use anyhow::{Context, Result};
 mod codegen_file;
 mod lut;
 mod named;
+mod rgb_matrix;
+mod spectral;
+mod temperature;
 
 fn main() -> Result<()> {
     named::generate().context("could not generate named color constants")?;
     lut::generate().context("could not generate conversion lookup tables")?;
+    rgb_matrix::generate().context("could not generate RGB conversion matrices")?;
+    spectral::generate().context("could not generate color matching functions")?;
+    temperature::generate().context("could not generate the Planckian locus table")?;
 
     Ok(())
 }
@@ -0,0 +1,211 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::codegen_file::CodegenFile;
+
+type Mat3 = [f64; 9];
+
+const D50: [f64; 3] = [0.96422, 1.0, 0.82521];
+const D65: [f64; 3] = [0.95047, 1.0, 1.08883];
+const DCI_P3_WHITE: [f64; 3] = [0.314 / 0.351, 1.0, 0.335 / 0.351];
+
+pub fn generate() -> Result<()> {
+    let mut file = CodegenFile::create("palette/src/encoding/matrix/codegen.rs")?;
+
+    // The primaries are read from the `Primaries` implementations in
+    // `palette::encoding`, to keep the constants in sync with them.
+    let spaces = vec![
+        RgbSpaceEntry::parse("srgb", "srgb.rs", "Srgb", D65)?,
+        RgbSpaceEntry::parse("adobe", "adobe.rs", "AdobeRgb", D65)?,
+        RgbSpaceEntry::parse("rec2020", "rec_standards.rs", "Rec2020", D65)?,
+        RgbSpaceEntry::parse("dci_p3", "p3.rs", "DciP3", DCI_P3_WHITE)?,
+        RgbSpaceEntry::parse("dci_p3_plus", "p3.rs", "DciP3Plus", DCI_P3_WHITE)?,
+        RgbSpaceEntry::parse("display_p3", "p3.rs", "DisplayP3", D65)?,
+        RgbSpaceEntry::parse("prophoto", "prophoto.rs", "ProPhotoRgb", D50)?,
+        RgbSpaceEntry::parse("s_gamut3_cine", "sony.rs", "SGamut3Cine", D65)?,
+        RgbSpaceEntry::parse("v_gamut", "panasonic.rs", "VGamut", D65)?,
+    ];
+
+    for entry in spaces {
+        file.append(build_matrices(&entry))?;
+    }
+
+    Ok(())
+}
+
+struct RgbSpaceEntry {
+    module: Ident,
+    red: [f64; 3],
+    green: [f64; 3],
+    blue: [f64; 3],
+    white_point: [f64; 3],
+}
+
+impl RgbSpaceEntry {
+    fn parse(module: &str, file: &str, type_name: &str, white_point: [f64; 3]) -> Result<Self> {
+        let path = format!("palette/src/encoding/{file}");
+        let source = fs::read_to_string(&path).with_context(|| format!("could not open {path}"))?;
+
+        let implementation = find_primaries_impl(&source, type_name)
+            .with_context(|| format!("missing `Primaries` for {type_name} in {path}"))?;
+
+        let read_primary = |name: &str| {
+            parse_primary(implementation, name)
+                .with_context(|| format!("couldn't parse `{name}` for {type_name} in {path}"))
+        };
+
+        Ok(Self {
+            module: format_ident!("{module}"),
+            red: read_primary("red")?,
+            green: read_primary("green")?,
+            blue: read_primary("blue")?,
+            white_point,
+        })
+    }
+}
+
+// Finds the source code after `impl<...> Primaries<T> for {type_name}`.
+fn find_primaries_impl<'a>(source: &'a str, type_name: &str) -> Option<&'a str> {
+    let pattern = format!("Primaries<T> for {type_name}");
+
+    source.match_indices(&pattern).find_map(|(index, _)| {
+        let rest = &source[index + pattern.len()..];
+        rest.starts_with([' ', '<']).then_some(rest)
+    })
+}
+
+// Parses the `[x, y, Y]` values in `fn {name}() -> Yxy<Any, T>`, which are
+// expected to be written as `T::from_f64(...)`.
+fn parse_primary(implementation: &str, name: &str) -> Result<[f64; 3]> {
+    let signature = format!("fn {name}()");
+    let start = implementation
+        .find(&signature)
+        .with_context(|| format!("missing `{signature}`"))?;
+    let mut body = &implementation[start + signature.len()..];
+
+    let mut values = [0.0; 3];
+    for value in &mut values {
+        let (_, rest) = body
+            .split_once("T::from_f64(")
+            .context("expected three `T::from_f64` values")?;
+        let (number, rest) = rest
+            .split_once(')')
+            .context("unterminated `T::from_f64` value")?;
+
+        *value = number
+            .trim()
+            .parse()
+            .with_context(|| format!("couldn't parse {number:?}"))?;
+        body = rest;
+    }
+
+    Ok(values)
+}
+
+fn build_matrices(entry: &RgbSpaceEntry) -> TokenStream {
+    let RgbSpaceEntry {
+        module,
+        red,
+        green,
+        blue,
+        white_point,
+    } = entry;
+
+    let rgb_to_xyz = rgb_to_xyz_matrix(*red, *green, *blue, *white_point);
+    let xyz_to_rgb = matrix_inverse(rgb_to_xyz);
+
+    quote! {
+        pub mod #module {
+            pub const RGB_TO_XYZ: [f64; 9] = [
+                #(#rgb_to_xyz),*
+            ];
+
+            pub const XYZ_TO_RGB: [f64; 9] = [
+                #(#xyz_to_rgb),*
+            ];
+        }
+    }
+}
+
+// The calculations below follow the ones in `palette::matrix`, to make the
+// constants match the dynamically calculated matrices.
+
+fn rgb_to_xyz_matrix(
+    red: [f64; 3],
+    green: [f64; 3],
+    blue: [f64; 3],
+    white_point: [f64; 3],
+) -> Mat3 {
+    let [rx, ry, rz] = yxy_to_xyz(red);
+    let [gx, gy, gz] = yxy_to_xyz(green);
+    let [bx, by, bz] = yxy_to_xyz(blue);
+
+    let matrix = [rx, gx, bx, ry, gy, by, rz, gz, bz];
+    let [s_red, s_green, s_blue] = multiply_3x3_and_vec3(matrix_inverse(matrix), white_point);
+
+    let [t0, t1, t2, t3, t4, t5, t6, t7, t8] = matrix;
+
+    [
+        t0 * s_red,
+        t1 * s_green,
+        t2 * s_blue,
+        t3 * s_red,
+        t4 * s_green,
+        t5 * s_blue,
+        t6 * s_red,
+        t7 * s_green,
+        t8 * s_blue,
+    ]
+}
+
+fn yxy_to_xyz(yxy: [f64; 3]) -> [f64; 3] {
+    let [x, y, luma] = yxy;
+
+    if !y.is_normal() {
+        return [0.0, luma, 0.0];
+    }
+
+    [x / y * luma, luma, (1.0 - x - y) / y * luma]
+}
+
+fn multiply_3x3_and_vec3(matrix: Mat3, vector: [f64; 3]) -> [f64; 3] {
+    let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = matrix;
+    let [x, y, z] = vector;
+
+    [
+        m0 * x + m1 * y + m2 * z,
+        m3 * x + m4 * y + m5 * z,
+        m6 * x + m7 * y + m8 * z,
+    ]
+}
+
+fn matrix_inverse(a: Mat3) -> Mat3 {
+    let d0 = a[4] * a[8] - a[5] * a[7];
+    let d1 = a[3] * a[8] - a[5] * a[6];
+    let d2 = a[3] * a[7] - a[4] * a[6];
+    let det = a[0] * d0 - a[1] * d1 + a[2] * d2;
+    let d3 = a[1] * a[8] - a[2] * a[7];
+    let d4 = a[0] * a[8] - a[2] * a[6];
+    let d5 = a[0] * a[7] - a[1] * a[6];
+    let d6 = a[1] * a[5] - a[2] * a[4];
+    let d7 = a[0] * a[5] - a[2] * a[3];
+    let d8 = a[0] * a[4] - a[1] * a[3];
+
+    assert!(det.is_normal(), "the matrix is not invertible");
+    let det = det.recip();
+
+    [
+        d0 * det,
+        -d3 * det,
+        d6 * det,
+        -d1 * det,
+        d4 * det,
+        -d7 * det,
+        d2 * det,
+        -d5 * det,
+        d8 * det,
+    ]
+}
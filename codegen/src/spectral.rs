@@ -0,0 +1,92 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use anyhow::{Context, Result};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+
+use crate::codegen_file::CodegenFile;
+
+const START: f64 = 380.0;
+const STEP: f64 = 5.0;
+
+pub fn generate() -> Result<()> {
+    let mut file = CodegenFile::create("palette/src/spectral/codegen.rs")?;
+
+    // The color matching functions are the 5 nm tables from CIE 15:2004,
+    // between 380 nm and 780 nm.
+    file.append(build_table(
+        "CIE_1931_2",
+        &parse_table("codegen/res/cie_1931_2.txt")?,
+    ))?;
+    file.append(build_table(
+        "CIE_1964_10",
+        &parse_table("codegen/res/cie_1964_10.txt")?,
+    ))?;
+
+    Ok(())
+}
+
+fn parse_table(path: &str) -> Result<Vec<[f64; 3]>> {
+    let reader =
+        BufReader::new(File::open(path).with_context(|| format!("could not open {path}"))?);
+
+    // Expected format: "wavelength\tx\ty\tz"
+    reader
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let line = line?;
+            let mut parts = line.split('\t');
+
+            let wavelength: f64 = parts
+                .next()
+                .with_context(|| format!("missing wavelength on line {} of {path}", index + 1))?
+                .parse()
+                .with_context(|| {
+                    format!("couldn't parse wavelength on line {} of {path}", index + 1)
+                })?;
+
+            let expected_wavelength = START + index as f64 * STEP;
+            anyhow::ensure!(
+                wavelength == expected_wavelength,
+                "expected {expected_wavelength} nm on line {} of {path}, but found {wavelength} nm",
+                index + 1
+            );
+
+            let mut values = [0.0; 3];
+            for (value, name) in values.iter_mut().zip(&["x", "y", "z"]) {
+                *value = parts
+                    .next()
+                    .with_context(|| format!("missing {name} for {wavelength} nm in {path}"))?
+                    .parse()
+                    .with_context(|| {
+                        format!("couldn't parse {name} for {wavelength} nm in {path}")
+                    })?;
+            }
+
+            Ok(values)
+        })
+        .collect()
+}
+
+fn build_table(name: &str, values: &[[f64; 3]]) -> TokenStream {
+    let rows = values.iter().map(|&[x, y, z]| quote! {[#x, #y, #z]});
+    let length = Literal::usize_unsuffixed(values.len());
+
+    let table = Ident::new(name, Span::call_site());
+    let start = Ident::new(&format!("{name}_START"), Span::call_site());
+    let step = Ident::new(&format!("{name}_STEP"), Span::call_site());
+
+    // The table has one `[x̄, ȳ, z̄]` entry per `STEP` nanometers, starting at
+    // `START`.
+    quote! {
+        pub const #start: f64 = #START;
+        pub const #step: f64 = #STEP;
+        pub const #table: [[f64; 3]; #length] = [
+            #(#rows),*
+        ];
+    }
+}
@@ -0,0 +1,55 @@
+use anyhow::Result;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::codegen_file::CodegenFile;
+
+const START: f64 = 1000.0;
+const END: f64 = 15000.0;
+const STEP_FACTOR: f64 = 1.01;
+
+pub fn generate() -> Result<()> {
+    let mut file = CodegenFile::create("palette/src/temperature/codegen.rs")?;
+
+    file.append(build_planckian_locus_table())?;
+
+    Ok(())
+}
+
+fn build_planckian_locus_table() -> TokenStream {
+    let mut rows = Vec::new();
+    let mut temperature = START;
+
+    while temperature <= END {
+        let [u, v] = planckian_locus_uv(temperature);
+        rows.push(quote! {[#temperature, #u, #v]});
+
+        temperature *= STEP_FACTOR;
+    }
+
+    let length = Literal::usize_unsuffixed(rows.len());
+
+    // The table has one `[T, u, v]` entry per 1% increase in temperature,
+    // starting at `START` Kelvin.
+    quote! {
+        pub const PLANCKIAN_LOCUS: [[f64; 3]; #length] = [
+            #(#rows),*
+        ];
+    }
+}
+
+// The CIE 1960 uv coordinates of the Planckian locus are approximated with the
+// rational functions from "An algorithm to calculate correlated colour
+// temperature" by Krystek (1985). They are within about 1e-4 of the exact
+// coordinates between 1000 K and 15000 K.
+fn planckian_locus_uv(temperature: f64) -> [f64; 2] {
+    let t = temperature;
+    let t2 = t * t;
+
+    let u = (0.860117757 + 1.54118254e-4 * t + 1.28641212e-7 * t2)
+        / (1.0 + 8.42420235e-4 * t + 7.08145163e-7 * t2);
+    let v = (0.317398726 + 4.22806245e-5 * t + 4.20481691e-8 * t2)
+        / (1.0 - 2.89741816e-5 * t + 1.61456053e-7 * t2);
+
+    [u, v]
+}
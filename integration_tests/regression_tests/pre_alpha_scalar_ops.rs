@@ -0,0 +1,64 @@
+// Checks that the `PreAlpha` operator implementations with the scalar on the
+// left side don't make the trait solver overflow, like in issue #283, when
+// another crate has its own generic operator implementations for `f32` and
+// `f64`. This only needs to compile.
+
+use std::ops::{Div, Mul};
+
+use palette::{blend::Premultiply, LinSrgb, Oklab};
+
+#[derive(Clone, Copy, Debug)]
+struct Vector<T>(T, T);
+
+impl<T> Mul<Vector<T>> for f32
+where
+    f32: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, vector: Vector<T>) -> Vector<T> {
+        Vector(self * vector.0, self * vector.1)
+    }
+}
+
+impl<T> Mul<Vector<T>> for f64
+where
+    f64: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, vector: Vector<T>) -> Vector<T> {
+        Vector(self * vector.0, self * vector.1)
+    }
+}
+
+impl<T> Div<Vector<T>> for f64
+where
+    f64: Div<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn div(self, vector: Vector<T>) -> Vector<T> {
+        Vector(self / vector.0, self / vector.1)
+    }
+}
+
+fn scale<T>(factor: f64, vector: Vector<T>) -> Vector<T>
+where
+    f64: Mul<Vector<T>, Output = Vector<T>>,
+{
+    factor * vector
+}
+
+fn main() {
+    println!("{}", 42.0 * 1.0);
+    println!("{:?}", 2.0 * Vector(1.0f32, 2.0));
+    println!("{:?}", 2.0 / Vector(1.0, 2.0));
+    println!("{:?}", scale(2.0, Vector(1.0, 2.0)));
+
+    let rgb = LinSrgb::new(0.8f32, 0.4, 0.2).premultiply(0.5);
+    println!("{:?}", 2.0 * rgb);
+
+    let lab = Oklab::new(0.5f64, 0.1, -0.1).premultiply(0.5);
+    println!("{:?}", 2.0 * lab);
+}
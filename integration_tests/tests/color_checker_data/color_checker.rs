@@ -9,7 +9,15 @@ The Rgb colors in this data appear to be adapted to the reference white point fo
 use approx::assert_relative_eq;
 use lazy_static::lazy_static;
 
-use palette::{convert::IntoColorUnclamped, num::IntoScalarArray, white_point::D50, Lab, Xyz, Yxy};
+use palette::{
+    chromatic_adaptation::AdaptIntoUnclamped,
+    convert::{Convert, FromColorUnclamped, IntoColorUnclamped},
+    encoding::{Linear, ProPhotoRgb},
+    num::IntoScalarArray,
+    rgb::LinProPhotoRgb,
+    white_point::{D50, D65},
+    Lab, LinSrgb, Xyz, Yxy,
+};
 
 use super::load_data::{load_color_checker, ColorCheckerRaw};
 use super::MAX_ERROR;
@@ -111,6 +119,19 @@ pub fn run_from_lab_tests() {
     }
 }
 
+pub fn run_rgb_matrix_tests() {
+    let matrix = LinSrgb::<f64>::matrix_from_rgb::<Linear<ProPhotoRgb>>();
+
+    for expected in TEST_DATA.iter() {
+        let prophoto = LinProPhotoRgb::from_color_unclamped(expected.xyz);
+
+        let multi_step: Xyz<D65, f64> = expected.xyz.adapt_into_unclamped();
+        let multi_step = LinSrgb::from_color_unclamped(multi_step);
+
+        assert_relative_eq!(matrix.convert(prophoto), multi_step, epsilon = 0.000001);
+    }
+}
+
 pub mod wide_f64x2 {
     use super::*;
 
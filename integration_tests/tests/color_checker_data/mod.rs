@@ -29,6 +29,10 @@ pub fn color_checker_from_xyz() {
 pub fn color_checker_from_lab() {
     color_checker::run_from_lab_tests();
 }
+#[test]
+pub fn color_checker_rgb_matrix() {
+    color_checker::run_rgb_matrix_tests();
+}
 
 pub mod wide {
     #[test]
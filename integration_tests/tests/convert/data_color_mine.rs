@@ -2,7 +2,7 @@
 List of color from www.colormine.org
 */
 
-use std::path::Path;
+use std::{convert::TryInto, path::Path};
 
 use approx::assert_relative_eq;
 use lazy_static::lazy_static;
@@ -274,53 +274,98 @@ where
     }
 }
 
-impl<F, S> From<ColorMine<S>> for [ColorMine<F>; 2]
+impl<F, S, const N: usize> From<ColorMine<S>> for [ColorMine<F>; N]
 where
-    S: IntoScalarArray<2, Scalar = F>,
-    F: Real + Zero + One + Default,
+    [Xyz<D65, F>; N]: Default,
+    [Yxy<D65, F>; N]: Default,
+    [Lab<D65, F>; N]: Default,
+    [Lch<D65, F>; N]: Default,
+    [LinSrgb<F>; N]: Default,
+    [Srgb<F>; N]: Default,
+    [Hsl<::palette::encoding::Srgb, F>; N]: Default,
+    [Hsv<::palette::encoding::Srgb, F>; N]: Default,
+    [Hwb<::palette::encoding::Srgb, F>; N]: Default,
+    S: IntoScalarArray<N, Scalar = F>,
+    F: Real + Zero + One + Default + Copy + std::fmt::Debug,
     D65: WhitePoint<F>,
     Yxy<D65, F>: FromColorUnclamped<Xyz<D65, F>>,
 {
     fn from(color_data: ColorMine<S>) -> Self {
-        let [xyz0, xyz1]: [Xyz<_, F>; 2] = color_data.xyz.into();
-        let [yxy0, yxy1]: [Yxy<_, F>; 2] = color_data.yxy.into();
-        let [lab0, lab1]: [Lab<_, F>; 2] = color_data.lab.into();
-        let [lch0, lch1]: [Lch<_, F>; 2] = color_data.lch.into();
-        let [linear_rgb0, linear_rgb1]: [LinSrgb<F>; 2] = color_data.linear_rgb.into();
-        let [rgb0, rgb1]: [Srgb<F>; 2] = color_data.rgb.into();
-        let [hsl0, hsl1]: [Hsl<_, F>; 2] = color_data.hsl.into();
-        let [hsv0, hsv1]: [Hsv<_, F>; 2] = color_data.hsv.into();
-        let [hwb0, hwb1]: [Hwb<_, F>; 2] = color_data.hwb.into();
+        let xyz: [Xyz<_, F>; N] = color_data.xyz.into();
+        let yxy: [Yxy<_, F>; N] = color_data.yxy.into();
+        let lab: [Lab<_, F>; N] = color_data.lab.into();
+        let lch: [Lch<_, F>; N] = color_data.lch.into();
+        let linear_rgb: [LinSrgb<F>; N] = color_data.linear_rgb.into();
+        let rgb: [Srgb<F>; N] = color_data.rgb.into();
+        let hsl: [Hsl<_, F>; N] = color_data.hsl.into();
+        let hsv: [Hsv<_, F>; N] = color_data.hsv.into();
+        let hwb: [Hwb<_, F>; N] = color_data.hwb.into();
+
+        let colors: Vec<_> = (0..N)
+            .map(|index| ColorMine {
+                xyz: xyz[index],
+                yxy: yxy[index],
+                lab: lab[index],
+                lch: lch[index],
+                linear_rgb: linear_rgb[index],
+                rgb: rgb[index],
+                hsl: hsl[index],
+                hsv: hsv[index],
+                hwb: hwb[index],
+            })
+            .collect();
 
-        [
-            ColorMine {
-                xyz: xyz0,
-                yxy: yxy0,
-                lab: lab0,
-                lch: lch0,
-                linear_rgb: linear_rgb0,
-                rgb: rgb0,
-                hsl: hsl0,
-                hsv: hsv0,
-                hwb: hwb0,
-            },
-            ColorMine {
-                xyz: xyz1,
-                yxy: yxy1,
-                lab: lab1,
-                lch: lch1,
-                linear_rgb: linear_rgb1,
-                rgb: rgb1,
-                hsl: hsl1,
-                hsv: hsv1,
-                hwb: hwb1,
-            },
-        ]
+        colors.try_into().unwrap()
+    }
+}
+
+impl From<ColorMine<f32>> for ColorMine<f64> {
+    fn from(color: ColorMine<f32>) -> Self {
+        ColorMine {
+            xyz: Xyz::new(color.xyz.x.into(), color.xyz.y.into(), color.xyz.z.into()),
+            yxy: Yxy::new(
+                color.yxy.x.into(),
+                color.yxy.y.into(),
+                color.yxy.luma.into(),
+            ),
+            lab: Lab::new(color.lab.l.into(), color.lab.a.into(), color.lab.b.into()),
+            lch: Lch::new(
+                color.lch.l.into(),
+                color.lch.chroma.into(),
+                f64::from(color.lch.hue.into_inner()),
+            ),
+            rgb: Srgb::new(
+                color.rgb.red.into(),
+                color.rgb.green.into(),
+                color.rgb.blue.into(),
+            ),
+            linear_rgb: LinSrgb::new(
+                color.linear_rgb.red.into(),
+                color.linear_rgb.green.into(),
+                color.linear_rgb.blue.into(),
+            ),
+            hsl: Hsl::new_srgb(
+                f64::from(color.hsl.hue.into_inner()),
+                color.hsl.saturation.into(),
+                color.hsl.lightness.into(),
+            ),
+            hsv: Hsv::new_srgb(
+                f64::from(color.hsv.hue.into_inner()),
+                color.hsv.saturation.into(),
+                color.hsv.value.into(),
+            ),
+            hwb: Hwb::new_srgb(
+                f64::from(color.hwb.hue.into_inner()),
+                color.hwb.whiteness.into(),
+                color.hwb.blackness.into(),
+            ),
+        }
     }
 }
 
 lazy_static! {
     static ref TEST_DATA: Vec<ColorMine<f64>> = load_data(None);
+    static ref TEST_DATA_F32: Vec<ColorMine<f32>> = load_data(None);
 }
 
 pub fn load_data<F>(data_path: Option<&Path>) -> Vec<ColorMine<F>>
@@ -358,6 +403,15 @@ fn check_equal_cie(src: &mut ColorMine<f64>, tgt: &ColorMine<f64>) {
     }
     assert_relative_eq!(src.lch, tgt.lch, epsilon = 7.0);
 }
+fn check_equal_cie_approx_recip(src: &mut ColorMine<f64>, tgt: &ColorMine<f64>) {
+    // The approximate `f32` SIMD reciprocal makes black come out of Lab as a
+    // tiny non-zero luma, which gives it the chromaticity of the white point.
+    if tgt.yxy.luma == 0.0 && src.yxy.luma.abs() < 1e-4 {
+        src.yxy.x = tgt.yxy.x;
+        src.yxy.y = tgt.yxy.y;
+    }
+    check_equal_cie(src, tgt);
+}
 fn check_equal_rgb(src: &ColorMine<f64>, tgt: &ColorMine<f64>) {
     assert_relative_eq!(src.rgb, tgt.rgb, epsilon = 0.05);
     assert_relative_eq!(src.hsl, tgt.hsl, epsilon = 0.05);
@@ -420,80 +474,91 @@ pub fn run_from_lch_tests() {
     }
 }
 
-pub mod wide_f64x2 {
-    use super::*;
+macro_rules! impl_wide_tests {
+    ($module: ident, $simd: ty, $scalar: ty, $lanes: expr, $data: ident, $check_cie: ident) => {
+        pub mod $module {
+            use super::*;
 
-    pub fn run_from_xyz_tests() {
-        for expected in TEST_DATA.chunks_exact(2) {
-            let colors = Xyz::<_, wide::f64x2>::from([expected[0].xyz, expected[1].xyz]);
-            let [mut result0, mut result1]: [ColorMine<f64>; 2] = ColorMine::from(colors).into();
-            check_equal_cie(&mut result0, &expected[0]);
-            check_equal_cie(&mut result1, &expected[1]);
-        }
-    }
-    pub fn run_from_yxy_tests() {
-        for expected in TEST_DATA.chunks_exact(2) {
-            let colors = Yxy::<_, wide::f64x2>::from([expected[0].yxy, expected[1].yxy]);
-            let [mut result0, mut result1]: [ColorMine<f64>; 2] = ColorMine::from(colors).into();
-            check_equal_cie(&mut result0, &expected[0]);
-            check_equal_cie(&mut result1, &expected[1]);
-        }
-    }
-    pub fn run_from_rgb_tests() {
-        for expected in TEST_DATA.chunks_exact(2) {
-            let colors = Srgb::<wide::f64x2>::from([expected[0].rgb, expected[1].rgb]);
-            let [result0, result1]: [ColorMine<f64>; 2] = ColorMine::from(colors).into();
-            check_equal_rgb(&result0, &expected[0]);
-            check_equal_rgb(&result1, &expected[1]);
-        }
-    }
-    pub fn run_from_linear_rgb_tests() {
-        for expected in TEST_DATA.chunks_exact(2) {
-            let colors =
-                LinSrgb::<wide::f64x2>::from([expected[0].linear_rgb, expected[1].linear_rgb]);
-            let [mut result0, mut result1]: [ColorMine<f64>; 2] = ColorMine::from(colors).into();
-            check_equal_cie(&mut result0, &expected[0]);
-            check_equal_cie(&mut result1, &expected[1]);
-        }
-    }
-    pub fn run_from_hsl_tests() {
-        for expected in TEST_DATA.chunks_exact(2) {
-            let colors = Hsl::<_, wide::f64x2>::from([expected[0].hsl, expected[1].hsl]);
-            let [result0, result1]: [ColorMine<f64>; 2] = ColorMine::from(colors).into();
-            check_equal_rgb(&result0, &expected[0]);
-            check_equal_rgb(&result1, &expected[1]);
-        }
-    }
-    pub fn run_from_hsv_tests() {
-        for expected in TEST_DATA.chunks_exact(2) {
-            let colors = Hsv::<_, wide::f64x2>::from([expected[0].hsv, expected[1].hsv]);
-            let [result0, result1]: [ColorMine<f64>; 2] = ColorMine::from(colors).into();
-            check_equal_rgb(&result0, &expected[0]);
-            check_equal_rgb(&result1, &expected[1]);
-        }
-    }
-    pub fn run_from_hwb_tests() {
-        for expected in TEST_DATA.chunks_exact(2) {
-            let colors = Hwb::<_, wide::f64x2>::from([expected[0].hwb, expected[1].hwb]);
-            let [result0, result1]: [ColorMine<f64>; 2] = ColorMine::from(colors).into();
-            check_equal_rgb(&result0, &expected[0]);
-            check_equal_rgb(&result1, &expected[1]);
-        }
-    }
-    pub fn run_from_lab_tests() {
-        for expected in TEST_DATA.chunks_exact(2) {
-            let colors = Lab::<_, wide::f64x2>::from([expected[0].lab, expected[1].lab]);
-            let [mut result0, mut result1]: [ColorMine<f64>; 2] = ColorMine::from(colors).into();
-            check_equal_cie(&mut result0, &expected[0]);
-            check_equal_cie(&mut result1, &expected[1]);
-        }
-    }
-    pub fn run_from_lch_tests() {
-        for expected in TEST_DATA.chunks_exact(2) {
-            let colors = Lch::<_, wide::f64x2>::from([expected[0].lch, expected[1].lch]);
-            let [mut result0, mut result1]: [ColorMine<f64>; 2] = ColorMine::from(colors).into();
-            check_equal_cie(&mut result0, &expected[0]);
-            check_equal_cie(&mut result1, &expected[1]);
+            fn run_tests<C>(
+                select: fn(ColorMine<$simd>) -> C,
+                check: fn(&mut ColorMine<f64>, &ColorMine<f64>),
+            ) where
+                ColorMine<$simd>: From<C>,
+            {
+                for (data, expected) in $data
+                    .chunks_exact($lanes)
+                    .zip(TEST_DATA.chunks_exact($lanes))
+                {
+                    let colors: [ColorMine<$scalar>; $lanes] = data.to_vec().try_into().unwrap();
+                    let colors =
+                        <ColorMine<$simd> as From<[ColorMine<$scalar>; $lanes]>>::from(colors);
+                    let results: [ColorMine<$scalar>; $lanes] =
+                        <ColorMine<$simd> as From<C>>::from(select(colors)).into();
+
+                    for (result, expected) in IntoIterator::into_iter(results).zip(expected) {
+                        check(&mut ColorMine::<f64>::from(result), expected);
+                    }
+                }
+            }
+
+            pub fn run_from_xyz_tests() {
+                run_tests(|colors| colors.xyz, $check_cie);
+            }
+            pub fn run_from_yxy_tests() {
+                run_tests(|colors| colors.yxy, $check_cie);
+            }
+            pub fn run_from_rgb_tests() {
+                run_tests(
+                    |colors| colors.rgb,
+                    |result, expected| check_equal_rgb(result, expected),
+                );
+            }
+            pub fn run_from_linear_rgb_tests() {
+                run_tests(|colors| colors.linear_rgb, $check_cie);
+            }
+            pub fn run_from_hsl_tests() {
+                run_tests(
+                    |colors| colors.hsl,
+                    |result, expected| check_equal_rgb(result, expected),
+                );
+            }
+            pub fn run_from_hsv_tests() {
+                run_tests(
+                    |colors| colors.hsv,
+                    |result, expected| check_equal_rgb(result, expected),
+                );
+            }
+            pub fn run_from_hwb_tests() {
+                run_tests(
+                    |colors| colors.hwb,
+                    |result, expected| check_equal_rgb(result, expected),
+                );
+            }
+            pub fn run_from_lab_tests() {
+                run_tests(|colors| colors.lab, $check_cie);
+            }
+            pub fn run_from_lch_tests() {
+                run_tests(|colors| colors.lch, $check_cie);
+            }
         }
-    }
+    };
 }
+
+impl_wide_tests!(wide_f64x2, wide::f64x2, f64, 2, TEST_DATA, check_equal_cie);
+impl_wide_tests!(wide_f64x4, wide::f64x4, f64, 4, TEST_DATA, check_equal_cie);
+impl_wide_tests!(
+    wide_f32x4,
+    wide::f32x4,
+    f32,
+    4,
+    TEST_DATA_F32,
+    check_equal_cie_approx_recip
+);
+impl_wide_tests!(
+    wide_f32x8,
+    wide::f32x8,
+    f32,
+    8,
+    TEST_DATA_F32,
+    check_equal_cie_approx_recip
+);
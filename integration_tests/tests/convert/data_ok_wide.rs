@@ -0,0 +1,86 @@
+/*
+Okhsl, Okhsv and Okhwb conversions for SIMD values, checked lane by lane against
+the scalar conversions. The colors are taken from the colormine data set.
+
+The SIMD math functions are not always rounded exactly like the scalar ones,
+so the lanes are allowed to differ by a small epsilon. The hue is in degrees,
+which makes its differences larger than for the other components.
+*/
+
+use std::convert::TryInto;
+
+use approx::assert_relative_eq;
+use lazy_static::lazy_static;
+
+use palette::{convert::FromColorUnclamped, Okhsl, Okhsv, Okhwb, Oklch, Srgb};
+
+use super::data_color_mine::{load_data, ColorMine};
+
+lazy_static! {
+    static ref TEST_DATA: Vec<Srgb<f64>> = load_data::<f64>(None)
+        .into_iter()
+        .map(|color: ColorMine<f64>| color.rgb)
+        .collect();
+}
+
+macro_rules! check_lanes {
+    ($color: ident, $simd: ty, $scalar: ty, $lanes: expr, $epsilon: expr) => {
+        for data in TEST_DATA.chunks_exact($lanes) {
+            let rgb: [Srgb<$scalar>; $lanes] = data
+                .iter()
+                .map(|color| color.into_format())
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+
+            // From sRGB
+            let expected = rgb.map($color::<$scalar>::from_color_unclamped);
+            let results: [$color<$scalar>; $lanes] =
+                $color::<$simd>::from_color_unclamped(Srgb::<$simd>::from(rgb)).into();
+
+            for ((mut result, expected), rgb) in
+                IntoIterator::into_iter(results).zip(expected).zip(rgb)
+            {
+                // The hue of almost gray colors is very sensitive to rounding
+                // errors, so it's only compared for colorful colors.
+                if Oklch::from_color_unclamped(rgb).chroma < 0.0001 {
+                    result.hue = expected.hue;
+                }
+
+                assert_relative_eq!(result, expected, epsilon = $epsilon);
+            }
+
+            // Into sRGB
+            let expected_rgb = expected.map(Srgb::<$scalar>::from_color_unclamped);
+            let results: [Srgb<$scalar>; $lanes] =
+                Srgb::<$simd>::from_color_unclamped($color::<$simd>::from(expected)).into();
+
+            for (result, expected) in IntoIterator::into_iter(results).zip(expected_rgb) {
+                assert_relative_eq!(result, expected, epsilon = $epsilon);
+            }
+        }
+    };
+}
+
+macro_rules! impl_wide_tests {
+    ($module: ident, $simd: ty, $scalar: ty, $lanes: expr, $epsilon: expr) => {
+        pub mod $module {
+            use super::*;
+
+            pub fn run_okhsl_tests() {
+                check_lanes!(Okhsl, $simd, $scalar, $lanes, $epsilon);
+            }
+            pub fn run_okhsv_tests() {
+                check_lanes!(Okhsv, $simd, $scalar, $lanes, $epsilon);
+            }
+            pub fn run_okhwb_tests() {
+                check_lanes!(Okhwb, $simd, $scalar, $lanes, $epsilon);
+            }
+        }
+    };
+}
+
+impl_wide_tests!(wide_f64x2, wide::f64x2, f64, 2, 0.0000000001);
+impl_wide_tests!(wide_f64x4, wide::f64x4, f64, 4, 0.0000000001);
+impl_wide_tests!(wide_f32x4, wide::f32x4, f32, 4, 0.002);
+impl_wide_tests!(wide_f32x8, wide::f32x8, f32, 8, 0.002);
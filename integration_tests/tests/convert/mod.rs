@@ -1,6 +1,7 @@
 mod data_cie_15_2004;
 mod data_ciede_2000;
 mod data_color_mine;
+mod data_ok_wide;
 mod lab_lch;
 
 #[test]
@@ -56,40 +57,79 @@ pub mod wide {
         super::data_cie_15_2004::wide_f32x4::run_tests();
     }
 
-    #[test]
-    pub fn color_mine_from_xyz() {
-        super::data_color_mine::wide_f64x2::run_from_xyz_tests();
-    }
-    #[test]
-    pub fn color_mine_from_yxy() {
-        super::data_color_mine::wide_f64x2::run_from_yxy_tests();
-    }
-    #[test]
-    pub fn color_mine_from_linear_rgb() {
-        super::data_color_mine::wide_f64x2::run_from_linear_rgb_tests();
-    }
-    #[test]
-    pub fn color_mine_from_rgb() {
-        super::data_color_mine::wide_f64x2::run_from_rgb_tests();
-    }
-    #[test]
-    pub fn color_mine_from_hsl() {
-        super::data_color_mine::wide_f64x2::run_from_hsl_tests();
-    }
-    #[test]
-    pub fn color_mine_from_hsv() {
-        super::data_color_mine::wide_f64x2::run_from_hsv_tests();
-    }
-    #[test]
-    pub fn color_mine_from_hwb() {
-        super::data_color_mine::wide_f64x2::run_from_hwb_tests();
+    macro_rules! color_mine_tests {
+        ($($module: ident),+) => {
+            $(
+                mod $module {
+                    use crate::convert::data_color_mine::$module;
+
+                    #[test]
+                    pub fn color_mine_from_xyz() {
+                        $module::run_from_xyz_tests();
+                    }
+                    #[test]
+                    pub fn color_mine_from_yxy() {
+                        $module::run_from_yxy_tests();
+                    }
+                    #[test]
+                    pub fn color_mine_from_linear_rgb() {
+                        $module::run_from_linear_rgb_tests();
+                    }
+                    #[test]
+                    pub fn color_mine_from_rgb() {
+                        $module::run_from_rgb_tests();
+                    }
+                    #[test]
+                    pub fn color_mine_from_hsl() {
+                        $module::run_from_hsl_tests();
+                    }
+                    #[test]
+                    pub fn color_mine_from_hsv() {
+                        $module::run_from_hsv_tests();
+                    }
+                    #[test]
+                    pub fn color_mine_from_hwb() {
+                        $module::run_from_hwb_tests();
+                    }
+                    #[test]
+                    pub fn color_mine_from_lab() {
+                        $module::run_from_lab_tests();
+                    }
+                    #[test]
+                    pub fn color_mine_from_lch() {
+                        $module::run_from_lch_tests();
+                    }
+                }
+            )+
+        };
     }
-    #[test]
-    pub fn color_mine_from_lab() {
-        super::data_color_mine::wide_f64x2::run_from_lab_tests();
+
+    color_mine_tests!(wide_f64x2, wide_f64x4, wide_f32x4, wide_f32x8);
+
+    macro_rules! ok_tests {
+        ($($module: ident),+) => {
+            $(
+                mod $module {
+                    use crate::convert::data_ok_wide::$module;
+
+                    #[test]
+                    pub fn okhsl_conversion() {
+                        $module::run_okhsl_tests();
+                    }
+                    #[test]
+                    pub fn okhsv_conversion() {
+                        $module::run_okhsv_tests();
+                    }
+                    #[test]
+                    pub fn okhwb_conversion() {
+                        $module::run_okhwb_tests();
+                    }
+                }
+            )+
+        };
     }
-    #[test]
-    pub fn color_mine_from_lch() {
-        super::data_color_mine::wide_f64x2::run_from_lch_tests();
+
+    mod ok {
+        ok_tests!(wide_f64x2, wide_f64x4, wide_f32x4, wide_f32x8);
     }
 }
@@ -0,0 +1,259 @@
+//! Property tests for converting colors from sRGB to other color spaces and
+//! back again. The expected accuracy for each color space is documented in
+//! `palette::convert::accuracy`.
+
+use core::fmt::Debug;
+
+use proptest::prelude::*;
+
+use palette::{
+    cam16::{Cam16, Cam16Jmh, Cam16UcsJab, Parameters},
+    cast::{self, ArrayCast},
+    convert::{accuracy, FromColorUnclamped, IntoColorUnclamped},
+    encoding,
+    white_point::D65,
+    Hsl, Hsluv, Hsv, Hwb, Lab, Lch, Lchuv, LinSrgb, Luv, Okhsl, Okhsv, Okhwb, Oklab, Oklch, Srgb,
+    Xyz, Yxy,
+};
+
+/// The corners of the RGB cube, and a middle gray.
+const BOUNDARY_COLORS: [[f64; 3]; 9] = [
+    [0.0, 0.0, 0.0],
+    [1.0, 1.0, 1.0],
+    [1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, 0.0, 1.0],
+    [0.0, 1.0, 1.0],
+    [1.0, 0.0, 1.0],
+    [1.0, 1.0, 0.0],
+    [0.5, 0.5, 0.5],
+];
+
+/// Colors outside the sRGB gamut, for the color spaces with unbounded
+/// components.
+const OUT_OF_GAMUT_COLORS: [[f64; 3]; 4] = [
+    [1.2, -0.1, 0.5],
+    [-0.5, 0.3, 1.5],
+    [2.0, 2.0, 2.0],
+    [-0.2, -0.2, -0.2],
+];
+
+fn check_components<T>(components: &[T]) -> Result<(), TestCaseError>
+where
+    T: Into<f64> + Copy + Debug,
+{
+    prop_assert!(
+        components
+            .iter()
+            .all(|&component| component.into().is_finite()),
+        "{:?} has NaN or infinite components",
+        components
+    );
+
+    Ok(())
+}
+
+fn check_round_trip<T>(
+    color: Srgb<T>,
+    round_trip: Srgb<T>,
+    epsilon: f64,
+) -> Result<(), TestCaseError>
+where
+    T: Into<f64> + Copy + Debug,
+{
+    let expected: [T; 3] = cast::into_array(color);
+    let actual: [T; 3] = cast::into_array(round_trip);
+
+    check_components(&actual)?;
+
+    for (&expected_component, &actual_component) in expected.iter().zip(&actual) {
+        let difference = (expected_component.into() - actual_component.into()).abs();
+        prop_assert!(
+            difference <= epsilon,
+            "{:?} became {:?}, which is more than {} off",
+            color,
+            round_trip,
+            epsilon
+        );
+    }
+
+    Ok(())
+}
+
+fn round_trip<C, T>(color: Srgb<T>, epsilon: f64) -> Result<(), TestCaseError>
+where
+    C: FromColorUnclamped<Srgb<T>> + IntoColorUnclamped<Srgb<T>> + ArrayCast<Array = [T; 3]> + Copy,
+    T: Into<f64> + Copy + Debug,
+{
+    let converted = C::from_color_unclamped(color);
+    check_components(&cast::into_array(converted))?;
+
+    check_round_trip(color, converted.into_color_unclamped(), epsilon)
+}
+
+fn cam16_round_trip<T>(color: Srgb<T>, epsilon: f64) -> Result<(), TestCaseError>
+where
+    T: Cam16RoundTrip,
+{
+    T::cam16_round_trip(color, epsilon)
+}
+
+/// Converts through the CAM16 types, which need viewing conditions.
+trait Cam16RoundTrip: Sized {
+    fn cam16_round_trip(color: Srgb<Self>, epsilon: f64) -> Result<(), TestCaseError>;
+}
+
+macro_rules! impl_cam16_round_trip {
+    ($($float: ident),+) => {
+        $(
+            impl Cam16RoundTrip for $float {
+                fn cam16_round_trip(color: Srgb<$float>, epsilon: f64) -> Result<(), TestCaseError> {
+                    let parameters = Parameters::default_static_wp(40.0).bake();
+                    let xyz: Xyz<D65, $float> = color.into_color_unclamped();
+
+                    let cam16 = Cam16::from_xyz(xyz, parameters);
+                    check_components(&[
+                        cam16.lightness,
+                        cam16.chroma,
+                        cam16.hue.into_degrees(),
+                        cam16.brightness,
+                        cam16.colorfulness,
+                        cam16.saturation,
+                    ])?;
+                    check_round_trip(
+                        color,
+                        cam16.into_xyz(parameters).into_color_unclamped(),
+                        epsilon,
+                    )?;
+
+                    let jmh = Cam16Jmh::from_full(cam16);
+                    check_round_trip(
+                        color,
+                        jmh.into_xyz(parameters).into_color_unclamped(),
+                        epsilon,
+                    )?;
+
+                    let ucs = Cam16UcsJab::from_color_unclamped(jmh);
+                    check_components(&cast::into_array(ucs))?;
+                    check_round_trip(
+                        color,
+                        Cam16Jmh::from_color_unclamped(ucs)
+                            .into_xyz(parameters)
+                            .into_color_unclamped(),
+                        epsilon,
+                    )
+                }
+            }
+        )+
+    };
+}
+
+impl_cam16_round_trip!(f32, f64);
+
+fn srgb<T>([red, green, blue]: [f64; 3], convert: fn(f64) -> T) -> Srgb<T> {
+    Srgb::new(convert(red), convert(green), convert(blue))
+}
+
+macro_rules! round_trip_tests {
+    ($($name: ident: $check: ident $(<$f32_ty: ty, $f64_ty: ty>)?, $epsilon: ident;)+) => {
+        $(
+            mod $name {
+                use super::*;
+
+                proptest! {
+                    #[test]
+                    fn f32_in_gamut(red in 0.0f32..=1.0, green in 0.0f32..=1.0, blue in 0.0f32..=1.0) {
+                        $check::<$($f32_ty,)? f32>(
+                            Srgb::new(red, green, blue),
+                            accuracy::$epsilon.f32.into(),
+                        )?;
+                    }
+
+                    #[test]
+                    fn f64_in_gamut(red in 0.0f64..=1.0, green in 0.0f64..=1.0, blue in 0.0f64..=1.0) {
+                        $check::<$($f64_ty,)? f64>(
+                            Srgb::new(red, green, blue),
+                            accuracy::$epsilon.f64,
+                        )?;
+                    }
+                }
+
+                #[test]
+                fn boundaries() {
+                    for &color in &BOUNDARY_COLORS {
+                        $check::<$($f32_ty,)? f32>(
+                            srgb(color, |component| component as f32),
+                            accuracy::$epsilon.f32.into(),
+                        )
+                        .unwrap();
+                        $check::<$($f64_ty,)? f64>(
+                            srgb(color, |component| component),
+                            accuracy::$epsilon.f64,
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! out_of_gamut_tests {
+    ($($name: ident: $f32_ty: ty, $f64_ty: ty, $epsilon: ident;)+) => {
+        $(
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn out_of_gamut() {
+                    for &color in &OUT_OF_GAMUT_COLORS {
+                        round_trip::<$f32_ty, f32>(
+                            srgb(color, |component| component as f32),
+                            accuracy::$epsilon.f32.into(),
+                        )
+                        .unwrap();
+                        round_trip::<$f64_ty, f64>(
+                            srgb(color, |component| component),
+                            accuracy::$epsilon.f64,
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+        )+
+    };
+}
+
+round_trip_tests! {
+    lin_srgb: round_trip<LinSrgb<f32>, LinSrgb<f64>>, LIN_SRGB;
+    xyz: round_trip<Xyz<D65, f32>, Xyz<D65, f64>>, XYZ;
+    yxy: round_trip<Yxy<D65, f32>, Yxy<D65, f64>>, YXY;
+    lab: round_trip<Lab<D65, f32>, Lab<D65, f64>>, LAB;
+    lch: round_trip<Lch<D65, f32>, Lch<D65, f64>>, LCH;
+    luv: round_trip<Luv<D65, f32>, Luv<D65, f64>>, LUV;
+    lchuv: round_trip<Lchuv<D65, f32>, Lchuv<D65, f64>>, LCHUV;
+    hsluv: round_trip<Hsluv<D65, f32>, Hsluv<D65, f64>>, HSLUV;
+    hsl: round_trip<Hsl<encoding::Srgb, f32>, Hsl<encoding::Srgb, f64>>, HSL;
+    hsv: round_trip<Hsv<encoding::Srgb, f32>, Hsv<encoding::Srgb, f64>>, HSV;
+    hwb: round_trip<Hwb<encoding::Srgb, f32>, Hwb<encoding::Srgb, f64>>, HWB;
+    oklab: round_trip<Oklab<f32>, Oklab<f64>>, OKLAB;
+    oklch: round_trip<Oklch<f32>, Oklch<f64>>, OKLCH;
+    okhsl: round_trip<Okhsl<f32>, Okhsl<f64>>, OKHSL;
+    okhsv: round_trip<Okhsv<f32>, Okhsv<f64>>, OKHSV;
+    okhwb: round_trip<Okhwb<f32>, Okhwb<f64>>, OKHWB;
+    cam16: cam16_round_trip, CAM16;
+}
+
+mod unbounded {
+    use super::*;
+
+    out_of_gamut_tests! {
+        lin_srgb: LinSrgb<f32>, LinSrgb<f64>, LIN_SRGB;
+        xyz: Xyz<D65, f32>, Xyz<D65, f64>, XYZ;
+        yxy: Yxy<D65, f32>, Yxy<D65, f64>, YXY;
+        lab: Lab<D65, f32>, Lab<D65, f64>, LAB;
+        lch: Lch<D65, f32>, Lch<D65, f64>, LCH;
+        oklab: Oklab<f32>, Oklab<f64>, OKLAB;
+        oklch: Oklch<f32>, Oklch<f64>, OKLCH;
+    }
+}
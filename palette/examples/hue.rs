@@ -1,4 +1,4 @@
-use palette::{FromColor, Hsl, Lch, ShiftHue, Srgb};
+use palette::prelude::*;
 
 fn main() {
     let mut image = image::open("example-data/input/fruits.png")
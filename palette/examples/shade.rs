@@ -1,4 +1,4 @@
-use palette::{Darken, FromColor, Hsv, IntoColor, Lab, Lighten, LinSrgb, Srgb};
+use palette::prelude::*;
 
 use image::{GenericImage, GenericImageView, RgbImage};
 
@@ -0,0 +1,587 @@
+//! Basic photo adjustments, such as exposure and white balance.
+//!
+//! The adjustments are small values that implement the [`Adjust`] trait, and
+//! operate on linear sRGB. They can be applied one by one, or combined into a
+//! [`Pipeline`], which folds consecutive linear adjustments into a single
+//! matrix.
+//!
+//! ```
+//! use palette::{
+//!     adjust::{Adjust, ChannelMixer, Exposure, WhiteBalance},
+//!     matrix::Mat3,
+//!     LinSrgb,
+//! };
+//!
+//! let color = LinSrgb::new(0.2f32, 0.3, 0.1);
+//!
+//! let brighter = Exposure(1.0).adjust(color);
+//! assert_eq!(brighter, LinSrgb::new(0.4, 0.6, 0.2));
+//!
+//! // Make an image that was lit by a 3200 K light source look neutral.
+//! let balanced = WhiteBalance::new(3200.0).adjust(color);
+//!
+//! // Swap the red and blue channels.
+//! let mixer = ChannelMixer(Mat3::from_rows([
+//!     [0.0, 0.0, 1.0],
+//!     [0.0, 1.0, 0.0],
+//!     [1.0, 0.0, 0.0],
+//! ]));
+//! assert_eq!(mixer.adjust(color), LinSrgb::new(0.1, 0.3, 0.2));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    cast::ArrayCast,
+    chromatic_adaptation::adaptation_matrix,
+    convert::{ConvertOnce, IntoColorUnclamped, Matrix3},
+    encoding::{Linear, Srgb},
+    lms::{
+        matrix::{Bradford, LmsToXyz, WithLmsMatrix, XyzToLms},
+        Lms,
+    },
+    num::{Arithmetics, FromScalar, IsValidDivisor, One, Powf, Real, Recip, Sqrt, Zero},
+    temperature::planckian_locus_uv,
+    white_point::{Any, WhitePoint, D65},
+    LinSrgb, Mat3, Xyz, Yxy,
+};
+
+/// An adjustment of linear sRGB colors.
+///
+/// The adjustments in this module are linear, and provide their matrix via
+/// [`linear_matrix`][Adjust::linear_matrix]. Other adjustments can implement
+/// this trait to be part of a [`Pipeline`].
+pub trait Adjust<C> {
+    /// Apply the adjustment to a single color.
+    #[must_use]
+    fn adjust(&self, color: C) -> C;
+
+    /// Apply the adjustment to all colors in `colors`.
+    ///
+    /// This is the same as calling [`adjust`][Adjust::adjust] for each color.
+    fn adjust_slice(&self, colors: &mut [C])
+    where
+        C: Clone,
+    {
+        for color in colors {
+            *color = self.adjust(color.clone());
+        }
+    }
+
+    /// Get a matrix that gives the same result as the adjustment, if the
+    /// adjustment is linear.
+    ///
+    /// The default implementation returns `None`, which means the adjustment
+    /// is always applied as it is.
+    fn linear_matrix(&self) -> Option<Matrix3<C, C>>
+    where
+        C: ArrayCast,
+    {
+        None
+    }
+}
+
+/// Change the exposure by a number of stops.
+///
+/// Each stop doubles or halves the linear values, so `Exposure(1.0)` makes
+/// the color twice as bright and `Exposure(-1.0)` makes it half as bright.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Exposure(pub f32);
+
+impl Exposure {
+    /// Get the factor the linear values are multiplied by.
+    #[must_use]
+    pub fn factor<T>(&self) -> T
+    where
+        T: Real + Powf,
+    {
+        T::from_f64(2.0).powf(T::from_f64(self.0.into()))
+    }
+}
+
+impl<T> Adjust<LinSrgb<T>> for Exposure
+where
+    T: Real + Powf + Zero + Arithmetics + Clone,
+{
+    #[inline]
+    fn adjust(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        color * self.factor::<T>()
+    }
+
+    #[inline]
+    fn adjust_slice(&self, colors: &mut [LinSrgb<T>]) {
+        let factor = self.factor::<T>();
+        for color in colors {
+            *color = color.clone() * factor.clone();
+        }
+    }
+
+    #[inline]
+    fn linear_matrix(&self) -> Option<Matrix3<LinSrgb<T>, LinSrgb<T>>> {
+        let factor = self.factor::<T>();
+        Some(Matrix3::scale(factor.clone(), factor.clone(), factor))
+    }
+}
+
+/// Correct the white balance for a light source with a given color
+/// temperature and tint.
+///
+/// Colors are adapted from the white point of the light source to D65, using
+/// the Bradford chromatic adaptation transform. An image that was lit by the
+/// light source will look neutral after the adjustment, so a low temperature
+/// makes the colors cooler, and a high temperature makes them warmer.
+///
+/// The white point of the light source is the point on the Planckian locus at
+/// `temp_kelvin`, moved `tint` along the normal of the locus in the CIE 1960
+/// uv diagram. This is the same as the Duv in the
+/// [`temperature`][crate::temperature] module, where positive values are
+/// towards green and negative values are towards magenta.
+///
+/// ```
+/// use palette::{adjust::{Adjust, WhiteBalance}, LinSrgb};
+///
+/// let daylight = WhiteBalance {
+///     temp_kelvin: 6504.0,
+///     tint: 0.0032,
+/// };
+/// let color = daylight.adjust(LinSrgb::new(0.8f32, 0.5, 0.3));
+///
+/// // D65 is very close to 6504 K, so the color barely changes.
+/// assert!((color.red - 0.8).abs() < 0.001);
+/// assert!((color.green - 0.5).abs() < 0.001);
+/// assert!((color.blue - 0.3).abs() < 0.001);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct WhiteBalance {
+    /// The color temperature of the light source, in Kelvin. It's clamped to
+    /// `1000.0..=15000.0`.
+    pub temp_kelvin: f32,
+
+    /// The distance of the light source from the Planckian locus.
+    pub tint: f32,
+}
+
+impl WhiteBalance {
+    /// Correct the white balance for a light source with the color temperature
+    /// `temp_kelvin`, on the Planckian locus.
+    pub const fn new(temp_kelvin: f32) -> Self {
+        WhiteBalance {
+            temp_kelvin,
+            tint: 0.0,
+        }
+    }
+
+    /// Get the matrix that corrects the white balance of linear sRGB colors.
+    #[must_use]
+    pub fn matrix<T>(&self) -> Matrix3<LinSrgb<T>, LinSrgb<T>>
+    where
+        T: Real
+            + Sqrt
+            + Zero
+            + One
+            + Recip
+            + IsValidDivisor<Mask = bool>
+            + Arithmetics
+            + FromScalar<Scalar = T>
+            + Clone,
+        D65: WhitePoint<T>,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+        Xyz<D65, T>: IntoColorUnclamped<Lms<WithLmsMatrix<D65, Bradford>, T>>,
+        Bradford: XyzToLms<T> + LmsToXyz<T>,
+    {
+        let rgb_to_xyz = Xyz::matrix_from_rgb::<Linear<Srgb>>();
+        let xyz_to_rgb = LinSrgb::matrix_from_xyz();
+
+        // The light source white point is only labeled as D65, since the
+        // adaptation matrix takes its actual value as a parameter.
+        let white_point = light_source_white_point(self.temp_kelvin, self.tint);
+        let adaptation = adaptation_matrix::<T, D65, D65, Bradford>(Some(white_point), None);
+
+        rgb_to_xyz.then(adaptation).then(xyz_to_rgb)
+    }
+}
+
+impl<T> Adjust<LinSrgb<T>> for WhiteBalance
+where
+    T: Real
+        + Sqrt
+        + Zero
+        + One
+        + Recip
+        + IsValidDivisor<Mask = bool>
+        + Arithmetics
+        + FromScalar<Scalar = T>
+        + Clone,
+    D65: WhitePoint<T>,
+    Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    Xyz<D65, T>: IntoColorUnclamped<Lms<WithLmsMatrix<D65, Bradford>, T>>,
+    Bradford: XyzToLms<T> + LmsToXyz<T>,
+{
+    #[inline]
+    fn adjust(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        self.matrix().convert_once(color)
+    }
+
+    #[inline]
+    fn adjust_slice(&self, colors: &mut [LinSrgb<T>]) {
+        let matrix = self.matrix();
+        for color in colors {
+            *color = matrix.clone().convert_once(color.clone());
+        }
+    }
+
+    #[inline]
+    fn linear_matrix(&self) -> Option<Matrix3<LinSrgb<T>, LinSrgb<T>>> {
+        Some(self.matrix())
+    }
+}
+
+/// Calculate the XYZ white point, with `Y = 1`, of a light source with a
+/// color temperature and a Duv tint.
+fn light_source_white_point<T>(temp_kelvin: f32, tint: f32) -> Xyz<D65, T>
+where
+    T: Real + Sqrt + Arithmetics + Clone,
+{
+    let temperature = f64::from(temp_kelvin).clamp(1000.0, 15000.0);
+    let ([u, v], [delta_u, delta_v]) = planckian_locus_uv(temperature);
+    let [u, v, delta_u, delta_v] = [u, v, delta_u, delta_v].map(T::from_f64);
+
+    // The normal of the locus points towards green when the temperature
+    // increases, since u decreases with the temperature.
+    let length = (delta_u.clone() * &delta_u + delta_v.clone() * &delta_v).sqrt();
+
+    let tint = T::from_f64(tint.into()) / length;
+    let u = u + delta_v * &tint;
+    let v = v - delta_u * tint;
+
+    // Convert from CIE 1960 uv to XYZ, with Y = 1.
+    let two_v = T::from_f64(2.0) * &v;
+    Xyz::new(
+        T::from_f64(3.0) * &u / &two_v,
+        T::from_f64(1.0),
+        (T::from_f64(4.0) - u - T::from_f64(10.0) * v) / two_v,
+    )
+}
+
+/// Mix the red, green and blue channels with a 3x3 matrix.
+///
+/// The matrix is in row-major order, where each row produces one of the output
+/// channels from the input channels. The identity matrix leaves the color
+/// unchanged.
+///
+/// ```
+/// use palette::{adjust::{Adjust, ChannelMixer}, matrix::Mat3, LinSrgb};
+///
+/// // A black and white mix with extra weight on the red channel.
+/// let mixer = ChannelMixer(Mat3::from_rows([
+///     [0.5, 0.25, 0.25],
+///     [0.5, 0.25, 0.25],
+///     [0.5, 0.25, 0.25],
+/// ]));
+///
+/// let gray = mixer.adjust(LinSrgb::new(0.8f32, 0.4, 0.0));
+/// assert_eq!(gray, LinSrgb::new(0.5, 0.5, 0.5));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ChannelMixer<T>(pub Mat3<T>);
+
+impl<T> Adjust<LinSrgb<T>> for ChannelMixer<T>
+where
+    T: Arithmetics + Clone,
+{
+    #[inline]
+    fn adjust(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        let [red, green, blue] = self
+            .0
+            .clone()
+            .mul_vector([color.red, color.green, color.blue]);
+        LinSrgb::new(red, green, blue)
+    }
+
+    #[inline]
+    fn linear_matrix(&self) -> Option<Matrix3<LinSrgb<T>, LinSrgb<T>>> {
+        Some(Matrix3::from_array(self.0.clone().into_array()))
+    }
+}
+
+/// A sequence of adjustments that are applied in order.
+///
+/// Consecutive linear adjustments, where
+/// [`linear_matrix`][Adjust::linear_matrix] returns a matrix, are folded into
+/// a single matrix when they are added. Applying the pipeline is then only a
+/// single matrix multiplication per color, unless it contains non-linear
+/// adjustments.
+///
+/// ```
+/// use palette::{
+///     adjust::{Adjust, Exposure, Pipeline, WhiteBalance},
+///     LinSrgb,
+/// };
+///
+/// let pipeline = Pipeline::new()
+///     .then(WhiteBalance::new(4500.0))
+///     .then(Exposure(0.5));
+///
+/// let mut image = [
+///     LinSrgb::new(0.8f32, 0.5, 0.3),
+///     LinSrgb::new(0.1, 0.2, 0.3),
+/// ];
+/// pipeline.adjust_slice(&mut image);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct Pipeline<T> {
+    stages: Vec<Stage<T>>,
+}
+
+#[cfg(feature = "alloc")]
+enum Stage<T> {
+    Linear(Matrix3<LinSrgb<T>, LinSrgb<T>>),
+    Other(Box<dyn Adjust<LinSrgb<T>>>),
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Pipeline<T> {
+    /// Create an empty pipeline, which leaves colors unchanged.
+    pub fn new() -> Self {
+        Pipeline { stages: Vec::new() }
+    }
+
+    /// Add an adjustment to the end of the pipeline.
+    ///
+    /// The adjustment is folded into the previous one if both are linear.
+    #[must_use]
+    pub fn then<A>(mut self, adjustment: A) -> Self
+    where
+        A: Adjust<LinSrgb<T>> + 'static,
+        T: Arithmetics + Clone,
+    {
+        let matrix = match adjustment.linear_matrix() {
+            Some(matrix) => matrix,
+            None => {
+                self.stages.push(Stage::Other(Box::new(adjustment)));
+                return self;
+            }
+        };
+
+        if let Some(Stage::Linear(previous)) = self.stages.last_mut() {
+            *previous = previous.clone().then(matrix);
+        } else {
+            self.stages.push(Stage::Linear(matrix));
+        }
+
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Default for Pipeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Adjust<LinSrgb<T>> for Pipeline<T>
+where
+    T: Zero + One + Arithmetics + Clone,
+{
+    #[inline]
+    fn adjust(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        self.stages.iter().fold(color, |color, stage| match stage {
+            Stage::Linear(matrix) => matrix.clone().convert_once(color),
+            Stage::Other(adjustment) => adjustment.adjust(color),
+        })
+    }
+
+    #[inline]
+    fn adjust_slice(&self, colors: &mut [LinSrgb<T>]) {
+        for stage in &self.stages {
+            match stage {
+                Stage::Linear(matrix) => {
+                    for color in &mut *colors {
+                        *color = matrix.clone().convert_once(color.clone());
+                    }
+                }
+                Stage::Other(adjustment) => adjustment.adjust_slice(colors),
+            }
+        }
+    }
+
+    #[inline]
+    fn linear_matrix(&self) -> Option<Matrix3<LinSrgb<T>, LinSrgb<T>>> {
+        match &*self.stages {
+            [] => Some(Matrix3::identity()),
+            [Stage::Linear(matrix)] => Some(matrix.clone()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+#[cfg(test)]
+mod test {
+    use super::{light_source_white_point, Adjust, ChannelMixer, Exposure, WhiteBalance};
+    use crate::{
+        convert::FromColorUnclamped, matrix::Mat3, temperature::cct_duv, white_point::D65, LinSrgb,
+        Xyz,
+    };
+
+    #[test]
+    fn exposure_doubles_per_stop() {
+        let color = LinSrgb::new(0.1f64, 0.25, 0.4);
+
+        assert_relative_eq!(Exposure(1.0).adjust(color), color * 2.0);
+        assert_relative_eq!(Exposure(2.0).adjust(color), color * 4.0);
+        assert_relative_eq!(Exposure(-1.0).adjust(color), color * 0.5);
+        assert_relative_eq!(Exposure(0.0).adjust(color), color);
+    }
+
+    #[test]
+    fn light_source_white_point_has_cct_and_duv() {
+        for &(temp_kelvin, tint) in &[(2700.0, 0.0), (5000.0, 0.01), (8000.0, -0.01)] {
+            let white_point: Xyz<D65, f64> = light_source_white_point(temp_kelvin, tint);
+            let result = cct_duv(white_point).unwrap();
+
+            assert!(
+                (result.cct - f64::from(temp_kelvin)).abs() < 5.0,
+                "{:?}",
+                result
+            );
+            assert!(
+                (result.duv - f64::from(tint)).abs() < 0.0002,
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn white_balance_neutralizes_light_source() {
+        let adjustment = WhiteBalance {
+            temp_kelvin: 3200.0,
+            tint: 0.005,
+        };
+        let white_point = light_source_white_point::<f64>(3200.0, 0.005);
+        let light = LinSrgb::from_color_unclamped(white_point) * 0.5;
+
+        let balanced = adjustment.adjust(light);
+        assert_relative_eq!(balanced, LinSrgb::new(0.5, 0.5, 0.5), epsilon = 0.000001);
+    }
+
+    #[test]
+    fn white_balance_direction() {
+        let gray = LinSrgb::new(0.5f64, 0.5, 0.5);
+
+        let warm_light = WhiteBalance::new(3000.0).adjust(gray);
+        assert!(warm_light.blue > warm_light.red);
+
+        let cool_light = WhiteBalance::new(10000.0).adjust(gray);
+        assert!(cool_light.red > cool_light.blue);
+    }
+
+    #[test]
+    fn adjust_slice_matches_adjust() {
+        let colors = [
+            LinSrgb::new(0.8f64, 0.5, 0.3),
+            LinSrgb::new(0.1, 0.2, 0.3),
+            LinSrgb::new(1.5, 0.0, 0.7),
+        ];
+
+        let exposure = Exposure(-0.7);
+        let mut adjusted = colors;
+        exposure.adjust_slice(&mut adjusted);
+        for (color, adjusted) in colors.iter().zip(adjusted.iter()) {
+            assert_eq!(exposure.adjust(*color), *adjusted);
+        }
+
+        let white_balance = WhiteBalance {
+            temp_kelvin: 5500.0,
+            tint: 0.002,
+        };
+        let mut adjusted = colors;
+        white_balance.adjust_slice(&mut adjusted);
+        for (color, adjusted) in colors.iter().zip(adjusted.iter()) {
+            assert_eq!(white_balance.adjust(*color), *adjusted);
+        }
+    }
+
+    #[test]
+    fn channel_mixer() {
+        let mixer = ChannelMixer(Mat3::from_rows([
+            [1.0, 0.5, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.25, 0.0, 0.5],
+        ]));
+
+        assert_relative_eq!(
+            mixer.adjust(LinSrgb::new(0.2f64, 0.4, 0.8)),
+            LinSrgb::new(0.4, 0.4, 0.45)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn pipeline_matches_sequential() {
+        use super::{Pipeline, Stage};
+
+        struct Square;
+
+        impl Adjust<LinSrgb<f64>> for Square {
+            fn adjust(&self, color: LinSrgb<f64>) -> LinSrgb<f64> {
+                color * color
+            }
+        }
+
+        let mixer = ChannelMixer(Mat3::from_rows([
+            [0.8, 0.1, 0.1],
+            [0.2, 0.7, 0.1],
+            [0.0, 0.3, 0.7],
+        ]));
+        let white_balance = WhiteBalance {
+            temp_kelvin: 4200.0,
+            tint: -0.003,
+        };
+
+        let folded = Pipeline::new()
+            .then(white_balance)
+            .then(Exposure(0.7))
+            .then(mixer);
+        assert_eq!(folded.stages.len(), 1);
+        assert!(folded.linear_matrix().is_some());
+
+        let mixed = Pipeline::new()
+            .then(Exposure(-0.5))
+            .then(mixer)
+            .then(Square)
+            .then(white_balance)
+            .then(Exposure(1.0));
+        assert_eq!(mixed.stages.len(), 3);
+        assert!(matches!(mixed.stages[1], Stage::Other(_)));
+        assert!(mixed.linear_matrix().is_none());
+
+        let colors = [
+            LinSrgb::new(0.8f64, 0.5, 0.3),
+            LinSrgb::new(0.1, 0.2, 0.3),
+            LinSrgb::new(1.5, 0.0, 0.7),
+        ];
+
+        for &color in &colors {
+            let sequential = mixer.adjust(Exposure(0.7).adjust(white_balance.adjust(color)));
+            assert_relative_eq!(folded.adjust(color), sequential, epsilon = 0.0000001);
+
+            let sequential = Exposure(1.0).adjust(
+                white_balance.adjust(Square.adjust(mixer.adjust(Exposure(-0.5).adjust(color)))),
+            );
+            assert_relative_eq!(mixed.adjust(color), sequential, epsilon = 0.0000001);
+        }
+
+        let mut adjusted = colors;
+        folded.adjust_slice(&mut adjusted);
+        for (color, adjusted) in colors.iter().zip(adjusted.iter()) {
+            assert_relative_eq!(folded.adjust(*color), *adjusted);
+        }
+
+        assert_eq!(Pipeline::new().adjust(colors[0]), colors[0]);
+    }
+}
@@ -73,6 +73,30 @@ mod alpha;
 ///
 /// assert_eq!(transparent.alpha, 10);
 /// ```
+///
+/// The alpha type is the same as the type of the alpha field by default. It can
+/// be changed with `#[palette(alpha = "SomeType")]`, which converts the alpha
+/// value to and from the field's type, using
+/// [`IntoStimulus`](crate::stimulus::IntoStimulus):
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::WithAlpha;
+///
+/// #[derive(WithAlpha)]
+/// struct Vertex(
+///     f32,
+///     f32,
+///     f32,
+///     #[palette(alpha = "f32")] u8,
+/// );
+///
+/// let color = Vertex(0.8, 0.5, 0.1, 255);
+/// let transparent = color.with_alpha(0.2);
+///
+/// assert_eq!(transparent.3, 51);
+/// assert_relative_eq!(transparent.split().1, 0.2);
+/// ```
 pub trait WithAlpha<A>: Sized {
     /// The opaque color type, without any transparency.
     ///
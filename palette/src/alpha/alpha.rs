@@ -1,6 +1,7 @@
 use core::{
     fmt,
-    iter::FromIterator,
+    hash::{Hash, Hasher},
+    iter::{FromIterator, FusedIterator},
     ops::{
         Add, AddAssign, BitAnd, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Sub, SubAssign,
     },
@@ -19,14 +20,20 @@ use rand::{
 
 use crate::{
     blend::{PreAlpha, Premultiply},
-    bool_mask::HasBoolMask,
+    bool_mask::{HasBoolMask, Selectable},
     cast::ArrayCast,
     clamp, clamp_assign,
+    color_difference::{Ciede2000, DeltaE, EuclideanDistance, HyAb, ImprovedDeltaE},
     convert::{FromColorUnclamped, IntoColorUnclamped},
-    num::{self, Arithmetics, One, PartialCmp, SaturatingAdd, SaturatingSub, Zero},
+    num::{
+        self, Arithmetics, IsValidDivisor, One, PartialCmp, Real, SaturatingAdd, SaturatingSub,
+        Sqrt, Zero,
+    },
     stimulus::Stimulus,
-    ArrayExt, Clamp, ClampAssign, GetHue, IsWithinBounds, Lighten, LightenAssign, Mix, MixAssign,
-    NextArray, Saturate, SaturateAssign, SetHue, ShiftHue, ShiftHueAssign, WithAlpha, WithHue,
+    ArrayExt, Blacken, BlackenAssign, Clamp, ClampAssign, ComponentWise, GetHue, IsAchromatic,
+    IsWithinBounds, Lighten, LightenAssign, Mix, MixAssign, NextArray, RelativeLuminance, Saturate,
+    SaturateAssign, SetHue, ShiftHue, ShiftHueAssign, TryGetHue, Whiten, WhitenAssign, WithAlpha,
+    WithHue,
 };
 
 /// An alpha component wrapper for colors, for adding transparency.
@@ -162,6 +169,63 @@ impl<C: Premultiply> Alpha<C, C::Scalar> {
     }
 }
 
+impl<C, T> Alpha<C, T>
+where
+    C: Premultiply<Scalar = T> + Mix<Scalar = T> + Selectable + Clone,
+    T: Real
+        + Zero
+        + One
+        + num::Clamp
+        + Arithmetics
+        + IsValidDivisor<Mask = C::Mask>
+        + Selectable
+        + Clone,
+    C::Mask: Clone,
+{
+    /// Mix the color with an other color, by `factor`, with the colors alpha
+    /// masked by their transparency.
+    ///
+    /// The regular [`Mix`] implementation interpolates the color and alpha
+    /// components separately. That lets the color of an almost transparent
+    /// endpoint bleed into the result, which causes halos around the edges of
+    /// transparent areas. This method premultiplies both colors, mixes them as
+    /// [`PreAlpha`] and unpremultiplies the result, so each color contributes
+    /// in proportion to its alpha. A fully transparent endpoint only changes
+    /// the alpha, and the result takes the color of the other endpoint.
+    ///
+    /// If the mixed alpha is `0`, there is no color to recover, so the colors
+    /// are mixed as with [`Mix`] instead.
+    ///
+    /// ```
+    /// use palette::{LinSrgba, Mix};
+    ///
+    /// let red = LinSrgba::new(1.0, 0.0, 0.0, 1.0);
+    /// let transparent_blue = LinSrgba::new(0.0, 0.0, 1.0, 0.0);
+    ///
+    /// // The blue color is invisible and doesn't affect the result.
+    /// assert_eq!(
+    ///     red.mix_premultiplied(transparent_blue, 0.5),
+    ///     LinSrgba::new(1.0, 0.0, 0.0, 0.5)
+    /// );
+    ///
+    /// // Plain mixing turns it purple.
+    /// assert_eq!(red.mix(transparent_blue, 0.5), LinSrgba::new(0.5, 0.0, 0.5, 0.5));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mix_premultiplied(self, other: Self, factor: T) -> Self {
+        let straight = Mix::mix(self.clone(), other.clone(), factor.clone());
+        let premultiplied = Mix::mix(
+            PreAlpha::new(self.color, self.alpha),
+            PreAlpha::new(other.color, other.alpha),
+            factor,
+        );
+        let is_visible = premultiplied.alpha.is_valid_divisor();
+
+        Self::select(is_visible, premultiplied.unpremultiply(), straight)
+    }
+}
+
 impl<C, T: Stimulus> Alpha<C, T> {
     /// Return the `alpha` value minimum.
     pub fn min_alpha() -> T {
@@ -191,6 +255,17 @@ where
 {
 }
 
+impl<C, T> Hash for Alpha<C, T>
+where
+    T: Hash,
+    C: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.color.hash(state);
+        self.alpha.hash(state);
+    }
+}
+
 impl<C1: WithAlpha<T>, C2, T> FromColorUnclamped<C1> for Alpha<C2, T>
 where
     C1::Color: IntoColorUnclamped<C2>,
@@ -271,7 +346,7 @@ where
     }
 }
 
-impl<C: Lighten> Lighten for Alpha<C, C::Scalar> {
+impl<C: Lighten, T> Lighten for Alpha<C, T> {
     type Scalar = C::Scalar;
 
     #[inline]
@@ -291,7 +366,7 @@ impl<C: Lighten> Lighten for Alpha<C, C::Scalar> {
     }
 }
 
-impl<C: LightenAssign> LightenAssign for Alpha<C, C::Scalar> {
+impl<C: LightenAssign, T> LightenAssign for Alpha<C, T> {
     type Scalar = C::Scalar;
 
     #[inline]
@@ -305,6 +380,74 @@ impl<C: LightenAssign> LightenAssign for Alpha<C, C::Scalar> {
     }
 }
 
+impl<C: Whiten, T> Whiten for Alpha<C, T> {
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn whiten(self, factor: C::Scalar) -> Self {
+        Alpha {
+            color: self.color.whiten(factor),
+            alpha: self.alpha,
+        }
+    }
+
+    #[inline]
+    fn whiten_fixed(self, amount: C::Scalar) -> Self {
+        Alpha {
+            color: self.color.whiten_fixed(amount),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<C: WhitenAssign, T> WhitenAssign for Alpha<C, T> {
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn whiten_assign(&mut self, factor: C::Scalar) {
+        self.color.whiten_assign(factor);
+    }
+
+    #[inline]
+    fn whiten_fixed_assign(&mut self, amount: C::Scalar) {
+        self.color.whiten_fixed_assign(amount);
+    }
+}
+
+impl<C: Blacken, T> Blacken for Alpha<C, T> {
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn blacken(self, factor: C::Scalar) -> Self {
+        Alpha {
+            color: self.color.blacken(factor),
+            alpha: self.alpha,
+        }
+    }
+
+    #[inline]
+    fn blacken_fixed(self, amount: C::Scalar) -> Self {
+        Alpha {
+            color: self.color.blacken_fixed(amount),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<C: BlackenAssign, T> BlackenAssign for Alpha<C, T> {
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn blacken_assign(&mut self, factor: C::Scalar) {
+        self.color.blacken_assign(factor);
+    }
+
+    #[inline]
+    fn blacken_fixed_assign(&mut self, amount: C::Scalar) {
+        self.color.blacken_fixed_assign(amount);
+    }
+}
+
 impl<C: GetHue, T> GetHue for Alpha<C, T> {
     type Hue = C::Hue;
 
@@ -314,6 +457,71 @@ impl<C: GetHue, T> GetHue for Alpha<C, T> {
     }
 }
 
+impl<C, T> TryGetHue for Alpha<C, T>
+where
+    C: TryGetHue,
+    T: HasBoolMask<Mask = C::Mask>,
+{
+    #[inline]
+    fn get_hue_masked(&self) -> (C::Hue, C::Mask) {
+        self.color.get_hue_masked()
+    }
+}
+
+impl<C, T> IsAchromatic for Alpha<C, T>
+where
+    C: IsAchromatic,
+    T: HasBoolMask<Mask = C::Mask>,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn is_achromatic_within(&self, tolerance: C::Scalar) -> C::Mask {
+        self.color.is_achromatic_within(tolerance)
+    }
+}
+
+impl<C> ComponentWise for Alpha<C, C::Scalar>
+where
+    C: ComponentWise,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn component_wise<F>(self, other: Self, mut f: F) -> Self
+    where
+        F: FnMut(C::Scalar, C::Scalar) -> C::Scalar,
+    {
+        Alpha {
+            color: self.color.component_wise(other.color, &mut f),
+            alpha: f(self.alpha, other.alpha),
+        }
+    }
+
+    #[inline]
+    fn map_components<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(C::Scalar) -> C::Scalar,
+    {
+        Alpha {
+            color: self.color.map_components(&mut f),
+            alpha: f(self.alpha),
+        }
+    }
+}
+
+impl<C, T> RelativeLuminance for Alpha<C, T>
+where
+    C: RelativeLuminance,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn get_relative_luminance(&self) -> C::Scalar {
+        self.color.get_relative_luminance()
+    }
+}
+
 impl<C, T, H> WithHue<H> for Alpha<C, T>
 where
     C: WithHue<H>,
@@ -360,7 +568,7 @@ where
     }
 }
 
-impl<C: Saturate> Saturate for Alpha<C, C::Scalar> {
+impl<C: Saturate, T> Saturate for Alpha<C, T> {
     type Scalar = C::Scalar;
 
     #[inline]
@@ -380,7 +588,7 @@ impl<C: Saturate> Saturate for Alpha<C, C::Scalar> {
     }
 }
 
-impl<C: SaturateAssign> SaturateAssign for Alpha<C, C::Scalar> {
+impl<C: SaturateAssign, T> SaturateAssign for Alpha<C, T> {
     type Scalar = C::Scalar;
 
     #[inline]
@@ -394,6 +602,82 @@ impl<C: SaturateAssign> SaturateAssign for Alpha<C, C::Scalar> {
     }
 }
 
+/// Calculates the distance between the colors. The alpha component is not part
+/// of the metric and is ignored.
+impl<C, T> EuclideanDistance for Alpha<C, T>
+where
+    C: EuclideanDistance,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn distance(self, other: Self) -> Self::Scalar
+    where
+        Self::Scalar: Sqrt,
+    {
+        self.color.distance(other.color)
+    }
+
+    #[inline]
+    fn distance_squared(self, other: Self) -> Self::Scalar {
+        self.color.distance_squared(other.color)
+    }
+}
+
+/// Calculates the difference between the colors. The alpha component is not
+/// part of the metric and is ignored.
+impl<C, T> Ciede2000 for Alpha<C, T>
+where
+    C: Ciede2000,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn difference(self, other: Self) -> Self::Scalar {
+        self.color.difference(other.color)
+    }
+}
+
+/// Calculates the distance between the colors. The alpha component is not part
+/// of the metric and is ignored.
+impl<C, T> HyAb for Alpha<C, T>
+where
+    C: HyAb,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn hybrid_distance(self, other: Self) -> Self::Scalar {
+        self.color.hybrid_distance(other.color)
+    }
+}
+
+/// Calculates the difference between the colors. The alpha component is not
+/// part of the metric and is ignored.
+impl<C, T> DeltaE for Alpha<C, T>
+where
+    C: DeltaE,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn delta_e(self, other: Self) -> Self::Scalar {
+        self.color.delta_e(other.color)
+    }
+}
+
+/// Calculates the difference between the colors. The alpha component is not
+/// part of the metric and is ignored.
+impl<C, T> ImprovedDeltaE for Alpha<C, T>
+where
+    C: ImprovedDeltaE,
+{
+    #[inline]
+    fn improved_delta_e(self, other: Self) -> Self::Scalar {
+        self.color.improved_delta_e(other.color)
+    }
+}
+
 impl<C, T> IsWithinBounds for Alpha<C, T>
 where
     C: IsWithinBounds,
@@ -450,6 +734,21 @@ where
     type Mask = C::Mask;
 }
 
+impl<C, T> Selectable for Alpha<C, T>
+where
+    C: Selectable,
+    C::Mask: Clone,
+    T: Selectable<Mask = C::Mask>,
+{
+    #[inline]
+    fn select(mask: C::Mask, a: Self, b: Self) -> Self {
+        Alpha {
+            color: C::select(mask.clone(), a.color, b.color),
+            alpha: T::select(mask, a.alpha, b.alpha),
+        }
+    }
+}
+
 impl<C: Default, T: Stimulus> Default for Alpha<C, T> {
     fn default() -> Alpha<C, T> {
         Alpha {
@@ -865,11 +1164,39 @@ where
 }
 
 /// An iterator for transparent colors.
+///
+/// The color and alpha iterators are zipped together, and iteration stops when
+/// either of them runs out of items. When both know their exact length,
+/// iterating from the back skips the excess items of the longer iterator first,
+/// so both ends see the same sequence of colors, even if the color and alpha
+/// buffers have different lengths.
 pub struct Iter<C, A> {
     pub(crate) color: C,
     pub(crate) alpha: A,
 }
 
+impl<C, A> Iter<C, A> {
+    /// Create an iterator from separate color and alpha iterators.
+    ///
+    /// ```
+    /// use palette::{alpha::Iter, Srgb, Srgba};
+    ///
+    /// let colors = [Srgb::new(1u8, 2, 3), Srgb::new(4, 5, 6)];
+    /// let alphas = [10u8, 20, 30];
+    ///
+    /// // The excess alpha value is ignored.
+    /// let mut iter = Iter::new(colors.iter().copied(), alphas.iter().copied());
+    /// assert_eq!(iter.len(), 2);
+    /// assert_eq!(iter.next_back(), Some(Srgba::new(4, 5, 6, 20)));
+    /// assert_eq!(iter.next_back(), Some(Srgba::new(1, 2, 3, 10)));
+    /// assert_eq!(iter.next_back(), None);
+    /// ```
+    #[inline]
+    pub fn new(color: C, alpha: A) -> Self {
+        Iter { color, alpha }
+    }
+}
+
 impl<C, A> Iterator for Iter<C, A>
 where
     C: Iterator,
@@ -889,25 +1216,11 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.color.size_hint();
-        debug_assert_eq!(
-            self.alpha.size_hint(),
-            hint,
-            "the color and alpha iterators have different size hints"
-        );
-
-        hint
+        crate::zip_size_hints(self.color.size_hint(), self.alpha.size_hint())
     }
 
     fn count(self) -> usize {
-        let count = self.color.count();
-        debug_assert_eq!(
-            self.alpha.count(),
-            count,
-            "the color and alpha iterators have different counts"
-        );
-
-        count
+        self.color.count().min(self.alpha.count())
     }
 }
 
@@ -917,6 +1230,11 @@ where
     A: DoubleEndedIterator,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(len) = crate::exact_len(self.size_hint()) {
+            crate::trim_back(&mut self.color, len);
+            crate::trim_back(&mut self.alpha, len);
+        }
+
         let color = self.color.next_back();
         let alpha = self.alpha.next_back();
 
@@ -934,17 +1252,17 @@ where
     A: ExactSizeIterator,
 {
     fn len(&self) -> usize {
-        let len = self.color.len();
-        debug_assert_eq!(
-            self.alpha.len(),
-            len,
-            "the color and alpha iterators have different lengths"
-        );
-
-        len
+        self.color.len().min(self.alpha.len())
     }
 }
 
+impl<C, A> FusedIterator for Iter<C, A>
+where
+    C: FusedIterator,
+    A: FusedIterator,
+{
+}
+
 #[cfg(feature = "serializing")]
 impl<C, T> serde::Serialize for Alpha<C, T>
 where
@@ -1089,6 +1407,100 @@ where
 mod test {
     use crate::encoding::Srgb;
     use crate::rgb::Rgba;
+    use crate::WithAlpha;
+
+    #[derive(Debug, PartialEq, WithAlpha)]
+    #[palette(palette_internal)]
+    struct MixedAlpha {
+        red: f32,
+        green: f32,
+        blue: f32,
+        #[palette(alpha)]
+        alpha: u8,
+    }
+
+    #[derive(Debug, PartialEq, WithAlpha)]
+    #[palette(palette_internal)]
+    struct TupleAlpha(f32, f32, f32, #[palette(alpha)] u8);
+
+    #[derive(Debug, PartialEq, WithAlpha)]
+    #[palette(palette_internal)]
+    struct ConvertedAlpha(f32, f32, f32, #[palette(alpha = "f32")] u8);
+
+    #[test]
+    fn derived_mixed_alpha_type() {
+        let color = MixedAlpha {
+            red: 0.2,
+            green: 0.4,
+            blue: 0.6,
+            alpha: 100,
+        };
+
+        let transparent = color.with_alpha(10u8);
+        assert_eq!(transparent.alpha, 10);
+        assert_eq!(transparent.red, 0.2);
+
+        let (opaque, alpha) = transparent.split();
+        assert_eq!(alpha, 10);
+        assert_eq!(opaque.alpha, 255);
+        assert_eq!(opaque.without_alpha().alpha, 255);
+    }
+
+    #[test]
+    fn derived_tuple_alpha_index() {
+        let color = TupleAlpha(0.2, 0.4, 0.6, 100);
+
+        let transparent = color.with_alpha(10u8);
+        assert_eq!(transparent, TupleAlpha(0.2, 0.4, 0.6, 10));
+
+        let (opaque, alpha) = transparent.split();
+        assert_eq!(alpha, 10);
+        assert_eq!(opaque, TupleAlpha(0.2, 0.4, 0.6, 255));
+        assert_eq!(opaque.without_alpha(), TupleAlpha(0.2, 0.4, 0.6, 255));
+    }
+
+    #[test]
+    fn derived_converted_alpha_type() {
+        let color = ConvertedAlpha(0.2, 0.4, 0.6, 255);
+
+        let transparent = color.with_alpha(0.2f32);
+        assert_eq!(transparent, ConvertedAlpha(0.2, 0.4, 0.6, 51));
+
+        let (opaque, alpha) = transparent.split();
+        assert_relative_eq!(alpha, 0.2);
+        assert_eq!(opaque, ConvertedAlpha(0.2, 0.4, 0.6, 255));
+    }
+
+    #[test]
+    fn mix_premultiplied() {
+        use crate::LinSrgba;
+
+        let red = LinSrgba::new(1.0f32, 0.0, 0.0, 1.0);
+        let transparent_blue = LinSrgba::new(0.0, 0.0, 1.0, 0.0);
+        let half_blue = LinSrgba::new(0.0, 0.0, 1.0, 0.5);
+
+        assert_relative_eq!(
+            red.mix_premultiplied(transparent_blue, 0.5),
+            LinSrgba::new(1.0, 0.0, 0.0, 0.5)
+        );
+        assert_relative_eq!(
+            transparent_blue.mix_premultiplied(red, 0.25),
+            LinSrgba::new(1.0, 0.0, 0.0, 0.25)
+        );
+
+        // The more opaque color has more weight.
+        assert_relative_eq!(
+            red.mix_premultiplied(half_blue, 0.5),
+            LinSrgba::new(2.0 / 3.0, 0.0, 1.0 / 3.0, 0.75)
+        );
+
+        // Nothing is visible, so fall back to a regular mix.
+        let transparent_red = LinSrgba::new(1.0, 0.0, 0.0, 0.0);
+        assert_relative_eq!(
+            transparent_red.mix_premultiplied(transparent_blue, 0.5),
+            LinSrgba::new(0.5, 0.0, 0.5, 0.0)
+        );
+    }
 
     #[test]
     fn lower_hex() {
@@ -1192,6 +1604,80 @@ mod test {
         assert_eq!(Rgba::<Srgb>::max_alpha(), 1.0);
     }
 
+    #[test]
+    fn forwards_color_operations() {
+        use crate::{
+            Alpha, Darken, Desaturate, Hsv, Lighten, LightenAssign, Oklaba, SetHue, ShiftHue,
+            WithHue,
+        };
+
+        let color = Oklaba::new(0.5f32, 0.1, -0.05, 0.3);
+        assert_eq!(color.darken(0.2), color.color.darken(0.2).with_alpha(0.3));
+        assert_eq!(
+            color.desaturate_fixed(0.1),
+            color.color.desaturate_fixed(0.1).with_alpha(0.3)
+        );
+
+        // The alpha type doesn't have to match the color's scalar type.
+        let mut color = Alpha {
+            color: Hsv::new_srgb(120.0f32, 0.5, 0.4),
+            alpha: 128u8,
+        };
+        let lightened = color.color.lighten_fixed(0.2);
+        color.lighten_fixed_assign(0.2);
+        assert_eq!(color, lightened.with_alpha(128));
+
+        assert_eq!(
+            color.shift_hue(10.0),
+            color.color.shift_hue(10.0).with_alpha(128)
+        );
+        assert_eq!(
+            color.with_hue(200.0),
+            color.color.with_hue(200.0).with_alpha(128)
+        );
+        color.set_hue(200.0);
+        assert_eq!(color.color, lightened.with_hue(200.0));
+        assert_eq!(color.alpha, 128);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn iter_shorter_alpha() {
+        let colors =
+            Rgba::<Srgb, Vec<u8>>::new(vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9], vec![10, 20]);
+
+        let iter = colors.iter();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(colors.iter().count(), 2);
+
+        let forward: Vec<_> = colors.iter().map(|c| (*c.red, *c.alpha)).collect();
+        assert_eq!(forward, [(1, 10), (2, 20)]);
+
+        // Iterating from the back skips the color without an alpha value.
+        let backward: Vec<_> = colors.iter().rev().map(|c| (*c.red, *c.alpha)).collect();
+        assert_eq!(backward, [(2, 20), (1, 10)]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn iter_shorter_color() {
+        let mut colors =
+            Rgba::<Srgb, Vec<u8>>::new(vec![1, 2], vec![4, 5], vec![7, 8], vec![10, 20]);
+        colors.alpha.push(30);
+        colors.color.blue.push(9);
+
+        let mut iter = colors.iter();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back().map(|c| (*c.blue, *c.alpha)), Some((8, 20)));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next().map(|c| (*c.blue, *c.alpha)), Some((7, 10)));
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+        assert!(iter.next().is_none());
+    }
+
     #[cfg(feature = "serializing")]
     #[test]
     fn serialize() {
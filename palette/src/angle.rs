@@ -5,6 +5,8 @@ use crate::{
     num::{Real, Round},
 };
 
+#[cfg(feature = "portable-simd")]
+mod portable_simd;
 #[cfg(feature = "wide")]
 mod wide;
 
@@ -56,6 +58,19 @@ pub trait UnsignedAngle {
     fn normalize_unsigned_angle(self) -> Self;
 }
 
+/// Angle types that can be shifted around the circle.
+///
+/// Integer angles wrap around when they overflow, since they represent a full
+/// rotation using their whole range.
+pub trait ShiftAngle {
+    /// Shift `self` by `amount`.
+    #[must_use]
+    fn shift_angle(self, amount: Self) -> Self;
+
+    /// Shift `self` by `amount`, in place.
+    fn shift_angle_assign(&mut self, amount: Self);
+}
+
 /// Performs value-to-value conversion between angle types. See also [`IntoAngle`].
 pub trait FromAngle<T> {
     /// Performs a conversion from `angle`.
@@ -134,6 +149,18 @@ macro_rules! impl_angle_float {
                     self - (Round::floor(self / 360.0) * 360.0)
                 }
             }
+
+            impl ShiftAngle for $ty {
+                #[inline]
+                fn shift_angle(self, amount: Self) -> Self {
+                    self + amount
+                }
+
+                #[inline]
+                fn shift_angle_assign(&mut self, amount: Self) {
+                    *self += amount;
+                }
+            }
         )+
     };
 }
@@ -149,56 +176,91 @@ macro_rules! impl_from_angle_float {
     };
 }
 
-macro_rules! impl_from_angle_u8 {
-    ($($float_ty: ident),*) => {
+macro_rules! impl_from_angle_uint {
+    ($($uint_ty: ident: $steps: expr),+ ; $float_ty: ident) => {
         $(
-            impl FromAngle<u8> for $float_ty {
+            impl FromAngle<$uint_ty> for $float_ty {
                 #[inline]
-                fn from_angle(angle: u8) -> Self {
-                    (angle as $float_ty / 256.0) * Self::full_rotation()
+                fn from_angle(angle: $uint_ty) -> Self {
+                    (angle as $float_ty / $steps) * Self::full_rotation()
                 }
             }
 
-            impl FromAngle<$float_ty> for u8 {
+            impl FromAngle<$float_ty> for $uint_ty {
                 #[inline]
                 fn from_angle(angle: $float_ty) -> Self {
                     let normalized = angle.normalize_unsigned_angle() / $float_ty::full_rotation();
-                    let rounded = (normalized * 256.0).round();
+                    let rounded = (normalized * $steps).round();
 
-                    if rounded > 255.5 {
+                    if rounded > $steps - 0.5 {
                         0
                     } else {
-                        rounded as u8
+                        rounded as $uint_ty
                     }
                 }
             }
-        )*
+        )+
+    };
+}
+
+macro_rules! impl_angle_uint {
+    ($($ty: ident),+) => {
+        $(
+            impl HalfRotation for $ty {
+                #[inline]
+                fn half_rotation() -> Self {
+                    1 << ($ty::BITS - 1)
+                }
+            }
+
+            impl AngleEq for $ty {
+                #[inline]
+                fn angle_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+
+            impl UnsignedAngle for $ty {
+                #[inline]
+                fn normalize_unsigned_angle(self) -> Self {
+                    self
+                }
+            }
+
+            impl ShiftAngle for $ty {
+                #[inline]
+                fn shift_angle(self, amount: Self) -> Self {
+                    self.wrapping_add(amount)
+                }
+
+                #[inline]
+                fn shift_angle_assign(&mut self, amount: Self) {
+                    *self = self.wrapping_add(amount);
+                }
+            }
+        )+
     };
 }
 
 impl_angle_float!(f32, f64);
 impl_from_angle_float!(f32 to f64);
 impl_from_angle_float!(f64 to f32);
-impl_from_angle_u8!(f32, f64);
-
-impl HalfRotation for u8 {
-    #[inline]
-    fn half_rotation() -> Self {
-        128
-    }
-}
+impl_from_angle_uint!(u8: 256.0, u16: 65536.0; f32);
+impl_from_angle_uint!(u8: 256.0, u16: 65536.0; f64);
+impl_angle_uint!(u8, u16);
 
-impl AngleEq for u8 {
+impl FromAngle<u8> for u16 {
     #[inline]
-    fn angle_eq(&self, other: &Self) -> bool {
-        self == other
+    fn from_angle(angle: u8) -> Self {
+        u16::from(angle) << 8
     }
 }
 
-impl UnsignedAngle for u8 {
+impl FromAngle<u16> for u8 {
     #[inline]
-    fn normalize_unsigned_angle(self) -> Self {
-        self
+    fn from_angle(angle: u16) -> Self {
+        // Rounds to the nearest step and wraps 256 around to 0.
+        ((u32::from(angle) + 0x80) >> 8) as u8
     }
 }
 
@@ -219,4 +281,69 @@ mod test {
         let hue_u8 = hue_f32.into_format::<f32>();
         assert_eq!(hue_u8, RgbHue::new(180.0f32));
     }
+
+    #[test]
+    fn f32_to_u16() {
+        let hue_f32 = RgbHue::new(180.0f32);
+        let hue_u16 = hue_f32.into_format::<u16>();
+        assert_eq!(hue_u16, RgbHue::new(32768u16));
+
+        let hue_f32 = RgbHue::new(-90.0f32);
+        let hue_u16 = hue_f32.into_format::<u16>();
+        assert_eq!(hue_u16, RgbHue::new(49152u16));
+
+        let hue_f32 = RgbHue::new(359.999f32);
+        let hue_u16 = hue_f32.into_format::<u16>();
+        assert_eq!(hue_u16, RgbHue::new(0u16));
+    }
+
+    #[test]
+    fn u8_to_u16() {
+        for angle in 0..=u8::MAX {
+            let hue_u16 = RgbHue::new(angle).into_format::<u16>();
+            assert_eq!(hue_u16, RgbHue::new(u16::from(angle) << 8));
+            assert_eq!(hue_u16.into_format::<u8>(), RgbHue::new(angle));
+        }
+
+        assert_eq!(RgbHue::new(0x12_7fu16).into_format(), RgbHue::new(0x12u8));
+        assert_eq!(RgbHue::new(0x12_80u16).into_format(), RgbHue::new(0x13u8));
+        assert_eq!(RgbHue::new(0xff_80u16).into_format(), RgbHue::new(0u8));
+    }
+
+    #[test]
+    fn uint_precision() {
+        for angle in 0..=u8::MAX {
+            let hue_f32 = RgbHue::new(angle).into_format::<f32>();
+            assert_eq!(hue_f32.into_format::<u8>(), RgbHue::new(angle));
+        }
+
+        for angle in (0..=u16::MAX).step_by(7) {
+            let hue_f64 = RgbHue::new(angle).into_format::<f64>();
+            assert_eq!(hue_f64.into_format::<u16>(), RgbHue::new(angle));
+        }
+
+        // The error shouldn't be more than half of a step.
+        for tenths in 0..3600 {
+            let degrees = tenths as f64 / 10.0;
+
+            let hue_u8 = RgbHue::new(degrees).into_format::<u8>();
+            let error = RgbHue::new(degrees) - hue_u8.into_format::<f64>();
+            assert!(error.into_degrees().abs() <= 360.0 / 512.0);
+
+            let hue_u16 = RgbHue::new(degrees).into_format::<u16>();
+            let error = RgbHue::new(degrees) - hue_u16.into_format::<f64>();
+            assert!(error.into_degrees().abs() <= 360.0 / 131072.0);
+        }
+    }
+
+    #[test]
+    fn uint_shift_wraps() {
+        assert_eq!(RgbHue::new(250u8).shift(20), RgbHue::new(14u8));
+        assert_eq!(RgbHue::new(250u8).shift(6), RgbHue::new(0u8));
+        assert_eq!(RgbHue::new(65530u16).shift(10), RgbHue::new(4u16));
+
+        let mut hue = RgbHue::new(200u8);
+        hue.shift_assign(100);
+        assert_eq!(hue, RgbHue::new(44u8));
+    }
 }
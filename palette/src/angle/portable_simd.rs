@@ -0,0 +1,77 @@
+use core::simd::{cmp::SimdPartialEq, Simd};
+
+use super::*;
+
+macro_rules! impl_angle_portable_simd_float {
+    ($($scalar: ident),+) => {
+        $(
+            impl<const N: usize> HalfRotation for Simd<$scalar, N> {
+                #[inline]
+                fn half_rotation() -> Self {
+                    Simd::splat(180.0)
+                }
+            }
+
+            impl<const N: usize> FullRotation for Simd<$scalar, N> {
+                #[inline]
+                fn full_rotation() -> Self {
+                    Simd::splat(360.0)
+                }
+            }
+
+            impl<const N: usize> RealAngle for Simd<$scalar, N> {
+                #[inline]
+                fn degrees_to_radians(self) -> Self {
+                    core::simd::num::SimdFloat::to_radians(self)
+                }
+
+                #[inline]
+                fn radians_to_degrees(self) -> Self {
+                    core::simd::num::SimdFloat::to_degrees(self)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> AngleEq for Simd<$scalar, N> {
+                #[inline]
+                fn angle_eq(&self, other: &Self) -> Self::Mask {
+                    self.normalize_unsigned_angle()
+                        .simd_eq(other.normalize_unsigned_angle())
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> SignedAngle for Simd<$scalar, N> {
+                #[inline]
+                fn normalize_signed_angle(self) -> Self {
+                    let full_rotation = Simd::splat(360.0);
+                    self - Round::ceil(((self + Simd::splat(180.0)) / full_rotation) - Simd::splat(1.0))
+                        * full_rotation
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> UnsignedAngle for Simd<$scalar, N> {
+                #[inline]
+                fn normalize_unsigned_angle(self) -> Self {
+                    let full_rotation = Simd::splat(360.0);
+                    self - (Round::floor(self / full_rotation) * full_rotation)
+                }
+            }
+
+            impl<const N: usize> ShiftAngle for Simd<$scalar, N> {
+                #[inline]
+                fn shift_angle(self, amount: Self) -> Self {
+                    self + amount
+                }
+
+                #[inline]
+                fn shift_angle_assign(&mut self, amount: Self) {
+                    *self += amount;
+                }
+            }
+        )+
+    };
+}
+
+impl_angle_portable_simd_float!(f32, f64);
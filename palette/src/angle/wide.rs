@@ -51,6 +51,18 @@ macro_rules! impl_angle_wide_float {
                     self - (Round::floor(self / 360.0) * 360.0)
                 }
             }
+
+            impl ShiftAngle for $ty {
+                #[inline]
+                fn shift_angle(self, amount: Self) -> Self {
+                    self + amount
+                }
+
+                #[inline]
+                fn shift_angle_assign(&mut self, amount: Self) {
+                    *self += amount;
+                }
+            }
         )+
     };
 }
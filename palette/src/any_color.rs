@@ -0,0 +1,386 @@
+//! A color in any of the built-in color spaces, chosen at runtime.
+//!
+//! The color types in Palette have their color space as part of the type,
+//! which is great for correctness, but makes it hard to store a color when the
+//! space is only known at runtime. Plugin systems, configuration files and
+//! user interfaces may need that. [`AnyColor`] is an enum of the most common
+//! color spaces, which can be converted to and from each of them. The
+//! conversions between different variants go through [`Xyz`]:
+//!
+//! ```
+//! use palette::{any_color::AnyColor, FromColor, Hsl, Srgb};
+//!
+//! let from_plugin = AnyColor::Hsl(Hsl::new(120.0, 0.5, 0.5));
+//! let rgb = Srgb::from_color(from_plugin);
+//!
+//! let back = AnyColor::from(rgb);
+//! assert!(matches!(back, AnyColor::Srgb(_)));
+//! ```
+//!
+//! This module is only available with the `"any_color"` feature, to avoid the
+//! cost of the conversion code when it's not needed.
+//!
+//! ## Serializing
+//!
+//! With the `"serializing"` feature, `AnyColor` is serialized as the fields of
+//! its color, with an additional `"space"` field for the variant name:
+//!
+//! ```
+//! # #[cfg(feature = "serializing")] {
+//! use palette::{any_color::AnyColor, Oklch};
+//!
+//! let color: AnyColor = AnyColor::Oklch(Oklch::new(0.5, 0.1, 120.0));
+//! let json = serde_json::to_string(&color).unwrap();
+//! assert_eq!(json, r#"{"space":"Oklch","l":0.5,"chroma":0.1,"hue":120.0}"#);
+//! # }
+//! ```
+
+use crate::{
+    convert::FromColorUnclamped, encoding, white_point::D65, Clamp, Hsl, Hsv, Hwb, Lab, Lch,
+    LinSrgb, Luma, Luv, Mix, Oklab, Oklch, Srgb, Xyz, Yxy,
+};
+
+/// A color in one of the built-in color spaces, chosen at runtime.
+///
+/// See the [module documentation](self) for details and examples. The
+/// variants use the sRGB standard and the `D65` white point, where
+/// applicable.
+///
+/// ## Mixing
+///
+/// [`Mix`] converts both colors to [`Oklab`], mixes them there, and converts
+/// the result to the color space of `self`:
+///
+/// ```
+/// use palette::{any_color::AnyColor, Mix, Oklab, Srgb};
+///
+/// let red = AnyColor::Srgb(Srgb::new(1.0, 0.0, 0.0));
+/// let blue = AnyColor::Oklab(Oklab::new(0.45, -0.03, -0.31));
+///
+/// let purple = red.mix(blue, 0.5);
+/// assert!(matches!(purple, AnyColor::Srgb(_)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serializing", serde(tag = "space"))]
+#[non_exhaustive]
+pub enum AnyColor<T = f32> {
+    /// Non-linear sRGB.
+    Srgb(Srgb<T>),
+
+    /// Linear sRGB.
+    LinSrgb(LinSrgb<T>),
+
+    /// HSL, based on sRGB.
+    Hsl(Hsl<encoding::Srgb, T>),
+
+    /// HSV, based on sRGB.
+    Hsv(Hsv<encoding::Srgb, T>),
+
+    /// HWB, based on sRGB.
+    Hwb(Hwb<encoding::Srgb, T>),
+
+    /// CIE L\*a\*b\* (CIELAB).
+    Lab(Lab<D65, T>),
+
+    /// CIE L\*C\*h°, the polar version of CIE L\*a\*b\*.
+    Lch(Lch<D65, T>),
+
+    /// Oklab.
+    Oklab(Oklab<T>),
+
+    /// Oklch, the polar version of Oklab.
+    Oklch(Oklch<T>),
+
+    /// CIE L\*u\*v\* (CIELUV).
+    Luv(Luv<D65, T>),
+
+    /// CIE 1931 XYZ.
+    Xyz(Xyz<D65, T>),
+
+    /// CIE 1931 Yxy (xyY).
+    Yxy(Yxy<D65, T>),
+
+    /// Non-linear sRGB luminance.
+    Luma(Luma<encoding::Srgb, T>),
+}
+
+impl<T> AnyColor<T> {
+    /// Get the name of the color space, which is the same as the variant name.
+    ///
+    /// ```
+    /// use palette::{any_color::AnyColor, Oklab};
+    ///
+    /// let color: AnyColor = Oklab::new(0.5, 0.1, 0.0).into();
+    /// assert_eq!(color.space_name(), "Oklab");
+    /// ```
+    #[must_use]
+    pub fn space_name(&self) -> &'static str {
+        match self {
+            AnyColor::Srgb(_) => "Srgb",
+            AnyColor::LinSrgb(_) => "LinSrgb",
+            AnyColor::Hsl(_) => "Hsl",
+            AnyColor::Hsv(_) => "Hsv",
+            AnyColor::Hwb(_) => "Hwb",
+            AnyColor::Lab(_) => "Lab",
+            AnyColor::Lch(_) => "Lch",
+            AnyColor::Oklab(_) => "Oklab",
+            AnyColor::Oklch(_) => "Oklch",
+            AnyColor::Luv(_) => "Luv",
+            AnyColor::Xyz(_) => "Xyz",
+            AnyColor::Yxy(_) => "Yxy",
+            AnyColor::Luma(_) => "Luma",
+        }
+    }
+}
+
+macro_rules! impl_any_color {
+    ($($variant: ident: $ty: ty),+ $(,)?) => {
+        impl<T> FromColorUnclamped<AnyColor<T>> for AnyColor<T> {
+            #[inline]
+            fn from_color_unclamped(color: AnyColor<T>) -> Self {
+                color
+            }
+        }
+
+        impl<T> PartialEq for AnyColor<T>
+        where
+            $($ty: PartialEq,)+
+        {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    $((AnyColor::$variant(a), AnyColor::$variant(b)) => a == b,)+
+                    _ => false,
+                }
+            }
+        }
+
+        impl<T> Clamp for AnyColor<T>
+        where
+            $($ty: Clamp,)+
+        {
+            #[inline]
+            fn clamp(self) -> Self {
+                match self {
+                    $(AnyColor::$variant(color) => AnyColor::$variant(color.clamp()),)+
+                }
+            }
+        }
+
+        impl<T> Mix for AnyColor<T>
+        where
+            T: Clone,
+            Oklab<T>: FromColorUnclamped<AnyColor<T>> + Mix<Scalar = T>,
+            $($ty: FromColorUnclamped<Oklab<T>>,)+
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn mix(self, other: Self, factor: T) -> Self {
+                let mixed = Oklab::from_color_unclamped(self.clone())
+                    .mix(Oklab::from_color_unclamped(other), factor);
+
+                match self {
+                    $(AnyColor::$variant(_) => AnyColor::$variant(<$ty>::from_color_unclamped(mixed)),)+
+                }
+            }
+        }
+
+        impl_any_color!(@conversions [$($variant: $ty),+] $($variant: $ty),+);
+    };
+    (@conversions $all: tt $($variant: ident: $ty: ty),+) => {
+        $(impl_any_color!(@conversion $all $variant: $ty);)+
+    };
+    (@conversion [$($all_variant: ident: $all_ty: ty),+] $variant: ident: $ty: ty) => {
+        impl<T> FromColorUnclamped<AnyColor<T>> for $ty
+        where
+            Self: FromColorUnclamped<Xyz<D65, T>>,
+            $(Xyz<D65, T>: FromColorUnclamped<$all_ty>,)+
+        {
+            #[inline]
+            #[allow(unreachable_patterns)]
+            fn from_color_unclamped(color: AnyColor<T>) -> Self {
+                match color {
+                    AnyColor::$variant(color) => color,
+                    $(AnyColor::$all_variant(color) => {
+                        Self::from_color_unclamped(Xyz::<D65, T>::from_color_unclamped(color))
+                    })+
+                }
+            }
+        }
+
+        impl<T> FromColorUnclamped<$ty> for AnyColor<T> {
+            #[inline]
+            fn from_color_unclamped(color: $ty) -> Self {
+                AnyColor::$variant(color)
+            }
+        }
+
+        impl<T> From<$ty> for AnyColor<T> {
+            #[inline]
+            fn from(color: $ty) -> Self {
+                AnyColor::$variant(color)
+            }
+        }
+    };
+}
+
+impl_any_color!(
+    Srgb: Srgb<T>,
+    LinSrgb: LinSrgb<T>,
+    Hsl: Hsl<encoding::Srgb, T>,
+    Hsv: Hsv<encoding::Srgb, T>,
+    Hwb: Hwb<encoding::Srgb, T>,
+    Lab: Lab<D65, T>,
+    Lch: Lch<D65, T>,
+    Oklab: Oklab<T>,
+    Oklch: Oklch<T>,
+    Luv: Luv<D65, T>,
+    Xyz: Xyz<D65, T>,
+    Yxy: Yxy<D65, T>,
+    Luma: Luma<encoding::Srgb, T>,
+);
+
+#[cfg(test)]
+mod test {
+    use super::AnyColor;
+    use crate::{
+        convert::FromColorUnclamped, encoding, white_point::D65, Clamp, FromColor, Hsl, Hsv, Hwb,
+        Lab, Lch, LinSrgb, Luma, Luv, Mix, Oklab, Oklch, Srgb, Xyz, Yxy,
+    };
+
+    fn all_variants() -> [AnyColor<f64>; 13] {
+        let srgb = Srgb::new(0.8, 0.3, 0.5);
+
+        [
+            AnyColor::Srgb(srgb),
+            AnyColor::LinSrgb(LinSrgb::from_color(srgb)),
+            AnyColor::Hsl(Hsl::from_color(srgb)),
+            AnyColor::Hsv(Hsv::from_color(srgb)),
+            AnyColor::Hwb(Hwb::from_color(srgb)),
+            AnyColor::Lab(Lab::from_color(srgb)),
+            AnyColor::Lch(Lch::from_color(srgb)),
+            AnyColor::Oklab(Oklab::from_color(srgb)),
+            AnyColor::Oklch(Oklch::from_color(srgb)),
+            AnyColor::Luv(Luv::from_color(srgb)),
+            AnyColor::Xyz(Xyz::from_color(srgb)),
+            AnyColor::Yxy(Yxy::from_color(srgb)),
+            AnyColor::Luma(Luma::new(0.5)),
+        ]
+    }
+
+    #[test]
+    fn conversion_round_trip() {
+        macro_rules! check {
+            ($color: expr, $variant: ident: $ty: ty) => {
+                let concrete = <$ty>::from_color_unclamped($color);
+                let any = AnyColor::from_color_unclamped(concrete);
+                assert!(matches!(any, AnyColor::$variant(_)));
+                assert_eq!(any, AnyColor::$variant(concrete));
+
+                let srgb_before = Srgb::<f64>::from_color_unclamped($color);
+                let srgb_after = Srgb::<f64>::from_color_unclamped(any);
+                assert_relative_eq!(srgb_before, srgb_after, epsilon = 0.000001);
+            };
+        }
+
+        for color in all_variants() {
+            if let AnyColor::Luma(_) = color {
+                // Luma can't represent the other colors.
+                continue;
+            }
+
+            check!(color, Srgb: Srgb<f64>);
+            check!(color, LinSrgb: LinSrgb<f64>);
+            check!(color, Hsl: Hsl<encoding::Srgb, f64>);
+            check!(color, Hsv: Hsv<encoding::Srgb, f64>);
+            check!(color, Hwb: Hwb<encoding::Srgb, f64>);
+            check!(color, Lab: Lab<D65, f64>);
+            check!(color, Lch: Lch<D65, f64>);
+            check!(color, Oklab: Oklab<f64>);
+            check!(color, Oklch: Oklch<f64>);
+            check!(color, Luv: Luv<D65, f64>);
+            check!(color, Xyz: Xyz<D65, f64>);
+            check!(color, Yxy: Yxy<D65, f64>);
+        }
+
+        let gray = AnyColor::Luma(Luma::<encoding::Srgb, f64>::new(0.5));
+        let srgb = Srgb::<f64>::from_color_unclamped(gray);
+        assert_relative_eq!(srgb, Srgb::new(0.5, 0.5, 0.5), epsilon = 0.000001);
+        assert_relative_eq!(
+            Luma::<encoding::Srgb, f64>::from_color_unclamped(AnyColor::from(srgb)).luma,
+            0.5,
+            epsilon = 0.000001
+        );
+    }
+
+    #[test]
+    fn space_names() {
+        let names = all_variants().map(|color| color.space_name());
+        assert_eq!(
+            names,
+            [
+                "Srgb", "LinSrgb", "Hsl", "Hsv", "Hwb", "Lab", "Lch", "Oklab", "Oklch", "Luv",
+                "Xyz", "Yxy", "Luma"
+            ]
+        );
+    }
+
+    #[test]
+    fn mix_in_oklab() {
+        let red = Srgb::new(1.0, 0.0, 0.0);
+        let blue = Srgb::new(0.0, 0.0, 1.0);
+        let expected = Oklab::<f64>::from_color(red).mix(Oklab::from_color(blue), 0.25);
+
+        let mixed =
+            AnyColor::Hsv(Hsv::from_color(red)).mix(AnyColor::Lab(Lab::from_color(blue)), 0.25);
+        assert!(matches!(mixed, AnyColor::Hsv(_)));
+        // Oklab has a more direct conversion from sRGB than from XYZ, which
+        // gives small differences.
+        assert_relative_eq!(
+            Oklab::from_color_unclamped(mixed),
+            expected,
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn clamp() {
+        let clamped = AnyColor::Srgb(Srgb::new(1.5, -0.5, 0.5)).clamp();
+        assert_eq!(clamped, AnyColor::Srgb(Srgb::new(1.0, 0.0, 0.5)));
+
+        let clamped = AnyColor::Oklch(Oklch::new(-0.5, 0.1, 20.0)).clamp();
+        assert_eq!(clamped, AnyColor::Oklch(Oklch::new(0.0, 0.1, 20.0)));
+
+        let srgb: Srgb<f64> = Srgb::from_color(AnyColor::Lab(Lab::new(150.0, 0.0, 0.0)));
+        assert_eq!(srgb, Srgb::new(1.0, 1.0, 1.0));
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serde_round_trip() {
+        for color in all_variants() {
+            let serialized = serde_json::to_string(&color).unwrap();
+            assert!(
+                serialized.starts_with(&format!(r#"{{"space":"{}","#, color.space_name())),
+                "{}",
+                serialized
+            );
+
+            let deserialized: AnyColor<f64> = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(deserialized, color);
+        }
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize_tagged() {
+        let color: AnyColor = serde_json::from_str(
+            r#"{"space":"Hsl","hue":120.0,"saturation":0.5,"lightness":0.25}"#,
+        )
+        .unwrap();
+        assert_eq!(color, AnyColor::Hsl(Hsl::new(120.0, 0.5, 0.25)));
+
+        assert!(serde_json::from_str::<AnyColor>(r#"{"space":"Cmyk","c":1.0}"#).is_err());
+    }
+}
@@ -1,14 +1,20 @@
-use core::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{
+    Add, AddAssign, BitAnd, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Sub, SubAssign,
+};
 
 #[cfg(feature = "approx")]
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 use crate::{
+    bool_mask::{HasBoolMask, Selectable},
     cast::ArrayCast,
-    clamp,
-    num::{self, Arithmetics, One, Real, Zero},
+    clamp, clamp_assign,
+    luma::Luma,
+    num::{self, Arithmetics, One, PartialCmp, Real, Zero},
+    rgb::Rgb,
     stimulus::Stimulus,
-    Alpha, ArrayExt, Mix, MixAssign, NextArray,
+    Alpha, ArrayExt, Clamp, ClampAssign, IsWithinBounds, Lighten, LightenAssign, Mix, MixAssign,
+    NextArray, Saturate, SaturateAssign, SetHue, ShiftHue, ShiftHueAssign, WithHue,
 };
 
 use super::Premultiply;
@@ -35,6 +41,29 @@ use super::Premultiply;
 /// Note that converting to and from premultiplied alpha will cause the alpha
 /// component to be clamped to [0.0, 1.0], and fully transparent colors will
 /// become black.
+///
+/// ## Valid values
+///
+/// A premultiplied stimulus color, such as [`Rgb`] or [`Luma`], is valid when
+/// `0.0 <= alpha <= 1.0` and each color component is within `0.0..=alpha`.
+/// A component that is larger than `alpha` would be larger than `1.0` when
+/// unpremultiplied. Blending operations may produce values outside of this
+/// range, and [`IsWithinBounds`] and [`Clamp`] can be used for checking and
+/// restoring this invariant:
+///
+/// ```
+/// use palette::{blend::PreAlpha, Clamp, IsWithinBounds, LinSrgb};
+///
+/// let color = PreAlpha {
+///     color: LinSrgb::new(0.6f32, -0.1, 0.3),
+///     alpha: 0.5,
+/// };
+/// assert!(!color.is_within_bounds());
+///
+/// let clamped = color.clamp();
+/// assert!(clamped.is_within_bounds());
+/// assert_eq!(clamped.color, LinSrgb::new(0.5, 0.0, 0.3));
+/// ```
 #[derive(Clone, Copy, Debug)]
 #[repr(C)]
 pub struct PreAlpha<C: Premultiply> {
@@ -155,6 +184,138 @@ where
     }
 }
 
+/// Lightens the unpremultiplied color, since the lightness of most color spaces
+/// is relative to fully opaque colors. The alpha value stays the same.
+impl<C> Lighten for PreAlpha<C>
+where
+    C: Premultiply + Lighten<Scalar = <C as Premultiply>::Scalar>,
+{
+    type Scalar = <C as Premultiply>::Scalar;
+
+    #[inline]
+    fn lighten(self, factor: Self::Scalar) -> Self {
+        self.unpremultiply().lighten(factor).into()
+    }
+
+    #[inline]
+    fn lighten_fixed(self, amount: Self::Scalar) -> Self {
+        self.unpremultiply().lighten_fixed(amount).into()
+    }
+}
+
+/// Lightens the unpremultiplied color. See the [`Lighten`] implementation for
+/// details.
+impl<C> LightenAssign for PreAlpha<C>
+where
+    C: Premultiply,
+    Self: Lighten<Scalar = C::Scalar> + Clone,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn lighten_assign(&mut self, factor: C::Scalar) {
+        *self = self.clone().lighten(factor);
+    }
+
+    #[inline]
+    fn lighten_fixed_assign(&mut self, amount: C::Scalar) {
+        *self = self.clone().lighten_fixed(amount);
+    }
+}
+
+/// Saturates the unpremultiplied color, since the saturation of most color
+/// spaces is relative to fully opaque colors. The alpha value stays the same.
+impl<C> Saturate for PreAlpha<C>
+where
+    C: Premultiply + Saturate<Scalar = <C as Premultiply>::Scalar>,
+{
+    type Scalar = <C as Premultiply>::Scalar;
+
+    #[inline]
+    fn saturate(self, factor: Self::Scalar) -> Self {
+        self.unpremultiply().saturate(factor).into()
+    }
+
+    #[inline]
+    fn saturate_fixed(self, amount: Self::Scalar) -> Self {
+        self.unpremultiply().saturate_fixed(amount).into()
+    }
+}
+
+/// Saturates the unpremultiplied color. See the [`Saturate`] implementation
+/// for details.
+impl<C> SaturateAssign for PreAlpha<C>
+where
+    C: Premultiply,
+    Self: Saturate<Scalar = C::Scalar> + Clone,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn saturate_assign(&mut self, factor: C::Scalar) {
+        *self = self.clone().saturate(factor);
+    }
+
+    #[inline]
+    fn saturate_fixed_assign(&mut self, amount: C::Scalar) {
+        *self = self.clone().saturate_fixed(amount);
+    }
+}
+
+/// Shifts the hue of the unpremultiplied color, which may otherwise be
+/// affected by the premultiplication. The alpha value stays the same.
+impl<C> ShiftHue for PreAlpha<C>
+where
+    C: Premultiply + ShiftHue,
+{
+    type Scalar = <C as ShiftHue>::Scalar;
+
+    #[inline]
+    fn shift_hue(self, amount: Self::Scalar) -> Self {
+        self.unpremultiply().shift_hue(amount).into()
+    }
+}
+
+/// Shifts the hue of the unpremultiplied color. See the [`ShiftHue`]
+/// implementation for details.
+impl<C> ShiftHueAssign for PreAlpha<C>
+where
+    C: Premultiply,
+    Self: ShiftHue + Clone,
+{
+    type Scalar = <Self as ShiftHue>::Scalar;
+
+    #[inline]
+    fn shift_hue_assign(&mut self, amount: Self::Scalar) {
+        *self = self.clone().shift_hue(amount);
+    }
+}
+
+/// Changes the hue of the unpremultiplied color, which may otherwise be
+/// affected by the premultiplication. The alpha value stays the same.
+impl<C, H> WithHue<H> for PreAlpha<C>
+where
+    C: Premultiply + WithHue<H>,
+{
+    #[inline]
+    fn with_hue(self, hue: H) -> Self {
+        self.unpremultiply().with_hue(hue).into()
+    }
+}
+
+/// Changes the hue of the unpremultiplied color. See the [`WithHue`]
+/// implementation for details.
+impl<C, H> SetHue<H> for PreAlpha<C>
+where
+    C: Premultiply,
+    Self: WithHue<H> + Clone,
+{
+    #[inline]
+    fn set_hue(&mut self, hue: H) {
+        *self = self.clone().with_hue(hue);
+    }
+}
+
 unsafe impl<C, T> ArrayCast for PreAlpha<C>
 where
     C: ArrayCast + Premultiply<Scalar = T>,
@@ -163,6 +324,29 @@ where
     type Array = <C::Array as NextArray>::Next;
 }
 
+impl<C> HasBoolMask for PreAlpha<C>
+where
+    C: Premultiply + HasBoolMask,
+    C::Scalar: HasBoolMask<Mask = C::Mask>,
+{
+    type Mask = C::Mask;
+}
+
+impl<C> Selectable for PreAlpha<C>
+where
+    C: Premultiply + Selectable,
+    C::Mask: Clone,
+    C::Scalar: Selectable<Mask = C::Mask>,
+{
+    #[inline]
+    fn select(mask: C::Mask, a: Self, b: Self) -> Self {
+        PreAlpha {
+            color: C::select(mask.clone(), a.color, b.color),
+            alpha: C::Scalar::select(mask, a.alpha, b.alpha),
+        }
+    }
+}
+
 impl<C> Default for PreAlpha<C>
 where
     C: Default + Premultiply,
@@ -268,6 +452,63 @@ macro_rules! impl_binop {
     };
 }
 
+macro_rules! impl_stimulus_bounds {
+    ($ty: ident <$($ty_param: ident),*> {$($component: ident),+}) => {
+        /// Checks that `0.0 <= alpha <= 1.0` and that each color component is
+        /// within `0.0..=alpha`.
+        impl<$($ty_param,)* T> IsWithinBounds for PreAlpha<$ty<$($ty_param,)* T>>
+        where
+            $ty<$($ty_param,)* T>: Premultiply<Scalar = T>,
+            T: Stimulus + PartialCmp,
+            T::Mask: BitAnd<Output = T::Mask>,
+        {
+            #[inline]
+            fn is_within_bounds(&self) -> T::Mask {
+                self.alpha.gt_eq(&T::zero())
+                    & self.alpha.lt_eq(&T::max_intensity())
+                    $(
+                        & self.color.$component.gt_eq(&T::zero())
+                        & self.color.$component.lt_eq(&self.alpha)
+                    )+
+            }
+        }
+
+        /// Clamps `alpha` to `0.0..=1.0` and each color component to
+        /// `0.0..=alpha`.
+        impl<$($ty_param,)* T> Clamp for PreAlpha<$ty<$($ty_param,)* T>>
+        where
+            $ty<$($ty_param,)* T>: Premultiply<Scalar = T>,
+            T: Stimulus + num::Clamp + Clone,
+        {
+            #[inline]
+            fn clamp(self) -> Self {
+                let PreAlpha { mut color, alpha } = self;
+                let alpha = clamp(alpha, T::zero(), T::max_intensity());
+                $(color.$component = clamp(color.$component, T::zero(), alpha.clone());)+
+
+                PreAlpha { color, alpha }
+            }
+        }
+
+        /// Clamps `alpha` to `0.0..=1.0` and each color component to
+        /// `0.0..=alpha`.
+        impl<$($ty_param,)* T> ClampAssign for PreAlpha<$ty<$($ty_param,)* T>>
+        where
+            $ty<$($ty_param,)* T>: Premultiply<Scalar = T>,
+            T: Stimulus + num::ClampAssign + Clone,
+        {
+            #[inline]
+            fn clamp_assign(&mut self) {
+                clamp_assign(&mut self.alpha, T::zero(), T::max_intensity());
+                $(clamp_assign(&mut self.color.$component, T::zero(), self.alpha.clone());)+
+            }
+        }
+    };
+}
+
+impl_stimulus_bounds!(Rgb<S> {red, green, blue});
+impl_stimulus_bounds!(Luma<S> {luma});
+
 impl_binop!(Add::add, AddAssign::add_assign);
 impl_binop!(Sub::sub, SubAssign::sub_assign);
 impl_binop!(Mul::mul, MulAssign::mul_assign);
@@ -294,22 +535,9 @@ macro_rules! impl_scalar_binop {
                 }
             }
 
-            // // Disabled as work-around for https://github.com/Ogeon/palette/issues/283
-            // // Blocked by https://github.com/rust-lang/rust/issues/80542
-            // impl<C> $op_trait<PreAlpha<C>> for $ty
-            // where
-            //     C: Premultiply<Scalar = $ty>,
-            //     $ty: $op_trait<$ty, Output = $ty> + $op_trait<C, Output = C>,
-            // {
-            //     type Output = PreAlpha<C>;
-            //
-            //     fn $op_trait_fn(self, color: PreAlpha<C>) -> Self::Output {
-            //         PreAlpha {
-            //             color: $op_trait::<C>::$op_trait_fn(self, color.color),
-            //             alpha: $op_trait::<$ty>::$op_trait_fn(self, color.alpha),
-            //         }
-            //     }
-            // }
+            // The reverse, with the scalar on the left side, is implemented
+            // for each color type in `impl_premultiply!`, as a work-around for
+            // https://github.com/Ogeon/palette/issues/283.
 
             impl<C> $op_assign_trait<$ty> for PreAlpha<C>
             where
@@ -407,10 +635,175 @@ where
 }
 
 #[cfg(test)]
-#[cfg(feature = "serializing")]
 mod test {
     use super::PreAlpha;
-    use crate::LinSrgb;
+    use crate::{white_point::D65, Clamp, ClampAssign, IsWithinBounds, LinLuma, LinSrgb};
+
+    #[test]
+    fn is_within_bounds() {
+        let valid = PreAlpha {
+            color: LinSrgb::new(0.5f32, 0.0, 0.3),
+            alpha: 0.5,
+        };
+        assert!(valid.is_within_bounds());
+
+        let above_alpha = PreAlpha {
+            color: LinSrgb::new(0.6f32, 0.2, 0.3),
+            alpha: 0.5,
+        };
+        assert!(!above_alpha.is_within_bounds());
+
+        let negative = PreAlpha {
+            color: LinSrgb::new(0.4f32, -0.1, 0.3),
+            alpha: 0.5,
+        };
+        assert!(!negative.is_within_bounds());
+
+        let alpha_above_one = PreAlpha {
+            color: LinSrgb::new(1.0f32, 1.0, 1.0),
+            alpha: 1.2,
+        };
+        assert!(!alpha_above_one.is_within_bounds());
+    }
+
+    #[test]
+    fn clamp_components_to_alpha() {
+        let color = PreAlpha {
+            color: LinSrgb::new(0.6f32, -0.1, 0.3),
+            alpha: 0.5,
+        };
+        let expected = PreAlpha {
+            color: LinSrgb::new(0.5f32, 0.0, 0.3),
+            alpha: 0.5,
+        };
+        assert_eq!(color.clamp(), expected);
+
+        let mut color = color;
+        color.clamp_assign();
+        assert_eq!(color, expected);
+    }
+
+    #[test]
+    fn clamp_alpha() {
+        let color = PreAlpha {
+            color: LinSrgb::new(1.2f32, 0.8, 0.3),
+            alpha: 1.5,
+        };
+        let expected = PreAlpha {
+            color: LinSrgb::new(1.0f32, 0.8, 0.3),
+            alpha: 1.0,
+        };
+        assert_eq!(color.clamp(), expected);
+
+        let mut color = PreAlpha {
+            color: LinLuma::<D65, f32>::new(0.2),
+            alpha: -0.5,
+        };
+        color.clamp_assign();
+        assert_eq!(
+            color,
+            PreAlpha {
+                color: LinLuma::new(0.0),
+                alpha: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn scalar_on_the_left() {
+        let color = PreAlpha {
+            color: LinSrgb::new(0.4f32, 0.2, 0.1),
+            alpha: 0.5,
+        };
+        assert_eq!(2.0 * color, color * 2.0);
+        assert_eq!(1.0 + color, color + 1.0);
+        assert_eq!(
+            1.0 - color,
+            PreAlpha {
+                color: LinSrgb::new(0.6, 0.8, 0.9),
+                alpha: 0.5,
+            }
+        );
+        assert_eq!(
+            1.0 / color,
+            PreAlpha {
+                color: LinSrgb::new(2.5, 5.0, 10.0),
+                alpha: 2.0,
+            }
+        );
+
+        let color = PreAlpha {
+            color: LinLuma::<D65, f64>::new(0.25),
+            alpha: 0.5,
+        };
+        assert_eq!(4.0 * color, color * 4.0);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn operates_on_unpremultiplied() {
+        use crate::{
+            Darken, Lighten, LightenAssign, LinSrgba, Oklaba, Saturate, SaturateAssign, ShiftHue,
+            ShiftHueAssign,
+        };
+
+        let color = LinSrgba::new(0.2f32, 0.4, 0.6, 0.5);
+        let premultiplied = PreAlpha::from(color);
+
+        assert_relative_eq!(
+            premultiplied.lighten(0.3),
+            PreAlpha::from(color.lighten(0.3)),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            premultiplied.darken_fixed(0.1),
+            PreAlpha::from(color.darken_fixed(0.1)),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            premultiplied.shift_hue(90.0),
+            PreAlpha::from(color.shift_hue(90.0)),
+            epsilon = 1e-6
+        );
+
+        let mut lightened = premultiplied;
+        lightened.lighten_assign(0.3);
+        assert_eq!(lightened, premultiplied.lighten(0.3));
+
+        let mut shifted = premultiplied;
+        shifted.shift_hue_assign(90.0);
+        assert_eq!(shifted, premultiplied.shift_hue(90.0));
+
+        let color = Oklaba::new(0.6f32, 0.05, -0.02, 0.25);
+        let mut premultiplied = PreAlpha::from(color);
+        let saturated = premultiplied.saturate(0.5);
+        assert_relative_eq!(
+            saturated,
+            PreAlpha::from(color.saturate(0.5)),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(saturated.alpha, 0.25);
+
+        premultiplied.saturate_assign(0.5);
+        assert_eq!(premultiplied, saturated);
+    }
+
+    #[cfg(feature = "wide")]
+    #[test]
+    fn is_within_bounds_wide() {
+        use wide::f32x4;
+
+        let color = PreAlpha {
+            color: LinSrgb::new(
+                f32x4::from([0.5, 0.6, -0.1, 0.2]),
+                f32x4::splat(0.1),
+                f32x4::splat(0.1),
+            ),
+            alpha: f32x4::from([0.5, 0.5, 0.5, 1.5]),
+        };
+        assert_eq!(color.is_within_bounds().move_mask(), 0b0001);
+        assert_eq!(color.clamp().is_within_bounds().move_mask(), 0b1111);
+    }
 
     #[cfg(feature = "serializing")]
     #[test]
@@ -5,6 +5,8 @@
 
 use core::ops::{BitAnd, BitOr, BitXor, Not};
 
+#[cfg(feature = "portable-simd")]
+mod portable_simd;
 #[cfg(feature = "wide")]
 mod wide;
 
@@ -21,14 +23,14 @@ pub trait HasBoolMask {
 
 impl<T> HasBoolMask for &'_ T
 where
-    T: HasBoolMask,
+    T: HasBoolMask + ?Sized,
 {
     type Mask = T::Mask;
 }
 
 impl<T> HasBoolMask for &'_ mut T
 where
-    T: HasBoolMask,
+    T: HasBoolMask + ?Sized,
 {
     type Mask = T::Mask;
 }
@@ -47,18 +49,54 @@ where
     type Mask = T::Mask;
 }
 
+#[cfg(feature = "alloc")]
+impl<T> HasBoolMask for alloc::vec::Vec<T>
+where
+    T: HasBoolMask,
+{
+    type Mask = T::Mask;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> HasBoolMask for alloc::boxed::Box<T>
+where
+    T: HasBoolMask + ?Sized,
+{
+    type Mask = T::Mask;
+}
+
 macro_rules! impl_has_bool_mask {
     ($($ty:ident),+) => {
         $(
             impl HasBoolMask for $ty {
                 type Mask = bool;
             }
+
+            impl Selectable for $ty {
+                #[inline(always)]
+                fn select(mask: bool, a: Self, b: Self) -> Self {
+                    mask.select(a, b)
+                }
+            }
         )+
     };
 }
 
 impl_has_bool_mask!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
 
+#[cfg(feature = "half")]
+impl HasBoolMask for half::f16 {
+    type Mask = bool;
+}
+
+#[cfg(feature = "half")]
+impl Selectable for half::f16 {
+    #[inline(always)]
+    fn select(mask: bool, a: Self, b: Self) -> Self {
+        mask.select(a, b)
+    }
+}
+
 /// Basic methods for boolean masks.
 pub trait BoolMask {
     /// Create a new mask where each lane is set to `value`.
@@ -149,6 +187,84 @@ where
     }
 }
 
+/// Values that can be picked from one of two options, component by component,
+/// using a mask.
+///
+/// This is implemented for scalar types, hues and all color types where the
+/// components implement `Selectable`. Each component (and each SIMD lane) is
+/// picked individually, which makes it possible to apply branchless effects,
+/// such as thresholding, to SIMD colors.
+///
+/// ```
+/// use palette::{bool_mask::Selectable, Hsv};
+///
+/// let a = Hsv::new_srgb(120.0, 1.0, 0.8);
+/// let b = Hsv::new_srgb(300.0, 0.5, 0.2);
+///
+/// assert_eq!(Hsv::select(true, a, b), a);
+/// assert_eq!(Hsv::select(false, a, b), b);
+/// ```
+///
+/// See [`select_colors`] for selecting between whole buffers of colors.
+pub trait Selectable: HasBoolMask + Sized {
+    /// Select each component from `a` where the corresponding lanes in `mask`
+    /// are `true`, and from `b` where they are `false`.
+    #[must_use]
+    fn select(mask: Self::Mask, a: Self, b: Self) -> Self;
+}
+
+/// Select between the values in two buffers, based on a buffer of masks.
+///
+/// Each value in `colors` is replaced by the result of
+/// [`Selectable::select`], where the value in `colors` is picked when the
+/// corresponding mask is `true`, and the value in `others` is picked when
+/// it's `false`.
+///
+/// ```
+/// use palette::{bool_mask::select_colors, Srgb};
+///
+/// let mut colors = [Srgb::new(1.0f32, 1.0, 1.0); 3];
+/// let others = [Srgb::new(0.0f32, 0.0, 0.0); 3];
+/// let luma = [0.2f32, 0.6, 0.4];
+/// let mask: Vec<bool> = luma.iter().map(|&luma| luma > 0.5).collect();
+///
+/// select_colors(&mask, &mut colors, &others);
+///
+/// assert_eq!(
+///     colors,
+///     [
+///         Srgb::new(0.0, 0.0, 0.0),
+///         Srgb::new(1.0, 1.0, 1.0),
+///         Srgb::new(0.0, 0.0, 0.0),
+///     ]
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics if `mask`, `colors` and `others` don't have the same length.
+#[inline]
+pub fn select_colors<C>(mask: &[C::Mask], colors: &mut [C], others: &[C])
+where
+    C: Selectable + Clone,
+    C::Mask: Clone,
+{
+    assert_eq!(
+        mask.len(),
+        colors.len(),
+        "the mask and color buffers have different lengths"
+    );
+    assert_eq!(
+        colors.len(),
+        others.len(),
+        "the color buffers have different lengths"
+    );
+
+    for ((mask, color), other) in mask.iter().zip(colors).zip(others) {
+        *color = C::select(mask.clone(), color.clone(), other.clone());
+    }
+}
+
 /// A helper trait that collects bit traits under one name.
 pub trait BitOps:
     Sized
@@ -173,3 +289,50 @@ impl<T> BitOps for T where
         + for<'a> BitXor<&'a Self, Output = Self>
 {
 }
+
+#[cfg(test)]
+mod test {
+    use super::{select_colors, Selectable};
+    use crate::{Hsv, Srgb, Srgba};
+
+    #[test]
+    fn select_scalar_colors() {
+        let a = Hsv::new_srgb(350.0f32, 1.0, 0.8);
+        let b = Hsv::new_srgb(10.0f32, 0.5, 0.2);
+
+        assert_eq!(Hsv::select(true, a, b), a);
+        assert_eq!(Hsv::select(false, a, b), b);
+        assert_eq!(Hsv::select(true, a, b).hue.into_inner(), 350.0);
+
+        let a = Srgba::new(255u8, 0, 0, 128);
+        let b = Srgba::new(0u8, 255, 0, 255);
+        assert_eq!(Srgba::select(true, a, b), a);
+        assert_eq!(Srgba::select(false, a, b), b);
+    }
+
+    #[test]
+    fn select_color_slices() {
+        let mut colors = [Hsv::new_srgb(0.0f32, 1.0, 1.0); 3];
+        let others = [Hsv::new_srgb(180.0f32, 0.0, 0.0); 3];
+
+        select_colors(&[false, true, false], &mut colors, &others);
+
+        assert_eq!(
+            colors,
+            [
+                Hsv::new_srgb(180.0, 0.0, 0.0),
+                Hsv::new_srgb(0.0, 1.0, 1.0),
+                Hsv::new_srgb(180.0, 0.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the mask and color buffers have different lengths")]
+    fn select_color_slices_mask_length_mismatch() {
+        let mut colors = [Srgb::new(1.0f32, 1.0, 1.0); 2];
+        let others = [Srgb::new(0.0f32, 0.0, 0.0); 2];
+
+        select_colors(&[true], &mut colors, &others);
+    }
+}
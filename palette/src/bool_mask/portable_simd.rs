@@ -0,0 +1,109 @@
+use core::simd::{Mask, Simd};
+
+use super::{BoolMask, HasBoolMask, LazySelect, Select, Selectable};
+
+macro_rules! impl_portable_simd_bool_mask {
+    ($($scalar: ident: $int: ident),+) => {
+        $(
+            impl<const N: usize> BoolMask for Mask<$int, N> {
+                #[inline]
+                fn from_bool(value: bool) -> Self {
+                    Mask::splat(value)
+                }
+
+                #[inline]
+                fn is_true(&self) -> bool {
+                    self.all()
+                }
+
+                #[inline]
+                fn is_false(&self) -> bool {
+                    !self.any()
+                }
+            }
+
+            impl<const N: usize> HasBoolMask for Mask<$int, N> {
+                type Mask = Self;
+            }
+
+            impl<const N: usize> HasBoolMask for Simd<$scalar, N> {
+                type Mask = Mask<$int, N>;
+            }
+
+            impl<const N: usize> Select<Self> for Mask<$int, N> {
+                #[inline]
+                fn select(self, a: Self, b: Self) -> Self {
+                    core::simd::Select::select(self, a, b)
+                }
+            }
+
+            impl<const N: usize> Select<Simd<$scalar, N>> for Mask<$int, N> {
+                #[inline]
+                fn select(self, a: Simd<$scalar, N>, b: Simd<$scalar, N>) -> Simd<$scalar, N> {
+                    core::simd::Select::select(self, a, b)
+                }
+            }
+
+            impl<const N: usize> Selectable for Simd<$scalar, N> {
+                #[inline]
+                fn select(mask: Mask<$int, N>, a: Self, b: Self) -> Self {
+                    core::simd::Select::select(mask, a, b)
+                }
+            }
+
+            impl<const N: usize> LazySelect<Self> for Mask<$int, N> {
+                #[inline]
+                fn lazy_select<A, B>(self, a: A, b: B) -> Self
+                where
+                    A: FnOnce() -> Self,
+                    B: FnOnce() -> Self,
+                {
+                    let a = a();
+                    let b = b();
+
+                    Select::<Self>::select(self, a, b)
+                }
+            }
+
+            impl<const N: usize> LazySelect<Simd<$scalar, N>> for Mask<$int, N> {
+                #[inline]
+                fn lazy_select<A, B>(self, a: A, b: B) -> Simd<$scalar, N>
+                where
+                    A: FnOnce() -> Simd<$scalar, N>,
+                    B: FnOnce() -> Simd<$scalar, N>,
+                {
+                    let a = a();
+                    let b = b();
+
+                    Select::<Simd<$scalar, N>>::select(self, a, b)
+                }
+            }
+        )+
+    };
+}
+
+impl_portable_simd_bool_mask!(f32: i32, f64: i64);
+
+#[cfg(test)]
+mod test {
+    use core::simd::Mask;
+
+    use crate::bool_mask::BoolMask;
+
+    #[test]
+    fn from_bool() {
+        assert!(Mask::<i32, 4>::from_bool(true).is_true());
+        assert!(!Mask::<i32, 4>::from_bool(true).is_false());
+
+        assert!(Mask::<i64, 8>::from_bool(false).is_false());
+        assert!(!Mask::<i64, 8>::from_bool(false).is_true());
+    }
+
+    #[test]
+    fn partially_true() {
+        let mask = Mask::<i32, 4>::from_array([true, false, true, true]);
+
+        assert!(!mask.is_true());
+        assert!(!mask.is_false());
+    }
+}
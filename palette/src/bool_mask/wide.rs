@@ -1,6 +1,6 @@
 use wide::{f32x4, f32x8, f64x2, f64x4};
 
-use super::{BoolMask, HasBoolMask, LazySelect, Select};
+use super::{BoolMask, HasBoolMask, LazySelect, Select, Selectable};
 
 macro_rules! impl_wide_bool_mask {
     ($($ty: ident: ($scalar: ident, $uint: ident)),+) => {
@@ -33,6 +33,13 @@ macro_rules! impl_wide_bool_mask {
                 }
             }
 
+            impl Selectable for $ty {
+                #[inline]
+                fn select(mask: Self, a: Self, b: Self) -> Self {
+                    mask.blend(a, b)
+                }
+            }
+
             impl LazySelect<Self> for $ty {
                 #[inline]
                 fn lazy_select<A, B>(self, a: A, b: B) -> Self
@@ -61,7 +68,11 @@ impl_wide_bool_mask!(
 mod test {
     use wide::{f32x4, f32x8, f64x2, f64x4};
 
-    use crate::bool_mask::BoolMask;
+    use crate::{
+        bool_mask::{select_colors, BoolMask, Selectable},
+        num::PartialCmp,
+        Hsv, Srgba,
+    };
 
     #[test]
     fn from_true() {
@@ -92,4 +103,53 @@ mod test {
         assert!(f64x4::from_bool(false).is_false());
         assert!(!f64x4::from_bool(false).is_true());
     }
+
+    #[test]
+    fn select_colors_by_lane() {
+        let a: Hsv<_, f32x4> = [Hsv::new_srgb(350.0f32, 1.0, 0.8); 4].into();
+        let b: Hsv<_, f32x4> = [Hsv::new_srgb(10.0f32, 0.5, 0.2); 4].into();
+        let mask = f32x4::new([1.0, 2.0, 3.0, 4.0]).gt(&f32x4::splat(2.5));
+
+        let result: [Hsv<_, f32>; 4] = Hsv::select(mask, a, b).into();
+        assert_eq!(
+            result,
+            [
+                Hsv::new_srgb(10.0, 0.5, 0.2),
+                Hsv::new_srgb(10.0, 0.5, 0.2),
+                Hsv::new_srgb(350.0, 1.0, 0.8),
+                Hsv::new_srgb(350.0, 1.0, 0.8),
+            ]
+        );
+    }
+
+    #[test]
+    fn select_color_slices_by_lane() {
+        let mut colors: [Srgba<f32x4>; 2] = [
+            [Srgba::new(1.0f32, 1.0, 1.0, 1.0); 4].into(),
+            [Srgba::new(1.0f32, 1.0, 1.0, 1.0); 4].into(),
+        ];
+        let others: [Srgba<f32x4>; 2] = [
+            [Srgba::new(0.0f32, 0.0, 0.0, 0.5); 4].into(),
+            [Srgba::new(0.0f32, 0.0, 0.0, 0.5); 4].into(),
+        ];
+        let masks = [
+            f32x4::from_bool(true),
+            f32x4::new([1.0, 0.0, 1.0, 0.0]).gt(&f32x4::ZERO),
+        ];
+
+        select_colors(&masks, &mut colors, &others);
+
+        let first: [Srgba<f32>; 4] = colors[0].into();
+        let second: [Srgba<f32>; 4] = colors[1].into();
+        assert_eq!(first, [Srgba::new(1.0, 1.0, 1.0, 1.0); 4]);
+        assert_eq!(
+            second,
+            [
+                Srgba::new(1.0, 1.0, 1.0, 1.0),
+                Srgba::new(0.0, 0.0, 0.0, 0.5),
+                Srgba::new(1.0, 1.0, 1.0, 1.0),
+                Srgba::new(0.0, 0.0, 0.0, 0.5),
+            ]
+        );
+    }
 }
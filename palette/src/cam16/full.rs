@@ -411,7 +411,11 @@ impl_clamp! {
 impl_eq_hue!(
     Cam16,
     Cam16Hue,
-    [lightness, chroma, brightness, colorfulness, saturation]
+    [lightness, chroma, brightness, colorfulness, saturation, hue]
+);
+impl_select!(
+    Cam16,
+    [lightness, chroma, brightness, colorfulness, saturation, hue]
 );
 impl_simd_array_conversion_hue!(
     Cam16,
@@ -487,6 +491,19 @@ mod test {
         assert_eq!(cam16.into_xyz(parameters), cam16jch.into_xyz(parameters));
     }
 
+    #[test]
+    fn approx_eq_compares_hue() {
+        let xyz = Srgb::from(0x5588cc).into_linear().into_color_unclamped();
+        let cam16: Cam16<f64> = Cam16::from_xyz(xyz, Parameters::TEST_DEFAULTS);
+
+        let mut other = cam16;
+        other.hue = (cam16.hue.into_degrees() - 360.0).into();
+        assert_relative_eq!(cam16, other, epsilon = 1e-12);
+
+        other.hue = (cam16.hue.into_degrees() + 1.0).into();
+        assert_relative_ne!(cam16, other, epsilon = 1e-12);
+    }
+
     #[test]
     fn example_blue() {
         // Uses the example color from https://observablehq.com/@jrus/cam16
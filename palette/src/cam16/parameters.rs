@@ -3,13 +3,13 @@ use core::marker::PhantomData;
 use crate::{
     angle::{RealAngle, SignedAngle},
     bool_mask::LazySelect,
-    convert::{Convert, ConvertOnce},
+    convert::{Convert, ConvertOnce, FromColorUnclamped},
     num::{
         Abs, Arithmetics, Clamp, Exp, FromScalar, One, PartialCmp, Powf, Real, Signum, Sqrt,
         Trigonometry, Zero,
     },
-    white_point::{self, WhitePoint},
-    Xyz,
+    white_point::{self, WhitePoint, D65},
+    Oklab, Oklch, Xyz,
 };
 
 use super::{Cam16, Cam16Jch, Cam16Jmh, Cam16Jsh, Cam16Qch, Cam16Qmh, Cam16Qsh};
@@ -169,6 +169,46 @@ pub struct BakedParameters<WpParam, T> {
     white_point: PhantomData<WpParam>,
 }
 
+impl<WpParam, T> BakedParameters<WpParam, T> {
+    /// Convert each color in `input` and write the results to `output`.
+    ///
+    /// This is a convenient way of converting a whole palette with the same
+    /// viewing conditions, for example between [`Cam16Jch`] and
+    /// [`Oklch`](crate::Oklch):
+    ///
+    /// ```
+    /// use palette::{Oklch, cam16::{Cam16Jch, Parameters}};
+    ///
+    /// let parameters = Parameters::default_static_wp(40.0).bake();
+    ///
+    /// let colors = [Oklch::new(0.5f32, 0.1, 30.0), Oklch::new(0.8, 0.05, 200.0)];
+    /// let mut cam16 = [Cam16Jch::new(0.0, 0.0, 0.0); 2];
+    /// parameters.convert_slice(&colors, &mut cam16);
+    ///
+    /// let mut roundtrip = [Oklch::new(0.0, 0.0, 0.0); 2];
+    /// parameters.convert_slice(&cam16, &mut roundtrip);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// If `input` and `output` have different lengths.
+    pub fn convert_slice<I, O>(&self, input: &[I], output: &mut [O])
+    where
+        Self: Convert<I, O>,
+        I: Clone,
+    {
+        assert_eq!(
+            input.len(),
+            output.len(),
+            "the input and output slices should have the same length"
+        );
+
+        for (input, output) in input.iter().zip(output) {
+            *output = self.convert(input.clone());
+        }
+    }
+}
+
 impl<WpParam, T, I, O> Convert<I, O> for BakedParameters<WpParam, T>
 where
     Self: ConvertOnce<I, O> + Copy,
@@ -279,6 +319,66 @@ macro_rules! impl_convert_cam16_partial {
 
 impl_convert_cam16_partial!(Cam16Jmh, Cam16Jch, Cam16Jsh, Cam16Qmh, Cam16Qch, Cam16Qsh);
 
+macro_rules! impl_convert_cam16_oklab {
+    ($($name: ident),+) => {
+        $(
+            impl<WpParam, T> ConvertOnce<Oklab<T>, $name<T>> for BakedParameters<WpParam, T::Scalar>
+            where
+                T: FromScalar,
+                Self: ConvertOnce<Xyz<D65, T>, Cam16<T>>,
+                Xyz<D65, T>: FromColorUnclamped<Oklab<T>>,
+                $name<T>: From<Cam16<T>>,
+            {
+                #[inline]
+                fn convert_once(self, input: Oklab<T>) -> $name<T> {
+                    let cam16: Cam16<T> = self.convert_once(Xyz::from_color_unclamped(input));
+                    cam16.into()
+                }
+            }
+
+            impl<WpParam, T> ConvertOnce<Oklch<T>, $name<T>> for BakedParameters<WpParam, T::Scalar>
+            where
+                T: FromScalar,
+                Self: ConvertOnce<Oklab<T>, $name<T>>,
+                Oklab<T>: FromColorUnclamped<Oklch<T>>,
+            {
+                #[inline]
+                fn convert_once(self, input: Oklch<T>) -> $name<T> {
+                    self.convert_once(Oklab::from_color_unclamped(input))
+                }
+            }
+
+            impl<WpParam, T> ConvertOnce<$name<T>, Oklab<T>> for BakedParameters<WpParam, T::Scalar>
+            where
+                T: FromScalar,
+                Self: ConvertOnce<$name<T>, Xyz<D65, T>>,
+                Oklab<T>: FromColorUnclamped<Xyz<D65, T>>,
+            {
+                #[inline]
+                fn convert_once(self, input: $name<T>) -> Oklab<T> {
+                    let xyz: Xyz<D65, T> = self.convert_once(input);
+                    Oklab::from_color_unclamped(xyz)
+                }
+            }
+
+            impl<WpParam, T> ConvertOnce<$name<T>, Oklch<T>> for BakedParameters<WpParam, T::Scalar>
+            where
+                T: FromScalar,
+                Self: ConvertOnce<$name<T>, Oklab<T>>,
+                Oklch<T>: FromColorUnclamped<Oklab<T>>,
+            {
+                #[inline]
+                fn convert_once(self, input: $name<T>) -> Oklch<T> {
+                    let oklab: Oklab<T> = self.convert_once(input);
+                    Oklch::from_color_unclamped(oklab)
+                }
+            }
+        )+
+    };
+}
+
+impl_convert_cam16_oklab!(Cam16, Cam16Jmh, Cam16Jch, Cam16Jsh, Cam16Qmh, Cam16Qch, Cam16Qsh);
+
 impl<WpParam, T> Clone for BakedParameters<WpParam, T>
 where
     T: Clone,
@@ -312,6 +412,8 @@ where
     T::Mask: LazySelect<T>,
 {
     fn from(value: Parameters<WpParam, T>) -> Self {
+        // Like `rgb_to_xyz_matrix`, this stays generic. It only runs on scalar
+        // types, and doing it in `f64` would change the `f32` results.
         Self {
             inner: super::math::prepare_parameters(value.into_any_white_point()),
             white_point: PhantomData,
@@ -413,3 +515,61 @@ impl<Wp> Clone for StaticWp<Wp> {
 }
 
 impl<Wp> Copy for StaticWp<Wp> {}
+
+#[cfg(feature = "approx")]
+#[cfg(test)]
+mod test {
+    use crate::{
+        cam16::{Cam16, Cam16Jch, Cam16Jmh, Parameters},
+        convert::Convert,
+        Oklab, Oklch,
+    };
+
+    #[test]
+    fn oklab_roundtrip() {
+        let parameters = Parameters::default_static_wp(40.0).bake();
+
+        for &oklab in &[
+            Oklab::new(0.5f64, 0.1, -0.05),
+            Oklab::new(0.9, -0.05, 0.1),
+            Oklab::new(0.2, 0.02, 0.02),
+        ] {
+            let cam16: Cam16<f64> = parameters.convert(oklab);
+            let roundtrip: Oklab<f64> = parameters.convert(cam16);
+            assert_relative_eq!(roundtrip, oklab, epsilon = 1e-10);
+
+            let jmh: Cam16Jmh<f64> = parameters.convert(oklab);
+            let roundtrip: Oklab<f64> = parameters.convert(jmh);
+            assert_relative_eq!(roundtrip, oklab, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn oklch_slice_roundtrip() {
+        let parameters = Parameters::default_static_wp(40.0f32).bake();
+
+        let colors = [
+            Oklch::new(0.5f32, 0.1, 30.0),
+            Oklch::new(0.7, 0.15, 140.0),
+            Oklch::new(0.3, 0.05, 260.0),
+        ];
+        let mut cam16 = [Cam16Jch::new(0.0, 0.0, 0.0); 3];
+        parameters.convert_slice(&colors, &mut cam16);
+
+        let mut roundtrip = [Oklch::new(0.0, 0.0, 0.0); 3];
+        parameters.convert_slice(&cam16, &mut roundtrip);
+
+        for (color, roundtrip) in colors.iter().zip(&roundtrip) {
+            assert_relative_eq!(color, roundtrip, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_slice_length_mismatch() {
+        let parameters = Parameters::default_static_wp(40.0f32).bake();
+
+        let mut cam16 = [Cam16Jch::new(0.0, 0.0, 0.0); 1];
+        parameters.convert_slice(&[Oklch::new(0.5f32, 0.1, 30.0); 2], &mut cam16);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     cam16::Cam16UcsJmh,
     convert::FromColorUnclamped,
-    num::{Arithmetics, Exp, One, Real},
+    num::{Arithmetics, Exp, One, Real, Zero},
     Alpha,
 };
 
@@ -521,6 +521,7 @@ macro_rules! make_partial_cam16 {
                     $luminance => [T::zero(), None],
                     $chromaticity => [T::zero(), None]
                 }
+                new($luminance: T, $chromaticity: T, hue: impl Into<Cam16Hue<T>>)
                 where T: Zero
             }
             impl_clamp! {
@@ -543,6 +544,7 @@ macro_rules! make_partial_cam16 {
             impl_struct_of_array_traits_hue!($name, Cam16HueIter, [$luminance, $chromaticity]);
 
             impl_eq_hue!($name, Cam16Hue, [$luminance, $chromaticity, hue]);
+            impl_select!($name, [$luminance, $chromaticity, hue]);
         }
     };
 }
@@ -552,6 +554,22 @@ macro_rules! make_partial_cam16 {
 /// See the [`Cam16Jcha` implementation in `Alpha`](crate::Alpha#Cam16Jcha).
 pub type Cam16Jcha<T> = Alpha<Cam16Jch<T>, T>;
 make_partial_cam16! {
+    ///
+    /// `Cam16Jch` implements the [`Lighten`][crate::Lighten] and
+    /// [`Saturate`][crate::Saturate] traits, in addition to the hue and mixing
+    /// traits. Lightening changes the lightness (J), within `0.0..=100.0`, and
+    /// saturating changes the chroma (C), within
+    /// `0.0..=`[`max_srgb_chroma`][Cam16Jch::max_srgb_chroma]. This makes it
+    /// behave like [`Lch`][crate::Lch] in generic code.
+    ///
+    /// ```
+    /// use palette::{cam16::Cam16Jch, Lighten, Saturate, ShiftHue};
+    ///
+    /// let color = Cam16Jch::new(50.0f32, 40.0, 120.0);
+    /// let adjusted = color.lighten(0.5).saturate_fixed(0.1).shift_hue(30.0);
+    ///
+    /// assert_eq!(adjusted, Cam16Jch::new(75.0, 52.0, 150.0));
+    /// ```
     cam16_jch::Cam16Jch {
         /// The [lightness](https://cie.co.at/eilvterm/17-22-063) (J) of the
         /// color.
@@ -566,6 +584,45 @@ make_partial_cam16! {
     }
 }
 
+impl<T> Cam16Jch<T>
+where
+    T: Zero + Real,
+{
+    /// Return the `lightness` value minimum.
+    pub fn min_lightness() -> T {
+        T::zero()
+    }
+
+    /// Return the `lightness` value maximum.
+    ///
+    /// This is the lightness of the adopted white point, but colors that are
+    /// brighter than it can have a higher lightness. It's used as the upper
+    /// bound in `Lighten` and `Darken`.
+    pub fn max_lightness() -> T {
+        T::from_f64(100.0)
+    }
+
+    /// Return the `chroma` value minimum.
+    pub fn min_chroma() -> T {
+        T::zero()
+    }
+
+    /// Return a `chroma` value maximum that includes the sRGB gamut.
+    ///
+    /// <p class="warning">
+    /// This is entirely arbitrary and only for use in `Saturate` and
+    /// `Desaturate`. Chroma doesn't have a well defined upper bound.
+    /// </p>
+    pub fn max_srgb_chroma() -> T {
+        // The sRGB primaries have a chroma of up to about 113, with the
+        // default viewing conditions.
+        T::from_f64(120.0)
+    }
+}
+
+impl_lighten!(Cam16Jch increase {lightness => [Self::min_lightness(), Self::max_lightness()]} other {hue, chroma});
+impl_saturate!(Cam16Jch increase {chroma => [Self::min_chroma(), Self::max_srgb_chroma()]} other {hue, lightness});
+
 /// Partial CIE CAM16 with lightness, colorfulness, and an alpha component.
 ///
 /// See the [`Cam16Jmha` implementation in `Alpha`](crate::Alpha#Cam16Jmha).
@@ -790,4 +847,112 @@ mod test {
         let cam16: Cam16<f64> = Cam16::from_xyz(xyz, Parameters::TEST_DEFAULTS);
         assert_partial_to_full!(cam16);
     }
+
+    #[test]
+    fn try_new() {
+        assert!(Cam16Jch::try_new(50.0f32, 20.0, 400.0).is_ok());
+        assert!(Cam16Jch::try_new(50.0f32, -20.0, 30.0).is_err());
+        assert!(Cam16Jch::try_new(f32::NAN, 20.0, 30.0).is_err());
+        assert!(Cam16Jch::try_new(50.0f32, 20.0, f32::NAN).is_err());
+    }
+
+    #[test]
+    fn jch_lighten() {
+        use crate::{Darken, Lighten, LightenAssign};
+
+        let color = Cam16Jch::new(50.0f64, 20.0, 30.0);
+        assert_eq!(color.lighten(0.5).lightness, 75.0);
+        assert_eq!(color.darken(0.5).lightness, 25.0);
+        assert_eq!(color.lighten_fixed(0.25).lightness, 75.0);
+        assert_eq!(color.lighten_fixed(1.0).lightness, 100.0);
+        assert_eq!(color.darken_fixed(1.0).lightness, 0.0);
+
+        let mut color = color;
+        color.lighten_assign(0.5);
+        assert_eq!(color, Cam16Jch::new(75.0, 20.0, 30.0));
+    }
+
+    #[test]
+    fn jch_saturate() {
+        use crate::{Desaturate, Saturate, SaturateAssign};
+
+        let color = Cam16Jch::new(50.0f64, 60.0, 30.0);
+        assert_eq!(color.saturate(0.5).chroma, 90.0);
+        assert_eq!(color.desaturate(0.5).chroma, 30.0);
+        assert_eq!(color.saturate_fixed(0.25).chroma, 90.0);
+        assert_eq!(color.desaturate_fixed(1.0).chroma, 0.0);
+
+        let mut color = color;
+        color.saturate_assign(0.5);
+        assert_eq!(color, Cam16Jch::new(50.0, 90.0, 30.0));
+    }
+
+    #[test]
+    fn jch_hue() {
+        use crate::{hues::Cam16Hue, GetHue, SetHue, ShiftHue, WithHue};
+
+        let color = Cam16Jch::new(50.0f64, 20.0, 350.0);
+        assert_eq!(color.get_hue(), Cam16Hue::new(350.0));
+        assert_eq!(color.shift_hue(20.0).hue, Cam16Hue::new(10.0));
+        assert_eq!(color.with_hue(90.0), Cam16Jch::new(50.0, 20.0, 90.0));
+
+        let mut color = color;
+        color.set_hue(180.0);
+        assert_eq!(color.hue, Cam16Hue::new(180.0));
+    }
+
+    #[test]
+    fn jch_mix_shortest_hue() {
+        use crate::Mix;
+
+        let a = Cam16Jch::new(40.0f64, 20.0, 350.0);
+        let b = Cam16Jch::new(60.0, 40.0, 30.0);
+        assert_eq!(a.mix(b, 0.5), Cam16Jch::new(50.0, 30.0, 10.0));
+    }
+
+    #[cfg(feature = "wide")]
+    #[test]
+    fn jch_lighten_saturate_wide() {
+        use crate::{Lighten, Saturate};
+
+        let color = Cam16Jch::new(
+            wide::f32x4::splat(50.0),
+            wide::f32x4::splat(60.0),
+            wide::f32x4::splat(30.0),
+        );
+        assert_eq!(
+            color.lighten(wide::f32x4::splat(0.5)).lightness,
+            wide::f32x4::splat(75.0)
+        );
+        assert_eq!(
+            color.saturate(wide::f32x4::splat(0.5)).chroma,
+            wide::f32x4::splat(90.0)
+        );
+    }
+
+    macro_rules! partial_struct_of_arrays_tests {
+        ($($module: ident :: $name: ident, $alpha_name: ident [$($element: ident),+];)+) => {
+            $(
+                mod $module {
+                    use crate::cam16::{$alpha_name, $name};
+
+                    struct_of_arrays_tests!(
+                        $name[$($element),+],
+                        $alpha_name::new(0.1f32, 0.2, 0.3, 0.4),
+                        $alpha_name::new(0.2, 0.3, 0.4, 0.5),
+                        $alpha_name::new(0.3, 0.4, 0.5, 0.6)
+                    );
+                }
+            )+
+        };
+    }
+
+    partial_struct_of_arrays_tests! {
+        cam16_jch::Cam16Jch, Cam16Jcha[lightness, chroma, hue];
+        cam16_jmh::Cam16Jmh, Cam16Jmha[lightness, colorfulness, hue];
+        cam16_jsh::Cam16Jsh, Cam16Jsha[lightness, saturation, hue];
+        cam16_qch::Cam16Qch, Cam16Qcha[brightness, chroma, hue];
+        cam16_qmh::Cam16Qmh, Cam16Qmha[brightness, colorfulness, hue];
+        cam16_qsh::Cam16Qsh, Cam16Qsha[brightness, saturation, hue];
+    }
 }
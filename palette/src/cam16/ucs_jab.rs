@@ -252,6 +252,7 @@ impl_is_within_bounds! {
     Cam16UcsJab {
         lightness => [Self::min_lightness(), Self::max_lightness()]
     }
+    new(lightness: T, a: T, b: T)
     where T: Real + Zero
 }
 impl_clamp! {
@@ -277,12 +278,14 @@ impl_color_add!(Cam16UcsJab, [lightness, a, b]);
 impl_color_sub!(Cam16UcsJab, [lightness, a, b]);
 impl_color_mul!(Cam16UcsJab, [lightness, a, b]);
 impl_color_div!(Cam16UcsJab, [lightness, a, b]);
+impl_component_wise!(Cam16UcsJab, [lightness, a, b]);
 
 impl_array_casts!(Cam16UcsJab<T>, [T; 3]);
 impl_simd_array_conversion!(Cam16UcsJab, [lightness, a, b]);
 impl_struct_of_array_traits!(Cam16UcsJab, [lightness, a, b]);
 
 impl_eq!(Cam16UcsJab, [lightness, a, b]);
+impl_select!(Cam16UcsJab, [lightness, a, b]);
 
 impl_rand_traits_cartesian!(
     UniformCam16UcsJab,
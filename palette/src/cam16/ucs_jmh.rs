@@ -256,6 +256,7 @@ impl_is_within_bounds! {
         lightness => [Self::min_lightness(), Self::max_lightness()],
         colorfulness => [Self::min_colorfulness(), None]
     }
+    new(lightness: T, colorfulness: T, hue: impl Into<Cam16Hue<T>>)
     where T: Zero + Real
 }
 impl_clamp! {
@@ -283,6 +284,7 @@ impl_simd_array_conversion_hue!(Cam16UcsJmh, [lightness, colorfulness]);
 impl_struct_of_array_traits_hue!(Cam16UcsJmh, Cam16HueIter, [lightness, colorfulness]);
 
 impl_eq_hue!(Cam16UcsJmh, Cam16Hue, [lightness, colorfulness, hue]);
+impl_select!(Cam16UcsJmh, [lightness, colorfulness, hue]);
 
 impl_rand_traits_cylinder!(
     UniformCam16UcsJmh,
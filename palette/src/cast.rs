@@ -121,6 +121,10 @@
 //! assert!(<&Srgb>::try_from(short_slice).is_err()); // Too few components.
 //! ```
 //!
+//! The references point to the same memory as the color, so changing the
+//! components through `AsMut` changes the color itself. `Borrow` is not
+//! implemented, since colors and arrays are hashed differently.
+//!
 //! ## Component Order
 //!
 //! The component order in an array or slice is not always the same as in the
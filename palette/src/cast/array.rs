@@ -33,6 +33,13 @@ use crate::ArrayExt;
 /// layout, or are zero-sized, they can be marked with attributes to show that
 /// their types are safe to use.
 ///
+/// A type with a single array field, such as `[T; N]`, uses that array type as
+/// its `Array` type, instead of wrapping it in another array. This makes it
+/// possible to derive `ArrayCast` for types with a const generic number of
+/// components. Casting them with an alpha component, as in `Alpha<C, T>`,
+/// requires [`NextArray`](crate::NextArray), which is implemented for arrays
+/// with up to 64 items.
+///
 /// ## Field Attributes
 ///
 /// * `#[palette_unsafe_same_layout_as = "SomeType"]`: Mark the field as having
@@ -111,6 +118,23 @@ use crate::ArrayExt;
 /// );
 /// ```
 ///
+/// Const generic number of components:
+///
+/// ```rust
+/// use palette::{cast::{self, ArrayCast}, Alpha};
+///
+/// #[derive(PartialEq, Debug, ArrayCast)]
+/// #[repr(transparent)]
+/// struct Spectrum<T, const N: usize>([T; N]);
+///
+/// let color: Spectrum<f32, 4> = cast::from_array([0.1, 0.2, 0.3, 0.4]);
+/// assert_eq!(color, Spectrum([0.1, 0.2, 0.3, 0.4]));
+///
+/// // Adding an alpha component makes the array one item longer.
+/// let transparent = Alpha { color, alpha: 0.5 };
+/// assert_eq!(cast::into_array(transparent), [0.1, 0.2, 0.3, 0.4, 0.5]);
+/// ```
+///
 /// ## Safety
 ///
 /// * The type must be inhabited (eg: no
@@ -1564,9 +1588,124 @@ pub enum VecCastErrorKind {
 
 #[cfg(test)]
 mod test {
+    use crate::{cast::ArrayCast, Alpha};
     #[cfg(feature = "alloc")]
     use crate::{LinSrgb, Srgb};
 
+    #[derive(Clone, Copy, PartialEq, Debug, ArrayCast)]
+    #[palette(palette_internal)]
+    #[repr(transparent)]
+    struct Spectral<T, const N: usize>([T; N]);
+
+    fn spectral_components() -> [f32; 62] {
+        let mut components = [0.0; 62];
+        for (index, component) in components.iter_mut().enumerate() {
+            *component = index as f32;
+        }
+        components
+    }
+
+    #[test]
+    fn const_generic_array_slices() {
+        let components = spectral_components();
+
+        let colors: &[Spectral<f32, 31>] = super::from_component_slice(&components);
+        assert_eq!(colors.len(), 2);
+        assert_eq!(colors[0].0[0], 0.0);
+        assert_eq!(colors[0].0[30], 30.0);
+        assert_eq!(colors[1].0[0], 31.0);
+        assert_eq!(colors[1].0[30], 61.0);
+
+        let arrays = super::into_array_slice(colors);
+        assert_eq!(arrays.len(), 2);
+        assert_eq!(super::into_component_slice(colors), &components[..]);
+    }
+
+    #[test]
+    fn const_generic_array_alpha() {
+        let components = spectral_components();
+
+        let colors: &[Alpha<Spectral<f32, 31>, f32>] =
+            super::from_component_slice(&components[..32]);
+        assert_eq!(colors.len(), 1);
+        assert_eq!(colors[0].color.0[0], 0.0);
+        assert_eq!(colors[0].color.0[30], 30.0);
+        assert_eq!(colors[0].alpha, 31.0);
+
+        let array: [f32; 32] = super::into_array(colors[0]);
+        assert_eq!(array[..], components[..32]);
+        assert_eq!(
+            super::from_array::<Alpha<Spectral<f32, 31>, f32>>(array),
+            colors[0]
+        );
+    }
+
+    #[test]
+    fn as_mut_aliases_fields() {
+        let mut color = crate::Srgba::new(0.1f32, 0.2, 0.3, 0.4);
+
+        let slice: &mut [f32] = color.as_mut();
+        slice[0] = 1.0;
+        slice[3] = 0.5;
+        assert_eq!(color.red, 1.0);
+        assert_eq!(color.alpha, 0.5);
+
+        let array: &mut [f32; 4] = color.as_mut();
+        array[2] = 0.8;
+        assert_eq!(color.blue, 0.8);
+
+        let array: &[f32; 4] = color.as_ref();
+        assert_eq!(*array, [1.0, 0.2, 0.8, 0.5]);
+        assert_eq!(array.as_ptr(), &color.red as *const f32);
+    }
+
+    #[test]
+    fn as_ref_slice_in_generic_context() {
+        fn sum<A: AsRef<[f32]>>(components: A) -> f32 {
+            components.as_ref().iter().sum()
+        }
+
+        assert_eq!(sum(crate::LinSrgb::new(1.0f32, 2.0, 3.0)), 6.0);
+        assert_eq!(
+            sum(crate::Hsv::<crate::encoding::Srgb, f32>::new(
+                90.0, 0.5, 0.5
+            )),
+            91.0
+        );
+    }
+
+    #[derive(Clone, Copy, PartialEq, Debug, ArrayCast)]
+    #[palette(palette_internal)]
+    #[repr(transparent)]
+    struct SingleArray([f32; 3]);
+
+    #[test]
+    fn single_array_field_is_the_array_type() {
+        fn assert_array_type<C: ArrayCast<Array = [f32; 3]>>() {}
+        assert_array_type::<SingleArray>();
+
+        let color = SingleArray([0.1, 0.2, 0.3]);
+
+        assert_eq!(super::into_array(color), [0.1, 0.2, 0.3]);
+        assert_eq!(
+            super::into_array(Alpha { color, alpha: 0.4 }),
+            [0.1, 0.2, 0.3, 0.4]
+        );
+    }
+
+    #[test]
+    fn const_generic_array_max_alpha_length() {
+        let mut components = [0.0f32; 65];
+        for (index, component) in components.iter_mut().enumerate() {
+            *component = index as f32;
+        }
+
+        let color: Alpha<Spectral<f32, 64>, f32> = super::from_array(components);
+        assert_eq!(color.color.0[63], 63.0);
+        assert_eq!(color.alpha, 64.0);
+        assert_eq!(super::into_array(color), components);
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn array_vec_len_cap() {
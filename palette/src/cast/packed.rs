@@ -1,6 +1,11 @@
-use core::marker::PhantomData;
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
 
-use crate::cast::UintCast;
+use crate::{cast::UintCast, encoding::Srgb, rgb::Rgba};
 
 use super::ArrayCast;
 
@@ -60,7 +65,40 @@ use super::ArrayCast;
 /// When an `Rgb` type is packed, the alpha value will be `0xFF` in the
 /// corresponding `u32`. Converting from a packed color type back to an `Rgb`
 /// type will disregard the alpha value.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// Colors with `u8` components can be packed into a `u32` in a `const`
+/// context, using `pack_const`, for the `Abgr`, `Argb`, `Bgra` and `Rgba`
+/// channel orders:
+///
+/// ```
+/// use palette::{rgb::PackedArgb, Srgba};
+///
+/// const ACCENT: PackedArgb = PackedArgb::pack_const(Srgba::new(0xFF, 0x80, 0x00, 0x7F));
+/// assert_eq!(ACCENT.color, 0x7FFF8000);
+/// ```
+///
+/// ## Comparing and Hashing
+///
+/// `Packed` is compared, ordered and hashed by its packed data, so it can be
+/// used as a key in a `HashMap` or `BTreeMap`, and sorted deterministically.
+/// The ordering follows the packed data, meaning it depends on the channel
+/// order.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use palette::{rgb::PackedArgb, Srgb};
+///
+/// let colors: HashSet<PackedArgb> = [
+///     Srgb::new(255u8, 0, 0),
+///     Srgb::new(0, 255, 0),
+///     Srgb::new(255, 0, 0),
+/// ]
+/// .iter()
+/// .map(|&color| PackedArgb::pack(color.into()))
+/// .collect();
+///
+/// assert_eq!(colors.len(), 2);
+/// ```
 #[repr(transparent)]
 pub struct Packed<O, P> {
     /// The color packed into a type `P`, such as `u32` or `[u8; 4]`.
@@ -96,6 +134,108 @@ impl<O, P> Packed<O, P> {
 
 impl<O, P> Copy for Packed<O, P> where P: Copy {}
 
+impl<O, P> Default for Packed<O, P>
+where
+    P: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            color: P::default(),
+            channel_order: PhantomData,
+        }
+    }
+}
+
+impl<O, P> PartialEq for Packed<O, P>
+where
+    P: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.color == other.color
+    }
+}
+
+impl<O, P> Eq for Packed<O, P> where P: Eq {}
+
+impl<O, P> PartialOrd for Packed<O, P>
+where
+    P: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.color.partial_cmp(&other.color)
+    }
+}
+
+impl<O, P> Ord for Packed<O, P>
+where
+    P: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.color.cmp(&other.color)
+    }
+}
+
+impl<O, P> Hash for Packed<O, P>
+where
+    P: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.color.hash(state)
+    }
+}
+
+/// Shows the packed integer as hexadecimal, followed by the unpacked RGBA
+/// channels.
+impl<O> fmt::Debug for Packed<O, u32>
+where
+    O: ComponentOrder<Rgba<Srgb, u8>, u32>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let color = O::unpack(self.color);
+
+        f.debug_struct("Packed")
+            .field("color", &format_args!("{:#010X}", self.color))
+            .field("red", &color.red)
+            .field("green", &color.green)
+            .field("blue", &color.blue)
+            .field("alpha", &color.alpha)
+            .finish()
+    }
+}
+
+macro_rules! impl_debug_uint {
+    ($($ty: ident => $width: literal),+) => {
+        $(
+            /// Shows the packed integer as hexadecimal.
+            impl<O> fmt::Debug for Packed<O, $ty> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_struct("Packed")
+                        .field("color", &format_args!(concat!("{:#0", $width, "X}"), self.color))
+                        .finish()
+                }
+            }
+        )+
+    };
+}
+
+impl_debug_uint!(u8 => 4, u16 => 6, u64 => 18, u128 => 34);
+
+impl<O, T, const N: usize> fmt::Debug for Packed<O, [T; N]>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Packed")
+            .field("color", &self.color)
+            .finish()
+    }
+}
+
 impl<O, P> Clone for Packed<O, P>
 where
     P: Clone,
@@ -170,6 +310,49 @@ unsafe impl<O, P> bytemuck::Zeroable for Packed<O, P> where P: bytemuck::Zeroabl
 #[cfg(feature = "bytemuck")]
 unsafe impl<O: 'static, P> bytemuck::Pod for Packed<O, P> where P: bytemuck::Pod {}
 
+/// Cast a slice of `u32` values to a slice of packed colors with the channel
+/// order `O`.
+///
+/// This is a shorthand for `cast::from_uint_slice::<Packed<O, u32>>`, for
+/// viewing a framebuffer of packed pixels as colors. The channel order
+/// describes the numeric value of each integer, such as `0xAARRGGBB` for
+/// [`Argb`](crate::rgb::channels::Argb), so the result is the same on little
+/// and big endian platforms. Only the order of the bytes in memory differs,
+/// which is not visible through the returned slice.
+///
+/// ```
+/// use palette::{cast, rgb::channels::Argb, Srgba};
+///
+/// let framebuffer = [0xFF17C64Cu32, 0x805D12D6];
+/// let colors = cast::packed_as::<Argb>(&framebuffer);
+///
+/// assert_eq!(Srgba::from(colors[0]), Srgba::new(0x17, 0xC6, 0x4C, 0xFF));
+/// assert_eq!(Srgba::from(colors[1]), Srgba::new(0x5D, 0x12, 0xD6, 0x80));
+/// ```
+#[inline]
+pub fn packed_as<O>(values: &[u32]) -> &[Packed<O, u32>] {
+    super::from_uint_slice(values)
+}
+
+/// Cast a mutable slice of `u32` values to a mutable slice of packed colors
+/// with the channel order `O`.
+///
+/// See [`packed_as`] for details about the channel order and endianness.
+///
+/// ```
+/// use palette::{cast, rgb::channels::Argb, Srgba};
+///
+/// let mut framebuffer = [0u32; 2];
+/// let colors = cast::packed_as_mut::<Argb>(&mut framebuffer);
+/// colors[1] = Srgba::new(0x5D, 0x12, 0xD6, 0x80).into();
+///
+/// assert_eq!(framebuffer, [0, 0x805D12D6]);
+/// ```
+#[inline]
+pub fn packed_as_mut<O>(values: &mut [u32]) -> &mut [Packed<O, u32>] {
+    super::from_uint_slice_mut(values)
+}
+
 /// Packs and unpacks color types with some component order.
 ///
 /// As an example, RGBA channels may be ordered as `ABGR`, `ARGB`, `BGRA`, or
@@ -257,3 +440,77 @@ where
         T::unpack(packed.to_be_bytes())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        rgb::{channels, PackedArgb, PackedRgba},
+        Srgba,
+    };
+
+    use super::Packed;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_set() {
+        use std::collections::HashSet;
+
+        let mut colors = HashSet::new();
+        assert!(colors.insert(PackedArgb::from(0xFF00FF00u32)));
+        assert!(colors.insert(PackedArgb::from(0xFFFF0000u32)));
+        assert!(!colors.insert(PackedArgb::pack(Srgba::new(0u8, 255, 0, 255))));
+
+        assert_eq!(colors.len(), 2);
+        assert!(colors.contains(&PackedArgb::from(0xFFFF0000u32)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sort() {
+        let mut colors = alloc::vec![
+            PackedRgba::from(0x00FF00FFu32),
+            PackedRgba::from(0xFF0000FF),
+            PackedRgba::from(0x0000FFFF),
+        ];
+        colors.sort();
+
+        assert_eq!(
+            colors,
+            [
+                PackedRgba::from(0x0000FFFFu32),
+                PackedRgba::from(0x00FF00FF),
+                PackedRgba::from(0xFF0000FF),
+            ]
+        );
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(<PackedArgb>::default().color, 0);
+        assert_eq!(Packed::<channels::Rgba, [u8; 4]>::default().color, [0; 4]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", PackedArgb::from(0xFF800040u32)),
+            "Packed { color: 0xFF800040, red: 128, green: 0, blue: 64, alpha: 255 }"
+        );
+        assert_eq!(
+            format!("{:?}", PackedRgba::from(0x0A0B0C0Du32)),
+            "Packed { color: 0x0A0B0C0D, red: 10, green: 11, blue: 12, alpha: 13 }"
+        );
+        assert_eq!(
+            format!("{:?}", Packed::<channels::Rgba, u64>::from(0xABCDu64)),
+            "Packed { color: 0x000000000000ABCD }"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                Packed::<channels::Bgra, [u8; 4]>::from([1, 2, 3, 4])
+            ),
+            "Packed { color: [1, 2, 3, 4] }"
+        );
+    }
+}
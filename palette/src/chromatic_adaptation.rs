@@ -46,7 +46,7 @@ use crate::{
         matrix::{Bradford, LmsToXyz, WithLmsMatrix, XyzToLms},
         Lms,
     },
-    matrix::{multiply_3x3, multiply_3x3_and_vec3, Mat3},
+    matrix::{multiply_3x3, multiply_3x3_and_vec3},
     num::{Arithmetics, Real, Zero},
     white_point::{Any, WhitePoint},
     xyz::meta::HasXyzMeta,
@@ -364,9 +364,9 @@ pub enum Method {
 )]
 pub struct ConeResponseMatrices<T> {
     ///3x3 matrix for the cone response domains
-    pub ma: Mat3<T>,
+    pub ma: [T; 9],
     ///3x3 matrix for the inverse of the cone response domains
-    pub inv_ma: Mat3<T>,
+    pub inv_ma: [T; 9],
 }
 
 /// Generates a conversion matrix to convert the Xyz tristimulus values from
@@ -391,7 +391,7 @@ where
         &self,
         source_wp: Xyz<Any, T>,
         destination_wp: Xyz<Any, T>,
-    ) -> Mat3<T> {
+    ) -> [T; 9] {
         let adapt = self.get_cone_response();
 
         let resp_src: Lms<Any, T> =
@@ -25,6 +25,22 @@
 //! | [`EuclideanDistance`] | Low | Medium to high for perceptually uniform spaces, otherwise low | Can be good enough for perceptually uniform spaces or as a "quick and dirty" check.
 //! | [`HyAb`] | Low | High accuracy for medium to large differences. Less accurate than CIEDE2000 for small differences, but still performs well and is much less computationally expensive. | Similar to Euclidean distance, but separates lightness and chroma more. Limited to Cartesian spaces with a lightness axis and a chroma plane.
 //! | [`Wcag21RelativeContrast`] | Low | Low and only compares lightness | Meant for checking contrasts in computer graphics (such as between text and background colors), assuming sRGB. Mostly useful as a hint or for checking WCAG 2.1 compliance, considering the criticism it has received.
+//!
+//! ## Transparent colors
+//!
+//! The difference and distance traits are also implemented for colors with an
+//! alpha component, such as [`Laba`][crate::Laba], but the alpha component is
+//! not part of any of the metrics. The result is the same as for the colors
+//! without their alpha components.
+//!
+//! ```
+//! use palette::{color_difference::Ciede2000, white_point::D65, Laba};
+//!
+//! let a = Laba::<D65, f32>::new(50.0, 10.0, -20.0, 1.0);
+//! let b = Laba::<D65, f32>::new(55.0, 12.0, -18.0, 0.2);
+//!
+//! assert_eq!(a.difference(b), a.color.difference(b.color));
+//! ```
 
 use core::ops::{Add, BitAnd, BitOr, Div, Mul};
 
@@ -290,7 +306,13 @@ pub trait EuclideanDistance: Sized {
 /// These criteria come with a couple of caveats:
 /// * sRGB is assumed as the presentation color space, which is why it's only
 ///   implemented for a limited set of [`Rgb`][crate::rgb::Rgb] and
-///   [`Luma`][crate::Luma] spaces.
+///   [`Luma`][crate::Luma] spaces, and the types that are derived from them.
+///   Color spaces that aren't tied to an RGB space, such as
+///   [`Lab`] and [`Oklch`][crate::Oklch], are converted to
+///   relative luminance directly, but will only be accurate for colors that
+///   are within the sRGB gamut.
+/// * 8 bit colors, such as `Srgb<u8>`, are converted to `f32` before
+///   calculating their relative luminance.
 /// * The contrast ratio is not considered entirely consistent with the
 ///   perceived contrast. WCAG 3.x is supposed to provide a better measurement.
 ///
@@ -501,7 +523,7 @@ mod test {
     use core::str::FromStr;
 
     use super::{HyAb, Wcag21RelativeContrast};
-    use crate::{FromColor, Lab, Srgb};
+    use crate::{encoding, luma::Luma, FromColor, Hsl, Hsv, Hwb, Lab, Lch, Oklab, Oklch, Srgb};
 
     #[test]
     fn relative_contrast() {
@@ -543,15 +565,117 @@ mod test {
         assert_relative_eq!(c1.relative_contrast(black), 17.11, epsilon = 0.01);
     }
 
+    #[test]
+    fn relative_contrast_u8() {
+        let white = Srgb::new(255u8, 255, 255);
+        let gray = Srgb::<u8>::from(0x777777);
+
+        assert_relative_eq!(gray.relative_contrast(white), 4.48, epsilon = 0.01);
+        assert!(!gray.has_min_contrast_text(white));
+        assert!(gray.has_min_contrast_large_text(white));
+
+        let gray = Luma::<encoding::Srgb, u8>::new(0x77);
+        let white = Luma::<encoding::Srgb, u8>::new(255);
+        assert_relative_eq!(gray.relative_contrast(white), 4.48, epsilon = 0.01);
+    }
+
+    #[test]
+    fn relative_contrast_other_types() {
+        let foreground = Srgb::new(0.4f64, 0.2, 0.6);
+        let background = Srgb::new(0.9, 0.95, 0.8);
+        let expected = foreground.relative_contrast(background);
+
+        assert_relative_eq!(
+            Hsl::from_color(foreground).relative_contrast(Hsl::from_color(background)),
+            expected,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            Hsv::from_color(foreground).relative_contrast(Hsv::from_color(background)),
+            expected,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            Hwb::from_color(foreground).relative_contrast(Hwb::from_color(background)),
+            expected,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            Lab::from_color(foreground).relative_contrast(Lab::from_color(background)),
+            expected,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            Lch::from_color(foreground).relative_contrast(Lch::from_color(background)),
+            expected,
+            epsilon = 0.000001
+        );
+        // Oklab goes through its own XYZ matrices, which are slightly less
+        // precise.
+        assert_relative_eq!(
+            Oklab::from_color(foreground).relative_contrast(Oklab::from_color(background)),
+            expected,
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            Oklch::from_color(foreground).relative_contrast(Oklch::from_color(background)),
+            expected,
+            epsilon = 0.001
+        );
+    }
+
     #[test]
     fn hyab() {
-        // From https://github.com/Evercoder/culori/blob/cd1fe08a12fa9ddfcf6b2e82914733d23ac117d0/test/difference.test.js#L186
+        // From https://github.com/Evercoder/culori/blob/cd1fe08a12fa9ddfcf6b2e82914733d23ac117d0/test/difference.test.js#L186.
+        // Culori rounds the sRGB matrices to 7 decimals, while they are derived
+        // from the primaries here, so the results differ slightly.
         let red = Lab::<_, f64>::from_color(Srgb::from(0xff0000).into_linear());
         let green = Lab::<_, f64>::from_color(Srgb::from(0x008000).into_linear());
         assert_relative_eq!(
             red.hybrid_distance(green),
             139.93576718451553,
-            epsilon = 0.000001
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn alpha_is_ignored() {
+        use super::{Ciede2000, DeltaE, EuclideanDistance, ImprovedCiede2000, ImprovedDeltaE};
+        use crate::{white_point::D65, Laba, Lcha, Oklaba};
+
+        let lab1 = Laba::<D65, f64>::new(50.0, 10.0, -20.0, 1.0);
+        let lab2 = Laba::<D65, f64>::new(55.0, 12.0, -18.0, 0.2);
+        assert_eq!(lab1.difference(lab2), lab1.color.difference(lab2.color));
+        assert_eq!(
+            lab1.improved_difference(lab2),
+            lab1.color.improved_difference(lab2.color)
+        );
+        assert_eq!(lab1.distance(lab2), lab1.color.distance(lab2.color));
+        assert_eq!(
+            lab1.distance_squared(lab2),
+            lab1.color.distance_squared(lab2.color)
+        );
+        assert_eq!(
+            lab1.hybrid_distance(lab2),
+            lab1.color.hybrid_distance(lab2.color)
+        );
+        assert_eq!(lab1.delta_e(lab2), lab1.color.delta_e(lab2.color));
+        assert_eq!(
+            lab1.improved_delta_e(lab2),
+            lab1.color.improved_delta_e(lab2.color)
+        );
+
+        let lch1 = Lcha::<D65, f64>::from_color(lab1);
+        let lch2 = Lcha::<D65, f64>::from_color(lab2);
+        assert_eq!(lch1.difference(lch2), lch1.color.difference(lch2.color));
+        assert_eq!(lch1.delta_e(lch2), lch1.color.delta_e(lch2.color));
+
+        let oklab1 = Oklaba::new(0.5f64, 0.1, -0.05, 0.0);
+        let oklab2 = Oklaba::new(0.6f64, 0.05, -0.1, 1.0);
+        assert_eq!(oklab1.distance(oklab2), oklab1.color.distance(oklab2.color));
+        assert_eq!(
+            oklab1.hybrid_distance(oklab2),
+            oklab1.color.hybrid_distance(oklab2.color)
         );
     }
 }
@@ -15,8 +15,20 @@
 //! lets you use any hue based color space. Some traits are also implemented for
 //! other color spaces, when it's possible to avoid converting them to their hue
 //! based counterparts.
+//!
+//! The hues are always shifted in the color's own hue space, so the result
+//! depends on which color space is used. An RGB based space, such as
+//! [`Hsl`][crate::Hsl] or [`Hsv`][crate::Hsv], places its hues differently from
+//! a Lab based space, such as [`Oklch`][crate::Oklch] or [`Lch`][crate::Lch].
+//! For example, a 120° shift takes pure red to pure green in `Hsl`, but to a
+//! more cyan-ish green in `Oklch`. [`Rgb`][crate::rgb::Rgb] itself is shifted
+//! in `Oklch`.
 
-use crate::{angle::HalfRotation, num::Real, ShiftHue};
+use crate::{
+    angle::HalfRotation,
+    num::{Arithmetics, Real},
+    ShiftHue,
+};
 
 /// Represents the complementary color scheme.
 ///
@@ -272,6 +284,30 @@ pub trait Tetradic: Sized {
     /// assert_eq!(hues, (120.0, 210.0, 300.0, 30.0));
     /// ```
     fn tetradic(self) -> (Self, Self, Self);
+
+    /// Return the three additional colors of a square color scheme.
+    ///
+    /// This is the same as [`tetradic`][Tetradic::tetradic], since the
+    /// tetradic colors are already placed in a square on the color wheel.
+    ///
+    /// ```
+    /// use palette::{Hsl, color_theory::Tetradic};
+    ///
+    /// let primary = Hsl::new_srgb(120.0f32, 0.8, 0.5);
+    /// let (square1, square2, square3) = primary.square();
+    ///
+    /// let hues = (
+    ///     square1.hue.into_positive_degrees(),
+    ///     square2.hue.into_positive_degrees(),
+    ///     square3.hue.into_positive_degrees(),
+    /// );
+    ///
+    /// assert_eq!(hues, (210.0, 300.0, 30.0));
+    /// ```
+    #[inline]
+    fn square(self) -> (Self, Self, Self) {
+        self.tetradic()
+    }
 }
 
 impl<T> Tetradic for T
@@ -287,3 +323,133 @@ where
         (first, second, third)
     }
 }
+
+/// Builds color schemes with any number of hue shifted colors.
+///
+/// The colors are returned as fixed-size arrays, which makes it possible to
+/// use this without allocating.
+pub trait HueScheme: ShiftHue + Sized {
+    /// Return one color for each hue shift in `hue_shifts`, in the same order.
+    ///
+    /// The following example makes a 4 color scheme, where the input color is
+    /// included as the first color:
+    ///
+    /// <div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: hsl(120deg, 80%, 50%);"></div>
+    /// <div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: hsl(180deg, 80%, 50%);"></div>
+    /// <div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: hsl(300deg, 80%, 50%);"></div>
+    /// <div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: hsl(0deg, 80%, 50%);"></div>
+    ///
+    /// ```
+    /// use palette::{Hsl, color_theory::HueScheme};
+    ///
+    /// let primary = Hsl::new_srgb(120.0f32, 0.8, 0.5);
+    /// let scheme = primary.hue_scheme([0.0, 60.0, 180.0, 240.0]);
+    ///
+    /// let hues = scheme.map(|color| color.hue.into_positive_degrees());
+    ///
+    /// assert_eq!(hues, [120.0, 180.0, 300.0, 0.0]);
+    /// ```
+    fn hue_scheme<const N: usize>(self, hue_shifts: [Self::Scalar; N]) -> [Self; N];
+
+    /// Return an analogous color scheme of `N` colors, with `spread` degrees
+    /// between each neighboring color.
+    ///
+    /// The colors are ordered by ascending hue and centered around `self`,
+    /// which is included in the middle when `N` is odd.
+    ///
+    /// The following example makes a 4 color analogous scheme:
+    ///
+    /// <div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: hsl(90deg, 80%, 50%);"></div>
+    /// <div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: hsl(110deg, 80%, 50%);"></div>
+    /// <div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: hsl(130deg, 80%, 50%);"></div>
+    /// <div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: hsl(150deg, 80%, 50%);"></div>
+    ///
+    /// ```
+    /// use palette::{Hsl, color_theory::HueScheme};
+    ///
+    /// let primary = Hsl::new_srgb(120.0f32, 0.8, 0.5);
+    /// let scheme: [_; 4] = primary.analogous_n(20.0);
+    ///
+    /// let hues = scheme.map(|color| color.hue.into_positive_degrees());
+    ///
+    /// assert_eq!(hues, [90.0, 110.0, 130.0, 150.0]);
+    /// ```
+    fn analogous_n<const N: usize>(self, spread: Self::Scalar) -> [Self; N];
+}
+
+impl<T> HueScheme for T
+where
+    T: ShiftHue + Clone,
+    T::Scalar: Real + Arithmetics + Clone,
+{
+    fn hue_scheme<const N: usize>(self, hue_shifts: [Self::Scalar; N]) -> [Self; N] {
+        hue_shifts.map(|shift| self.clone().shift_hue(shift))
+    }
+
+    fn analogous_n<const N: usize>(self, spread: Self::Scalar) -> [Self; N] {
+        let center = (N as f64 - 1.0) / 2.0;
+
+        let mut index = 0;
+        let hue_shifts = [(); N].map(|_| {
+            let steps = T::Scalar::from_f64(index as f64 - center);
+            index += 1;
+
+            steps * spread.clone()
+        });
+
+        self.hue_scheme(hue_shifts)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Analogous, Complementary, HueScheme, SplitComplementary, Tetradic, Triadic};
+    use crate::Hsl;
+
+    fn hues<const N: usize>(colors: [Hsl<crate::encoding::Srgb, f64>; N]) -> [f64; N] {
+        colors.map(|color| color.hue.into_positive_degrees())
+    }
+
+    #[test]
+    fn scheme_hues() {
+        let color = Hsl::new_srgb(40.0, 0.6, 0.3);
+
+        assert_eq!(hues([color.complementary()]), [220.0]);
+
+        let (first, second) = color.split_complementary();
+        assert_eq!(hues([first, second]), [190.0, 250.0]);
+
+        let (first, second) = color.analogous();
+        assert_eq!(hues([first, second]), [10.0, 70.0]);
+
+        let (first, second) = color.triadic();
+        assert_eq!(hues([first, second]), [160.0, 280.0]);
+
+        let (first, second, third) = color.tetradic();
+        assert_eq!(hues([first, second, third]), [130.0, 220.0, 310.0]);
+
+        let (first, second, third) = color.square();
+        assert_eq!(hues([first, second, third]), [130.0, 220.0, 310.0]);
+
+        assert_eq!(hues(color.analogous_n(15.0)), [25.0, 40.0, 55.0]);
+        assert_eq!(hues(color.analogous_n(30.0)), [355.0, 25.0, 55.0, 85.0]);
+        assert_eq!(hues(color.analogous_n::<0>(30.0)), [0.0f64; 0]);
+    }
+
+    #[test]
+    fn schemes_keep_saturation_and_lightness() {
+        let color = Hsl::new_srgb(40.0, 0.6, 0.3);
+
+        let (first, second, third) = color.tetradic();
+        let (fourth, fifth) = color.split_complementary();
+        let scheme: [_; 5] = color.analogous_n(45.0);
+
+        for other in [first, second, third, fourth, fifth]
+            .iter()
+            .chain(scheme.iter())
+        {
+            assert_eq!(other.saturation, color.saturation);
+            assert_eq!(other.lightness, color.lightness);
+        }
+    }
+}
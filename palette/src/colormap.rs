@@ -0,0 +1,632 @@
+//! Colormaps for scientific visualization.
+//!
+//! This module contains the perceptually uniform colormaps from matplotlib,
+//! [`viridis`], [`magma`], [`inferno`] and [`plasma`], as well as Google's
+//! [`turbo`]. They map a value in `[0.0, 1.0]` to an sRGB color, without
+//! allocating:
+//!
+//! ```
+//! use palette::{colormap, Srgb};
+//!
+//! let low: Srgb<u8> = colormap::viridis(0.0).into_format();
+//! let high: Srgb<u8> = colormap::viridis(1.0).into_format();
+//! ```
+//!
+//! [`Colormap`] can be used for choosing the colormap at runtime and for
+//! getting the colors as any color type that sRGB can be converted into:
+//!
+//! ```
+//! use palette::{colormap::Colormap, Oklch};
+//!
+//! let color: Oklch = Colormap::Magma.get(0.5);
+//! ```
+//!
+//! ## Accuracy
+//!
+//! The colormaps are stored as their published 256 entry tables, and values
+//! between the entries are linearly interpolated. The table entries are
+//! reproduced exactly at `i / 255.0`, for `i` in `0..=255`.
+//!
+//! Input values outside `[0.0, 1.0]` are clamped, and NaN is treated as `0.0`,
+//! so the result is always a valid sRGB color.
+//!
+//! ## Custom Colormaps
+//!
+//! [`diverging`] and [`cyclic`] build colormaps in [`Oklch`], from a few
+//! parameters. Colors that end up outside the sRGB gamut along the way get
+//! their chroma reduced until they fit, which keeps their lightness and hue.
+//!
+//! ```
+//! use palette::{colormap, Oklch, Srgb};
+//!
+//! let blue_red = colormap::diverging(
+//!     Oklch::new(0.45, 0.15, 260.0),
+//!     Oklch::new(0.45, 0.15, 25.0),
+//!     0.95,
+//! );
+//! let legend: Vec<Srgb> = blue_red.take(9).collect();
+//!
+//! let phase = colormap::cyclic(0.0);
+//! let color: Srgb = phase.get(0.25);
+//! ```
+
+mod tables;
+
+use crate::{
+    convert::{FromColorUnclamped, IntoColor},
+    hues::OklabHue,
+    IsWithinBounds, LinSrgb, Oklab, Oklch, Srgb,
+};
+
+/// A selection of colormaps, for when the colormap is chosen at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Colormap {
+    /// The [`viridis`] colormap.
+    Viridis,
+    /// The [`magma`] colormap.
+    Magma,
+    /// The [`inferno`] colormap.
+    Inferno,
+    /// The [`plasma`] colormap.
+    Plasma,
+    /// The [`turbo`] colormap.
+    Turbo,
+}
+
+impl Colormap {
+    /// Get the sRGB color at `t`, where `t` is in `[0.0, 1.0]`.
+    ///
+    /// ```
+    /// use palette::colormap::{self, Colormap};
+    ///
+    /// assert_eq!(Colormap::Plasma.srgb(0.3), colormap::plasma(0.3));
+    /// ```
+    #[inline]
+    pub fn srgb(self, t: f32) -> Srgb<f32> {
+        match self {
+            Colormap::Viridis => viridis(t),
+            Colormap::Magma => magma(t),
+            Colormap::Inferno => inferno(t),
+            Colormap::Plasma => plasma(t),
+            Colormap::Turbo => turbo(t),
+        }
+    }
+
+    /// Get the color at `t`, where `t` is in `[0.0, 1.0]`, converted to `C`.
+    ///
+    /// ```
+    /// use palette::{colormap::Colormap, Hsl, Lab, LinSrgb};
+    ///
+    /// let lab: Lab = Colormap::Viridis.get(0.25);
+    /// let hsl: Hsl = Colormap::Inferno.get(0.75);
+    /// let linear: LinSrgb = Colormap::Turbo.get(0.5);
+    /// ```
+    #[inline]
+    pub fn get<C>(self, t: f32) -> C
+    where
+        Srgb<f32>: IntoColor<C>,
+    {
+        self.srgb(t).into_color()
+    }
+}
+
+/// The viridis colormap from matplotlib, from dark blue to yellow.
+///
+/// ```
+/// use palette::{colormap, Srgb};
+///
+/// let color: Srgb<u8> = colormap::viridis(0.5).into_format();
+/// ```
+#[inline]
+pub fn viridis(t: f32) -> Srgb<f32> {
+    interpolate_table(&tables::VIRIDIS, t)
+}
+
+/// The magma colormap from matplotlib, from black to light yellow, via purple.
+///
+/// ```
+/// use palette::{colormap, Srgb};
+///
+/// let color: Srgb<u8> = colormap::magma(0.5).into_format();
+/// ```
+#[inline]
+pub fn magma(t: f32) -> Srgb<f32> {
+    interpolate_table(&tables::MAGMA, t)
+}
+
+/// The inferno colormap from matplotlib, from black to light yellow, via red.
+///
+/// ```
+/// use palette::{colormap, Srgb};
+///
+/// let color: Srgb<u8> = colormap::inferno(0.5).into_format();
+/// ```
+#[inline]
+pub fn inferno(t: f32) -> Srgb<f32> {
+    interpolate_table(&tables::INFERNO, t)
+}
+
+/// The plasma colormap from matplotlib, from dark blue to yellow, via pink.
+///
+/// ```
+/// use palette::{colormap, Srgb};
+///
+/// let color: Srgb<u8> = colormap::plasma(0.5).into_format();
+/// ```
+#[inline]
+pub fn plasma(t: f32) -> Srgb<f32> {
+    interpolate_table(&tables::PLASMA, t)
+}
+
+/// The turbo colormap from Google, a rainbow colormap from dark blue to dark
+/// red.
+///
+/// Turbo isn't perceptually uniform, but it has smoother transitions than
+/// most rainbow colormaps.
+///
+/// ```
+/// use palette::{colormap, Srgb};
+///
+/// let color: Srgb<u8> = colormap::turbo(0.5).into_format();
+/// ```
+#[inline]
+pub fn turbo(t: f32) -> Srgb<f32> {
+    interpolate_table(&tables::TURBO, t)
+}
+
+/// Create a diverging colormap, from `low` to `high` via a neutral gray.
+///
+/// The first half of the map goes from `low` to a gray with
+/// `neutral_lightness`, and the second half goes from that gray to `high`.
+/// Lightness and chroma are interpolated linearly on each side, while the hue
+/// stays the same as the endpoint's. This makes the lightness change
+/// monotonically on both sides of the middle.
+///
+/// ```
+/// use palette::{colormap, Oklch};
+///
+/// let map = colormap::diverging(
+///     Oklch::new(0.4, 0.15, 260.0),
+///     Oklch::new(0.4, 0.15, 40.0),
+///     0.9,
+/// );
+///
+/// let middle: Oklch = map.get(0.5);
+/// assert_eq!(middle.l, 0.9);
+/// assert_eq!(middle.chroma, 0.0);
+/// ```
+#[inline]
+pub fn diverging(low: Oklch<f32>, high: Oklch<f32>, neutral_lightness: f32) -> Diverging {
+    Diverging {
+        low,
+        high,
+        neutral_lightness,
+    }
+}
+
+/// Create a cyclic colormap, that goes around the hue circle in [`Oklch`],
+/// starting at `base_hue`.
+///
+/// The lightness and chroma stay the same for all hues, which makes the map
+/// isoluminant. The default lightness and chroma are chosen to fit all hues
+/// in the sRGB gamut, and can be changed in the returned [`Cyclic`].
+///
+/// ```
+/// use palette::{colormap, Oklch};
+///
+/// let map = colormap::cyclic(30.0);
+///
+/// let start: Oklch = map.get(0.0);
+/// let end: Oklch = map.get(1.0);
+/// assert_eq!(start, end);
+/// ```
+#[inline]
+pub fn cyclic<H: Into<OklabHue<f32>>>(base_hue: H) -> Cyclic {
+    Cyclic {
+        base_hue: base_hue.into(),
+        lightness: 0.75,
+        chroma: 0.125,
+    }
+}
+
+/// A diverging colormap, created with [`diverging`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Diverging {
+    /// The color at `0.0`.
+    pub low: Oklch<f32>,
+
+    /// The color at `1.0`.
+    pub high: Oklch<f32>,
+
+    /// The lightness of the neutral gray at `0.5`.
+    pub neutral_lightness: f32,
+}
+
+impl Diverging {
+    /// Get the color at `t`, where `t` is in `[0.0, 1.0]`, converted to `C`.
+    #[inline]
+    pub fn get<C>(&self, t: f32) -> C
+    where
+        Oklch<f32>: IntoColor<C>,
+    {
+        self.oklch(t).into_color()
+    }
+
+    /// Take `n` evenly spaced colors, from `0.0` to `1.0`, converted to `C`.
+    ///
+    /// A single color is taken from the neutral middle.
+    #[inline]
+    pub fn take<C>(self, n: usize) -> impl Iterator<Item = C>
+    where
+        Oklch<f32>: IntoColor<C>,
+    {
+        (0..n).map(move |index| {
+            let t = if n > 1 {
+                index as f32 / (n - 1) as f32
+            } else {
+                0.5
+            };
+            self.get(t)
+        })
+    }
+
+    fn oklch(&self, t: f32) -> Oklch<f32> {
+        let t = clamp_input(t);
+
+        let (end, factor) = if t < 0.5 {
+            (self.low, 1.0 - t * 2.0)
+        } else {
+            (self.high, t * 2.0 - 1.0)
+        };
+
+        let lightness = self.neutral_lightness + (end.l - self.neutral_lightness) * factor;
+        fit_chroma(Oklch::new(lightness, end.chroma * factor, end.hue))
+    }
+}
+
+/// A cyclic colormap, created with [`cyclic`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cyclic {
+    /// The hue at `0.0` and `1.0`.
+    pub base_hue: OklabHue<f32>,
+
+    /// The lightness of all colors in the map.
+    pub lightness: f32,
+
+    /// The chroma of all colors in the map. It's reduced for the hues where it
+    /// doesn't fit in the sRGB gamut.
+    pub chroma: f32,
+}
+
+impl Cyclic {
+    /// Get the color at `t`, where `t` is in `[0.0, 1.0]`, converted to `C`.
+    #[inline]
+    pub fn get<C>(&self, t: f32) -> C
+    where
+        Oklch<f32>: IntoColor<C>,
+    {
+        self.oklch(t).into_color()
+    }
+
+    /// Take `n` evenly spaced colors around the hue circle, converted to `C`.
+    ///
+    /// The colors start at `0.0` and stop before `1.0`, since it's the same as
+    /// `0.0`.
+    #[inline]
+    pub fn take<C>(self, n: usize) -> impl Iterator<Item = C>
+    where
+        Oklch<f32>: IntoColor<C>,
+    {
+        (0..n).map(move |index| self.get(index as f32 / n as f32))
+    }
+
+    fn oklch(&self, t: f32) -> Oklch<f32> {
+        let hue = self.base_hue + clamp_input(t) * 360.0;
+        fit_chroma(Oklch::new(self.lightness, self.chroma, hue))
+    }
+}
+
+// Clamps `t` to `[0.0, 1.0]`. NaN fails the comparison and becomes 0.
+#[inline]
+fn clamp_input(t: f32) -> f32 {
+    if t > 0.0 {
+        t.min(1.0)
+    } else {
+        0.0
+    }
+}
+
+// Reduces the chroma of `color` until it's within the sRGB gamut, while
+// keeping its lightness and hue.
+fn fit_chroma(color: Oklch<f32>) -> Oklch<f32> {
+    let fits = |chroma: f32| {
+        let oklab = Oklab::from_color_unclamped(Oklch::new(color.l, chroma, color.hue));
+        LinSrgb::from_color_unclamped(oklab).is_within_bounds()
+    };
+
+    if fits(color.chroma) {
+        return color;
+    }
+
+    let factor = crate::oklab::max_chroma_factor(false, |factor: f32| fits(color.chroma * factor));
+    Oklch::new(color.l, color.chroma * factor, color.hue)
+}
+
+// Looks up `t` in `table`, with linear interpolation between the entries.
+#[inline]
+fn interpolate_table(table: &[[f32; 3]; 256], t: f32) -> Srgb<f32> {
+    let position = clamp_input(t) * 255.0;
+    let index = (position as usize).min(254);
+    let factor = position - index as f32;
+
+    let [red1, green1, blue1] = table[index];
+    let [red2, green2, blue2] = table[index + 1];
+    let mix = |from: f32, to: f32| (from * (1.0 - factor) + to * factor).clamp(0.0, 1.0);
+
+    Srgb::new(mix(red1, red2), mix(green1, green2), mix(blue1, blue2))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{cyclic, diverging, inferno, magma, plasma, tables, turbo, viridis, Colormap};
+    use crate::{convert::FromColorUnclamped, IsWithinBounds, Lab, LinSrgb, Oklab, Oklch, Srgb};
+
+    // Reference colors at 0, 0.25, 0.5, 0.75 and 1, from the published
+    // tables. The middle entries are the ones matplotlib picks for those
+    // values.
+    const REFERENCE_ENTRIES: [u8; 5] = [0, 64, 128, 192, 255];
+    const VIRIDIS_REFERENCE: [u32; 5] = [0x440154, 0x3b528b, 0x21918c, 0x5ec962, 0xfde725];
+    const MAGMA_REFERENCE: [u32; 5] = [0x000004, 0x51127c, 0xb73779, 0xfc8961, 0xfcfdbf];
+    const INFERNO_REFERENCE: [u32; 5] = [0x000004, 0x57106e, 0xbc3754, 0xf98e09, 0xfcffa4];
+    const PLASMA_REFERENCE: [u32; 5] = [0x0d0887, 0x7e03a8, 0xcc4778, 0xf89540, 0xf0f921];
+    const TURBO_REFERENCE: [u32; 5] = [0x30123b, 0x28bceb, 0xa4fc3c, 0xfb7e21, 0x7a0403];
+
+    fn assert_close_to_reference(colormap: fn(f32) -> Srgb<f32>, reference: [u32; 5]) {
+        for (&entry, &hex) in REFERENCE_ENTRIES.iter().zip(&reference) {
+            let color: Srgb<u8> = colormap(f32::from(entry) / 255.0).into_format();
+            let expected = Srgb::<u8>::from(hex);
+
+            for (&channel, &expected_channel) in [color.red, color.green, color.blue].iter().zip(&[
+                expected.red,
+                expected.green,
+                expected.blue,
+            ]) {
+                let difference = (i16::from(channel) - i16::from(expected_channel)).abs();
+                assert!(
+                    difference <= 1,
+                    "{:?} is too far from {:?} at entry {}",
+                    color,
+                    expected,
+                    entry
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn viridis_reference() {
+        assert_close_to_reference(viridis, VIRIDIS_REFERENCE);
+    }
+
+    #[test]
+    fn magma_reference() {
+        assert_close_to_reference(magma, MAGMA_REFERENCE);
+    }
+
+    #[test]
+    fn inferno_reference() {
+        assert_close_to_reference(inferno, INFERNO_REFERENCE);
+    }
+
+    #[test]
+    fn plasma_reference() {
+        assert_close_to_reference(plasma, PLASMA_REFERENCE);
+    }
+
+    #[test]
+    fn turbo_reference() {
+        assert_close_to_reference(turbo, TURBO_REFERENCE);
+    }
+
+    #[test]
+    fn reproduces_table_entries() {
+        for &(colormap, table) in &[
+            (viridis as fn(f32) -> Srgb<f32>, &tables::VIRIDIS),
+            (magma, &tables::MAGMA),
+            (inferno, &tables::INFERNO),
+            (plasma, &tables::PLASMA),
+            (turbo, &tables::TURBO),
+        ] {
+            for (index, &[red, green, blue]) in table.iter().enumerate() {
+                let color = colormap(index as f32 / 255.0);
+                assert_relative_eq!(color, Srgb::new(red, green, blue), epsilon = 0.000001);
+            }
+        }
+    }
+
+    #[test]
+    fn interpolates_between_entries() {
+        let [red1, green1, blue1] = tables::TURBO[100];
+        let [red2, green2, blue2] = tables::TURBO[101];
+        let expected = Srgb::new(
+            (red1 + red2) / 2.0,
+            (green1 + green2) / 2.0,
+            (blue1 + blue2) / 2.0,
+        );
+
+        assert_relative_eq!(turbo(100.5 / 255.0), expected, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn turbo_shape() {
+        // Blue, via green, to red, with dark ends.
+        let low = turbo(0.1);
+        let middle = turbo(0.5);
+        let high = turbo(0.9);
+
+        assert!(low.blue > low.red && low.blue > low.green);
+        assert!(middle.green > middle.red && middle.green > middle.blue);
+        assert!(high.red > high.green && high.red > high.blue);
+
+        for &end in &[turbo(0.0), turbo(1.0)] {
+            assert!(end.red + end.green + end.blue < 1.0);
+        }
+    }
+
+    #[test]
+    fn clamps_input() {
+        for &colormap in &[
+            Colormap::Viridis,
+            Colormap::Magma,
+            Colormap::Inferno,
+            Colormap::Plasma,
+            Colormap::Turbo,
+        ] {
+            assert_eq!(colormap.srgb(-1.0), colormap.srgb(0.0));
+            assert_eq!(colormap.srgb(2.0), colormap.srgb(1.0));
+            assert_eq!(colormap.srgb(f32::NAN), colormap.srgb(0.0));
+
+            for step in 0..=100 {
+                let color = colormap.srgb(step as f32 / 100.0);
+                for &channel in &[color.red, color.green, color.blue] {
+                    assert!((0.0..=1.0).contains(&channel));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn perceptually_uniform_maps_increase_in_lightness() {
+        for &colormap in &[
+            Colormap::Viridis,
+            Colormap::Magma,
+            Colormap::Inferno,
+            Colormap::Plasma,
+        ] {
+            let mut previous: Lab = colormap.get(0.0);
+            for step in 1..=20 {
+                let current: Lab = colormap.get(step as f32 / 20.0);
+                assert!(current.l > previous.l, "{:?} at {}", colormap, step);
+                previous = current;
+            }
+        }
+    }
+
+    fn is_in_gamut(color: Oklch<f32>) -> bool {
+        LinSrgb::from_color_unclamped(Oklab::from_color_unclamped(color)).is_within_bounds()
+    }
+
+    #[test]
+    fn diverging_monotonic_lightness() {
+        let map = diverging(
+            Oklch::new(0.35, 0.2, 260.0),
+            Oklch::new(0.5, 0.25, 30.0),
+            0.95,
+        );
+
+        let samples = 2000;
+        let mut previous: Oklch<f32> = map.get(0.0);
+        for step in 1..=samples {
+            let t = step as f32 / samples as f32;
+            let current: Oklch<f32> = map.get(t);
+
+            if t <= 0.5 {
+                assert!(current.l >= previous.l, "{} at {}", current.l, t);
+            } else {
+                assert!(current.l <= previous.l, "{} at {}", current.l, t);
+            }
+
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn diverging_endpoints_and_middle() {
+        let low = Oklch::new(0.4, 0.1, 260.0);
+        let high = Oklch::new(0.45, 0.1, 40.0);
+        let map = diverging(low, high, 0.9);
+
+        assert_eq!(map.get::<Oklch>(0.0), low);
+        assert_eq!(map.get::<Oklch>(1.0), high);
+        assert_eq!(map.get::<Oklch>(0.5), Oklch::new(0.9, 0.0, 40.0));
+
+        let colors: Vec<Oklch> = map.take(5).collect();
+        assert_eq!(colors.len(), 5);
+        assert_eq!(colors[0], low);
+        assert_eq!(colors[2].chroma, 0.0);
+        assert_eq!(colors[4], high);
+
+        let single: Vec<Oklch> = map.take(1).collect();
+        assert_eq!(single, [map.get::<Oklch>(0.5)]);
+    }
+
+    #[test]
+    fn diverging_reduces_chroma() {
+        // The endpoints are far outside the sRGB gamut.
+        let map = diverging(
+            Oklch::new(0.4, 0.5, 260.0),
+            Oklch::new(0.6, 0.5, 140.0),
+            0.9,
+        );
+
+        for step in 0..=100 {
+            let t = step as f32 / 100.0;
+            let color: Oklch<f32> = map.get(t);
+            let (end, factor) = if t < 0.5 {
+                (map.low, 1.0 - t * 2.0)
+            } else {
+                (map.high, t * 2.0 - 1.0)
+            };
+
+            assert!(is_in_gamut(color), "{:?} at {}", color, t);
+            assert!(color.chroma <= 0.5 * factor);
+            assert_eq!(color.l, 0.9 + (end.l - 0.9) * factor);
+            assert_eq!(color.hue, end.hue);
+        }
+
+        // Clamping each channel would have changed the hue.
+        let low: Srgb = map.get(0.0);
+        let low = Oklch::from_color_unclamped(Oklab::from_color_unclamped(low.into_linear()));
+        assert!((low.hue - map.low.hue).into_degrees().abs() < 0.1);
+    }
+
+    #[test]
+    fn cyclic_is_isoluminant() {
+        let map = cyclic(90.0);
+
+        let start: Oklch = map.get(0.0);
+        assert_eq!(start.hue, 90.0);
+        assert_eq!(start, map.get(1.0));
+
+        for step in 0..=360 {
+            let color: Oklch = map.get(step as f32 / 360.0);
+            assert_eq!(color.l, 0.75);
+            assert_eq!(color.chroma, 0.125);
+            assert!(is_in_gamut(color));
+        }
+    }
+
+    #[test]
+    fn cyclic_take() {
+        let map = cyclic(0.0);
+        let colors: Vec<Oklch> = map.take(4).collect();
+
+        assert_eq!(colors.len(), 4);
+        for (color, hue) in colors.iter().zip(&[0.0, 90.0, 180.0, 270.0]) {
+            assert!((color.hue - *hue).into_degrees().abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn cyclic_reduces_chroma() {
+        let mut map = cyclic(0.0);
+        map.chroma = 0.3;
+
+        for step in 0..=360 {
+            let color: Oklch = map.get(step as f32 / 360.0);
+            assert_eq!(color.l, 0.75);
+            assert!(color.chroma <= 0.3);
+            assert!(is_in_gamut(color));
+        }
+    }
+}
@@ -44,6 +44,14 @@
 //! methods. They are possible to work around by splitting the conversion into
 //! multiple steps.
 //!
+//! # Converting From References
+//!
+//! The [`FromColorRef`] and [`IntoColorRef`] traits, and their unclamped
+//! counterparts, are implemented for all colors that can be copied. They are
+//! convenient when the colors are only borrowed, such as when iterating over a
+//! slice. The [`slice_into_color`] function converts a whole slice into a new
+//! `Vec`.
+//!
 //! # In-place Conversion
 //!
 //! It's possible for some color spaces to be converted in-place, meaning the
@@ -214,6 +222,11 @@
 //! * `luma_standard = "some::rgb_standard::Type"`: Sets the Luma standard type
 //!   that should be used when deriving, similar to `rgb_standard`.
 //!
+//! * `bound = "T: SomeTrait, Wp: OtherTrait<T>"`: Adds extra bounds to the
+//!   `where` clauses of the derived implementations. This is useful when the
+//!   manual implementations have requirements that the generic parameters
+//!   don't express on their own.
+//!
 //! ### Field Attributes
 //!
 //! * `alpha`: Specifies field as the color's transparency value.
@@ -343,6 +356,74 @@
 //! }
 //! ```
 //!
+//! With a generic white point:
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! use core::marker::PhantomData;
+//!
+//! use palette::convert::{FromColorUnclamped, IntoColorUnclamped};
+//! use palette::num::Real;
+//! use palette::white_point::{WhitePoint, D50};
+//! use palette::{Lab, Xyz};
+//!
+//! /// XYZ, but with components from 0 to 100.
+//! #[derive(Copy, Clone, PartialEq, Debug, FromColorUnclamped)]
+//! #[palette(
+//!     component = "T",
+//!     white_point = "Wp",
+//!     bound = "T: Real + core::ops::Mul<Output = T> + core::ops::Div<Output = T>",
+//! )]
+//! struct Xyz100<Wp, T> {
+//!     x: T,
+//!     y: T,
+//!     z: T,
+//!     white_point: PhantomData<Wp>,
+//! }
+//!
+//! impl<Wp, T> FromColorUnclamped<Xyz<Wp, T>> for Xyz100<Wp, T>
+//! where
+//!     Wp: WhitePoint<T>,
+//!     T: Real + core::ops::Mul<Output = T>,
+//! {
+//!     fn from_color_unclamped(color: Xyz<Wp, T>) -> Self {
+//!         Xyz100 {
+//!             x: color.x * T::from_f64(100.0),
+//!             y: color.y * T::from_f64(100.0),
+//!             z: color.z * T::from_f64(100.0),
+//!             white_point: PhantomData,
+//!         }
+//!     }
+//! }
+//!
+//! impl<Wp, T> FromColorUnclamped<Xyz100<Wp, T>> for Xyz<Wp, T>
+//! where
+//!     Wp: WhitePoint<T>,
+//!     T: Real + core::ops::Div<Output = T>,
+//! {
+//!     fn from_color_unclamped(color: Xyz100<Wp, T>) -> Self {
+//!         Xyz::new(
+//!             color.x / T::from_f64(100.0),
+//!             color.y / T::from_f64(100.0),
+//!             color.z / T::from_f64(100.0),
+//!         )
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let lab = Lab::<D50, f64>::new(50.0, 10.0, -20.0);
+//!     let xyz100: Xyz100<D50, f64> = lab.into_color_unclamped();
+//!     let xyz: Xyz<D50, f64> = lab.into_color_unclamped();
+//!
+//!     assert_relative_eq!(xyz100.y, xyz.y * 100.0, epsilon = 0.0001);
+//!
+//!     let lab_again: Lab<D50, f64> = xyz100.into_color_unclamped();
+//!     assert_relative_eq!(lab_again, lab, epsilon = 0.0001);
+//! }
+//! ```
+//!
 //! With alpha component:
 //!
 //! ```rust
@@ -412,12 +493,16 @@
 //! ```
 
 pub use self::{
-    from_into_color::*, from_into_color_mut::*, from_into_color_unclamped::*,
-    from_into_color_unclamped_mut::*, matrix3::*, try_from_into_color::*,
+    from_into_color::*, from_into_color_mut::*, from_into_color_ref::*,
+    from_into_color_unclamped::*, from_into_color_unclamped_mut::*, matrix3::*,
+    try_from_into_color::*,
 };
 
+pub mod accuracy;
+
 mod from_into_color;
 mod from_into_color_mut;
+mod from_into_color_ref;
 mod from_into_color_unclamped;
 mod from_into_color_unclamped_mut;
 mod matrix3;
@@ -0,0 +1,145 @@
+//! Expected round trip accuracy for conversions from and to sRGB.
+//!
+//! Converting a color to another color space and back again doesn't always
+//! give back the exact same values, due to rounding errors and approximations
+//! in the conversion steps. The constants in this module document how large
+//! the difference is expected to be for each color space, when an in-gamut
+//! [`Srgb`](crate::Srgb) color is converted to the color space and back to
+//! `Srgb`. The difference is measured per sRGB component, in the range `[0.0,
+//! 1.0]`.
+//!
+//! The values are checked by the property tests in the `integration_tests`
+//! crate, with a margin above the largest difference they have been seen to
+//! have. They are meant as documentation and as a guide for picking
+//! tolerances, and may change between versions if the conversions are
+//! changed.
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::{convert::accuracy, FromColor, Okhsl, Srgb};
+//!
+//! let color = Srgb::new(0.3f32, 0.8, 0.1);
+//! let round_trip = Srgb::from_color(Okhsl::from_color(color));
+//!
+//! assert_relative_eq!(color, round_trip, epsilon = accuracy::OKHSL.f32);
+//! ```
+//!
+//! Colors outside the sRGB gamut, and colors with components outside their
+//! typical ranges, may not round trip for the color spaces that have bounded
+//! components, such as [`Hsl`](crate::Hsl) and [`Okhsl`](crate::Okhsl).
+
+/// The largest expected round trip difference for `f32` and `f64` components.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoundTripEpsilon {
+    /// The expected difference when converting with `f32` components.
+    pub f32: f32,
+
+    /// The expected difference when converting with `f64` components.
+    pub f64: f64,
+}
+
+/// The round trip accuracy for [`LinSrgb`](crate::LinSrgb).
+pub const LIN_SRGB: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-6,
+    f64: 1e-15,
+};
+
+/// The round trip accuracy for [`Xyz`](crate::Xyz).
+pub const XYZ: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-5,
+    f64: 1e-13,
+};
+
+/// The round trip accuracy for [`Yxy`](crate::Yxy).
+pub const YXY: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-5,
+    f64: 1e-13,
+};
+
+/// The round trip accuracy for [`Lab`](crate::Lab).
+pub const LAB: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 5e-5,
+    f64: 1e-13,
+};
+
+/// The round trip accuracy for [`Lch`](crate::Lch).
+pub const LCH: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-4,
+    f64: 1e-13,
+};
+
+/// The round trip accuracy for [`Luv`](crate::Luv).
+pub const LUV: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-4,
+    f64: 1e-13,
+};
+
+/// The round trip accuracy for [`Lchuv`](crate::Lchuv).
+pub const LCHUV: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-4,
+    f64: 1e-13,
+};
+
+/// The round trip accuracy for [`Hsluv`](crate::Hsluv).
+pub const HSLUV: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-4,
+    f64: 1e-13,
+};
+
+/// The round trip accuracy for [`Hsl`](crate::Hsl).
+pub const HSL: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-5,
+    f64: 1e-14,
+};
+
+/// The round trip accuracy for [`Hsv`](crate::Hsv).
+pub const HSV: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-5,
+    f64: 1e-14,
+};
+
+/// The round trip accuracy for [`Hwb`](crate::Hwb).
+pub const HWB: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-5,
+    f64: 1e-14,
+};
+
+/// The round trip accuracy for [`Oklab`](crate::Oklab).
+pub const OKLAB: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-4,
+    f64: 1e-12,
+};
+
+/// The round trip accuracy for [`Oklch`](crate::Oklch).
+pub const OKLCH: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-4,
+    f64: 1e-12,
+};
+
+/// The round trip accuracy for [`Okhsl`](crate::Okhsl).
+///
+/// `Okhsl`, `Okhsv` and `Okhwb` use approximations of the sRGB gamut, so they
+/// are less accurate than the other color spaces, even with `f64` components.
+pub const OKHSL: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 1e-3,
+    f64: 1e-6,
+};
+
+/// The round trip accuracy for [`Okhsv`](crate::Okhsv).
+pub const OKHSV: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 2e-4,
+    f64: 1e-6,
+};
+
+/// The round trip accuracy for [`Okhwb`](crate::Okhwb).
+pub const OKHWB: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 2e-4,
+    f64: 1e-6,
+};
+
+/// The round trip accuracy for [`Cam16`](crate::cam16::Cam16) and its partial
+/// and UCS forms, under the default viewing conditions.
+pub const CAM16: RoundTripEpsilon = RoundTripEpsilon {
+    f32: 5e-4,
+    f64: 1e-12,
+};
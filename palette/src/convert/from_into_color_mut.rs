@@ -90,14 +90,37 @@ use super::{FromColor, FromColorUnclampedMut, FromColorUnclampedMutGuard, IntoCo
 /// );
 /// ```
 ///
+/// Slices in a `Vec` or `Box<[T]>` can be converted the same way, by
+/// converting the slice they dereference to:
+///
+/// ```
+/// use palette::{FromColorMut, ShiftHueAssign, Srgb, Hsv};
+///
+/// let mut rgb = vec![Srgb::new(1.0, 0.0, 0.0), Srgb::new(0.0, 1.0, 0.0)];
+///
+/// {
+///     let mut hsv = <[Hsv]>::from_color_mut(&mut rgb);
+///     hsv.shift_hue_assign(120.0);
+/// }
+///
+/// assert_eq!(rgb, [Srgb::new(0.0, 1.0, 0.0), Srgb::new(0.0, 0.0, 1.0)]);
+/// ```
+///
+/// # Panics and unwinding
+///
+/// The guard restores the colors when it's dropped while unwinding from a
+/// panic, the same way as when it goes out of scope. The slice implementation
+/// will also restore any already converted colors if one of the conversions
+/// panics, so the memory space is never left with a mix of color types.
+///
 /// # Note
 ///
-/// The reused memory space could end up with unexpected values if the
-/// conversion panics or if the scope guard's `drop` function doesn't run. The
-/// default implementations of `FromColorMut` uses [`ArrayCast`], which is only
-/// implemented for color types that can safely accept and recover from any
-/// value. Other color types will have to provide their own implementations that
-/// can handle this case.
+/// The reused memory space could end up with unexpected values if the scope
+/// guard's `drop` function doesn't run, for example if it's passed to
+/// [`core::mem::forget`]. The default implementations of `FromColorMut` uses
+/// [`ArrayCast`], which is only implemented for color types that can safely
+/// accept and recover from any value. Other color types will have to provide
+/// their own implementations that can handle this case.
 pub trait FromColorMut<T>
 where
     T: ?Sized + FromColorMut<Self>,
@@ -137,10 +160,13 @@ where
 {
     #[inline]
     fn from_color_mut(colors: &mut [U]) -> FromColorMutGuard<Self, [U]> {
-        for color in &mut *colors {
+        let colors = SliceConversion::new(colors, |color: &mut T| {
+            core::mem::forget(U::from_color_mut(color));
+        })
+        .convert(|color| {
             // Forgetting the guard leaves the colors in the converted state.
             core::mem::forget(T::from_color_mut(color));
-        }
+        });
 
         FromColorMutGuard {
             current: Some(cast::from_array_slice_mut(cast::into_array_slice_mut(
@@ -151,6 +177,64 @@ where
     }
 }
 
+/// Converts the colors in a slice one by one, and restores the already
+/// converted colors if a conversion panics. This prevents the slice from being
+/// left with a mix of color types after unwinding.
+pub(super) struct SliceConversion<'a, T, U>
+where
+    T: ArrayCast,
+    U: ArrayCast<Array = T::Array>,
+{
+    colors: &'a mut [U],
+    converted: usize,
+    restore: fn(&mut T),
+}
+
+impl<'a, T, U> SliceConversion<'a, T, U>
+where
+    T: ArrayCast,
+    U: ArrayCast<Array = T::Array>,
+{
+    #[inline]
+    pub(super) fn new(colors: &'a mut [U], restore: fn(&mut T)) -> Self {
+        SliceConversion {
+            colors,
+            converted: 0,
+            restore,
+        }
+    }
+
+    /// Convert each color with `convert`, which is expected to leave them in
+    /// the converted state.
+    #[inline]
+    pub(super) fn convert(mut self, convert: impl Fn(&mut U)) -> &'a mut [U] {
+        while let Some(color) = self.colors.get_mut(self.converted) {
+            convert(color);
+            self.converted += 1;
+        }
+
+        // Everything is converted, so there's nothing to restore.
+        self.converted = 0;
+        core::mem::take(&mut self.colors)
+    }
+}
+
+impl<T, U> Drop for SliceConversion<'_, T, U>
+where
+    T: ArrayCast,
+    U: ArrayCast<Array = T::Array>,
+{
+    fn drop(&mut self) {
+        let converted: &mut [T] = cast::from_array_slice_mut(cast::into_array_slice_mut(
+            &mut self.colors[..self.converted],
+        ));
+
+        for color in converted {
+            (self.restore)(color);
+        }
+    }
+}
+
 /// Temporarily convert colors in place. The `Into` counterpart to
 /// [`FromColorMut`].
 ///
@@ -359,3 +443,153 @@ where
         core::mem::forget(self.current.take().map(U::from_color_mut));
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::{FromColorMut, IntoColorMut};
+    use crate::{
+        bool_mask::HasBoolMask, convert::FromColorUnclamped, Clamp, Hsl, Hsv, ShiftHueAssign, Srgb,
+    };
+
+    /// Stores the components in reverse order, to make unrestored colors
+    /// noticeable, and refuses to convert from colors that are too red.
+    #[derive(Clone, Copy, PartialEq, Debug, ArrayCast)]
+    #[palette(palette_internal)]
+    #[repr(transparent)]
+    struct Fragile([f32; 3]);
+
+    impl FromColorUnclamped<Srgb> for Fragile {
+        fn from_color_unclamped(color: Srgb) -> Self {
+            assert!(color.red <= 0.5, "the color is too red");
+            Fragile([color.blue, color.green, color.red])
+        }
+    }
+
+    impl FromColorUnclamped<Fragile> for Srgb {
+        fn from_color_unclamped(color: Fragile) -> Self {
+            let [blue, green, red] = color.0;
+            Srgb::new(red, green, blue)
+        }
+    }
+
+    impl HasBoolMask for Fragile {
+        type Mask = bool;
+    }
+
+    impl Clamp for Fragile {
+        fn clamp(self) -> Self {
+            self
+        }
+    }
+
+    fn primaries() -> [Srgb; 3] {
+        [
+            Srgb::new(1.0, 0.0, 0.0),
+            Srgb::new(0.0, 1.0, 0.0),
+            Srgb::new(0.0, 0.0, 1.0),
+        ]
+    }
+
+    #[test]
+    fn nested_scopes() {
+        let mut rgb = primaries();
+
+        {
+            let mut hsv = <[Hsv]>::from_color_mut(&mut rgb);
+            hsv.shift_hue_assign(60.0);
+
+            {
+                let mut hsl: crate::convert::FromColorMutGuard<[Hsl], [Hsv]> = hsv.into_color_mut();
+                hsl.shift_hue_assign(60.0);
+            }
+
+            assert_eq!(hsv[0], Hsv::new(120.0, 1.0, 1.0));
+        }
+
+        assert_eq!(
+            rgb,
+            [
+                Srgb::new(0.0, 1.0, 0.0),
+                Srgb::new(0.0, 0.0, 1.0),
+                Srgb::new(1.0, 0.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn early_drop() {
+        let mut rgb = primaries();
+
+        let mut hsv = <[Hsv]>::from_color_mut(&mut rgb);
+        hsv.shift_hue_assign(120.0);
+        let restored = hsv.restore();
+        assert_eq!(restored[0], Srgb::new(0.0, 1.0, 0.0));
+
+        let mut hsv = <[Hsv]>::from_color_mut(&mut rgb);
+        hsv.shift_hue_assign(120.0);
+        drop(hsv);
+
+        assert_eq!(
+            rgb,
+            [
+                Srgb::new(0.0, 0.0, 1.0),
+                Srgb::new(1.0, 0.0, 0.0),
+                Srgb::new(0.0, 1.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn vec_and_boxed_slice() {
+        let mut rgb = primaries().to_vec();
+
+        {
+            let hsv: &mut [Hsv] = &mut rgb.into_color_mut();
+            hsv.shift_hue_assign(120.0);
+        }
+
+        assert_eq!(rgb[0], Srgb::new(0.0, 1.0, 0.0));
+
+        let mut rgb = rgb.into_boxed_slice();
+
+        {
+            let mut hsv = <[Hsv]>::from_color_mut(&mut rgb);
+            hsv.shift_hue_assign(120.0);
+        }
+
+        assert_eq!(rgb[0], Srgb::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn restore_after_panicking_conversion() {
+        let original = [
+            Srgb::new(0.1, 0.2, 0.3),
+            Srgb::new(0.2, 0.3, 0.4),
+            Srgb::new(0.9, 0.0, 0.0),
+        ];
+        let mut rgb = original;
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _fragile = <[Fragile]>::from_color_mut(&mut rgb);
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(rgb, original);
+    }
+
+    #[test]
+    fn restore_after_panic_in_scope() {
+        let mut rgb = primaries();
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let mut hsv = <[Hsv]>::from_color_mut(&mut rgb);
+            hsv.shift_hue_assign(120.0);
+            panic!("interrupted");
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(rgb[0], Srgb::new(0.0, 1.0, 0.0));
+    }
+}
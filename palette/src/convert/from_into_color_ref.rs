@@ -0,0 +1,185 @@
+use super::{FromColor, FromColorUnclamped};
+
+/// A trait for converting a color from a reference to another color, in a
+/// possibly lossy way.
+///
+/// `U: FromColorRef<T>` is implemented for every type `U: FromColor<T>`, where
+/// `T` is [`Copy`]. This makes it possible to convert borrowed colors, for
+/// example in generic code, without having to dereference them first:
+///
+/// ```
+/// use palette::{convert::FromColorRef, Lab, Srgb};
+///
+/// let rgb = Srgb::new(0.8f32, 0.2, 0.1);
+/// let lab = Lab::from_color_ref(&rgb);
+/// ```
+///
+/// See [`FromColor`] for more details, and [`slice_into_color`] for
+/// converting a whole slice into a `Vec`.
+pub trait FromColorRef<T>: Sized {
+    /// Convert from a reference to `T`, with values clamped to the color
+    /// defined bounds.
+    #[must_use]
+    fn from_color_ref(color: &T) -> Self;
+}
+
+impl<T, U> FromColorRef<T> for U
+where
+    T: Copy,
+    U: FromColor<T>,
+{
+    #[inline]
+    fn from_color_ref(color: &T) -> Self {
+        U::from_color(*color)
+    }
+}
+
+/// A trait for converting a color from a reference to another color, without
+/// clamping.
+///
+/// `U: FromColorUnclampedRef<T>` is implemented for every type `U:
+/// FromColorUnclamped<T>`, where `T` is [`Copy`].
+///
+/// See [`FromColorUnclamped`] for more details.
+pub trait FromColorUnclampedRef<T>: Sized {
+    /// Convert from a reference to `T`. The resulting color might be invalid
+    /// in its color space.
+    #[must_use]
+    fn from_color_unclamped_ref(color: &T) -> Self;
+}
+
+impl<T, U> FromColorUnclampedRef<T> for U
+where
+    T: Copy,
+    U: FromColorUnclamped<T>,
+{
+    #[inline]
+    fn from_color_unclamped_ref(color: &T) -> Self {
+        U::from_color_unclamped(*color)
+    }
+}
+
+/// A trait for converting a reference to a color into another color, in a
+/// possibly lossy way.
+///
+/// `T: IntoColorRef<U>` is implemented for every type `U: FromColorRef<T>`.
+///
+/// ```
+/// use palette::{convert::IntoColorRef, Lab, Srgb};
+///
+/// let rgb = Srgb::new(0.8f32, 0.2, 0.1);
+/// let lab: Lab = rgb.into_color_ref();
+/// ```
+///
+/// See [`FromColorRef`] for more details.
+pub trait IntoColorRef<T> {
+    /// Convert into `T` with values clamped to the color defined bounds,
+    /// without taking ownership of `self`.
+    #[must_use]
+    #[allow(clippy::wrong_self_convention)]
+    fn into_color_ref(&self) -> T;
+}
+
+impl<T, U> IntoColorRef<U> for T
+where
+    U: FromColorRef<T>,
+{
+    #[inline]
+    fn into_color_ref(&self) -> U {
+        U::from_color_ref(self)
+    }
+}
+
+/// Convert a slice of colors into a `Vec` of another color type, in a possibly
+/// lossy way.
+///
+/// The colors are converted one at a time, as they are copied into the new
+/// `Vec`. See [`FromColorMut`](crate::convert::FromColorMut) for converting
+/// the colors in place instead, and [`FromColor`] for converting an owned
+/// `Vec` without reallocating.
+///
+/// ```
+/// use palette::{convert::slice_into_color, Oklab, Srgb};
+///
+/// let rgb = [Srgb::new(0.8f32, 0.2, 0.1), Srgb::new(0.1, 0.5, 0.9)];
+/// let oklab: Vec<Oklab> = slice_into_color(&rgb);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn slice_into_color<T, U>(colors: &[T]) -> alloc::vec::Vec<U>
+where
+    U: FromColorRef<T>,
+{
+    colors.iter().map(U::from_color_ref).collect()
+}
+
+/// Convert a slice of colors into a `Vec` of another color type, without
+/// clamping.
+///
+/// See [`slice_into_color`] for more details.
+#[cfg(feature = "alloc")]
+pub fn slice_into_color_unclamped<T, U>(colors: &[T]) -> alloc::vec::Vec<U>
+where
+    U: FromColorUnclampedRef<T>,
+{
+    colors.iter().map(U::from_color_unclamped_ref).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FromColorRef, FromColorUnclampedRef, IntoColorRef};
+    use crate::{convert::FromColorUnclamped, FromColor, Hsv, Lab, Oklab, Srgb};
+
+    fn colors() -> [Srgb; 3] {
+        [
+            Srgb::new(0.8, 0.2, 0.1),
+            Srgb::new(0.1, 0.5, 0.9),
+            Srgb::new(1.2, -0.1, 0.5),
+        ]
+    }
+
+    #[test]
+    fn same_as_by_value() {
+        for color in colors() {
+            assert_eq!(Lab::from_color_ref(&color), Lab::from_color(color));
+            assert_eq!(
+                Hsv::from_color_unclamped_ref(&color),
+                Hsv::from_color_unclamped(color)
+            );
+
+            let oklab: Oklab = color.into_color_ref();
+            assert_eq!(oklab, Oklab::from_color(color));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn slice_same_as_by_value() {
+        let colors = colors();
+
+        let oklab: Vec<Oklab> = super::slice_into_color(&colors);
+        let expected: Vec<Oklab> = colors.iter().copied().map(Oklab::from_color).collect();
+        assert_eq!(oklab, expected);
+
+        let hsv: Vec<Hsv> = super::slice_into_color_unclamped(&colors);
+        let expected: Vec<Hsv> = colors
+            .iter()
+            .copied()
+            .map(Hsv::from_color_unclamped)
+            .collect();
+        assert_eq!(hsv, expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_in_place() {
+        let colors = colors().to_vec();
+        let expected: Vec<Hsv> = super::slice_into_color(&colors);
+
+        // The in-place conversion reuses the allocation instead of copying.
+        let pointer = colors.as_ptr() as *const u8;
+        let hsv = Vec::<Hsv>::from_color(colors);
+
+        assert_eq!(hsv.as_ptr() as *const u8, pointer);
+        assert_eq!(hsv, expected);
+    }
+}
@@ -5,7 +5,10 @@ use core::{
 
 use crate::cast::{self, ArrayCast};
 
-use super::{FromColorMut, FromColorMutGuard, FromColorUnclamped, IntoColorUnclamped};
+use super::{
+    from_into_color_mut::SliceConversion, FromColorMut, FromColorMutGuard, FromColorUnclamped,
+    IntoColorUnclamped,
+};
 
 /// Temporarily convert colors in place, without clamping.
 ///
@@ -91,14 +94,20 @@ use super::{FromColorMut, FromColorMutGuard, FromColorUnclamped, IntoColorUnclam
 /// );
 /// ```
 ///
+/// # Panics and unwinding
+///
+/// The colors are restored while unwinding from a panic, in the same way as for
+/// [`FromColorMut`].
+///
 /// # Note
 ///
-/// The reused memory space could end up with unexpected values if the
-/// conversion panics or if the scope guard's `drop` function doesn't run. The
-/// default implementations of `FromColorUnclampedMut` uses [`ArrayCast`], which
-/// is only implemented for color types that can safely accept and recover from
-/// any value. Other color types will have to provide their own implementations
-/// that can handle this case.
+/// The reused memory space could end up with unexpected values if the scope
+/// guard's `drop` function doesn't run, for example if it's passed to
+/// [`core::mem::forget`]. The default implementations of
+/// `FromColorUnclampedMut` uses [`ArrayCast`], which is only implemented for
+/// color types that can safely accept and recover from any value. Other color
+/// types will have to provide their own implementations that can handle this
+/// case.
 pub trait FromColorUnclampedMut<T>
 where
     T: ?Sized + FromColorUnclampedMut<Self>,
@@ -138,10 +147,13 @@ where
 {
     #[inline]
     fn from_color_unclamped_mut(colors: &mut [U]) -> FromColorUnclampedMutGuard<Self, [U]> {
-        for color in &mut *colors {
+        let colors = SliceConversion::new(colors, |color: &mut T| {
+            core::mem::forget(U::from_color_unclamped_mut(color));
+        })
+        .convert(|color| {
             // Forgetting the guard leaves the colors in the converted state.
             core::mem::forget(T::from_color_unclamped_mut(color));
-        }
+        });
 
         FromColorUnclampedMutGuard {
             current: Some(cast::from_array_slice_mut(cast::into_array_slice_mut(
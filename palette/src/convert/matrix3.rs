@@ -4,7 +4,7 @@ use crate::{
     cast::{self, ArrayCast},
     matrix::{matrix_inverse, multiply_3x3, multiply_3x3_and_vec3},
     num::{Arithmetics, IsValidDivisor, One, Recip, Zero},
-    ArrayExt, Mat3,
+    ArrayExt,
 };
 
 use super::{Convert, ConvertOnce};
@@ -31,7 +31,7 @@ pub struct Matrix3<I, O>
 where
     I: ArrayCast,
 {
-    matrix: Mat3<<I::Array as ArrayExt>::Item>,
+    matrix: [<I::Array as ArrayExt>::Item; 9],
     transform: PhantomData<fn(I) -> O>,
 }
 
@@ -210,7 +210,7 @@ where
     /// assert_eq!(input, output);
     /// ```
     #[inline]
-    pub const fn from_array(matrix: Mat3<T>) -> Self {
+    pub const fn from_array(matrix: [T; 9]) -> Self {
         Self {
             matrix,
             transform: PhantomData,
@@ -221,7 +221,7 @@ where
     ///
     /// The matrix elements are stored in row-major order.
     #[inline]
-    pub fn into_array(self) -> Mat3<T> {
+    pub fn into_array(self) -> [T; 9] {
         self.matrix
     }
 }
@@ -6,6 +6,19 @@ use super::FromColorUnclamped;
 
 /// The error type for a color conversion that converted a color into a color
 /// with invalid values.
+///
+/// It's also returned from the checked constructors, such as
+/// [`Srgb::try_new`](crate::rgb::Rgb::try_new), when any of the components are
+/// out of bounds:
+///
+/// ```
+/// use palette::{Clamp, Srgb};
+///
+/// let error = Srgb::try_new(0.8f32, 1.2, 0.5).unwrap_err();
+///
+/// // The unchecked color is still available, for example to clamp it.
+/// assert_eq!(error.color().clamp(), Srgb::new(0.8, 1.0, 0.5));
+/// ```
 #[derive(Debug)]
 pub struct OutOfBounds<T> {
     color: T,
@@ -14,7 +27,7 @@ pub struct OutOfBounds<T> {
 impl<T> OutOfBounds<T> {
     /// Create a new error wrapping a color
     #[inline]
-    fn new(color: T) -> Self {
+    pub(crate) fn new(color: T) -> Self {
         OutOfBounds { color }
     }
 
@@ -0,0 +1,364 @@
+//! Simulation of color vision deficiency (CVD).
+//!
+//! This module simulates how colors are perceived by someone with a color
+//! vision deficiency, using the model from [Machado, Oliveira and Fernandes
+//! (2009)](https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html).
+//! It's based on a set of published matrices that are applied to linear sRGB,
+//! with one matrix per tenth of the severity. The severity `1.0` matrices
+//! simulate dichromacy, and the lower severities simulate the corresponding
+//! anomalous trichromacy. Severities in between the published values are
+//! interpolated.
+//!
+//! ```
+//! use palette::{
+//!     cvd::{simulate, Deficiency},
+//!     Srgb,
+//! };
+//!
+//! let red = Srgb::new(1.0f32, 0.0, 0.0);
+//! let simulated = simulate(red, Deficiency::Deuteranopia, 1.0);
+//!
+//! // Red and green are hard to tell apart with deuteranopia.
+//! assert!(simulated.green > simulated.blue);
+//! ```
+//!
+//! The simulated colors may end up slightly outside the sRGB gamut, since the
+//! matrices are applied without clamping. Use [`Clamp`][crate::Clamp] to bring
+//! them back, if necessary.
+
+use crate::{
+    convert::FromColorUnclamped,
+    matrix::{matrix_map, Mat3},
+    num::{Arithmetics, Real},
+    LinSrgb,
+};
+
+/// The type of color vision deficiency to simulate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Deficiency {
+    /// Missing or anomalous L cones, also known as red-blindness or
+    /// protanomaly.
+    Protanopia,
+    /// Missing or anomalous M cones, also known as green-blindness or
+    /// deuteranomaly.
+    Deuteranopia,
+    /// Missing or anomalous S cones, also known as blue-blindness or
+    /// tritanomaly.
+    Tritanopia,
+}
+
+impl Deficiency {
+    /// Get the linear sRGB simulation matrix for this deficiency.
+    ///
+    /// `severity` is expected to be between `0.0` and `1.0`, where `0.0`
+    /// results in the identity matrix and `1.0` results in the dichromacy
+    /// matrix. Values outside that range are clamped.
+    pub fn matrix<T>(self, severity: f32) -> Mat3<T>
+    where
+        T: Real,
+    {
+        let table = match self {
+            Deficiency::Protanopia => &PROTANOPIA,
+            Deficiency::Deuteranopia => &DEUTERANOPIA,
+            Deficiency::Tritanopia => &TRITANOPIA,
+        };
+
+        // The comparisons are written this way to map NaN to 0.0.
+        let severity = if severity > 0.0 {
+            f64::from(severity.min(1.0)) * 10.0
+        } else {
+            0.0
+        };
+
+        let index = (severity as usize).min(9);
+        let factor = severity - index as f64;
+        let low = table[index];
+        let high = table[index + 1];
+
+        let mut position = 0;
+        Mat3::from_array(matrix_map(low, |low| {
+            let high = high[position];
+            position += 1;
+
+            T::from_f64(low + (high - low) * factor)
+        }))
+    }
+}
+
+/// Simulate how `color` is perceived with the color vision deficiency
+/// `deficiency`, at the given `severity`.
+///
+/// `severity` is expected to be between `0.0` and `1.0`, where `0.0` leaves
+/// the color unchanged and `1.0` simulates complete dichromacy. The color is
+/// converted to linear sRGB and back.
+///
+/// ```
+/// use palette::{
+///     cvd::{simulate, Deficiency},
+///     LinSrgb,
+/// };
+///
+/// let color = LinSrgb::new(0.2f32, 0.8, 0.4);
+/// let simulated = simulate(color, Deficiency::Tritanopia, 0.5);
+///
+/// let [red, green, blue] = Deficiency::Tritanopia
+///     .matrix(0.5)
+///     .mul_vector([0.2, 0.8, 0.4]);
+/// assert_eq!(simulated, LinSrgb::new(red, green, blue));
+///
+/// // A severity of 0.0 doesn't change the color.
+/// assert_eq!(simulate(color, Deficiency::Tritanopia, 0.0), color);
+/// ```
+pub fn simulate<C, T>(color: C, deficiency: Deficiency, severity: f32) -> C
+where
+    C: FromColorUnclamped<LinSrgb<T>>,
+    LinSrgb<T>: FromColorUnclamped<C>,
+    T: Real + Arithmetics,
+{
+    apply_matrix(color, deficiency.matrix(severity))
+}
+
+/// Simulate how all colors in `colors` are perceived with the color vision
+/// deficiency `deficiency`, at the given `severity`.
+///
+/// This is the same as calling [`simulate`] for each color, but the
+/// simulation matrix is only calculated once.
+///
+/// ```
+/// use palette::{
+///     cvd::{simulate, simulate_slice, Deficiency},
+///     Srgb,
+/// };
+///
+/// let original = [
+///     Srgb::new(1.0f32, 0.0, 0.0),
+///     Srgb::new(0.0, 1.0, 0.0),
+///     Srgb::new(0.0, 0.0, 1.0),
+/// ];
+/// let mut image = original;
+/// simulate_slice(&mut image, Deficiency::Protanopia, 1.0);
+///
+/// for (simulated, color) in image.iter().zip(original) {
+///     assert_eq!(*simulated, simulate(color, Deficiency::Protanopia, 1.0));
+/// }
+/// ```
+pub fn simulate_slice<C, T>(colors: &mut [C], deficiency: Deficiency, severity: f32)
+where
+    C: FromColorUnclamped<LinSrgb<T>> + Clone,
+    LinSrgb<T>: FromColorUnclamped<C>,
+    T: Real + Arithmetics + Clone,
+{
+    let matrix = deficiency.matrix::<T>(severity);
+
+    for color in colors {
+        *color = apply_matrix(color.clone(), matrix.clone());
+    }
+}
+
+#[inline]
+fn apply_matrix<C, T>(color: C, matrix: Mat3<T>) -> C
+where
+    C: FromColorUnclamped<LinSrgb<T>>,
+    LinSrgb<T>: FromColorUnclamped<C>,
+    T: Arithmetics,
+{
+    let linear = LinSrgb::from_color_unclamped(color);
+    let [red, green, blue] = matrix.mul_vector([linear.red, linear.green, linear.blue]);
+
+    C::from_color_unclamped(LinSrgb::new(red, green, blue))
+}
+
+// The published matrices, from severity 0.0 to 1.0 in steps of 0.1.
+
+#[rustfmt::skip]
+const PROTANOPIA: [[f64; 9]; 11] = [
+    [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    [0.856167, 0.182038, -0.038205, 0.029342, 0.955115, 0.015544, -0.002880, -0.001563, 1.004443],
+    [0.734766, 0.334872, -0.069637, 0.051840, 0.919198, 0.028963, -0.004928, -0.004209, 1.009137],
+    [0.630323, 0.465641, -0.095964, 0.069181, 0.890046, 0.040773, -0.006308, -0.007724, 1.014032],
+    [0.539009, 0.579343, -0.118352, 0.082546, 0.866121, 0.051332, -0.007136, -0.011959, 1.019095],
+    [0.458064, 0.679578, -0.137642, 0.092785, 0.846313, 0.060902, -0.007494, -0.016807, 1.024301],
+    [0.385450, 0.769005, -0.154455, 0.100526, 0.829802, 0.069673, -0.007442, -0.022190, 1.029632],
+    [0.319627, 0.849633, -0.169261, 0.106241, 0.815969, 0.077790, -0.007025, -0.028051, 1.035076],
+    [0.259411, 0.923008, -0.182420, 0.110296, 0.804340, 0.085364, -0.006276, -0.034346, 1.040622],
+    [0.203876, 0.990338, -0.194214, 0.112975, 0.794542, 0.092483, -0.005222, -0.041043, 1.046265],
+    [0.152286, 1.052583, -0.204868, 0.114503, 0.786281, 0.099216, -0.003882, -0.048116, 1.051998],
+];
+
+#[rustfmt::skip]
+const DEUTERANOPIA: [[f64; 9]; 11] = [
+    [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    [0.866435, 0.177704, -0.044139, 0.049567, 0.939063, 0.011370, -0.003453, 0.007233, 0.996220],
+    [0.760729, 0.319078, -0.079807, 0.090568, 0.889315, 0.020117, -0.006027, 0.013325, 0.992702],
+    [0.675425, 0.433850, -0.109275, 0.125303, 0.847755, 0.026942, -0.007950, 0.018572, 0.989378],
+    [0.605511, 0.528560, -0.134071, 0.155318, 0.812366, 0.032316, -0.009376, 0.023176, 0.986200],
+    [0.547494, 0.607765, -0.155259, 0.181692, 0.781742, 0.036566, -0.010410, 0.027275, 0.983136],
+    [0.498864, 0.674741, -0.173604, 0.205199, 0.754872, 0.039929, -0.011131, 0.030969, 0.980162],
+    [0.457771, 0.731899, -0.189670, 0.226409, 0.731012, 0.042579, -0.011595, 0.034333, 0.977261],
+    [0.422823, 0.781057, -0.203881, 0.245752, 0.709602, 0.044646, -0.011843, 0.037423, 0.974421],
+    [0.392952, 0.823610, -0.216562, 0.263559, 0.690210, 0.046232, -0.011910, 0.040281, 0.971630],
+    [0.367322, 0.860646, -0.227968, 0.280085, 0.672501, 0.047413, -0.011820, 0.042940, 0.968881],
+];
+
+#[rustfmt::skip]
+const TRITANOPIA: [[f64; 9]; 11] = [
+    [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    [0.926670, 0.092514, -0.019184, 0.021191, 0.964503, 0.014306, 0.008437, 0.054813, 0.936750],
+    [0.895720, 0.133330, -0.029050, 0.029997, 0.945400, 0.024603, 0.013027, 0.104707, 0.882266],
+    [0.905871, 0.127791, -0.033662, 0.026856, 0.941251, 0.031893, 0.013410, 0.148296, 0.838294],
+    [0.948035, 0.089490, -0.037526, 0.014364, 0.946792, 0.038844, 0.010853, 0.193991, 0.795156],
+    [1.017277, 0.027029, -0.044306, -0.006113, 0.958479, 0.047634, 0.006379, 0.248708, 0.744913],
+    [1.104996, -0.046633, -0.058363, -0.032137, 0.971635, 0.060503, 0.001336, 0.317922, 0.680742],
+    [1.193214, -0.109812, -0.083402, -0.058496, 0.979410, 0.079086, -0.002346, 0.403492, 0.598854],
+    [1.257728, -0.139648, -0.118081, -0.078003, 0.975409, 0.102594, -0.003316, 0.501214, 0.502102],
+    [1.278864, -0.125333, -0.153531, -0.084748, 0.957674, 0.127074, -0.000989, 0.601151, 0.399838],
+    [1.255528, -0.076749, -0.178779, -0.078411, 0.930809, 0.147602, 0.004733, 0.691367, 0.303900],
+];
+
+#[cfg(feature = "approx")]
+#[cfg(test)]
+mod test {
+    use super::{simulate, simulate_slice, Deficiency};
+    use crate::{FromColor, LinSrgb, Srgb};
+
+    fn simulate_primaries(deficiency: Deficiency, severity: f32) -> [f64; 9] {
+        let red = simulate(LinSrgb::new(1.0, 0.0, 0.0), deficiency, severity);
+        let green = simulate(LinSrgb::new(0.0, 1.0, 0.0), deficiency, severity);
+        let blue = simulate(LinSrgb::new(0.0, 0.0, 1.0), deficiency, severity);
+
+        [
+            red.red,
+            green.red,
+            blue.red,
+            red.green,
+            green.green,
+            blue.green,
+            red.blue,
+            green.blue,
+            blue.blue,
+        ]
+    }
+
+    fn check_matrix(computed: [f64; 9], expected: [f64; 9]) {
+        for (c, e) in computed.iter().zip(expected.iter()) {
+            assert_relative_eq!(c, e, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn full_severity() {
+        check_matrix(
+            simulate_primaries(Deficiency::Protanopia, 1.0),
+            [
+                0.152286, 1.052583, -0.204868, 0.114503, 0.786281, 0.099216, -0.003882, -0.048116,
+                1.051998,
+            ],
+        );
+        check_matrix(
+            simulate_primaries(Deficiency::Deuteranopia, 1.0),
+            [
+                0.367322, 0.860646, -0.227968, 0.280085, 0.672501, 0.047413, -0.011820, 0.042940,
+                0.968881,
+            ],
+        );
+        check_matrix(
+            simulate_primaries(Deficiency::Tritanopia, 1.0),
+            [
+                1.255528, -0.076749, -0.178779, -0.078411, 0.930809, 0.147602, 0.004733, 0.691367,
+                0.303900,
+            ],
+        );
+    }
+
+    #[test]
+    fn half_severity() {
+        check_matrix(
+            simulate_primaries(Deficiency::Protanopia, 0.5),
+            [
+                0.458064, 0.679578, -0.137642, 0.092785, 0.846313, 0.060902, -0.007494, -0.016807,
+                1.024301,
+            ],
+        );
+        check_matrix(
+            simulate_primaries(Deficiency::Deuteranopia, 0.5),
+            [
+                0.547494, 0.607765, -0.155259, 0.181692, 0.781742, 0.036566, -0.010410, 0.027275,
+                0.983136,
+            ],
+        );
+        check_matrix(
+            simulate_primaries(Deficiency::Tritanopia, 0.5),
+            [
+                1.017277, 0.027029, -0.044306, -0.006113, 0.958479, 0.047634, 0.006379, 0.248708,
+                0.744913,
+            ],
+        );
+    }
+
+    #[test]
+    fn interpolated_severity() {
+        let low = simulate_primaries(Deficiency::Deuteranopia, 0.5);
+        let high = simulate_primaries(Deficiency::Deuteranopia, 0.6);
+        let computed = simulate_primaries(Deficiency::Deuteranopia, 0.525);
+
+        for ((c, l), h) in computed.iter().zip(low.iter()).zip(high.iter()) {
+            assert_relative_eq!(*c, l + (h - l) * 0.25, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn out_of_range_severity() {
+        let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+        for deficiency in [
+            Deficiency::Protanopia,
+            Deficiency::Deuteranopia,
+            Deficiency::Tritanopia,
+        ] {
+            check_matrix(simulate_primaries(deficiency, 0.0), identity);
+            check_matrix(simulate_primaries(deficiency, -1.0), identity);
+            check_matrix(simulate_primaries(deficiency, f32::NAN), identity);
+            check_matrix(
+                simulate_primaries(deficiency, 2.0),
+                simulate_primaries(deficiency, 1.0),
+            );
+        }
+    }
+
+    #[test]
+    fn keeps_white_and_gray() {
+        for deficiency in [
+            Deficiency::Protanopia,
+            Deficiency::Deuteranopia,
+            Deficiency::Tritanopia,
+        ] {
+            let gray = Srgb::new(0.5f64, 0.5, 0.5);
+            assert_relative_eq!(simulate(gray, deficiency, 0.75), gray, epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn slice() {
+        let colors = [
+            Srgb::new(0.8f64, 0.1, 0.3),
+            Srgb::new(0.2, 0.6, 0.3),
+            Srgb::new(0.4, 0.4, 0.9),
+        ];
+
+        let mut simulated = colors;
+        simulate_slice(&mut simulated, Deficiency::Deuteranopia, 0.7);
+
+        for (color, simulated) in colors.iter().zip(simulated.iter()) {
+            let expected = simulate(*color, Deficiency::Deuteranopia, 0.7);
+            assert_relative_eq!(*simulated, expected);
+
+            let linear = LinSrgb::from_color(*color);
+            let expected_linear = simulate(linear, Deficiency::Deuteranopia, 0.7);
+            assert_relative_eq!(
+                LinSrgb::from_color(*simulated),
+                expected_linear,
+                epsilon = 0.000001
+            );
+        }
+    }
+}
@@ -10,11 +10,15 @@ pub use self::adobe::AdobeRgb;
 pub use self::gamma::{F2p2, Gamma};
 pub use self::linear::Linear;
 pub use self::p3::{DciP3, DciP3Plus, DisplayP3, P3Gamma};
+pub use self::panasonic::{VGamut, VLog};
 pub use self::prophoto::ProPhotoRgb;
 pub use self::rec_standards::{Rec2020, Rec709, RecOetf};
+pub use self::scaled::ScaledTransfer;
+pub use self::sony::{SGamut3Cine, SLog3};
 pub use self::srgb::Srgb;
 
 pub mod adobe;
+pub mod dither;
 #[deprecated(
     since = "0.7.7",
     note = "`Gamma`, `GammaFn` and `F2p2` are error prone and incorrectly implemented. See `palette::encoding` for possible alternatives or implement `palette::encoding::FromLinear` and `palette::encoding::IntoLinear` for a custom type."
@@ -22,11 +26,15 @@ pub mod adobe;
 pub mod gamma;
 pub mod linear;
 pub mod p3;
+pub mod panasonic;
 pub mod prophoto;
 pub mod rec_standards;
+pub mod scaled;
+pub mod sony;
 pub mod srgb;
 
 mod lut;
+mod matrix;
 
 /// A transfer function from linear space.
 pub trait FromLinear<L, E> {
@@ -9,7 +9,7 @@ use crate::{
     num::{Powf, Real},
     rgb::{Primaries, RgbSpace, RgbStandard},
     white_point::{Any, D65},
-    Mat3, Yxy,
+    Yxy,
 };
 
 /// The Adobe RGB (1998) (a.k.a. opRGB) color space and standard.
@@ -66,26 +66,14 @@ impl RgbSpace for AdobeRgb {
     type Primaries = AdobeRgb;
     type WhitePoint = D65;
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn rgb_to_xyz_matrix() -> Option<Mat3<f64>> {
-        // Matrix from http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
-        Some([
-            0.5767309, 0.1855540, 0.1881852,
-            0.2973769, 0.6273491, 0.0752741,
-            0.0270343, 0.0706872, 0.9911085,
-        ])
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::adobe::RGB_TO_XYZ)
     }
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn xyz_to_rgb_matrix() -> Option<Mat3<f64>> {
-        // Matrix from http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
-        Some([
-             2.0413690, -0.5649464, -0.3446944,
-            -0.9692660,  1.8760108,  0.0415560,
-             0.0134474, -0.1183897,  1.0154096,
-        ])
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::adobe::XYZ_TO_RGB)
     }
 }
 
@@ -159,14 +147,14 @@ mod test {
         fn rgb_to_xyz() {
             let dynamic = rgb_to_xyz_matrix::<AdobeRgb, f64>();
             let constant = AdobeRgb::rgb_to_xyz_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
         fn xyz_to_rgb() {
             let dynamic = matrix_inverse(rgb_to_xyz_matrix::<AdobeRgb, f64>());
             let constant = AdobeRgb::xyz_to_rgb_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
     }
 
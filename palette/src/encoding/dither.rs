@@ -0,0 +1,164 @@
+//! Dithering for quantizing encoded colors.
+//!
+//! Converting smooth gradients from floating point to `u8` components rounds
+//! many nearby values to the same integer, which shows up as visible bands.
+//! Dithering adds a small, spatially varying offset to each value before it's
+//! rounded, so the average of a neighborhood stays closer to the original
+//! value. The offset is applied in the encoded (non-linear) domain, just before
+//! quantization.
+//!
+//! See [`Rgb::from_linear_dithered`](crate::rgb::Rgb::from_linear_dithered)
+//! for how to use it.
+
+/// A source of dithering offsets, walked in row-major order.
+///
+/// Implement this trait to use a different dithering pattern, such as a
+/// precomputed blue noise texture.
+pub trait Dither {
+    /// Get the offset for the next pixel in the current row and move one step
+    /// to the right.
+    ///
+    /// The offset is measured in quantization steps and should be in the range
+    /// `[-0.5, 0.5)`.
+    fn next_offset(&mut self) -> f32;
+
+    /// Move to the first pixel of the next row.
+    fn next_row(&mut self);
+}
+
+/// Ordered dithering with a 4×4 or 8×8 Bayer matrix.
+///
+/// ```
+/// use palette::encoding::dither::{Bayer, Dither};
+///
+/// let mut dither = Bayer::new_4x4();
+/// let first = dither.next_offset();
+/// let second = dither.next_offset();
+///
+/// assert!(first >= -0.5 && first < 0.5);
+/// assert_ne!(first, second);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bayer {
+    size: usize,
+    x: usize,
+    y: usize,
+}
+
+impl Bayer {
+    /// Create a dithering state with a 4×4 Bayer matrix, starting in the top
+    /// left corner.
+    #[inline]
+    pub fn new_4x4() -> Self {
+        Bayer {
+            size: 4,
+            x: 0,
+            y: 0,
+        }
+    }
+
+    /// Create a dithering state with an 8×8 Bayer matrix, starting in the top
+    /// left corner.
+    #[inline]
+    pub fn new_8x8() -> Self {
+        Bayer {
+            size: 8,
+            x: 0,
+            y: 0,
+        }
+    }
+
+    /// Move to the pixel at `(x, y)`, for example when processing a region
+    /// that doesn't start at the top left corner of the image.
+    #[inline]
+    pub fn set_position(&mut self, x: usize, y: usize) {
+        self.x = x % self.size;
+        self.y = y % self.size;
+    }
+}
+
+impl Dither for Bayer {
+    #[inline]
+    fn next_offset(&mut self) -> f32 {
+        // The top left quarter of the 8×8 matrix is the 4×4 matrix times 4.
+        let shift = if self.size == 4 { 2 } else { 0 };
+        let threshold = BAYER_8X8[self.y][self.x] >> shift;
+        let levels = (self.size * self.size) as f32;
+
+        self.x = (self.x + 1) % self.size;
+
+        (threshold as f32 + 0.5) / levels - 0.5
+    }
+
+    #[inline]
+    fn next_row(&mut self) {
+        self.x = 0;
+        self.y = (self.y + 1) % self.size;
+    }
+}
+
+/// Quantize an encoded value in `[0.0, 1.0]` to `u8`, after adding `offset`
+/// quantization steps.
+#[inline]
+pub(crate) fn quantize_u8(encoded: f32, offset: f32) -> u8 {
+    // Float to integer casts saturate, which takes care of clamping the result.
+    (encoded * 255.0 + offset + 0.5) as u8
+}
+
+#[rustfmt::skip]
+const BAYER_8X8: [[u8; 8]; 8] = [
+    [ 0, 32,  8, 40,  2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44,  4, 36, 14, 46,  6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [ 3, 35, 11, 43,  1, 33,  9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47,  7, 39, 13, 45,  5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+#[cfg(test)]
+mod test {
+    use super::{Bayer, Dither};
+
+    fn assert_full_matrix(mut dither: Bayer, size: usize) {
+        let mut offsets = [0.0f32; 64];
+        let offsets = &mut offsets[..size * size];
+
+        for row in offsets.chunks_mut(size) {
+            for offset in row {
+                *offset = dither.next_offset();
+            }
+            dither.next_row();
+        }
+
+        offsets.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        let levels = (size * size) as f32;
+
+        for (index, &offset) in offsets.iter().enumerate() {
+            assert_eq!(offset, (index as f32 + 0.5) / levels - 0.5);
+        }
+    }
+
+    #[test]
+    fn bayer_4x4_covers_all_levels() {
+        assert_full_matrix(Bayer::new_4x4(), 4);
+    }
+
+    #[test]
+    fn bayer_8x8_covers_all_levels() {
+        assert_full_matrix(Bayer::new_8x8(), 8);
+    }
+
+    #[test]
+    fn bayer_wraps_around() {
+        let mut dither = Bayer::new_4x4();
+        let first = dither.next_offset();
+
+        for _ in 0..3 {
+            dither.next_offset();
+        }
+
+        assert_eq!(dither.next_offset(), first);
+    }
+}
@@ -0,0 +1,5 @@
+//! Pre-calculated conversion matrices for the built-in RGB spaces.
+
+mod codegen;
+
+pub use self::codegen::*;
@@ -0,0 +1,229 @@
+// This file is auto-generated and any manual changes to it will be overwritten.
+//
+// Run `cargo run -p codegen` from the project root to regenerate it.
+
+pub mod srgb {
+    pub const RGB_TO_XYZ: [f64; 9] = [
+        0.41245643908969243f64,
+        0.357576077643909f64,
+        0.1804374832663989f64,
+        0.21267285140562264f64,
+        0.715152155287818f64,
+        0.07217499330655958f64,
+        0.01933389558232931f64,
+        0.11919202588130297f64,
+        0.9503040785363678f64,
+    ];
+    pub const XYZ_TO_RGB: [f64; 9] = [
+        3.240454162114103f64,
+        -1.5371385127977157f64,
+        -0.49853140955601577f64,
+        -0.9692660305051868f64,
+        1.8760108454466942f64,
+        0.0415560175303498f64,
+        0.05564343095911474f64,
+        -0.20402591351675387f64,
+        1.0572251882231793f64,
+    ];
+}
+
+pub mod adobe {
+    pub const RGB_TO_XYZ: [f64; 9] = [
+        0.5767308871981476f64,
+        0.18555395071121408f64,
+        0.18818516209063843f64,
+        0.29737686371154487f64,
+        0.6273490714522f64,
+        0.07527406483625537f64,
+        0.02703426033741314f64,
+        0.0706872193185578f64,
+        0.9911085203440291f64,
+    ];
+    pub const XYZ_TO_RGB: [f64; 9] = [
+        2.0413689792600795f64,
+        -0.5649463871751956f64,
+        -0.3446943843778484f64,
+        -0.9692660305051863f64,
+        1.876010845446693f64,
+        0.04155601753034985f64,
+        0.013447387216170273f64,
+        -0.11838974235412555f64,
+        1.0154095719504166f64,
+    ];
+}
+
+pub mod rec2020 {
+    pub const RGB_TO_XYZ: [f64; 9] = [
+        0.6370101914111009f64,
+        0.14461502739696927f64,
+        0.16884478119192986f64,
+        0.2627217173616405f64,
+        0.6779892755022618f64,
+        0.0592890071360975f64,
+        0.00000000000000004994515405547192f64,
+        0.028072328847646908f64,
+        1.060757671152353f64,
+    ];
+    pub const XYZ_TO_RGB: [f64; 9] = [
+        1.7165106697619736f64,
+        -0.3556416699867159f64,
+        -0.2533455418219072f64,
+        -0.6666930011826243f64,
+        1.6165022083469107f64,
+        0.015768750389995017f64,
+        0.017643638767459013f64,
+        -0.04277978166904463f64,
+        0.9423050727200185f64,
+    ];
+}
+
+pub mod dci_p3 {
+    pub const RGB_TO_XYZ: [f64; 9] = [
+        0.4451698155645526f64,
+        0.2771344092067777f64,
+        0.17228266981556456f64,
+        0.20949167791273063f64,
+        0.7215952541610436f64,
+        0.06891306792622583f64,
+        -0.00000000000000003634101316969858f64,
+        0.04706056005398116f64,
+        0.9073553943619734f64,
+    ];
+    pub const XYZ_TO_RGB: [f64; 9] = [
+        2.725394030491732f64,
+        -1.0180030062271845f64,
+        -0.4401631951900363f64,
+        -0.7951680258087642f64,
+        1.689732054843624f64,
+        0.022647190608477457f64,
+        0.04124189139570004f64,
+        -0.0876390192158624f64,
+        1.1009293786463221f64,
+    ];
+}
+
+pub mod dci_p3_plus {
+    pub const RGB_TO_XYZ: [f64; 9] = [
+        0.559073555024972f64,
+        0.24893594934080762f64,
+        0.08657739022111492f64,
+        0.20398629710370605f64,
+        0.8825910931174088f64,
+        -0.08657739022111492f64,
+        -0.007555048040878009f64,
+        0f64,
+        0.9619710024568324f64,
+    ];
+    pub const XYZ_TO_RGB: [f64; 9] = [
+        1.9904034896401308f64,
+        -0.5613958560523447f64,
+        -0.22966194111232283f64,
+        -0.4584927916120578f64,
+        1.262346002621232f64,
+        0.1548754914809961f64,
+        0.015632065775950686f64,
+        -0.004409044193216861f64,
+        1.0377286742034944f64,
+    ];
+}
+
+pub mod display_p3 {
+    pub const RGB_TO_XYZ: [f64; 9] = [
+        0.4866326500000001f64,
+        0.26566316250000005f64,
+        0.19817418750000002f64,
+        0.22900360000000006f64,
+        0.6917267250000001f64,
+        0.079269675f64,
+        -0.000000000000000039725792100320245f64,
+        0.04511261250000005f64,
+        1.0437173875f64,
+    ];
+    pub const XYZ_TO_RGB: [f64; 9] = [
+        2.493180755328966f64,
+        -0.9312655254971396f64,
+        -0.40265972375888165f64,
+        -0.8295031158210786f64,
+        1.762694121119792f64,
+        0.023625088741739592f64,
+        0.035853625780071695f64,
+        -0.07618895478265218f64,
+        0.9570926215180214f64,
+    ];
+}
+
+pub mod prophoto {
+    pub const RGB_TO_XYZ: [f64; 9] = [
+        0.7976749444306043f64,
+        0.13519170147409817f64,
+        0.03135335409529741f64,
+        0.2880402378623102f64,
+        0.7118740972357902f64,
+        0.0000856649018997197f64,
+        0f64,
+        0f64,
+        0.82521f64,
+    ];
+    pub const XYZ_TO_RGB: [f64; 9] = [
+        1.3459433009386657f64,
+        -0.255607509316767f64,
+        -0.05111176587088495f64,
+        -0.544598869458717f64,
+        1.508167317720767f64,
+        0.020535141586646908f64,
+        0f64,
+        -0f64,
+        1.2118127506937628f64,
+    ];
+}
+
+pub mod s_gamut3_cine {
+    pub const RGB_TO_XYZ: [f64; 9] = [
+        0.5991282178962282f64,
+        0.2489154902660598f64,
+        0.10242629183771203f64,
+        0.21509172313506889f64,
+        0.8850328542793237f64,
+        -0.10012457741439265f64,
+        -0.032068220540137575f64,
+        -0.02765727669622889f64,
+        1.1485554972363663f64,
+    ];
+    pub const XYZ_TO_RGB: [f64; 9] = [
+        1.8466424258585883f64,
+        -0.5259472336639536f64,
+        -0.21052964451921677f64,
+        -0.44417115256402584f64,
+        1.259493630807477f64,
+        0.14940599043932745f64,
+        0.04086347783558861f64,
+        0.015643973674762456f64,
+        0.8683784581126953f64,
+    ];
+}
+
+pub mod v_gamut {
+    pub const RGB_TO_XYZ: [f64; 9] = [
+        0.6796876796098115f64,
+        0.15220671663983332f64,
+        0.11857560375035515f64,
+        0.26070212368595513f64,
+        0.7748705574391515f64,
+        -0.035572681125106545f64,
+        -0.009310790131641261f64,
+        -0.004612324746661621f64,
+        1.102753114878303f64,
+    ];
+    pub const XYZ_TO_RGB: [f64; 9] = [
+        1.5889107557520104f64,
+        -0.3131845731361911f64,
+        -0.18095334706374921f64,
+        -0.5340693867628862f64,
+        1.3960545023455835f64,
+        0.10246083198565174f64,
+        0.011181753167910488f64,
+        0.003194786619402998f64,
+        0.9057220066007489f64,
+    ];
+}
+
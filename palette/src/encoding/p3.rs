@@ -11,7 +11,7 @@ use crate::{
     num::{Powf, Real},
     rgb::{Primaries, RgbSpace, RgbStandard},
     white_point::{Any, WhitePoint, D65},
-    Mat3, Xyz, Yxy,
+    Xyz, Yxy,
 };
 
 /// The theatrical DCI-P3 standard.
@@ -53,32 +53,25 @@ impl<T: Real> WhitePoint<T> for DciP3 {
             T::from_f64(0.335 / 0.351),
         )
     }
+
+    #[inline]
+    fn name() -> &'static str {
+        "DCI-P3"
+    }
 }
 
 impl RgbSpace for DciP3 {
     type Primaries = DciP3;
     type WhitePoint = DciP3;
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn rgb_to_xyz_matrix() -> Option<Mat3<f64>> {
-        // Matrix calculated using https://www.russellcottrell.com/photo/matrixCalculator.htm
-        Some([
-            0.4451698, 0.2771344, 0.1722827,
-            0.2094917, 0.7215953, 0.0689131,
-            0.0000000, 0.0470606, 0.9073554,
-        ])
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::dci_p3::RGB_TO_XYZ)
     }
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn xyz_to_rgb_matrix() -> Option<Mat3<f64>> {
-        // Matrix calculated using https://www.russellcottrell.com/photo/matrixCalculator.htm
-        Some([
-             2.7253940, -1.0180030, -0.4401632,
-            -0.7951680,  1.6897321,  0.0226472,
-             0.0412419, -0.0876390,  1.1009294,
-        ])
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::dci_p3::XYZ_TO_RGB)
     }
 }
 
@@ -128,26 +121,14 @@ impl<F> RgbSpace for DciP3Plus<F> {
     type Primaries = DciP3Plus<F>;
     type WhitePoint = DciP3;
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn rgb_to_xyz_matrix() -> Option<Mat3<f64>> {
-        // Matrix calculated using https://www.russellcottrell.com/photo/matrixCalculator.htm
-        Some([
-            0.5590736, 0.2489359,  0.0865774,
-            0.2039863, 0.8825911, -0.0865774,
-           -0.0075550, 0.0000000,  0.9619710,
-        ])
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::dci_p3_plus::RGB_TO_XYZ)
     }
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn xyz_to_rgb_matrix() -> Option<Mat3<f64>> {
-        // Matrix calculated using https://www.russellcottrell.com/photo/matrixCalculator.htm
-        Some([
-             1.9904035, -0.5613959, -0.2296619,
-            -0.4584928,  1.2623460,  0.1548755,
-             0.0156321, -0.0044090,  1.0377287,
-        ])
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::dci_p3_plus::XYZ_TO_RGB)
     }
 }
 
@@ -247,26 +228,14 @@ impl RgbSpace for DisplayP3 {
     type Primaries = DisplayP3;
     type WhitePoint = D65;
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn rgb_to_xyz_matrix() -> Option<Mat3<f64>> {
-        // Matrix calculated using https://www.russellcottrell.com/photo/matrixCalculator.htm
-        Some([
-            0.4866327, 0.2656632, 0.1981742,
-            0.2290036, 0.6917267, 0.0792697,
-            0.0000000, 0.0451126, 1.0437174,
-        ])
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::display_p3::RGB_TO_XYZ)
     }
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn xyz_to_rgb_matrix() -> Option<Mat3<f64>> {
-        // Matrix calculated using https://www.russellcottrell.com/photo/matrixCalculator.htm
-        Some([
-             2.4931808, -0.9312655, -0.4026597,
-            -0.8295031,  1.7626941,  0.0236251,
-             0.0358536, -0.0761890,  0.9570926,
-        ])
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::display_p3::XYZ_TO_RGB)
     }
 }
 
@@ -297,42 +266,42 @@ mod test {
         fn rgb_to_xyz_display_p3() {
             let dynamic = rgb_to_xyz_matrix::<DisplayP3, f64>();
             let constant = DisplayP3::rgb_to_xyz_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
         fn xyz_to_rgb_display_p3() {
             let dynamic = matrix_inverse(rgb_to_xyz_matrix::<DisplayP3, f64>());
             let constant = DisplayP3::xyz_to_rgb_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
         fn rgb_to_xyz_dci_p3() {
             let dynamic = rgb_to_xyz_matrix::<DciP3, f64>();
             let constant = DciP3::rgb_to_xyz_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
         fn xyz_to_rgb_dci_p3() {
             let dynamic = matrix_inverse(rgb_to_xyz_matrix::<DciP3, f64>());
             let constant = DciP3::xyz_to_rgb_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
         fn rgb_to_xyz_dci_p3_plus() {
             let dynamic = rgb_to_xyz_matrix::<DciP3Plus<P3Gamma>, f64>();
             let constant = DciP3Plus::<P3Gamma>::rgb_to_xyz_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
         fn xyz_to_rgb_dci_p3_plus() {
             let dynamic = matrix_inverse(rgb_to_xyz_matrix::<DciP3Plus<P3Gamma>, f64>());
             let constant = DciP3Plus::<P3Gamma>::xyz_to_rgb_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
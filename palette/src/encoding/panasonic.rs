@@ -0,0 +1,212 @@
+//! Panasonic's V-Gamut color space and V-Log transfer function.
+
+use crate::{
+    bool_mask::LazySelect,
+    encoding::{FromLinear, IntoLinear},
+    luma::LumaStandard,
+    num::{Arithmetics, Ln, PartialCmp, Powf, Real},
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, D65},
+    Yxy,
+};
+
+/// The V-Gamut color space and V-Log standard from Panasonic.
+///
+/// V-Gamut is a wide gamut color space for Panasonic's VariCam and Lumix
+/// cameras. It's recorded with the [`VLog`] transfer function.
+///
+/// ```
+/// use palette::{encoding::VGamut, rgb::Rgb, Srgb, FromColor};
+///
+/// // An 18% gray card, as recorded in V-Log.
+/// let gray = Rgb::<VGamut, f32>::new(0.4233, 0.4233, 0.4233);
+/// let srgb = Srgb::from_color(gray.into_linear::<f32>());
+///
+/// assert!((srgb.red - 0.4614).abs() < 0.001);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VGamut;
+
+impl<T: Real> Primaries<T> for VGamut {
+    // Primary values from the "V-Log/V-Gamut Reference Manual" by Panasonic,
+    // with `luma` values taken from the conversion matrix in the `RgbSpace`
+    // implementation.
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.730),
+            T::from_f64(0.280),
+            T::from_f64(0.260702),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.165),
+            T::from_f64(0.840),
+            T::from_f64(0.774871),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.100),
+            T::from_f64(-0.030),
+            T::from_f64(-0.035573),
+        )
+    }
+}
+
+impl RgbSpace for VGamut {
+    type Primaries = VGamut;
+    type WhitePoint = D65;
+
+    #[inline(always)]
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::v_gamut::RGB_TO_XYZ)
+    }
+
+    #[inline(always)]
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::v_gamut::XYZ_TO_RGB)
+    }
+}
+
+impl RgbStandard for VGamut {
+    type Space = VGamut;
+    type TransferFn = VLog;
+}
+
+impl LumaStandard for VGamut {
+    type WhitePoint = D65;
+    type TransferFn = VLog;
+}
+
+/// The V-Log transfer function from Panasonic.
+///
+/// V-Log is a logarithmic curve with a linear segment near black. Linear
+/// values are scene reflectance, where `0.18` is an 18% gray card and `0.9`
+/// is a 90% white card. The curve covers reflectance values far above `1.0`,
+/// so linear values are not limited to `0.0..=1.0`.
+///
+/// The encoded values are full range code values, divided by `1023.0`. The
+/// conversions are done with the formulas from the "V-Log/V-Gamut Reference
+/// Manual" by Panasonic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VLog;
+
+const VLOG_CUT_LINEAR: f64 = 0.01;
+const VLOG_CUT_ENCODED: f64 = 0.181;
+const VLOG_B: f64 = 0.00873;
+const VLOG_C: f64 = 0.241514;
+const VLOG_D: f64 = 0.598206;
+
+impl<T> IntoLinear<T, T> for VLog
+where
+    T: Real + Powf + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        lazy_select! {
+            if encoded.lt(&T::from_f64(VLOG_CUT_ENCODED)) =>
+                (encoded.clone() - T::from_f64(0.125)) / T::from_f64(5.6),
+            else => T::from_f64(10.0).powf((encoded.clone() - T::from_f64(VLOG_D)) / T::from_f64(VLOG_C))
+                - T::from_f64(VLOG_B),
+        }
+    }
+}
+
+impl<T> FromLinear<T, T> for VLog
+where
+    T: Real + Ln + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        lazy_select! {
+            if linear.lt(&T::from_f64(VLOG_CUT_LINEAR)) =>
+                T::from_f64(5.6) * &linear + T::from_f64(0.125),
+            else => (linear.clone() + T::from_f64(VLOG_B)).ln()
+                * T::from_f64(VLOG_C / core::f64::consts::LN_10)
+                + T::from_f64(VLOG_D),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "approx")]
+    mod conversion {
+        use crate::{
+            encoding::panasonic::VGamut,
+            matrix::{matrix_inverse, rgb_to_xyz_matrix},
+            rgb::RgbSpace,
+        };
+
+        #[test]
+        fn rgb_to_xyz() {
+            let dynamic = rgb_to_xyz_matrix::<VGamut, f64>();
+            let constant = VGamut::rgb_to_xyz_matrix().unwrap();
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
+        }
+
+        #[test]
+        fn xyz_to_rgb() {
+            let dynamic = matrix_inverse(rgb_to_xyz_matrix::<VGamut, f64>());
+            let constant = VGamut::xyz_to_rgb_matrix().unwrap();
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
+        }
+
+        #[test]
+        fn published_matrix() {
+            // The V-Gamut to XYZ matrix from Panasonic's reference manual. It's
+            // calculated with a slightly different D65 white point.
+            let published = [
+                0.679644, 0.152211, 0.118600, 0.260686, 0.774894, -0.035580, -0.009310, -0.004612,
+                1.102980,
+            ];
+            let constant = VGamut::rgb_to_xyz_matrix().unwrap();
+            assert_relative_eq!(published[..], constant[..], epsilon = 0.0005);
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    mod transfer {
+        use crate::encoding::{FromLinear, IntoLinear, VLog};
+
+        // 10 bit code values and IRE from the reflectance table in Panasonic's
+        // reference manual.
+        const REFLECTANCE_TABLE: [(f64, f64, f64); 3] =
+            [(0.0, 128.0, 7.3), (0.18, 433.0, 42.0), (0.9, 602.0, 61.0)];
+
+        fn code_to_ire(code: f64) -> f64 {
+            (code - 64.0) / (940.0 - 64.0) * 100.0
+        }
+
+        #[test]
+        fn reflectance_table() {
+            for &(reflectance, code, ire) in &REFLECTANCE_TABLE {
+                let encoded: f64 = VLog::from_linear(reflectance);
+                assert_eq!((encoded * 1023.0).round(), code);
+                assert!((code_to_ire(encoded * 1023.0) - ire).abs() < 0.5);
+
+                let linear: f64 = VLog::into_linear(code / 1023.0);
+                assert_relative_eq!(linear, reflectance, epsilon = 0.005);
+            }
+        }
+
+        #[test]
+        fn lin_to_enc_to_lin() {
+            for i in 0..=1000 {
+                let linear = i as f64 / 100.0;
+                let encoded: f64 = VLog::from_linear(linear);
+                assert_relative_eq!(linear, VLog::into_linear(encoded), epsilon = 0.0000001);
+            }
+        }
+
+        #[test]
+        fn continuous_at_cut() {
+            let below: f64 = VLog::from_linear(0.009_999_999);
+            let above: f64 = VLog::from_linear(0.01);
+            assert_relative_eq!(below, above, epsilon = 0.000001);
+        }
+    }
+}
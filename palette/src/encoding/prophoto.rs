@@ -7,7 +7,7 @@ use crate::{
     num::{Arithmetics, PartialCmp, Powf, Real},
     rgb::{Primaries, RgbSpace, RgbStandard},
     white_point::{Any, D50},
-    Mat3, Yxy,
+    Yxy,
 };
 
 #[cfg(feature = "gamma_lut_u16")]
@@ -63,26 +63,14 @@ impl RgbSpace for ProPhotoRgb {
     type Primaries = ProPhotoRgb;
     type WhitePoint = D50;
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn rgb_to_xyz_matrix() -> Option<Mat3<f64>> {
-        // Matrix from http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
-        Some([
-            0.7976749, 0.1351917, 0.0313534,
-            0.2880402, 0.7118741, 0.0000857,
-            0.0000000, 0.0000000, 0.8252100,
-        ])
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::prophoto::RGB_TO_XYZ)
     }
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn xyz_to_rgb_matrix() -> Option<Mat3<f64>> {
-        // Matrix from http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
-        Some([
-             1.3459433, -0.2556075, -0.0511118,
-            -0.5445989,  1.5081673,  0.0205351,
-             0.0000000,  0.0000000,  1.2118128,
-        ])
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::prophoto::XYZ_TO_RGB)
     }
 }
 
@@ -176,14 +164,14 @@ mod test {
         fn rgb_to_xyz() {
             let dynamic = rgb_to_xyz_matrix::<ProPhotoRgb, f64>();
             let constant = ProPhotoRgb::rgb_to_xyz_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
         fn xyz_to_rgb() {
             let dynamic = matrix_inverse(rgb_to_xyz_matrix::<ProPhotoRgb, f64>());
             let constant = ProPhotoRgb::xyz_to_rgb_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
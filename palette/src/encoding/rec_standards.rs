@@ -11,7 +11,7 @@ use crate::{
     num::{Arithmetics, MulAdd, MulSub, PartialCmp, Powf, Real},
     rgb::{Primaries, RgbSpace, RgbStandard},
     white_point::{Any, D65},
-    Mat3, Yxy,
+    Yxy,
 };
 
 /// The Rec. 2020 standard, color space, and transfer function ([`RecOetf`]).
@@ -36,28 +36,14 @@ impl RgbSpace for Rec2020 {
     type Primaries = Rec2020;
     type WhitePoint = D65;
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn rgb_to_xyz_matrix() -> Option<Mat3<f64>> {
-        // Matrix calculated using specified primary values and white point
-        // using formulas from http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
-        Some([
-            0.6370102, 0.1446150, 0.1688448,
-            0.2627217, 0.6779893, 0.0592890,
-            0.0000000, 0.0280723, 1.0607577,
-        ])
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::rec2020::RGB_TO_XYZ)
     }
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn xyz_to_rgb_matrix() -> Option<Mat3<f64>> {
-        // Matrix calculated using specified primary values and white point
-        // using formulas from http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
-        Some([
-             1.7165107, -0.3556417, -0.2533455,
-            -0.6666930,  1.6165022,  0.0157688,
-             0.0176436, -0.0427798,  0.9423051,
-        ])
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::rec2020::XYZ_TO_RGB)
     }
 }
 
@@ -175,14 +161,14 @@ mod test {
         fn rgb_to_xyz() {
             let dynamic = rgb_to_xyz_matrix::<Rec2020, f64>();
             let constant = Rec2020::rgb_to_xyz_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
         fn xyz_to_rgb() {
             let dynamic = matrix_inverse(rgb_to_xyz_matrix::<Rec2020, f64>());
             let constant = Rec2020::xyz_to_rgb_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
     }
 
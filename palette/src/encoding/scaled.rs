@@ -0,0 +1,250 @@
+//! Transfer functions with scale and offset adjustments.
+
+use core::marker::PhantomData;
+
+use crate::{
+    bool_mask::LazySelect,
+    num::{Arithmetics, PartialCmp, Real},
+};
+
+use super::{FromLinear, IntoLinear};
+
+/// A transfer function that adds a scale and offset to another transfer
+/// function.
+///
+/// `ScaledTransfer` wraps the transfer function `F`, and adjusts it with the
+/// parameters in `P`. This makes it possible to express curves with the shape
+/// "gamma plus linear segment, with scale and offset", like many camera log
+/// encodings and the ICC parametric curves, without implementing the whole
+/// transfer function from scratch. Converting an encoded value `x` into linear
+/// space is done like this:
+///
+/// ```text
+/// if x < LINEAR_THRESHOLD {
+///     LINEAR_SLOPE * x + LINEAR_OFFSET
+/// } else {
+///     OUTPUT_SCALE * F(INPUT_SCALE * x + INPUT_OFFSET) + OUTPUT_OFFSET
+/// }
+/// ```
+///
+/// Converting from linear space is the inverse of the same steps. The curve
+/// has to be continuous and increasing for it to be a true inverse, so the
+/// linear segment is expected to meet the rest of the curve at
+/// `LINEAR_THRESHOLD`.
+///
+/// ```
+/// use palette::{
+///     convert::FromColorUnclampedMut,
+///     encoding::{self, scaled::ScaleOffset, ScaledTransfer},
+///     rgb::Rgb,
+///     LinSrgb,
+/// };
+///
+/// // sRGB in the limited range from video signals, where black is 16/255
+/// // and white is 235/255.
+/// struct LimitedRange;
+///
+/// impl ScaleOffset for LimitedRange {
+///     const INPUT_SCALE: f64 = 255.0 / 219.0;
+///     const INPUT_OFFSET: f64 = -16.0 / 219.0;
+/// }
+///
+/// type LimitedSrgb = (encoding::Srgb, ScaledTransfer<encoding::Srgb, LimitedRange>);
+///
+/// let black = Rgb::<LimitedSrgb, f32>::new(16.0 / 255.0, 16.0 / 255.0, 16.0 / 255.0);
+/// let white = Rgb::<LimitedSrgb, f32>::new(235.0 / 255.0, 235.0 / 255.0, 235.0 / 255.0);
+///
+/// assert!(black.into_linear::<f32>().red.abs() < 0.0001);
+/// assert!((white.into_linear::<f32>().red - 1.0).abs() < 0.0001);
+///
+/// // Slices can be converted in place, like any other RGB standard.
+/// let mut image = [black, white];
+/// let linear = <[LinSrgb]>::from_color_unclamped_mut(&mut image);
+/// assert!(linear[0].red.abs() < 0.0001);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ScaledTransfer<F, P>(PhantomData<(F, P)>);
+
+/// The scale and offset parameters for [`ScaledTransfer`].
+///
+/// All parameters have default values that leave the inner transfer function
+/// unchanged, so only the ones that differ need to be set.
+pub trait ScaleOffset: 'static {
+    /// The scale that is applied to encoded values before the inner transfer
+    /// function.
+    const INPUT_SCALE: f64 = 1.0;
+
+    /// The offset that is added to encoded values before the inner transfer
+    /// function, after scaling them.
+    const INPUT_OFFSET: f64 = 0.0;
+
+    /// The scale that is applied to the result of the inner transfer function.
+    const OUTPUT_SCALE: f64 = 1.0;
+
+    /// The offset that is added to the result of the inner transfer function,
+    /// after scaling it.
+    const OUTPUT_OFFSET: f64 = 0.0;
+
+    /// Encoded values below this threshold use the linear segment instead of
+    /// the inner transfer function. The default is negative infinity, which
+    /// disables the linear segment.
+    const LINEAR_THRESHOLD: f64 = f64::NEG_INFINITY;
+
+    /// The slope of the linear segment.
+    const LINEAR_SLOPE: f64 = 1.0;
+
+    /// The offset of the linear segment.
+    const LINEAR_OFFSET: f64 = 0.0;
+}
+
+impl<T, F, P> IntoLinear<T, T> for ScaledTransfer<F, P>
+where
+    T: Real + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+    F: IntoLinear<T, T>,
+    P: ScaleOffset,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        lazy_select! {
+            if encoded.lt(&T::from_f64(P::LINEAR_THRESHOLD)) =>
+                T::from_f64(P::LINEAR_SLOPE) * &encoded + T::from_f64(P::LINEAR_OFFSET),
+            else => {
+                let input = T::from_f64(P::INPUT_SCALE) * &encoded + T::from_f64(P::INPUT_OFFSET);
+                T::from_f64(P::OUTPUT_SCALE) * F::into_linear(input) + T::from_f64(P::OUTPUT_OFFSET)
+            },
+        }
+    }
+}
+
+impl<T, F, P> FromLinear<T, T> for ScaledTransfer<F, P>
+where
+    T: Real + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+    F: FromLinear<T, T>,
+    P: ScaleOffset,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        let linear_threshold = P::LINEAR_SLOPE * P::LINEAR_THRESHOLD + P::LINEAR_OFFSET;
+
+        lazy_select! {
+            if linear.lt(&T::from_f64(linear_threshold)) =>
+                (linear.clone() - T::from_f64(P::LINEAR_OFFSET)) / T::from_f64(P::LINEAR_SLOPE),
+            else => {
+                let output = (linear.clone() - T::from_f64(P::OUTPUT_OFFSET)) / T::from_f64(P::OUTPUT_SCALE);
+                (F::from_linear(output) - T::from_f64(P::INPUT_OFFSET)) / T::from_f64(P::INPUT_SCALE)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(deprecated)]
+
+    use super::{ScaleOffset, ScaledTransfer};
+    use crate::{
+        convert::FromColorUnclampedMut,
+        encoding::{
+            gamma::{GammaFn, Number},
+            FromLinear, IntoLinear, Srgb,
+        },
+        rgb::Rgb,
+        LinSrgb,
+    };
+
+    // `GammaFn` raises encoded values to `1 / VALUE` when converting them into
+    // linear space.
+    struct InverseGamma2p4;
+
+    impl Number for InverseGamma2p4 {
+        const VALUE: f64 = 1.0 / 2.4;
+    }
+
+    // The ICC parametric curve type 3, with the sRGB parameters:
+    // Y = (a * X + b)^g for X >= d, and Y = c * X for X < d.
+    struct IccSrgbParameters;
+
+    impl ScaleOffset for IccSrgbParameters {
+        const INPUT_SCALE: f64 = 1.0 / 1.055;
+        const INPUT_OFFSET: f64 = 0.055 / 1.055;
+        const LINEAR_THRESHOLD: f64 = 0.04045;
+        const LINEAR_SLOPE: f64 = 1.0 / 12.92;
+    }
+
+    type IccSrgb = ScaledTransfer<GammaFn<InverseGamma2p4>, IccSrgbParameters>;
+
+    struct Identity;
+
+    impl ScaleOffset for Identity {}
+
+    #[test]
+    fn icc_parametric_type_3() {
+        for step in 0..=1000 {
+            let encoded = step as f64 / 1000.0;
+            let linear: f64 = IccSrgb::into_linear(encoded);
+            let expected: f64 = Srgb::into_linear(encoded);
+            assert!((linear - expected).abs() < 1e-12, "{}", encoded);
+
+            let linear = encoded;
+            let encoded: f64 = IccSrgb::from_linear(linear);
+            let expected: f64 = Srgb::from_linear(linear);
+            assert!((encoded - expected).abs() < 1e-12, "{}", linear);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        struct Shifted;
+
+        impl ScaleOffset for Shifted {
+            const INPUT_SCALE: f64 = 0.8;
+            const INPUT_OFFSET: f64 = 0.1;
+            const OUTPUT_SCALE: f64 = 2.0;
+            const OUTPUT_OFFSET: f64 = -0.05;
+        }
+
+        type ShiftedSrgb = ScaledTransfer<Srgb, Shifted>;
+
+        for step in 0..=100 {
+            let encoded = step as f64 / 100.0;
+            let linear: f64 = ShiftedSrgb::into_linear(encoded);
+            let round_trip: f64 = ShiftedSrgb::from_linear(linear);
+            assert!((round_trip - encoded).abs() < 1e-12, "{}", encoded);
+        }
+    }
+
+    #[test]
+    fn default_parameters() {
+        for step in 0..=100 {
+            let encoded = step as f32 / 100.0;
+            let linear: f32 = ScaledTransfer::<Srgb, Identity>::into_linear(encoded);
+            let expected: f32 = Srgb::into_linear(encoded);
+            assert_eq!(linear, expected);
+
+            let linear = encoded;
+            let encoded: f32 = ScaledTransfer::<Srgb, Identity>::from_linear(linear);
+            let expected: f32 = Srgb::from_linear(linear);
+            assert_eq!(encoded, expected);
+        }
+    }
+
+    #[test]
+    fn slice_conversion() {
+        let colors = [
+            Rgb::<(Srgb, IccSrgb), f64>::new(0.8, 0.02, 0.5),
+            Rgb::new(0.0, 1.0, 0.3),
+        ];
+
+        let mut converted = colors;
+        let linear = <[LinSrgb<f64>]>::from_color_unclamped_mut(&mut converted);
+
+        for (color, linear) in colors.iter().zip(linear.iter()) {
+            let expected = crate::Srgb::new(color.red, color.green, color.blue).into_linear();
+            assert!((linear.red - expected.red).abs() < 1e-12);
+            assert!((linear.green - expected.green).abs() < 1e-12);
+            assert!((linear.blue - expected.blue).abs() < 1e-12);
+        }
+    }
+}
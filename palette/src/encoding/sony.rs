@@ -0,0 +1,223 @@
+//! Sony's S-Gamut3.Cine color space and S-Log3 transfer function.
+
+use crate::{
+    bool_mask::LazySelect,
+    encoding::{FromLinear, IntoLinear},
+    luma::LumaStandard,
+    num::{Arithmetics, Ln, PartialCmp, Powf, Real},
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, D65},
+    Yxy,
+};
+
+/// The S-Gamut3.Cine color space and S-Log3 standard from Sony.
+///
+/// S-Gamut3.Cine is a wide gamut color space for Sony's digital cinema
+/// cameras, designed to be easy to grade for DCI-P3 output. It's recorded
+/// with the [`SLog3`] transfer function.
+///
+/// ```
+/// use palette::{encoding::SGamut3Cine, rgb::Rgb, Srgb, FromColor};
+///
+/// // An 18% gray card, as recorded in S-Log3.
+/// let gray = Rgb::<SGamut3Cine, f32>::new(0.4106, 0.4106, 0.4106);
+/// let srgb = Srgb::from_color(gray.into_linear::<f32>());
+///
+/// assert!((srgb.red - 0.4614).abs() < 0.001);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SGamut3Cine;
+
+impl<T: Real> Primaries<T> for SGamut3Cine {
+    // Primary values from the "Technical Summary for S-Gamut3.Cine/S-Log3 and
+    // S-Gamut3/S-Log3" by Sony, with `luma` values taken from the conversion
+    // matrix in the `RgbSpace` implementation.
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.766),
+            T::from_f64(0.275),
+            T::from_f64(0.215092),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.225),
+            T::from_f64(0.800),
+            T::from_f64(0.885033),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.089),
+            T::from_f64(-0.087),
+            T::from_f64(-0.100125),
+        )
+    }
+}
+
+impl RgbSpace for SGamut3Cine {
+    type Primaries = SGamut3Cine;
+    type WhitePoint = D65;
+
+    #[inline(always)]
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::s_gamut3_cine::RGB_TO_XYZ)
+    }
+
+    #[inline(always)]
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::s_gamut3_cine::XYZ_TO_RGB)
+    }
+}
+
+impl RgbStandard for SGamut3Cine {
+    type Space = SGamut3Cine;
+    type TransferFn = SLog3;
+}
+
+impl LumaStandard for SGamut3Cine {
+    type WhitePoint = D65;
+    type TransferFn = SLog3;
+}
+
+/// The S-Log3 transfer function from Sony.
+///
+/// S-Log3 is a logarithmic curve with a linear segment near black. Linear
+/// values are scene reflectance, where `0.18` is an 18% gray card and `0.9`
+/// is a 90% white card. The curve covers reflectance values far above `1.0`,
+/// so linear values are not limited to `0.0..=1.0`.
+///
+/// The encoded values are full range code values, divided by `1023.0`. The
+/// conversions are done with the formulas from the "Technical Summary for
+/// S-Gamut3.Cine/S-Log3 and S-Gamut3/S-Log3" by Sony.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SLog3;
+
+// The code value and reflectance where the linear segment meets the curve.
+const SLOG3_CUT_CODE: f64 = 171.210_294_692_9;
+const SLOG3_CUT_LINEAR: f64 = 0.011_25;
+
+impl<T> IntoLinear<T, T> for SLog3
+where
+    T: Real + Powf + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        let code = T::from_f64(1023.0) * encoded;
+
+        lazy_select! {
+            if code.lt(&T::from_f64(SLOG3_CUT_CODE)) =>
+                (code.clone() - T::from_f64(95.0)) * T::from_f64(SLOG3_CUT_LINEAR / (SLOG3_CUT_CODE - 95.0)),
+            else => T::from_f64(10.0).powf((code.clone() - T::from_f64(420.0)) / T::from_f64(261.5))
+                * T::from_f64(0.18 + 0.01)
+                - T::from_f64(0.01),
+        }
+    }
+}
+
+impl<T> FromLinear<T, T> for SLog3
+where
+    T: Real + Ln + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        let code = lazy_select! {
+            if linear.lt(&T::from_f64(SLOG3_CUT_LINEAR)) =>
+                linear.clone() * T::from_f64((SLOG3_CUT_CODE - 95.0) / SLOG3_CUT_LINEAR) + T::from_f64(95.0),
+            else => ((linear.clone() + T::from_f64(0.01)) / T::from_f64(0.18 + 0.01)).ln()
+                * T::from_f64(261.5 / core::f64::consts::LN_10)
+                + T::from_f64(420.0),
+        };
+
+        code / T::from_f64(1023.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "approx")]
+    mod conversion {
+        use crate::{
+            encoding::sony::SGamut3Cine,
+            matrix::{matrix_inverse, rgb_to_xyz_matrix},
+            rgb::RgbSpace,
+        };
+
+        #[test]
+        fn rgb_to_xyz() {
+            let dynamic = rgb_to_xyz_matrix::<SGamut3Cine, f64>();
+            let constant = SGamut3Cine::rgb_to_xyz_matrix().unwrap();
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
+        }
+
+        #[test]
+        fn xyz_to_rgb() {
+            let dynamic = matrix_inverse(rgb_to_xyz_matrix::<SGamut3Cine, f64>());
+            let constant = SGamut3Cine::xyz_to_rgb_matrix().unwrap();
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
+        }
+
+        #[test]
+        fn published_matrix() {
+            // The S-Gamut3.Cine to XYZ matrix from Sony's technical summary.
+            // It's calculated with a slightly different D65 white point.
+            let published = [
+                0.599083920,
+                0.248925516,
+                0.102446490,
+                0.215075820,
+                0.885068502,
+                -0.100144322,
+                -0.032065850,
+                -0.027658391,
+                1.148781896,
+            ];
+            let constant = SGamut3Cine::rgb_to_xyz_matrix().unwrap();
+            assert_relative_eq!(published[..], constant[..], epsilon = 0.0005);
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    mod transfer {
+        use crate::encoding::{FromLinear, IntoLinear, SLog3};
+
+        // 10 bit code values and IRE from the reflectance table in Sony's
+        // technical summary.
+        const REFLECTANCE_TABLE: [(f64, f64, f64); 3] =
+            [(0.0, 95.0, 3.5), (0.18, 420.0, 41.0), (0.9, 598.0, 61.0)];
+
+        fn code_to_ire(code: f64) -> f64 {
+            (code - 64.0) / (940.0 - 64.0) * 100.0
+        }
+
+        #[test]
+        fn reflectance_table() {
+            for &(reflectance, code, ire) in &REFLECTANCE_TABLE {
+                let encoded: f64 = SLog3::from_linear(reflectance);
+                assert_eq!((encoded * 1023.0).round(), code);
+                assert!((code_to_ire(encoded * 1023.0) - ire).abs() < 0.5);
+
+                let linear: f64 = SLog3::into_linear(code / 1023.0);
+                assert_relative_eq!(linear, reflectance, epsilon = 0.005);
+            }
+        }
+
+        #[test]
+        fn lin_to_enc_to_lin() {
+            for i in 0..=1000 {
+                let linear = i as f64 / 100.0;
+                let encoded: f64 = SLog3::from_linear(linear);
+                assert_relative_eq!(linear, SLog3::into_linear(encoded), epsilon = 0.0000001);
+            }
+        }
+
+        #[test]
+        fn continuous_at_cut() {
+            let below: f64 = SLog3::from_linear(0.011_249_999);
+            let above: f64 = SLog3::from_linear(0.011_25);
+            assert_relative_eq!(below, above, epsilon = 0.000001);
+        }
+    }
+}
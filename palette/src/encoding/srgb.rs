@@ -10,7 +10,12 @@ use crate::{
     num::{Arithmetics, MulAdd, MulSub, PartialCmp, Powf, Real},
     rgb::{Primaries, RgbSpace, RgbStandard},
     white_point::{Any, D65},
-    Mat3, Yxy,
+    Yxy,
+};
+
+#[cfg(feature = "gamma_lut_u16")]
+use super::lut::srgb_u16::{
+    SRGB_LINEAR_SCALE, SRGB_MIN_FLOAT as SRGB_U16_MIN_FLOAT, SRGB_U16_TO_F64, TO_SRGB_U16,
 };
 
 /// The sRGB standard, color space, and transfer function.
@@ -28,6 +33,16 @@ use crate::{
 /// * When converting from `f32` or `f64` to `u8`, while converting from linear
 ///   space. This uses a fast algorithm that guarantees a maximum error in the
 ///   result of less than 0.6 in line with [this DirectX spec](<https://microsoft.github.io/DirectX-Specs/d3d/archive/D3D11_3_FunctionalSpec.htm#FLOATtoSRGB>).
+///
+/// Using the `gamma_lut_u16` feature allows the same optimizations for `u16`:
+///
+/// * When converting from `u16` to `f32` or `f64`, while converting to linear
+///   space.
+/// * When converting from `f32` or `f64` to `u16`, while converting from linear
+///   space.
+///
+/// These apply to both RGB and luma, so `SrgbLuma<u8>` and `SrgbLuma<u16>` are
+/// decoded and encoded the same way as `Srgb<u8>` and `Srgb<u16>`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Srgb;
 
@@ -59,26 +74,14 @@ impl RgbSpace for Srgb {
     type Primaries = Srgb;
     type WhitePoint = D65;
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn rgb_to_xyz_matrix() -> Option<Mat3<f64>> {
-        // Matrix from http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
-        Some([
-            0.4124564, 0.3575761, 0.1804375,
-            0.2126729, 0.7151522, 0.0721750,
-            0.0193339, 0.1191920, 0.9503041,
-        ])
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::srgb::RGB_TO_XYZ)
     }
 
-    #[rustfmt::skip]
     #[inline(always)]
-    fn xyz_to_rgb_matrix() -> Option<Mat3<f64>> {
-        // Matrix from http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
-        Some([
-             3.2404542, -1.5371385, -0.4985314,
-            -0.9692660,  1.8760108,  0.0415560,
-             0.0556434, -0.2040259,  1.0572252,
-        ])
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
+        Some(super::matrix::srgb::XYZ_TO_RGB)
     }
 }
 
@@ -124,10 +127,16 @@ where
 impl IntoLinear<f32, u8> for Srgb {
     #[inline]
     fn into_linear(encoded: u8) -> f32 {
-        SRGB_U8_TO_F32[encoded as usize]
+        srgb_u8_to_linear_f32(encoded)
     }
 }
 
+/// Decode an 8 bit sRGB component in a `const` context.
+#[inline]
+pub(crate) const fn srgb_u8_to_linear_f32(encoded: u8) -> f32 {
+    SRGB_U8_TO_F32[encoded as usize]
+}
+
 impl FromLinear<f32, u8> for Srgb {
     #[inline]
     fn from_linear(linear: f32) -> u8 {
@@ -149,6 +158,43 @@ impl FromLinear<f64, u8> for Srgb {
     }
 }
 
+#[cfg(feature = "gamma_lut_u16")]
+impl IntoLinear<f32, u16> for Srgb {
+    #[inline]
+    fn into_linear(encoded: u16) -> f32 {
+        SRGB_U16_TO_F64[encoded as usize] as f32
+    }
+}
+
+#[cfg(feature = "gamma_lut_u16")]
+impl FromLinear<f32, u16> for Srgb {
+    #[inline]
+    fn from_linear(linear: f32) -> u16 {
+        lut::linear_f32_to_encoded_u16_with_linear_scale(
+            linear,
+            SRGB_LINEAR_SCALE,
+            SRGB_U16_MIN_FLOAT,
+            &TO_SRGB_U16,
+        )
+    }
+}
+
+#[cfg(feature = "gamma_lut_u16")]
+impl IntoLinear<f64, u16> for Srgb {
+    #[inline]
+    fn into_linear(encoded: u16) -> f64 {
+        SRGB_U16_TO_F64[encoded as usize]
+    }
+}
+
+#[cfg(feature = "gamma_lut_u16")]
+impl FromLinear<f64, u16> for Srgb {
+    #[inline]
+    fn from_linear(linear: f64) -> u16 {
+        <Srgb>::from_linear(linear as f32)
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[cfg(feature = "approx")]
@@ -163,14 +209,14 @@ mod test {
         fn rgb_to_xyz() {
             let dynamic = rgb_to_xyz_matrix::<Srgb, f64>();
             let constant = Srgb::rgb_to_xyz_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
 
         #[test]
         fn xyz_to_rgb() {
             let dynamic = matrix_inverse(rgb_to_xyz_matrix::<Srgb, f64>());
             let constant = Srgb::xyz_to_rgb_matrix().unwrap();
-            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+            assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000000001);
         }
     }
 
@@ -237,5 +283,95 @@ mod test {
                 assert_eq!(result, expected);
             }
         }
+
+        #[test]
+        fn luma_u8_matches_rgb() {
+            use crate::{LinLuma, LinSrgb, Srgb, SrgbLuma};
+
+            for i in 0..=255u8 {
+                let luma: LinLuma<_, f32> = SrgbLuma::new(i).into_linear();
+                let rgb: LinSrgb<f32> = Srgb::new(i, i, i).into_linear();
+                assert_eq!(luma.luma, rgb.red);
+                assert_eq!(SrgbLuma::<u8>::from_linear(luma).luma, i);
+            }
+        }
+
+        #[cfg(feature = "gamma_lut_u16")]
+        #[test]
+        fn luma_u16_matches_rgb() {
+            use crate::{LinLuma, LinSrgb, Srgb, SrgbLuma};
+
+            for i in 0..=65535u16 {
+                let luma: LinLuma<_, f32> = SrgbLuma::new(i).into_linear();
+                let rgb: LinSrgb<f32> = Srgb::new(i, i, i).into_linear();
+                assert_eq!(luma.luma, rgb.red);
+                assert_eq!(SrgbLuma::<u16>::from_linear(luma).luma, i);
+            }
+        }
+
+        /// The sRGB decoding function from the standard, evaluated in `f64`.
+        #[cfg(feature = "gamma_lut_u16")]
+        fn reference_into_linear(encoded: u16) -> f64 {
+            let encoded = encoded as f64 / 65535.0;
+            if encoded <= 0.04045 {
+                encoded / 12.92
+            } else {
+                ((encoded + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        #[cfg(feature = "gamma_lut_u16")]
+        #[test]
+        fn u16_to_f32_is_correctly_rounded() {
+            for i in 0..=65535u16 {
+                let u16_impl: f32 = Srgb::into_linear(i);
+                assert_eq!(u16_impl, reference_into_linear(i) as f32, "{}", i);
+            }
+        }
+
+        #[cfg(feature = "gamma_lut_u16")]
+        #[test]
+        fn u16_to_f64_matches_reference() {
+            for i in 0..=65535u16 {
+                let u16_impl: f64 = Srgb::into_linear(i);
+                assert_eq!(u16_impl, reference_into_linear(i), "{}", i);
+            }
+        }
+
+        #[cfg(feature = "gamma_lut_u16")]
+        #[test]
+        fn u16_to_f32_to_u16() {
+            for expected in 0..=65535u16 {
+                let linear: f32 = Srgb::into_linear(expected);
+                let result: u16 = Srgb::from_linear(linear);
+                assert_eq!(result, expected);
+            }
+        }
+
+        #[cfg(feature = "gamma_lut_u16")]
+        #[test]
+        fn u16_to_f64_to_u16() {
+            for expected in 0..=65535u16 {
+                let linear: f64 = Srgb::into_linear(expected);
+                let result: u16 = Srgb::from_linear(linear);
+                assert_eq!(result, expected);
+            }
+        }
+
+        #[cfg(feature = "gamma_lut_u16")]
+        #[test]
+        fn f32_to_u16_error() {
+            for i in 0..=10000 {
+                let linear = i as f32 / 10000.0;
+                let result: u16 = Srgb::from_linear(linear);
+                let expected = <Srgb as FromLinear<f64, f64>>::from_linear(linear as f64) * 65535.0;
+                assert!(
+                    (result as f64 - expected).abs() < 0.6,
+                    "{} != {}",
+                    result,
+                    expected
+                );
+            }
+        }
     }
 }
@@ -4,14 +4,15 @@ use core::{any::TypeId, marker::PhantomData, ops::Not};
 
 use crate::{
     angle::{FromAngle, RealAngle},
-    bool_mask::{BitOps, BoolMask, HasBoolMask, LazySelect, Select},
+    bool_mask::{BitOps, BoolMask, HasBoolMask, LazySelect},
     convert::FromColorUnclamped,
     encoding::Srgb,
+    hsv::{hue_u16_to_rgb, rgb_u8_to_hue},
     hues::RgbHueIter,
-    num::{Arithmetics, IsValidDivisor, MinMax, One, PartialCmp, Real, Zero},
+    num::{Abs, Arithmetics, IsValidDivisor, MinMax, One, PartialCmp, Real, Zero},
     rgb::{Rgb, RgbSpace, RgbStandard},
     stimulus::{FromStimulus, Stimulus},
-    Alpha, FromColor, Hsv, RgbHue, Xyz,
+    Alpha, FromColor, Hsv, IsAchromatic, RgbHue, TryGetHue, Xyz,
 };
 
 /// Linear HSL with an alpha component. See the [`Hsla` implementation in
@@ -49,7 +50,7 @@ pub type Hsla<S = Srgb, T = f32> = Alpha<Hsl<S, T>, T>;
     palette_internal,
     rgb_standard = "S",
     component = "T",
-    skip_derives(Rgb, Hsv, Hsl)
+    skip_derives(Rgb, Hsv, Hsl, Okhsl)
 )]
 #[repr(C)]
 pub struct Hsl<S = Srgb, T = f32> {
@@ -104,6 +105,23 @@ impl<S, T> Hsl<S, T> {
         }
     }
 
+    /// Create an HSL color, with the hue in radians instead of degrees.
+    ///
+    /// ```
+    /// use core::f32::consts::PI;
+    /// use palette::Hsl;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let hsl: Hsl = Hsl::new_radians(PI / 2.0, 0.5, 0.5);
+    /// assert_relative_eq!(hsl.hue.into_degrees(), 90.0);
+    /// ```
+    pub fn new_radians(hue: T, saturation: T, lightness: T) -> Self
+    where
+        T: RealAngle,
+    {
+        Self::new_const(RgbHue::from_radians(hue), saturation, lightness)
+    }
+
     /// Convert into another component type.
     pub fn into_format<U>(self) -> Hsl<S, U>
     where
@@ -171,6 +189,69 @@ where
     }
 }
 
+impl<S> Hsl<S, u16> {
+    /// Convert from RGB with `u8` components, using integer arithmetic.
+    ///
+    /// This is an alternative to converting via floating point components.
+    /// The components have the same ranges as when using
+    /// [`into_format`](Hsl::into_format), so the hue is in the range `[0,
+    /// 65535]`, where 65536 would be a full turn. The 16 bit hue and saturation
+    /// are precise enough to get the same RGB color back with
+    /// [`into_rgb_u8`](Hsl::into_rgb_u8). That's not the case for `Hsl<S, u8>`,
+    /// where the 256 hue steps can't represent every RGB hue.
+    ///
+    /// ```
+    /// use palette::{Hsl, Srgb};
+    ///
+    /// let hsl = Hsl::from_rgb_u8(Srgb::new(255u8, 128, 0));
+    /// assert_eq!(hsl, Hsl::new_srgb(5483u16, 65535, 32768));
+    ///
+    /// let rgb = hsl.into_rgb_u8();
+    /// assert_eq!(rgb, Srgb::new(255u8, 128, 0));
+    /// ```
+    pub fn from_rgb_u8(rgb: Rgb<S, u8>) -> Self {
+        let max = Ord::max(Ord::max(rgb.red, rgb.green), rgb.blue);
+        let min = Ord::min(Ord::min(rgb.red, rgb.green), rgb.blue);
+        let chroma = u32::from(max - min);
+
+        // The sum is twice the lightness, which keeps the precision.
+        let sum = u32::from(max) + u32::from(min);
+        let divisor = 255 - (sum as i32 - 255).unsigned_abs();
+        let saturation = (chroma * 65535 + divisor / 2)
+            .checked_div(divisor)
+            .unwrap_or(0) as u16;
+
+        Hsl {
+            hue: RgbHue::new(rgb_u8_to_hue(rgb.red, rgb.green, rgb.blue)),
+            saturation,
+            lightness: ((sum * 257 + 1) / 2) as u16,
+            standard: PhantomData,
+        }
+    }
+
+    /// Convert into RGB with `u8` components, using integer arithmetic.
+    ///
+    /// See [`from_rgb_u8`](Hsl::from_rgb_u8) for more details.
+    pub fn into_rgb_u8(self) -> Rgb<S, u8> {
+        let lightness = u64::from(self.lightness);
+        let max_chroma = 65535 - (lightness as i64 * 2 - 65535).unsigned_abs();
+
+        // The values are scaled by twice the usual amount, to avoid rounding
+        // when halving the chroma.
+        let chroma = max_chroma * u64::from(self.saturation);
+        let min = lightness * 65535 * 2 - chroma;
+
+        let [red, green, blue] =
+            hue_u16_to_rgb(self.hue.into_inner(), chroma * 2, min, 65535 * 65535 * 2);
+        Rgb {
+            red,
+            green,
+            blue,
+            standard: PhantomData,
+        }
+    }
+}
+
 ///<span id="Hsla"></span>[`Hsla`](crate::Hsla) implementations.
 impl<T, A> Alpha<Hsl<Srgb, T>, A> {
     /// Create an sRGB HSL color with transparency. This method can be used
@@ -244,6 +325,7 @@ impl<S, T, A> Alpha<Hsl<S, T>, A> {
 
 impl_reference_component_methods_hue!(Hsl<S>, [saturation, lightness], standard);
 impl_struct_of_arrays_methods_hue!(Hsl<S>, [saturation, lightness], standard);
+impl_sort_keys!(Hsl<S> {lightness: lightness, hue});
 
 impl<S1, S2, T> FromColorUnclamped<Hsl<S1, T>> for Hsl<S2, T>
 where
@@ -272,130 +354,13 @@ where
     T::Mask: BoolMask + BitOps + LazySelect<T> + Clone + 'static,
 {
     fn from_color_unclamped(rgb: Rgb<S, T>) -> Self {
-        // Avoid negative numbers
-        let red = rgb.red.max(T::zero());
-        let green = rgb.green.max(T::zero());
-        let blue = rgb.blue.max(T::zero());
-
-        // The SIMD optimized version showed significant slowdown for regular floats.
-        if TypeId::of::<T::Mask>() == TypeId::of::<bool>() {
-            let (max, min, sep, coeff) = {
-                let (max, min, sep, coeff) = if red.gt(&green).is_true() {
-                    (red.clone(), green.clone(), green.clone() - &blue, T::zero())
-                } else {
-                    (
-                        green.clone(),
-                        red.clone(),
-                        blue.clone() - &red,
-                        T::from_f64(2.0),
-                    )
-                };
-                if blue.gt(&max).is_true() {
-                    (blue, min, red - green, T::from_f64(4.0))
-                } else {
-                    let min_val = if blue.lt(&min).is_true() { blue } else { min };
-                    (max, min_val, sep, coeff)
-                }
-            };
-
-            let mut h = T::zero();
-            let mut s = T::zero();
-
-            let sum = max.clone() + &min;
-            let l = sum.clone() / T::from_f64(2.0);
-            if max.neq(&min).is_true() {
-                let d = max - min;
-                s = if sum.gt(&T::one()).is_true() {
-                    d.clone() / (T::from_f64(2.0) - sum)
-                } else {
-                    d.clone() / sum
-                };
-                h = ((sep / d) + coeff) * T::from_f64(60.0);
-            };
-
-            Hsl {
-                hue: h.into(),
-                saturation: s,
-                lightness: l,
-                standard: PhantomData,
-            }
-        } else {
-            // Based on OPTIMIZED RGB TO HSV COLOR CONVERSION USING SSE TECHNOLOGY
-            // by KOBALICEK, Petr & BLIZNAK, Michal
-            //
-            // This implementation assumes less about the underlying mask and number
-            // representation. The hue is also multiplied by 6 to avoid rounding
-            // errors when using degrees.
-
-            let six = T::from_f64(6.0);
-
-            let max = red.clone().max(green.clone()).max(blue.clone());
-            let min = red.clone().min(green.clone()).min(blue.clone());
-
-            let sum = max.clone() + &min;
-            let lightness = T::from_f64(0.5) * &sum;
-
-            let chroma = max.clone() - &min;
-            let saturation = lazy_select! {
-                if min.eq(&max) => T::zero(),
-                else => chroma.clone() /
-                    sum.gt(&T::one()).select(T::from_f64(2.0) - &sum, sum.clone()),
-            };
-
-            // Each of these represents an RGB component. The maximum will be false
-            // while the two other will be true. They are later used for determining
-            // which branch in the hue equation we end up in.
-            let x = max.neq(&red);
-            let y = max.eq(&red) | max.neq(&green);
-            let z = max.eq(&red) | max.eq(&green);
-
-            // The hue base is the `1`, `2/6`, `4/6` or 0 part of the hue equation,
-            // except it's multiplied by 6 here.
-            let hue_base = x.clone().select(
-                z.clone().select(T::from_f64(-4.0), T::from_f64(4.0)),
-                T::zero(),
-            ) + &six;
-
-            // Each of these is a part of `G - B`, `B - R`, `R - G` or 0 from the
-            // hue equation. They become positive, negative or 0, depending on which
-            // branch we should be in. This makes the sum of all three combine as
-            // expected.
-            let red_m = lazy_select! {
-               if x => y.clone().select(red.clone(), -red),
-               else => T::zero(),
-            };
-            let green_m = lazy_select! {
-               if y.clone() => z.clone().select(green.clone(), -green),
-               else => T::zero(),
-            };
-            let blue_m = lazy_select! {
-               if z => y.select(-blue.clone(), blue),
-               else => T::zero(),
-            };
-
-            // This is the hue equation parts combined. The hue base is the constant
-            // and the RGB components are masked so up to two of them are non-zero.
-            // Once again, this is multiplied by 6, so the chroma isn't multiplied
-            // before dividing.
-            //
-            // We also avoid dividing by 0 for non-SIMD values.
-            let hue = lazy_select! {
-                if chroma.eq(&T::zero()) => T::zero(),
-                else => hue_base + (red_m + green_m + blue_m) / &chroma,
-            };
-
-            // hue will always be within [0, 12) (it's multiplied by 6, compared to
-            // the paper), so we can subtract by 6 instead of using % to get it
-            // within [0, 6).
-            let hue_sub = hue.gt_eq(&six).select(six, T::zero());
-            let hue = hue - hue_sub;
-
-            Hsl {
-                hue: RgbHue::from_degrees(hue * T::from_f64(60.0)),
-                saturation,
-                lightness,
-                standard: PhantomData,
-            }
+        let (hue, saturation, lightness) = rgb.into_hsl_components();
+
+        Hsl {
+            hue,
+            saturation,
+            lightness,
+            standard: PhantomData,
         }
     }
 }
@@ -447,6 +412,7 @@ impl_is_within_bounds! {
         saturation => [Self::min_saturation(), Self::max_saturation()],
         lightness => [Self::min_lightness(), Self::max_lightness()]
     }
+    new(hue: impl Into<RgbHue<T>>, saturation: T, lightness: T)
     where T: Stimulus
 }
 impl_clamp! {
@@ -463,6 +429,37 @@ impl_lighten!(Hsl<S> increase {lightness => [Self::min_lightness(), Self::max_li
 impl_saturate!(Hsl<S> increase {saturation => [Self::min_saturation(), Self::max_saturation()]} other {hue, lightness} phantom: standard where T: Stimulus);
 impl_hue_ops!(Hsl<S>, RgbHue);
 
+impl<S, T> TryGetHue for Hsl<S, T>
+where
+    T: Real + One + Abs + Arithmetics + PartialCmp + Clone,
+    T::Mask: BitOps,
+{
+    /// The hue is considered meaningful if the chroma, calculated from the
+    /// saturation and lightness, is greater than `0.00001`.
+    fn get_hue_masked(&self) -> (RgbHue<T>, T::Mask) {
+        (
+            self.hue.clone(),
+            !self.is_achromatic_within(T::from_f64(0.00001)),
+        )
+    }
+}
+
+impl<S, T> IsAchromatic for Hsl<S, T>
+where
+    T: Real + One + Abs + Arithmetics + PartialCmp + Clone,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the chroma, calculated from the
+    /// saturation and lightness, is at most `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        let chroma = (T::one() - (self.lightness.clone() * T::from_f64(2.0) - T::one()).abs())
+            * &self.saturation;
+
+        chroma.lt_eq(&tolerance)
+    }
+}
+
 impl<S, T> HasBoolMask for Hsl<S, T>
 where
     T: HasBoolMask,
@@ -492,6 +489,8 @@ impl_simd_array_conversion_hue!(Hsl<S>, [saturation, lightness], standard);
 impl_struct_of_array_traits_hue!(Hsl<S>, RgbHueIter, [saturation, lightness], standard);
 
 impl_eq_hue!(Hsl<S>, RgbHue, [hue, saturation, lightness]);
+impl_select!(Hsl<S>, [hue, saturation, lightness], standard);
+impl_relative_luminance!(Hsl<S> via crate::Xyz<<S::Space as crate::rgb::RgbSpace>::WhitePoint, T> where S: RgbStandard);
 impl_copy_clone!(Hsl<S>, [hue, saturation, lightness], standard);
 
 #[allow(deprecated)]
@@ -513,6 +512,8 @@ where
     }
 }
 
+impl_wcag21_relative_contrast!(Hsl<S> where S: RgbStandard<Space = Srgb>);
+
 impl_rand_traits_hsl_bicone!(
     UniformHsl,
     Hsl<S> {
@@ -590,6 +591,77 @@ mod test {
         }
     }
 
+    #[test]
+    fn try_get_hue() {
+        use crate::{RgbHue, TryGetHue};
+
+        assert_eq!(
+            Hsl::new_srgb(120.0f32, 1.0, 0.5).try_get_hue(),
+            Some(RgbHue::new(120.0))
+        );
+        assert_eq!(Hsl::new_srgb(120.0f32, 0.0, 0.5).try_get_hue(), None);
+        assert_eq!(Hsl::new_srgb(120.0f32, 0.000001, 0.5).try_get_hue(), None);
+        assert_eq!(Hsl::new_srgb(120.0f32, 1.0, 0.0).try_get_hue(), None);
+        assert_eq!(Hsl::new_srgb(120.0f32, 1.0, 1.0).try_get_hue(), None);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn u8_rgb_round_trip() {
+        use crate::Srgb;
+
+        for red in 0..=255u8 {
+            for green in 0..=255u8 {
+                for blue in 0..=255u8 {
+                    let rgb = Srgb::new(red, green, blue);
+                    assert_eq!(Hsl::from_rgb_u8(rgb).into_rgb_u8(), rgb);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn u8_rgb_same_as_float() {
+        use crate::{FromColor, Srgb};
+
+        for red in (0..=255u8).step_by(5) {
+            for green in (0..=255u8).step_by(5) {
+                for blue in (0..=255u8).step_by(5) {
+                    let rgb = Srgb::new(red, green, blue);
+                    let integer = Hsl::from_rgb_u8(rgb);
+                    let float = Hsl::from_color(rgb.into_format::<f32>()).into_format::<u16>();
+
+                    let hue_error = integer
+                        .hue
+                        .into_inner()
+                        .wrapping_sub(float.hue.into_inner());
+                    assert!(
+                        hue_error <= 1 || hue_error == u16::MAX,
+                        "{:?} != {:?}",
+                        integer,
+                        float
+                    );
+
+                    let integer = integer.into_components();
+                    let float = float.into_components();
+                    assert!((i32::from(integer.1) - i32::from(float.1)).abs() <= 1);
+                    assert!((i32::from(integer.2) - i32::from(float.2)).abs() <= 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_achromatic() {
+        use crate::IsAchromatic;
+
+        assert!(Hsl::new_srgb(120.0f32, 0.0, 0.5).is_achromatic_within(0.0));
+        assert!(Hsl::new_srgb(120.0f32, 1.0, 1.0).is_achromatic_within(0.0));
+        assert!(Hsl::new_srgb(120.0f32, 0.01, 0.5).is_achromatic_within(0.02));
+        assert!(!Hsl::new_srgb(120.0f32, 0.01, 0.5).is_achromatic_within(0.005));
+        assert!(!Hsl::new_srgb(120.0f32, 1.0, 0.5).is_achromatic_within(0.02));
+    }
+
     #[test]
     fn ranges() {
         assert_ranges! {
@@ -605,6 +677,63 @@ mod test {
         }
     }
 
+    #[test]
+    fn try_new() {
+        use crate::encoding::Srgb;
+
+        assert_eq!(
+            Hsl::<Srgb, f32>::try_new(400.0, 0.5, 1.0).unwrap(),
+            Hsl::new(400.0, 0.5, 1.0)
+        );
+        assert!(Hsl::<Srgb, f32>::try_new(40.0, 1.5, 1.0).is_err());
+        assert!(Hsl::<Srgb, f32>::try_new(40.0, 0.5, -0.5).is_err());
+        assert!(Hsl::<Srgb, f32>::try_new(f32::NAN, 0.5, 0.5).is_err());
+        assert!(Hsl::<Srgb, f32>::try_new(40.0, f32::NAN, 0.5).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn modify_struct_of_arrays() {
+        use crate::{LightenAssign, SaturateAssign, ShiftHueAssign};
+
+        let mut vec_of_colors = vec![
+            Hsl::new_srgb(-30.0f32, 0.2, 0.3),
+            Hsl::new_srgb(120.0, 0.9, 0.7),
+            Hsl::new_srgb(400.0, 0.5, 0.5),
+        ];
+        let mut color_of_vecs: Hsl<crate::encoding::Srgb, Vec<_>> =
+            vec_of_colors.clone().into_iter().collect();
+
+        color_of_vecs.lighten_assign(0.3);
+        color_of_vecs.lighten_fixed_assign(-0.1);
+        color_of_vecs.saturate_assign(-0.4);
+        color_of_vecs.saturate_fixed_assign(0.5);
+        color_of_vecs.shift_hue_assign(100.0);
+
+        vec_of_colors.lighten_assign(0.3);
+        vec_of_colors.lighten_fixed_assign(-0.1);
+        vec_of_colors.saturate_assign(-0.4);
+        vec_of_colors.saturate_fixed_assign(0.5);
+        vec_of_colors.shift_hue_assign(100.0);
+
+        assert_eq!(color_of_vecs.into_iter().collect::<Vec<_>>(), vec_of_colors);
+    }
+
+    #[test]
+    fn clamp_struct_of_arrays() {
+        use crate::{ClampAssign, IsWithinBounds};
+
+        let mut colors =
+            Hsl::<crate::encoding::Srgb, [f32; 2]>::new([-30.0, 400.0], [1.5, 0.5], [0.5, -0.5]);
+        assert!(!colors.is_within_bounds());
+
+        colors.clamp_assign();
+        assert!(colors.is_within_bounds());
+        assert_eq!(colors.hue.into_inner(), [-30.0, 400.0]);
+        assert_eq!(colors.saturation, [1.0, 0.5]);
+        assert_eq!(colors.lightness, [0.5, 0.0]);
+    }
+
     raw_pixel_conversion_tests!(Hsl<crate::encoding::Srgb>: hue, saturation, lightness);
     raw_pixel_conversion_fail_tests!(Hsl<crate::encoding::Srgb>: hue, saturation, lightness);
 
@@ -618,6 +747,36 @@ mod test {
         assert_eq!(Hsl::<Srgb>::max_lightness(), 1.0);
     }
 
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter_mut_lighten() {
+        use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+        use crate::Lighten;
+
+        let colors: Vec<Hsl> = (0..100)
+            .map(|index| {
+                let x = index as f32 / 100.0;
+                Hsl::new(x * 360.0, 1.0 - x, x * 0.5)
+            })
+            .collect();
+
+        let mut parallel: Hsl<_, Vec<f32>> = colors.iter().copied().collect();
+        parallel
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(index, mut color)| color.set(color.copied().lighten(index as f32 / 200.0)));
+
+        let serial: Vec<_> = colors
+            .into_iter()
+            .enumerate()
+            .map(|(index, color)| color.lighten(index as f32 / 200.0))
+            .collect();
+        let parallel: Vec<_> = parallel.into_iter().collect();
+
+        assert_eq!(parallel, serial);
+    }
+
     struct_of_arrays_tests!(
         Hsl<crate::encoding::Srgb>[hue, saturation, lightness] phantom: standard,
         super::Hsla::new(0.1f32, 0.2, 0.3, 0.4),
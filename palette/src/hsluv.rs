@@ -74,6 +74,14 @@ impl<Wp, T> Hsluv<Wp, T> {
         }
     }
 
+    /// Create an HSLuv color, with the hue in radians instead of degrees.
+    pub fn new_radians(hue: T, saturation: T, l: T) -> Self
+    where
+        T: RealAngle,
+    {
+        Self::new_const(LuvHue::from_radians(hue), saturation, l)
+    }
+
     /// Convert to a `(hue, saturation, l)` tuple.
     pub fn into_components(self) -> (LuvHue<T>, T, T) {
         (self.hue, self.saturation, self.l)
@@ -145,6 +153,7 @@ impl<Wp, T, A> Alpha<Hsluv<Wp, T>, A> {
 
 impl_reference_component_methods_hue!(Hsluv<Wp>, [saturation, l], white_point);
 impl_struct_of_arrays_methods_hue!(Hsluv<Wp>, [saturation, l], white_point);
+impl_sort_keys!(Hsluv<Wp> {lightness: l, hue});
 
 impl<Wp, T> FromColorUnclamped<Hsluv<Wp, T>> for Hsluv<Wp, T> {
     fn from_color_unclamped(hsluv: Hsluv<Wp, T>) -> Self {
@@ -177,6 +186,7 @@ impl_is_within_bounds! {
         saturation => [Self::min_saturation(), Self::max_saturation()],
         l => [Self::min_l(), Self::max_l()]
     }
+    new(hue: impl Into<LuvHue<T>>, saturation: T, l: T)
     where T: Real + Zero
 }
 impl_clamp! {
@@ -218,6 +228,8 @@ impl_simd_array_conversion_hue!(Hsluv<Wp>, [saturation, l], white_point);
 impl_struct_of_array_traits_hue!(Hsluv<Wp>, LuvHueIter, [saturation, l], white_point);
 
 impl_eq_hue!(Hsluv<Wp>, LuvHue, [hue, saturation, l]);
+impl_select!(Hsluv<Wp>, [hue, saturation, l], white_point);
+impl_relative_luminance!(Hsluv<Wp> via crate::Xyz<Wp, T>);
 impl_copy_clone!(Hsluv<Wp>, [hue, saturation, l], white_point);
 
 #[allow(deprecated)]
@@ -260,6 +272,7 @@ mod test {
     use crate::white_point::D65;
 
     test_convert_into_from_xyz!(Hsluv);
+    test_lighten_lightness!(Hsluv<D65> {l} new(30.0, 20.0, 50.0));
 
     #[cfg(feature = "approx")]
     #[cfg_attr(miri, ignore)]
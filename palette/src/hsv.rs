@@ -11,7 +11,7 @@ use crate::{
     num::{Arithmetics, IsValidDivisor, MinMax, One, PartialCmp, Real, Zero},
     rgb::{Rgb, RgbSpace, RgbStandard},
     stimulus::{FromStimulus, Stimulus},
-    Alpha, FromColor, Hsl, Hwb, RgbHue, Xyz,
+    Alpha, FromColor, Hsl, Hwb, IsAchromatic, RgbHue, TryGetHue, Xyz,
 };
 
 /// Linear HSV with an alpha component. See the [`Hsva` implementation in
@@ -46,7 +46,7 @@ pub type Hsva<S = Srgb, T = f32> = Alpha<Hsv<S, T>, T>;
     palette_internal,
     rgb_standard = "S",
     component = "T",
-    skip_derives(Rgb, Hsl, Hwb, Hsv)
+    skip_derives(Rgb, Hsl, Hwb, Hsv, Okhsv)
 )]
 #[repr(C)]
 #[doc(alias = "hsb")]
@@ -103,6 +103,14 @@ impl<S, T> Hsv<S, T> {
         }
     }
 
+    /// Create an HSV color, with the hue in radians instead of degrees.
+    pub fn new_radians(hue: T, saturation: T, value: T) -> Self
+    where
+        T: RealAngle,
+    {
+        Self::new_const(RgbHue::from_radians(hue), saturation, value)
+    }
+
     /// Convert into another component type.
     pub fn into_format<U>(self) -> Hsv<S, U>
     where
@@ -170,6 +178,125 @@ where
     }
 }
 
+impl<S> Hsv<S, u16> {
+    /// Convert from RGB with `u8` components, using integer arithmetic.
+    ///
+    /// This is an alternative to converting via floating point components.
+    /// The components have the same ranges as when using
+    /// [`into_format`](Hsv::into_format), so the hue is in the range `[0,
+    /// 65535]`, where 65536 would be a full turn. The 16 bit hue and saturation
+    /// are precise enough to get the same RGB color back with
+    /// [`into_rgb_u8`](Hsv::into_rgb_u8). That's not the case for `Hsv<S, u8>`,
+    /// where the 256 hue steps can't represent every RGB hue.
+    ///
+    /// ```
+    /// use palette::{Hsv, Srgb};
+    ///
+    /// let hsv = Hsv::from_rgb_u8(Srgb::new(255u8, 128, 0));
+    /// assert_eq!(hsv, Hsv::new_srgb(5483u16, 65535, 65535));
+    ///
+    /// let rgb = hsv.into_rgb_u8();
+    /// assert_eq!(rgb, Srgb::new(255u8, 128, 0));
+    /// ```
+    pub fn from_rgb_u8(rgb: Rgb<S, u8>) -> Self {
+        let max = Ord::max(Ord::max(rgb.red, rgb.green), rgb.blue);
+        let min = Ord::min(Ord::min(rgb.red, rgb.green), rgb.blue);
+        let chroma = u32::from(max - min);
+
+        let saturation = if max == 0 {
+            0
+        } else {
+            let max = u32::from(max);
+            ((chroma * 65535 + max / 2) / max) as u16
+        };
+
+        Hsv {
+            hue: RgbHue::new(rgb_u8_to_hue(rgb.red, rgb.green, rgb.blue)),
+            saturation,
+            value: u16::from(max) * 257,
+            standard: PhantomData,
+        }
+    }
+
+    /// Convert into RGB with `u8` components, using integer arithmetic.
+    ///
+    /// See [`from_rgb_u8`](Hsv::from_rgb_u8) for more details.
+    pub fn into_rgb_u8(self) -> Rgb<S, u8> {
+        let value = u64::from(self.value);
+        let chroma = value * u64::from(self.saturation);
+        let min = value * 65535 - chroma;
+
+        let [red, green, blue] = hue_u16_to_rgb(self.hue.into_inner(), chroma, min, 65535 * 65535);
+        Rgb {
+            red,
+            green,
+            blue,
+            standard: PhantomData,
+        }
+    }
+}
+
+/// Calculate the hue of an RGB color with `u8` components, as a `u16` where
+/// 65536 is a full turn.
+pub(crate) fn rgb_u8_to_hue(red: u8, green: u8, blue: u8) -> u16 {
+    let max = Ord::max(Ord::max(red, green), blue);
+    let min = Ord::min(Ord::min(red, green), blue);
+
+    if max == min {
+        return 0;
+    }
+
+    let chroma = i32::from(max - min);
+    let max = i32::from(max);
+    let (red, green, blue) = (i32::from(red), i32::from(green), i32::from(blue));
+
+    // The hue in sixths of a turn, multiplied by the chroma.
+    let mut hue = if red == max {
+        green - blue
+    } else if green == max {
+        blue - red + 2 * chroma
+    } else {
+        red - green + 4 * chroma
+    };
+
+    if hue < 0 {
+        hue += 6 * chroma;
+    }
+
+    ((hue * 65536 + 3 * chroma) / (6 * chroma) % 65536) as u16
+}
+
+/// Calculate the RGB components of a color with a `u16` hue, where 65536 is a
+/// full turn. The chroma and minimum component values are fractions of
+/// `scale`.
+pub(crate) fn hue_u16_to_rgb(hue: u16, chroma: u64, min: u64, scale: u64) -> [u8; 3] {
+    let hue = u32::from(hue) * 6;
+    let sector = hue >> 16;
+    let fraction = u64::from(hue & 0xffff);
+
+    // Everything is multiplied by 65536 to keep the fractional part of the hue.
+    let max = (chroma + min) << 16;
+    let min = min << 16;
+    let mid = if sector % 2 == 0 {
+        chroma * fraction
+    } else {
+        chroma * (65536 - fraction)
+    } + min;
+
+    let [red, green, blue] = match sector {
+        0 => [max, mid, min],
+        1 => [mid, max, min],
+        2 => [min, max, mid],
+        3 => [min, mid, max],
+        4 => [mid, min, max],
+        _ => [max, min, mid],
+    };
+
+    let divisor = scale << 16;
+    let round = |value: u64| ((value * 255 + divisor / 2) / divisor) as u8;
+    [round(red), round(green), round(blue)]
+}
+
 ///<span id="Hsva"></span>[`Hsva`](crate::Hsva) implementations.
 impl<T, A> Alpha<Hsv<Srgb, T>, A> {
     /// Create an sRGB HSV color with transparency. This method can be used
@@ -244,6 +371,7 @@ impl<S, T, A> Alpha<Hsv<S, T>, A> {
 
 impl_reference_component_methods_hue!(Hsv<S>, [saturation, value], standard);
 impl_struct_of_arrays_methods_hue!(Hsv<S>, [saturation, value], standard);
+impl_sort_keys!(Hsv<S> {hue});
 
 impl<S1, S2, T> FromColorUnclamped<Hsv<S1, T>> for Hsv<S2, T>
 where
@@ -272,121 +400,13 @@ where
     T::Mask: BoolMask + BitOps + LazySelect<T> + Clone + 'static,
 {
     fn from_color_unclamped(rgb: Rgb<S, T>) -> Self {
-        // Avoid negative numbers
-        let red = rgb.red.max(T::zero());
-        let green = rgb.green.max(T::zero());
-        let blue = rgb.blue.max(T::zero());
-
-        // The SIMD optimized version showed significant slowdown for regular floats.
-        if TypeId::of::<T::Mask>() == TypeId::of::<bool>() {
-            let (max, min, sep, coeff) = {
-                let (max, min, sep, coeff) = if red.gt(&green).is_true() {
-                    (red.clone(), green.clone(), green.clone() - &blue, T::zero())
-                } else {
-                    (
-                        green.clone(),
-                        red.clone(),
-                        blue.clone() - &red,
-                        T::from_f64(2.0),
-                    )
-                };
-                if blue.gt(&max).is_true() {
-                    (blue, min, red - green, T::from_f64(4.0))
-                } else {
-                    let min_val = if blue.lt(&min).is_true() { blue } else { min };
-                    (max, min_val, sep, coeff)
-                }
-            };
-
-            let (h, s) = if max.neq(&min).is_true() {
-                let d = max.clone() - min;
-                let h = ((sep / &d) + coeff) * T::from_f64(60.0);
-                let s = d / &max;
-
-                (h, s)
-            } else {
-                (T::zero(), T::zero())
-            };
-            let v = max;
-
-            Hsv {
-                hue: h.into(),
-                saturation: s,
-                value: v,
-                standard: PhantomData,
-            }
-        } else {
-            // Based on OPTIMIZED RGB TO HSV COLOR CONVERSION USING SSE TECHNOLOGY
-            // by KOBALICEK, Petr & BLIZNAK, Michal
-            //
-            // This implementation assumes less about the underlying mask and number
-            // representation. The hue is also multiplied by 6 to avoid rounding
-            // errors when using degrees.
-
-            let six = T::from_f64(6.0);
-
-            let value = red.clone().max(green.clone()).max(blue.clone());
-            let min = red.clone().min(green.clone()).min(blue.clone());
-
-            let chroma = value.clone() - min;
-            let saturation = chroma
-                .eq(&T::zero())
-                .lazy_select(|| T::zero(), || chroma.clone() / &value);
-
-            // Each of these represents an RGB component. The maximum will be false
-            // while the two other will be true. They are later used for determining
-            // which branch in the hue equation we end up in.
-            let x = value.neq(&red);
-            let y = value.eq(&red) | value.neq(&green);
-            let z = value.eq(&red) | value.eq(&green);
-
-            // The hue base is the `1`, `2/6`, `4/6` or 0 part of the hue equation,
-            // except it's multiplied by 6 here.
-            let hue_base = x.clone().select(
-                z.clone().select(T::from_f64(-4.0), T::from_f64(4.0)),
-                T::zero(),
-            ) + &six;
-
-            // Each of these is a part of `G - B`, `B - R`, `R - G` or 0 from the
-            // hue equation. They become positive, negative or 0, depending on which
-            // branch we should be in. This makes the sum of all three combine as
-            // expected.
-            let red_m = lazy_select! {
-               if x => y.clone().select(red.clone(), -red),
-               else => T::zero(),
-            };
-            let green_m = lazy_select! {
-               if y.clone() => z.clone().select(green.clone(), -green),
-               else => T::zero(),
-            };
-            let blue_m = lazy_select! {
-               if z => y.select(-blue.clone(), blue),
-               else => T::zero(),
-            };
-
-            // This is the hue equation parts combined. The hue base is the constant
-            // and the RGB components are masked so up to two of them are non-zero.
-            // Once again, this is multiplied by 6, so the chroma isn't multiplied
-            // before dividing.
-            //
-            // We also avoid dividing by 0 for non-SIMD values.
-            let hue = lazy_select! {
-                if chroma.eq(&T::zero()) => T::zero(),
-                else => hue_base + (red_m + green_m + blue_m) / &chroma,
-            };
-
-            // hue will always be within [0, 12) (it's multiplied by 6, compared to
-            // the paper), so we can subtract by 6 instead of using % to get it
-            // within [0, 6).
-            let hue_sub = hue.gt_eq(&six).select(six, T::zero());
-            let hue = hue - hue_sub;
-
-            Hsv {
-                hue: RgbHue::from_degrees(hue * T::from_f64(60.0)),
-                saturation,
-                value,
-                standard: PhantomData,
-            }
+        let (hue, saturation, value) = rgb.into_hsv_components();
+
+        Hsv {
+            hue,
+            saturation,
+            value,
+            standard: PhantomData,
         }
     }
 }
@@ -422,17 +442,19 @@ where
 
 impl<S, T> FromColorUnclamped<Hwb<S, T>> for Hsv<S, T>
 where
-    T: One + Zero + IsValidDivisor + Arithmetics,
-    T::Mask: LazySelect<T>,
+    T: One + Zero + IsValidDivisor + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T> + Select<T>,
 {
     #[inline]
     fn from_color_unclamped(hwb: Hwb<S, T>) -> Self {
+        // A whiteness and blackness that add up to more than 1 is a gray, as
+        // in CSS, and not an invalid color.
         let Hwb {
             hue,
             whiteness,
             blackness,
             ..
-        } = hwb;
+        } = hwb.normalize();
 
         let value = T::one() - blackness;
 
@@ -458,6 +480,7 @@ impl_is_within_bounds! {
         saturation => [Self::min_saturation(), Self::max_saturation()],
         value => [Self::min_value(), Self::max_value()]
     }
+    new(hue: impl Into<RgbHue<T>>, saturation: T, value: T)
     where T: Stimulus
 }
 impl_clamp! {
@@ -474,6 +497,36 @@ impl_lighten!(Hsv<S> increase {value => [Self::min_value(), Self::max_value()]}
 impl_saturate!(Hsv<S> increase {saturation => [Self::min_saturation(), Self::max_saturation()]} other {hue, value} phantom: standard where T: Stimulus);
 impl_hue_ops!(Hsv<S>, RgbHue);
 
+impl<S, T> TryGetHue for Hsv<S, T>
+where
+    T: Real + Arithmetics + PartialCmp + Clone,
+    T::Mask: BitOps,
+{
+    /// The hue is considered meaningful if the chroma, calculated from the
+    /// saturation and value, is greater than `0.00001`.
+    fn get_hue_masked(&self) -> (RgbHue<T>, T::Mask) {
+        (
+            self.hue.clone(),
+            !self.is_achromatic_within(T::from_f64(0.00001)),
+        )
+    }
+}
+
+impl<S, T> IsAchromatic for Hsv<S, T>
+where
+    T: Arithmetics + PartialCmp + Clone,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the chroma, calculated from the
+    /// saturation and value, is at most `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        let chroma = self.saturation.clone() * &self.value;
+
+        chroma.lt_eq(&tolerance)
+    }
+}
+
 impl<S, T> HasBoolMask for Hsv<S, T>
 where
     T: HasBoolMask,
@@ -499,6 +552,8 @@ impl_simd_array_conversion_hue!(Hsv<S>, [saturation, value], standard);
 impl_struct_of_array_traits_hue!(Hsv<S>, RgbHueIter, [saturation, value], standard);
 
 impl_eq_hue!(Hsv<S>, RgbHue, [hue, saturation, value]);
+impl_select!(Hsv<S>, [hue, saturation, value], standard);
+impl_relative_luminance!(Hsv<S> via crate::Xyz<<S::Space as crate::rgb::RgbSpace>::WhitePoint, T> where S: RgbStandard);
 impl_copy_clone!(Hsv<S>, [hue, saturation, value], standard);
 
 #[allow(deprecated)]
@@ -520,6 +575,8 @@ where
     }
 }
 
+impl_wcag21_relative_contrast!(Hsv<S> where S: RgbStandard<Space = Srgb>);
+
 impl_rand_traits_hsv_cone!(
     UniformHsv,
     Hsv<S> {
@@ -597,6 +654,89 @@ mod test {
         }
     }
 
+    #[test]
+    fn try_get_hue() {
+        use crate::{RgbHue, TryGetHue};
+
+        assert_eq!(
+            Hsv::new_srgb(120.0f32, 1.0, 0.5).try_get_hue(),
+            Some(RgbHue::new(120.0))
+        );
+        assert_eq!(Hsv::new_srgb(120.0f32, 0.0, 0.5).try_get_hue(), None);
+        assert_eq!(Hsv::new_srgb(120.0f32, 0.000001, 0.5).try_get_hue(), None);
+        assert_eq!(Hsv::new_srgb(120.0f32, 1.0, 0.0).try_get_hue(), None);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn u8_rgb_round_trip() {
+        use crate::Srgb;
+
+        for red in 0..=255u8 {
+            for green in 0..=255u8 {
+                for blue in 0..=255u8 {
+                    let rgb = Srgb::new(red, green, blue);
+                    assert_eq!(Hsv::from_rgb_u8(rgb).into_rgb_u8(), rgb);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn u8_rgb_same_as_float() {
+        use crate::{FromColor, Srgb};
+
+        for red in (0..=255u8).step_by(5) {
+            for green in (0..=255u8).step_by(5) {
+                for blue in (0..=255u8).step_by(5) {
+                    let rgb = Srgb::new(red, green, blue);
+                    let integer = Hsv::from_rgb_u8(rgb);
+                    let float = Hsv::from_color(rgb.into_format::<f32>()).into_format::<u16>();
+
+                    let hue_error = integer
+                        .hue
+                        .into_inner()
+                        .wrapping_sub(float.hue.into_inner());
+                    assert!(
+                        hue_error <= 1 || hue_error == u16::MAX,
+                        "{:?} != {:?}",
+                        integer,
+                        float
+                    );
+
+                    let integer = integer.into_components();
+                    let float = float.into_components();
+                    assert!((i32::from(integer.1) - i32::from(float.1)).abs() <= 1);
+                    assert!((i32::from(integer.2) - i32::from(float.2)).abs() <= 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_achromatic() {
+        use crate::IsAchromatic;
+
+        assert!(Hsv::new_srgb(120.0f32, 0.0, 0.5).is_achromatic_within(0.0));
+        assert!(Hsv::new_srgb(120.0f32, 1.0, 0.0).is_achromatic_within(0.0));
+        assert!(Hsv::new_srgb(120.0f32, 0.02, 0.5).is_achromatic_within(0.02));
+        assert!(!Hsv::new_srgb(120.0f32, 0.02, 0.5).is_achromatic_within(0.005));
+        assert!(!Hsv::new_srgb(120.0f32, 1.0, 0.5).is_achromatic_within(0.02));
+    }
+
+    #[test]
+    fn u8_hue_shift() {
+        use crate::{RgbHue, ShiftHue, ShiftHueAssign};
+
+        let color = Hsv::<crate::encoding::Srgb, u8>::new(250u8, 200, 100);
+        assert_eq!(core::mem::size_of_val(&color), 3);
+        assert_eq!(color.shift_hue(20), Hsv::new(14u8, 200, 100));
+
+        let mut color = color;
+        color.shift_hue_assign(6);
+        assert_eq!(color.hue, RgbHue::new(0u8));
+    }
+
     #[test]
     fn ranges() {
         assert_ranges! {
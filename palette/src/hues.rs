@@ -1,9 +1,57 @@
 //! Hues and hue related types.
-
-#[cfg(any(feature = "approx", feature = "random"))]
-use core::ops::Mul;
-
-use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+//!
+//! ## Units
+//!
+//! Hues with real number components, such as `f32`, are stored as degrees, and
+//! both [`new`][RgbHue::new] and `From<T>` expect degrees. This is easy to miss
+//! when working with radians, such as the output of `atan2` or the constants
+//! in `core::f32::consts`, since `RgbHue::new(PI)` is a hue of about 3.14°, not
+//! 180°. Use [`from_radians`][RgbHue::from_radians] or
+//! [`from_turns`][RgbHue::from_turns] for other units, and their `into_*`
+//! counterparts to get them back.
+//!
+//! ```
+//! use core::f32::consts::PI;
+//! use palette::RgbHue;
+//! use approx::assert_relative_eq;
+//!
+//! assert_relative_eq!(RgbHue::new(PI).into_degrees(), 3.1415927);
+//! assert_relative_eq!(RgbHue::from_radians(PI).into_degrees(), 180.0);
+//! assert_relative_eq!(RgbHue::from_turns(0.5f32).into_degrees(), 180.0);
+//! ```
+//!
+//! ## Arithmetics
+//!
+//! The hue types are circular, so their arithmetic operations follow a few
+//! rules to make them useful for angular math:
+//!
+//! * Adding, subtracting and negating hues works on the inner values as they
+//!   are, without normalizing them. The result is only normalized when it's
+//!   converted, for example with [`into_degrees`][RgbHue::into_degrees], which
+//!   makes `b - a` the signed difference from `a` to `b` in the range
+//!   `(-180, 180]`, and `a + (b - a)` the same hue as `b`.
+//! * Multiplying or dividing a hue by a number first normalizes it to
+//!   `(-180, 180]`, so the hue is scaled along the shortest arc. This makes
+//!   `a + (b - a) * t` interpolate from `a` to `b` the short way around.
+//!
+//! ```
+//! use palette::RgbHue;
+//!
+//! let a = RgbHue::new(350.0f32);
+//! let b = RgbHue::new(30.0);
+//!
+//! assert_eq!((b - a).into_degrees(), 40.0);
+//! assert_eq!(a + (b - a), b);
+//! assert_eq!(a + (b - a) * 0.5, RgbHue::new(10.0));
+//! assert_eq!(-(-a), a);
+//! ```
+//!
+//! The same applies to SIMD types, where each lane is treated separately.
+
+use core::{
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
 
 #[cfg(feature = "approx")]
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
@@ -24,8 +72,9 @@ use crate::{angle::HalfRotation, num::Zero};
 use crate::angle::FullRotation;
 
 use crate::{
-    angle::{AngleEq, FromAngle, RealAngle, SignedAngle, UnsignedAngle},
-    num::Trigonometry,
+    angle::{AngleEq, FromAngle, RealAngle, ShiftAngle, SignedAngle, UnsignedAngle},
+    bool_mask::{HasBoolMask, Select, Selectable},
+    num::{Abs, Arithmetics, PartialCmp, Real, Trigonometry},
 };
 
 macro_rules! make_hues {
@@ -37,6 +86,10 @@ macro_rules! make_hues {
         /// number (like `f32`). This makes many calculations easier, but may
         /// also have some surprising effects if it's expected to act as a
         /// linear number.
+        ///
+        /// The approximate comparison traits from `approx` are also circular,
+        /// when the `approx` feature is enabled, so `350` and `-10` are equal,
+        /// and `179.9` is as close to `-179.9` as it is to `180.1`.
         #[derive(Clone, Copy, Debug, Default)]
         #[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
         #[repr(C)]
@@ -47,7 +100,9 @@ macro_rules! make_hues {
             /// type `T`.
             ///
             /// `f32`, `f64` and other real number types represent degrees,
-            /// while `u8` simply represents the range `[0, 360]` as `[0, 256]`.
+            /// while `u8` and `u16` represent the range `[0, 360]` as `[0, 256]`
+            /// and `[0, 65536]`. Use [`into_format`](Self::into_format) to
+            /// convert between them.
             #[inline]
             pub const fn new(angle: T) -> Self {
                 Self(angle)
@@ -56,7 +111,9 @@ macro_rules! make_hues {
             /// Get the internal representation without normalizing or converting it.
             ///
             /// `f32`, `f64` and other real number types represent degrees,
-            /// while `u8` simply represents the range `[0, 360]` as `[0, 256]`.
+            /// while `u8` and `u16` represent the range `[0, 360]` as `[0, 256]`
+            /// and `[0, 65536]`. Use [`into_format`](Self::into_format) to
+            /// convert between them.
             pub fn into_inner(self) -> T {
                 self.0
             }
@@ -102,6 +159,38 @@ macro_rules! make_hues {
             pub fn into_raw_radians(self) -> T {
                 T::degrees_to_radians(self.0)
             }
+
+            /// Create a new hue from turns, where `1.0` is a full turn.
+            #[inline]
+            pub fn from_turns(turns: T) -> Self
+            where
+                T: Mul<Output = T>,
+            {
+                Self(turns * T::from_f64(360.0))
+            }
+
+            /// Get the internal representation as turns, without normalizing it.
+            #[inline]
+            pub fn into_raw_turns(self) -> T
+            where
+                T: Div<Output = T>,
+            {
+                self.0 / T::from_f64(360.0)
+            }
+
+            /// Return an iterator over `n` hues, evenly spaced around the
+            /// circle, starting at this hue.
+            ///
+            /// ```
+            /// use palette::RgbHue;
+            ///
+            /// let hues: Vec<_> = RgbHue::new(30.0f32).circle(3).collect();
+            /// assert_eq!(hues, [RgbHue::new(30.0), RgbHue::new(150.0), RgbHue::new(270.0)]);
+            /// ```
+            #[inline]
+            pub fn circle(self, n: usize) -> Circle<Self, T> {
+                Circle::new(self.0, n)
+            }
         }
 
         impl<T: RealAngle + SignedAngle> $name<T> {
@@ -116,6 +205,32 @@ macro_rules! make_hues {
             pub fn into_radians(self) -> T {
                 T::degrees_to_radians(self.0.normalize_signed_angle())
             }
+
+            /// Convert the hue to turns, in the range `(-0.5, 0.5]`.
+            #[inline]
+            pub fn into_turns(self) -> T
+            where
+                T: Div<Output = T>,
+            {
+                self.0.normalize_signed_angle() / T::from_f64(360.0)
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<T> $name<T>
+        where
+            T: SignedAngle + Sub<Output = T> + Clone,
+        {
+            /// Get both hues as degrees, where `other` is shifted by full
+            /// turns to be as close as possible to `self`. This makes hues on
+            /// each side of `180` and `-180` compare as close to each other.
+            fn comparable_degrees(&self, other: &Self) -> (T, T) {
+                let self_degrees = self.0.clone().normalize_signed_angle();
+                let difference = (self.0.clone() - other.0.clone()).normalize_signed_angle();
+                let other_degrees = self_degrees.clone() - difference;
+
+                (self_degrees, other_degrees)
+            }
         }
 
         impl<T: RealAngle + UnsignedAngle> $name<T> {
@@ -130,6 +245,15 @@ macro_rules! make_hues {
             pub fn into_positive_radians(self) -> T {
                 T::degrees_to_radians(self.0.normalize_unsigned_angle())
             }
+
+            /// Convert the hue to positive turns, in the range `[0, 1)`.
+            #[inline]
+            pub fn into_positive_turns(self) -> T
+            where
+                T: Div<Output = T>,
+            {
+                self.0.normalize_unsigned_angle() / T::from_f64(360.0)
+            }
         }
 
         impl<T: RealAngle + Trigonometry> $name<T> {
@@ -158,6 +282,116 @@ macro_rules! make_hues {
             }
         }
 
+        impl<T: ShiftAngle> $name<T> {
+            /// Shift the hue by `amount`, in the default unit for the angle
+            /// type `T`.
+            ///
+            /// Integer hues wrap around when they pass a full rotation, while
+            /// real number hues are only normalized when they are converted.
+            ///
+            /// ```
+            /// use palette::RgbHue;
+            ///
+            /// assert_eq!(RgbHue::new(250u8).shift(20), RgbHue::new(14u8));
+            /// assert_eq!(RgbHue::new(350.0f32).shift(20.0), RgbHue::new(10.0));
+            /// ```
+            #[inline]
+            pub fn shift(self, amount: T) -> Self {
+                $name(self.0.shift_angle(amount))
+            }
+
+            /// Shift the hue by `amount`, in place. See [`shift`](Self::shift)
+            /// for details.
+            #[inline]
+            pub fn shift_assign(&mut self, amount: T) {
+                self.0.shift_angle_assign(amount);
+            }
+        }
+
+        impl<T: RealAngle + SignedAngle + Arithmetics> $name<T> {
+            /// Get the signed, shortest difference from `self` to `other`, in
+            /// degrees in the range `(-180, 180]`.
+            ///
+            /// ```
+            /// use palette::RgbHue;
+            ///
+            /// assert_eq!(RgbHue::new(350.0f32).difference(RgbHue::new(10.0)), 20.0);
+            /// assert_eq!(RgbHue::new(10.0f32).difference(RgbHue::new(350.0)), -20.0);
+            /// ```
+            #[inline]
+            pub fn difference(self, other: Self) -> T {
+                (other.0 - self.0).normalize_signed_angle()
+            }
+
+            /// Get the circular distance between `self` and `other`, in
+            /// degrees in the range `[0, 180]`.
+            ///
+            /// ```
+            /// use palette::RgbHue;
+            ///
+            /// assert_eq!(RgbHue::new(10.0f32).distance(RgbHue::new(350.0)), 20.0);
+            /// ```
+            #[inline]
+            pub fn distance(self, other: Self) -> T
+            where
+                T: Abs,
+            {
+                self.difference(other).abs()
+            }
+
+            /// Linearly interpolate between `self` and `other` along the
+            /// shortest arc, where a `factor` of `0.0` is `self` and `1.0` is
+            /// `other`.
+            ///
+            /// ```
+            /// use palette::RgbHue;
+            ///
+            /// let hue = RgbHue::new(350.0f32).lerp(RgbHue::new(30.0), 0.25);
+            /// assert_eq!(hue, RgbHue::new(0.0));
+            /// ```
+            #[inline]
+            pub fn lerp(self, other: Self, factor: T) -> Self
+            where
+                T: Clone,
+            {
+                let difference = self.clone().difference(other);
+                $name(self.0 + difference * factor)
+            }
+
+            /// Clamp the hue to the arc that goes counterclockwise from `min`
+            /// to `max`, wrapping around `0` if `min` is larger than `max`.
+            ///
+            /// Hues outside the arc are moved to the closest of `min` and
+            /// `max`.
+            ///
+            /// ```
+            /// use palette::RgbHue;
+            ///
+            /// let min = RgbHue::new(330.0f32);
+            /// let max = RgbHue::new(30.0);
+            ///
+            /// assert_eq!(RgbHue::new(10.0).clamp_to_arc(min, max), RgbHue::new(10.0));
+            /// assert_eq!(RgbHue::new(60.0).clamp_to_arc(min, max), max);
+            /// assert_eq!(RgbHue::new(300.0).clamp_to_arc(min, max), min);
+            /// ```
+            #[inline]
+            pub fn clamp_to_arc(self, min: Self, max: Self) -> Self
+            where
+                T: UnsignedAngle + Abs + PartialCmp + Clone,
+                T::Mask: Select<T> + Clone,
+            {
+                let width = (max.0.clone() - min.0.clone()).normalize_unsigned_angle();
+                let offset = (self.0.clone() - min.0.clone()).normalize_unsigned_angle();
+                let is_outside = offset.gt(&width);
+
+                let to_min = self.clone().distance(min.clone());
+                let to_max = self.clone().distance(max.clone());
+                let closest = to_min.lt_eq(&to_max).select(min.0, max.0);
+
+                $name(is_outside.select(closest, self.0))
+            }
+        }
+
         impl<T> $name<&T> {
             /// Get an owned, copied version of this hue.
             #[inline(always)]
@@ -244,6 +478,24 @@ macro_rules! make_hues {
             {
                 self.0.as_mut().get_mut(index).map($name)
             }
+
+            /// Get a hue with a slice of the values.
+            #[inline(always)]
+            pub fn as_slice<T>(&self) -> $name<&[T]>
+            where
+                C: AsRef<[T]>,
+            {
+                $name(self.0.as_ref())
+            }
+
+            /// Get a hue with a mutable slice of the values.
+            #[inline(always)]
+            pub fn as_mut_slice<T>(&mut self) -> $name<&mut [T]>
+            where
+                C: AsMut<[T]>,
+            {
+                $name(self.0.as_mut())
+            }
         }
 
         #[cfg(feature = "alloc")]
@@ -275,6 +527,41 @@ macro_rules! make_hues {
             {
                 $iter_name(self.0.drain(range))
             }
+
+            /// Return the number of hues in the hue vector. See [`Vec::len`] for details.
+            pub fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            /// Check if the hue vector is empty. See [`Vec::is_empty`] for details.
+            pub fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            /// Reserve capacity for at least `additional` more hues in the hue vector. See [`Vec::reserve`] for details.
+            pub fn reserve(&mut self, additional: usize) {
+                self.0.reserve(additional);
+            }
+
+            /// Shorten the hue vector to `len` hues. See [`Vec::truncate`] for details.
+            pub fn truncate(&mut self, len: usize) {
+                self.0.truncate(len);
+            }
+
+            /// Insert a hue at `index` in the hue vector. See [`Vec::insert`] for details.
+            pub fn insert(&mut self, index: usize, value: $name<T>) {
+                self.0.insert(index, value.0);
+            }
+
+            /// Remove and return the hue at `index` from the hue vector. See [`Vec::remove`] for details.
+            pub fn remove(&mut self, index: usize) -> $name<T> {
+                $name(self.0.remove(index))
+            }
+
+            /// Remove and return the hue at `index` from the hue vector, and replace it with the last hue. See [`Vec::swap_remove`] for details.
+            pub fn swap_remove(&mut self, index: usize) -> $name<T> {
+                $name(self.0.swap_remove(index))
+            }
         }
 
         impl<T> From<T> for $name<T> {
@@ -319,6 +606,24 @@ macro_rules! make_hues {
             }
         }
 
+        impl From<$name<u16>> for u16 {
+            #[inline]
+            fn from(hue: $name<u16>) -> u16 {
+                hue.0
+            }
+        }
+
+        impl<T> HasBoolMask for $name<T> where T: HasBoolMask {
+            type Mask = T::Mask;
+        }
+
+        impl<T> Selectable for $name<T> where T: Selectable {
+            #[inline]
+            fn select(mask: T::Mask, a: Self, b: Self) -> Self {
+                $name(T::select(mask, a.0, b.0))
+            }
+        }
+
         impl<T> PartialEq for $name<T> where T: AngleEq<Mask = bool> + PartialEq {
             #[inline]
             fn eq(&self, other: &$name<T>) -> bool {
@@ -355,10 +660,12 @@ macro_rules! make_hues {
             }
 
             fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
-                T::abs_diff_eq(&self.clone().into_degrees(), &other.clone().into_degrees(), epsilon)
+                let (self_degrees, other_degrees) = self.comparable_degrees(other);
+                T::abs_diff_eq(&self_degrees, &other_degrees, epsilon)
             }
             fn abs_diff_ne(&self, other: &Self, epsilon: T::Epsilon) -> bool {
-                T::abs_diff_ne(&self.clone().into_degrees(), &other.clone().into_degrees(), epsilon)
+                let (self_degrees, other_degrees) = self.comparable_degrees(other);
+                T::abs_diff_ne(&self_degrees, &other_degrees, epsilon)
             }
         }
 
@@ -378,7 +685,8 @@ macro_rules! make_hues {
                 epsilon: T::Epsilon,
                 max_relative: T::Epsilon,
             ) -> bool {
-                T::relative_eq(&self.clone().into_degrees(), &other.clone().into_degrees(), epsilon, max_relative)
+                let (self_degrees, other_degrees) = self.comparable_degrees(other);
+                T::relative_eq(&self_degrees, &other_degrees, epsilon, max_relative)
             }
             fn relative_ne(
                 &self,
@@ -386,7 +694,8 @@ macro_rules! make_hues {
                 epsilon: Self::Epsilon,
                 max_relative: Self::Epsilon,
             ) -> bool {
-                T::relative_ne(&self.clone().into_degrees(), &other.clone().into_degrees(), epsilon, max_relative)
+                let (self_degrees, other_degrees) = self.comparable_degrees(other);
+                T::relative_ne(&self_degrees, &other_degrees, epsilon, max_relative)
             }
         }
 
@@ -401,10 +710,12 @@ macro_rules! make_hues {
             }
 
             fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-                T::ulps_eq(&self.clone().into_degrees(), &other.clone().into_degrees(), epsilon, max_ulps)
+                let (self_degrees, other_degrees) = self.comparable_degrees(other);
+                T::ulps_eq(&self_degrees, &other_degrees, epsilon, max_ulps)
             }
             fn ulps_ne(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
-                T::ulps_ne(&self.clone().into_degrees(), &other.clone().into_degrees(), epsilon, max_ulps)
+                let (self_degrees, other_degrees) = self.comparable_degrees(other);
+                T::ulps_ne(&self_degrees, &other_degrees, epsilon, max_ulps)
             }
         }
 
@@ -572,6 +883,51 @@ macro_rules! make_hues {
             }
         }
 
+        impl<T: Neg<Output=T>> Neg for $name<T> {
+            type Output = $name<T>;
+
+            #[inline]
+            fn neg(self) -> $name<T> {
+                $name(-self.0)
+            }
+        }
+
+        impl<T: Mul<Output=T> + SignedAngle> Mul<T> for $name<T> {
+            type Output = $name<T>;
+
+            #[inline]
+            fn mul(self, factor: T) -> $name<T> {
+                $name(self.0.normalize_signed_angle() * factor)
+            }
+        }
+
+        impl Mul<$name<f32>> for f32 {
+            type Output = $name<f32>;
+
+            #[inline]
+            fn mul(self, hue: $name<f32>) -> $name<f32> {
+                hue * self
+            }
+        }
+
+        impl Mul<$name<f64>> for f64 {
+            type Output = $name<f64>;
+
+            #[inline]
+            fn mul(self, hue: $name<f64>) -> $name<f64> {
+                hue * self
+            }
+        }
+
+        impl<T: Div<Output=T> + SignedAngle> Div<T> for $name<T> {
+            type Output = $name<T>;
+
+            #[inline]
+            fn div(self, divisor: T) -> $name<T> {
+                $name(self.0.normalize_signed_angle() / divisor)
+            }
+        }
+
         impl<C, T> Extend<T> for $name<C> where C: Extend<T> {
             #[inline(always)]
             fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
@@ -759,6 +1115,12 @@ macro_rules! make_hues {
             }
         }
 
+        impl<I> core::iter::FusedIterator for $iter_name<I>
+        where
+            I: core::iter::FusedIterator,
+        {
+        }
+
         #[cfg(feature = "random")]
         impl<T> Distribution<$name<T>> for Standard
         where
@@ -806,6 +1168,113 @@ make_hues! {
     struct Cam16Hue; Cam16HueIter
 }
 
+/// An iterator over evenly spaced hues around the circle.
+///
+/// It's created by the `circle` method of the hue types, such as
+/// [`RgbHue::circle`]. Each hue is calculated as `start + i * 360 / n`, instead
+/// of repeatedly adding the step size, so there's no accumulated rounding error
+/// for large `n`.
+///
+/// It can be combined with [`WithHue`](crate::WithHue) to make a categorical
+/// palette from a base color:
+///
+/// ```
+/// use palette::{OklabHue, Oklch, WithHue};
+///
+/// let base = Oklch::new(0.7f32, 0.12, 20.0);
+/// let categories: Vec<Oklch> = base.hue.circle(6).map(|hue| base.with_hue(hue)).collect();
+///
+/// assert_eq!(categories.len(), 6);
+/// assert_eq!(categories[0], base);
+/// assert_eq!(categories[3].hue, OklabHue::new(200.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Circle<H, T> {
+    start: T,
+    n: usize,
+    front: usize,
+    back: usize,
+    hue: PhantomData<fn() -> H>,
+}
+
+impl<H, T> Circle<H, T> {
+    fn new(start: T, n: usize) -> Self {
+        Circle {
+            start,
+            n,
+            front: 0,
+            back: n,
+            hue: PhantomData,
+        }
+    }
+}
+
+impl<H, T> Circle<H, T>
+where
+    H: From<T>,
+    T: Real + Add<Output = T> + Clone,
+{
+    fn hue_at(&self, index: usize) -> H {
+        let offset = index as f64 * 360.0 / self.n as f64;
+        H::from(self.start.clone() + T::from_f64(offset))
+    }
+}
+
+impl<H, T> Iterator for Circle<H, T>
+where
+    H: From<T>,
+    T: Real + Add<Output = T> + Clone,
+{
+    type Item = H;
+
+    #[inline]
+    fn next(&mut self) -> Option<H> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let hue = self.hue_at(self.front);
+        self.front += 1;
+        Some(hue)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<H, T> DoubleEndedIterator for Circle<H, T>
+where
+    H: From<T>,
+    T: Real + Add<Output = T> + Clone,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<H> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.hue_at(self.back))
+    }
+}
+
+impl<H, T> ExactSizeIterator for Circle<H, T>
+where
+    H: From<T>,
+    T: Real + Add<Output = T> + Clone,
+{
+}
+
+impl<H, T> core::iter::FusedIterator for Circle<H, T>
+where
+    H: From<T>,
+    T: Real + Add<Output = T> + Clone,
+{
+}
+
 macro_rules! impl_uniform {
     (  $uni_ty: ident , $base_ty: ident) => {
         #[doc = concat!("Sample [`", stringify!($base_ty), "`] uniformly.")]
@@ -1001,6 +1470,43 @@ mod test {
             }
         }
 
+        #[test]
+        fn full_turn_radians_wraps_to_zero() {
+            let hue = RgbHue::from_radians(core::f64::consts::TAU);
+            assert_relative_eq!(hue.into_raw_degrees(), 360.0);
+            assert_abs_diff_eq!(hue.into_degrees(), 0.0);
+            assert_abs_diff_eq!(hue.into_positive_degrees(), 0.0);
+            assert_abs_diff_eq!(hue.into_radians(), 0.0);
+            assert_abs_diff_eq!(hue.into_turns(), 0.0);
+        }
+
+        #[test]
+        fn turns_round_trip() {
+            for &turns in &[-1.25f64, -0.5, -0.25, 0.0, 0.1, 0.25, 0.5, 0.75, 1.0, 2.5] {
+                let hue = OklabHue::from_turns(turns);
+                assert_relative_eq!(hue.into_raw_turns(), turns);
+                assert_relative_eq!(OklabHue::from_turns(hue.into_turns()), hue);
+                assert_relative_eq!(OklabHue::from_turns(hue.into_positive_turns()), hue);
+                assert_relative_eq!(hue.into_raw_degrees(), turns * 360.0);
+            }
+
+            assert_relative_eq!(RgbHue::from_turns(0.75f32).into_turns(), -0.25);
+            assert_relative_eq!(RgbHue::from_turns(-0.25f32).into_positive_turns(), 0.75);
+            assert_relative_eq!(RgbHue::from_turns(0.5f32).into_turns(), 0.5);
+        }
+
+        #[test]
+        fn approx_eq_is_circular() {
+            assert_relative_eq!(RgbHue::from(350.0_f32), RgbHue::from(-10.0));
+            assert_relative_eq!(RgbHue::from(720.0_f32), RgbHue::from(0.0));
+            assert_abs_diff_eq!(RgbHue::from(179.9_f64), RgbHue::from(-179.9), epsilon = 0.3);
+            assert_abs_diff_eq!(RgbHue::from(-179.9_f64), RgbHue::from(179.9), epsilon = 0.3);
+            assert_ulps_eq!(RgbHue::from(180.0_f64), RgbHue::from(-180.0));
+
+            assert_abs_diff_ne!(RgbHue::from(179.9_f64), RgbHue::from(-179.9), epsilon = 0.1);
+            assert_relative_ne!(RgbHue::from(10.0_f32), RgbHue::from(-10.0));
+        }
+
         #[test]
         fn float_conversion() {
             for i in -180..180 {
@@ -1017,6 +1523,194 @@ mod test {
         }
     }
 
+    mod arc {
+        use crate::{OklabHue, RgbHue};
+
+        const ANGLES: [f64; 12] = [
+            -720.0, -400.5, -180.0, -90.0, -0.5, 0.0, 10.0, 179.5, 180.0, 270.25, 359.5, 1000.0,
+        ];
+
+        #[test]
+        fn difference_is_antisymmetric() {
+            for &a in &ANGLES {
+                for &b in &ANGLES {
+                    let (a, b) = (OklabHue::new(a), OklabHue::new(b));
+                    let difference = a.difference(b);
+
+                    assert!(difference > -180.0 && difference <= 180.0);
+
+                    if difference != 180.0 {
+                        assert_eq!(difference, -b.difference(a));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn distance_is_at_most_half_rotation() {
+            for &a in &ANGLES {
+                for &b in &ANGLES {
+                    let distance = OklabHue::new(a).distance(OklabHue::new(b));
+
+                    assert!((0.0..=180.0).contains(&distance));
+                    assert_eq!(distance, OklabHue::new(b).distance(OklabHue::new(a)));
+                }
+            }
+        }
+
+        #[test]
+        fn lerp_endpoints() {
+            for &a in &ANGLES {
+                for &b in &ANGLES {
+                    let (a, b) = (RgbHue::new(a), RgbHue::new(b));
+
+                    assert_eq!(a.lerp(b, 0.0), a);
+                    assert_eq!(a.lerp(b, 1.0), b);
+                    assert!(a.lerp(b, 0.5).distance(a) <= 90.0);
+                }
+            }
+        }
+
+        #[test]
+        fn add_difference() {
+            for &a in &ANGLES {
+                for &b in &ANGLES {
+                    let (a, b) = (OklabHue::new(a), OklabHue::new(b));
+                    let difference = (b - a).into_degrees();
+
+                    assert!(difference > -180.0 && difference <= 180.0);
+                    assert_eq!(a + (b - a), b);
+                    assert_eq!(b - a, -(a - b));
+                }
+            }
+        }
+
+        #[test]
+        fn double_negation() {
+            for &a in &ANGLES {
+                let hue = RgbHue::new(a);
+                assert_eq!(-(-hue), hue);
+
+                if hue.into_degrees() != 180.0 {
+                    assert_eq!((-hue).into_degrees(), -hue.into_degrees());
+                }
+            }
+        }
+
+        #[test]
+        fn scale_shortest_arc() {
+            for &a in &ANGLES {
+                for &b in &ANGLES {
+                    let (a, b) = (RgbHue::new(a), RgbHue::new(b));
+
+                    for &t in &[0.0, 0.25, 0.5, 1.0] {
+                        let interpolated = a + (b - a) * t;
+                        assert!(interpolated.distance(a.lerp(b, t)) < 1e-9);
+                    }
+
+                    assert_eq!((b - a) / 2.0, (b - a) * 0.5);
+                    assert_eq!(2.0 * (b - a), (b - a) * 2.0);
+                }
+            }
+
+            assert_eq!(RgbHue::new(270.0) * 2.0, RgbHue::new(-180.0));
+            assert_eq!((RgbHue::new(270.0) / 3.0).into_degrees(), -30.0);
+        }
+
+        #[test]
+        fn clamp_to_arc() {
+            let min = RgbHue::new(300.0f32);
+            let max = RgbHue::new(60.0);
+
+            assert_eq!(RgbHue::new(0.0).clamp_to_arc(min, max), RgbHue::new(0.0));
+            assert_eq!(
+                RgbHue::new(-30.0).clamp_to_arc(min, max),
+                RgbHue::new(-30.0)
+            );
+            assert_eq!(RgbHue::new(100.0).clamp_to_arc(min, max), max);
+            assert_eq!(RgbHue::new(250.0).clamp_to_arc(min, max), min);
+
+            // The arc doesn't wrap when min is smaller than max.
+            assert_eq!(RgbHue::new(0.0).clamp_to_arc(max, min), max);
+            assert_eq!(
+                RgbHue::new(180.0).clamp_to_arc(max, min),
+                RgbHue::new(180.0)
+            );
+        }
+
+        #[cfg(feature = "wide")]
+        #[test]
+        fn simd() {
+            use ::wide::f32x4;
+
+            let a = RgbHue::new(f32x4::new([350.0, 10.0, 90.0, 0.0]));
+            let b = RgbHue::new(f32x4::new([10.0, 350.0, 270.5, 0.0]));
+
+            assert_eq!(a.difference(b).to_array(), [20.0, -20.0, -179.5, 0.0]);
+            assert_eq!(
+                ((b - a) * f32x4::splat(0.5)).into_inner().to_array(),
+                [10.0, -10.0, -89.75, 0.0]
+            );
+            assert_eq!((-(-a)).into_inner().to_array(), a.into_inner().to_array());
+            assert_eq!(
+                RgbHue::new(f32x4::splat(100.0))
+                    .clamp_to_arc(
+                        RgbHue::new(f32x4::splat(300.0)),
+                        RgbHue::new(f32x4::splat(60.0))
+                    )
+                    .into_inner()
+                    .to_array(),
+                [60.0; 4]
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod circle {
+        use crate::{OklabHue, RgbHue};
+
+        #[test]
+        fn evenly_divided() {
+            let hues: Vec<_> = RgbHue::new(0.0f32)
+                .circle(8)
+                .map(RgbHue::into_raw_degrees)
+                .collect();
+
+            assert_eq!(hues, [0.0, 45.0, 90.0, 135.0, 180.0, 225.0, 270.0, 315.0]);
+            assert_eq!(RgbHue::new(10.0f32).circle(0).count(), 0);
+            assert_eq!(
+                RgbHue::new(10.0f32).circle(1).collect::<Vec<_>>(),
+                [RgbHue::new(10.0)]
+            );
+        }
+
+        #[test]
+        fn unique() {
+            let mut hues: Vec<_> = OklabHue::new(-90.0f64)
+                .circle(360)
+                .map(OklabHue::into_positive_degrees)
+                .collect();
+            hues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            hues.dedup();
+
+            assert_eq!(hues.len(), 360);
+        }
+
+        #[test]
+        fn no_drift() {
+            let n = 1_000_000;
+            let mut circle = OklabHue::new(0.0f32).circle(n);
+
+            assert_eq!(circle.len(), n);
+            assert_eq!(circle.nth(n / 2).unwrap().into_raw_degrees(), 180.0);
+            assert_eq!(
+                circle.next_back().unwrap().into_raw_degrees(),
+                (360.0 - 360.0 / n as f64) as f32
+            );
+            assert_eq!(circle.len(), n / 2 - 2);
+        }
+    }
+
     #[cfg(feature = "serializing")]
     mod serde {
         use crate::RgbHue;
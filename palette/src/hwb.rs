@@ -6,7 +6,7 @@ use core::{any::TypeId, marker::PhantomData};
 use crate::hsv::UniformHsv;
 
 use crate::{
-    angle::FromAngle,
+    angle::{FromAngle, RealAngle},
     bool_mask::{HasBoolMask, LazySelect, Select},
     convert::FromColorUnclamped,
     encoding::Srgb,
@@ -14,7 +14,7 @@ use crate::{
     num::{Arithmetics, One, PartialCmp, Real},
     rgb::{RgbSpace, RgbStandard},
     stimulus::{FromStimulus, Stimulus},
-    Alpha, FromColor, Hsv, RgbHue, Xyz,
+    Alpha, FromColor, Hsv, IsAchromatic, RgbHue, Xyz,
 };
 
 /// Linear HWB with an alpha component. See the [`Hwba` implementation in
@@ -50,7 +50,7 @@ pub type Hwba<S = Srgb, T = f32> = Alpha<Hwb<S, T>, T>;
     palette_internal,
     rgb_standard = "S",
     component = "T",
-    skip_derives(Hsv, Hwb)
+    skip_derives(Hsv, Hwb, Okhwb)
 )]
 #[repr(C)]
 pub struct Hwb<S = Srgb, T = f32> {
@@ -110,6 +110,14 @@ impl<S, T> Hwb<S, T> {
         }
     }
 
+    /// Create an HWB color, with the hue in radians instead of degrees.
+    pub fn new_radians(hue: T, whiteness: T, blackness: T) -> Self
+    where
+        T: RealAngle,
+    {
+        Self::new_const(RgbHue::from_radians(hue), whiteness, blackness)
+    }
+
     /// Convert into another component type.
     pub fn into_format<U>(self) -> Hwb<S, U>
     where
@@ -152,6 +160,44 @@ impl<S, T> Hwb<S, T> {
     }
 }
 
+impl<S, T> Hwb<S, T>
+where
+    T: One + Arithmetics + PartialCmp + Clone,
+    T::Mask: Select<T>,
+{
+    /// Scale the whiteness and blackness down proportionally if their sum is
+    /// greater than 1, the same way as
+    /// [CSS](https://www.w3.org/TR/css-color-4/#the-hwb-notation) does it.
+    ///
+    /// A color where the whiteness and blackness add up to 1 or more is a
+    /// shade of gray, where the ratio between them decides how light it is.
+    /// This normalization keeps that ratio, and is done automatically when
+    /// converting to other color spaces.
+    ///
+    /// ```
+    /// use palette::Hwb;
+    ///
+    /// let hwb = Hwb::new_srgb(120.0, 0.75, 1.25).normalize();
+    /// assert_eq!(hwb, Hwb::new_srgb(120.0, 0.375, 0.625));
+    ///
+    /// // Colors within the valid range are unchanged.
+    /// let hwb = Hwb::new_srgb(120.0, 0.3, 0.5).normalize();
+    /// assert_eq!(hwb, Hwb::new_srgb(120.0, 0.3, 0.5));
+    /// ```
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        let sum = self.whiteness.clone() + &self.blackness;
+        let divisor = sum.gt(&T::one()).select(sum, T::one());
+
+        Hwb {
+            hue: self.hue,
+            whiteness: self.whiteness / &divisor,
+            blackness: self.blackness / divisor,
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S, T> Hwb<S, T>
 where
     T: Stimulus,
@@ -250,6 +296,7 @@ impl<S, T, A> Alpha<Hwb<S, T>, A> {
 
 impl_reference_component_methods_hue!(Hwb<S>, [whiteness, blackness], standard);
 impl_struct_of_arrays_methods_hue!(Hwb<S>, [whiteness, blackness], standard);
+impl_sort_keys!(Hwb<S> {hue});
 
 impl<S1, S2, T> FromColorUnclamped<Hwb<S1, T>> for Hwb<S2, T>
 where
@@ -288,13 +335,28 @@ where
 }
 
 impl_tuple_conversion_hue!(Hwb<S> as (H, T, T), RgbHue);
-impl_is_within_bounds_hwb!(Hwb<S> where T: Stimulus);
+impl_is_within_bounds_hwb!(Hwb<S> new(hue: impl Into<RgbHue<T>>, whiteness: T, blackness: T) where T: Stimulus);
 impl_clamp_hwb!(Hwb<S> phantom: standard where T: Stimulus);
 
 impl_mix_hue!(Hwb<S> {whiteness, blackness} phantom: standard);
 impl_lighten_hwb!(Hwb<S> phantom: standard where T: Stimulus);
 impl_hue_ops!(Hwb<S>, RgbHue);
 
+impl<S, T> IsAchromatic for Hwb<S, T>
+where
+    T: One + Arithmetics + PartialCmp + Clone,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the chroma, calculated as `1 -
+    /// whiteness - blackness`, is at most `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        let chroma = T::one() - &self.whiteness - &self.blackness;
+
+        chroma.lt_eq(&tolerance)
+    }
+}
+
 impl<S, T> HasBoolMask for Hwb<S, T>
 where
     T: HasBoolMask,
@@ -325,6 +387,8 @@ impl_struct_of_array_traits_hue!(Hwb<S>, RgbHueIter, [whiteness, blackness], sta
 
 impl_copy_clone!(Hwb<S>, [hue, whiteness, blackness], standard);
 impl_eq_hue!(Hwb<S>, RgbHue, [hue, whiteness, blackness]);
+impl_select!(Hwb<S>, [hue, whiteness, blackness], standard);
+impl_relative_luminance!(Hwb<S> via crate::Xyz<<S::Space as crate::rgb::RgbSpace>::WhitePoint, T> where S: RgbStandard);
 
 #[allow(deprecated)]
 impl<S, T> crate::RelativeContrast for Hwb<S, T>
@@ -345,6 +409,8 @@ where
     }
 }
 
+impl_wcag21_relative_contrast!(Hwb<S> where S: RgbStandard<Space = Srgb>);
+
 impl_rand_traits_hwb_cone!(
     UniformHwb,
     Hwb<S>,
@@ -410,7 +476,7 @@ mod test {
 
     #[cfg(feature = "approx")]
     mod clamp {
-        use crate::{Clamp, Hwb};
+        use crate::{Clamp, ClampAssign, Hwb};
 
         #[test]
         fn clamp_invalid() {
@@ -437,6 +503,201 @@ mod test {
             let clamped = Hwb::new_srgb(240.0, 0.3, 0.1).clamp();
             assert_relative_eq!(expected, clamped);
         }
+
+        #[test]
+        fn clamp_negative_and_over_one() {
+            let expected = Hwb::new_srgb(240.0, 0.0, 1.0);
+            let clamped = Hwb::new_srgb(240.0, -0.5, 1.2).clamp();
+            assert_relative_eq!(expected, clamped);
+
+            let mut clamped = Hwb::new_srgb(240.0, -0.5, 1.2);
+            clamped.clamp_assign();
+            assert_relative_eq!(expected, clamped);
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    mod css {
+        use crate::{FromColor, Hwb, Srgb};
+
+        // Examples from CSS Color Module Level 4, where whiteness and
+        // blackness add up to 100% or more.
+        #[test]
+        fn gray_from_ratio() {
+            let rgb = Srgb::from_color(Hwb::new_srgb(0.0, 0.6, 0.6));
+            assert_relative_eq!(rgb, Srgb::new(0.5, 0.5, 0.5));
+
+            let rgb = Srgb::from_color(Hwb::new_srgb(120.0, 1.0, 1.0));
+            assert_relative_eq!(rgb, Srgb::new(0.5, 0.5, 0.5));
+
+            let rgb = Srgb::from_color(Hwb::new_srgb(240.0, 0.5, 0.8));
+            assert_relative_eq!(rgb, Srgb::new(0.5 / 1.3, 0.5 / 1.3, 0.5 / 1.3));
+        }
+
+        #[test]
+        fn gray_at_one() {
+            let rgb = Srgb::from_color(Hwb::new_srgb(60.0, 0.2, 0.8));
+            assert_relative_eq!(rgb, Srgb::new(0.2, 0.2, 0.2));
+
+            let rgb = Srgb::from_color(Hwb::new_srgb(60.0, 0.0, 1.0));
+            assert_relative_eq!(rgb, Srgb::new(0.0, 0.0, 0.0));
+
+            let rgb = Srgb::from_color(Hwb::new_srgb(60.0, 1.0, 0.0));
+            assert_relative_eq!(rgb, Srgb::new(1.0, 1.0, 1.0));
+        }
+
+        #[test]
+        fn unchanged_below_one() {
+            let rgb = Srgb::from_color(Hwb::new_srgb(120.0, 0.0, 0.0));
+            assert_relative_eq!(rgb, Srgb::new(0.0, 1.0, 0.0));
+
+            let rgb = Srgb::from_color(Hwb::new_srgb(120.0, 0.2, 0.3));
+            assert_relative_eq!(rgb, Srgb::new(0.2, 0.7, 0.2));
+        }
+
+        #[test]
+        fn normalize_keeps_ratio() {
+            let hwb = Hwb::new_srgb(120.0, 0.75, 0.5).normalize();
+            assert_relative_eq!(hwb, Hwb::new_srgb(120.0, 0.6, 0.4));
+        }
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Hwb::<crate::encoding::Srgb, f32>::try_new(40.0, 0.5, 0.5).is_ok());
+        assert!(Hwb::<crate::encoding::Srgb, f32>::try_new(40.0, 0.75, 0.5).is_err());
+        assert!(Hwb::<crate::encoding::Srgb, f32>::try_new(40.0, -0.1, 0.5).is_err());
+        assert!(Hwb::<crate::encoding::Srgb, f32>::try_new(f32::NAN, 0.5, 0.5).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lighten_struct_of_arrays() {
+        use crate::LightenAssign;
+
+        let mut vec_of_colors = vec![
+            Hwb::new_srgb(-30.0f32, 0.2, 0.3),
+            Hwb::new_srgb(120.0, 0.9, 0.1),
+            Hwb::new_srgb(400.0, 0.5, 0.5),
+        ];
+        let mut color_of_vecs: Hwb<crate::encoding::Srgb, Vec<_>> =
+            vec_of_colors.clone().into_iter().collect();
+
+        color_of_vecs.lighten_assign(0.3);
+        color_of_vecs.lighten_fixed_assign(-0.2);
+        vec_of_colors.lighten_assign(0.3);
+        vec_of_colors.lighten_fixed_assign(-0.2);
+
+        assert_eq!(color_of_vecs.into_iter().collect::<Vec<_>>(), vec_of_colors);
+    }
+
+    #[cfg(feature = "approx")]
+    mod whiten_blacken {
+        use crate::{Blacken, BlackenAssign, FromColor, Hwb, Lighten, Srgb, Whiten, WhitenAssign};
+
+        #[test]
+        fn whiten_only_changes_whiteness() {
+            let color = Hwb::new_srgb(120.0, 0.2, 0.4);
+
+            assert_relative_eq!(color.whiten(0.5), Hwb::new_srgb(120.0, 0.6, 0.4));
+            assert_relative_eq!(color.whiten(-0.5), Hwb::new_srgb(120.0, 0.1, 0.4));
+            assert_relative_eq!(color.blacken(0.5), Hwb::new_srgb(120.0, 0.2, 0.7));
+            assert_relative_eq!(color.blacken(-0.5), Hwb::new_srgb(120.0, 0.2, 0.2));
+
+            let mut assigned = color;
+            assigned.whiten_assign(0.5);
+            assigned.blacken_assign(0.5);
+            assert_relative_eq!(assigned, color.whiten(0.5).blacken(0.5));
+        }
+
+        #[test]
+        fn fixed_amounts_stay_in_range() {
+            let color = Hwb::new_srgb(120.0, 0.2, 0.4);
+
+            assert_relative_eq!(color.whiten_fixed(2.0).whiteness, 1.0);
+            assert_relative_eq!(color.whiten_fixed(-2.0).whiteness, 0.0);
+            assert_relative_eq!(color.blacken_fixed(2.0).blackness, 1.0);
+            assert_relative_eq!(color.blacken_fixed(-2.0).blackness, 0.0);
+
+            let mut assigned = color;
+            assigned.whiten_fixed_assign(2.0);
+            assigned.blacken_fixed_assign(-2.0);
+            assert_relative_eq!(assigned, Hwb::new_srgb(120.0, 1.0, 0.0));
+        }
+
+        #[test]
+        fn saturated_sum_is_gray() {
+            // Whiteness and blackness already add up to 1.
+            let color = Hwb::new_srgb(120.0, 0.4, 0.6);
+
+            let whitened = color.whiten(0.5);
+            assert_relative_eq!(whitened, Hwb::new_srgb(120.0, 0.7, 0.6));
+            assert_relative_eq!(
+                Srgb::from_color(whitened),
+                Srgb::new(0.7 / 1.3, 0.7 / 1.3, 0.7 / 1.3)
+            );
+
+            let blackened = color.blacken(1.0);
+            assert_relative_eq!(
+                Srgb::from_color(blackened),
+                Srgb::new(0.4 / 1.4, 0.4 / 1.4, 0.4 / 1.4)
+            );
+
+            // Fully whitening a color without blackness gives white.
+            let white = Hwb::new_srgb(120.0, 0.3, 0.0).whiten(1.0);
+            assert_relative_eq!(Srgb::from_color(white), Srgb::new(1.0, 1.0, 1.0));
+        }
+
+        #[test]
+        fn lighten_keeps_sum_within_one() {
+            for &(whiteness, blackness) in &[(0.0, 0.0), (0.2, 0.8), (0.5, 0.5), (0.3, 0.1)] {
+                let color = Hwb::new_srgb(120.0, whiteness, blackness);
+
+                for &factor in &[-1.0, -0.5, 0.0, 0.5, 1.0] {
+                    let lightened = color.lighten(factor);
+                    assert!(lightened.whiteness + lightened.blackness <= 1.0 + f64::EPSILON);
+                    assert_relative_eq!(lightened, color.whiten(factor).blacken(-factor));
+
+                    let lightened = color.lighten_fixed(factor);
+                    assert!(lightened.whiteness + lightened.blackness <= 1.0 + f64::EPSILON);
+                    assert_relative_eq!(
+                        lightened,
+                        color.whiten_fixed(factor).blacken_fixed(-factor)
+                    );
+                }
+            }
+
+            let color = Hwb::new_srgb(120.0, 0.2, 0.8);
+            assert_relative_eq!(color.lighten(1.0), Hwb::new_srgb(120.0, 1.0, 0.0));
+            assert_relative_eq!(color.lighten(-1.0), Hwb::new_srgb(120.0, 0.0, 1.0));
+            assert_relative_eq!(color.lighten_fixed(2.0), Hwb::new_srgb(120.0, 1.0, 0.0));
+            assert_relative_eq!(color.lighten_fixed(-2.0), Hwb::new_srgb(120.0, 0.0, 1.0));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn clamp_struct_of_arrays() {
+        use crate::{ClampAssign, IsWithinBounds};
+
+        let mut vec_of_colors = vec![
+            Hwb::new_srgb(-30.0f32, 0.75, 0.5),
+            Hwb::new_srgb(120.0, -0.5, 0.25),
+            Hwb::new_srgb(400.0, 0.25, 0.5),
+        ];
+        let mut color_of_vecs: Hwb<crate::encoding::Srgb, Vec<_>> =
+            vec_of_colors.clone().into_iter().collect();
+        assert!(!color_of_vecs.is_within_bounds());
+
+        color_of_vecs.clamp_assign();
+        vec_of_colors.iter_mut().for_each(ClampAssign::clamp_assign);
+
+        assert!(color_of_vecs.is_within_bounds());
+        assert_eq!(
+            color_of_vecs.hue.clone().into_inner(),
+            [-30.0, 120.0, 400.0]
+        );
+        assert_eq!(color_of_vecs.into_iter().collect::<Vec<_>>(), vec_of_colors);
     }
 
     raw_pixel_conversion_tests!(Hwb<crate::encoding::Srgb>: hue, whiteness, blackness);
@@ -452,6 +713,17 @@ mod test {
         assert_eq!(Hwb::<Srgb>::max_blackness(), 1.0,);
     }
 
+    #[test]
+    fn is_achromatic() {
+        use crate::IsAchromatic;
+
+        assert!(Hwb::new_srgb(120.0f32, 0.5, 0.5).is_achromatic_within(0.0));
+        assert!(Hwb::new_srgb(120.0f32, 0.8, 0.6).is_achromatic_within(0.0));
+        assert!(Hwb::new_srgb(120.0f32, 0.49, 0.5).is_achromatic_within(0.02));
+        assert!(!Hwb::new_srgb(120.0f32, 0.49, 0.5).is_achromatic_within(0.005));
+        assert!(!Hwb::new_srgb(120.0f32, 0.0, 0.0).is_achromatic_within(0.02));
+    }
+
     struct_of_arrays_tests!(
         Hwb<crate::encoding::Srgb>[hue, whiteness, blackness] phantom: standard,
         super::Hwba::new(0.1f32, 0.2, 0.3, 0.4),
@@ -483,6 +755,8 @@ mod test {
             blue: (0.0, 1.0)
         },
         min: Hwb::new(0.0f32, 0.0, 0.0),
-        max: Hwb::new(360.0, 1.0, 1.0)
+        // Full whiteness and blackness would be normalized to 50% gray, so
+        // black is used to reach the bottom of the cone.
+        max: Hwb::new(360.0, 0.0, 1.0)
     }
 }
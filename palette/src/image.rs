@@ -0,0 +1,245 @@
+//! Conversions and casting between pixel buffers from [`image`](::image) and
+//! Palette's types.
+//!
+//! The pixel types from `image` can be converted to and from [`Rgb`] and
+//! [`Alpha<Rgb>`](crate::Alpha) with [`From`] and [`Into`], and whole image
+//! buffers can be viewed as slices of colors without copying:
+//!
+//! ```
+//! use image::{Rgb, RgbImage};
+//! use palette::{image::to_linear, image::from_linear, image::as_rgb_slice_mut};
+//! use palette::{FromColor, Lighten, LinSrgb, Oklab};
+//!
+//! let image = RgbImage::from_pixel(4, 4, Rgb([100, 150, 200]));
+//!
+//! // Lighten each pixel in Oklab, using linear intermediate values.
+//! let mut linear = to_linear(&image);
+//! let colors: &mut [LinSrgb] = as_rgb_slice_mut(&mut linear);
+//!
+//! for color in colors {
+//!     *color = LinSrgb::from_color(Oklab::from_color(*color).lighten(0.2));
+//! }
+//!
+//! let lighter = from_linear(&linear);
+//! assert!(lighter.get_pixel(0, 0)[0] > 100);
+//! ```
+
+use core::ops::{Deref, DerefMut};
+
+use ::image::{ImageBuffer, Primitive, Rgb32FImage, RgbImage};
+
+use crate::{cast::ComponentsAs, cast::ComponentsAsMut, rgb::Rgb, Alpha, LinSrgb, Srgb};
+
+impl<S, T> From<::image::Rgb<T>> for Rgb<S, T>
+where
+    T: Primitive,
+{
+    #[inline]
+    fn from(pixel: ::image::Rgb<T>) -> Self {
+        let [red, green, blue] = pixel.0;
+        Rgb::new(red, green, blue)
+    }
+}
+
+impl<S, T> From<Rgb<S, T>> for ::image::Rgb<T>
+where
+    T: Primitive,
+{
+    #[inline]
+    fn from(color: Rgb<S, T>) -> Self {
+        ::image::Rgb([color.red, color.green, color.blue])
+    }
+}
+
+impl<S, T> From<::image::Rgba<T>> for Alpha<Rgb<S, T>, T>
+where
+    T: Primitive,
+{
+    #[inline]
+    fn from(pixel: ::image::Rgba<T>) -> Self {
+        let [red, green, blue, alpha] = pixel.0;
+        Alpha::<Rgb<S, T>, T>::new(red, green, blue, alpha)
+    }
+}
+
+impl<S, T> From<Alpha<Rgb<S, T>, T>> for ::image::Rgba<T>
+where
+    T: Primitive,
+{
+    #[inline]
+    fn from(color: Alpha<Rgb<S, T>, T>) -> Self {
+        ::image::Rgba([color.red, color.green, color.blue, color.alpha])
+    }
+}
+
+/// View the pixels of an RGB image buffer as a slice of [`Rgb`] colors.
+///
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use palette::Srgb;
+///
+/// let image = RgbImage::from_pixel(2, 2, Rgb([10, 20, 30]));
+/// let colors: &[Srgb<u8>] = palette::image::as_rgb_slice(&image);
+///
+/// assert_eq!(colors, &[Srgb::new(10, 20, 30); 4]);
+/// ```
+#[inline]
+pub fn as_rgb_slice<S, T, Container>(
+    image: &ImageBuffer<::image::Rgb<T>, Container>,
+) -> &[Rgb<S, T>]
+where
+    T: Primitive + 'static,
+    Container: Deref<Target = [T]>,
+{
+    (**image).components_as()
+}
+
+/// View the pixels of an RGB image buffer as a mutable slice of [`Rgb`]
+/// colors.
+///
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use palette::Srgb;
+///
+/// let mut image = RgbImage::new(2, 2);
+/// let colors: &mut [Srgb<u8>] = palette::image::as_rgb_slice_mut(&mut image);
+/// colors[3] = Srgb::new(10, 20, 30);
+///
+/// assert_eq!(image.get_pixel(1, 1), &Rgb([10, 20, 30]));
+/// ```
+#[inline]
+pub fn as_rgb_slice_mut<S, T, Container>(
+    image: &mut ImageBuffer<::image::Rgb<T>, Container>,
+) -> &mut [Rgb<S, T>]
+where
+    T: Primitive + 'static,
+    Container: DerefMut<Target = [T]>,
+{
+    (**image).components_as_mut()
+}
+
+/// View the pixels of an RGBA image buffer as a slice of transparent [`Rgb`]
+/// colors.
+///
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use palette::Srgba;
+///
+/// let image = RgbaImage::from_pixel(2, 2, Rgba([10, 20, 30, 40]));
+/// let colors: &[Srgba<u8>] = palette::image::as_rgba_slice(&image);
+///
+/// assert_eq!(colors, &[Srgba::new(10, 20, 30, 40); 4]);
+/// ```
+#[inline]
+pub fn as_rgba_slice<S, T, Container>(
+    image: &ImageBuffer<::image::Rgba<T>, Container>,
+) -> &[Alpha<Rgb<S, T>, T>]
+where
+    T: Primitive + 'static,
+    Container: Deref<Target = [T]>,
+{
+    (**image).components_as()
+}
+
+/// View the pixels of an RGBA image buffer as a mutable slice of transparent
+/// [`Rgb`] colors.
+///
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use palette::Srgba;
+///
+/// let mut image = RgbaImage::new(2, 2);
+/// let colors: &mut [Srgba<u8>] = palette::image::as_rgba_slice_mut(&mut image);
+/// colors[3] = Srgba::new(10, 20, 30, 40);
+///
+/// assert_eq!(image.get_pixel(1, 1), &Rgba([10, 20, 30, 40]));
+/// ```
+#[inline]
+pub fn as_rgba_slice_mut<S, T, Container>(
+    image: &mut ImageBuffer<::image::Rgba<T>, Container>,
+) -> &mut [Alpha<Rgb<S, T>, T>]
+where
+    T: Primitive + 'static,
+    Container: DerefMut<Target = [T]>,
+{
+    (**image).components_as_mut()
+}
+
+/// Decode an 8 bit sRGB image into a linear, floating point image, using the
+/// sRGB transfer function.
+///
+/// See the [module documentation](crate::image) for an example.
+pub fn to_linear<Container>(image: &ImageBuffer<::image::Rgb<u8>, Container>) -> Rgb32FImage
+where
+    Container: Deref<Target = [u8]>,
+{
+    let (width, height) = image.dimensions();
+    let mut linear = Rgb32FImage::new(width, height);
+
+    let colors: &[Srgb<u8>] = as_rgb_slice(image);
+    let linear_colors: &mut [LinSrgb<f32>] = as_rgb_slice_mut(&mut linear);
+
+    for (linear_color, &color) in linear_colors.iter_mut().zip(colors) {
+        *linear_color = color.into_linear();
+    }
+
+    linear
+}
+
+/// Encode a linear, floating point image as an 8 bit sRGB image, using the
+/// sRGB transfer function.
+///
+/// See the [module documentation](crate::image) for an example.
+pub fn from_linear<Container>(image: &ImageBuffer<::image::Rgb<f32>, Container>) -> RgbImage
+where
+    Container: Deref<Target = [f32]>,
+{
+    let (width, height) = image.dimensions();
+    let mut encoded = RgbImage::new(width, height);
+
+    let linear_colors: &[LinSrgb<f32>] = as_rgb_slice(image);
+    let colors: &mut [Srgb<u8>] = as_rgb_slice_mut(&mut encoded);
+
+    for (color, &linear_color) in colors.iter_mut().zip(linear_colors) {
+        *color = Srgb::from_linear(linear_color);
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use ::image::{Rgb, RgbImage, Rgba};
+
+    use super::{as_rgb_slice, from_linear, to_linear};
+    use crate::{LinSrgb, Srgb, Srgba};
+
+    #[test]
+    fn pixel_roundtrip() {
+        let color = Srgba::new(10u8, 20, 30, 40);
+        let pixel: Rgba<u8> = color.into();
+
+        assert_eq!(pixel, Rgba([10, 20, 30, 40]));
+        assert_eq!(Srgba::from(pixel), color);
+
+        let color = Srgb::new(0.1f32, 0.2, 0.3);
+        let pixel: Rgb<f32> = color.into();
+
+        assert_eq!(pixel, Rgb([0.1, 0.2, 0.3]));
+        assert_eq!(Srgb::from(pixel), color);
+    }
+
+    #[test]
+    fn linear_roundtrip() {
+        let mut image = RgbImage::new(16, 16);
+        for (index, component) in image.iter_mut().enumerate() {
+            *component = index as u8;
+        }
+
+        let linear = to_linear(&image);
+        let colors: &[LinSrgb<f32>] = as_rgb_slice(&linear);
+
+        assert_eq!(colors[1], Srgb::new(3u8, 4, 5).into_linear());
+        assert_eq!(from_linear(&linear), image);
+    }
+}
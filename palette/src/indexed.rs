@@ -0,0 +1,253 @@
+//! Indexed colors, where each pixel is an index into a shared palette.
+//!
+//! An [`IndexedColor`] is a single `u8` index, so an indexed image with up to
+//! 256 palette entries can be cast from a `&[u8]` buffer without copying. The
+//! palette is kept separately, as a slice of colors, and is passed to
+//! [`IndexedColor::resolve`] or [`resolve_all`] to look up the actual colors.
+//! Indices that are outside the palette are reported as an
+//! [`IndexOutOfRangeError`], instead of being clamped or wrapped.
+//!
+//! ```
+//! use palette::{cast::ComponentsAs, indexed::{self, IndexedColor}, Srgb};
+//!
+//! let palette = [Srgb::new(0u8, 0, 0), Srgb::new(255, 255, 255)];
+//! let buffer = [0u8, 1, 1, 0];
+//!
+//! let image: &[IndexedColor] = buffer.components_as();
+//! assert_eq!(image[1].resolve(&palette), Ok(Srgb::new(255, 255, 255)));
+//!
+//! let colors = indexed::resolve_all(image, &palette).unwrap();
+//! assert_eq!(colors[3], Srgb::new(0, 0, 0));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::quantize::QuantizeColor;
+
+use crate::cast::ArrayCast;
+
+/// An index into a palette of up to 256 colors.
+///
+/// `IndexedColor` has the same memory layout as `u8`, so buffers of indices
+/// can be cast to and from buffers of `IndexedColor` with the functions and
+/// traits in [`cast`](crate::cast).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, ArrayCast)]
+#[palette(palette_internal)]
+#[repr(transparent)]
+pub struct IndexedColor {
+    /// The position of the color in the palette.
+    pub index: u8,
+}
+
+impl IndexedColor {
+    /// Create an indexed color from a palette index.
+    #[inline]
+    pub const fn new(index: u8) -> Self {
+        IndexedColor { index }
+    }
+
+    /// Look up the color at this index in `palette`.
+    ///
+    /// Returns an error if the index is outside `palette`.
+    ///
+    /// ```
+    /// use palette::{indexed::IndexedColor, Srgb};
+    ///
+    /// let palette = [Srgb::new(255u8, 0, 0), Srgb::new(0, 0, 255)];
+    ///
+    /// assert_eq!(IndexedColor::new(1).resolve(&palette), Ok(Srgb::new(0, 0, 255)));
+    /// assert!(IndexedColor::new(2).resolve(&palette).is_err());
+    /// ```
+    #[inline]
+    pub fn resolve<C>(self, palette: &[C]) -> Result<C, IndexOutOfRangeError>
+    where
+        C: Clone,
+    {
+        palette
+            .get(usize::from(self.index))
+            .cloned()
+            .ok_or(IndexOutOfRangeError {
+                index: self.index,
+                palette_len: palette.len(),
+            })
+    }
+}
+
+impl From<u8> for IndexedColor {
+    #[inline]
+    fn from(index: u8) -> Self {
+        IndexedColor { index }
+    }
+}
+
+impl From<IndexedColor> for u8 {
+    #[inline]
+    fn from(color: IndexedColor) -> Self {
+        color.index
+    }
+}
+
+/// Look up the colors of all indices in `indices`.
+///
+/// Returns an error for the first index that is outside `palette`.
+///
+/// ```
+/// use palette::{indexed::{resolve_all, IndexedColor}, Srgb};
+///
+/// let palette = [Srgb::new(255u8, 0, 0), Srgb::new(0, 0, 255)];
+/// let indices = [IndexedColor::new(1), IndexedColor::new(0)];
+///
+/// assert_eq!(
+///     resolve_all(&indices, &palette),
+///     Ok(vec![Srgb::new(0, 0, 255), Srgb::new(255, 0, 0)])
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn resolve_all<C>(
+    indices: &[IndexedColor],
+    palette: &[C],
+) -> Result<Vec<C>, IndexOutOfRangeError>
+where
+    C: Clone,
+{
+    indices.iter().map(|color| color.resolve(palette)).collect()
+}
+
+/// Map each color in `colors` to the index of its closest color in
+/// `palette`.
+///
+/// This works like [`quantize::remap`](crate::quantize::remap), and measures
+/// the distances in [`Oklab`](crate::Oklab). Any color type that implements
+/// [`QuantizeColor`] can be used, including [`Srgb<u8>`](crate::Srgb).
+///
+/// # Panics
+///
+/// Panics if `palette` has more than 256 colors, or if it's empty and `colors`
+/// isn't.
+///
+/// ```
+/// use palette::{indexed::{quantize, IndexedColor}, Srgb};
+///
+/// let palette = [Srgb::new(0u8, 0, 0), Srgb::new(255, 255, 255)];
+/// let colors = [Srgb::new(230u8, 200, 230), Srgb::new(25, 50, 25)];
+///
+/// assert_eq!(
+///     quantize(&colors, &palette),
+///     [IndexedColor::new(1), IndexedColor::new(0)]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn quantize<C>(colors: &[C], palette: &[C]) -> Vec<IndexedColor>
+where
+    C: QuantizeColor,
+{
+    assert!(palette.len() <= 256, "the palette has more than 256 colors");
+
+    crate::quantize::remap(colors, palette)
+        .into_iter()
+        .map(|index| IndexedColor::new(index as u8))
+        .collect()
+}
+
+/// The error type returned when an index is outside its palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfRangeError {
+    /// The index that was looked up.
+    pub index: u8,
+
+    /// The number of colors in the palette.
+    pub palette_len: usize,
+}
+
+impl core::fmt::Display for IndexOutOfRangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "index {} is out of range for a palette with {} colors",
+            self.index, self.palette_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexOutOfRangeError {}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod test {
+    use super::{quantize, resolve_all, IndexOutOfRangeError, IndexedColor};
+    use crate::{cast, Srgb};
+
+    #[test]
+    fn out_of_range() {
+        let palette = [Srgb::new(10u8, 20, 30), Srgb::new(40, 50, 60)];
+        let buffer = [1u8, 2, 0, 255];
+        let indices: &[IndexedColor] = cast::from_component_slice(&buffer);
+
+        assert_eq!(indices[0].resolve(&palette), Ok(palette[1]));
+        assert_eq!(
+            indices[1].resolve(&palette),
+            Err(IndexOutOfRangeError {
+                index: 2,
+                palette_len: 2
+            })
+        );
+        assert_eq!(
+            resolve_all(indices, &palette),
+            Err(IndexOutOfRangeError {
+                index: 2,
+                palette_len: 2
+            })
+        );
+        assert!(IndexedColor::new(0).resolve::<Srgb<u8>>(&[]).is_err());
+    }
+
+    #[test]
+    fn buffer_casting_aliases() {
+        let mut buffer = [0u8, 1, 2];
+
+        let indices: &mut [IndexedColor] = cast::from_component_slice_mut(&mut buffer);
+        indices[1] = IndexedColor::new(7);
+
+        assert_eq!(buffer, [0, 7, 2]);
+    }
+
+    #[test]
+    fn quantize_resolve_round_trip() {
+        let palette: Vec<Srgb<u8>> = (0..=255u8)
+            .map(|value| Srgb::new(value, 0, 255 - value))
+            .collect();
+
+        let image: Vec<Srgb<u8>> = [3u8, 200, 200, 0, 255, 17]
+            .iter()
+            .map(|&index| palette[usize::from(index)])
+            .collect();
+
+        let indices = quantize(&image, &palette);
+        assert_eq!(
+            cast::into_component_slice(&indices),
+            [3, 200, 200, 0, 255, 17]
+        );
+        assert_eq!(resolve_all(&indices, &palette), Ok(image));
+    }
+
+    #[test]
+    fn quantize_float_colors() {
+        let palette = [Srgb::new(0.0f32, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0)];
+        let colors = [Srgb::new(0.9f32, 0.8, 0.9), Srgb::new(0.1, 0.2, 0.1)];
+
+        assert_eq!(
+            quantize(&colors, &palette),
+            [IndexedColor::new(1), IndexedColor::new(0)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the palette has more than 256 colors")]
+    fn quantize_too_large_palette() {
+        let palette = vec![Srgb::new(0.0f32, 0.0, 0.0); 257];
+        quantize(&palette[..1], &palette);
+    }
+}
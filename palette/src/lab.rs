@@ -7,7 +7,7 @@ use core::{
 
 use crate::{
     angle::RealAngle,
-    bool_mask::{HasBoolMask, LazySelect},
+    bool_mask::{BitOps, HasBoolMask, LazySelect},
     color_difference::{
         get_ciede2000_difference, Ciede2000, DeltaE, EuclideanDistance, ImprovedDeltaE,
         LabColorDiff,
@@ -18,7 +18,7 @@ use crate::{
         Trigonometry, Zero,
     },
     white_point::{WhitePoint, D65},
-    Alpha, FromColor, GetHue, LabHue, Lch, Xyz,
+    Alpha, FromColor, GetHue, IsAchromatic, LabHue, Lch, TryGetHue, Xyz,
 };
 
 /// CIE L\*a\*b\* (CIELAB) with an alpha component. See the [`Laba`
@@ -149,6 +149,7 @@ impl<Wp, T, A> Alpha<Lab<Wp, T>, A> {
 
 impl_reference_component_methods!(Lab<Wp>, [l, a, b], white_point);
 impl_struct_of_arrays_methods!(Lab<Wp>, [l, a, b], white_point);
+impl_sort_keys!(Lab<Wp> {lightness: l});
 
 impl<Wp, T> FromColorUnclamped<Lab<Wp, T>> for Lab<Wp, T> {
     fn from_color_unclamped(color: Lab<Wp, T>) -> Self {
@@ -214,6 +215,7 @@ impl_is_within_bounds! {
         a => [Self::min_a(), Self::max_a()],
         b => [Self::min_b(), Self::max_b()]
     }
+    new(l: T, a: T, b: T)
     where T: Real + Zero
 }
 impl_clamp! {
@@ -231,6 +233,7 @@ impl_lighten!(Lab<Wp> increase {l => [Self::min_l(), Self::max_l()]} other {a, b
 impl_premultiply!(Lab<Wp> {l, a, b} phantom: white_point);
 impl_euclidean_distance!(Lab<Wp> {l, a, b});
 impl_hyab!(Lab<Wp> {lightness: l, chroma1: a, chroma2: b});
+impl_saturate_cartesian!(Lab<Wp> {chroma1: a, chroma2: b} max_chroma: Lch::<Wp, T>::max_chroma());
 impl_lab_color_schemes!(Lab<Wp>[l, white_point]);
 
 impl<Wp, T> GetHue for Lab<Wp, T>
@@ -244,6 +247,36 @@ where
     }
 }
 
+impl<Wp, T> TryGetHue for Lab<Wp, T>
+where
+    T: Real + RealAngle + Trigonometry + Arithmetics + PartialCmp + Clone,
+    T::Mask: BitOps,
+{
+    /// The hue is considered meaningful if the chroma, calculated from `a`
+    /// and `b`, is greater than `0.001`.
+    fn get_hue_masked(&self) -> (LabHue<T>, T::Mask) {
+        (
+            self.get_hue(),
+            !self.is_achromatic_within(T::from_f64(0.001)),
+        )
+    }
+}
+
+impl<Wp, T> IsAchromatic for Lab<Wp, T>
+where
+    T: Arithmetics + PartialCmp + Clone,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the chroma, calculated from `a`
+    /// and `b`, is at most `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        let chroma_squared = self.a.clone() * &self.a + self.b.clone() * &self.b;
+
+        chroma_squared.lt_eq(&(tolerance.clone() * tolerance))
+    }
+}
+
 impl<Wp, T> DeltaE for Lab<Wp, T>
 where
     Self: EuclideanDistance<Scalar = T>,
@@ -345,12 +378,15 @@ impl_color_add!(Lab<Wp>, [l, a, b], white_point);
 impl_color_sub!(Lab<Wp>, [l, a, b], white_point);
 impl_color_mul!(Lab<Wp>, [l, a, b], white_point);
 impl_color_div!(Lab<Wp>, [l, a, b], white_point);
+impl_component_wise!(Lab<Wp>, [l, a, b], white_point);
 
 impl_array_casts!(Lab<Wp, T>, [T; 3]);
 impl_simd_array_conversion!(Lab<Wp>, [l, a, b], white_point);
 impl_struct_of_array_traits!(Lab<Wp>, [l, a, b], white_point);
 
 impl_eq!(Lab<Wp>, [l, a, b]);
+impl_select!(Lab<Wp>, [l, a, b], white_point);
+impl_relative_luminance!(Lab<Wp> via crate::Xyz<Wp, T>);
 impl_copy_clone!(Lab<Wp>, [l, a, b], white_point);
 
 #[allow(deprecated)]
@@ -371,6 +407,8 @@ where
     }
 }
 
+impl_wcag21_relative_contrast!(Lab<Wp>);
+
 impl_rand_traits_cartesian!(
     UniformLab,
     Lab<Wp> {
@@ -397,6 +435,17 @@ mod test {
     use crate::Lch;
 
     test_convert_into_from_xyz!(Lab);
+    test_lighten_lightness!(Lab<D65> {l} new(50.0, 20.0, 30.0));
+
+    test_saturate_cartesian!(
+        Lab / Lch[
+            Lab::<D65, f64>::new(53.2, 80.1, 67.2),
+            Lab::<D65, f64>::new(32.3, 79.2, -107.9),
+            Lab::<D65, f64>::new(70.0, -10.0, 5.0),
+            Lab::<D65, f64>::new(40.0, 100.0, 100.0)
+        ],
+        gray: Lab::<D65, f64>::new(50.0, 0.0, 0.0)
+    );
 
     #[cfg(feature = "approx")]
     mod conversion {
@@ -422,6 +471,65 @@ mod test {
             let b = Lab::new(32.302586, 79.19668, -107.863686);
             assert_relative_eq!(a, b, epsilon = 0.01);
         }
+
+        #[test]
+        fn degree_10_observer() {
+            use crate::{
+                white_point::{D65Degree10, D65},
+                Lch, Xyz,
+            };
+
+            // Reference values from the CIE 15:2004 formulas, with the ASTM
+            // E308 10° D65 tristimulus values Xn = 94.811, Yn = 100.0,
+            // Zn = 107.304.
+            let xyz = Xyz::<D65Degree10, f64>::new(0.4, 0.35, 0.3);
+            let lab = Lab::from_color(xyz);
+            assert_relative_eq!(
+                lab,
+                Lab::new(65.748665, 22.640093, 10.168852),
+                epsilon = 0.000001
+            );
+
+            let white = Lab::from_color(Xyz::<D65Degree10, f64>::new(0.94811, 1.0, 1.07304));
+            assert_relative_eq!(white, Lab::new(100.0, 0.0, 0.0), epsilon = 0.000001);
+
+            // The same tristimulus values give different results with the 2°
+            // white point.
+            let lab_2 = Lab::from_color(Xyz::<D65, f64>::new(0.4, 0.35, 0.3));
+            assert_relative_eq!(
+                lab_2,
+                Lab::new(65.748665, 22.329459, 10.804101),
+                epsilon = 0.000001
+            );
+
+            let lch = Lch::from_color(lab);
+            assert_relative_eq!(Xyz::from_color(lch), xyz, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn try_get_hue() {
+        use crate::{FromColor, Srgb, TryGetHue};
+
+        assert!(Lab::<D65, f32>::new(50.0, 0.01, 0.0)
+            .try_get_hue()
+            .is_some());
+        assert_eq!(Lab::<D65, f32>::new(50.0, 0.0, 0.0).try_get_hue(), None);
+        assert_eq!(Lab::<D65, f32>::new(50.0, 0.0005, 0.0).try_get_hue(), None);
+
+        // Converted grays may not be exactly gray.
+        let near_gray = Lab::<D65, f32>::from_color(Srgb::new(0.3, 0.3, 0.3));
+        assert_eq!(near_gray.try_get_hue(), None);
+    }
+
+    #[test]
+    fn is_achromatic() {
+        use crate::IsAchromatic;
+
+        assert!(Lab::<D65, f32>::new(50.0, 0.0, 0.0).is_achromatic_within(0.0));
+        assert!(Lab::<D65, f32>::new(50.0, 0.3, -0.4).is_achromatic_within(0.6));
+        assert!(!Lab::<D65, f32>::new(50.0, 0.3, -0.4).is_achromatic_within(0.4));
+        assert!(!Lab::<D65, f32>::new(50.0, 30.0, 10.0).is_achromatic_within(0.5));
     }
 
     #[test]
@@ -451,6 +559,28 @@ mod test {
         assert_eq!(Lab::<D65, f32>::max_b(), 127.0);
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn modify_struct_of_arrays() {
+        use crate::{LightenAssign, SaturateAssign};
+
+        let mut vec_of_colors = vec![
+            Lab::<D65, f32>::new(20.0, 30.0, -40.0),
+            Lab::new(80.0, -100.0, 100.0),
+            Lab::new(50.0, 0.0, 0.0),
+        ];
+        let mut color_of_vecs: Lab<D65, Vec<_>> = vec_of_colors.clone().into_iter().collect();
+
+        color_of_vecs.lighten_assign(0.3);
+        color_of_vecs.saturate_assign(0.4);
+        color_of_vecs.saturate_fixed_assign(-0.1);
+        vec_of_colors.lighten_assign(0.3);
+        vec_of_colors.saturate_assign(0.4);
+        vec_of_colors.saturate_fixed_assign(-0.1);
+
+        assert_eq!(color_of_vecs.into_iter().collect::<Vec<_>>(), vec_of_colors);
+    }
+
     struct_of_arrays_tests!(
         Lab<D65>[l, a, b] phantom: white_point,
         super::Laba::new(0.1f32, 0.2, 0.3, 0.4),
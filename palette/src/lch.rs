@@ -7,13 +7,13 @@ use core::{
 
 use crate::{
     angle::RealAngle,
-    bool_mask::{HasBoolMask, LazySelect},
+    bool_mask::{BitOps, HasBoolMask, LazySelect},
     color_difference::{get_ciede2000_difference, Ciede2000, DeltaE, ImprovedDeltaE, LabColorDiff},
     convert::{FromColorUnclamped, IntoColorUnclamped},
     hues::LabHueIter,
     num::{Abs, Arithmetics, Exp, Hypot, One, PartialCmp, Powi, Real, Sqrt, Trigonometry, Zero},
     white_point::D65,
-    Alpha, FromColor, GetHue, Lab, LabHue, Xyz,
+    Alpha, FromColor, GetHue, IsAchromatic, Lab, LabHue, TryGetHue, Xyz,
 };
 
 /// CIE L\*C\*h° with an alpha component. See the [`Lcha` implementation in
@@ -26,6 +26,10 @@ pub type Lcha<Wp = D65, T = f32> = Alpha<Lch<Wp, T>, T>;
 /// it's a cylindrical color space, like [HSL](crate::Hsl) and
 /// [HSV](crate::Hsv). This gives it the same ability to directly change
 /// the hue and colorfulness of a color, while preserving other visual aspects.
+///
+/// Some other libraries call this color space HCL, and order the components as
+/// `(h°, C*, L*)`. See [`Lch::from_hcl`] and [`Lch::into_hcl`] for that order.
+#[doc(alias = "hcl")]
 #[derive(Debug, ArrayCast, FromColorUnclamped, WithAlpha)]
 #[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 #[palette(
@@ -60,6 +64,9 @@ pub struct Lch<Wp = D65, T = f32> {
 
 impl<Wp, T> Lch<Wp, T> {
     /// Create a CIE L\*C\*h° color.
+    ///
+    /// The components are in `(L*, C*, h°)` order. Use [`Lch::from_hcl`] for
+    /// the reversed `(h°, C*, L*)` order that is used by some other libraries.
     pub fn new<H: Into<LabHue<T>>>(l: T, chroma: T, hue: H) -> Self {
         Self::new_const(l, chroma, hue.into())
     }
@@ -75,6 +82,14 @@ impl<Wp, T> Lch<Wp, T> {
         }
     }
 
+    /// Create a CIE L\*C\*h° color, with the hue in radians instead of degrees.
+    pub fn new_radians(l: T, chroma: T, hue: T) -> Self
+    where
+        T: RealAngle,
+    {
+        Self::new_const(l, chroma, LabHue::from_radians(hue))
+    }
+
     /// Convert to a `(L\*, C\*, h°)` tuple.
     pub fn into_components(self) -> (T, T, LabHue<T>) {
         (self.l, self.chroma, self.hue)
@@ -84,6 +99,37 @@ impl<Wp, T> Lch<Wp, T> {
     pub fn from_components<H: Into<LabHue<T>>>((l, chroma, hue): (T, T, H)) -> Self {
         Self::new(l, chroma, hue)
     }
+
+    /// Create a CIE L\*C\*h° color from components in `(h°, C*, L*)` order.
+    ///
+    /// This is the order used by the HCL colors in JavaScript libraries, such
+    /// as d3 and chroma.js, and makes it harder to accidentally swap the hue
+    /// and lightness when porting code from them.
+    ///
+    /// ```
+    /// use palette::{FromColor, Lch, Srgb};
+    ///
+    /// // d3.hcl("steelblue") is { h: 262.78, c: 32.45, l: 52.47 }.
+    /// let steelblue = Lch::from_hcl(262.78, 32.45, 52.47);
+    /// assert_eq!(steelblue, Lch::new(52.47, 32.45, 262.78));
+    ///
+    /// let srgb: Srgb<u8> = Srgb::from_color(steelblue).into_format();
+    /// assert_eq!(srgb, Srgb::new(0x46, 0x82, 0xb4));
+    /// ```
+    #[doc(alias = "hcl")]
+    pub fn from_hcl<H: Into<LabHue<T>>>(hue: H, chroma: T, l: T) -> Self {
+        Self::new(l, chroma, hue)
+    }
+
+    /// Convert to a `(h°, C*, L*)` tuple, the reverse of
+    /// [`into_components`](Lch::into_components).
+    ///
+    /// This is the order used by the HCL colors in JavaScript libraries, such
+    /// as d3 and chroma.js.
+    #[doc(alias = "hcl")]
+    pub fn into_hcl(self) -> (LabHue<T>, T, T) {
+        (self.hue, self.chroma, self.l)
+    }
 }
 
 impl<Wp, T> Lch<Wp, T>
@@ -120,6 +166,39 @@ where
     }
 }
 
+impl<Wp, T> Lch<Wp, T>
+where
+    T: Real + PartialCmp + Clone,
+    T::Mask: BitOps + LazySelect<T>,
+{
+    /// Replace the hue with `reference_hue` if the color is achromatic.
+    ///
+    /// Converting an achromatic `Lch` color to [`Lab`] and back loses its
+    /// hue, since `Lab` has no hue to store for gray colors. This
+    /// restores a known hue, such as the hue from before the conversion, to
+    /// keep animations through gray from jumping. The hue is replaced where
+    /// [`TryGetHue::get_hue_masked`] considers it to not be meaningful.
+    ///
+    /// ```
+    /// use palette::{FromColor, Lab, Lch};
+    ///
+    /// let gray: Lch = Lch::new(50.0, 0.0, 120.0);
+    /// let roundtrip = Lch::from_color(Lab::from_color(gray));
+    /// assert_ne!(roundtrip.hue, gray.hue);
+    /// assert_eq!(roundtrip.with_reference_hue(gray.hue).hue, 120.0);
+    /// ```
+    #[must_use]
+    pub fn with_reference_hue<H: Into<LabHue<T>>>(mut self, reference_hue: H) -> Self {
+        let (hue, has_hue) = self.get_hue_masked();
+        let hue = lazy_select! {
+            if has_hue => hue.into_inner(),
+            else => reference_hue.into().into_inner(),
+        };
+        self.hue = LabHue::new(hue);
+        self
+    }
+}
+
 ///<span id="Lcha"></span>[`Lcha`](crate::Lcha) implementations.
 impl<Wp, T, A> Alpha<Lch<Wp, T>, A> {
     /// Create a CIE L\*C\*h° color with transparency.
@@ -150,6 +229,7 @@ impl<Wp, T, A> Alpha<Lch<Wp, T>, A> {
 
 impl_reference_component_methods_hue!(Lch<Wp>, [l, chroma], white_point);
 impl_struct_of_arrays_methods_hue!(Lch<Wp>, [l, chroma], white_point);
+impl_sort_keys!(Lch<Wp> {lightness: l, chroma: chroma, hue});
 
 impl<Wp, T> FromColorUnclamped<Lch<Wp, T>> for Lch<Wp, T> {
     fn from_color_unclamped(color: Lch<Wp, T>) -> Self {
@@ -179,6 +259,7 @@ impl_is_within_bounds! {
         l => [Self::min_l(), Self::max_l()],
         chroma => [Self::min_chroma(), None]
     }
+    new(l: T, chroma: T, hue: impl Into<LabHue<T>>)
     where T: Real + Zero
 }
 impl_clamp! {
@@ -195,6 +276,34 @@ impl_lighten!(Lch<Wp> increase {l => [Self::min_l(), Self::max_l()]} other {hue,
 impl_saturate!(Lch<Wp> increase {chroma => [Self::min_chroma(), Self::max_chroma()]} other {hue, l} phantom: white_point);
 impl_hue_ops!(Lch<Wp>, LabHue);
 
+impl<Wp, T> TryGetHue for Lch<Wp, T>
+where
+    T: Real + PartialCmp + Clone,
+    T::Mask: BitOps,
+{
+    /// The hue is considered meaningful if the chroma is greater than
+    /// `0.001`.
+    fn get_hue_masked(&self) -> (LabHue<T>, T::Mask) {
+        (
+            self.hue.clone(),
+            !self.is_achromatic_within(T::from_f64(0.001)),
+        )
+    }
+}
+
+impl<Wp, T> IsAchromatic for Lch<Wp, T>
+where
+    T: PartialCmp,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the chroma is at most
+    /// `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        self.chroma.lt_eq(&tolerance)
+    }
+}
+
 impl<Wp, T> DeltaE for Lch<Wp, T>
 where
     Lab<Wp, T>: FromColorUnclamped<Self> + DeltaE<Scalar = T>,
@@ -297,6 +406,8 @@ impl_simd_array_conversion_hue!(Lch<Wp>, [l, chroma], white_point);
 impl_struct_of_array_traits_hue!(Lch<Wp>, LabHueIter, [l, chroma], white_point);
 
 impl_eq_hue!(Lch<Wp>, LabHue, [l, chroma, hue]);
+impl_select!(Lch<Wp>, [l, chroma, hue], white_point);
+impl_relative_luminance!(Lch<Wp> via crate::Xyz<Wp, T>);
 impl_copy_clone!(Lch<Wp>, [l, chroma, hue], white_point);
 
 #[allow(deprecated)]
@@ -317,6 +428,8 @@ where
     }
 }
 
+impl_wcag21_relative_contrast!(Lch<Wp>);
+
 impl_rand_traits_cylinder!(
     UniformLch,
     Lch<Wp> {
@@ -346,6 +459,59 @@ mod test {
     };
 
     test_convert_into_from_xyz!(Lch);
+    test_lighten_lightness!(Lch<D65> {l} new(50.0, 20.0, 30.0));
+
+    #[test]
+    fn try_get_hue() {
+        use crate::{LabHue, TryGetHue};
+
+        assert_eq!(
+            Lch::<D65, f32>::new(50.0, 20.0, 10.0).try_get_hue(),
+            Some(LabHue::new(10.0))
+        );
+        assert_eq!(Lch::<D65, f32>::new(50.0, 0.0, 10.0).try_get_hue(), None);
+        assert_eq!(Lch::<D65, f32>::new(50.0, 0.0005, 10.0).try_get_hue(), None);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn reference_hue_through_gray() {
+        use crate::{FromColor, Lab, Mix};
+
+        let start = Lch::<D65, f64>::new(50.0, 40.0, 120.0);
+        let gray = Lch::new(50.0, 0.0, 120.0);
+
+        for step in 0..=10 {
+            let color = start.mix(gray, f64::from(step) / 10.0);
+            let roundtrip = Lch::from_color(Lab::from_color(color)).with_reference_hue(color.hue);
+            assert_relative_eq!(roundtrip, color, epsilon = 1e-10);
+        }
+
+        // Chromatic colors keep their own hue.
+        let color = Lch::<D65, f64>::new(50.0, 20.0, 30.0).with_reference_hue(120.0);
+        assert_eq!(color.hue, 30.0);
+    }
+
+    #[test]
+    fn is_achromatic() {
+        use crate::IsAchromatic;
+
+        assert!(Lch::<D65, f32>::new(50.0, 0.0, 10.0).is_achromatic_within(0.0));
+        assert!(Lch::<D65, f32>::new(50.0, 0.4, 10.0).is_achromatic_within(0.5));
+        assert!(!Lch::<D65, f32>::new(50.0, 0.4, 10.0).is_achromatic_within(0.3));
+        assert!(!Lch::<D65, f32>::new(50.0, 20.0, 10.0).is_achromatic_within(0.5));
+    }
+
+    #[test]
+    fn hcl_order() {
+        let color = Lch::<D65, f32>::from_hcl(262.78, 32.45, 52.47);
+        assert_eq!(color, Lch::new(52.47, 32.45, 262.78));
+
+        let (hue, chroma, l) = color.into_hcl();
+        assert_eq!(hue, color.hue);
+        assert_eq!(chroma, 32.45);
+        assert_eq!(l, 52.47);
+    }
 
     #[test]
     fn ranges() {
@@ -4,13 +4,13 @@ use core::{marker::PhantomData, ops::Mul};
 
 use crate::{
     angle::RealAngle,
-    bool_mask::{HasBoolMask, LazySelect},
+    bool_mask::{BitOps, HasBoolMask, LazySelect},
     convert::FromColorUnclamped,
     hues::LuvHueIter,
     luv_bounds::LuvBounds,
     num::{Arithmetics, Hypot, PartialCmp, Powi, Real, Zero},
     white_point::D65,
-    Alpha, FromColor, GetHue, Hsluv, Luv, LuvHue, Xyz,
+    Alpha, FromColor, GetHue, Hsluv, IsAchromatic, Luv, LuvHue, TryGetHue, Xyz,
 };
 
 /// CIE L\*C\*uv h°uv with an alpha component. See the [`Lchuva` implementation in
@@ -74,6 +74,15 @@ impl<Wp, T> Lchuv<Wp, T> {
         }
     }
 
+    /// Create a CIE L\*C\*uv h°uv color, with the hue in radians instead of
+    /// degrees.
+    pub fn new_radians(l: T, chroma: T, hue: T) -> Self
+    where
+        T: RealAngle,
+    {
+        Self::new_const(l, chroma, LuvHue::from_radians(hue))
+    }
+
     /// Convert to a `(L\*, C\*uv, h°uv)` tuple.
     pub fn into_components(self) -> (T, T, LuvHue<T>) {
         (self.l, self.chroma, self.hue)
@@ -110,6 +119,39 @@ where
     }
 }
 
+impl<Wp, T> Lchuv<Wp, T>
+where
+    T: Real + PartialCmp + Clone,
+    T::Mask: BitOps + LazySelect<T>,
+{
+    /// Replace the hue with `reference_hue` if the color is achromatic.
+    ///
+    /// Converting an achromatic `Lchuv` color to [`Luv`] and back loses its
+    /// hue, since `Luv` has no hue to store for gray colors. This
+    /// restores a known hue, such as the hue from before the conversion, to
+    /// keep animations through gray from jumping. The hue is replaced where
+    /// [`TryGetHue::get_hue_masked`] considers it to not be meaningful.
+    ///
+    /// ```
+    /// use palette::{FromColor, Luv, Lchuv};
+    ///
+    /// let gray: Lchuv = Lchuv::new(50.0, 0.0, 120.0);
+    /// let roundtrip = Lchuv::from_color(Luv::from_color(gray));
+    /// assert_ne!(roundtrip.hue, gray.hue);
+    /// assert_eq!(roundtrip.with_reference_hue(gray.hue).hue, 120.0);
+    /// ```
+    #[must_use]
+    pub fn with_reference_hue<H: Into<LuvHue<T>>>(mut self, reference_hue: H) -> Self {
+        let (hue, has_hue) = self.get_hue_masked();
+        let hue = lazy_select! {
+            if has_hue => hue.into_inner(),
+            else => reference_hue.into().into_inner(),
+        };
+        self.hue = LuvHue::new(hue);
+        self
+    }
+}
+
 ///<span id="Lchuva"></span>[`Lchuva`](crate::Lchuva) implementations.
 impl<Wp, T, A> Alpha<Lchuv<Wp, T>, A> {
     /// Create a CIE L\*C\*uv h°uv color with transparency.
@@ -140,6 +182,7 @@ impl<Wp, T, A> Alpha<Lchuv<Wp, T>, A> {
 
 impl_reference_component_methods_hue!(Lchuv<Wp>, [l, chroma], white_point);
 impl_struct_of_arrays_methods_hue!(Lchuv<Wp>, [l, chroma], white_point);
+impl_sort_keys!(Lchuv<Wp> {lightness: l, chroma: chroma, hue});
 
 impl<Wp, T> FromColorUnclamped<Lchuv<Wp, T>> for Lchuv<Wp, T> {
     fn from_color_unclamped(color: Lchuv<Wp, T>) -> Self {
@@ -187,6 +230,7 @@ impl_is_within_bounds! {
         l => [Self::min_l(), Self::max_l()],
         chroma => [Self::min_chroma(), Self::max_chroma()]
     }
+    new(l: T, chroma: T, hue: impl Into<LuvHue<T>>)
     where T: Real + Zero
 }
 impl_clamp! {
@@ -203,6 +247,34 @@ impl_lighten!(Lchuv<Wp> increase {l => [Self::min_l(), Self::max_l()]} other {hu
 impl_saturate!(Lchuv<Wp> increase {chroma => [Self::min_chroma(), Self::max_chroma()]} other {hue, l} phantom: white_point);
 impl_hue_ops!(Lchuv<Wp>, LuvHue);
 
+impl<Wp, T> TryGetHue for Lchuv<Wp, T>
+where
+    T: Real + PartialCmp + Clone,
+    T::Mask: BitOps,
+{
+    /// The hue is considered meaningful if the chroma is greater than
+    /// `0.001`.
+    fn get_hue_masked(&self) -> (LuvHue<T>, T::Mask) {
+        (
+            self.hue.clone(),
+            !self.is_achromatic_within(T::from_f64(0.001)),
+        )
+    }
+}
+
+impl<Wp, T> IsAchromatic for Lchuv<Wp, T>
+where
+    T: PartialCmp,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the chroma is at most
+    /// `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        self.chroma.lt_eq(&tolerance)
+    }
+}
+
 impl<Wp, T> HasBoolMask for Lchuv<Wp, T>
 where
     T: HasBoolMask,
@@ -228,6 +300,8 @@ impl_simd_array_conversion_hue!(Lchuv<Wp>, [l, chroma], white_point);
 impl_struct_of_array_traits_hue!(Lchuv<Wp>, LuvHueIter, [l, chroma], white_point);
 
 impl_eq_hue!(Lchuv<Wp>, LuvHue, [l, chroma, hue]);
+impl_select!(Lchuv<Wp>, [l, chroma, hue], white_point);
+impl_relative_luminance!(Lchuv<Wp> via crate::Xyz<Wp, T>);
 impl_copy_clone!(Lchuv<Wp>, [l, chroma, hue], white_point);
 
 #[allow(deprecated)]
@@ -271,6 +345,7 @@ mod test {
     use crate::Lchuv;
 
     test_convert_into_from_xyz!(Lchuv);
+    test_lighten_lightness!(Lchuv<D65> {l} new(50.0, 20.0, 30.0));
 
     #[test]
     fn ranges() {
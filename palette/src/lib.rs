@@ -11,6 +11,11 @@
 //! RGB format in images and tools. Their documentation has more details and
 //! examples.
 //!
+//! Most of the functionality is provided through traits, which have to be in
+//! scope to be used. The [`prelude`] module re-exports the most commonly used
+//! traits and color types, so they can be imported with a single `use
+//! palette::prelude::*;`.
+//!
 //! The documentation for each module and type goes deeper into their concepts.
 //! Here are a few you may want to read:
 //!
@@ -99,7 +104,7 @@
 //! converts it back to RGB:
 //!
 //! ```
-//! use palette::{FromColor, ShiftHue, IntoColor, Lch, Srgb};
+//! use palette::prelude::*;
 //!
 //! let lch_color: Lch = Srgb::new(0.8, 0.2, 0.1).into_color();
 //! let new_color = Srgb::from_color(lch_color.shift_hue(180.0));
@@ -143,7 +148,7 @@
 //!
 //! ```rust
 //! use image::RgbImage;
-//! use palette::{Srgb, Oklab, cast::FromComponents, Lighten, IntoColor, FromColor};
+//! use palette::prelude::*;
 //!
 //! fn lighten(image: &mut RgbImage, amount: f32) {
 //!     // RgbImage can be dereferenced as [u8], allowing us to cast it as a
@@ -250,6 +255,7 @@
 
 // Keep the standard library when running tests, too
 #![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 #![doc(html_root_url = "https://docs.rs/palette/0.7.6/")]
 #![warn(missing_docs)]
 
@@ -333,9 +339,20 @@ pub use matrix::Mat3;
 #[allow(deprecated)]
 pub use relative_contrast::{contrast_ratio, RelativeContrast};
 
+pub use palette_derive::{Lighten, Mix, Saturate};
+
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "any_color")]
+pub mod any_color;
+
+#[cfg(feature = "colormap")]
+pub mod colormap;
+
+#[cfg(feature = "image")]
+pub mod image;
+
 #[cfg(feature = "named")]
 pub mod named;
 
@@ -345,6 +362,7 @@ mod random_sampling;
 #[cfg(feature = "serializing")]
 pub mod serde;
 
+pub mod adjust;
 pub mod alpha;
 pub mod angle;
 pub mod blend;
@@ -355,12 +373,14 @@ pub mod chromatic_adaptation;
 pub mod color_difference;
 pub mod color_theory;
 pub mod convert;
+pub mod cvd;
 pub mod encoding;
 pub mod hsl;
 pub mod hsluv;
 pub mod hsv;
 pub mod hues;
 pub mod hwb;
+pub mod indexed;
 pub mod lab;
 pub mod lch;
 pub mod lchuv;
@@ -368,6 +388,7 @@ pub mod lms;
 pub mod luma;
 pub mod luv;
 mod luv_bounds;
+pub mod matrix;
 pub mod num;
 mod ok_utils;
 pub mod okhsl;
@@ -375,9 +396,17 @@ pub mod okhsv;
 pub mod okhwb;
 pub mod oklab;
 pub mod oklch;
+pub mod prelude;
+pub mod proofing;
+#[cfg(feature = "alloc")]
+pub mod quantize;
 mod relative_contrast;
 pub mod rgb;
+pub mod spectral;
+pub mod stats;
 pub mod stimulus;
+pub mod temperature;
+pub mod tonemap;
 pub mod white_point;
 pub mod xyz;
 pub mod yxy;
@@ -386,9 +415,6 @@ pub mod yxy;
 #[cfg(feature = "approx")]
 mod visual;
 
-#[doc(hidden)]
-pub mod matrix;
-
 #[inline]
 fn clamp<T: num::Clamp>(value: T, min: T, max: T) -> T {
     value.clamp(min, max)
@@ -409,6 +435,40 @@ fn clamp_min_assign<T: num::ClampAssign>(value: &mut T, min: T) {
     value.clamp_min_assign(min);
 }
 
+/// Combines the size hints of two iterators that are zipped together, and
+/// stop at the shorter one.
+#[inline]
+fn zip_size_hints(
+    (a_lower, a_upper): (usize, Option<usize>),
+    (b_lower, b_upper): (usize, Option<usize>),
+) -> (usize, Option<usize>) {
+    let upper = match (a_upper, b_upper) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (upper, None) | (None, upper) => upper,
+    };
+
+    (a_lower.min(b_lower), upper)
+}
+
+/// Returns the number of remaining items, if `size_hint` is exact.
+#[inline]
+fn exact_len(size_hint: (usize, Option<usize>)) -> Option<usize> {
+    match size_hint {
+        (lower, Some(upper)) if lower == upper => Some(lower),
+        _ => None,
+    }
+}
+
+/// Removes items from the back of `iter` until it has `len` items left. Does
+/// nothing if `iter` doesn't know its exact length.
+#[inline]
+fn trim_back<I: DoubleEndedIterator>(iter: &mut I, len: usize) {
+    let excess = exact_len(iter.size_hint()).map_or(0, |iter_len| iter_len.saturating_sub(len));
+    if excess > 0 {
+        iter.nth_back(excess - 1);
+    }
+}
+
 /// Checks if color components are within their expected range bounds.
 ///
 /// A color with out-of-bounds components may be clamped with [`Clamp`] or
@@ -569,6 +629,13 @@ where
 /// assert_relative_eq!(a.mix(b, 0.5), LinSrgb::new(0.5, 0.5, 0.5));
 /// assert_relative_eq!(a.mix(b, 1.0), b);
 /// ```
+///
+/// ## Deriving
+///
+/// `Mix` and [`MixAssign`] can be derived for custom color types, where each
+/// field is mixed linearly. Fields of the type `PhantomData` are kept as they
+/// are. The component type is `f32` by default, and can be changed with
+/// `#[palette(component = "T")]`. See [`Lighten`] for an example.
 pub trait Mix {
     /// The type of the mixing factor.
     type Scalar;
@@ -625,6 +692,42 @@ pub trait MixAssign {
 /// added to its lightness value resulting in a new value of 100%.
 ///
 /// See also [`LightenAssign`], [`Darken`] and [`DarkenAssign`].
+///
+/// ## Deriving
+///
+/// `Lighten` and [`LightenAssign`] can be derived for custom color types. The
+/// fields that should be changed are marked with
+/// `#[palette(lightness(min = "...", max = "..."))]`, where `min` and `max`
+/// are expressions for the field's range. Fields that become lower when the
+/// color gets lighter, such as blackness, can also be marked as `inverted`.
+/// The component type is `f32` by default, and can be changed with
+/// `#[palette(component = "T")]`.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{Darken, Lighten, Mix};
+///
+/// /// A device dependent CMYK color.
+/// #[derive(Clone, Copy, Debug, PartialEq, Mix, Lighten)]
+/// struct Cmyk {
+///     cyan: f32,
+///     magenta: f32,
+///     yellow: f32,
+///     #[palette(lightness(min = "0.0", max = "1.0", inverted))]
+///     key: f32,
+/// }
+///
+/// let color = Cmyk { cyan: 0.2, magenta: 0.4, yellow: 0.6, key: 0.5 };
+///
+/// assert_relative_eq!(color.lighten(0.5).key, 0.25);
+/// assert_relative_eq!(color.darken(0.5).key, 0.75);
+/// assert_relative_eq!(color.lighten_fixed(0.25).key, 0.25);
+///
+/// let white = Cmyk { cyan: 0.0, magenta: 0.0, yellow: 0.0, key: 0.0 };
+/// let mixed = color.mix(white, 0.5);
+/// assert_relative_eq!(mixed.cyan, 0.1);
+/// assert_relative_eq!(mixed.key, 0.25);
+/// ```
 pub trait Lighten {
     /// The type of the lighten modifier.
     type Scalar;
@@ -886,6 +989,214 @@ where
     }
 }
 
+/// Operators for whitening a color.
+///
+/// This is for color spaces with a whiteness component, such as [`Hwb`] and
+/// [`Okhwb`], where it only changes the whiteness. The blackness is left as
+/// it is, so the result may have a whiteness and blackness that add up to
+/// more than 1. Such a color is a shade of gray, where the ratio between them
+/// decides how light it is, as described in [`Hwb::normalize`]. Use
+/// [`Lighten`] to also decrease the blackness.
+///
+/// The relative function, [`whiten`](Whiten::whiten), scales the whiteness
+/// towards the maximum whiteness value, and the fixed function,
+/// [`whiten_fixed`](Whiten::whiten_fixed), increases the whiteness by an amount
+/// that is independent of the current whiteness. Negative values decrease the
+/// whiteness in the same way.
+///
+/// See also [`WhitenAssign`], [`Blacken`] and [`BlackenAssign`].
+pub trait Whiten {
+    /// The type of the whiten modifier.
+    type Scalar;
+
+    /// Scale the whiteness towards the maximum whiteness by `factor`, a value
+    /// ranging from `0.0` to `1.0`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Hwb, Whiten};
+    ///
+    /// let color = Hwb::new_srgb(0.0, 0.2, 0.4);
+    /// assert_relative_eq!(color.whiten(0.5).whiteness, 0.6);
+    /// assert_relative_eq!(color.whiten(0.5).blackness, 0.4);
+    /// ```
+    #[must_use]
+    fn whiten(self, factor: Self::Scalar) -> Self;
+
+    /// Increase the whiteness by `amount`, a value ranging from `0.0` to
+    /// `1.0`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Hwb, Whiten};
+    ///
+    /// let color = Hwb::new_srgb(0.0, 0.2, 0.4);
+    /// assert_relative_eq!(color.whiten_fixed(0.2).whiteness, 0.4);
+    /// ```
+    #[must_use]
+    fn whiten_fixed(self, amount: Self::Scalar) -> Self;
+}
+
+/// Assigning operators for whitening a color.
+///
+/// See [`Whiten`] for more details.
+pub trait WhitenAssign {
+    /// The type of the whiten modifier.
+    type Scalar;
+
+    /// Scale the whiteness towards the maximum whiteness by `factor`, a value
+    /// ranging from `0.0` to `1.0`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Hwb, WhitenAssign};
+    ///
+    /// let mut color = Hwb::new_srgb(0.0, 0.2, 0.4);
+    /// color.whiten_assign(0.5);
+    /// assert_relative_eq!(color.whiteness, 0.6);
+    /// ```
+    fn whiten_assign(&mut self, factor: Self::Scalar);
+
+    /// Increase the whiteness by `amount`, a value ranging from `0.0` to
+    /// `1.0`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Hwb, WhitenAssign};
+    ///
+    /// let mut color = Hwb::new_srgb(0.0, 0.2, 0.4);
+    /// color.whiten_fixed_assign(0.2);
+    /// assert_relative_eq!(color.whiteness, 0.4);
+    /// ```
+    fn whiten_fixed_assign(&mut self, amount: Self::Scalar);
+}
+
+impl<T> WhitenAssign for [T]
+where
+    T: WhitenAssign,
+    T::Scalar: Clone,
+{
+    type Scalar = T::Scalar;
+
+    #[inline]
+    fn whiten_assign(&mut self, factor: Self::Scalar) {
+        for color in self {
+            color.whiten_assign(factor.clone());
+        }
+    }
+
+    #[inline]
+    fn whiten_fixed_assign(&mut self, amount: Self::Scalar) {
+        for color in self {
+            color.whiten_fixed_assign(amount.clone());
+        }
+    }
+}
+
+/// Operators for blackening a color.
+///
+/// This is for color spaces with a blackness component, such as [`Hwb`] and
+/// [`Okhwb`], where it only changes the blackness. The whiteness is left as
+/// it is, so the result may have a whiteness and blackness that add up to
+/// more than 1. Such a color is a shade of gray, where the ratio between them
+/// decides how light it is, as described in [`Hwb::normalize`]. Use
+/// [`Darken`] to also decrease the whiteness.
+///
+/// The relative function, [`blacken`](Blacken::blacken), scales the blackness
+/// towards the maximum blackness value, and the fixed function,
+/// [`blacken_fixed`](Blacken::blacken_fixed), increases the blackness by an
+/// amount that is independent of the current blackness. Negative values
+/// decrease the blackness in the same way.
+///
+/// See also [`BlackenAssign`], [`Whiten`] and [`WhitenAssign`].
+pub trait Blacken {
+    /// The type of the blacken modifier.
+    type Scalar;
+
+    /// Scale the blackness towards the maximum blackness by `factor`, a value
+    /// ranging from `0.0` to `1.0`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Blacken, Hwb};
+    ///
+    /// let color = Hwb::new_srgb(0.0, 0.2, 0.4);
+    /// assert_relative_eq!(color.blacken(0.5).blackness, 0.7);
+    /// assert_relative_eq!(color.blacken(0.5).whiteness, 0.2);
+    /// ```
+    #[must_use]
+    fn blacken(self, factor: Self::Scalar) -> Self;
+
+    /// Increase the blackness by `amount`, a value ranging from `0.0` to
+    /// `1.0`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Blacken, Hwb};
+    ///
+    /// let color = Hwb::new_srgb(0.0, 0.2, 0.4);
+    /// assert_relative_eq!(color.blacken_fixed(0.2).blackness, 0.6);
+    /// ```
+    #[must_use]
+    fn blacken_fixed(self, amount: Self::Scalar) -> Self;
+}
+
+/// Assigning operators for blackening a color.
+///
+/// See [`Blacken`] for more details.
+pub trait BlackenAssign {
+    /// The type of the blacken modifier.
+    type Scalar;
+
+    /// Scale the blackness towards the maximum blackness by `factor`, a value
+    /// ranging from `0.0` to `1.0`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{BlackenAssign, Hwb};
+    ///
+    /// let mut color = Hwb::new_srgb(0.0, 0.2, 0.4);
+    /// color.blacken_assign(0.5);
+    /// assert_relative_eq!(color.blackness, 0.7);
+    /// ```
+    fn blacken_assign(&mut self, factor: Self::Scalar);
+
+    /// Increase the blackness by `amount`, a value ranging from `0.0` to
+    /// `1.0`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{BlackenAssign, Hwb};
+    ///
+    /// let mut color = Hwb::new_srgb(0.0, 0.2, 0.4);
+    /// color.blacken_fixed_assign(0.2);
+    /// assert_relative_eq!(color.blackness, 0.6);
+    /// ```
+    fn blacken_fixed_assign(&mut self, amount: Self::Scalar);
+}
+
+impl<T> BlackenAssign for [T]
+where
+    T: BlackenAssign,
+    T::Scalar: Clone,
+{
+    type Scalar = T::Scalar;
+
+    #[inline]
+    fn blacken_assign(&mut self, factor: Self::Scalar) {
+        for color in self {
+            color.blacken_assign(factor.clone());
+        }
+    }
+
+    #[inline]
+    fn blacken_fixed_assign(&mut self, amount: Self::Scalar) {
+        for color in self {
+            color.blacken_fixed_assign(amount.clone());
+        }
+    }
+}
+
 /// A trait for colors where a hue may be calculated.
 ///
 /// See also [`WithHue`], [`SetHue`], [`ShiftHue`] and [`ShiftHueAssign`].
@@ -921,6 +1232,237 @@ pub trait GetHue {
     fn get_hue(&self) -> Self::Hue;
 }
 
+/// A trait for colors where a hue may be calculated, and where it's possible
+/// to tell if the hue is meaningful.
+///
+/// Colors in, or very close to, the gray scale don't have a well defined hue.
+/// [`GetHue`] returns `0` or an arbitrary angle for them, which makes them
+/// indistinguishable from red-ish colors. `TryGetHue` also checks the chroma
+/// (or an equivalent property) of the color, and flags colors where it's too
+/// small to give a stable hue. The threshold is small enough to only catch
+/// rounding errors and colors that are visually gray.
+///
+/// See also [`GetHue`].
+///
+/// ```
+/// use palette::{OklabHue, Oklch, TryGetHue};
+///
+/// // Calculates the average hue, weighted by chroma, and skips any colors
+/// // without a hue.
+/// fn mean_hue(colors: &[Oklch]) -> Option<OklabHue> {
+///     let mut a = 0.0;
+///     let mut b = 0.0;
+///     let mut has_hue = false;
+///
+///     for color in colors {
+///         if let Some(hue) = color.try_get_hue() {
+///             let (hue_a, hue_b) = hue.into_cartesian();
+///             a += hue_a * color.chroma;
+///             b += hue_b * color.chroma;
+///             has_hue = true;
+///         }
+///     }
+///
+///     has_hue.then(|| OklabHue::from_cartesian(a, b))
+/// }
+///
+/// let colors = [
+///     Oklch::new(0.6, 0.2, 20.0),
+///     Oklch::new(0.7, 0.1, 50.0),
+///     Oklch::new(0.5, 0.0, 200.0),
+/// ];
+/// let grays = [Oklch::new(0.2, 0.0, 0.0), Oklch::new(0.8, 0.0, 120.0)];
+///
+/// let hue = mean_hue(&colors).unwrap().into_positive_degrees();
+/// assert!(hue > 20.0 && hue < 35.0);
+/// assert_eq!(mean_hue(&grays), None);
+/// ```
+///
+/// SIMD colors can't return an `Option` for each lane, so they get a mask
+/// instead:
+///
+/// ```
+/// # #[cfg(feature = "wide")] {
+/// use palette::{Srgb, TryGetHue};
+///
+/// let colors = Srgb::<wide::f32x4>::from([
+///     Srgb::new(1.0, 0.0, 0.0),
+///     Srgb::new(0.5, 0.5, 0.5),
+///     Srgb::new(0.0, 0.0, 1.0),
+///     Srgb::new(0.0, 0.0, 0.0),
+/// ]);
+///
+/// let (_hues, has_hue) = colors.get_hue_masked();
+/// assert_eq!(has_hue.move_mask(), 0b0101);
+/// # }
+/// ```
+pub trait TryGetHue: GetHue + HasBoolMask {
+    /// Calculate the hue, together with a mask that is `false` where the color
+    /// is too close to the gray scale to have a meaningful hue.
+    ///
+    /// The hue is the same as from [`GetHue::get_hue`], regardless of the
+    /// mask.
+    #[must_use]
+    fn get_hue_masked(&self) -> (Self::Hue, Self::Mask);
+
+    /// Calculate the hue, or return `None` if the color is too close to the
+    /// gray scale to have a meaningful hue.
+    ///
+    /// ```
+    /// use palette::{Srgb, TryGetHue};
+    ///
+    /// assert!(Srgb::new(0.8f32, 0.3, 0.3).try_get_hue().is_some());
+    /// assert_eq!(Srgb::new(0.5f32, 0.5, 0.5).try_get_hue(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn try_get_hue(&self) -> Option<Self::Hue>
+    where
+        Self: HasBoolMask<Mask = bool>,
+    {
+        let (hue, has_hue) = self.get_hue_masked();
+
+        if has_hue {
+            Some(hue)
+        } else {
+            None
+        }
+    }
+}
+
+/// A trait for checking if a color is achromatic, meaning that it's gray,
+/// black or white.
+///
+/// Each color type measures the "colorfulness" in its own way, such as the
+/// difference between the largest and smallest component in RGB, the chroma in
+/// CIE L\*a\*b\* and L\*C\*h°, or the chroma calculated from the saturation
+/// and lightness in HSL. The tolerance is the largest value of that measure
+/// that is still considered gray, so its scale depends on the color type. The
+/// documentation for each implementation describes what it compares.
+///
+/// ```
+/// use palette::{IsAchromatic, Oklch, Srgb};
+///
+/// assert!(Srgb::new(0.5f32, 0.5, 0.5).is_achromatic_within(0.0));
+/// assert!(Srgb::new(0.5f32, 0.505, 0.5).is_achromatic_within(0.01));
+/// assert!(!Srgb::new(0.8f32, 0.3, 0.3).is_achromatic_within(0.01));
+///
+/// assert!(Oklch::new(0.5f32, 0.005, 120.0).is_achromatic_within(0.01));
+/// assert!(!Oklch::new(0.5f32, 0.1, 120.0).is_achromatic_within(0.01));
+/// ```
+///
+/// SIMD colors return a mask with the result for each lane:
+///
+/// ```
+/// # #[cfg(feature = "wide")] {
+/// use palette::{IsAchromatic, Srgb};
+///
+/// let colors = Srgb::<wide::f32x4>::from([
+///     Srgb::new(1.0, 0.0, 0.0),
+///     Srgb::new(0.5, 0.5, 0.5),
+///     Srgb::new(0.0, 0.0, 1.0),
+///     Srgb::new(0.0, 0.0, 0.0),
+/// ]);
+///
+/// let is_gray = colors.is_achromatic_within(wide::f32x4::splat(0.01));
+/// assert_eq!(is_gray.move_mask(), 0b1010);
+/// # }
+/// ```
+pub trait IsAchromatic: HasBoolMask {
+    /// The type of the tolerance.
+    type Scalar;
+
+    /// Check if the color is achromatic, with a margin of `tolerance`.
+    #[must_use]
+    fn is_achromatic_within(&self, tolerance: Self::Scalar) -> Self::Mask;
+}
+
+/// Get the relative luminance of a color, which is the Y component of CIE XYZ.
+///
+/// The relative luminance is the linear light intensity of a color, relative
+/// to its white point. `0.0` is black and `1.0` is the luminance of the white
+/// point. It's the same value as `y` in [`Xyz`], `luma` in [`Yxy`] and a
+/// linear [`Luma`], but some colors, such as RGB and luma, can calculate it
+/// without converting all of their components.
+///
+/// Relative luminance is not the same as the lightness in, for example,
+/// [`Lab`] and [`Oklab`], which is scaled to be perceptually uniform, or the
+/// value of a gamma encoded [`SrgbLuma`]. Unlike
+/// [`Wcag21RelativeContrast::relative_luminance`][crate::color_difference::Wcag21RelativeContrast::relative_luminance],
+/// this trait uses the color's own white point and returns the value as is,
+/// instead of as a D65 [`LinLuma`].
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{RelativeLuminance, Oklab, Srgb, SrgbLuma};
+///
+/// assert_relative_eq!(Srgb::new(1.0f32, 1.0, 1.0).get_relative_luminance(), 1.0);
+/// assert_relative_eq!(
+///     Srgb::new(0.5f32, 0.5, 0.5).get_relative_luminance(),
+///     SrgbLuma::new(0.5f32).get_relative_luminance(),
+/// );
+///
+/// // Oklab's lightness is perceptual, so half the lightness is a lot less
+/// // than half the luminance.
+/// let luminance = Oklab::new(0.5f32, 0.0, 0.0).get_relative_luminance();
+/// assert_relative_eq!(luminance, 0.125, epsilon = 0.001);
+/// ```
+pub trait RelativeLuminance {
+    /// The type of the luminance value.
+    type Scalar;
+
+    /// Get the relative luminance of the color, as in the Y component of CIE
+    /// XYZ.
+    #[must_use]
+    fn get_relative_luminance(&self) -> Self::Scalar;
+}
+
+/// Apply a function to each component of a color, or to each pair of
+/// components of two colors.
+///
+/// This is implemented for color types where all components have the same
+/// meaning and scale, such as [`Rgb`](rgb::Rgb), [`Lab`] and [`Xyz`], and
+/// makes it possible to write generic operations that work the same on every
+/// component.
+/// Color types with a hue component are not included, since the hue wraps
+/// around and can't be treated like the other components.
+///
+/// ```
+/// use palette::{ComponentWise, LinSrgb, Xyz};
+///
+/// // A simple tone mapping operator that works with any of the color types.
+/// fn reinhard<C: ComponentWise<Scalar = f32>>(color: C) -> C {
+///     color.map_components(|c| c / (1.0 + c))
+/// }
+///
+/// assert_eq!(reinhard(LinSrgb::new(1.0, 3.0, 0.0)), LinSrgb::new(0.5, 0.75, 0.0));
+/// let xyz: Xyz = Xyz::new(1.0, 1.0, 1.0);
+/// assert_eq!(reinhard(xyz), Xyz::new(0.5, 0.5, 0.5));
+///
+/// let max = LinSrgb::new(0.2, 0.8, 0.5).component_wise(LinSrgb::new(0.5, 0.3, 0.5), f32::max);
+/// assert_eq!(max, LinSrgb::new(0.5, 0.8, 0.5));
+/// ```
+///
+/// The alpha component is included when using [`Alpha`], the same way as when
+/// adding or multiplying colors. Use the `color` field to only change the
+/// color.
+pub trait ComponentWise {
+    /// The type of the color's components.
+    type Scalar;
+
+    /// Combine the components of `self` and `other`, pair by pair, using `f`.
+    #[must_use]
+    fn component_wise<F>(self, other: Self, f: F) -> Self
+    where
+        F: FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar;
+
+    /// Replace each component with the result of `f`.
+    #[must_use]
+    fn map_components<F>(self, f: F) -> Self
+    where
+        F: FnMut(Self::Scalar) -> Self::Scalar;
+}
+
 /// Change the hue of a color to a specific value.
 ///
 /// See also [`SetHue`], [`GetHue`], [`ShiftHue`] and [`ShiftHueAssign`].
@@ -1076,6 +1618,12 @@ where
 /// assert_relative_eq!(a.saturate(0.5).saturation, 0.75);
 /// assert_relative_eq!(a.saturate_fixed(0.5).saturation, 1.0);
 /// ```
+///
+/// ## Deriving
+///
+/// `Saturate` and [`SaturateAssign`] can be derived in the same way as
+/// [`Lighten`], but with the fields marked as
+/// `#[palette(saturation(min = "...", max = "..."))]`.
 pub trait Saturate {
     /// The type of the saturation modifier.
     type Scalar;
@@ -1402,6 +1950,9 @@ unsafe impl<T, const N: usize> ArrayExt for [T; N] {
 
 /// Temporary helper trait for getting an array type of size `N + 1`.
 ///
+/// It's implemented for arrays with up to 64 items, since it's not yet
+/// possible to express `N + 1` for any `N` on stable Rust.
+///
 /// ## Safety
 ///
 /// * `Next` must have the same item type as `Self`.
@@ -1422,7 +1973,200 @@ macro_rules! impl_next_array {
     };
 }
 
-impl_next_array!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17);
+impl_next_array!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49,
+    50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65
+);
+
+#[cfg(test)]
+mod test {
+    use crate::{Hsl, Hwb, Lab, Lighten, LightenAssign, Mix, MixAssign, Saturate, SaturateAssign};
+
+    #[derive(Clone, Copy, Debug, PartialEq, Mix, Lighten)]
+    #[palette(palette_internal, component = "T")]
+    struct DerivedLab<T> {
+        #[palette(lightness(min = "T::zero()", max = "T::from_f64(100.0)"))]
+        l: T,
+        a: T,
+        b: T,
+    }
+
+    impl From<Lab<crate::white_point::D65, f64>> for DerivedLab<f64> {
+        fn from(color: Lab<crate::white_point::D65, f64>) -> Self {
+            DerivedLab {
+                l: color.l,
+                a: color.a,
+                b: color.b,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Saturate)]
+    #[palette(palette_internal, component = "f64")]
+    struct DerivedHsl(
+        f64,
+        #[palette(saturation(min = "0.0", max = "1.0"))] f64,
+        f64,
+    );
+
+    #[derive(Clone, Copy, Debug, PartialEq, Lighten)]
+    #[palette(palette_internal, component = "f64")]
+    struct DerivedHwb {
+        hue: f64,
+        #[palette(lightness(min = "0.0", max = "1.0"))]
+        whiteness: f64,
+        #[palette(lightness(min = "0.0", max = "1.0", inverted))]
+        blackness: f64,
+    }
+
+    #[test]
+    fn derived_mix() {
+        let a = Lab::<_, f64>::new(20.0, -30.0, 40.0);
+        let b = Lab::new(80.0, 50.0, -10.0);
+
+        for &factor in &[0.0, 0.3, 0.5, 1.0, 1.5] {
+            let mut derived = DerivedLab::from(a);
+            derived.mix_assign(b.into(), factor);
+
+            assert_eq!(
+                DerivedLab::from(a).mix(b.into(), factor),
+                a.mix(b, factor).into()
+            );
+            assert_eq!(derived, a.mix(b, factor).into());
+        }
+    }
+
+    #[test]
+    fn derived_lighten() {
+        let lab = Lab::<_, f64>::new(40.0, 10.0, 20.0);
+
+        for &factor in &[-1.0, -0.3, 0.0, 0.3, 1.0, 2.0] {
+            let mut derived = DerivedLab::from(lab);
+            derived.lighten_assign(factor);
+            assert_eq!(derived, lab.lighten(factor).into());
+            assert_eq!(
+                DerivedLab::from(lab).lighten(factor),
+                lab.lighten(factor).into()
+            );
+
+            let mut derived = DerivedLab::from(lab);
+            derived.lighten_fixed_assign(factor);
+            assert_eq!(derived, lab.lighten_fixed(factor).into());
+            assert_eq!(
+                DerivedLab::from(lab).lighten_fixed(factor),
+                lab.lighten_fixed(factor).into()
+            );
+        }
+    }
+
+    #[test]
+    fn derived_lighten_inverted() {
+        let hwb = Hwb::new_srgb(120.0, 0.2f64, 0.3);
+        let derived = DerivedHwb {
+            hue: 120.0,
+            whiteness: 0.2,
+            blackness: 0.3,
+        };
+
+        for &factor in &[-0.5, -0.3, 0.0, 0.3, 0.5] {
+            let expected = hwb.lighten(factor);
+            let result = derived.lighten(factor);
+            assert_relative_eq!(result.whiteness, expected.whiteness);
+            assert_relative_eq!(result.blackness, expected.blackness);
+
+            let expected = hwb.lighten_fixed(factor);
+            let result = derived.lighten_fixed(factor);
+            assert_relative_eq!(result.whiteness, expected.whiteness);
+            assert_relative_eq!(result.blackness, expected.blackness);
+        }
+    }
+
+    #[test]
+    fn derived_saturate() {
+        let hsl = Hsl::new_srgb(120.0, 0.4f64, 0.5);
+        let derived = DerivedHsl(120.0, 0.4, 0.5);
+
+        for &factor in &[-1.0, -0.3, 0.0, 0.3, 1.0, 2.0] {
+            assert_eq!(derived.saturate(factor).1, hsl.saturate(factor).saturation);
+            assert_eq!(
+                derived.saturate_fixed(factor).1,
+                hsl.saturate_fixed(factor).saturation
+            );
+
+            let mut derived = derived;
+            derived.saturate_assign(factor);
+            assert_eq!(derived.1, hsl.saturate(factor).saturation);
+        }
+    }
+
+    #[test]
+    fn derived_lighten_keeps_other_fields() {
+        let derived = DerivedLab {
+            l: 50.0f64,
+            a: 12.0,
+            b: -7.0,
+        };
+        let lightened = derived.lighten(0.5);
+
+        assert_eq!(lightened.a, 12.0);
+        assert_eq!(lightened.b, -7.0);
+        assert_eq!(lightened.l, 75.0);
+    }
+
+    #[test]
+    fn component_wise_gamma() {
+        use crate::{ComponentWise, LinSrgba, Oklab, Srgb};
+
+        fn gamma<C: ComponentWise<Scalar = f64>>(color: C) -> C {
+            color.map_components(|c| c.powf(2.2))
+        }
+
+        let rgb = Srgb::new(0.2f64, 0.5, 0.9);
+        assert_eq!(
+            gamma(rgb),
+            Srgb::new(0.2f64.powf(2.2), 0.5f64.powf(2.2), 0.9f64.powf(2.2))
+        );
+
+        let lab = Lab::<crate::white_point::D65, f64>::new(50.0, 20.0, 30.0);
+        assert_eq!(
+            gamma(lab),
+            Lab::new(50.0f64.powf(2.2), 20.0f64.powf(2.2), 30.0f64.powf(2.2))
+        );
+
+        let oklab = Oklab::new(0.5f64, 0.1, 0.2);
+        assert_eq!(
+            gamma(oklab),
+            Oklab::new(0.5f64.powf(2.2), 0.1f64.powf(2.2), 0.2f64.powf(2.2))
+        );
+
+        let rgba = LinSrgba::new(0.25f64, 0.5, 1.0, 0.5);
+        assert_eq!(
+            gamma(rgba),
+            LinSrgba::new(0.25f64.powf(2.2), 0.5f64.powf(2.2), 1.0, 0.5f64.powf(2.2))
+        );
+    }
+
+    #[test]
+    fn component_wise_pairs() {
+        use crate::{ComponentWise, LinSrgba, Oklab};
+
+        let a = Oklab::new(0.2f32, -0.1, 0.3);
+        let b = Oklab::new(0.6, 0.2, -0.1);
+        assert_eq!(a.component_wise(b, f32::min), Oklab::new(0.2, -0.1, -0.1));
+
+        let mut calls = 0;
+        let sum = LinSrgba::new(0.1f32, 0.2, 0.3, 0.4).component_wise(
+            LinSrgba::new(0.4, 0.3, 0.2, 0.1),
+            |a, b| {
+                calls += 1;
+                a.max(b)
+            },
+        );
+        assert_eq!(sum, LinSrgba::new(0.4, 0.3, 0.3, 0.4));
+        assert_eq!(calls, 4);
+    }
+}
 
 #[cfg(doctest)]
 macro_rules! doctest {
@@ -349,6 +349,7 @@ impl_is_within_bounds! {
         medium => [Self::min_medium(), None],
         short => [Self::min_short(), None]
     }
+    new(long: T, medium: T, short: T)
     where T: Stimulus
 }
 impl_clamp! {
@@ -369,6 +370,7 @@ impl_color_add!(Lms<M>, [long, medium, short], meta);
 impl_color_sub!(Lms<M>, [long, medium, short], meta);
 impl_color_mul!(Lms<M>, [long, medium, short], meta);
 impl_color_div!(Lms<M>, [long, medium, short], meta);
+impl_component_wise!(Lms<M>, [long, medium, short], meta);
 
 impl_tuple_conversion!(Lms<M> as (T, T, T));
 impl_array_casts!(Lms<M, T>, [T; 3]);
@@ -376,6 +378,7 @@ impl_simd_array_conversion!(Lms<M>, [long, medium, short], meta);
 impl_struct_of_array_traits!(Lms<M>, [long, medium, short], meta);
 
 impl_eq!(Lms<M>, [long, medium, short]);
+impl_select!(Lms<M>, [long, medium, short], meta);
 impl_copy_clone!(Lms<M>, [long, medium, short], meta);
 
 impl_rand_traits_cartesian!(UniformLms, Lms<M> {long, medium, short} phantom: meta: PhantomData<M>);
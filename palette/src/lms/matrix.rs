@@ -2,7 +2,7 @@
 
 use core::marker::PhantomData;
 
-use crate::{num::Real, white_point::Any, xyz::meta::HasXyzMeta, Mat3};
+use crate::{num::Real, white_point::Any, xyz::meta::HasXyzMeta};
 
 /// Implemented by meta types that contain an LMS matrix.
 pub trait HasLmsMatrix {
@@ -15,7 +15,7 @@ pub trait HasLmsMatrix {
 pub trait XyzToLms<T> {
     /// Get an [`Xyz`][crate::Xyz] to [`Lms`][super::Lms] conversion matrix with
     /// elements of type `T`.
-    fn xyz_to_lms_matrix() -> Mat3<T>;
+    fn xyz_to_lms_matrix() -> [T; 9];
 }
 
 /// Provides a matrix for converting from [`Lms`][super::Lms] to
@@ -23,7 +23,7 @@ pub trait XyzToLms<T> {
 pub trait LmsToXyz<T> {
     /// Get an [`Lms`][super::Lms] to [`Xyz`][crate::Xyz] conversion matrix with
     /// elements of type `T`.
-    fn lms_to_xyz_matrix() -> Mat3<T>;
+    fn lms_to_xyz_matrix() -> [T; 9];
 }
 
 /// Adds an LMS matrix `Matrix` to another meta type `T`.
@@ -56,7 +56,7 @@ where
 {
     #[rustfmt::skip]
     #[inline]
-    fn xyz_to_lms_matrix() -> Mat3<T> {
+    fn xyz_to_lms_matrix() -> [T; 9] {
         [
             T::from_f64( 0.4002400), T::from_f64(0.7076000), T::from_f64(-0.0808100),
             T::from_f64(-0.2263000), T::from_f64(1.1653200), T::from_f64( 0.0457000),
@@ -71,7 +71,7 @@ where
 {
     #[rustfmt::skip]
     #[inline]
-    fn lms_to_xyz_matrix() -> Mat3<T> {
+    fn lms_to_xyz_matrix() -> [T; 9] {
         [
             T::from_f64(1.8599364), T::from_f64(-1.1293816), T::from_f64( 0.2198974),
             T::from_f64(0.3611914), T::from_f64( 0.6388125), T::from_f64(-0.0000064),
@@ -104,7 +104,7 @@ where
 {
     #[rustfmt::skip]
     #[inline]
-    fn xyz_to_lms_matrix() -> Mat3<T> {
+    fn xyz_to_lms_matrix() -> [T; 9] {
         [
             T::from_f64( 0.8951000), T::from_f64( 0.2664000), T::from_f64(-0.1614000),
             T::from_f64(-0.7502000), T::from_f64( 1.7135000), T::from_f64( 0.0367000),
@@ -119,7 +119,7 @@ where
 {
     #[rustfmt::skip]
     #[inline]
-    fn lms_to_xyz_matrix() -> Mat3<T> {
+    fn lms_to_xyz_matrix() -> [T; 9] {
         [
             T::from_f64( 0.9869929), T::from_f64(-0.1470543), T::from_f64(0.1599627),
             T::from_f64( 0.4323053), T::from_f64( 0.5183603), T::from_f64(0.0492912),
@@ -149,7 +149,7 @@ where
 {
     #[rustfmt::skip]
     #[inline]
-    fn xyz_to_lms_matrix() -> Mat3<T> {
+    fn xyz_to_lms_matrix() -> [T; 9] {
         [
             T::from_f64(1.0000000), T::from_f64(0.0000000), T::from_f64(0.0000000),
             T::from_f64(0.0000000), T::from_f64(1.0000000), T::from_f64(0.0000000),
@@ -164,7 +164,7 @@ where
 {
     #[rustfmt::skip]
     #[inline]
-    fn lms_to_xyz_matrix() -> Mat3<T> {
+    fn lms_to_xyz_matrix() -> [T; 9] {
         [
             T::from_f64(1.0000000), T::from_f64(0.0000000), T::from_f64(0.0000000),
             T::from_f64(0.0000000), T::from_f64(1.0000000), T::from_f64(0.0000000),
@@ -3,6 +3,7 @@
 pub mod channels;
 #[allow(clippy::module_inception)]
 mod luma;
+pub mod weights;
 #[allow(deprecated)]
 use crate::encoding::{Gamma, Linear, Srgb};
 use crate::white_point::D65;
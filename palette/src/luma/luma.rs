@@ -3,7 +3,7 @@ use core::{
     convert::TryInto,
     fmt,
     marker::PhantomData,
-    ops::{Add, Div},
+    ops::{Add, Div, Mul},
 };
 
 use crate::{
@@ -14,9 +14,9 @@ use crate::{
     encoding::{FromLinear, IntoLinear, Linear, Srgb},
     luma::LumaStandard,
     num::{Arithmetics, MinMax, PartialCmp, Real},
-    stimulus::{FromStimulus, Stimulus, StimulusColor},
+    stimulus::{mix_u8, FromStimulus, Stimulus, StimulusColor},
     white_point::D65,
-    Alpha, IntoColor, Xyz, Yxy,
+    Alpha, IntoColor, RelativeLuminance, Xyz, Yxy,
 };
 
 /// Luminance with an alpha component. See the [`Lumaa` implementation
@@ -88,17 +88,6 @@ impl<S, T> Luma<S, T> {
     pub fn from_components((luma,): (T,)) -> Self {
         Self::new(luma)
     }
-
-    fn reinterpret_as<S2>(self) -> Luma<S2, T>
-    where
-        S: LumaStandard,
-        S2: LumaStandard<WhitePoint = S::WhitePoint>,
-    {
-        Luma {
-            luma: self.luma,
-            standard: PhantomData,
-        }
-    }
 }
 
 impl<S, T> Luma<S, T>
@@ -172,6 +161,60 @@ impl<S> Luma<S, u8> {
     {
         O::unpack(color).color
     }
+
+    /// Mix the color with `other` by `factor`, using integer math.
+    ///
+    /// The luma is mixed and rounded like the components in
+    /// [`Rgb::mix_u8`](crate::rgb::Rgb::mix_u8), in the encoded space of the
+    /// color. Mixing non-linear colors, such as
+    /// [`SrgbLuma`](crate::SrgbLuma), makes the transitions look darker than
+    /// mixing in linear space.
+    ///
+    /// ```
+    /// use palette::SrgbLuma;
+    ///
+    /// let a = SrgbLuma::new(255u8);
+    /// let b = SrgbLuma::new(0u8);
+    ///
+    /// assert_eq!(a.mix_u8(b, 0), a);
+    /// assert_eq!(a.mix_u8(b, 255), b);
+    /// assert_eq!(a.mix_u8(b, 128), SrgbLuma::new(127));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mix_u8(self, other: Self, factor: u8) -> Self {
+        Luma::new(mix_u8(self.luma, other.luma, factor))
+    }
+
+    /// Mix each color in `colors` with the corresponding color in `others`
+    /// by `factor`, in place.
+    ///
+    /// This is the same as calling [`mix_u8`](Self::mix_u8) for each pair of
+    /// colors.
+    ///
+    /// ```
+    /// use palette::SrgbLuma;
+    ///
+    /// let mut colors = [SrgbLuma::new(255u8), SrgbLuma::new(0u8)];
+    /// SrgbLuma::mix_u8_slice(&mut colors, &[SrgbLuma::new(0u8), SrgbLuma::new(255u8)], 128);
+    /// assert_eq!(colors, [SrgbLuma::new(127), SrgbLuma::new(128)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` and `others` don't have the same length.
+    #[inline]
+    pub fn mix_u8_slice(colors: &mut [Self], others: &[Self], factor: u8) {
+        assert_eq!(
+            colors.len(),
+            others.len(),
+            "the color buffers have different lengths"
+        );
+
+        for (color, &other) in colors.iter_mut().zip(others) {
+            *color = color.mix_u8(other, factor);
+        }
+    }
 }
 
 impl<S, T> Luma<S, T>
@@ -265,6 +308,50 @@ impl<Wp, T> Luma<Linear<Wp>, T> {
     {
         color.into_linear()
     }
+
+    /// Create a linear luminance color from an absolute luminance, such as in
+    /// cd/m².
+    ///
+    /// The luminance is divided by `reference_white`, the luminance of the
+    /// reference white, so `luma` is `1.0` when they are equal. This is purely
+    /// arithmetical. Only linear luminance is supported, since the value has
+    /// to be proportional to the light intensity.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::LinLuma;
+    ///
+    /// let gray: LinLuma = LinLuma::from_absolute_luminance(18.0, 100.0);
+    /// assert_relative_eq!(gray.luma, 0.18);
+    /// ```
+    #[inline]
+    pub fn from_absolute_luminance(luminance: T, reference_white: T) -> Self
+    where
+        T: Div<Output = T>,
+    {
+        Self::new(luminance / reference_white)
+    }
+
+    /// Get the absolute luminance, such as in cd/m², given the luminance of
+    /// the reference white.
+    ///
+    /// This is `luma * reference_white` and the inverse of
+    /// [`from_absolute_luminance`](Luma::from_absolute_luminance).
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::LinLuma;
+    ///
+    /// let gray: LinLuma = LinLuma::new(0.18);
+    /// assert_relative_eq!(gray.absolute_luminance(100.0), 18.0);
+    /// ```
+    #[inline]
+    pub fn absolute_luminance(self, reference_white: T) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.luma * reference_white
+    }
 }
 
 // Safety:
@@ -405,6 +492,62 @@ impl<S> Lumaa<S, u8> {
     {
         O::unpack(color)
     }
+
+    /// Mix the color with `other` by `factor`, using integer math.
+    ///
+    /// The luma and alpha are mixed and rounded like the components in
+    /// [`Rgb::mix_u8`](crate::rgb::Rgb::mix_u8), in the encoded space of the
+    /// color. The alpha is mixed like the luma, so the colors are expected to
+    /// not be premultiplied.
+    ///
+    /// ```
+    /// use palette::SrgbLumaa;
+    ///
+    /// let a = SrgbLumaa::new(255u8, 255);
+    /// let b = SrgbLumaa::new(0u8, 0);
+    ///
+    /// assert_eq!(a.mix_u8(b, 0), a);
+    /// assert_eq!(a.mix_u8(b, 255), b);
+    /// assert_eq!(a.mix_u8(b, 128), SrgbLumaa::new(127, 127));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mix_u8(self, other: Self, factor: u8) -> Self {
+        Alpha {
+            color: self.color.mix_u8(other.color, factor),
+            alpha: mix_u8(self.alpha, other.alpha, factor),
+        }
+    }
+
+    /// Mix each color in `colors` with the corresponding color in `others`
+    /// by `factor`, in place.
+    ///
+    /// This is the same as calling [`mix_u8`](Self::mix_u8) for each pair of
+    /// colors.
+    ///
+    /// ```
+    /// use palette::SrgbLumaa;
+    ///
+    /// let mut colors = [SrgbLumaa::new(255u8, 255), SrgbLumaa::new(0u8, 0)];
+    /// SrgbLumaa::mix_u8_slice(&mut colors, &[SrgbLumaa::new(0u8, 0), SrgbLumaa::new(255u8, 255)], 128);
+    /// assert_eq!(colors, [SrgbLumaa::new(127, 127), SrgbLumaa::new(128, 128)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` and `others` don't have the same length.
+    #[inline]
+    pub fn mix_u8_slice(colors: &mut [Self], others: &[Self], factor: u8) {
+        assert_eq!(
+            colors.len(),
+            others.len(),
+            "the color buffers have different lengths"
+        );
+
+        for (color, &other) in colors.iter_mut().zip(others) {
+            *color = color.mix_u8(other, factor);
+        }
+    }
 }
 
 impl<S, T, A> Alpha<Luma<S, T>, A>
@@ -513,18 +656,46 @@ impl<Wp, T, A> Alpha<Luma<Linear<Wp>, T>, A> {
 impl_reference_component_methods!(Luma<S>, [luma], standard);
 impl_struct_of_arrays_methods!(Luma<S>, [luma], standard);
 
+/// Converts directly between luma standards, without going through [`Xyz`].
+///
+/// The luminance is relative to the white point, so converting between
+/// standards with different white points doesn't change the linear luminance.
+/// The transfer functions are only applied when they differ between the
+/// standards.
+///
+/// ```
+/// use palette::{encoding::Srgb, luma::Luma, white_point::D50, FromColor, SrgbLuma};
+///
+/// let d50: Luma<(D50, Srgb), f32> = Luma::new(0.5);
+/// assert_eq!(SrgbLuma::from_color(d50), SrgbLuma::new(0.5));
+/// ```
+///
+/// Slices can be temporarily converted in place, using
+/// [`IntoColorMut`](crate::convert::IntoColorMut):
+///
+/// ```
+/// use palette::{convert::IntoColorMut, LinLuma, SrgbLuma};
+///
+/// let mut samples = [SrgbLuma::new(0.0f32), SrgbLuma::new(0.5), SrgbLuma::new(1.0)];
+///
+/// {
+///     let linear: &mut [LinLuma] = &mut samples.into_color_mut();
+///     assert_eq!(linear[2], LinLuma::new(1.0));
+/// }
+/// ```
 impl<S1, S2, T> FromColorUnclamped<Luma<S2, T>> for Luma<S1, T>
 where
-    S1: LumaStandard + 'static,
-    S2: LumaStandard<WhitePoint = S1::WhitePoint> + 'static,
-    S1::TransferFn: FromLinear<T, T>,
-    S2::TransferFn: IntoLinear<T, T>,
+    S1: LumaStandard,
+    S2: LumaStandard,
+    S1::TransferFn: FromLinear<T, T> + 'static,
+    S2::TransferFn: IntoLinear<T, T> + 'static,
 {
     fn from_color_unclamped(color: Luma<S2, T>) -> Self {
-        if TypeId::of::<S1>() == TypeId::of::<S2>() {
-            color.reinterpret_as()
+        if TypeId::of::<S1::TransferFn>() == TypeId::of::<S2::TransferFn>() {
+            Luma::new(color.luma)
         } else {
-            Self::from_linear(color.into_linear().reinterpret_as())
+            let linear = S2::TransferFn::into_linear(color.luma);
+            Luma::new(S1::TransferFn::from_linear(linear))
         }
     }
 }
@@ -557,10 +728,26 @@ where
 
 impl_tuple_conversion!(Luma<S> as (T));
 
+/// Decodes the luma value, without converting to [`Xyz`].
+impl<S, T> RelativeLuminance for Luma<S, T>
+where
+    S: LumaStandard,
+    S::TransferFn: IntoLinear<T, T>,
+    T: Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_relative_luminance(&self) -> T {
+        S::TransferFn::into_linear(self.luma.clone())
+    }
+}
+
 impl_is_within_bounds! {
     Luma<S> {
         luma => [Self::min_luma(), Self::max_luma()]
     }
+    new(luma: T)
     where T: Stimulus
 }
 impl_clamp! {
@@ -598,6 +785,7 @@ impl_color_add!(Luma<S>, [luma], standard);
 impl_color_sub!(Luma<S>, [luma], standard);
 impl_color_mul!(Luma<S>, [luma], standard);
 impl_color_div!(Luma<S>, [luma], standard);
+impl_component_wise!(Luma<S>, [luma], standard);
 
 impl_array_casts!(Luma<S, T>, [T; 1]);
 
@@ -758,6 +946,7 @@ impl_struct_of_array_traits!(Luma<S>, [luma], standard);
 
 impl_copy_clone!(Luma<S>, [luma], standard);
 impl_eq!(Luma<S>, [luma]);
+impl_select!(Luma<S>, [luma], standard);
 
 impl<S, T> fmt::LowerHex for Luma<S, T>
 where
@@ -811,6 +1000,20 @@ where
     }
 }
 
+/// Calculates the relative luminance and contrast of 8 bit gray scale colors,
+/// with `f32` as the working type.
+impl<S> Wcag21RelativeContrast for Luma<S, u8>
+where
+    Luma<S, f32>: Wcag21RelativeContrast<Scalar = f32>,
+{
+    type Scalar = f32;
+
+    #[inline]
+    fn relative_luminance(self) -> Luma<Linear<D65>, Self::Scalar> {
+        self.into_format::<f32>().relative_luminance()
+    }
+}
+
 impl_rand_traits_cartesian!(UniformLuma, Luma<S> {luma} phantom: standard: PhantomData<S>);
 
 #[cfg(feature = "bytemuck")]
@@ -894,6 +1097,41 @@ mod test {
         assert_eq!(Luma::<Srgb, f32>::max_luma(), 1.0);
     }
 
+    #[cfg(feature = "approx")]
+    #[test]
+    fn convert_between_standards() {
+        use crate::{
+            encoding::{Linear, Rec709, RecOetf},
+            white_point::D50,
+            FromColor, LinLuma, SrgbLuma, Xyz,
+        };
+
+        for i in 0..=20 {
+            let value = i as f64 / 20.0;
+            let srgb = SrgbLuma::new(value);
+
+            // Same white point, compared with going through XYZ.
+            let rec709 = Luma::<Rec709, f64>::from_color(srgb);
+            let generic = Luma::<Rec709, f64>::from_color(Xyz::from_color(srgb));
+            assert_relative_eq!(rec709, generic, epsilon = 1e-12);
+
+            // Different white points and transfer functions.
+            let gamma = Luma::<(D50, RecOetf), f64>::from_color(srgb);
+            let expected = Luma::<(D50, RecOetf), f64>::from_linear(Luma::<Linear<D50>, f64>::new(
+                srgb.into_linear().luma,
+            ));
+            assert_relative_eq!(gamma, expected, epsilon = 1e-12);
+
+            // Only the white point differs.
+            let d50 = Luma::<(D50, Srgb), f64>::from_color(srgb);
+            assert_eq!(d50.luma, value);
+            assert_eq!(
+                LinLuma::<D50, f64>::from_color(d50).luma,
+                srgb.into_linear().luma
+            );
+        }
+    }
+
     struct_of_arrays_tests!(
         Luma<Srgb>[luma] phantom: standard,
         super::Lumaa::new(0.1f32, 0.4),
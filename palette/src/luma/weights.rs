@@ -0,0 +1,172 @@
+//! Weights for calculating luma from RGB components.
+//!
+//! Video standards define luma as a weighted sum of the red, green and blue
+//! components, with weights that are fixed by the standard rather than derived
+//! from the primaries of the RGB space. The types in this module carry those
+//! weights and can be used with
+//! [`Rgb::relative_luma_with`](crate::rgb::Rgb::relative_luma_with).
+//!
+//! The result depends on the encoding of the input color:
+//!
+//! * Applying the weights to non-linear (gamma encoded) components, such as
+//!   [`Srgb`](crate::Srgb), produces "luma" (Y′), as used in video signals.
+//! * Applying the weights to linear components, such as
+//!   [`LinSrgb`](crate::LinSrgb), produces relative luminance (Y), as long as
+//!   the weights match the primaries of the RGB space.
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::{luma::weights::{Bt601, Bt709}, FromColor, Srgb, Xyz};
+//!
+//! let color = Srgb::new(0.8f32, 0.4, 0.2);
+//!
+//! // Luma (Y′) with the weights from BT.601 and BT.709.
+//! assert_relative_eq!(color.relative_luma_with::<Bt601>(), 0.4968);
+//! assert_relative_eq!(color.relative_luma_with::<Bt709>(), 0.4706);
+//!
+//! // Relative luminance (Y) is calculated from the linear components.
+//! let luminance = color.into_linear().relative_luma_with::<Bt709>();
+//! assert_relative_eq!(luminance, 0.2258, epsilon = 0.0001);
+//!
+//! // BT.709 has the same primaries as sRGB, so it's also the Y of `Xyz`.
+//! assert_relative_eq!(luminance, Xyz::from_color(color).y, epsilon = 0.0001);
+//! ```
+
+use crate::num::Real;
+
+/// A set of weights for the red, green and blue components, used for
+/// calculating luma.
+///
+/// The weights are expected to add up to `1.0`.
+pub trait LumaWeights<T> {
+    /// The weight of the red component.
+    fn red() -> T;
+    /// The weight of the green component.
+    fn green() -> T;
+    /// The weight of the blue component.
+    fn blue() -> T;
+}
+
+/// The luma weights from ITU-R BT.601, which are used for standard definition
+/// video.
+///
+/// `Y′ = 0.299 R′ + 0.587 G′ + 0.114 B′`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bt601;
+
+impl<T: Real> LumaWeights<T> for Bt601 {
+    #[inline]
+    fn red() -> T {
+        T::from_f64(0.299)
+    }
+
+    #[inline]
+    fn green() -> T {
+        T::from_f64(0.587)
+    }
+
+    #[inline]
+    fn blue() -> T {
+        T::from_f64(0.114)
+    }
+}
+
+/// The luma weights from ITU-R BT.709, which are used for high definition
+/// video. They match the primaries of sRGB.
+///
+/// `Y′ = 0.2126 R′ + 0.7152 G′ + 0.0722 B′`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bt709;
+
+impl<T: Real> LumaWeights<T> for Bt709 {
+    #[inline]
+    fn red() -> T {
+        T::from_f64(0.2126)
+    }
+
+    #[inline]
+    fn green() -> T {
+        T::from_f64(0.7152)
+    }
+
+    #[inline]
+    fn blue() -> T {
+        T::from_f64(0.0722)
+    }
+}
+
+/// The luma weights from ITU-R BT.2020, which are used for ultra high
+/// definition video.
+///
+/// `Y′ = 0.2627 R′ + 0.6780 G′ + 0.0593 B′`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bt2020;
+
+impl<T: Real> LumaWeights<T> for Bt2020 {
+    #[inline]
+    fn red() -> T {
+        T::from_f64(0.2627)
+    }
+
+    #[inline]
+    fn green() -> T {
+        T::from_f64(0.6780)
+    }
+
+    #[inline]
+    fn blue() -> T {
+        T::from_f64(0.0593)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Bt2020, Bt601, Bt709, LumaWeights};
+    use crate::{convert::FromColorUnclamped, LinSrgb, Srgb, Xyz};
+
+    fn assert_weights<W: LumaWeights<f64>>(red: f64, green: f64, blue: f64) {
+        assert_eq!(W::red(), red);
+        assert_eq!(W::green(), green);
+        assert_eq!(W::blue(), blue);
+        assert_relative_eq!(W::red() + W::green() + W::blue(), 1.0);
+
+        assert_relative_eq!(Srgb::new(1.0, 0.0, 0.0).relative_luma_with::<W>(), red);
+        assert_relative_eq!(Srgb::new(0.0, 1.0, 0.0).relative_luma_with::<W>(), green);
+        assert_relative_eq!(Srgb::new(0.0, 0.0, 1.0).relative_luma_with::<W>(), blue);
+        assert_relative_eq!(Srgb::new(1.0, 1.0, 1.0).relative_luma_with::<W>(), 1.0);
+        assert_relative_eq!(Srgb::new(0.0, 0.0, 0.0).relative_luma_with::<W>(), 0.0);
+    }
+
+    #[test]
+    fn bt601() {
+        assert_weights::<Bt601>(0.299, 0.587, 0.114);
+    }
+
+    #[test]
+    fn bt709() {
+        assert_weights::<Bt709>(0.2126, 0.7152, 0.0722);
+    }
+
+    #[test]
+    fn bt2020() {
+        assert_weights::<Bt2020>(0.2627, 0.6780, 0.0593);
+    }
+
+    #[test]
+    fn luma_from_encoded_components() {
+        let color = Srgb::new(0.8f64, 0.4, 0.2);
+        let expected = 0.299 * 0.8 + 0.587 * 0.4 + 0.114 * 0.2;
+
+        assert_relative_eq!(color.relative_luma_with::<Bt601>(), expected);
+    }
+
+    #[test]
+    fn luminance_from_linear_components() {
+        // BT.709 has the same primaries as sRGB, so the weights should give
+        // the same result as the Y component in XYZ.
+        let color = LinSrgb::new(0.8f64, 0.4, 0.2);
+        let xyz = Xyz::from_color_unclamped(color);
+
+        assert_relative_eq!(color.relative_luma_with::<Bt709>(), xyz.y, epsilon = 0.0001);
+    }
+}
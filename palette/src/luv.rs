@@ -11,7 +11,7 @@ use crate::{
     convert::FromColorUnclamped,
     num::{Arithmetics, MinMax, PartialCmp, Powf, Powi, Real, Recip, Trigonometry, Zero},
     white_point::{WhitePoint, D65},
-    Alpha, FromColor, GetHue, Lchuv, LuvHue, Xyz,
+    Alpha, FromColor, GetHue, IsAchromatic, Lchuv, LuvHue, Xyz,
 };
 
 /// CIE L\*u\*v\* (CIELUV) with an alpha component. See the [`Luva`
@@ -139,6 +139,7 @@ impl<Wp, T, A> Alpha<Luv<Wp, T>, A> {
 
 impl_reference_component_methods!(Luv<Wp>, [l, u, v], white_point);
 impl_struct_of_arrays_methods!(Luv<Wp>, [l, u, v], white_point);
+impl_sort_keys!(Luv<Wp> {lightness: l});
 
 impl<Wp, T> FromColorUnclamped<Luv<Wp, T>> for Luv<Wp, T> {
     fn from_color_unclamped(color: Luv<Wp, T>) -> Self {
@@ -216,6 +217,7 @@ impl_is_within_bounds! {
         u => [Self::min_u(), Self::max_u()],
         v => [Self::min_v(), Self::max_v()]
     }
+    new(l: T, u: T, v: T)
     where T: Real + Zero
 }
 impl_clamp! {
@@ -233,6 +235,7 @@ impl_lighten!(Luv<Wp> increase {l => [Self::min_l(), Self::max_l()]} other {u, v
 impl_premultiply!(Luv<Wp> {l, u, v} phantom: white_point);
 impl_euclidean_distance!(Luv<Wp> {l, u, v});
 impl_hyab!(Luv<Wp> {lightness: l, chroma1: u, chroma2: v});
+impl_saturate_cartesian!(Luv<Wp> {chroma1: u, chroma2: v} max_chroma: Lchuv::<Wp, T>::max_chroma());
 impl_lab_color_schemes!(Luv<Wp>[u, v][l, white_point]);
 
 impl<Wp, T> GetHue for Luv<Wp, T>
@@ -246,6 +249,21 @@ where
     }
 }
 
+impl<Wp, T> IsAchromatic for Luv<Wp, T>
+where
+    T: Arithmetics + PartialCmp + Clone,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the chroma, calculated from `u`
+    /// and `v`, is at most `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        let chroma_squared = self.u.clone() * &self.u + self.v.clone() * &self.v;
+
+        chroma_squared.lt_eq(&(tolerance.clone() * tolerance))
+    }
+}
+
 impl<Wp, T> HasBoolMask for Luv<Wp, T>
 where
     T: HasBoolMask,
@@ -266,12 +284,15 @@ impl_color_add!(Luv<Wp>, [l, u, v], white_point);
 impl_color_sub!(Luv<Wp>, [l, u, v], white_point);
 impl_color_mul!(Luv<Wp>, [l, u, v], white_point);
 impl_color_div!(Luv<Wp>, [l, u, v], white_point);
+impl_component_wise!(Luv<Wp>, [l, u, v], white_point);
 
 impl_array_casts!(Luv<Wp, T>, [T; 3]);
 impl_simd_array_conversion!(Luv<Wp>, [l, u, v], white_point);
 impl_struct_of_array_traits!(Luv<Wp>, [l, u, v], white_point);
 
 impl_eq!(Luv<Wp>, [l, u, v]);
+impl_select!(Luv<Wp>, [l, u, v], white_point);
+impl_relative_luminance!(Luv<Wp> via crate::Xyz<Wp, T>);
 impl_copy_clone!(Luv<Wp>, [l, u, v], white_point);
 
 #[allow(deprecated)]
@@ -319,6 +340,17 @@ mod test {
     use crate::Lchuv;
 
     test_convert_into_from_xyz!(Luv);
+    test_lighten_lightness!(Luv<D65> {l} new(50.0, 20.0, 30.0));
+
+    test_saturate_cartesian!(
+        Luv / Lchuv[
+            Luv::<D65, f64>::new(53.2, 175.0, 37.8),
+            Luv::<D65, f64>::new(32.3, -9.4, -130.3),
+            Luv::<D65, f64>::new(70.0, -10.0, 5.0),
+            Luv::<D65, f64>::new(40.0, 150.0, 150.0)
+        ],
+        gray: Luv::<D65, f64>::new(50.0, 0.0, 0.0)
+    );
 
     #[cfg(feature = "approx")]
     mod conversion {
@@ -344,6 +376,28 @@ mod test {
             let v = Luv::new(32.30087, -9.40241, -130.35109);
             assert_relative_eq!(u, v, epsilon = 0.01);
         }
+
+        #[test]
+        fn degree_10_observer() {
+            use crate::{white_point::D65Degree10, Lchuv, Xyz};
+
+            // Reference values from the CIE 15:2004 formulas, with the ASTM
+            // E308 10° D65 tristimulus values Xn = 94.811, Yn = 100.0,
+            // Zn = 107.304.
+            let xyz = Xyz::<D65Degree10, f64>::new(0.4, 0.35, 0.3);
+            let luv = Luv::from_color(xyz);
+            assert_relative_eq!(
+                luv,
+                Luv::new(65.748665, 39.671723, 9.713707),
+                epsilon = 0.000001
+            );
+
+            let white = Luv::from_color(Xyz::<D65Degree10, f64>::new(0.94811, 1.0, 1.07304));
+            assert_relative_eq!(white, Luv::new(100.0, 0.0, 0.0), epsilon = 0.000001);
+
+            let lchuv = Lchuv::from_color(luv);
+            assert_relative_eq!(Xyz::from_color(lchuv), xyz, epsilon = 0.000001);
+        }
     }
 
     #[test]
@@ -34,8 +34,12 @@ mod reference_component;
 #[macro_use]
 mod copy_clone;
 #[macro_use]
+mod select;
+#[macro_use]
 mod hue;
 #[macro_use]
 mod random;
 #[macro_use]
 mod color_theory;
+#[macro_use]
+mod sort_key;
@@ -165,7 +165,8 @@ macro_rules! impl_color_sub {
 
 /// Implement `Mul` and `MulAssign` traits for a color space.
 ///
-/// Both scalars and color arithmetic are implemented.
+/// Both scalars and color arithmetic are implemented. Struct of arrays colors
+/// also get `MulAssign` with scalars.
 macro_rules! impl_color_mul {
     ($self_ty: ident , [$($element: ident),+]) => {
         impl_color_mul!($self_ty<>, [$($element),+]);
@@ -216,12 +217,34 @@ macro_rules! impl_color_mul {
                 $( self.$element *= c.clone(); )+
             }
         }
+
+        impl_color_mul!(@struct_of_arrays $self_ty<$($ty_param),*>, [$($element),+] impl['a,] &'a mut [T]);
+        impl_color_mul!(@struct_of_arrays $self_ty<$($ty_param),*>, [$($element),+] impl[const N: usize,] [T; N]);
+        #[cfg(feature = "alloc")]
+        impl_color_mul!(@struct_of_arrays $self_ty<$($ty_param),*>, [$($element),+] impl[] alloc::vec::Vec<T>);
+        #[cfg(feature = "alloc")]
+        impl_color_mul!(@struct_of_arrays $self_ty<$($ty_param),*>, [$($element),+] impl[] alloc::boxed::Box<[T]>);
+    };
+    (@struct_of_arrays $self_ty: ident < $($ty_param: ident),* >, [$($element: ident),+] impl[$($generic: tt)*] $container: ty) => {
+        impl<$($generic)* $($ty_param,)* T> core::ops::MulAssign<T> for $self_ty<$($ty_param,)* $container>
+        where
+            T: core::ops::MulAssign + Clone
+        {
+            fn mul_assign(&mut self, c: T) {
+                $(
+                    for value in self.$element.iter_mut() {
+                        *value *= c.clone();
+                    }
+                )+
+            }
+        }
     };
 }
 
 /// Implement `Div` and `DivAssign` traits for a color space.
 ///
-/// Both scalars and color arithmetic are implemented.
+/// Both scalars and color arithmetic are implemented. Struct of arrays colors
+/// also get `DivAssign` with scalars.
 macro_rules! impl_color_div {
     ($self_ty: ident , [$($element: ident),+]) => {
         impl_color_div!($self_ty<>, [$($element),+]);
@@ -272,5 +295,60 @@ macro_rules! impl_color_div {
                 $( self.$element /= c.clone(); )+
             }
         }
+
+        impl_color_div!(@struct_of_arrays $self_ty<$($ty_param),*>, [$($element),+] impl['a,] &'a mut [T]);
+        impl_color_div!(@struct_of_arrays $self_ty<$($ty_param),*>, [$($element),+] impl[const N: usize,] [T; N]);
+        #[cfg(feature = "alloc")]
+        impl_color_div!(@struct_of_arrays $self_ty<$($ty_param),*>, [$($element),+] impl[] alloc::vec::Vec<T>);
+        #[cfg(feature = "alloc")]
+        impl_color_div!(@struct_of_arrays $self_ty<$($ty_param),*>, [$($element),+] impl[] alloc::boxed::Box<[T]>);
+    };
+    (@struct_of_arrays $self_ty: ident < $($ty_param: ident),* >, [$($element: ident),+] impl[$($generic: tt)*] $container: ty) => {
+        impl<$($generic)* $($ty_param,)* T> core::ops::DivAssign<T> for $self_ty<$($ty_param,)* $container>
+        where
+            T: core::ops::DivAssign + Clone
+        {
+            fn div_assign(&mut self, c: T) {
+                $(
+                    for value in self.$element.iter_mut() {
+                        *value /= c.clone();
+                    }
+                )+
+            }
+        }
+    };
+}
+
+/// Implement `ComponentWise` for a color space.
+macro_rules! impl_component_wise {
+    ($self_ty: ident , [$($element: ident),+]) => {
+        impl_component_wise!($self_ty<>, [$($element),+]);
+    };
+    ($self_ty: ident < $($ty_param: ident),* > , [$($element: ident),+] $(, $phantom: ident)?) => {
+        impl<$($ty_param,)* T> crate::ComponentWise for $self_ty<$($ty_param,)* T> {
+            type Scalar = T;
+
+            #[inline]
+            fn component_wise<F>(self, other: Self, mut f: F) -> Self
+            where
+                F: FnMut(T, T) -> T,
+            {
+                $self_ty {
+                    $($element: f(self.$element, other.$element),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+
+            #[inline]
+            fn map_components<F>(self, mut f: F) -> Self
+            where
+                F: FnMut(T) -> T,
+            {
+                $self_ty {
+                    $($element: f(self.$element),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+        }
     };
 }
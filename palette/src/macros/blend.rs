@@ -58,5 +58,38 @@ macro_rules! impl_premultiply {
                 Self::unpremultiply(premultiplied).0
             }
         }
+
+        impl_premultiply!(@scalar_lhs $ty<$($ty_param),*> {$($component),+} $(phantom: $phantom)?, f32);
+        impl_premultiply!(@scalar_lhs $ty<$($ty_param),*> {$($component),+} $(phantom: $phantom)?, f64);
+    };
+    (@scalar_lhs $ty: ident <$($ty_param: ident),*> {$($component: ident),+} $(phantom: $phantom: ident)?, $scalar: ident) => {
+        impl_premultiply!(@scalar_lhs_op $ty<$($ty_param),*> {$($component),+} $(phantom: $phantom)?, $scalar, Add::add);
+        impl_premultiply!(@scalar_lhs_op $ty<$($ty_param),*> {$($component),+} $(phantom: $phantom)?, $scalar, Sub::sub);
+        impl_premultiply!(@scalar_lhs_op $ty<$($ty_param),*> {$($component),+} $(phantom: $phantom)?, $scalar, Mul::mul);
+        impl_premultiply!(@scalar_lhs_op $ty<$($ty_param),*> {$($component),+} $(phantom: $phantom)?, $scalar, Div::div);
+    };
+    (@scalar_lhs_op $ty: ident <$($ty_param: ident),*> {$($component: ident),+} $(phantom: $phantom: ident)?, $scalar: ident, $op_trait: ident :: $op_trait_fn: ident) => {
+        // These are implemented for each concrete color type, instead of for
+        // any `PreAlpha<C>`, to not send the trait solver into an infinite
+        // recursion. See https://github.com/Ogeon/palette/issues/283.
+        impl<$($ty_param),*> core::ops::$op_trait<crate::blend::PreAlpha<$ty<$($ty_param,)* $scalar>>> for $scalar {
+            type Output = crate::blend::PreAlpha<$ty<$($ty_param,)* $scalar>>;
+
+            #[inline]
+            fn $op_trait_fn(self, color: crate::blend::PreAlpha<$ty<$($ty_param,)* $scalar>>) -> Self::Output {
+                let crate::blend::PreAlpha {
+                    color: $ty { $($component,)+ .. },
+                    alpha,
+                } = color;
+
+                crate::blend::PreAlpha {
+                    color: $ty {
+                        $($component: core::ops::$op_trait::$op_trait_fn(self, $component),)+
+                        $($phantom: core::marker::PhantomData,)?
+                    },
+                    alpha: core::ops::$op_trait::$op_trait_fn(self, alpha),
+                }
+            }
+        }
     };
 }
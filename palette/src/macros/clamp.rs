@@ -2,16 +2,20 @@ macro_rules! impl_is_within_bounds {
     (
         $ty: ident
         {$($component: ident => [$get_min: expr, $get_max: expr]),+}
+        $(new $new_args: tt)?
         $(where $($where: tt)+)?
     ) => {
         // add empty generics brackets
-        impl_is_within_bounds!($ty<> {$($component => [$get_min, $get_max]),+} $(where $($where)+)?);
+        impl_is_within_bounds!($ty<> {$($component => [$get_min, $get_max]),+} $(new $new_args)? $(where $($where)+)?);
     };
     (
         $ty: ident <$($ty_param: ident),*>
         {$($component: ident => [$get_min: expr, $get_max: expr]),+}
+        $(new $new_args: tt)?
         $(where $($where: tt)+)?
     ) => {
+        impl_is_within_bounds!(@try_new $ty<$($ty_param),*> $(new $new_args)?);
+
         impl<$($ty_param,)* T> crate::IsWithinBounds for $ty<$($ty_param,)* T>
         where
             T: crate::num::PartialCmp,
@@ -26,21 +30,112 @@ macro_rules! impl_is_within_bounds {
                 )&+
             }
         }
+
+        impl<$($ty_param,)* T> $ty<$($ty_param,)* T>
+        where
+            T: crate::num::PartialCmp,
+            T::Mask: core::ops::BitAnd<Output = T::Mask>,
+            $($($where)+)?
+        {
+            // Checks the component slices of a struct of arrays against the
+            // bounds of `Self`, one component at the time.
+            #[inline]
+            fn slices_are_within_bounds($($component: &[T]),+) -> T::Mask {
+                let mut result = <T::Mask as crate::BoolMask>::from_bool(true);
+
+                $(
+                    let min = $get_min;
+                    let max: Option<T> = Option::from($get_max);
+
+                    for value in $component {
+                        result = result & value.gt_eq(&min);
+
+                        if let Some(max) = &max {
+                            result = result & value.lt_eq(max);
+                        }
+                    }
+                )+
+
+                result
+            }
+        }
+
+        impl_is_within_bounds!(@struct_of_arrays $ty<$($ty_param),*> [$($component),+] impl['a,] &'a [T] $(where $($where)+)?);
+        impl_is_within_bounds!(@struct_of_arrays $ty<$($ty_param),*> [$($component),+] impl['a,] &'a mut [T] $(where $($where)+)?);
+        impl_is_within_bounds!(@struct_of_arrays $ty<$($ty_param),*> [$($component),+] impl[const N: usize,] [T; N] $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_is_within_bounds!(@struct_of_arrays $ty<$($ty_param),*> [$($component),+] impl[] alloc::vec::Vec<T> $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_is_within_bounds!(@struct_of_arrays $ty<$($ty_param),*> [$($component),+] impl[] alloc::boxed::Box<[T]> $(where $($where)+)?);
+    };
+    (@try_new $ty: ident <$($ty_param: ident),*>) => {};
+    (@try_new $ty: ident <$($ty_param: ident),*> new ($($arg: ident : $arg_ty: ty),+)) => {
+        impl<$($ty_param,)* T> $ty<$($ty_param,)* T>
+        where
+            Self: crate::IsWithinBounds<Mask = bool> + PartialEq,
+        {
+            /// Create a color from its components, if they are within the
+            /// expected bounds.
+            ///
+            /// This is a checked alternative to [`new`](Self::new), for when
+            /// out of bounds components should be treated as an error. An
+            /// [`OutOfBounds`](crate::convert::OutOfBounds) error, with the
+            /// unchecked color, is returned if any of the components are out of
+            /// bounds or NaN. Use `new` followed by
+            /// [`clamp`](crate::Clamp::clamp) to move the components into the
+            /// bounds instead.
+            #[inline]
+            pub fn try_new($($arg: $arg_ty),+) -> Result<Self, crate::convert::OutOfBounds<Self>> {
+                let color = Self::new($($arg),+);
+
+                // A color isn't equal to itself if any component is NaN,
+                // including unbounded ones like the hue.
+                #[allow(clippy::eq_op)]
+                let is_number = color == color;
+
+                if is_number && crate::IsWithinBounds::is_within_bounds(&color) {
+                    Ok(color)
+                } else {
+                    Err(crate::convert::OutOfBounds::new(color))
+                }
+            }
+        }
+    };
+    (
+        @struct_of_arrays $ty: ident <$($ty_param: ident),*> [$($component: ident),+]
+        impl[$($generic: tt)*] $container: ty
+        $(where $($where: tt)+)?
+    ) => {
+        impl<$($generic)* $($ty_param,)* T> crate::IsWithinBounds for $ty<$($ty_param,)* $container>
+        where
+            T: crate::num::PartialCmp,
+            T::Mask: core::ops::BitAnd<Output = T::Mask>,
+            $($($where)+)?
+        {
+            #[inline]
+            fn is_within_bounds(&self) -> T::Mask {
+                $ty::<$($ty_param,)* T>::slices_are_within_bounds($(&self.$component),+)
+            }
+        }
     };
 }
 
 macro_rules! impl_is_within_bounds_hwb {
     (
         $ty: ident
+        $(new $new_args: tt)?
         $(where $($where: tt)+)?
     ) => {
         // add empty generics brackets
-        impl_is_within_bounds_hwb!($ty<> $(where $($where)+)?);
+        impl_is_within_bounds_hwb!($ty<> $(new $new_args)? $(where $($where)+)?);
     };
     (
         $ty: ident <$($ty_param: ident),*>
+        $(new $new_args: tt)?
         $(where $($where: tt)+)?
     ) => {
+        impl_is_within_bounds!(@try_new $ty<$($ty_param),*> $(new $new_args)?);
+
         impl<$($ty_param,)* T> crate::IsWithinBounds for $ty<$($ty_param,)* T>
         where
             T: crate::num::PartialCmp + core::ops::Add<Output = T> + Clone,
@@ -54,6 +149,54 @@ macro_rules! impl_is_within_bounds_hwb {
                 (self.whiteness.clone() + self.blackness.clone()).lt_eq(&T::max_intensity())
             }
         }
+
+        impl<$($ty_param,)* T> $ty<$($ty_param,)* T>
+        where
+            T: crate::num::PartialCmp + core::ops::Add<Output = T> + Clone,
+            T::Mask: core::ops::BitAnd<Output = T::Mask>,
+            $($($where)+)?
+        {
+            // Checks the component slices of a struct of arrays against the
+            // bounds of `Self`, one pair of components at the time.
+            #[inline]
+            fn slices_are_within_bounds(whiteness: &[T], blackness: &[T]) -> T::Mask {
+                let mut result = <T::Mask as crate::BoolMask>::from_bool(true);
+
+                for (whiteness, blackness) in whiteness.iter().zip(blackness) {
+                    result = result
+                        & blackness.gt_eq(&Self::min_blackness()) & blackness.lt_eq(&Self::max_blackness())
+                        & whiteness.gt_eq(&Self::min_whiteness()) & whiteness.lt_eq(&Self::max_blackness())
+                        & (whiteness.clone() + blackness.clone()).lt_eq(&T::max_intensity());
+                }
+
+                result
+            }
+        }
+
+        impl_is_within_bounds_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl['a,] &'a [T] $(where $($where)+)?);
+        impl_is_within_bounds_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl['a,] &'a mut [T] $(where $($where)+)?);
+        impl_is_within_bounds_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl[const N: usize,] [T; N] $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_is_within_bounds_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl[] alloc::vec::Vec<T> $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_is_within_bounds_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl[] alloc::boxed::Box<[T]> $(where $($where)+)?);
+    };
+    (
+        @struct_of_arrays $ty: ident <$($ty_param: ident),*>
+        impl[$($generic: tt)*] $container: ty
+        $(where $($where: tt)+)?
+    ) => {
+        impl<$($generic)* $($ty_param,)* T> crate::IsWithinBounds for $ty<$($ty_param,)* $container>
+        where
+            T: crate::num::PartialCmp + core::ops::Add<Output = T> + Clone,
+            T::Mask: core::ops::BitAnd<Output = T::Mask>,
+            $($($where)+)?
+        {
+            #[inline]
+            fn is_within_bounds(&self) -> T::Mask {
+                $ty::<$($ty_param,)* T>::slices_are_within_bounds(&self.whiteness, &self.blackness)
+            }
+        }
     };
 }
 
@@ -70,6 +213,19 @@ macro_rules! _clamp_value {
     (@assign $value: expr, $min: expr, $max: expr) => {
         crate::clamp_assign($value, $min, $max)
     };
+    (@assign_slice $values: expr, $min: expr) => {{
+        let min = $min;
+        for value in $values {
+            crate::clamp_min_assign(value, min.clone());
+        }
+    }};
+    (@assign_slice $values: expr, $min: expr, $max: expr) => {{
+        let min = $min;
+        let max = $max;
+        for value in $values {
+            crate::clamp_assign(value, min.clone(), max.clone());
+        }
+    }};
 }
 
 macro_rules! impl_clamp {
@@ -112,6 +268,42 @@ macro_rules! impl_clamp {
                 $(_clamp_value!(@assign &mut self.$component, $get_min $(, $get_max)?);)+
             }
         }
+
+        impl<$($ty_param,)* T> $ty<$($ty_param,)* T>
+        where
+            T: crate::num::ClampAssign + Clone,
+            $($($where)+)?
+        {
+            // Clamps the component slices of a struct of arrays to the bounds
+            // of `Self`, one component at the time.
+            #[inline]
+            fn clamp_slices_assign($($component: &mut [T]),+) {
+                $(_clamp_value!(@assign_slice $component, $get_min $(, $get_max)?);)+
+            }
+        }
+
+        impl_clamp!(@struct_of_arrays $ty<$($ty_param),*> [$($component),+] impl['a,] &'a mut [T] $(where $($where)+)?);
+        impl_clamp!(@struct_of_arrays $ty<$($ty_param),*> [$($component),+] impl[const N: usize,] [T; N] $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_clamp!(@struct_of_arrays $ty<$($ty_param),*> [$($component),+] impl[] alloc::vec::Vec<T> $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_clamp!(@struct_of_arrays $ty<$($ty_param),*> [$($component),+] impl[] alloc::boxed::Box<[T]> $(where $($where)+)?);
+    };
+    (
+        @struct_of_arrays $ty: ident <$($ty_param: ident),*> [$($component: ident),+]
+        impl[$($generic: tt)*] $container: ty
+        $(where $($where: tt)+)?
+    ) => {
+        impl<$($generic)* $($ty_param,)* T> crate::ClampAssign for $ty<$($ty_param,)* $container>
+        where
+            T: crate::num::ClampAssign + Clone,
+            $($($where)+)?
+        {
+            #[inline]
+            fn clamp_assign(&mut self) {
+                $ty::<$($ty_param,)* T>::clamp_slices_assign($(&mut self.$component),+);
+            }
+        }
     };
 }
 
@@ -142,10 +334,10 @@ macro_rules! impl_clamp_hwb {
         {
             #[inline]
             fn clamp(self) -> Self {
-                let mut whiteness = crate::clamp_min(self.whiteness.clone(), Self::min_whiteness());
-                let mut blackness = crate::clamp_min(self.blackness.clone(), Self::min_blackness());
+                let mut whiteness = crate::clamp_min(self.whiteness, Self::min_whiteness());
+                let mut blackness = crate::clamp_min(self.blackness, Self::min_blackness());
 
-                let sum = self.blackness + self.whiteness;
+                let sum = blackness.clone() + whiteness.clone();
                 let divisor = sum.gt(&T::max_intensity()).select(sum, T::one());
                 whiteness /= divisor.clone();
                 blackness /= divisor;
@@ -176,6 +368,62 @@ macro_rules! impl_clamp_hwb {
                 self.blackness /= divisor;
             }
         }
+
+        impl<$($ty_param,)* T> $ty<$($ty_param,)* T>
+        where
+            T: crate::num::One
+                + crate::num::ClampAssign
+                + crate::num::PartialCmp
+                + core::ops::Add<Output = T>
+                + core::ops::DivAssign
+                + Clone,
+            T::Mask: crate::bool_mask::Select<T>,
+            $($($where)+)?
+        {
+            // Clamps the component slices of a struct of arrays to the bounds
+            // of `Self`, one pair of components at the time.
+            #[inline]
+            fn clamp_slices_assign(whiteness: &mut [T], blackness: &mut [T]) {
+                for (whiteness, blackness) in whiteness.iter_mut().zip(blackness) {
+                    crate::clamp_min_assign(whiteness, Self::min_whiteness());
+                    crate::clamp_min_assign(blackness, Self::min_blackness());
+
+                    let sum = blackness.clone() + whiteness.clone();
+                    let divisor = sum.gt(&T::max_intensity()).select(sum, T::one());
+                    *whiteness /= divisor.clone();
+                    *blackness /= divisor;
+                }
+            }
+        }
+
+        impl_clamp_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl['a,] &'a mut [T] $(where $($where)+)?);
+        impl_clamp_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl[const N: usize,] [T; N] $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_clamp_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl[] alloc::vec::Vec<T> $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_clamp_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl[] alloc::boxed::Box<[T]> $(where $($where)+)?);
+    };
+    (
+        @struct_of_arrays $ty: ident <$($ty_param: ident),*>
+        impl[$($generic: tt)*] $container: ty
+        $(where $($where: tt)+)?
+    ) => {
+        impl<$($generic)* $($ty_param,)* T> crate::ClampAssign for $ty<$($ty_param,)* $container>
+        where
+            T: crate::num::One
+                + crate::num::ClampAssign
+                + crate::num::PartialCmp
+                + core::ops::Add<Output = T>
+                + core::ops::DivAssign
+                + Clone,
+            T::Mask: crate::bool_mask::Select<T>,
+            $($($where)+)?
+        {
+            #[inline]
+            fn clamp_assign(&mut self) {
+                $ty::<$($ty_param,)* T>::clamp_slices_assign(&mut self.whiteness, &mut self.blackness);
+            }
+        }
     };
 }
 
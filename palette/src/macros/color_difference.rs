@@ -62,3 +62,31 @@ macro_rules! impl_hyab {
         }
     };
 }
+
+macro_rules! impl_wcag21_relative_contrast {
+    (
+        $ty: ident
+        $(where $($where: tt)+)?
+    ) => {
+        // add empty generics brackets
+        impl_wcag21_relative_contrast!($ty<> $(where $($where)+)?);
+    };
+    (
+        $ty: ident <$($ty_param: ident),*>
+        $(where $($where: tt)+)?
+    ) => {
+        impl<$($ty_param,)* T> crate::color_difference::Wcag21RelativeContrast for $ty<$($ty_param,)* T>
+        where
+            Self: crate::IntoColor<crate::luma::Luma<crate::encoding::Linear<crate::white_point::D65>, T>>,
+            T: crate::num::Real + core::ops::Add<T, Output = T> + core::ops::Div<T, Output = T> + crate::num::PartialCmp + crate::num::MinMax,
+            $($($where)+)?
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn relative_luminance(self) -> crate::luma::Luma<crate::encoding::Linear<crate::white_point::D65>, T> {
+                crate::IntoColor::into_color(self)
+            }
+        }
+    };
+}
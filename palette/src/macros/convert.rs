@@ -84,3 +84,31 @@ macro_rules! impl_tuple_conversion_hue {
         }
     };
 }
+
+macro_rules! impl_relative_luminance {
+    (
+        $ty: ident via $xyz_ty: ty
+        $(where $($where: tt)+)?
+    ) => {
+        // add empty generics brackets
+        impl_relative_luminance!($ty<> via $xyz_ty $(where $($where)+)?);
+    };
+    (
+        $ty: ident <$($ty_param: ident),*> via $xyz_ty: ty
+        $(where $($where: tt)+)?
+    ) => {
+        impl<$($ty_param,)* T> crate::RelativeLuminance for $ty<$($ty_param,)* T>
+        where
+            Self: Clone,
+            $xyz_ty: crate::convert::FromColorUnclamped<Self>,
+            $($($where)+)?
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn get_relative_luminance(&self) -> T {
+                <$xyz_ty as crate::convert::FromColorUnclamped<Self>>::from_color_unclamped(self.clone()).y
+            }
+        }
+    };
+}
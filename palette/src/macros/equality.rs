@@ -14,6 +14,15 @@ macro_rules! impl_eq {
 
         impl<$($ty_param,)* T> Eq for $self_ty<$($ty_param,)* T> where T: Eq {}
 
+        impl<$($ty_param,)* T> core::hash::Hash for $self_ty<$($ty_param,)* T>
+        where
+            T: core::hash::Hash,
+        {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.$element.hash(state);
+            }
+        }
+
         #[cfg(feature = "approx")]
         impl<$($ty_param,)* T> approx::AbsDiffEq for $self_ty<$($ty_param,)* T>
         where
@@ -82,6 +91,15 @@ macro_rules! impl_eq {
 
         impl<$($ty_param,)* T> Eq for $self_ty<$($ty_param,)* T> where T: Eq {}
 
+        impl<$($ty_param,)* T> core::hash::Hash for $self_ty<$($ty_param,)* T>
+        where
+            T: core::hash::Hash,
+        {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                $( self.$element.hash(state); )+
+            }
+        }
+
         #[cfg(feature = "approx")]
         impl<$($ty_param,)* T> approx::AbsDiffEq for $self_ty<$($ty_param,)* T>
         where
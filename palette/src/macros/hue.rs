@@ -38,27 +38,49 @@ macro_rules! impl_hue_ops {
 
         impl<$($ty_param,)* T> crate::ShiftHue for $self_ty<$($ty_param,)* T>
         where
-            T: core::ops::Add<Output = T>,
+            T: crate::angle::ShiftAngle,
         {
             type Scalar = T;
 
             #[inline]
             fn shift_hue(mut self, amount: Self::Scalar) -> Self {
-                self.hue = self.hue + amount;
+                self.hue = self.hue.shift(amount);
                 self
             }
         }
 
         impl<$($ty_param,)* T> crate::ShiftHueAssign for $self_ty<$($ty_param,)* T>
         where
-            T: core::ops::AddAssign,
+            T: crate::angle::ShiftAngle,
         {
             type Scalar = T;
 
             #[inline]
             fn shift_hue_assign(&mut self, amount: Self::Scalar) {
-                self.hue += amount;
+                self.hue.shift_assign(amount);
             }
         }
-    }
+
+        impl_hue_ops!(@struct_of_arrays $self_ty<$($ty_param),*> impl['a,] &'a mut [T]);
+        impl_hue_ops!(@struct_of_arrays $self_ty<$($ty_param),*> impl[const N: usize,] [T; N]);
+        #[cfg(feature = "alloc")]
+        impl_hue_ops!(@struct_of_arrays $self_ty<$($ty_param),*> impl[] alloc::vec::Vec<T>);
+        #[cfg(feature = "alloc")]
+        impl_hue_ops!(@struct_of_arrays $self_ty<$($ty_param),*> impl[] alloc::boxed::Box<[T]>);
+    };
+    (@struct_of_arrays $self_ty: ident < $($ty_param: ident),* > impl[$($generic: tt)*] $container: ty) => {
+        impl<$($generic)* $($ty_param,)* T> crate::ShiftHueAssign for $self_ty<$($ty_param,)* $container>
+        where
+            T: crate::angle::ShiftAngle + Clone,
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn shift_hue_assign(&mut self, amount: Self::Scalar) {
+                for hue in self.hue.as_mut_slice().into_inner() {
+                    hue.shift_angle_assign(amount.clone());
+                }
+            }
+        }
+    };
 }
@@ -2,6 +2,7 @@ macro_rules! _impl_increase_value_trait {
     (
         $trait: ident :: {$method: ident, $method_fixed: ident},
         $assign_trait: ident :: {$assign_method: ident, $assign_method_fixed: ident},
+        $slices_method: ident, $slices_method_fixed: ident,
         $ty: ident <$($ty_param: ident),*>
         increase {$($component: ident => [$get_min: expr, $get_max: expr]),+}
         other {$($other_component: ident),*}
@@ -86,6 +87,106 @@ macro_rules! _impl_increase_value_trait {
                 )+
             }
         }
+
+        impl<$($ty_param,)* T> $ty<$($ty_param,)* T>
+        where
+            T: crate::num::Real
+                + crate::num::Zero
+                + crate::num::MinMax
+                + crate::num::ClampAssign
+                + core::ops::AddAssign
+                + crate::num::Arithmetics
+                + crate::num::PartialCmp
+                + Clone,
+            T::Mask: crate::bool_mask::LazySelect<T>,
+            $($($where)+)?
+        {
+            // Changes the component slices of a struct of arrays, one
+            // component at the time.
+            #[inline]
+            fn $slices_method(factor: T, $($component: &mut [T]),+) {
+                $(
+                    let min = $get_min;
+                    let max = $get_max;
+
+                    for value in $component {
+                        let difference = lazy_select!{
+                            if factor.gt_eq(&T::zero()) => max.clone() - &*value,
+                            else => value.clone(),
+                        };
+
+                        *value += difference.max(T::zero()) * &factor;
+                        crate::clamp_assign(value, min.clone(), max.clone());
+                    }
+                )+
+            }
+
+            #[inline]
+            fn $slices_method_fixed(amount: T, $($component: &mut [T]),+) {
+                $(
+                    let min = $get_min;
+                    let max = $get_max;
+                    let delta = max.clone() * &amount;
+
+                    for value in $component {
+                        *value += delta.clone();
+                        crate::clamp_assign(value, min.clone(), max.clone());
+                    }
+                )+
+            }
+        }
+
+        _impl_increase_value_trait!(
+            @struct_of_arrays $assign_trait::{$assign_method, $assign_method_fixed}, $slices_method, $slices_method_fixed,
+            $ty<$($ty_param),*> [$($component),+] impl['a,] &'a mut [T] $(where $($where)+)?
+        );
+        _impl_increase_value_trait!(
+            @struct_of_arrays $assign_trait::{$assign_method, $assign_method_fixed}, $slices_method, $slices_method_fixed,
+            $ty<$($ty_param),*> [$($component),+] impl[const N: usize,] [T; N] $(where $($where)+)?
+        );
+        #[cfg(feature = "alloc")]
+        _impl_increase_value_trait!(
+            @struct_of_arrays $assign_trait::{$assign_method, $assign_method_fixed}, $slices_method, $slices_method_fixed,
+            $ty<$($ty_param),*> [$($component),+] impl[] alloc::vec::Vec<T> $(where $($where)+)?
+        );
+        #[cfg(feature = "alloc")]
+        _impl_increase_value_trait!(
+            @struct_of_arrays $assign_trait::{$assign_method, $assign_method_fixed}, $slices_method, $slices_method_fixed,
+            $ty<$($ty_param),*> [$($component),+] impl[] alloc::boxed::Box<[T]> $(where $($where)+)?
+        );
+    };
+    (
+        @struct_of_arrays $assign_trait: ident :: {$assign_method: ident, $assign_method_fixed: ident},
+        $slices_method: ident, $slices_method_fixed: ident,
+        $ty: ident <$($ty_param: ident),*> [$($component: ident),+]
+        impl[$($generic: tt)*] $container: ty
+        $(where $($where: tt)+)?
+    ) => {
+        impl<$($generic)* $($ty_param,)* T> crate::$assign_trait for $ty<$($ty_param,)* $container>
+        where
+            T: crate::num::Real
+                + crate::num::Zero
+                + crate::num::MinMax
+                + crate::num::ClampAssign
+                + core::ops::AddAssign
+                + crate::num::Arithmetics
+                + crate::num::PartialCmp
+                + Clone,
+            T::Mask: crate::bool_mask::LazySelect<T>,
+            $($($where)+)?
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn $assign_method(&mut self, factor: T) {
+                $ty::<$($ty_param,)* T>::$slices_method(factor, $(&mut self.$component),+);
+            }
+
+            #[inline]
+            fn $assign_method_fixed(&mut self, amount: T) {
+                $ty::<$($ty_param,)* T>::$slices_method_fixed(amount, $(&mut self.$component),+);
+            }
+        }
     };
 }
 
@@ -97,6 +198,7 @@ macro_rules! impl_lighten {
         _impl_increase_value_trait!(
             Lighten::{lighten, lighten_fixed},
             LightenAssign::{lighten_assign, lighten_fixed_assign},
+            lighten_slices_assign, lighten_fixed_slices_assign,
             $($input)+
         );
     };
@@ -111,11 +213,199 @@ macro_rules! impl_saturate {
         _impl_increase_value_trait!(
             Saturate::{saturate, saturate_fixed},
             SaturateAssign::{saturate_assign, saturate_fixed_assign},
+            saturate_slices_assign, saturate_fixed_slices_assign,
             $($input)+
         );
     };
 }
 
+// Implements `Saturate` and `SaturateAssign` for Cartesian color spaces, by
+// scaling the length of the chroma vector. The result is the same as for the
+// corresponding cylindrical color space, with chroma in `[0, $get_max]`,
+// except that gray colors stay gray.
+macro_rules! impl_saturate_cartesian {
+    (
+        $ty: ident
+        {$($components: tt)+}
+        max_chroma: $get_max: expr
+        $(, where $($where: tt)+)?
+    ) => {
+        // add empty generics brackets
+        impl_saturate_cartesian!($ty<> {$($components)+} max_chroma: $get_max $(, where $($where)+)?);
+    };
+    (
+        $ty: ident <$($ty_param: ident),*>
+        {chroma1: $chroma1:ident, chroma2: $chroma2:ident $(,)? }
+        max_chroma: $get_max: expr
+        $(, where $($where: tt)+)?
+    ) => {
+        impl<$($ty_param,)* T> crate::Saturate for $ty<$($ty_param,)* T>
+        where
+            T: crate::num::Real
+                + crate::num::Zero
+                + crate::num::MinMax
+                + crate::num::Clamp
+                + crate::num::Hypot
+                + crate::num::IsValidDivisor
+                + crate::num::Arithmetics
+                + crate::num::PartialCmp
+                + Clone,
+            T::Mask: crate::bool_mask::LazySelect<T>,
+            $($($where)+)?
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn saturate(self, factor: T) -> Self {
+                let chroma = self.$chroma1.clone().hypot(self.$chroma2.clone());
+                let difference = lazy_select! {
+                    if factor.gt_eq(&T::zero()) => $get_max - &chroma,
+                    else => chroma.clone(),
+                };
+                let new_chroma = chroma.clone() + difference.max(T::zero()) * factor;
+
+                impl_saturate_cartesian!(@scale self, chroma, new_chroma, $get_max, $chroma1, $chroma2)
+            }
+
+            #[inline]
+            fn saturate_fixed(self, amount: T) -> Self {
+                let chroma = self.$chroma1.clone().hypot(self.$chroma2.clone());
+                let new_chroma = chroma.clone() + $get_max * amount;
+
+                impl_saturate_cartesian!(@scale self, chroma, new_chroma, $get_max, $chroma1, $chroma2)
+            }
+        }
+
+        impl<$($ty_param,)* T> crate::SaturateAssign for $ty<$($ty_param,)* T>
+        where
+            Self: crate::Saturate<Scalar = T> + Clone,
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn saturate_assign(&mut self, factor: T) {
+                *self = crate::Saturate::saturate(self.clone(), factor);
+            }
+
+            #[inline]
+            fn saturate_fixed_assign(&mut self, amount: T) {
+                *self = crate::Saturate::saturate_fixed(self.clone(), amount);
+            }
+        }
+
+        impl<$($ty_param,)* T> $ty<$($ty_param,)* T>
+        where
+            T: crate::num::Real
+                + crate::num::Zero
+                + crate::num::MinMax
+                + crate::num::Clamp
+                + crate::num::Hypot
+                + crate::num::IsValidDivisor
+                + crate::num::Arithmetics
+                + crate::num::PartialCmp
+                + Clone,
+            T::Mask: crate::bool_mask::LazySelect<T>,
+            $($($where)+)?
+        {
+            // Saturates the component slices of a struct of arrays, one pair
+            // of chroma components at the time.
+            #[inline]
+            fn saturate_slices_assign(factor: T, $chroma1: &mut [T], $chroma2: &mut [T]) {
+                let max_chroma = $get_max;
+
+                for ($chroma1, $chroma2) in $chroma1.iter_mut().zip($chroma2) {
+                    let chroma = $chroma1.clone().hypot($chroma2.clone());
+                    let difference = lazy_select! {
+                        if factor.gt_eq(&T::zero()) => max_chroma.clone() - &chroma,
+                        else => chroma.clone(),
+                    };
+                    let new_chroma = chroma.clone() + difference.max(T::zero()) * &factor;
+
+                    impl_saturate_cartesian!(@scale_assign $chroma1, $chroma2, chroma, new_chroma, max_chroma.clone());
+                }
+            }
+
+            #[inline]
+            fn saturate_fixed_slices_assign(amount: T, $chroma1: &mut [T], $chroma2: &mut [T]) {
+                let max_chroma = $get_max;
+                let delta = max_chroma.clone() * amount;
+
+                for ($chroma1, $chroma2) in $chroma1.iter_mut().zip($chroma2) {
+                    let chroma = $chroma1.clone().hypot($chroma2.clone());
+                    let new_chroma = chroma.clone() + delta.clone();
+
+                    impl_saturate_cartesian!(@scale_assign $chroma1, $chroma2, chroma, new_chroma, max_chroma.clone());
+                }
+            }
+        }
+
+        impl_saturate_cartesian!(@struct_of_arrays $ty<$($ty_param),*> [$chroma1, $chroma2] impl['a,] &'a mut [T] $(where $($where)+)?);
+        impl_saturate_cartesian!(@struct_of_arrays $ty<$($ty_param),*> [$chroma1, $chroma2] impl[const N: usize,] [T; N] $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_saturate_cartesian!(@struct_of_arrays $ty<$($ty_param),*> [$chroma1, $chroma2] impl[] alloc::vec::Vec<T> $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_saturate_cartesian!(@struct_of_arrays $ty<$($ty_param),*> [$chroma1, $chroma2] impl[] alloc::boxed::Box<[T]> $(where $($where)+)?);
+    };
+    (
+        @struct_of_arrays $ty: ident <$($ty_param: ident),*> [$chroma1:ident, $chroma2:ident]
+        impl[$($generic: tt)*] $container: ty
+        $(where $($where: tt)+)?
+    ) => {
+        impl<$($generic)* $($ty_param,)* T> crate::SaturateAssign for $ty<$($ty_param,)* $container>
+        where
+            T: crate::num::Real
+                + crate::num::Zero
+                + crate::num::MinMax
+                + crate::num::Clamp
+                + crate::num::Hypot
+                + crate::num::IsValidDivisor
+                + crate::num::Arithmetics
+                + crate::num::PartialCmp
+                + Clone,
+            T::Mask: crate::bool_mask::LazySelect<T>,
+            $($($where)+)?
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn saturate_assign(&mut self, factor: T) {
+                $ty::<$($ty_param,)* T>::saturate_slices_assign(factor, &mut self.$chroma1, &mut self.$chroma2);
+            }
+
+            #[inline]
+            fn saturate_fixed_assign(&mut self, amount: T) {
+                $ty::<$($ty_param,)* T>::saturate_fixed_slices_assign(amount, &mut self.$chroma1, &mut self.$chroma2);
+            }
+        }
+    };
+    (@scale_assign $chroma1: ident, $chroma2: ident, $chroma: ident, $new_chroma: ident, $get_max: expr) => {{
+        let new_chroma = crate::clamp($new_chroma, T::zero(), $get_max);
+        let scale = lazy_select! {
+            if $chroma.is_valid_divisor() => new_chroma / &$chroma,
+            else => T::zero(),
+        };
+
+        *$chroma1 = $chroma1.clone() * &scale;
+        *$chroma2 = $chroma2.clone() * scale;
+    }};
+    (@scale $self: ident, $chroma: ident, $new_chroma: ident, $get_max: expr, $chroma1:ident, $chroma2:ident) => {{
+        let new_chroma = crate::clamp($new_chroma, T::zero(), $get_max);
+        let scale = lazy_select! {
+            if $chroma.is_valid_divisor() => new_chroma / &$chroma,
+            else => T::zero(),
+        };
+
+        Self {
+            $chroma1: $self.$chroma1 * &scale,
+            $chroma2: $self.$chroma2 * scale,
+            ..$self
+        }
+    }};
+}
+
+// Implements `Whiten` and `Blacken` for the whiteness and blackness
+// components, and `Lighten` as whitening and blackening in opposite
+// directions.
 macro_rules! impl_lighten_hwb {
     (
         $ty: ident
@@ -129,11 +419,34 @@ macro_rules! impl_lighten_hwb {
         $(phantom: $phantom: ident)?
         $(where $($where: tt)+)?
     ) => {
+        _impl_increase_value_trait!(
+            Whiten::{whiten, whiten_fixed},
+            WhitenAssign::{whiten_assign, whiten_fixed_assign},
+            whiten_slices_assign, whiten_fixed_slices_assign,
+            $ty<$($ty_param),*>
+            increase {whiteness => [Self::min_whiteness(), Self::max_whiteness()]}
+            other {hue, blackness}
+            $(phantom: $phantom)?
+            $(where $($where)+)?
+        );
+
+        _impl_increase_value_trait!(
+            Blacken::{blacken, blacken_fixed},
+            BlackenAssign::{blacken_assign, blacken_fixed_assign},
+            blacken_slices_assign, blacken_fixed_slices_assign,
+            $ty<$($ty_param),*>
+            increase {blackness => [Self::min_blackness(), Self::max_blackness()]}
+            other {hue, whiteness}
+            $(phantom: $phantom)?
+            $(where $($where)+)?
+        );
+
         impl<$($ty_param,)* T> crate::Lighten for $ty<$($ty_param,)* T>
         where
             T: crate::num::Real
                 + crate::num::Zero
                 + crate::num::MinMax
+                + crate::num::Clamp
                 + crate::num::Arithmetics
                 + crate::num::PartialCmp
                 + Clone,
@@ -144,35 +457,16 @@ macro_rules! impl_lighten_hwb {
 
             #[inline]
             fn lighten(self, factor: T) -> Self {
-                let difference_whiteness = lazy_select! {
-                    if factor.gt_eq(&T::zero()) => Self::max_whiteness() - &self.whiteness,
-                    else => self.whiteness.clone(),
-                };
-                let delta_whiteness = difference_whiteness.max(T::zero()) * &factor;
-
-                let difference_blackness = lazy_select! {
-                    if factor.gt_eq(&T::zero()) => self.blackness.clone(),
-                    else => Self::max_blackness() - &self.blackness,
-                };
-                let delta_blackness = difference_blackness.max(T::zero()) * factor;
+                use crate::{Blacken, Whiten};
 
-                Self {
-                    hue: self.hue,
-                    whiteness: (self.whiteness + delta_whiteness).max(Self::min_whiteness()),
-                    blackness: (self.blackness - delta_blackness).max(Self::min_blackness()),
-                    $($phantom: PhantomData,)?
-                }
+                self.whiten(factor.clone()).blacken(T::zero() - factor)
             }
 
             #[inline]
             fn lighten_fixed(self, amount: T) -> Self {
-                Self {
-                    hue: self.hue,
-                    whiteness: (self.whiteness + Self::max_whiteness() * &amount)
-                        .max(Self::min_whiteness()),
-                    blackness: (self.blackness - Self::max_blackness() * amount).max(Self::min_blackness()),
-                    $($phantom: PhantomData,)?
-                }
+                use crate::{Blacken, Whiten};
+
+                self.whiten_fixed(amount.clone()).blacken_fixed(T::zero() - amount)
             }
         }
 
@@ -183,7 +477,6 @@ macro_rules! impl_lighten_hwb {
                 + crate::num::MinMax
                 + crate::num::ClampAssign
                 + core::ops::AddAssign
-                + core::ops::SubAssign
                 + crate::num::Arithmetics
                 + crate::num::PartialCmp
                 + Clone,
@@ -194,29 +487,150 @@ macro_rules! impl_lighten_hwb {
 
             #[inline]
             fn lighten_assign(&mut self, factor: T) {
-                let difference_whiteness = lazy_select! {
-                    if factor.gt_eq(&T::zero()) => Self::max_whiteness() - &self.whiteness,
-                    else => self.whiteness.clone(),
-                };
-                self.whiteness += difference_whiteness.max(T::zero()) * &factor;
-                crate::clamp_min_assign(&mut self.whiteness, Self::min_whiteness());
+                use crate::{BlackenAssign, WhitenAssign};
 
-                let difference_blackness = lazy_select! {
-                    if factor.gt_eq(&T::zero()) => self.blackness.clone(),
-                    else => Self::max_blackness() - &self.blackness,
-                };
-                self.blackness -= difference_blackness.max(T::zero()) * factor;
-                crate::clamp_min_assign(&mut self.blackness, Self::min_blackness());
+                self.whiten_assign(factor.clone());
+                self.blacken_assign(T::zero() - factor);
+            }
+
+            #[inline]
+            fn lighten_fixed_assign(&mut self, amount: T) {
+                use crate::{BlackenAssign, WhitenAssign};
+
+                self.whiten_fixed_assign(amount.clone());
+                self.blacken_fixed_assign(T::zero() - amount);
+            }
+        }
+
+        impl_lighten_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl['a,] &'a mut [T] $(where $($where)+)?);
+        impl_lighten_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl[const N: usize,] [T; N] $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_lighten_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl[] alloc::vec::Vec<T> $(where $($where)+)?);
+        #[cfg(feature = "alloc")]
+        impl_lighten_hwb!(@struct_of_arrays $ty<$($ty_param),*> impl[] alloc::boxed::Box<[T]> $(where $($where)+)?);
+    };
+    (
+        @struct_of_arrays $ty: ident <$($ty_param: ident),*>
+        impl[$($generic: tt)*] $container: ty
+        $(where $($where: tt)+)?
+    ) => {
+        impl<$($generic)* $($ty_param,)* T> crate::LightenAssign for $ty<$($ty_param,)* $container>
+        where
+            T: crate::num::Real
+                + crate::num::Zero
+                + crate::num::MinMax
+                + crate::num::ClampAssign
+                + core::ops::AddAssign
+                + crate::num::Arithmetics
+                + crate::num::PartialCmp
+                + Clone,
+            T::Mask: LazySelect<T>,
+            $($($where)+)?
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn lighten_assign(&mut self, factor: T) {
+                $ty::<$($ty_param,)* T>::whiten_slices_assign(factor.clone(), &mut self.whiteness);
+                $ty::<$($ty_param,)* T>::blacken_slices_assign(T::zero() - factor, &mut self.blackness);
             }
 
             #[inline]
             fn lighten_fixed_assign(&mut self, amount: T) {
-                self.whiteness += Self::max_whiteness() * &amount;
-                crate::clamp_min_assign(&mut self.whiteness, Self::min_whiteness());
+                $ty::<$($ty_param,)* T>::whiten_fixed_slices_assign(amount.clone(), &mut self.whiteness);
+                $ty::<$($ty_param,)* T>::blacken_fixed_slices_assign(T::zero() - amount, &mut self.blackness);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+macro_rules! test_saturate_cartesian {
+    ($color_ty: ident / $radial_ty: ident [$($color: expr),+], gray: $gray: expr) => {
+        #[cfg(feature = "approx")]
+        #[test]
+        fn saturate_like_radial() {
+            use crate::{convert::FromColorUnclamped, Saturate, SaturateAssign};
+
+            for color in [$($color),+] {
+                let radial = $radial_ty::from_color_unclamped(color);
+
+                for factor in [-1.0, -0.5, -0.1, 0.0, 0.1, 0.5, 1.0] {
+                    let saturated = color.saturate(factor);
+                    assert_relative_eq!(
+                        saturated,
+                        $color_ty::from_color_unclamped(radial.saturate(factor)),
+                        epsilon = 0.000000001
+                    );
 
-                self.blackness -= Self::max_blackness() * amount;
-                crate::clamp_min_assign(&mut self.blackness, Self::min_blackness());
+                    let saturated_fixed = color.saturate_fixed(factor);
+                    assert_relative_eq!(
+                        saturated_fixed,
+                        $color_ty::from_color_unclamped(radial.saturate_fixed(factor)),
+                        epsilon = 0.000000001
+                    );
+
+                    let mut assigned = color;
+                    assigned.saturate_assign(factor);
+                    assert_eq!(assigned, saturated);
+
+                    let mut assigned = color;
+                    assigned.saturate_fixed_assign(factor);
+                    assert_eq!(assigned, saturated_fixed);
+                }
             }
         }
+
+        #[test]
+        fn saturate_gray() {
+            use crate::{Desaturate, Saturate};
+
+            let gray = $gray;
+            assert_eq!(gray.saturate(0.5), gray);
+            assert_eq!(gray.saturate_fixed(0.5), gray);
+            assert_eq!(gray.desaturate(0.5), gray);
+        }
+    };
+}
+
+#[cfg(test)]
+macro_rules! test_lighten_lightness {
+    ($ty: ident <$wp: ty> {$component: ident} new($($arg: expr),+)) => {
+        // The colors are expected to have a lightness of 50.
+        #[test]
+        fn lighten_uses_max_lightness() {
+            use crate::{Darken, Lighten, LightenAssign};
+
+            let color = $ty::<$wp, f32>::new($($arg),+);
+            assert_eq!(color.lighten(0.5).$component, 75.0);
+            assert_eq!(color.darken(0.5).$component, 25.0);
+            assert_eq!(color.lighten_fixed(0.25).$component, 75.0);
+
+            let color = $ty::<$wp, f64>::new($($arg),+);
+            assert_eq!(color.lighten(0.5).$component, 75.0);
+            assert_eq!(color.darken(0.5).$component, 25.0);
+            assert_eq!(color.lighten_fixed(0.25).$component, 75.0);
+
+            let mut color = $ty::<$wp, f64>::new($($arg),+);
+            color.lighten_assign(0.5);
+            assert_eq!(color.$component, 75.0);
+        }
+
+        #[cfg(feature = "wide")]
+        #[test]
+        fn lighten_uses_max_lightness_wide() {
+            use crate::{Darken, Lighten};
+
+            let color = $ty::<$wp, wide::f32x4>::new(
+                $(wide::f32x4::splat($arg)),+
+            );
+            assert_eq!(color.lighten(wide::f32x4::splat(0.5)).$component, wide::f32x4::splat(75.0));
+            assert_eq!(color.darken(wide::f32x4::splat(0.5)).$component, wide::f32x4::splat(25.0));
+
+            let color = $ty::<$wp, wide::f64x4>::new(
+                $(wide::f64x4::splat($arg)),+
+            );
+            assert_eq!(color.lighten(wide::f64x4::splat(0.5)).$component, wide::f64x4::splat(75.0));
+        }
     };
 }
@@ -0,0 +1,20 @@
+macro_rules! impl_select {
+    (  $self_ty: ident , [$($element: ident),+] $(, $phantom: ident)?) => {
+        impl_select!($self_ty<>, [$($element),+] $(, $phantom)?);
+    };
+    (  $self_ty: ident < $($phantom_ty: ident)? > , [$($element: ident),+] $(, $phantom: ident)?) => {
+        impl<$($phantom_ty,)? T> crate::bool_mask::Selectable for $self_ty<$($phantom_ty,)? T>
+        where
+            T: crate::bool_mask::Selectable,
+            T::Mask: Clone,
+        {
+            #[inline]
+            fn select(mask: T::Mask, a: Self, b: Self) -> Self {
+                $self_ty {
+                    $($element: crate::bool_mask::Selectable::select(mask.clone(), a.$element, b.$element),)*
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+        }
+    }
+}
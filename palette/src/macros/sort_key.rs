@@ -0,0 +1,48 @@
+/// Implement `sort_key_*` methods for the listed attributes.
+macro_rules! impl_sort_keys {
+    ($self_ty: ident {$($attribute: ident $(: $field: ident)?),+}) => {
+        impl_sort_keys!($self_ty<> {$($attribute $(: $field)?),+});
+    };
+    ($self_ty: ident < $($ty_param: ident),* > {$($attribute: ident $(: $field: ident)?),+}) => {
+        impl<$($ty_param,)* T> $self_ty<$($ty_param,)* T> {
+            $(
+                impl_sort_keys!(@method $attribute $(: $field)?);
+            )+
+        }
+    };
+    (@method lightness: $field: ident) => {
+        /// Get the lightness as a sorting key, with a total ordering that
+        /// handles NaN. See [`TotalOrder`](crate::num::TotalOrder) for more
+        /// details.
+        #[inline]
+        pub fn sort_key_lightness(&self) -> crate::num::TotalOrder<T>
+        where
+            T: Clone,
+        {
+            crate::num::TotalOrder(self.$field.clone())
+        }
+    };
+    (@method chroma: $field: ident) => {
+        /// Get the chroma as a sorting key, with a total ordering that handles
+        /// NaN. See [`TotalOrder`](crate::num::TotalOrder) for more details.
+        #[inline]
+        pub fn sort_key_chroma(&self) -> crate::num::TotalOrder<T>
+        where
+            T: Clone,
+        {
+            crate::num::TotalOrder(self.$field.clone())
+        }
+    };
+    (@method hue) => {
+        /// Get the hue, in positive degrees, as a sorting key, with a total
+        /// ordering that handles NaN. See [`TotalOrder`](crate::num::TotalOrder)
+        /// for more details.
+        #[inline]
+        pub fn sort_key_hue(&self) -> crate::num::TotalOrder<T>
+        where
+            T: crate::angle::RealAngle + crate::angle::UnsignedAngle + Clone,
+        {
+            crate::num::TotalOrder(self.hue.clone().into_positive_degrees())
+        }
+    };
+}
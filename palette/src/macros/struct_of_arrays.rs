@@ -1,9 +1,11 @@
+#[cfg(feature = "alloc")]
 macro_rules! first {
     (($($first: tt)+) $(, ($($rest: tt)+))*) => {
         $($first)+
     };
 }
 
+#[cfg(feature = "alloc")]
 macro_rules! skip_first {
     (($($first: tt)+) $(, ($($rest: tt)+))*) => {
         $($($rest)+)*
@@ -61,11 +63,11 @@ macro_rules! impl_struct_of_array_traits {
             }
         }
 
-        impl<$($phantom_ty,)? T, const N: usize> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [T; N]>
+        impl<$($phantom_ty,)? T, A, const N: usize> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [A; N]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? T>, T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? T>, A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::array::IntoIter<T, N> $(,$phantom_ty)?>, core::array::IntoIter<T, N>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::array::IntoIter<T, N> $(,$phantom_ty)?>, core::array::IntoIter<A, N>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -90,11 +92,11 @@ macro_rules! impl_struct_of_array_traits {
             }
         }
 
-        impl<'a, $($phantom_ty,)? T> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a [T]>, &'a [T]>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a [T]>, &'a [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -119,11 +121,11 @@ macro_rules! impl_struct_of_array_traits {
             }
         }
 
-        impl<'a, $($phantom_ty,)? T> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut [T]>, &'a mut [T]>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut [T]>, &'a mut [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -150,11 +152,11 @@ macro_rules! impl_struct_of_array_traits {
         }
 
         #[cfg(feature = "alloc")]
-        impl<$($phantom_ty,)? T> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<T>>
+        impl<$($phantom_ty,)? T, A> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<A>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? T>, T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? T>, A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<alloc::vec::IntoIter<T> $(,$phantom_ty)?>, alloc::vec::IntoIter<T>>;
+            type IntoIter = crate::alpha::Iter<Iter<alloc::vec::IntoIter<T> $(,$phantom_ty)?>, alloc::vec::IntoIter<A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -179,11 +181,11 @@ macro_rules! impl_struct_of_array_traits {
             }
         }
 
-        impl<'a, $($phantom_ty,)? T, const N: usize> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [T; N]>
+        impl<'a, $($phantom_ty,)? T, A, const N: usize> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [A; N]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -208,11 +210,11 @@ macro_rules! impl_struct_of_array_traits {
             }
         }
 
-        impl<'a, 'b, $($phantom_ty,)? T> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b [T]>, &'b [T]>
+        impl<'a, 'b, $($phantom_ty,)? T, A> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b [T]>, &'b [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -237,11 +239,11 @@ macro_rules! impl_struct_of_array_traits {
             }
         }
 
-        impl<'a, 'b, $($phantom_ty,)? T> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b mut [T]>, &'b mut [T]>
+        impl<'a, 'b, $($phantom_ty,)? T, A> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b mut [T]>, &'b mut [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -268,11 +270,11 @@ macro_rules! impl_struct_of_array_traits {
         }
 
         #[cfg(feature = "alloc")]
-        impl<'a, $($phantom_ty,)? T> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<T>>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<A>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -299,11 +301,11 @@ macro_rules! impl_struct_of_array_traits {
         }
 
         #[cfg(feature = "alloc")]
-        impl<'a, $($phantom_ty,)? T> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::boxed::Box<[T]>>, alloc::boxed::Box<[T]>>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::boxed::Box<[T]>>, alloc::boxed::Box<[A]>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -328,11 +330,11 @@ macro_rules! impl_struct_of_array_traits {
             }
         }
 
-        impl<'a, $($phantom_ty,)? T, const N: usize> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [T; N]>
+        impl<'a, $($phantom_ty,)? T, A, const N: usize> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [A; N]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -357,11 +359,11 @@ macro_rules! impl_struct_of_array_traits {
             }
         }
 
-        impl<'a, 'b, $($phantom_ty,)? T> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b mut [T]>, &'b mut [T]>
+        impl<'a, 'b, $($phantom_ty,)? T, A> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b mut [T]>, &'b mut [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -388,11 +390,11 @@ macro_rules! impl_struct_of_array_traits {
         }
 
         #[cfg(feature = "alloc")]
-        impl<'a, $($phantom_ty,)? T> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<T>>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<A>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -421,11 +423,11 @@ macro_rules! impl_struct_of_array_traits {
         }
 
         #[cfg(feature = "alloc")]
-        impl<'a, $($phantom_ty,)? T> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::boxed::Box<[T]>>, alloc::boxed::Box<[T]>>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::boxed::Box<[T]>>, alloc::boxed::Box<[A]>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -463,18 +465,15 @@ macro_rules! impl_struct_of_array_traits {
 
             #[inline(always)]
             fn size_hint(&self) -> (usize, Option<usize>) {
-                let hint = first!($((self.$element)),+).size_hint();
-                skip_first!($((debug_assert_eq!(self.$element.size_hint(), hint, "the component iterators have different size hints");)),+);
+                let hint = (usize::MAX, None);
+                $(let hint = crate::zip_size_hints(hint, self.$element.size_hint());)+
 
                 hint
             }
 
             #[inline(always)]
             fn count(self) -> usize {
-                let count = first!($((self.$element)),+).count();
-                skip_first!($((debug_assert_eq!(self.$element.count(), count, "the component iterators have different counts");)),+);
-
-                count
+                usize::MAX $(.min(self.$element.count()))+
             }
         }
 
@@ -484,6 +483,10 @@ macro_rules! impl_struct_of_array_traits {
         {
             #[inline(always)]
             fn next_back(&mut self) -> Option<Self::Item> {
+                if let Some(len) = crate::exact_len(self.size_hint()) {
+                    $(crate::trim_back(&mut self.$element, len);)+
+                }
+
                 $(let $element = self.$element.next_back();)+
 
                 if let ($(Some($element),)+) = ($($element,)+) {
@@ -503,12 +506,15 @@ macro_rules! impl_struct_of_array_traits {
         {
             #[inline(always)]
             fn len(&self) -> usize {
-                let len = first!($((self.$element)),+).len();
-                skip_first!($((debug_assert_eq!(self.$element.len(), len, "the component iterators have different lengths");)),+);
-
-                len
+                usize::MAX $(.min(self.$element.len()))+
             }
         }
+
+        impl<I $(,$phantom_ty)?> core::iter::FusedIterator for Iter<I $(,$phantom_ty)?>
+        where
+            I: core::iter::FusedIterator,
+        {
+        }
     }
 }
 
@@ -566,11 +572,11 @@ macro_rules! impl_struct_of_array_traits_hue {
             }
         }
 
-        impl<$($phantom_ty,)? T, const N: usize> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [T; N]>
+        impl<$($phantom_ty,)? T, A, const N: usize> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [A; N]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? T>, T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? T>, A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::array::IntoIter<T, N> $(,$phantom_ty)?>, core::array::IntoIter<T, N>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::array::IntoIter<T, N> $(,$phantom_ty)?>, core::array::IntoIter<A, N>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -596,11 +602,11 @@ macro_rules! impl_struct_of_array_traits_hue {
             }
         }
 
-        impl<'a, $($phantom_ty,)? T> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a [T]>, &'a [T]>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a [T]>, &'a [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -626,11 +632,11 @@ macro_rules! impl_struct_of_array_traits_hue {
             }
         }
 
-        impl<'a, $($phantom_ty,)? T> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut [T]>, &'a mut [T]>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut [T]>, &'a mut [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -658,11 +664,11 @@ macro_rules! impl_struct_of_array_traits_hue {
         }
 
         #[cfg(feature = "alloc")]
-        impl<$($phantom_ty,)? T> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<T>>
+        impl<$($phantom_ty,)? T, A> IntoIterator for crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<A>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? T>, T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? T>, A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<alloc::vec::IntoIter<T> $(,$phantom_ty)?>, alloc::vec::IntoIter<T>>;
+            type IntoIter = crate::alpha::Iter<Iter<alloc::vec::IntoIter<T> $(,$phantom_ty)?>, alloc::vec::IntoIter<A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -688,11 +694,11 @@ macro_rules! impl_struct_of_array_traits_hue {
             }
         }
 
-        impl<'a, $($phantom_ty,)? T, const N: usize> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [T; N]>
+        impl<'a, $($phantom_ty,)? T, A, const N: usize> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [A; N]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -718,11 +724,11 @@ macro_rules! impl_struct_of_array_traits_hue {
             }
         }
 
-        impl<'a, 'b, $($phantom_ty,)? T> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b [T]>, &'b [T]>
+        impl<'a, 'b, $($phantom_ty,)? T, A> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b [T]>, &'b [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -748,11 +754,11 @@ macro_rules! impl_struct_of_array_traits_hue {
             }
         }
 
-        impl<'a, 'b, $($phantom_ty,)? T> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b mut [T]>, &'b mut [T]>
+        impl<'a, 'b, $($phantom_ty,)? T, A> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b mut [T]>, &'b mut [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -780,11 +786,11 @@ macro_rules! impl_struct_of_array_traits_hue {
         }
 
         #[cfg(feature = "alloc")]
-        impl<'a, $($phantom_ty,)? T> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<T>>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<A>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -812,11 +818,11 @@ macro_rules! impl_struct_of_array_traits_hue {
         }
 
         #[cfg(feature = "alloc")]
-        impl<'a, $($phantom_ty,)? T> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::boxed::Box<[T]>>, alloc::boxed::Box<[T]>>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for &'a crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::boxed::Box<[T]>>, alloc::boxed::Box<[A]>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a T>, &'a A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::Iter<'a, T> $(,$phantom_ty)?>, core::slice::Iter<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -842,11 +848,11 @@ macro_rules! impl_struct_of_array_traits_hue {
             }
         }
 
-        impl<'a, $($phantom_ty,)? T, const N: usize> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [T; N]>
+        impl<'a, $($phantom_ty,)? T, A, const N: usize> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? [T; N]>, [A; N]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -872,11 +878,11 @@ macro_rules! impl_struct_of_array_traits_hue {
             }
         }
 
-        impl<'a, 'b, $($phantom_ty,)? T> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b mut [T]>, &'b mut [T]>
+        impl<'a, 'b, $($phantom_ty,)? T, A> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'b mut [T]>, &'b mut [A]>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -904,11 +910,11 @@ macro_rules! impl_struct_of_array_traits_hue {
         }
 
         #[cfg(feature = "alloc")]
-        impl<'a, $($phantom_ty,)? T> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<T>>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::vec::Vec<T>>, alloc::vec::Vec<A>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -936,11 +942,11 @@ macro_rules! impl_struct_of_array_traits_hue {
         }
 
         #[cfg(feature = "alloc")]
-        impl<'a, $($phantom_ty,)? T> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::boxed::Box<[T]>>, alloc::boxed::Box<[T]>>
+        impl<'a, $($phantom_ty,)? T, A> IntoIterator for &'a mut crate::alpha::Alpha<$self_ty<$($phantom_ty,)? alloc::boxed::Box<[T]>>, alloc::boxed::Box<[A]>>
         {
-            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut T>;
+            type Item = crate::alpha::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>;
 
-            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, T>>;
+            type IntoIter = crate::alpha::Iter<Iter<core::slice::IterMut<'a, T> $(,$phantom_ty)?>, core::slice::IterMut<'a, A>>;
 
             fn into_iter(self) -> Self::IntoIter {
                 crate::alpha::Iter {
@@ -978,17 +984,14 @@ macro_rules! impl_struct_of_array_traits_hue {
             #[inline(always)]
             fn size_hint(&self) -> (usize, Option<usize>) {
                 let hint = self.hue.size_hint();
-                $(debug_assert_eq!(self.$element.size_hint(), hint, "the component iterators have different size hints");)+
+                $(let hint = crate::zip_size_hints(hint, self.$element.size_hint());)+
 
                 hint
             }
 
             #[inline(always)]
             fn count(self) -> usize {
-                let count = self.hue.count();
-                $(debug_assert_eq!(self.$element.count(), count, "the component iterators have different counts");)+
-
-                count
+                self.hue.count() $(.min(self.$element.count()))+
             }
         }
 
@@ -998,6 +1001,11 @@ macro_rules! impl_struct_of_array_traits_hue {
         {
             #[inline(always)]
             fn next_back(&mut self) -> Option<Self::Item> {
+                if let Some(len) = crate::exact_len(self.size_hint()) {
+                    crate::trim_back(&mut self.hue, len);
+                    $(crate::trim_back(&mut self.$element, len);)+
+                }
+
                 let hue = self.hue.next_back();
                 $(let $element = self.$element.next_back();)+
 
@@ -1015,12 +1023,15 @@ macro_rules! impl_struct_of_array_traits_hue {
         {
             #[inline(always)]
             fn len(&self) -> usize {
-                let len = self.hue.len();
-                $(debug_assert_eq!(self.$element.len(), len, "the component iterators have different lengths");)+
-
-                len
+                self.hue.len() $(.min(self.$element.len()))+
             }
         }
+
+        impl<I $(,$phantom_ty)?> core::iter::FusedIterator for Iter<I $(,$phantom_ty)?>
+        where
+            I: core::iter::FusedIterator,
+        {
+        }
     }
 }
 
@@ -1081,6 +1092,51 @@ macro_rules! impl_struct_of_arrays_methods {
                     None
                 }
             }
+
+            /// Get a color with slices of all the components.
+            #[inline(always)]
+            pub fn as_slices<T>(&self) -> $self_ty<$($phantom_ty,)? &[T]>
+            where
+                C: AsRef<[T]>,
+            {
+                $self_ty {
+                    $($element: self.$element.as_ref(),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+
+            /// Get a color with mutable slices of all the components.
+            #[inline(always)]
+            pub fn as_mut_slices<T>(&mut self) -> $self_ty<$($phantom_ty,)? &mut [T]>
+            where
+                C: AsMut<[T]>,
+            {
+                $self_ty {
+                    $($element: self.$element.as_mut(),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl<$($phantom_ty,)? C> $self_ty<$($phantom_ty,)? C> {
+            /// Return a parallel iterator that allows modifying the colors in the wrapped collections.
+            #[inline(always)]
+            pub fn par_iter_mut<'a, T>(&'a mut self) -> impl rayon::iter::IndexedParallelIterator<Item = $self_ty<$($phantom_ty,)? &'a mut T>> + 'a
+            where
+                T: Send + 'a,
+                C: AsMut<[T]>,
+                $($phantom_ty: Send + 'a,)?
+            {
+                use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+                ($(self.$element.as_mut(),)+)
+                    .into_par_iter()
+                    .map(|($($element,)+)| $self_ty {
+                        $($element,)+
+                        $($phantom: core::marker::PhantomData,)?
+                    })
+            }
         }
 
         #[cfg(feature = "alloc")]
@@ -1130,6 +1186,85 @@ macro_rules! impl_struct_of_arrays_methods {
                     $($phantom: core::marker::PhantomData,)?
                 }
             }
+
+            /// Create a struct of vectors from a slice of colors, by copying
+            /// each component into its own vector.
+            ///
+            /// The slice of colors can be a zero-copy view of a flat buffer of
+            /// components, made with [`cast::from_component_slice`](crate::cast::from_component_slice).
+            #[inline]
+            pub fn from_interleaved(colors: &[$self_ty<$($phantom_ty,)? T>]) -> Self
+            where
+                T: Clone,
+            {
+                $(
+                    let mut $element = alloc::vec::Vec::with_capacity(colors.len());
+                    $element.extend(colors.iter().map(|color| color.$element.clone()));
+                )+
+
+                $self_ty {
+                    $($element,)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+
+            /// Convert the struct of vectors into a vector of colors.
+            #[inline]
+            pub fn into_interleaved(self) -> alloc::vec::Vec<$self_ty<$($phantom_ty,)? T>> {
+                let mut colors = alloc::vec::Vec::with_capacity(self.len());
+                colors.extend(self);
+                colors
+            }
+
+            /// Return the number of colors in the component vectors. See [`Vec::len`] for details.
+            #[inline(always)]
+            pub fn len(&self) -> usize {
+                let len = first!($((self.$element)),+).len();
+                skip_first!($((debug_assert_eq!(self.$element.len(), len, "the component vectors have different lengths");)),+);
+                len
+            }
+
+            /// Check if the component vectors are empty. See [`Vec::is_empty`] for details.
+            #[inline(always)]
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Reserve capacity for at least `additional` more colors in the component vectors. See [`Vec::reserve`] for details.
+            #[inline(always)]
+            pub fn reserve(&mut self, additional: usize) {
+                $(self.$element.reserve(additional);)+
+            }
+
+            /// Shorten the component vectors to `len` colors. See [`Vec::truncate`] for details.
+            #[inline(always)]
+            pub fn truncate(&mut self, len: usize) {
+                $(self.$element.truncate(len);)+
+            }
+
+            /// Insert a color's components at `index` in the component vectors. See [`Vec::insert`] for details.
+            #[inline(always)]
+            pub fn insert(&mut self, index: usize, value: $self_ty<$($phantom_ty,)? T>) {
+                $(self.$element.insert(index, value.$element);)+
+            }
+
+            /// Remove and return the color at `index` from the component vectors. See [`Vec::remove`] for details.
+            #[inline(always)]
+            pub fn remove(&mut self, index: usize) -> $self_ty<$($phantom_ty,)? T> {
+                $self_ty {
+                    $($element: self.$element.remove(index),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+
+            /// Remove and return the color at `index` from the component vectors, and replace it with the last color. See [`Vec::swap_remove`] for details.
+            #[inline(always)]
+            pub fn swap_remove(&mut self, index: usize) -> $self_ty<$($phantom_ty,)? T> {
+                $self_ty {
+                    $($element: self.$element.swap_remove(index),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
         }
 
         impl<$($phantom_ty,)? Ct, Ca> crate::Alpha<$self_ty<$($phantom_ty,)? Ct>, Ca> {
@@ -1178,6 +1313,53 @@ macro_rules! impl_struct_of_arrays_methods {
                     None
                 }
             }
+
+            /// Get a color with slices of all the components, including alpha.
+            #[inline(always)]
+            pub fn as_slices<T, A>(&self) -> crate::Alpha<$self_ty<$($phantom_ty,)? &[T]>, &[A]>
+            where
+                Ct: AsRef<[T]>,
+                Ca: AsRef<[A]>,
+            {
+                crate::Alpha {
+                    color: self.color.as_slices(),
+                    alpha: self.alpha.as_ref(),
+                }
+            }
+
+            /// Get a color with mutable slices of all the components, including alpha.
+            #[inline(always)]
+            pub fn as_mut_slices<T, A>(&mut self) -> crate::Alpha<$self_ty<$($phantom_ty,)? &mut [T]>, &mut [A]>
+            where
+                Ct: AsMut<[T]>,
+                Ca: AsMut<[A]>,
+            {
+                crate::Alpha {
+                    color: self.color.as_mut_slices(),
+                    alpha: self.alpha.as_mut(),
+                }
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl<$($phantom_ty,)? Ct, Ca> crate::Alpha<$self_ty<$($phantom_ty,)? Ct>, Ca> {
+            /// Return a parallel iterator that allows modifying the colors in the wrapped collections.
+            #[inline(always)]
+            pub fn par_iter_mut<'a, T, A>(&'a mut self) -> impl rayon::iter::IndexedParallelIterator<Item = crate::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>> + 'a
+            where
+                T: Send + 'a,
+                A: Send + 'a,
+                Ct: AsMut<[T]>,
+                Ca: AsMut<[A]>,
+                $($phantom_ty: Send + 'a,)?
+            {
+                use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+                self.color
+                    .par_iter_mut()
+                    .zip(self.alpha.as_mut().into_par_iter())
+                    .map(|(color, alpha)| crate::Alpha { color, alpha })
+            }
         }
 
         #[cfg(feature = "alloc")]
@@ -1228,6 +1410,99 @@ macro_rules! impl_struct_of_arrays_methods {
                     alpha: self.alpha.drain(range),
                 }
             }
+
+            /// Create a struct of vectors from a slice of colors, by copying
+            /// each component into its own vector.
+            ///
+            /// The slice of colors can be a zero-copy view of a flat buffer of
+            /// components, made with [`cast::from_component_slice`](crate::cast::from_component_slice).
+            #[inline]
+            pub fn from_interleaved(colors: &[crate::Alpha<$self_ty<$($phantom_ty,)? T>, A>]) -> Self
+            where
+                T: Clone,
+                A: Clone,
+            {
+                $(
+                    let mut $element = alloc::vec::Vec::with_capacity(colors.len());
+                    $element.extend(colors.iter().map(|color| color.color.$element.clone()));
+                )+
+                let mut alpha = alloc::vec::Vec::with_capacity(colors.len());
+                alpha.extend(colors.iter().map(|color| color.alpha.clone()));
+
+                crate::Alpha {
+                    color: $self_ty {
+                        $($element,)+
+                        $($phantom: core::marker::PhantomData,)?
+                    },
+                    alpha,
+                }
+            }
+
+            /// Convert the struct of vectors into a vector of colors.
+            #[inline]
+            pub fn into_interleaved(self) -> alloc::vec::Vec<crate::Alpha<$self_ty<$($phantom_ty,)? T>, A>> {
+                let mut colors = alloc::vec::Vec::with_capacity(self.len());
+                colors.extend(
+                    self.color
+                        .into_iter()
+                        .zip(self.alpha)
+                        .map(|(color, alpha)| crate::Alpha { color, alpha }),
+                );
+                colors
+            }
+
+            /// Return the number of colors in the component vectors. See [`Vec::len`] for details.
+            #[inline(always)]
+            pub fn len(&self) -> usize {
+                let len = self.color.len();
+                debug_assert_eq!(self.alpha.len(), len, "the color and alpha vectors have different lengths");
+                len
+            }
+
+            /// Check if the component vectors are empty. See [`Vec::is_empty`] for details.
+            #[inline(always)]
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Reserve capacity for at least `additional` more colors in the component vectors. See [`Vec::reserve`] for details.
+            #[inline(always)]
+            pub fn reserve(&mut self, additional: usize) {
+                self.color.reserve(additional);
+                self.alpha.reserve(additional);
+            }
+
+            /// Shorten the component vectors to `len` colors. See [`Vec::truncate`] for details.
+            #[inline(always)]
+            pub fn truncate(&mut self, len: usize) {
+                self.color.truncate(len);
+                self.alpha.truncate(len);
+            }
+
+            /// Insert a color's components at `index` in the component vectors. See [`Vec::insert`] for details.
+            #[inline(always)]
+            pub fn insert(&mut self, index: usize, value: crate::Alpha<$self_ty<$($phantom_ty,)? T>, A>) {
+                self.color.insert(index, value.color);
+                self.alpha.insert(index, value.alpha);
+            }
+
+            /// Remove and return the color at `index` from the component vectors. See [`Vec::remove`] for details.
+            #[inline(always)]
+            pub fn remove(&mut self, index: usize) -> crate::Alpha<$self_ty<$($phantom_ty,)? T>, A> {
+                crate::Alpha {
+                    color: self.color.remove(index),
+                    alpha: self.alpha.remove(index),
+                }
+            }
+
+            /// Remove and return the color at `index` from the component vectors, and replace it with the last color. See [`Vec::swap_remove`] for details.
+            #[inline(always)]
+            pub fn swap_remove(&mut self, index: usize) -> crate::Alpha<$self_ty<$($phantom_ty,)? T>, A> {
+                crate::Alpha {
+                    color: self.color.swap_remove(index),
+                    alpha: self.alpha.swap_remove(index),
+                }
+            }
         }
     };
 }
@@ -1285,6 +1560,56 @@ macro_rules! impl_struct_of_arrays_methods_hue {
                     None
                 }
             }
+
+            /// Get a color with slices of all the components.
+            #[inline(always)]
+            pub fn as_slices<T>(&self) -> $self_ty<$($phantom_ty,)? &[T]>
+            where
+                C: AsRef<[T]>,
+            {
+                $self_ty {
+                    hue: self.hue.as_slice(),
+                    $($element: self.$element.as_ref(),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+
+            /// Get a color with mutable slices of all the components.
+            #[inline(always)]
+            pub fn as_mut_slices<T>(&mut self) -> $self_ty<$($phantom_ty,)? &mut [T]>
+            where
+                C: AsMut<[T]>,
+            {
+                $self_ty {
+                    hue: self.hue.as_mut_slice(),
+                    $($element: self.$element.as_mut(),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl<$($phantom_ty,)? C> $self_ty<$($phantom_ty,)? C> {
+            /// Return a parallel iterator that allows modifying the colors in the wrapped collections.
+            #[inline(always)]
+            pub fn par_iter_mut<'a, T>(&'a mut self) -> impl rayon::iter::IndexedParallelIterator<Item = $self_ty<$($phantom_ty,)? &'a mut T>> + 'a
+            where
+                T: Send + 'a,
+                C: AsMut<[T]>,
+                $($phantom_ty: Send + 'a,)?
+            {
+                use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+                let $self_ty {hue, $($element,)+ ..} = self.as_mut_slices();
+
+                (hue.into_inner(), $($element,)+)
+                    .into_par_iter()
+                    .map(|(hue, $($element,)+)| $self_ty {
+                        hue: hue.into(),
+                        $($element,)+
+                        $($phantom: core::marker::PhantomData,)?
+                    })
+            }
         }
 
         #[cfg(feature = "alloc")]
@@ -1337,6 +1662,93 @@ macro_rules! impl_struct_of_arrays_methods_hue {
                     $($phantom: core::marker::PhantomData,)?
                 }
             }
+
+            /// Create a struct of vectors from a slice of colors, by copying
+            /// each component into its own vector.
+            ///
+            /// The slice of colors can be a zero-copy view of a flat buffer of
+            /// components, made with [`cast::from_component_slice`](crate::cast::from_component_slice).
+            #[inline]
+            pub fn from_interleaved(colors: &[$self_ty<$($phantom_ty,)? T>]) -> Self
+            where
+                T: Clone,
+            {
+                let mut hue = alloc::vec::Vec::with_capacity(colors.len());
+                hue.extend(colors.iter().map(|color| color.hue.clone().into_inner()));
+                $(
+                    let mut $element = alloc::vec::Vec::with_capacity(colors.len());
+                    $element.extend(colors.iter().map(|color| color.$element.clone()));
+                )+
+
+                $self_ty {
+                    hue: hue.into(),
+                    $($element,)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+
+            /// Convert the struct of vectors into a vector of colors.
+            #[inline]
+            pub fn into_interleaved(self) -> alloc::vec::Vec<$self_ty<$($phantom_ty,)? T>> {
+                let mut colors = alloc::vec::Vec::with_capacity(self.len());
+                colors.extend(self);
+                colors
+            }
+
+            /// Return the number of colors in the component vectors. See [`Vec::len`] for details.
+            #[inline(always)]
+            pub fn len(&self) -> usize {
+                let len = self.hue.len();
+                $(debug_assert_eq!(self.$element.len(), len, "the component vectors have different lengths");)+
+                len
+            }
+
+            /// Check if the component vectors are empty. See [`Vec::is_empty`] for details.
+            #[inline(always)]
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Reserve capacity for at least `additional` more colors in the component vectors. See [`Vec::reserve`] for details.
+            #[inline(always)]
+            pub fn reserve(&mut self, additional: usize) {
+                self.hue.reserve(additional);
+                $(self.$element.reserve(additional);)+
+            }
+
+            /// Shorten the component vectors to `len` colors. See [`Vec::truncate`] for details.
+            #[inline(always)]
+            pub fn truncate(&mut self, len: usize) {
+                self.hue.truncate(len);
+                $(self.$element.truncate(len);)+
+            }
+
+            /// Insert a color's components at `index` in the component vectors. See [`Vec::insert`] for details.
+            #[inline(always)]
+            pub fn insert(&mut self, index: usize, value: $self_ty<$($phantom_ty,)? T>) {
+                self.hue.insert(index, value.hue);
+                $(self.$element.insert(index, value.$element);)+
+            }
+
+            /// Remove and return the color at `index` from the component vectors. See [`Vec::remove`] for details.
+            #[inline(always)]
+            pub fn remove(&mut self, index: usize) -> $self_ty<$($phantom_ty,)? T> {
+                $self_ty {
+                    hue: self.hue.remove(index),
+                    $($element: self.$element.remove(index),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
+
+            /// Remove and return the color at `index` from the component vectors, and replace it with the last color. See [`Vec::swap_remove`] for details.
+            #[inline(always)]
+            pub fn swap_remove(&mut self, index: usize) -> $self_ty<$($phantom_ty,)? T> {
+                $self_ty {
+                    hue: self.hue.swap_remove(index),
+                    $($element: self.$element.swap_remove(index),)+
+                    $($phantom: core::marker::PhantomData,)?
+                }
+            }
         }
 
         impl<$($phantom_ty,)? Ct, Ca> crate::Alpha<$self_ty<$($phantom_ty,)? Ct>, Ca> {
@@ -1385,6 +1797,53 @@ macro_rules! impl_struct_of_arrays_methods_hue {
                     None
                 }
             }
+
+            /// Get a color with slices of all the components, including alpha.
+            #[inline(always)]
+            pub fn as_slices<T, A>(&self) -> crate::Alpha<$self_ty<$($phantom_ty,)? &[T]>, &[A]>
+            where
+                Ct: AsRef<[T]>,
+                Ca: AsRef<[A]>,
+            {
+                crate::Alpha {
+                    color: self.color.as_slices(),
+                    alpha: self.alpha.as_ref(),
+                }
+            }
+
+            /// Get a color with mutable slices of all the components, including alpha.
+            #[inline(always)]
+            pub fn as_mut_slices<T, A>(&mut self) -> crate::Alpha<$self_ty<$($phantom_ty,)? &mut [T]>, &mut [A]>
+            where
+                Ct: AsMut<[T]>,
+                Ca: AsMut<[A]>,
+            {
+                crate::Alpha {
+                    color: self.color.as_mut_slices(),
+                    alpha: self.alpha.as_mut(),
+                }
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl<$($phantom_ty,)? Ct, Ca> crate::Alpha<$self_ty<$($phantom_ty,)? Ct>, Ca> {
+            /// Return a parallel iterator that allows modifying the colors in the wrapped collections.
+            #[inline(always)]
+            pub fn par_iter_mut<'a, T, A>(&'a mut self) -> impl rayon::iter::IndexedParallelIterator<Item = crate::Alpha<$self_ty<$($phantom_ty,)? &'a mut T>, &'a mut A>> + 'a
+            where
+                T: Send + 'a,
+                A: Send + 'a,
+                Ct: AsMut<[T]>,
+                Ca: AsMut<[A]>,
+                $($phantom_ty: Send + 'a,)?
+            {
+                use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+                self.color
+                    .par_iter_mut()
+                    .zip(self.alpha.as_mut().into_par_iter())
+                    .map(|(color, alpha)| crate::Alpha { color, alpha })
+            }
         }
 
         #[cfg(feature = "alloc")]
@@ -1435,6 +1894,102 @@ macro_rules! impl_struct_of_arrays_methods_hue {
                     alpha: self.alpha.drain(range),
                 }
             }
+
+            /// Create a struct of vectors from a slice of colors, by copying
+            /// each component into its own vector.
+            ///
+            /// The slice of colors can be a zero-copy view of a flat buffer of
+            /// components, made with [`cast::from_component_slice`](crate::cast::from_component_slice).
+            #[inline]
+            pub fn from_interleaved(colors: &[crate::Alpha<$self_ty<$($phantom_ty,)? T>, A>]) -> Self
+            where
+                T: Clone,
+                A: Clone,
+            {
+                let mut hue = alloc::vec::Vec::with_capacity(colors.len());
+                hue.extend(colors.iter().map(|color| color.color.hue.clone().into_inner()));
+                $(
+                    let mut $element = alloc::vec::Vec::with_capacity(colors.len());
+                    $element.extend(colors.iter().map(|color| color.color.$element.clone()));
+                )+
+                let mut alpha = alloc::vec::Vec::with_capacity(colors.len());
+                alpha.extend(colors.iter().map(|color| color.alpha.clone()));
+
+                crate::Alpha {
+                    color: $self_ty {
+                        hue: hue.into(),
+                        $($element,)+
+                        $($phantom: core::marker::PhantomData,)?
+                    },
+                    alpha,
+                }
+            }
+
+            /// Convert the struct of vectors into a vector of colors.
+            #[inline]
+            pub fn into_interleaved(self) -> alloc::vec::Vec<crate::Alpha<$self_ty<$($phantom_ty,)? T>, A>> {
+                let mut colors = alloc::vec::Vec::with_capacity(self.len());
+                colors.extend(
+                    self.color
+                        .into_iter()
+                        .zip(self.alpha)
+                        .map(|(color, alpha)| crate::Alpha { color, alpha }),
+                );
+                colors
+            }
+
+            /// Return the number of colors in the component vectors. See [`Vec::len`] for details.
+            #[inline(always)]
+            pub fn len(&self) -> usize {
+                let len = self.color.len();
+                debug_assert_eq!(self.alpha.len(), len, "the color and alpha vectors have different lengths");
+                len
+            }
+
+            /// Check if the component vectors are empty. See [`Vec::is_empty`] for details.
+            #[inline(always)]
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Reserve capacity for at least `additional` more colors in the component vectors. See [`Vec::reserve`] for details.
+            #[inline(always)]
+            pub fn reserve(&mut self, additional: usize) {
+                self.color.reserve(additional);
+                self.alpha.reserve(additional);
+            }
+
+            /// Shorten the component vectors to `len` colors. See [`Vec::truncate`] for details.
+            #[inline(always)]
+            pub fn truncate(&mut self, len: usize) {
+                self.color.truncate(len);
+                self.alpha.truncate(len);
+            }
+
+            /// Insert a color's components at `index` in the component vectors. See [`Vec::insert`] for details.
+            #[inline(always)]
+            pub fn insert(&mut self, index: usize, value: crate::Alpha<$self_ty<$($phantom_ty,)? T>, A>) {
+                self.color.insert(index, value.color);
+                self.alpha.insert(index, value.alpha);
+            }
+
+            /// Remove and return the color at `index` from the component vectors. See [`Vec::remove`] for details.
+            #[inline(always)]
+            pub fn remove(&mut self, index: usize) -> crate::Alpha<$self_ty<$($phantom_ty,)? T>, A> {
+                crate::Alpha {
+                    color: self.color.remove(index),
+                    alpha: self.alpha.remove(index),
+                }
+            }
+
+            /// Remove and return the color at `index` from the component vectors, and replace it with the last color. See [`Vec::swap_remove`] for details.
+            #[inline(always)]
+            pub fn swap_remove(&mut self, index: usize) -> crate::Alpha<$self_ty<$($phantom_ty,)? T>, A> {
+                crate::Alpha {
+                    color: self.color.swap_remove(index),
+                    alpha: self.alpha.swap_remove(index),
+                }
+            }
         }
     };
 }
@@ -1576,6 +2131,182 @@ macro_rules! struct_of_arrays_tests {
             assert_eq!(vec_of_colors2, vec![]);
         }
 
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn insert_remove() {
+            let mut vec_of_colors = vec![$($values.color),+];
+
+            let mut color_of_vecs: $color_ty<$($phantom_ty,)? Vec<_>> = vec_of_colors.clone().into_iter().collect();
+
+            let first = vec_of_colors[0];
+            color_of_vecs.insert(1, first);
+            vec_of_colors.insert(1, first);
+            assert_eq!(color_of_vecs.len(), vec_of_colors.len());
+
+            assert_eq!(color_of_vecs.remove(0), vec_of_colors.remove(0));
+            assert_eq!(color_of_vecs.swap_remove(0), vec_of_colors.swap_remove(0));
+
+            let color_of_vecs: Vec<_> = color_of_vecs.into_iter().collect();
+
+            assert_eq!(color_of_vecs, vec_of_colors);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn insert_remove_alpha() {
+            let mut vec_of_colors = vec![$($values),+];
+
+            let mut color_of_vecs: crate::alpha::Alpha<$color_ty<$($phantom_ty,)? Vec<_>>, Vec<_>> = vec_of_colors.clone().into_iter().collect();
+
+            let first = vec_of_colors[0];
+            color_of_vecs.insert(1, first);
+            vec_of_colors.insert(1, first);
+            assert_eq!(color_of_vecs.len(), vec_of_colors.len());
+
+            assert_eq!(color_of_vecs.remove(0), vec_of_colors.remove(0));
+            assert_eq!(color_of_vecs.swap_remove(0), vec_of_colors.swap_remove(0));
+
+            let color_of_vecs: Vec<_> = color_of_vecs.into_iter().collect();
+
+            assert_eq!(color_of_vecs, vec_of_colors);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn clamp_assign() {
+            use crate::{ClampAssign, IsWithinBounds};
+
+            let mut vec_of_colors = vec![$($values.color),+];
+            let mut color_of_vecs: $color_ty<$($phantom_ty,)? Vec<_>> = vec_of_colors.clone().into_iter().collect();
+
+            let within_bounds = vec_of_colors.iter().all(|color| color.is_within_bounds());
+            assert_eq!(color_of_vecs.is_within_bounds(), within_bounds);
+
+            color_of_vecs.clamp_assign();
+            vec_of_colors.iter_mut().for_each(ClampAssign::clamp_assign);
+
+            assert!(color_of_vecs.is_within_bounds());
+            assert_eq!(color_of_vecs.into_iter().collect::<Vec<_>>(), vec_of_colors);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn len_truncate() {
+            let vec_of_colors = vec![$($values.color),+];
+
+            let mut color_of_vecs: $color_ty<$($phantom_ty,)? Vec<_>> = vec_of_colors.clone().into_iter().collect();
+            assert_eq!(color_of_vecs.len(), vec_of_colors.len());
+            assert!(!color_of_vecs.is_empty());
+
+            color_of_vecs.reserve(10);
+            color_of_vecs.truncate(1);
+            assert_eq!(color_of_vecs.len(), 1);
+            assert_eq!(color_of_vecs.pop(), Some(vec_of_colors[0]));
+            assert!(color_of_vecs.is_empty());
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn len_truncate_alpha() {
+            let vec_of_colors = vec![$($values),+];
+
+            let mut color_of_vecs: crate::alpha::Alpha<$color_ty<$($phantom_ty,)? Vec<_>>, Vec<_>> = vec_of_colors.clone().into_iter().collect();
+            assert_eq!(color_of_vecs.len(), vec_of_colors.len());
+            assert!(!color_of_vecs.is_empty());
+
+            color_of_vecs.reserve(10);
+            color_of_vecs.truncate(1);
+            assert_eq!(color_of_vecs.len(), 1);
+            assert_eq!(color_of_vecs.pop(), Some(vec_of_colors[0]));
+            assert!(color_of_vecs.is_empty());
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn as_slices() {
+            let vec_of_colors = vec![$($values.color),+];
+
+            let mut color_of_vecs: $color_ty<$($phantom_ty,)? Vec<_>> = vec_of_colors.clone().into_iter().collect();
+
+            for mut color in color_of_vecs.as_mut_slices() {
+                color.set(color.copied() + 2.0);
+            }
+
+            let slices_of_colors: Vec<_> = color_of_vecs.as_slices().into_iter().map(|color| color.copied()).collect();
+
+            assert_eq!(slices_of_colors, vec![$($values.color + 2.0),+]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn as_slices_alpha() {
+            let vec_of_colors = vec![$($values),+];
+
+            let mut color_of_vecs: crate::alpha::Alpha<$color_ty<$($phantom_ty,)? Vec<_>>, Vec<_>> = vec_of_colors.clone().into_iter().collect();
+
+            for mut color in color_of_vecs.as_mut_slices() {
+                color.set(color.copied() + 2.0);
+            }
+
+            let slices_of_colors: Vec<_> = color_of_vecs.as_slices().into_iter().map(|color| color.copied()).collect();
+
+            assert_eq!(slices_of_colors, vec![$($values + 2.0),+]);
+        }
+
+        #[cfg(feature = "rayon")]
+        #[test]
+        fn par_iter_mut() {
+            use rayon::iter::ParallelIterator;
+
+            let vec_of_colors = vec![$($values.color),+];
+
+            let mut color_of_vecs: $color_ty<$($phantom_ty,)? Vec<_>> = vec_of_colors.into_iter().collect();
+
+            color_of_vecs.par_iter_mut().for_each(|mut color| color.set(color.copied() + 2.0));
+
+            let vec_of_colors: Vec<_> = color_of_vecs.into_iter().collect();
+
+            assert_eq!(vec_of_colors, vec![$($values.color + 2.0),+]);
+        }
+
+        #[cfg(feature = "rayon")]
+        #[test]
+        fn par_iter_mut_alpha() {
+            use rayon::iter::ParallelIterator;
+
+            let vec_of_colors = vec![$($values),+];
+
+            let mut color_of_vecs: crate::alpha::Alpha<$color_ty<$($phantom_ty,)? Vec<_>>, Vec<_>> = vec_of_colors.into_iter().collect();
+
+            color_of_vecs.par_iter_mut().for_each(|mut color| color.set(color.copied() + 2.0));
+
+            let vec_of_colors: Vec<_> = color_of_vecs.into_iter().collect();
+
+            assert_eq!(vec_of_colors, vec![$($values + 2.0),+]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn interleaved() {
+            let vec_of_colors = vec![$($values.color),+];
+
+            let color_of_vecs = $color_ty::<$($phantom_ty,)? Vec<_>>::from_interleaved(&vec_of_colors);
+            assert_eq!(color_of_vecs.len(), vec_of_colors.len());
+
+            assert_eq!(color_of_vecs.into_interleaved(), vec_of_colors);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn interleaved_alpha() {
+            let vec_of_colors = vec![$($values),+];
+
+            let color_of_vecs = crate::alpha::Alpha::<$color_ty<$($phantom_ty,)? Vec<_>>, Vec<_>>::from_interleaved(&vec_of_colors);
+            assert_eq!(color_of_vecs.len(), vec_of_colors.len());
+
+            assert_eq!(color_of_vecs.into_interleaved(), vec_of_colors);
+        }
+
         #[cfg(feature = "alloc")]
         #[test]
         fn modify() {
@@ -1,21 +1,217 @@
-//! This module provides simple matrix operations on 3x3 matrices to aid in
-//! chromatic adaptation and conversion calculations.
+//! Simple operations on 3x3 matrices, for chromatic adaptation, conversion
+//! and custom color spaces.
+//!
+//! A matrix is represented as a [`Mat3`], which wraps an array of 9 items in
+//! row-major order and has methods for the common matrix operations:
+//!
+//! ```
+//! use palette::matrix::Mat3;
+//!
+//! let scale: Mat3<f32> = Mat3::from_rows([
+//!     [2.0, 0.0, 0.0],
+//!     [0.0, 4.0, 0.0],
+//!     [0.0, 0.0, 8.0],
+//! ]);
+//!
+//! let inverse = scale.invert().unwrap();
+//! assert_eq!(inverse.mul_vector([2.0, 4.0, 8.0]), [1.0, 1.0, 1.0]);
+//! assert_eq!(scale.mul_matrix(inverse), Mat3::identity());
+//! ```
+//!
+//! The operations use regular floating point arithmetic, without any
+//! compensation for rounding errors. Expect results that are close to, but not
+//! always exactly the same as, the mathematically correct values.
 
 use crate::{
     convert::IntoColorUnclamped,
-    num::{Arithmetics, FromScalar, IsValidDivisor, Recip},
+    num::{Arithmetics, FromScalar, IsValidDivisor, One, Recip, Zero},
     rgb::{Primaries, RgbSpace},
     white_point::{Any, WhitePoint},
     Xyz, Yxy,
 };
 
-/// A 9 element array representing a 3x3 matrix.
-pub type Mat3<T> = [T; 9];
-pub type Vec3<T> = [T; 3];
+/// A 3 element array representing a column vector.
+type Vec3<T> = [T; 3];
+
+/// A 3x3 matrix.
+///
+/// The items are stored as an array of 9 items, in row-major order. This is
+/// the same layout as the matrix arrays in the rest of the library, such as in
+/// [`Matrix3::from_array`](crate::convert::Matrix3::from_array) and
+/// [`RgbSpace::rgb_to_xyz_matrix`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Mat3<T>([T; 9]);
+
+impl<T> Mat3<T> {
+    /// Create a matrix from an array of 9 items, in row-major order.
+    ///
+    /// ```
+    /// use palette::matrix::Mat3;
+    ///
+    /// let matrix = Mat3::from_array([1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// assert_eq!(matrix.into_rows(), [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// ```
+    #[inline]
+    pub const fn from_array(array: [T; 9]) -> Self {
+        Mat3(array)
+    }
+
+    /// Convert the matrix into an array of 9 items, in row-major order.
+    #[inline]
+    pub fn into_array(self) -> [T; 9] {
+        self.0
+    }
+
+    /// Create an identity matrix, which leaves any vector unchanged when
+    /// multiplied with it.
+    #[rustfmt::skip]
+    #[must_use]
+    #[inline]
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        Mat3([
+            T::one(), T::zero(), T::zero(),
+            T::zero(), T::one(), T::zero(),
+            T::zero(), T::zero(), T::one(),
+        ])
+    }
+
+    /// Create a matrix from an array of rows.
+    ///
+    /// ```
+    /// use palette::matrix::Mat3;
+    ///
+    /// let matrix = Mat3::from_rows([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.into_array(), [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    #[inline]
+    pub fn from_rows(rows: [Vec3<T>; 3]) -> Self {
+        let [[m0, m1, m2], [m3, m4, m5], [m6, m7, m8]] = rows;
+        Mat3([m0, m1, m2, m3, m4, m5, m6, m7, m8])
+    }
+
+    /// Split the matrix into an array of rows.
+    #[inline]
+    pub fn into_rows(self) -> [Vec3<T>; 3] {
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = self.0;
+        [[m0, m1, m2], [m3, m4, m5], [m6, m7, m8]]
+    }
+
+    /// Multiply the matrix with `other`, where `self` is on the left side.
+    ///
+    /// The resulting matrix has the same effect as applying `other` first and
+    /// `self` second.
+    ///
+    /// ```
+    /// use palette::matrix::Mat3;
+    ///
+    /// let scale = Mat3::from_rows([[2, 0, 0], [0, 2, 0], [0, 0, 2]]);
+    /// let swap = Mat3::from_rows([[0, 0, 1], [0, 1, 0], [1, 0, 0]]);
+    ///
+    /// let matrix = scale.mul_matrix(swap);
+    /// assert_eq!(matrix.mul_vector([1, 2, 3]), [6, 4, 2]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mul_matrix(self, other: Self) -> Self
+    where
+        T: Arithmetics + Clone,
+    {
+        Mat3(multiply_3x3(self.0, other.0))
+    }
+
+    /// Multiply the matrix with a column vector.
+    ///
+    /// ```
+    /// use palette::matrix::Mat3;
+    ///
+    /// let matrix = Mat3::from_rows([[1, 0, 0], [0, 2, 0], [1, 0, 1]]);
+    ///
+    /// assert_eq!(matrix.mul_vector([1, 2, 3]), [1, 4, 4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mul_vector(self, vector: Vec3<T>) -> Vec3<T>
+    where
+        T: Arithmetics,
+    {
+        multiply_3x3_and_vec3(self.0, vector)
+    }
+
+    /// Calculate the inverse of the matrix.
+    ///
+    /// Returns `None` if the matrix isn't invertible. This is decided by
+    /// [`IsValidDivisor`], meaning that floating point matrices are rejected if
+    /// the determinant is zero, subnormal, infinite or NaN. Matrices that are
+    /// close to being singular may still have an inverse with very large
+    /// values and low precision. This includes singular matrices that aren't
+    /// exactly singular after rounding, such as matrices with fractions that
+    /// can't be represented exactly.
+    ///
+    /// ```
+    /// use palette::matrix::Mat3;
+    ///
+    /// // The second row is a multiple of the first row.
+    /// let singular: Mat3<f64> = Mat3::from_rows([
+    ///     [1.0, 2.0, 3.0],
+    ///     [2.0, 4.0, 6.0],
+    ///     [0.0, 0.0, 1.0],
+    /// ]);
+    ///
+    /// assert_eq!(singular.invert(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn invert(self) -> Option<Self>
+    where
+        T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone,
+    {
+        try_matrix_inverse(self.0).map(Mat3)
+    }
+
+    /// Swap the rows and columns of the matrix.
+    ///
+    /// ```
+    /// use palette::matrix::Mat3;
+    ///
+    /// let matrix = Mat3::from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    ///
+    /// assert_eq!(matrix.transpose().into_rows(), [[1, 4, 7], [2, 5, 8], [3, 6, 9]]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn transpose(self) -> Self {
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = self.0;
+        Mat3([m0, m3, m6, m1, m4, m7, m2, m5, m8])
+    }
+}
+
+impl<T> From<[T; 9]> for Mat3<T> {
+    #[inline]
+    fn from(array: [T; 9]) -> Self {
+        Mat3(array)
+    }
+}
+
+impl<T> From<Mat3<T>> for [T; 9] {
+    #[inline]
+    fn from(matrix: Mat3<T>) -> Self {
+        matrix.0
+    }
+}
 
 /// Multiply the 3x3 matrix with an XYZ color.
 #[inline]
-pub fn multiply_3x3_and_vec3<T>(matrix: Mat3<T>, vector: Vec3<T>) -> Vec3<T>
+pub(crate) fn multiply_3x3_and_vec3<T>(matrix: [T; 9], vector: Vec3<T>) -> Vec3<T>
 where
     T: Arithmetics,
 {
@@ -40,7 +236,7 @@ where
 
 /// Multiply two 3x3 matrices.
 #[inline]
-pub fn multiply_3x3<T>(c: Mat3<T>, f: Mat3<T>) -> Mat3<T>
+pub(crate) fn multiply_3x3<T>(c: [T; 9], f: [T; 9]) -> [T; 9]
 where
     T: Arithmetics + Clone,
 {
@@ -65,7 +261,27 @@ where
 
 /// Invert a 3x3 matrix and panic if matrix is not invertible.
 #[inline]
-pub fn matrix_inverse<T>(a: Mat3<T>) -> Mat3<T>
+pub(crate) fn matrix_inverse<T>(a: [T; 9]) -> [T; 9]
+where
+    T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone,
+{
+    match try_matrix_inverse(a) {
+        Some(inverse) => inverse,
+        None => not_invertible(),
+    }
+}
+
+// Kept out of `matrix_inverse`, so the panic isn't repeated for each
+// component type.
+#[cold]
+#[inline(never)]
+fn not_invertible() -> ! {
+    panic!("The given matrix is not invertible")
+}
+
+/// Invert a 3x3 matrix, or return `None` if it's not invertible.
+#[inline]
+fn try_matrix_inverse<T>(a: [T; 9]) -> Option<[T; 9]>
 where
     T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone,
 {
@@ -85,11 +301,11 @@ where
     let d8 = a[0].clone() * &a[4] - a[1].clone() * &a[3];
 
     if !det.is_valid_divisor() {
-        panic!("The given matrix is not invertible")
+        return None;
     }
     det = det.recip();
 
-    [
+    Some([
         d0 * &det,
         -d3 * &det,
         d6 * &det,
@@ -99,14 +315,14 @@ where
         d2 * &det,
         -d5 * &det,
         d8 * det,
-    ]
+    ])
 }
 
 /// Maps a matrix from one item type to another.
 ///
 /// This turned out to be easier for the compiler to optimize than `matrix.map(f)`.
 #[inline(always)]
-pub fn matrix_map<T, U>(matrix: Mat3<T>, mut f: impl FnMut(T) -> U) -> Mat3<U> {
+pub(crate) fn matrix_map<T, U>(matrix: [T; 9], mut f: impl FnMut(T) -> U) -> [U; 9] {
     let [m1, m2, m3, m4, m5, m6, m7, m8, m9] = matrix;
     [
         f(m1),
@@ -121,9 +337,12 @@ pub fn matrix_map<T, U>(matrix: Mat3<T>, mut f: impl FnMut(T) -> U) -> Mat3<U> {
     ]
 }
 
-/// Generates the Srgb to Xyz transformation matrix for a given white point.
+/// Generates the RGB to Xyz transformation matrix for an RGB space.
+///
+/// The matrix is returned as an array in row-major order, and can be turned
+/// into a [`Mat3`] with [`Mat3::from_array`].
 #[inline]
-pub fn rgb_to_xyz_matrix<S, T>() -> Mat3<T>
+pub fn rgb_to_xyz_matrix<S, T>() -> [T; 9]
 where
     S: RgbSpace,
     S::Primaries: Primaries<T>,
@@ -131,16 +350,42 @@ where
     T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone + FromScalar<Scalar = T>,
     Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
 {
-    let r = S::Primaries::red().into_color_unclamped();
-    let g = S::Primaries::green().into_color_unclamped();
-    let b = S::Primaries::blue().into_color_unclamped();
+    rgb_to_xyz_matrix_from_primaries(
+        S::Primaries::red(),
+        S::Primaries::green(),
+        S::Primaries::blue(),
+        S::WhitePoint::get_xyz(),
+    )
+}
+
+/// Generates the RGB to Xyz transformation matrix for a set of primaries and a
+/// white point.
+///
+/// Only the chromaticity of each primary matters, since the primaries are
+/// scaled to add up to the white point. The matrix is returned as an array in
+/// row-major order, like in [`rgb_to_xyz_matrix`].
+#[inline]
+pub fn rgb_to_xyz_matrix_from_primaries<T>(
+    red: Yxy<Any, T>,
+    green: Yxy<Any, T>,
+    blue: Yxy<Any, T>,
+    white_point: Xyz<Any, T>,
+) -> [T; 9]
+where
+    T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone + FromScalar<Scalar = T>,
+    Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+{
+    // This isn't moved to a non-generic `f64` function. `T` is already a
+    // scalar, so SIMD types share this with `f32` and `f64`, and calculating
+    // in `f64` would change the `f32` results.
+    let r = red.into_color_unclamped();
+    let g = green.into_color_unclamped();
+    let b = blue.into_color_unclamped();
 
     let matrix = mat3_from_primaries(r, g, b);
 
-    let [s_red, s_green, s_blue] = multiply_3x3_and_vec3(
-        matrix_inverse(matrix.clone()),
-        S::WhitePoint::get_xyz().into(),
-    );
+    let [s_red, s_green, s_blue] =
+        multiply_3x3_and_vec3(matrix_inverse(matrix.clone()), white_point.into());
 
     // Destructuring has some performance benefits, don't change unless measured
     let [t0, t1, t2, t3, t4, t5, t6, t7, t8] = matrix;
@@ -160,7 +405,7 @@ where
 
 #[rustfmt::skip]
 #[inline]
-fn mat3_from_primaries<T>(r: Xyz<Any, T>, g: Xyz<Any, T>, b: Xyz<Any, T>) -> Mat3<T> {
+fn mat3_from_primaries<T>(r: Xyz<Any, T>, g: Xyz<Any, T>, b: Xyz<Any, T>) -> [T; 9] {
     [
         r.x, g.x, b.x,
         r.y, g.y, b.y,
@@ -171,9 +416,12 @@ fn mat3_from_primaries<T>(r: Xyz<Any, T>, g: Xyz<Any, T>, b: Xyz<Any, T>) -> Mat
 #[cfg(feature = "approx")]
 #[cfg(test)]
 mod test {
-    use super::{matrix_inverse, multiply_3x3, rgb_to_xyz_matrix};
+    use super::{matrix_inverse, multiply_3x3, rgb_to_xyz_matrix, Mat3};
+    use crate::convert::IntoColorUnclamped;
     use crate::encoding::Srgb;
     use crate::matrix::multiply_3x3_and_vec3;
+    use crate::white_point::{Any, D65};
+    use crate::{LinSrgb, Xyz};
 
     #[test]
     fn matrix_multiply_3x3() {
@@ -210,6 +458,7 @@ mod test {
             assert_relative_eq!(t1, t2);
         }
     }
+
     #[test]
     fn matrix_inverse_check_2() {
         let input: [f64; 9] = [1.0, 0.0, 1.0, 0.0, 2.0, 1.0, 1.0, 1.0, 1.0];
@@ -220,6 +469,81 @@ mod test {
             assert_relative_eq!(t1, t2);
         }
     }
+
+    #[test]
+    fn invert_singular() {
+        let zero: Mat3<f64> = Mat3::from_array([0.0; 9]);
+        assert_eq!(zero.invert(), None);
+
+        let repeated_row: Mat3<f64> =
+            Mat3::from_rows([[1.0, 2.0, 3.0], [1.0, 2.0, 3.0], [0.5, 0.25, 0.125]]);
+        assert_eq!(repeated_row.invert(), None);
+
+        let dependent_columns: Mat3<f64> =
+            Mat3::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 9.0], [7.0, 8.0, 15.0]]);
+        assert_eq!(dependent_columns.invert(), None);
+
+        let nan: Mat3<f64> = Mat3::from_array([f64::NAN; 9]);
+        assert_eq!(nan.invert(), None);
+
+        let infinite: Mat3<f64> =
+            Mat3::from_rows([[f64::INFINITY, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_eq!(infinite.invert(), None);
+    }
+
+    #[test]
+    fn invert_round_trip() {
+        let matrix: Mat3<f64> = Mat3::from_rows([
+            [0.4124564, 0.3575761, 0.1804375],
+            [0.2126729, 0.7151522, 0.0721750],
+            [0.0193339, 0.1191920, 0.9503041],
+        ]);
+        let inverse = matrix.invert().unwrap();
+
+        assert_relative_eq!(
+            matrix.mul_matrix(inverse).into_array()[..],
+            Mat3::<f64>::identity().into_array()[..],
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            inverse.mul_matrix(matrix).into_array()[..],
+            Mat3::<f64>::identity().into_array()[..],
+            epsilon = 0.0000001
+        );
+        assert_eq!(
+            Mat3::<f64>::identity().invert(),
+            Some(Mat3::<f64>::identity())
+        );
+    }
+
+    #[test]
+    fn rows_and_transpose() {
+        let rows = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        let matrix: Mat3<i32> = Mat3::from_rows(rows);
+
+        assert_eq!(matrix.into_rows(), rows);
+        assert_eq!(matrix.transpose().into_array(), [1, 4, 7, 2, 5, 8, 3, 6, 9]);
+        assert_eq!(matrix.transpose().transpose(), matrix);
+    }
+
+    #[test]
+    fn transform_colors() {
+        let matrix = Mat3::from_array(rgb_to_xyz_matrix::<Srgb, f64>());
+
+        let rgb = LinSrgb::new(0.2, 0.4, 0.6);
+        let xyz = Xyz::<Any, f64>::new(0.2, 0.4, 0.6).transform_by(&matrix);
+        let expected: Xyz<D65, f64> = rgb.into_color_unclamped();
+
+        assert_relative_eq!(xyz.x, expected.x, epsilon = 0.0000001);
+        assert_relative_eq!(xyz.y, expected.y, epsilon = 0.0000001);
+        assert_relative_eq!(xyz.z, expected.z, epsilon = 0.0000001);
+
+        let round_trip = rgb
+            .transform_by(&matrix)
+            .transform_by(&matrix.invert().unwrap());
+        assert_relative_eq!(round_trip, rgb, epsilon = 0.0000001);
+    }
+
     #[test]
     #[should_panic]
     fn matrix_inverse_panic() {
@@ -16,8 +16,12 @@ use core::ops::{Add, Div, Mul, Neg, Sub};
 
 use crate::bool_mask::HasBoolMask;
 
+#[cfg(feature = "half")]
+mod half;
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 mod libm;
+#[cfg(feature = "portable-simd")]
+mod portable_simd;
 #[cfg(feature = "wide")]
 mod wide;
 
@@ -39,6 +43,24 @@ pub trait FromScalar {
     /// corresponds to `splat` for SIMD types.
     #[must_use]
     fn from_scalar(scalar: Self::Scalar) -> Self;
+
+    /// Multiply a 3x3 `f64` matrix with a column `vector`, as in the
+    /// conversions between RGB and XYZ with precomputed matrices.
+    ///
+    /// The default implementation converts the matrix with
+    /// [`Real::from_f64`] and [`from_scalar`](FromScalar::from_scalar) before
+    /// multiplying. Types with low precision, such as `f16`, can override it
+    /// to calculate each component with higher precision and round it once.
+    #[must_use]
+    #[inline]
+    fn mul_f64_matrix(matrix: [f64; 9], vector: [Self; 3]) -> [Self; 3]
+    where
+        Self: Arithmetics + Sized,
+        Self::Scalar: Real,
+    {
+        let matrix = matrix.map(|value| Self::from_scalar(Self::Scalar::from_f64(value)));
+        crate::matrix::multiply_3x3_and_vec3(matrix, vector)
+    }
 }
 
 /// Conversion from an array of scalars to a vectorized value.
@@ -322,7 +344,7 @@ pub trait Ln {
     fn ln(self) -> Self;
 }
 
-macro_rules! impl_uint {
+macro_rules! impl_int {
     ($($ty: ident),+) => {
         $(
             impl FromScalar for $ty {
@@ -741,7 +763,7 @@ macro_rules! impl_float {
     };
 }
 
-impl_uint!(u8, u16, u32, u64, u128);
+impl_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
 impl_float!(f32, f64);
 
 /// "borrowed" from num_traits
@@ -853,3 +875,123 @@ macro_rules! impl_partial_cmp {
 }
 
 impl_partial_cmp!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// A wrapper for floating point numbers that gives them a total ordering, so
+/// they can be sorted and used as keys.
+///
+/// The ordering is the same as the `totalOrder` predicate in IEEE 754, where
+/// `-0.0` is less than `+0.0`, negative NaN is less than everything else, and
+/// positive NaN is greater than everything else. This means that sorting never
+/// panics, and NaN values end up at the ends.
+///
+/// It's returned by the `sort_key_*` methods on color types, such as
+/// [`Oklch::sort_key_lightness`](crate::Oklch::sort_key_lightness):
+///
+/// ```
+/// use palette::Oklch;
+///
+/// let mut colors = vec![
+///     Oklch::new(0.8f32, 0.1, 40.0),
+///     Oklch::new(0.2, 0.05, 120.0),
+///     Oklch::new(0.5, 0.2, 300.0),
+/// ];
+///
+/// colors.sort_by_key(Oklch::sort_key_lightness);
+/// assert_eq!(colors[0].l, 0.2);
+///
+/// colors.sort_by_key(Oklch::sort_key_hue);
+/// assert_eq!(colors[0].hue, 40.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TotalOrder<T>(pub T);
+
+macro_rules! impl_total_order {
+    ($($ty: ident => $int: ident, $uint: ident;)+) => {
+        $(
+            impl TotalOrder<$ty> {
+                /// Turns the bits into an integer with the same ordering.
+                #[inline]
+                fn key(&self) -> $int {
+                    let bits = self.0.to_bits() as $int;
+
+                    // Flip all bits except the sign bit for negative numbers,
+                    // to make them count in the opposite direction.
+                    bits ^ ((((bits >> ($int::BITS - 1)) as $uint) >> 1) as $int)
+                }
+            }
+
+            impl PartialEq for TotalOrder<$ty> {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.key() == other.key()
+                }
+            }
+
+            impl Eq for TotalOrder<$ty> {}
+
+            impl PartialOrd for TotalOrder<$ty> {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for TotalOrder<$ty> {
+                #[inline]
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    self.key().cmp(&other.key())
+                }
+            }
+
+            impl core::hash::Hash for TotalOrder<$ty> {
+                #[inline]
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    self.key().hash(state)
+                }
+            }
+        )+
+    };
+}
+
+impl_total_order! {
+    f32 => i32, u32;
+    f64 => i64, u64;
+}
+
+#[cfg(test)]
+mod test {
+    use super::TotalOrder;
+
+    #[test]
+    fn total_order() {
+        let mut values = [
+            TotalOrder(1.0f32),
+            TotalOrder(f32::NAN),
+            TotalOrder(-0.0),
+            TotalOrder(f32::NEG_INFINITY),
+            TotalOrder(0.0),
+            TotalOrder(-f32::NAN),
+            TotalOrder(-2.5),
+            TotalOrder(f32::INFINITY),
+        ];
+        values.sort();
+
+        let sorted = values.map(|TotalOrder(value)| value);
+        assert!(sorted[0].is_nan() && sorted[0].is_sign_negative());
+        assert_eq!(
+            sorted[1..7],
+            [f32::NEG_INFINITY, -2.5, -0.0, 0.0, 1.0, f32::INFINITY]
+        );
+        assert!(sorted[1 + 2].is_sign_negative());
+        assert!(sorted[7].is_nan() && sorted[7].is_sign_positive());
+    }
+
+    #[test]
+    fn total_order_f64() {
+        assert!(TotalOrder(-1.0f64) < TotalOrder(-0.5));
+        assert!(TotalOrder(-0.0f64) < TotalOrder(0.0));
+        assert!(TotalOrder(f64::MAX) < TotalOrder(f64::INFINITY));
+        assert!(TotalOrder(f64::INFINITY) < TotalOrder(f64::NAN));
+        assert_eq!(TotalOrder(f64::NAN), TotalOrder(f64::NAN));
+    }
+}
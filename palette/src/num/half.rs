@@ -0,0 +1,394 @@
+use ::half::f16;
+
+use super::*;
+
+impl Real for f16 {
+    #[inline]
+    fn from_f64(n: f64) -> f16 {
+        f16::from_f64(n)
+    }
+}
+
+impl FromScalar for f16 {
+    type Scalar = Self;
+
+    #[inline]
+    fn from_scalar(scalar: Self) -> Self {
+        scalar
+    }
+
+    // The matrix is converted to `f32`, where the products and sums are
+    // calculated, and only the results are rounded back to `f16`.
+    #[inline]
+    fn mul_f64_matrix(matrix: [f64; 9], vector: [Self; 3]) -> [Self; 3] {
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = matrix.map(|value| value as f32);
+        let [x, y, z] = vector.map(f16::to_f32);
+
+        [
+            f16::from_f32(m0 * x + m1 * y + m2 * z),
+            f16::from_f32(m3 * x + m4 * y + m5 * z),
+            f16::from_f32(m6 * x + m7 * y + m8 * z),
+        ]
+    }
+}
+
+impl FromScalarArray<1> for f16 {
+    #[inline]
+    fn from_array(scalars: [Self; 1]) -> Self {
+        let [scalar] = scalars;
+        scalar
+    }
+}
+
+impl IntoScalarArray<1> for f16 {
+    #[inline]
+    fn into_array(self) -> [Self; 1] {
+        [self]
+    }
+}
+
+impl Zero for f16 {
+    #[inline]
+    fn zero() -> Self {
+        f16::ZERO
+    }
+}
+
+impl One for f16 {
+    #[inline]
+    fn one() -> Self {
+        f16::ONE
+    }
+}
+
+impl MinMax for f16 {
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        f16::max(self, other)
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        f16::min(self, other)
+    }
+
+    #[inline]
+    fn min_max(self, other: Self) -> (Self, Self) {
+        if self > other {
+            (other, self)
+        } else {
+            (self, other)
+        }
+    }
+}
+
+impl Powu for f16 {
+    #[inline]
+    fn powu(self, exp: u32) -> Self {
+        pow(self, exp)
+    }
+}
+
+impl IsValidDivisor for f16 {
+    #[inline]
+    fn is_valid_divisor(&self) -> bool {
+        f16::is_normal(*self)
+    }
+}
+
+impl Clamp for f16 {
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        f16::clamp(self, min, max)
+    }
+
+    #[inline]
+    fn clamp_min(self, min: Self) -> Self {
+        f16::max(self, min)
+    }
+
+    #[inline]
+    fn clamp_max(self, max: Self) -> Self {
+        f16::min(self, max)
+    }
+}
+
+impl ClampAssign for f16 {
+    #[inline]
+    fn clamp_assign(&mut self, min: Self, max: Self) {
+        *self = f16::clamp(*self, min, max);
+    }
+
+    #[inline]
+    fn clamp_min_assign(&mut self, min: Self) {
+        *self = f16::max(*self, min);
+    }
+
+    #[inline]
+    fn clamp_max_assign(&mut self, max: Self) {
+        *self = f16::min(*self, max);
+    }
+}
+
+impl MulSub for f16 {
+    #[inline]
+    fn mul_sub(self, m: Self, s: Self) -> Self {
+        (self * m) - s
+    }
+}
+
+impl PartialCmp for f16 {
+    #[inline]
+    fn lt(&self, other: &Self) -> bool {
+        self < other
+    }
+
+    #[inline]
+    fn lt_eq(&self, other: &Self) -> bool {
+        self <= other
+    }
+
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    #[inline]
+    fn neq(&self, other: &Self) -> bool {
+        self != other
+    }
+
+    #[inline]
+    fn gt_eq(&self, other: &Self) -> bool {
+        self >= other
+    }
+
+    #[inline]
+    fn gt(&self, other: &Self) -> bool {
+        self > other
+    }
+}
+
+// The remaining operations don't have native `f16` implementations, so they
+// are calculated in `f32` and rounded back. This requires the same math
+// functions as `f32`.
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Trigonometry for f16 {
+    #[inline]
+    fn sin(self) -> Self {
+        f16::from_f32(Trigonometry::sin(self.to_f32()))
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        f16::from_f32(Trigonometry::cos(self.to_f32()))
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = Trigonometry::sin_cos(self.to_f32());
+        (f16::from_f32(sin), f16::from_f32(cos))
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        f16::from_f32(Trigonometry::tan(self.to_f32()))
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        f16::from_f32(Trigonometry::asin(self.to_f32()))
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        f16::from_f32(Trigonometry::acos(self.to_f32()))
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        f16::from_f32(Trigonometry::atan(self.to_f32()))
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        f16::from_f32(Trigonometry::atan2(self.to_f32(), other.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Abs for f16 {
+    #[inline]
+    fn abs(self) -> Self {
+        f16::from_f32(Abs::abs(self.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Sqrt for f16 {
+    #[inline]
+    fn sqrt(self) -> Self {
+        f16::from_f32(Sqrt::sqrt(self.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Cbrt for f16 {
+    #[inline]
+    fn cbrt(self) -> Self {
+        f16::from_f32(Cbrt::cbrt(self.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Powf for f16 {
+    #[inline]
+    fn powf(self, exp: Self) -> Self {
+        f16::from_f32(Powf::powf(self.to_f32(), exp.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Powi for f16 {
+    #[inline]
+    fn powi(self, exp: i32) -> Self {
+        f16::from_f32(Powi::powi(self.to_f32(), exp))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Recip for f16 {
+    #[inline]
+    fn recip(self) -> Self {
+        f16::from_f32(Recip::recip(self.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Exp for f16 {
+    #[inline]
+    fn exp(self) -> Self {
+        f16::from_f32(Exp::exp(self.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Hypot for f16 {
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        f16::from_f32(Hypot::hypot(self.to_f32(), other.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Round for f16 {
+    #[inline]
+    fn round(self) -> Self {
+        f16::from_f32(Round::round(self.to_f32()))
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        f16::from_f32(Round::floor(self.to_f32()))
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        f16::from_f32(Round::ceil(self.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl MulAdd for f16 {
+    #[inline]
+    fn mul_add(self, m: Self, a: Self) -> Self {
+        f16::from_f32(MulAdd::mul_add(self.to_f32(), m.to_f32(), a.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Signum for f16 {
+    #[inline]
+    fn signum(self) -> Self {
+        f16::from_f32(Signum::signum(self.to_f32()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+impl Ln for f16 {
+    #[inline]
+    fn ln(self) -> Self {
+        f16::from_f32(Ln::ln(self.to_f32()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::half::f16;
+
+    use crate::{cast, convert::FromColorUnclamped, LinSrgb, Srgb, Xyz};
+
+    #[test]
+    fn u8_to_f16_to_f32() {
+        for value in 0..=255u8 {
+            let color = Srgb::new(value, 255 - value, value / 2);
+            let half: Srgb<f16> = color.into_format();
+            let float: Srgb<f32> = half.into_format();
+            let expected: Srgb<f32> = color.into_format();
+
+            // The precision of f16 is 11 bits, so the relative error is at
+            // most 2^-11.
+            assert_relative_eq!(float, expected, max_relative = 0.0005);
+            assert_eq!(half.into_format::<u8>(), color);
+        }
+    }
+
+    #[test]
+    fn f32_to_f16_to_f32() {
+        let color = LinSrgb::new(0.25f32, 0.5, 0.75);
+        let half: LinSrgb<f16> = color.into_format();
+
+        assert_eq!(half.into_format::<f32>(), color);
+    }
+
+    #[test]
+    fn array_cast() {
+        let color = Srgb::new(f16::from_f32(0.1), f16::from_f32(0.2), f16::from_f32(0.3));
+        let array: [f16; 3] = cast::into_array(color);
+
+        assert_eq!(array, [color.red, color.green, color.blue]);
+        assert_eq!(cast::from_array::<Srgb<f16>>(array), color);
+    }
+
+    #[test]
+    fn xyz_conversion() {
+        for &(red, green, blue) in &[(0.2f32, 0.4, 0.6), (1.0, 0.5, 0.0), (0.05, 0.9, 0.3)] {
+            let half = LinSrgb::new(red, green, blue).into_format::<f16>();
+            let float = Xyz::from_color_unclamped(half.into_format::<f32>());
+            let half = Xyz::from_color_unclamped(half);
+
+            let half = Xyz::<_, f32>::new(half.x.to_f32(), half.y.to_f32(), half.z.to_f32());
+
+            // The relative size of 1 ULP is at most 2^-10 for normal f16 values.
+            assert_relative_eq!(half, float, max_relative = 0.0009765625);
+        }
+    }
+
+    #[test]
+    fn rgb_conversion() {
+        for &(x, y, z) in &[(0.2f32, 0.3, 0.4), (0.95, 1.0, 1.09), (0.4, 0.2, 0.05)] {
+            let half = Xyz::new(f16::from_f32(x), f16::from_f32(y), f16::from_f32(z));
+            let float = LinSrgb::from_color_unclamped(Xyz::new(
+                half.x.to_f32(),
+                half.y.to_f32(),
+                half.z.to_f32(),
+            ));
+            let half = LinSrgb::from_color_unclamped(half);
+
+            let half =
+                LinSrgb::<f32>::new(half.red.to_f32(), half.green.to_f32(), half.blue.to_f32());
+            assert_relative_eq!(half, float, max_relative = 0.0009765625);
+        }
+    }
+}
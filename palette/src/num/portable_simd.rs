@@ -0,0 +1,405 @@
+use core::simd::{
+    cmp::{SimdPartialEq, SimdPartialOrd},
+    num::SimdFloat,
+    Simd,
+};
+
+use super::*;
+
+macro_rules! impl_portable_simd_float {
+    ($($scalar: ident),+) => {
+        $(
+            impl<const N: usize> Real for Simd<$scalar, N> {
+                #[inline]
+                fn from_f64(n: f64) -> Self {
+                    Simd::splat(n as $scalar)
+                }
+            }
+
+            impl<const N: usize> FromScalar for Simd<$scalar, N> {
+                type Scalar = $scalar;
+
+                #[inline]
+                fn from_scalar(scalar: $scalar) -> Self {
+                    Simd::splat(scalar)
+                }
+            }
+
+            impl<const N: usize> FromScalarArray<N> for Simd<$scalar, N> {
+                #[inline]
+                fn from_array(scalars: [$scalar; N]) -> Self {
+                    Simd::from_array(scalars)
+                }
+            }
+
+            impl<const N: usize> IntoScalarArray<N> for Simd<$scalar, N> {
+                #[inline]
+                fn into_array(self) -> [$scalar; N] {
+                    self.to_array()
+                }
+            }
+
+            impl<const N: usize> Zero for Simd<$scalar, N> {
+                #[inline]
+                fn zero() -> Self {
+                    Simd::splat(0.0)
+                }
+            }
+
+            impl<const N: usize> One for Simd<$scalar, N> {
+                #[inline]
+                fn one() -> Self {
+                    Simd::splat(1.0)
+                }
+            }
+
+            impl<const N: usize> MinMax for Simd<$scalar, N> {
+                #[inline]
+                fn max(self, other: Self) -> Self {
+                    self.simd_max(other)
+                }
+
+                #[inline]
+                fn min(self, other: Self) -> Self {
+                    self.simd_min(other)
+                }
+
+                #[inline]
+                fn min_max(self, other: Self) -> (Self, Self) {
+                    (self.simd_min(other), self.simd_max(other))
+                }
+            }
+
+            impl<const N: usize> Powu for Simd<$scalar, N> {
+                #[inline]
+                fn powu(self, exp: u32) -> Self {
+                    pow(self, exp)
+                }
+            }
+
+            impl<const N: usize> IsValidDivisor for Simd<$scalar, N> {
+                #[inline]
+                fn is_valid_divisor(&self) -> Self::Mask {
+                    self.simd_ne(Simd::splat(0.0))
+                }
+            }
+
+            impl<const N: usize> Abs for Simd<$scalar, N> {
+                #[inline]
+                fn abs(self) -> Self {
+                    SimdFloat::abs(self)
+                }
+            }
+
+            impl<const N: usize> Recip for Simd<$scalar, N> {
+                #[inline]
+                fn recip(self) -> Self {
+                    SimdFloat::recip(self)
+                }
+            }
+
+            impl<const N: usize> Powi for Simd<$scalar, N> {
+                #[inline]
+                fn powi(mut self, mut exp: i32) -> Self {
+                    if exp < 0 {
+                        exp = exp.wrapping_neg();
+                        self = SimdFloat::recip(self);
+                    }
+
+                    Powu::powu(self, exp as u32)
+                }
+            }
+
+            impl<const N: usize> Clamp for Simd<$scalar, N> {
+                #[inline]
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    self.simd_min(max).simd_max(min)
+                }
+
+                #[inline]
+                fn clamp_min(self, min: Self) -> Self {
+                    self.simd_max(min)
+                }
+
+                #[inline]
+                fn clamp_max(self, max: Self) -> Self {
+                    self.simd_min(max)
+                }
+            }
+
+            impl<const N: usize> ClampAssign for Simd<$scalar, N> {
+                #[inline]
+                fn clamp_assign(&mut self, min: Self, max: Self) {
+                    *self = Clamp::clamp(*self, min, max);
+                }
+
+                #[inline]
+                fn clamp_min_assign(&mut self, min: Self) {
+                    *self = self.simd_max(min);
+                }
+
+                #[inline]
+                fn clamp_max_assign(&mut self, max: Self) {
+                    *self = self.simd_min(max);
+                }
+            }
+
+            impl<const N: usize> PartialCmp for Simd<$scalar, N> {
+                #[inline]
+                fn lt(&self, other: &Self) -> Self::Mask {
+                    self.simd_lt(*other)
+                }
+
+                #[inline]
+                fn lt_eq(&self, other: &Self) -> Self::Mask {
+                    self.simd_le(*other)
+                }
+
+                #[inline]
+                fn eq(&self, other: &Self) -> Self::Mask {
+                    self.simd_eq(*other)
+                }
+
+                #[inline]
+                fn neq(&self, other: &Self) -> Self::Mask {
+                    self.simd_ne(*other)
+                }
+
+                #[inline]
+                fn gt_eq(&self, other: &Self) -> Self::Mask {
+                    self.simd_ge(*other)
+                }
+
+                #[inline]
+                fn gt(&self, other: &Self) -> Self::Mask {
+                    self.simd_gt(*other)
+                }
+            }
+
+            impl<const N: usize> MulAdd for Simd<$scalar, N> {
+                #[inline]
+                fn mul_add(self, m: Self, a: Self) -> Self {
+                    self * m + a
+                }
+            }
+
+            impl<const N: usize> MulSub for Simd<$scalar, N> {
+                #[inline]
+                fn mul_sub(self, m: Self, s: Self) -> Self {
+                    self * m - s
+                }
+            }
+
+            impl<const N: usize> Signum for Simd<$scalar, N> {
+                #[inline]
+                fn signum(self) -> Self {
+                    SimdFloat::signum(self)
+                }
+            }
+
+            // `core::simd` doesn't provide the remaining operations, so they
+            // are calculated for each lane, using the scalar implementations.
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> Trigonometry for Simd<$scalar, N> {
+                #[inline]
+                fn sin(self) -> Self {
+                    map_lanes(self, Trigonometry::sin)
+                }
+
+                #[inline]
+                fn cos(self) -> Self {
+                    map_lanes(self, Trigonometry::cos)
+                }
+
+                #[inline]
+                fn sin_cos(self) -> (Self, Self) {
+                    let mut sin = self.to_array();
+                    let mut cos = sin;
+
+                    for (sin, cos) in sin.iter_mut().zip(&mut cos) {
+                        let (sin_value, cos_value) = Trigonometry::sin_cos(*sin);
+                        *sin = sin_value;
+                        *cos = cos_value;
+                    }
+
+                    (Simd::from_array(sin), Simd::from_array(cos))
+                }
+
+                #[inline]
+                fn tan(self) -> Self {
+                    map_lanes(self, Trigonometry::tan)
+                }
+
+                #[inline]
+                fn asin(self) -> Self {
+                    map_lanes(self, Trigonometry::asin)
+                }
+
+                #[inline]
+                fn acos(self) -> Self {
+                    map_lanes(self, Trigonometry::acos)
+                }
+
+                #[inline]
+                fn atan(self) -> Self {
+                    map_lanes(self, Trigonometry::atan)
+                }
+
+                #[inline]
+                fn atan2(self, other: Self) -> Self {
+                    zip_lanes(self, other, Trigonometry::atan2)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> Sqrt for Simd<$scalar, N> {
+                #[inline]
+                fn sqrt(self) -> Self {
+                    map_lanes(self, Sqrt::sqrt)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> Cbrt for Simd<$scalar, N> {
+                #[inline]
+                fn cbrt(self) -> Self {
+                    map_lanes(self, Cbrt::cbrt)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> Powf for Simd<$scalar, N> {
+                #[inline]
+                fn powf(self, exp: Self) -> Self {
+                    zip_lanes(self, exp, Powf::powf)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> Exp for Simd<$scalar, N> {
+                #[inline]
+                fn exp(self) -> Self {
+                    map_lanes(self, Exp::exp)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> Hypot for Simd<$scalar, N> {
+                #[inline]
+                fn hypot(self, other: Self) -> Self {
+                    zip_lanes(self, other, Hypot::hypot)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> Round for Simd<$scalar, N> {
+                #[inline]
+                fn round(self) -> Self {
+                    map_lanes(self, Round::round)
+                }
+
+                #[inline]
+                fn floor(self) -> Self {
+                    map_lanes(self, Round::floor)
+                }
+
+                #[inline]
+                fn ceil(self) -> Self {
+                    map_lanes(self, Round::ceil)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm", test))]
+            impl<const N: usize> Ln for Simd<$scalar, N> {
+                #[inline]
+                fn ln(self) -> Self {
+                    map_lanes(self, Ln::ln)
+                }
+            }
+        )+
+    };
+}
+
+impl_portable_simd_float!(f32, f64);
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+#[inline]
+fn map_lanes<T, const N: usize>(value: Simd<T, N>, f: impl Fn(T) -> T) -> Simd<T, N>
+where
+    T: core::simd::SimdElement,
+{
+    Simd::from_array(value.to_array().map(f))
+}
+
+#[cfg(any(feature = "std", feature = "libm", test))]
+#[inline]
+fn zip_lanes<T, const N: usize>(a: Simd<T, N>, b: Simd<T, N>, f: impl Fn(T, T) -> T) -> Simd<T, N>
+where
+    T: core::simd::SimdElement,
+{
+    let mut a = a.to_array();
+
+    for (a, b) in a.iter_mut().zip(b.to_array()) {
+        *a = f(*a, b);
+    }
+
+    Simd::from_array(a)
+}
+
+#[cfg(test)]
+mod test {
+    use core::simd::Simd;
+
+    use crate::{convert::FromColorUnclamped, num::Trigonometry, LinSrgb, Oklab, Oklch, Srgb, Xyz};
+
+    #[test]
+    fn rgb_array_conversion() {
+        let colors = [
+            Srgb::new(0.1f32, 0.2, 0.3),
+            Srgb::new(0.4, 0.5, 0.6),
+            Srgb::new(0.7, 0.8, 0.9),
+            Srgb::new(1.0, 0.0, 0.5),
+        ];
+
+        let simd = Srgb::<Simd<f32, 4>>::from(colors);
+        assert_eq!(simd.red, Simd::from_array([0.1, 0.4, 0.7, 1.0]));
+
+        let array: [Srgb<f32>; 4] = simd.into();
+        assert_eq!(array, colors);
+    }
+
+    #[test]
+    fn matches_scalar_conversion() {
+        let colors = [
+            LinSrgb::new(0.1f64, 0.2, 0.3),
+            LinSrgb::new(0.4, 0.5, 0.6),
+            LinSrgb::new(0.0, 0.0, 0.0),
+            LinSrgb::new(1.0, 0.0, 0.5),
+        ];
+
+        let simd = LinSrgb::<Simd<f64, 4>>::from(colors);
+        let lch: [Oklch<f64>; 4] = Oklch::from_color_unclamped(Oklab::from_color_unclamped(
+            Xyz::from_color_unclamped(simd),
+        ))
+        .into();
+
+        for (color, lch) in IntoIterator::into_iter(colors).zip(lch) {
+            let expected = Oklch::from_color_unclamped(Oklab::from_color_unclamped(
+                Xyz::from_color_unclamped(color),
+            ));
+            assert_relative_eq!(lch, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn per_lane_math() {
+        let value = Simd::from_array([0.0f32, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+        let (sin, cos) = value.sin_cos();
+
+        for (index, scalar) in IntoIterator::into_iter(value.to_array()).enumerate() {
+            assert_eq!(sin[index], scalar.sin());
+            assert_eq!(cos[index], scalar.cos());
+        }
+    }
+}
@@ -5,9 +5,10 @@
 use crate::{angle::RealAngle, num::Trigonometry, OklabHue};
 
 use crate::{
+    bool_mask::{BitOps, LazySelect, Select},
     convert::IntoColorUnclamped,
-    num::{Arithmetics, Cbrt, MinMax, One, Powi, Real, Sqrt, Zero},
-    HasBoolMask, LinSrgb, Oklab,
+    num::{Arithmetics, Cbrt, MinMax, One, PartialCmp, Powi, Real, Sqrt, Zero},
+    LinSrgb, Oklab,
 };
 
 /// Finds intersection of the line defined by
@@ -19,104 +20,120 @@ use crate::{
 /// a and b must be normalized so a² + b² == 1
 fn find_gamut_intersection<T>(a: T, b: T, l1: T, c1: T, l0: T, cusp: LC<T>) -> T
 where
-    T: Real + One + Zero + Arithmetics + MinMax + HasBoolMask<Mask = bool> + PartialOrd + Clone,
+    T: Real + One + Zero + Arithmetics + MinMax + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
 {
     // Find the intersection for upper and lower half separately
-    if ((l1.clone() - &l0) * &cusp.chroma - (cusp.lightness.clone() - &l0) * &c1) <= T::zero() {
-        // Lower half
+    let is_lower_half = ((l1.clone() - &l0) * &cusp.chroma - (cusp.lightness.clone() - &l0) * &c1)
+        .lt_eq(&T::zero());
+
+    lazy_select! {
+        if is_lower_half => {
+            cusp.chroma.clone() * &l0
+                / (c1.clone() * &cusp.lightness + cusp.chroma.clone() * (l0.clone() - &l1))
+        },
+        else => find_upper_gamut_intersection(
+            a,
+            b,
+            l1.clone(),
+            c1.clone(),
+            l0.clone(),
+            cusp.clone(),
+        ),
+    }
+}
 
-        cusp.chroma.clone() * &l0 / (c1 * cusp.lightness + cusp.chroma * (l0 - l1))
-    } else {
-        // Upper half
+/// The upper half of [`find_gamut_intersection`], which uses one step of
+/// Halley's method to compensate for the curved top of the gamut.
+fn find_upper_gamut_intersection<T>(a: T, b: T, l1: T, c1: T, l0: T, cusp: LC<T>) -> T
+where
+    T: Real + One + Zero + Arithmetics + MinMax + PartialCmp + Clone,
+    T::Mask: Select<T>,
+{
+    // First intersect with triangle
+    let t = cusp.chroma.clone() * (l0.clone() - T::one())
+        / (c1.clone() * (cusp.lightness - T::one()) + cusp.chroma * (l0.clone() - &l1));
 
-        // First intersect with triangle
-        let t = cusp.chroma.clone() * (l0.clone() - T::one())
-            / (c1.clone() * (cusp.lightness - T::one()) + cusp.chroma * (l0.clone() - &l1));
+    // Then one step Halley's method
+    {
+        let dl = l1.clone() - &l0;
+        let dc = c1.clone();
+
+        let k_l = T::from_f64(0.39633779217376786) * &a + T::from_f64(0.2158037580607588) * &b;
+        let k_m = -T::from_f64(0.10556134232365635) * &a - T::from_f64(0.06385417477170591) * &b;
+        let k_s = -T::from_f64(0.08948418209496575) * a - T::from_f64(1.2914855378640917) * b;
 
-        // Then one step Halley's method
+        let l_dt = dl.clone() + dc.clone() * &k_l;
+        let m_dt = dl.clone() + dc.clone() * &k_m;
+        let s_dt = dl + dc * &k_s;
+
+        // If higher accuracy is required, 2 or 3 iterations of the following block can be used:
         {
-            let dl = l1.clone() - &l0;
-            let dc = c1.clone();
-
-            let k_l = T::from_f64(0.3963377774) * &a + T::from_f64(0.2158037573) * &b;
-            let k_m = -T::from_f64(0.1055613458) * &a - T::from_f64(0.0638541728) * &b;
-            let k_s = -T::from_f64(0.0894841775) * a - T::from_f64(1.2914855480) * b;
-
-            let l_dt = dl.clone() + dc.clone() * &k_l;
-            let m_dt = dl.clone() + dc.clone() * &k_m;
-            let s_dt = dl + dc * &k_s;
-
-            // If higher accuracy is required, 2 or 3 iterations of the following block can be used:
-            {
-                let lightness = l0 * (T::one() - &t) + t.clone() * l1;
-                let chroma = t.clone() * c1;
-
-                let l_ = lightness.clone() + chroma.clone() * k_l;
-                let m_ = lightness.clone() + chroma.clone() * k_m;
-                let s_ = lightness + chroma * k_s;
-
-                let l = l_.clone() * &l_ * &l_;
-                let m = m_.clone() * &m_ * &m_;
-                let s = s_.clone() * &s_ * &s_;
-
-                let ldt = T::from_f64(3.0) * &l_dt * &l_ * &l_;
-                let mdt = T::from_f64(3.0) * &m_dt * &m_ * &m_;
-                let sdt = T::from_f64(3.0) * &s_dt * &s_ * &s_;
-
-                let ldt2 = T::from_f64(6.0) * &l_dt * l_dt * l_;
-                let mdt2 = T::from_f64(6.0) * &m_dt * m_dt * m_;
-                let sdt2 = T::from_f64(6.0) * &s_dt * s_dt * s_;
-
-                let r = T::from_f64(4.0767416621) * &l - T::from_f64(3.3077115913) * &m
-                    + T::from_f64(0.2309699292) * &s
-                    - T::one();
-                let r1 = T::from_f64(4.0767416621) * &ldt - T::from_f64(3.3077115913) * &mdt
-                    + T::from_f64(0.2309699292) * &sdt;
-                let r2 = T::from_f64(4.0767416621) * &ldt2 - T::from_f64(3.3077115913) * &mdt2
-                    + T::from_f64(0.2309699292) * &sdt2;
-
-                let u_r = r1.clone() / (r1.clone() * r1 - T::from_f64(0.5) * &r * r2);
-                let mut t_r = -r * &u_r;
-
-                let g = -T::from_f64(1.2684380046) * &l + T::from_f64(2.6097574011) * &m
-                    - T::from_f64(0.3413193965) * &s
-                    - T::one();
-                let g1 = -T::from_f64(1.2684380046) * &ldt + T::from_f64(2.6097574011) * &mdt
-                    - T::from_f64(0.3413193965) * &sdt;
-                let g2 = -T::from_f64(1.2684380046) * &ldt2 + T::from_f64(2.6097574011) * &mdt2
-                    - T::from_f64(0.3413193965) * &sdt2;
-
-                let u_g = g1.clone() / (g1.clone() * g1 - T::from_f64(0.5) * &g * g2);
-                let mut t_g = -g * &u_g;
-
-                let b = -T::from_f64(0.0041960863) * l - T::from_f64(0.7034186147) * m
-                    + T::from_f64(1.7076147010) * s
-                    - T::one();
-                let b1 = -T::from_f64(0.0041960863) * ldt - T::from_f64(0.7034186147) * mdt
-                    + T::from_f64(1.7076147010) * sdt;
-                let b2 = -T::from_f64(0.0041960863) * ldt2 - T::from_f64(0.7034186147) * mdt2
-                    + T::from_f64(1.7076147010) * sdt2;
-
-                let u_b = b1.clone() / (b1.clone() * b1 - T::from_f64(0.5) * &b * b2);
-                let mut t_b = -b * &u_b;
-
-                // flt_max really is a constant, but cannot be defined as one due to the T::from_f64 function
-                let flt_max = T::from_f64(10e5);
-
-                t_r = if u_r >= T::zero() {
-                    t_r
-                } else {
-                    flt_max.clone()
-                };
-                t_g = if u_g >= T::zero() {
-                    t_g
-                } else {
-                    flt_max.clone()
-                };
-                t_b = if u_b >= T::zero() { t_b } else { flt_max };
-
-                t + T::min(t_r, T::min(t_g, t_b))
-            }
+            let lightness = l0 * (T::one() - &t) + t.clone() * l1;
+            let chroma = t.clone() * c1;
+
+            let l_ = lightness.clone() + chroma.clone() * k_l;
+            let m_ = lightness.clone() + chroma.clone() * k_m;
+            let s_ = lightness + chroma * k_s;
+
+            let l = l_.clone() * &l_ * &l_;
+            let m = m_.clone() * &m_ * &m_;
+            let s = s_.clone() * &s_ * &s_;
+
+            let ldt = T::from_f64(3.0) * &l_dt * &l_ * &l_;
+            let mdt = T::from_f64(3.0) * &m_dt * &m_ * &m_;
+            let sdt = T::from_f64(3.0) * &s_dt * &s_ * &s_;
+
+            let ldt2 = T::from_f64(6.0) * &l_dt * l_dt * l_;
+            let mdt2 = T::from_f64(6.0) * &m_dt * m_dt * m_;
+            let sdt2 = T::from_f64(6.0) * &s_dt * s_dt * s_;
+
+            let r = T::from_f64(4.076741661347994) * &l - T::from_f64(3.3077115904081933) * &m
+                + T::from_f64(0.2309699287294279) * &s
+                - T::one();
+            let r1 = T::from_f64(4.076741661347994) * &ldt - T::from_f64(3.3077115904081933) * &mdt
+                + T::from_f64(0.2309699287294279) * &sdt;
+            let r2 = T::from_f64(4.076741661347994) * &ldt2
+                - T::from_f64(3.3077115904081933) * &mdt2
+                + T::from_f64(0.2309699287294279) * &sdt2;
+
+            let u_r = r1.clone() / (r1.clone() * r1 - T::from_f64(0.5) * &r * r2);
+            let mut t_r = -r * &u_r;
+
+            let g = -T::from_f64(1.268438004092176) * &l + T::from_f64(2.6097574006633715) * &m
+                - T::from_f64(0.3413193963102196) * &s
+                - T::one();
+            let g1 = -T::from_f64(1.268438004092176) * &ldt
+                + T::from_f64(2.6097574006633715) * &mdt
+                - T::from_f64(0.3413193963102196) * &sdt;
+            let g2 = -T::from_f64(1.268438004092176) * &ldt2
+                + T::from_f64(2.6097574006633715) * &mdt2
+                - T::from_f64(0.3413193963102196) * &sdt2;
+
+            let u_g = g1.clone() / (g1.clone() * g1 - T::from_f64(0.5) * &g * g2);
+            let mut t_g = -g * &u_g;
+
+            let b = -T::from_f64(0.004196086541837109) * l - T::from_f64(0.7034186144594496) * m
+                + T::from_f64(1.7076147009309448) * s
+                - T::one();
+            let b1 = -T::from_f64(0.004196086541837109) * ldt
+                - T::from_f64(0.7034186144594496) * mdt
+                + T::from_f64(1.7076147009309448) * sdt;
+            let b2 = -T::from_f64(0.004196086541837109) * ldt2
+                - T::from_f64(0.7034186144594496) * mdt2
+                + T::from_f64(1.7076147009309448) * sdt2;
+
+            let u_b = b1.clone() / (b1.clone() * b1 - T::from_f64(0.5) * &b * b2);
+            let mut t_b = -b * &u_b;
+
+            // flt_max really is a constant, but cannot be defined as one due to the T::from_f64 function
+            let flt_max = T::from_f64(10e5);
+
+            t_r = u_r.gt_eq(&T::zero()).select(t_r, flt_max.clone());
+            t_g = u_g.gt_eq(&T::zero()).select(t_g, flt_max.clone());
+            t_b = u_b.gt_eq(&T::zero()).select(t_b, flt_max);
+
+            t + T::min(t_r, T::min(t_g, t_b))
         }
     }
 }
@@ -129,17 +146,8 @@ pub struct ChromaValues<T> {
 
 impl<T> ChromaValues<T>
 where
-    T: Real
-        + One
-        + Zero
-        + Arithmetics
-        + MinMax
-        + Cbrt
-        + Sqrt
-        + Powi
-        + Clone
-        + HasBoolMask<Mask = bool>
-        + PartialOrd,
+    T: Real + One + Zero + Arithmetics + MinMax + Cbrt + Sqrt + Powi + Clone + PartialCmp,
+    T::Mask: LazySelect<T> + Clone,
     Oklab<T>: IntoColorUnclamped<LinSrgb<T>>,
 {
     // Corresponds to `get_Cs` in the reference implementation. Assumes that
@@ -228,7 +236,8 @@ pub(crate) const MAX_SRGB_SATURATION_INACCURACY: f64 = 1e-6;
 
 impl<T> LC<T>
 where
-    T: Real + One + Arithmetics + Powi + HasBoolMask<Mask = bool> + PartialOrd + Clone,
+    T: Real + One + Arithmetics + Powi + PartialCmp + Clone,
+    T::Mask: LazySelect<T> + Clone,
 {
     /// Returns the cusp of the geometrical shape of representable `sRGB` colors for
     /// normalized `a` and `b` values of an `OKlabHue`, where "normalized" means, `a² + b² == 1`.
@@ -272,52 +281,37 @@ where
         // Select different coefficients depending on which component goes below zero first
         // wl, wm and ws are coefficients for https://en.wikipedia.org/wiki/LMS_color_space
         // -- the color space modelling human perception.
-        let (k0, k1, k2, k3, k4, wl, wm, ws) =
-            if T::from_f64(-1.88170328) * &a - T::from_f64(0.80936493) * &b > T::one() {
-                // red component at zero first
-                (
-                    T::from_f64(1.19086277),
-                    T::from_f64(1.76576728),
-                    T::from_f64(0.59662641),
-                    T::from_f64(0.75515197),
-                    T::from_f64(0.56771245),
-                    T::from_f64(4.0767416621),
-                    T::from_f64(-3.3077115913),
-                    T::from_f64(0.2309699292),
-                )
-            } else if T::from_f64(1.81444104) * &a - T::from_f64(1.19445276) * &b > T::one() {
-                //green component at zero first
-                (
-                    T::from_f64(0.73956515),
-                    T::from_f64(-0.45954404),
-                    T::from_f64(0.08285427),
-                    T::from_f64(0.12541070),
-                    T::from_f64(0.14503204),
-                    T::from_f64(-1.2684380046),
-                    T::from_f64(2.6097574011),
-                    T::from_f64(-0.3413193965),
-                )
-            } else {
-                //blue component at zero first
-                (
-                    T::from_f64(1.35733652),
-                    T::from_f64(-0.00915799),
-                    T::from_f64(-1.15130210),
-                    T::from_f64(-0.50559606),
-                    T::from_f64(0.00692167),
-                    T::from_f64(-0.0041960863),
-                    T::from_f64(-0.7034186147),
-                    T::from_f64(1.7076147010),
-                )
-            };
+        let is_red_first =
+            (T::from_f64(-1.88170328) * &a - T::from_f64(0.80936493) * &b).gt(&T::one());
+        let is_green_first =
+            (T::from_f64(1.81444104) * &a - T::from_f64(1.19445276) * &b).gt(&T::one());
+
+        // Picks the coefficient for the red, green or blue component, in that
+        // order.
+        let coefficient = |red: f64, green: f64, blue: f64| {
+            lazy_select! {
+                if is_red_first.clone() => T::from_f64(red),
+                if is_green_first.clone() => T::from_f64(green),
+                else => T::from_f64(blue),
+            }
+        };
+
+        let k0 = coefficient(1.19086277, 0.73956515, 1.35733652);
+        let k1 = coefficient(1.76576728, -0.45954404, -0.00915799);
+        let k2 = coefficient(0.59662641, 0.08285427, -1.15130210);
+        let k3 = coefficient(0.75515197, 0.12541070, -0.50559606);
+        let k4 = coefficient(0.56771245, 0.14503204, 0.00692167);
+        let wl = coefficient(4.076741661347994, -1.268438004092176, -0.004196086541837109);
+        let wm = coefficient(-3.3077115904081933, 2.6097574006633715, -0.7034186144594496);
+        let ws = coefficient(0.2309699287294279, -0.3413193963102196, 1.7076147009309448);
 
         // Approximate max saturation using a polynomial
         let mut approx_max_saturation =
             k0 + k1 * &a + k2 * &b + k3 * a.clone().powi(2) + k4 * &a * &b;
         // Get closer with Halley's method
-        let k_l = T::from_f64(0.3963377774) * &a + T::from_f64(0.2158037573) * &b;
-        let k_m = T::from_f64(-0.1055613458) * &a - T::from_f64(0.0638541728) * &b;
-        let k_s = T::from_f64(-0.0894841775) * a - T::from_f64(1.2914855480) * b;
+        let k_l = T::from_f64(0.39633779217376786) * &a + T::from_f64(0.2158037580607588) * &b;
+        let k_m = T::from_f64(-0.10556134232365635) * &a - T::from_f64(0.06385417477170591) * &b;
+        let k_s = T::from_f64(-0.08948418209496575) * a - T::from_f64(1.2914855378640917) * b;
 
         for _i in 0..MAX_SRGB_SATURATION_SEARCH_MAX_ITER {
             let l_ = T::one() + approx_max_saturation.clone() * &k_l;
@@ -356,16 +350,8 @@ where
 #[cfg(test)]
 impl<T> OklabHue<T>
 where
-    T: RealAngle
-        + One
-        + Arithmetics
-        + Trigonometry
-        + MinMax
-        + Cbrt
-        + Powi
-        + HasBoolMask<Mask = bool>
-        + PartialOrd
-        + Clone,
+    T: RealAngle + One + Arithmetics + Trigonometry + MinMax + Cbrt + Powi + PartialCmp + Clone,
+    T::Mask: LazySelect<T> + Clone,
     Oklab<T>: IntoColorUnclamped<LinSrgb<T>>,
 {
     pub(crate) fn srgb_limits(self) -> (LC<T>, T, T) {
@@ -489,6 +475,21 @@ where
     (l_r.clone().powi(2) + k_1 * &l_r) / (k_3 * (l_r + k_2))
 }
 
+/// Checks if an `Oklab` lightness is so close to black or white that the gamut
+/// calculations become numerically unstable.
+///
+/// The sRGB gamut narrows down to a single point at both ends of the lightness
+/// scale, so colors in this range are treated as achromatic. The margin is
+/// small enough to not be visible in 8 or 16 bit sRGB.
+pub(crate) fn is_extreme_lightness<T>(oklab_lightness: &T) -> T::Mask
+where
+    T: Real + Arithmetics + One + PartialCmp,
+    T::Mask: BitOps,
+{
+    let epsilon = T::from_f64(1e-6);
+    oklab_lightness.lt_eq(&epsilon) | oklab_lightness.gt_eq(&(T::one() - epsilon))
+}
+
 #[cfg(feature = "approx")]
 #[cfg(test)]
 mod tests {
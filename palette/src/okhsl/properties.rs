@@ -14,6 +14,7 @@ impl_is_within_bounds! {
         saturation => [Self::min_saturation(), Self::max_saturation()],
         lightness => [Self::min_lightness(), Self::max_lightness()]
     }
+    new(hue: impl Into<OklabHue<T>>, saturation: T, lightness: T)
     where T: Stimulus
 }
 impl_clamp! {
@@ -41,6 +42,8 @@ impl_simd_array_conversion_hue!(Okhsl, [saturation, lightness]);
 impl_struct_of_array_traits_hue!(Okhsl, OklabHueIter, [saturation, lightness]);
 
 impl_eq_hue!(Okhsl, OklabHue, [hue, saturation, lightness]);
+impl_select!(Okhsl, [hue, saturation, lightness]);
+impl_relative_luminance!(Okhsl via crate::Xyz<crate::white_point::D65, T>);
 
 #[allow(deprecated)]
 impl<T> crate::RelativeContrast for Okhsl<T>
@@ -7,16 +7,18 @@ pub use alpha::Okhsva;
 pub use random::UniformOkhsv;
 
 use crate::{
-    angle::FromAngle,
-    bool_mask::LazySelect,
+    angle::{FromAngle, RealAngle},
+    bool_mask::{BitOps, LazySelect},
     convert::{FromColorUnclamped, IntoColorUnclamped},
     num::{
-        Arithmetics, Cbrt, Hypot, IsValidDivisor, MinMax, One, Powi, Real, Sqrt, Trigonometry, Zero,
+        Arithmetics, Cbrt, Hypot, IsValidDivisor, MinMax, One, PartialCmp, Powi, Real, Sqrt,
+        Trigonometry, Zero,
     },
     ok_utils::{self, LC, ST},
+    rgb::Rgb,
     stimulus::{FromStimulus, Stimulus},
     white_point::D65,
-    GetHue, HasBoolMask, LinSrgb, Okhwb, Oklab, OklabHue,
+    GetHue, HasBoolMask, Hsv, LinSrgb, Okhwb, Oklab, OklabHue,
 };
 
 pub use self::properties::Iter;
@@ -41,7 +43,7 @@ mod visual_eq;
     palette_internal,
     white_point = "D65",
     component = "T",
-    skip_derives(Oklab, Okhwb)
+    skip_derives(Oklab, Okhwb, Hsv)
 )]
 #[repr(C)]
 pub struct Okhsv<T = f32> {
@@ -125,6 +127,7 @@ where
 
 impl_reference_component_methods_hue!(Okhsv, [saturation, value]);
 impl_struct_of_arrays_methods_hue!(Okhsv, [saturation, value]);
+impl_sort_keys!(Okhsv { hue });
 
 impl<T> Okhsv<T> {
     /// Create an `Okhsv` color.
@@ -146,6 +149,14 @@ impl<T> Okhsv<T> {
         }
     }
 
+    /// Create an `Okhsv` color, with the hue in radians instead of degrees.
+    pub fn new_radians(hue: T, saturation: T, value: T) -> Self
+    where
+        T: RealAngle,
+    {
+        Self::new_const(OklabHue::from_radians(hue), saturation, value)
+    }
+
     /// Convert into another component type.
     pub fn into_format<U>(self) -> Okhsv<U>
     where
@@ -188,72 +199,89 @@ where
         + Zero
         + Hypot
         + One
-        + IsValidDivisor<Mask = bool>
-        + HasBoolMask<Mask = bool>
-        + PartialOrd,
+        + IsValidDivisor
+        + PartialCmp,
+    T::Mask: LazySelect<T> + BitOps + Clone,
     Oklab<T>: GetHue<Hue = OklabHue<T>> + IntoColorUnclamped<LinSrgb<T>>,
 {
     fn from_color_unclamped(lab: Oklab<T>) -> Self {
-        if lab.l == T::zero() {
-            // the color is pure black
-            return Self::new(T::zero(), T::zero(), T::zero());
-        }
+        // The color is pure black.
+        let is_black = lab.l.eq(&T::zero());
 
         let chroma = lab.get_chroma();
         let hue = lab.get_hue();
-        if chroma.is_valid_divisor() {
-            let (a_, b_) = (lab.a / &chroma, lab.b / &chroma);
-
-            // For each hue the sRGB gamut can be drawn on a 2-dimensional space.
-            // Let L_r, the lightness in relation to the possible luminance of sRGB, be spread
-            // along the y-axis (bottom is black, top is bright) and Chroma along the x-axis
-            // (left is desaturated, right is colorful). The gamut then takes a triangular shape,
-            // with a concave top side and a cusp to the right.
-            // To use saturation and brightness values, the gamut must be mapped to a square.
-            // The lower point of the triangle is expanded to the lower side of the square.
-            // The left side remains unchanged and the cusp of the triangle moves to the upper right.
-            let cusp = LC::find_cusp(a_.clone(), b_.clone());
-            let st_max = ST::<T>::from(cusp);
-
-            let s_0 = T::from_f64(0.5);
-            let k = T::one() - s_0.clone() / st_max.s;
-
-            // first we find L_v, C_v, L_vt and C_vt
-            let t = st_max.t.clone() / (chroma.clone() + lab.l.clone() * &st_max.t);
-            let l_v = t.clone() * &lab.l;
-            let c_v = t * chroma;
-
-            let l_vt = ok_utils::toe_inv(l_v.clone());
-            let c_vt = c_v.clone() * &l_vt / &l_v;
-
-            // we can then use these to invert the step that compensates for the toe and the curved top part of the triangle:
-            let rgb_scale: LinSrgb<T> =
-                Oklab::new(l_vt, a_ * &c_vt, b_ * c_vt).into_color_unclamped();
-            let lightness_scale_factor = T::cbrt(
-                T::one()
-                    / T::max(
-                        T::max(rgb_scale.red, rgb_scale.green),
-                        T::max(rgb_scale.blue, T::zero()),
-                    ),
-            );
+        let Oklab { l, a, b } = lab;
+
+        // The color is totally desaturated if the chroma is 0.
+        let is_chromatic = chroma.is_valid_divisor();
+
+        // The rest of the calculations assume a chromatic color. Achromatic
+        // colors are replaced at the end, instead of returning early, to make
+        // it work for SIMD values.
+
+        let (a_, b_) = (a / &chroma, b / &chroma);
+
+        // For each hue the sRGB gamut can be drawn on a 2-dimensional space.
+        // Let L_r, the lightness in relation to the possible luminance of sRGB, be spread
+        // along the y-axis (bottom is black, top is bright) and Chroma along the x-axis
+        // (left is desaturated, right is colorful). The gamut then takes a triangular shape,
+        // with a concave top side and a cusp to the right.
+        // To use saturation and brightness values, the gamut must be mapped to a square.
+        // The lower point of the triangle is expanded to the lower side of the square.
+        // The left side remains unchanged and the cusp of the triangle moves to the upper right.
+        let cusp = LC::find_cusp(a_.clone(), b_.clone());
+        let st_max = ST::<T>::from(cusp);
+
+        let s_0 = T::from_f64(0.5);
+        let k = T::one() - s_0.clone() / st_max.s;
+
+        // first we find L_v, C_v, L_vt and C_vt
+        let t = st_max.t.clone() / (chroma.clone() + l.clone() * &st_max.t);
+        let l_v = t.clone() * &l;
+        let c_v = t * chroma;
+
+        let l_vt = ok_utils::toe_inv(l_v.clone());
+        let c_vt = c_v.clone() * &l_vt / &l_v;
+
+        // we can then use these to invert the step that compensates for the toe and the curved top part of the triangle:
+        let rgb_scale: LinSrgb<T> = Oklab::new(l_vt, a_ * &c_vt, b_ * c_vt).into_color_unclamped();
+        let lightness_scale_factor = T::cbrt(
+            T::one()
+                / T::max(
+                    T::max(rgb_scale.red, rgb_scale.green),
+                    T::max(rgb_scale.blue, T::zero()),
+                ),
+        );
 
-            //chroma = chroma / lightness_scale_factor;
+        //chroma = chroma / lightness_scale_factor;
 
-            // use L_r instead of L and also scale C by L_r/L
-            let l_r = ok_utils::toe(lab.l / lightness_scale_factor);
-            //chroma = chroma * l_r / (lab.l / lightness_scale_factor);
+        // use L_r instead of L and also scale C by L_r/L
+        let l_r = ok_utils::toe(l.clone() / lightness_scale_factor);
+        //chroma = chroma * l_r / (l / lightness_scale_factor);
 
-            // we can now compute v and s:
-            let v = l_r / l_v;
-            let s =
-                (s_0.clone() + &st_max.t) * &c_v / ((st_max.t.clone() * s_0) + st_max.t * k * c_v);
+        // we can now compute v and s:
+        let v = l_r / l_v;
+        let s = (s_0.clone() + &st_max.t) * &c_v / ((st_max.t.clone() * s_0) + st_max.t * k * c_v);
 
-            Self::new(hue, s, v)
-        } else {
-            // the color is totally desaturated.
-            let v = ok_utils::toe(lab.l);
-            Self::new(T::zero(), T::zero(), v)
-        }
+        // Colors very close to black or white are also treated as achromatic,
+        // since the gamut calculations become unstable there.
+        let is_achromatic = is_black.clone() | !is_chromatic | ok_utils::is_extreme_lightness(&l);
+
+        let hue = lazy_select! {
+            if is_achromatic.clone() => T::zero(),
+            else => hue.into_inner(),
+        };
+        let s = lazy_select! {
+            if is_achromatic.clone() => T::zero(),
+            else => s,
+        };
+        let v = lazy_select! {
+            if is_black => T::zero(),
+            if is_achromatic => ok_utils::toe(l),
+            else => v,
+        };
+
+        Self::new(hue, s, v)
     }
 }
 impl<T> FromColorUnclamped<Okhwb<T>> for Okhsv<T>
@@ -284,6 +312,34 @@ where
     }
 }
 
+impl<S, T> FromColorUnclamped<Hsv<S, T>> for Okhsv<T>
+where
+    Rgb<S, T>: FromColorUnclamped<Hsv<S, T>>,
+    Oklab<T>: FromColorUnclamped<Rgb<S, T>>,
+    Self: FromColorUnclamped<Oklab<T>>,
+{
+    /// Converts `hsv` to `Okhsv` via RGB and [`Oklab`], without detouring
+    /// through [`Xyz`](crate::Xyz) when `S` is sRGB.
+    fn from_color_unclamped(hsv: Hsv<S, T>) -> Self {
+        let rgb = Rgb::<S, T>::from_color_unclamped(hsv);
+        Self::from_color_unclamped(Oklab::from_color_unclamped(rgb))
+    }
+}
+
+impl<S, T> FromColorUnclamped<Okhsv<T>> for Hsv<S, T>
+where
+    Oklab<T>: FromColorUnclamped<Okhsv<T>>,
+    Rgb<S, T>: FromColorUnclamped<Oklab<T>>,
+    Self: FromColorUnclamped<Rgb<S, T>>,
+{
+    /// Converts `okhsv` to `Hsv` via [`Oklab`] and RGB, without detouring
+    /// through [`Xyz`](crate::Xyz) when `S` is sRGB.
+    fn from_color_unclamped(okhsv: Okhsv<T>) -> Self {
+        let rgb = Rgb::<S, T>::from_color_unclamped(Oklab::from_color_unclamped(okhsv));
+        Self::from_color_unclamped(rgb)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{convert::FromColorUnclamped, Clamp, IsWithinBounds, LinSrgb, Okhsv, Oklab};
@@ -318,8 +374,14 @@ mod tests {
                     Oklab::from_color_unclamped(LinSrgb::new(1.0, 0.0, 1.0)),
                 ),
                 (
+                    // White is achromatic, so the rounding noise in a and b
+                    // from the conversion matrices is not preserved.
                     "white",
-                    Oklab::from_color_unclamped(LinSrgb::new(1.0, 1.0, 1.0)),
+                    Oklab::new(
+                        Oklab::from_color_unclamped(LinSrgb::new(1.0, 1.0, 1.0)).l,
+                        0.0,
+                        0.0,
+                    ),
                 ),
                 (
                     "black",
@@ -383,12 +445,16 @@ mod tests {
             // 1 iteration : 264.0520206380550121, 0.9999910912349018, 0.9999999646150918
             // 2 iterations: 264.0520206380550121, 0.9999999869716002, 0.9999999646150844
             // 3 iterations: 264.0520206380550121, 0.9999999869716024, 0.9999999646150842
+            //
+            // The reference implementation rounds the inverse Oklab matrices to
+            // 10 decimals. They are stored with full precision here, which
+            // shifts the saturation slightly and makes the value of pure blue 1.
             #[allow(clippy::excessive_precision)]
             let expected_hue = OklabHue::new(264.0520206380550121);
-            let expected_saturation = 0.9999910912349018;
-            let expected_value = 0.9999999646150918;
+            let expected_saturation = 0.9999910849450256;
+            let expected_value = 1.0;
 
-            // compare to the reference implementation values
+            // compare to the reference implementation values, with 1 iteration
             assert_abs_diff_eq!(okhsv_blue_64.hue, expected_hue, epsilon = 1e-12);
             assert_abs_diff_eq!(
                 okhsv_blue_64.saturation,
@@ -548,6 +614,87 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "approx")]
+    #[test]
+    fn hsv_roundtrip() {
+        use crate::{encoding, visual::VisuallyEqual, FromColor, Hsv, IntoColor, Srgb};
+
+        let colors = [
+            Srgb::new(1.0f64, 0.0, 0.0),
+            Srgb::new(0.2, 0.6, 0.3),
+            Srgb::new(0.9, 0.8, 0.1),
+            Srgb::new(0.1, 0.2, 0.9),
+            Srgb::new(0.5, 0.5, 0.5),
+        ];
+
+        for rgb in colors {
+            let okhsv: Okhsv<f64> = rgb.into_color();
+            let hsv: Hsv<encoding::Srgb, f64> = okhsv.into_color();
+            assert_relative_eq!(Srgb::from_color(hsv), rgb, epsilon = 1e-7);
+
+            let roundtrip: Okhsv<f64> = hsv.into_color();
+            assert!(Okhsv::visually_eq(roundtrip, okhsv, 1e-6));
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn black_and_white_roundtrip() {
+        use crate::{FromColor, Srgb};
+
+        let black = Okhsv::from_color(Srgb::new(0.0f64, 0.0, 0.0));
+        assert_eq!(black, Okhsv::new(0.0, 0.0, 0.0));
+        let black = Okhsv::from_color(Srgb::new(0.0f32, 0.0, 0.0));
+        assert_eq!(black, Okhsv::new(0.0, 0.0, 0.0));
+
+        let white = Okhsv::from_color(Srgb::new(1.0f64, 1.0, 1.0));
+        assert_eq!(white.hue, 0.0);
+        assert_eq!(white.saturation, 0.0);
+        assert_relative_eq!(white.value, 1.0, epsilon = 1e-6);
+        let white = Okhsv::from_color(Srgb::new(1.0f32, 1.0, 1.0));
+        assert_eq!(white.hue, 0.0);
+        assert_eq!(white.saturation, 0.0);
+        assert_relative_eq!(white.value, 1.0, epsilon = 1e-6);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn srgb_corners_are_finite() {
+        use crate::{FromColor, Srgb};
+
+        let corners = [
+            Srgb::new(0.0f64, 0.0, 0.0),
+            Srgb::new(1.0, 0.0, 0.0),
+            Srgb::new(0.0, 1.0, 0.0),
+            Srgb::new(0.0, 0.0, 1.0),
+            Srgb::new(1.0, 1.0, 0.0),
+            Srgb::new(1.0, 0.0, 1.0),
+            Srgb::new(0.0, 1.0, 1.0),
+            Srgb::new(1.0, 1.0, 1.0),
+            Srgb::new(1e-12, 1e-12, 1e-12),
+            Srgb::new(1e-12, 0.0, 0.0),
+            Srgb::new(1.0 - 1e-12, 1.0, 1.0),
+        ];
+
+        for rgb in corners {
+            let okhsv = Okhsv::from_color(rgb);
+            assert!(okhsv.hue.into_inner().is_finite(), "{:?}", okhsv);
+            assert!(okhsv.saturation.is_finite(), "{:?}", okhsv);
+            assert!(okhsv.value.is_finite(), "{:?}", okhsv);
+
+            let roundtrip = Srgb::from_color(okhsv);
+            assert_relative_eq!(roundtrip, rgb, epsilon = 1e-3);
+
+            let okhsv = Okhsv::from_color(rgb.into_format::<f32>());
+            assert!(okhsv.hue.into_inner().is_finite(), "{:?}", okhsv);
+            assert!(okhsv.saturation.is_finite(), "{:?}", okhsv);
+            assert!(okhsv.value.is_finite(), "{:?}", okhsv);
+
+            let roundtrip = Srgb::from_color(okhsv);
+            assert_relative_eq!(roundtrip, rgb.into_format(), epsilon = 1e-3);
+        }
+    }
+
     struct_of_arrays_tests!(
         Okhsv[hue, saturation, value],
         super::Okhsva::new(0.1f32, 0.2, 0.3, 0.4),
@@ -11,6 +11,7 @@ impl_is_within_bounds! {
         saturation => [Self::min_saturation(), Self::max_saturation()+ T::from_f64(ok_utils::MAX_SRGB_SATURATION_INACCURACY)],
         value => [Self::min_value(), Self::max_value()+ T::from_f64(ok_utils::MAX_SRGB_SATURATION_INACCURACY)]
     }
+    new(hue: impl Into<OklabHue<T>>, saturation: T, value: T)
     where T: Real+Arithmetics+Stimulus
 }
 
@@ -36,3 +37,5 @@ impl_simd_array_conversion_hue!(Okhsv, [saturation, value]);
 impl_struct_of_array_traits_hue!(Okhsv, OklabHueIter, [saturation, value]);
 
 impl_eq_hue!(Okhsv, OklabHue, [hue, saturation, value]);
+impl_select!(Okhsv, [hue, saturation, value]);
+impl_relative_luminance!(Okhsv via crate::Xyz<crate::white_point::D65, T>);
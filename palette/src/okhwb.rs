@@ -5,12 +5,12 @@ use core::fmt::Debug;
 pub use alpha::Okhwba;
 
 use crate::{
-    angle::FromAngle,
+    angle::{FromAngle, RealAngle},
     convert::FromColorUnclamped,
     num::{Arithmetics, One},
     stimulus::{FromStimulus, Stimulus},
     white_point::D65,
-    HasBoolMask, Okhsv, OklabHue,
+    HasBoolMask, Hsv, Hwb, Okhsv, OklabHue,
 };
 
 pub use self::properties::Iter;
@@ -34,7 +34,7 @@ mod visual_eq;
     palette_internal,
     white_point = "D65",
     component = "T",
-    skip_derives(Okhwb, Okhsv)
+    skip_derives(Okhwb, Okhsv, Hwb)
 )]
 #[repr(C)]
 pub struct Okhwb<T = f32> {
@@ -59,6 +59,8 @@ pub struct Okhwb<T = f32> {
     pub blackness: T,
 }
 
+impl_tuple_conversion_hue!(Okhwb as (H, T, T), OklabHue);
+
 impl<T> Okhwb<T> {
     /// Create an `Okhwb` color.
     pub fn new<H: Into<OklabHue<T>>>(hue: H, whiteness: T, blackness: T) -> Self {
@@ -79,6 +81,14 @@ impl<T> Okhwb<T> {
             blackness,
         }
     }
+
+    /// Create an `Okhwb` color, with the hue in radians instead of degrees.
+    pub fn new_radians(hue: T, whiteness: T, blackness: T) -> Self
+    where
+        T: RealAngle,
+    {
+        Self::new_const(OklabHue::from_radians(hue), whiteness, blackness)
+    }
     /// Convert into another component type.
     pub fn into_format<U>(self) -> Okhwb<U>
     where
@@ -128,6 +138,7 @@ where
 
 impl_reference_component_methods_hue!(Okhwb, [whiteness, blackness]);
 impl_struct_of_arrays_methods_hue!(Okhwb, [whiteness, blackness]);
+impl_sort_keys!(Okhwb { hue });
 
 impl<T> FromColorUnclamped<Okhsv<T>> for Okhwb<T>
 where
@@ -144,6 +155,32 @@ where
     }
 }
 
+impl<S, T> FromColorUnclamped<Hwb<S, T>> for Okhwb<T>
+where
+    Hsv<S, T>: FromColorUnclamped<Hwb<S, T>>,
+    Okhsv<T>: FromColorUnclamped<Hsv<S, T>>,
+    Self: FromColorUnclamped<Okhsv<T>>,
+{
+    /// Converts `hwb` to `Okhwb` via [`Hsv`] and [`Okhsv`].
+    fn from_color_unclamped(hwb: Hwb<S, T>) -> Self {
+        let hsv = Hsv::<S, T>::from_color_unclamped(hwb);
+        Self::from_color_unclamped(Okhsv::from_color_unclamped(hsv))
+    }
+}
+
+impl<S, T> FromColorUnclamped<Okhwb<T>> for Hwb<S, T>
+where
+    Okhsv<T>: FromColorUnclamped<Okhwb<T>>,
+    Hsv<S, T>: FromColorUnclamped<Okhsv<T>>,
+    Self: FromColorUnclamped<Hsv<S, T>>,
+{
+    /// Converts `okhwb` to `Hwb` via [`Okhsv`] and [`Hsv`].
+    fn from_color_unclamped(okhwb: Okhwb<T>) -> Self {
+        let hsv = Hsv::<S, T>::from_color_unclamped(Okhsv::from_color_unclamped(okhwb));
+        Self::from_color_unclamped(hsv)
+    }
+}
+
 impl<T> HasBoolMask for Okhwb<T>
 where
     T: HasBoolMask,
@@ -205,8 +242,14 @@ mod tests {
                     Oklab::from_color_unclamped(LinSrgb::new(1.0, 0.0, 1.0)),
                 ),
                 (
+                    // White is achromatic, so the rounding noise in a and b
+                    // from the conversion matrices is not preserved.
                     "white",
-                    Oklab::from_color_unclamped(LinSrgb::new(1.0, 1.0, 1.0)),
+                    Oklab::new(
+                        Oklab::from_color_unclamped(LinSrgb::new(1.0, 1.0, 1.0)).l,
+                        0.0,
+                        0.0,
+                    ),
                 ),
                 (
                     "black",
@@ -281,6 +324,70 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "approx")]
+    #[test]
+    fn hwb_roundtrip() {
+        use crate::{encoding, visual::VisuallyEqual, FromColor, Hwb, IntoColor, Srgb};
+
+        let colors = [
+            Srgb::new(1.0f64, 0.0, 0.0),
+            Srgb::new(0.2, 0.6, 0.3),
+            Srgb::new(0.9, 0.8, 0.1),
+            Srgb::new(0.1, 0.2, 0.9),
+            Srgb::new(0.5, 0.5, 0.5),
+        ];
+
+        for rgb in colors {
+            let okhwb: Okhwb<f64> = rgb.into_color();
+            let hwb: Hwb<encoding::Srgb, f64> = okhwb.into_color();
+            assert_relative_eq!(Srgb::from_color(hwb), rgb, epsilon = 1e-7);
+
+            let roundtrip: Okhwb<f64> = hwb.into_color();
+            assert!(Okhwb::visually_eq(roundtrip, okhwb, 1e-6));
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn okhsv_into_color() {
+        use crate::{visual::VisuallyEqual, IntoColor, Okhsv};
+
+        let okhsv = Okhsv::new(120.0f64, 0.8, 0.6);
+        let okhwb: Okhwb<f64> = okhsv.into_color();
+        let roundtrip: Okhsv<f64> = okhwb.into_color();
+        assert!(Okhsv::visually_eq(roundtrip, okhsv, 1e-6));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn whiten_blacken() {
+        use crate::{Blacken, Lighten, Whiten};
+
+        let color = Okhwb::new(120.0f64, 0.2, 0.4);
+
+        assert_relative_eq!(color.whiten(0.5), Okhwb::new(120.0, 0.6, 0.4));
+        assert_relative_eq!(color.blacken(0.5), Okhwb::new(120.0, 0.2, 0.7));
+        assert_relative_eq!(color.lighten(0.5), Okhwb::new(120.0, 0.6, 0.2));
+        assert_relative_eq!(color.whiten_fixed(1.0), Okhwb::new(120.0, 1.0, 0.4));
+    }
+
+    #[test]
+    fn lighten_keeps_sum_within_one() {
+        use crate::Lighten;
+
+        for &(whiteness, blackness) in &[(0.0, 0.0), (0.2, 0.8), (0.5, 0.5), (0.3, 0.1)] {
+            let color = Okhwb::new(120.0f64, whiteness, blackness);
+
+            for &factor in &[-2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0] {
+                let lightened = color.lighten(factor);
+                assert!(lightened.whiteness + lightened.blackness <= 1.0 + f64::EPSILON);
+
+                let lightened = color.lighten_fixed(factor);
+                assert!(lightened.whiteness + lightened.blackness <= 1.0 + f64::EPSILON);
+            }
+        }
+    }
+
     struct_of_arrays_tests!(
         Okhwb[hue, whiteness, blackness],
         super::Okhwba::new(0.1f32, 0.2, 0.3, 0.4),
@@ -9,7 +9,7 @@ use crate::{
 
 use super::Okhwb;
 
-impl_is_within_bounds_hwb!(Okhwb where T: Stimulus);
+impl_is_within_bounds_hwb!(Okhwb new(hue: impl Into<OklabHue<T>>, whiteness: T, blackness: T) where T: Stimulus);
 impl_clamp_hwb!(Okhwb where T: Stimulus);
 
 impl_mix_hue!(Okhwb {
@@ -45,3 +45,5 @@ where
 }
 
 impl_eq_hue!(Okhwb, OklabHue, [hue, whiteness, blackness]);
+impl_select!(Okhwb, [hue, whiteness, blackness]);
+impl_relative_luminance!(Okhwb via crate::Xyz<crate::white_point::D65, T>);
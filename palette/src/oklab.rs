@@ -6,15 +6,17 @@ pub use alpha::Oklaba;
 
 use crate::{
     angle::RealAngle,
-    bool_mask::HasBoolMask,
+    bool_mask::{BitOps, HasBoolMask, LazySelect, Select},
     convert::{FromColorUnclamped, IntoColorUnclamped},
     encoding::{IntoLinear, Srgb},
     matrix::multiply_3x3_and_vec3,
-    num::{Arithmetics, Cbrt, Hypot, MinMax, One, Powi, Real, Sqrt, Trigonometry, Zero},
-    ok_utils::{toe_inv, ChromaValues, LC, ST},
+    num::{
+        Arithmetics, Cbrt, Hypot, MinMax, One, PartialCmp, Powi, Real, Sqrt, Trigonometry, Zero,
+    },
+    ok_utils::{is_extreme_lightness, toe_inv, ChromaValues, LC, ST},
     rgb::{Rgb, RgbSpace, RgbStandard},
     white_point::D65,
-    LinSrgb, Mat3, Okhsl, Okhsv, Oklch, Xyz,
+    LinSrgb, Okhsl, Okhsv, Oklch, Xyz,
 };
 
 pub use self::properties::Iter;
@@ -38,7 +40,7 @@ mod visual_eq;
 
 /// XYZ to LSM transformation matrix
 #[rustfmt::skip]
-fn m1<T: Real>() -> Mat3<T> {
+fn m1<T: Real>() -> [T; 9] {
     [
         T::from_f64(0.8190224432164319), T::from_f64(0.3619062562801221), T::from_f64(-0.12887378261216414),
         T::from_f64(0.0329836671980271), T::from_f64(0.9292868468965546), T::from_f64(0.03614466816999844),
@@ -48,7 +50,7 @@ fn m1<T: Real>() -> Mat3<T> {
 
 /// LMS to XYZ transformation matrix
 #[rustfmt::skip]
-pub(crate) fn m1_inv<T: Real>() -> Mat3<T> {
+pub(crate) fn m1_inv<T: Real>() -> [T; 9] {
     [
         T::from_f64(1.2268798733741557), T::from_f64(-0.5578149965554813), T::from_f64(0.28139105017721583),
         T::from_f64(-0.04057576262431372), T::from_f64(1.1122868293970594), T::from_f64(-0.07171106666151701),
@@ -58,7 +60,7 @@ pub(crate) fn m1_inv<T: Real>() -> Mat3<T> {
 
 /// LMS to Oklab transformation matrix
 #[rustfmt::skip]
-fn m2<T: Real>() -> Mat3<T> {
+fn m2<T: Real>() -> [T; 9] {
     [
         T::from_f64(0.2104542553), T::from_f64(0.7936177850), T::from_f64(-0.0040720468),
         T::from_f64(1.9779984951), T::from_f64(-2.4285922050), T::from_f64(0.4505937099),
@@ -69,7 +71,7 @@ fn m2<T: Real>() -> Mat3<T> {
 /// Oklab to LMS transformation matrix
 #[rustfmt::skip]
 #[allow(clippy::excessive_precision)]
-pub(crate) fn m2_inv<T: Real>() -> Mat3<T> {
+pub(crate) fn m2_inv<T: Real>() -> [T; 9] {
     [
         T::from_f64(0.99999999845051981432), T::from_f64(0.39633779217376785678), T::from_f64(0.21580375806075880339),
         T::from_f64(1.0000000088817607767), T::from_f64(-0.1055613423236563494), T::from_f64(-0.063854174771705903402),
@@ -110,6 +112,12 @@ pub(crate) fn m2_inv<T: Real>() -> Mat3<T> {
 /// that the eye is adapted to the color's luminance and the hue and chroma are
 /// perceived linearly.
 ///
+/// The `Oklab` transformation is fitted for [`D65`] with the 2° standard
+/// observer, so it only converts from and to [`Xyz<D65>`](crate::Xyz). Colors
+/// with other white points, including
+/// [`D65Degree10`][crate::white_point::D65Degree10], have to be adapted to
+/// [`D65`] first, for example using
+/// [`AdaptIntoUnclamped`][crate::chromatic_adaptation::AdaptIntoUnclamped].
 ///
 /// `Oklab`'s chroma is unlimited. Thus it can represent colors of any color
 /// space (including HDR). `l` is in the range `0.0 .. 1.0` and `a` and `b` are
@@ -242,6 +250,7 @@ where
 
 impl_reference_component_methods!(Oklab, [l, a, b]);
 impl_struct_of_arrays_methods!(Oklab, [l, a, b]);
+impl_sort_keys!(Oklab { lightness: l });
 
 impl<T> Oklab<T>
 where
@@ -278,19 +287,67 @@ fn linear_srgb_to_oklab<T>(c: LinSrgb<T>) -> Oklab<T>
 where
     T: Real + Arithmetics + Cbrt + Copy,
 {
-    let l = T::from_f64(0.4122214708) * c.red
-        + T::from_f64(0.5363325363) * c.green
-        + T::from_f64(0.0514459929) * c.blue;
-    let m = T::from_f64(0.2119034982) * c.red
-        + T::from_f64(0.6806995451) * c.green
-        + T::from_f64(0.1073969566) * c.blue;
-    let s = T::from_f64(0.0883024619) * c.red
-        + T::from_f64(0.2817188376) * c.green
-        + T::from_f64(0.6299787005) * c.blue;
-
-    let l_ = l.cbrt();
-    let m_ = m.cbrt();
-    let s_ = s.cbrt();
+    oklab_lms_to_oklab(linear_srgb_to_oklab_lms(c))
+}
+
+pub(crate) fn oklab_to_linear_srgb<T>(c: Oklab<T>) -> LinSrgb<T>
+where
+    T: Real + Arithmetics + Copy,
+{
+    oklab_lms_to_linear_srgb(oklab_to_oklab_lms(c))
+}
+
+/// Convert linear sRGB to the non-linear LMS values that are used as an
+/// intermediate step when converting to Oklab.
+///
+/// The result is the cube root of the cone responses, `[l', m', s']`, as
+/// described in the [Oklab
+/// post](https://bottosson.github.io/posts/oklab/#converting-from-linear-srgb-to-oklab).
+/// White becomes `[1.0, 1.0, 1.0]` and black becomes `[0.0, 0.0, 0.0]`.
+///
+/// Together with [`oklab_lms_to_oklab`], it's the same as converting from
+/// [`LinSrgb`] to [`Oklab`]. It can be used for building custom color spaces
+/// from the same cone responses.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{
+///     convert::FromColorUnclamped,
+///     oklab::{linear_srgb_to_oklab_lms, oklab_lms_to_oklab},
+///     LinSrgb, Oklab,
+/// };
+///
+/// let rgb = LinSrgb::new(0.8f64, 0.2, 0.4);
+/// let lms = linear_srgb_to_oklab_lms(rgb);
+///
+/// assert_relative_eq!(oklab_lms_to_oklab(lms), Oklab::from_color_unclamped(rgb));
+/// ```
+pub fn linear_srgb_to_oklab_lms<T>(rgb: LinSrgb<T>) -> [T; 3]
+where
+    T: Real + Arithmetics + Cbrt + Copy,
+{
+    let l = T::from_f64(0.4122214708) * rgb.red
+        + T::from_f64(0.5363325363) * rgb.green
+        + T::from_f64(0.0514459929) * rgb.blue;
+    let m = T::from_f64(0.2119034982) * rgb.red
+        + T::from_f64(0.6806995451) * rgb.green
+        + T::from_f64(0.1073969566) * rgb.blue;
+    let s = T::from_f64(0.0883024619) * rgb.red
+        + T::from_f64(0.2817188376) * rgb.green
+        + T::from_f64(0.6299787005) * rgb.blue;
+
+    [l.cbrt(), m.cbrt(), s.cbrt()]
+}
+
+/// Convert the non-linear LMS values `[l', m', s']` to Oklab.
+///
+/// This is the second half of the conversion from linear sRGB to Oklab. See
+/// [`linear_srgb_to_oklab_lms`].
+pub fn oklab_lms_to_oklab<T>(lms: [T; 3]) -> Oklab<T>
+where
+    T: Real + Arithmetics + Copy,
+{
+    let [l_, m_, s_] = lms;
 
     Oklab::new(
         T::from_f64(0.2104542553) * l_ + T::from_f64(0.7936177850) * m_
@@ -302,28 +359,102 @@ where
     )
 }
 
-pub(crate) fn oklab_to_linear_srgb<T>(c: Oklab<T>) -> LinSrgb<T>
+/// Convert Oklab to the non-linear LMS values `[l', m', s']`.
+///
+/// This is the first half of the conversion from Oklab to linear sRGB, and
+/// the inverse of [`oklab_lms_to_oklab`]. See [`oklab_lms_to_linear_srgb`]
+/// for the second half.
+///
+/// Cubing the non-linear LMS values gives the cone responses, which can't be
+/// negative for visible colors. Checking their signs is a cheap first step
+/// before a full gamut check.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{
+///     convert::FromColorUnclamped,
+///     oklab::{oklab_lms_to_linear_srgb, oklab_to_oklab_lms},
+///     LinSrgb, Oklab,
+/// };
+///
+/// let oklab = Oklab::new(0.6f64, 0.1, -0.05);
+/// let lms = oklab_to_oklab_lms(oklab);
+///
+/// assert_relative_eq!(oklab_lms_to_linear_srgb(lms), LinSrgb::from_color_unclamped(oklab));
+/// ```
+pub fn oklab_to_oklab_lms<T>(oklab: Oklab<T>) -> [T; 3]
+where
+    T: Real + Arithmetics + Copy,
+{
+    let l_ = T::from_f64(0.9999999984505198) * oklab.l
+        + T::from_f64(0.39633779217376786) * oklab.a
+        + T::from_f64(0.2158037580607588) * oklab.b;
+    let m_ = T::from_f64(1.0000000088817609) * oklab.l
+        - T::from_f64(0.10556134232365635) * oklab.a
+        - T::from_f64(0.06385417477170591) * oklab.b;
+    let s_ = T::from_f64(1.0000000546724108) * oklab.l
+        - T::from_f64(0.08948418209496575) * oklab.a
+        - T::from_f64(1.2914855378640917) * oklab.b;
+
+    [l_, m_, s_]
+}
+
+/// Convert the non-linear LMS values `[l', m', s']` to linear sRGB.
+///
+/// This is the second half of the conversion from Oklab to linear sRGB, and
+/// the inverse of [`linear_srgb_to_oklab_lms`]. See [`oklab_to_oklab_lms`]
+/// for the first half.
+pub fn oklab_lms_to_linear_srgb<T>(lms: [T; 3]) -> LinSrgb<T>
 where
     T: Real + Arithmetics + Copy,
 {
-    let l_ = c.l + T::from_f64(0.3963377774) * c.a + T::from_f64(0.2158037573) * c.b;
-    let m_ = c.l - T::from_f64(0.1055613458) * c.a - T::from_f64(0.0638541728) * c.b;
-    let s_ = c.l - T::from_f64(0.0894841775) * c.a - T::from_f64(1.2914855480) * c.b;
+    let [l_, m_, s_] = lms;
 
     let l = l_ * l_ * l_;
     let m = m_ * m_ * m_;
     let s = s_ * s_ * s_;
 
     LinSrgb::new(
-        T::from_f64(4.0767416621) * l - T::from_f64(3.3077115913) * m
-            + T::from_f64(0.2309699292) * s,
-        T::from_f64(-1.2684380046) * l + T::from_f64(2.6097574011) * m
-            - T::from_f64(0.3413193965) * s,
-        T::from_f64(-0.0041960863) * l - T::from_f64(0.7034186147) * m
-            + T::from_f64(1.7076147010) * s,
+        T::from_f64(4.076741661347994) * l - T::from_f64(3.3077115904081933) * m
+            + T::from_f64(0.2309699287294279) * s,
+        T::from_f64(-1.268438004092176) * l + T::from_f64(2.6097574006633715) * m
+            - T::from_f64(0.3413193963102196) * s,
+        T::from_f64(-0.004196086541837109) * l - T::from_f64(0.7034186144594496) * m
+            + T::from_f64(1.7076147009309448) * s,
     )
 }
 
+/// Find the largest factor, up to 1, that the chroma of a color can be
+/// scaled by while `is_within_bounds` is true for the scaled color.
+///
+/// `fits` is the result of `is_within_bounds` for the unscaled color, and
+/// gives those lanes a factor of 1. The factor is found with a binary search,
+/// from below, so the scaled color is always within bounds if its lightness
+/// is. This is used for gamut mapping in `Oklab` and `Oklch`, where the
+/// lightness and hue are kept.
+pub(crate) fn max_chroma_factor<T, F>(fits: T::Mask, is_within_bounds: F) -> T
+where
+    T: Real + Zero + One + Arithmetics + HasBoolMask + Clone,
+    T::Mask: Select<T> + Clone,
+    F: Fn(T) -> T::Mask,
+{
+    // 24 halvings are enough to reach the precision of `f32`.
+    const SEARCH_STEPS: usize = 24;
+
+    let mut low = fits.select(T::one(), T::zero());
+    let mut high = T::one();
+
+    for _ in 0..SEARCH_STEPS {
+        let middle = (low.clone() + &high) * T::from_f64(0.5);
+        let fits = is_within_bounds(middle.clone());
+
+        low = fits.clone().select(middle.clone(), low);
+        high = fits.select(high, middle);
+    }
+
+    low
+}
+
 impl<S, T> FromColorUnclamped<Rgb<S, T>> for Oklab<T>
 where
     T: Real + Cbrt + Arithmetics + Copy,
@@ -371,12 +502,12 @@ where
         + Arithmetics
         + Sqrt
         + MinMax
-        + PartialOrd
-        + HasBoolMask<Mask = bool>
+        + PartialCmp
         + Powi
         + Cbrt
         + Trigonometry
         + Clone,
+    T::Mask: LazySelect<T> + BitOps + Clone,
     Oklab<T>: IntoColorUnclamped<LinSrgb<T>>,
 {
     fn from_color_unclamped(hsl: Okhsl<T>) -> Self {
@@ -384,51 +515,73 @@ where
         let s = hsl.saturation;
         let l = hsl.lightness;
 
-        if l == T::one() {
-            return Oklab::new(T::one(), T::zero(), T::zero());
-        } else if l == T::zero() {
-            return Oklab::new(T::zero(), T::zero(), T::zero());
-        }
+        let is_white = l.eq(&T::one());
+        let is_black = l.eq(&T::zero());
 
         let (a_, b_) = h.into_cartesian();
         let oklab_lightness = toe_inv(l);
 
-        let cs = ChromaValues::from_normalized(oklab_lightness.clone(), a_.clone(), b_.clone());
+        // Lightness values very close to black or white would otherwise
+        // produce NaN in the gamut calculations.
+        let is_achromatic = is_white.clone() | &is_black | is_extreme_lightness(&oklab_lightness);
 
-        // Interpolate the three values for C so that:
-        // At s=0: dC/ds = cs.zero, C = 0
-        // At s=0.8: C = cs.mid
-        // At s=1.0: C = cs.max
+        let chroma = lazy_select! {
+            if is_achromatic.clone() => T::zero(),
+            else => {
+                let cs = ChromaValues::from_normalized(oklab_lightness.clone(), a_.clone(), b_.clone());
 
-        let mid = T::from_f64(0.8);
-        let mid_inv = T::from_f64(1.25);
+                // Interpolate the three values for C so that:
+                // At s=0: dC/ds = cs.zero, C = 0
+                // At s=0.8: C = cs.mid
+                // At s=1.0: C = cs.max
 
-        let chroma = if s < mid {
-            let t = mid_inv * s;
+                let mid = T::from_f64(0.8);
+                let mid_inv = T::from_f64(1.25);
 
-            let k_1 = mid * cs.zero;
-            let k_2 = T::one() - k_1.clone() / cs.mid;
+                lazy_select! {
+                    if s.lt(&mid) => {
+                        let t = mid_inv.clone() * &s;
 
-            t.clone() * k_1 / (T::one() - k_2 * t)
-        } else {
-            let t = (s - &mid) / (T::one() - &mid);
+                        let k_1 = mid.clone() * &cs.zero;
+                        let k_2 = T::one() - k_1.clone() / &cs.mid;
 
-            let k_0 = cs.mid.clone();
-            let k_1 = (T::one() - mid) * &cs.mid * &cs.mid * &mid_inv * mid_inv / cs.zero;
-            let k_2 = T::one() - k_1.clone() / (cs.max - cs.mid);
+                        t.clone() * k_1 / (T::one() - k_2 * t)
+                    },
+                    else => {
+                        let t = (s.clone() - &mid) / (T::one() - &mid);
 
-            k_0 + t.clone() * k_1 / (T::one() - k_2 * t)
+                        let k_0 = cs.mid.clone();
+                        let k_1 = (T::one() - &mid) * &cs.mid * &cs.mid * &mid_inv * &mid_inv / &cs.zero;
+                        let k_2 = T::one() - k_1.clone() / (cs.max.clone() - &cs.mid);
+
+                        k_0 + t.clone() * k_1 / (T::one() - k_2 * t)
+                    },
+                }
+            },
         };
 
-        Oklab::new(oklab_lightness, chroma.clone() * a_, chroma * b_)
+        let l = lazy_select! {
+            if is_white => T::one(),
+            if is_black => T::zero(),
+            else => oklab_lightness,
+        };
+        let a = lazy_select! {
+            if is_achromatic.clone() => T::zero(),
+            else => chroma.clone() * a_,
+        };
+        let b = lazy_select! {
+            if is_achromatic => T::zero(),
+            else => chroma * b_,
+        };
+
+        Oklab::new(l, a, b)
     }
 }
 
 impl<T> FromColorUnclamped<Okhsv<T>> for Oklab<T>
 where
     T: RealAngle
-        + PartialOrd
-        + HasBoolMask<Mask = bool>
+        + PartialCmp
         + MinMax
         + Powi
         + Arithmetics
@@ -437,29 +590,27 @@ where
         + Zero
         + Cbrt
         + Trigonometry,
+    T::Mask: LazySelect<T> + BitOps + Clone,
     Oklab<T>: IntoColorUnclamped<LinSrgb<T>>,
 {
     fn from_color_unclamped(hsv: Okhsv<T>) -> Self {
-        if hsv.value == T::zero() {
-            // pure black
-            return Self {
-                l: T::zero(),
-                a: T::zero(),
-                b: T::zero(),
-            };
-        }
-
-        if hsv.saturation == T::zero() {
-            // totally desaturated color -- the triangle is just the 0-chroma-line
-            let l = toe_inv(hsv.value);
-            return Self {
-                l,
-                a: T::zero(),
-                b: T::zero(),
-            };
-        }
-
-        let h_radians = hsv.hue.into_raw_radians();
+        let Okhsv {
+            hue,
+            saturation,
+            value,
+        } = hsv;
+
+        // pure black
+        let is_black = value.eq(&T::zero());
+        // totally desaturated color -- the triangle is just the 0-chroma-line
+        let is_gray = saturation.eq(&T::zero());
+        let is_achromatic = is_black.clone() | &is_gray;
+
+        // The rest of the calculations assume a chromatic color. Achromatic
+        // colors are replaced at the end, instead of returning early, to make
+        // it work for SIMD values.
+
+        let h_radians = hue.into_raw_radians();
         let a_ = T::cos(h_radians.clone());
         let b_ = T::sin(h_radians);
 
@@ -472,17 +623,16 @@ where
 
         // L, C, when v == 1:
         let l_v = T::one()
-            - hsv.saturation.clone() * s_0.clone()
-                / (s_0.clone() + &cusp.t - cusp.t.clone() * &k * &hsv.saturation);
-        let c_v =
-            hsv.saturation.clone() * &cusp.t * &s_0 / (s_0 + &cusp.t - cusp.t * k * hsv.saturation);
+            - saturation.clone() * s_0.clone()
+                / (s_0.clone() + &cusp.t - cusp.t.clone() * &k * &saturation);
+        let c_v = saturation.clone() * &cusp.t * &s_0 / (s_0 + &cusp.t - cusp.t * k * saturation);
 
         // then we compensate for both toe and the curved top part of the triangle:
         let l_vt = toe_inv(l_v.clone());
         let c_vt = c_v.clone() * &l_vt / &l_v;
 
-        let mut lightness = hsv.value.clone() * l_v;
-        let mut chroma = hsv.value * c_v;
+        let mut lightness = value.clone() * l_v;
+        let mut chroma = value.clone() * c_v;
         let lightness_new = toe_inv(lightness.clone());
         chroma = chroma * &lightness_new / lightness;
         // the values may be outside the normal range
@@ -499,7 +649,21 @@ where
         lightness = lightness_new * &lightness_scale_factor;
         chroma = chroma * lightness_scale_factor;
 
-        Oklab::new(lightness, chroma.clone() * a_, chroma * b_)
+        let l = lazy_select! {
+            if is_black => T::zero(),
+            if is_gray => toe_inv(value),
+            else => lightness,
+        };
+        let a = lazy_select! {
+            if is_achromatic.clone() => T::zero(),
+            else => chroma.clone() * a_,
+        };
+        let b = lazy_select! {
+            if is_achromatic => T::zero(),
+            else => chroma * b_,
+        };
+
+        Oklab::new(l, a, b)
     }
 }
 
@@ -521,6 +685,8 @@ where
     }
 }
 
+impl_wcag21_relative_contrast!(Oklab);
+
 #[cfg(feature = "bytemuck")]
 unsafe impl<T> bytemuck::Zeroable for Oklab<T> where T: bytemuck::Zeroable {}
 
@@ -531,8 +697,66 @@ unsafe impl<T> bytemuck::Pod for Oklab<T> where T: bytemuck::Pod {}
 mod test {
     use crate::Oklab;
 
+    #[cfg(feature = "approx")]
+    use crate::Oklch;
+
+    #[test]
+    fn try_new() {
+        assert!(Oklab::try_new(0.5f32, -0.5, 0.7).is_ok());
+        assert!(Oklab::try_new(1.5f32, 0.1, 0.1).is_err());
+        assert!(Oklab::try_new(0.5f32, f32::NAN, 0.1).is_err());
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn amplify_difference() {
+        let reference = Oklab::new(0.6f64, 0.05, 0.02);
+        let color = Oklab::new(0.62, 0.07, 0.01);
+
+        let amplified = reference + (color - reference) * 3.0;
+        assert_relative_eq!(amplified, Oklab::new(0.66, 0.11, -0.01), epsilon = 1e-12);
+
+        let restored = reference + (amplified - reference) / 3.0;
+        assert_relative_eq!(restored, color, epsilon = 1e-12);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "approx"))]
+    #[test]
+    fn amplify_difference_struct_of_arrays() {
+        let differences = [
+            Oklab::new(0.02f32, 0.02, -0.01),
+            Oklab::new(-0.05, 0.01, 0.03),
+            Oklab::new(0.0, -0.04, 0.02),
+        ];
+
+        let mut planar = Oklab::<Vec<f32>>::from_interleaved(&differences);
+        planar *= 3.0;
+        planar /= 2.0;
+
+        let mut l = planar.l.clone();
+        let mut a = planar.a.clone();
+        let mut b = planar.b.clone();
+        let mut slices = Oklab::new(&mut *l, &mut *a, &mut *b);
+        slices *= 2.0;
+
+        for (i, &difference) in differences.iter().enumerate() {
+            assert_relative_eq!(planar.get(i).unwrap().copied(), difference * 1.5);
+            assert_relative_eq!(Oklab::new(l[i], a[i], b[i]), difference * 3.0);
+        }
+    }
+
     test_convert_into_from_xyz!(Oklab);
 
+    test_saturate_cartesian!(
+        Oklab / Oklch[
+            Oklab::<f64>::new(0.63, 0.22, 0.13),
+            Oklab::<f64>::new(0.45, -0.03, -0.31),
+            Oklab::<f64>::new(0.7, -0.05, 0.02),
+            Oklab::<f64>::new(0.5, 0.3, 0.3)
+        ],
+        gray: Oklab::<f64>::new(0.5, 0.0, 0.0)
+    );
+
     #[cfg(feature = "approx")]
     mod conversion {
         use core::str::FromStr;
@@ -542,6 +766,40 @@ mod test {
             FromColor, Lab, LinSrgb, Oklab, Srgb,
         };
 
+        #[test]
+        fn oklab_lms_halves() {
+            use crate::oklab::{
+                linear_srgb_to_oklab_lms, oklab_lms_to_linear_srgb, oklab_lms_to_oklab,
+                oklab_to_oklab_lms,
+            };
+
+            let colors = [
+                LinSrgb::new(0.0f64, 0.0, 0.0),
+                LinSrgb::new(1.0, 1.0, 1.0),
+                LinSrgb::new(1.0, 0.0, 0.0),
+                LinSrgb::new(0.0, 1.0, 0.0),
+                LinSrgb::new(0.0, 0.0, 1.0),
+                LinSrgb::new(0.8, 0.2, 0.4),
+                LinSrgb::new(0.05, 0.6, 0.9),
+            ];
+
+            for rgb in colors {
+                let oklab = Oklab::from_color_unclamped(rgb);
+                let lms = linear_srgb_to_oklab_lms(rgb);
+                assert_eq!(oklab_lms_to_oklab(lms), oklab);
+                assert_relative_eq!(oklab_to_oklab_lms(oklab)[..], lms[..], epsilon = 1e-9);
+
+                let back = oklab_lms_to_linear_srgb(oklab_to_oklab_lms(oklab));
+                assert_eq!(back, LinSrgb::from_color_unclamped(oklab));
+                assert_relative_eq!(back, rgb, epsilon = 1e-9);
+            }
+
+            let [l, m, s] = linear_srgb_to_oklab_lms(LinSrgb::new(1.0f64, 1.0, 1.0));
+            assert_relative_eq!(l, 1.0, epsilon = 1e-9);
+            assert_relative_eq!(m, 1.0, epsilon = 1e-9);
+            assert_relative_eq!(s, 1.0, epsilon = 1e-9);
+        }
+
         /// Asserts that, for any color space, the lightness of pure white is converted to `l == 1.0`
         #[test]
         fn lightness_of_white_is_one() {
@@ -652,6 +910,29 @@ mod test {
         }
     }
 
+    #[test]
+    fn try_get_hue() {
+        use crate::{FromColor, Srgb, TryGetHue};
+
+        assert!(Oklab::new(0.5f32, 0.001, 0.0).try_get_hue().is_some());
+        assert_eq!(Oklab::new(0.5f32, 0.0, 0.0).try_get_hue(), None);
+        assert_eq!(Oklab::new(0.5f32, 0.000005, 0.0).try_get_hue(), None);
+
+        // Converted grays may not be exactly gray.
+        let near_gray = Oklab::<f32>::from_color(Srgb::new(0.3, 0.3, 0.3));
+        assert_eq!(near_gray.try_get_hue(), None);
+    }
+
+    #[test]
+    fn is_achromatic() {
+        use crate::IsAchromatic;
+
+        assert!(Oklab::new(0.5f32, 0.0, 0.0).is_achromatic_within(0.0));
+        assert!(Oklab::new(0.5f32, 0.003, -0.004).is_achromatic_within(0.006));
+        assert!(!Oklab::new(0.5f32, 0.003, -0.004).is_achromatic_within(0.004));
+        assert!(!Oklab::new(0.5f32, 0.1, 0.05).is_achromatic_within(0.005));
+    }
+
     #[test]
     fn ranges() {
         assert_ranges! {
@@ -2,10 +2,10 @@ use core::ops::{Add, Neg};
 
 use crate::{
     angle::RealAngle,
-    bool_mask::LazySelect,
+    bool_mask::{BitOps, LazySelect},
     num::{Arithmetics, One, PartialCmp, Real, Trigonometry, Zero},
     white_point::D65,
-    FromColor, GetHue, OklabHue, Xyz,
+    FromColor, GetHue, IsAchromatic, OklabHue, TryGetHue, Xyz,
 };
 
 use super::Oklab;
@@ -14,6 +14,7 @@ impl_is_within_bounds! {
     Oklab {
         l => [Self::min_l(), Self::max_l()]
     }
+    new(l: T, a: T, b: T)
     where T: Zero + One
 }
 impl_clamp! {
@@ -33,6 +34,7 @@ impl_hyab!(Oklab {
     chroma1: a,
     chroma2: b
 });
+impl_saturate_cartesian!(Oklab {chroma1: a, chroma2: b} max_chroma: crate::Oklch::<T>::max_srgb_chroma());
 impl_lab_color_schemes!(Oklab[l]);
 
 impl<T> GetHue for Oklab<T>
@@ -46,16 +48,49 @@ where
     }
 }
 
+impl<T> TryGetHue for Oklab<T>
+where
+    T: Real + RealAngle + Trigonometry + Arithmetics + PartialCmp + Clone,
+    T::Mask: BitOps,
+{
+    /// The hue is considered meaningful if the chroma, calculated from `a`
+    /// and `b`, is greater than `0.00001`.
+    fn get_hue_masked(&self) -> (OklabHue<T>, T::Mask) {
+        (
+            self.get_hue(),
+            !self.is_achromatic_within(T::from_f64(0.00001)),
+        )
+    }
+}
+
+impl<T> IsAchromatic for Oklab<T>
+where
+    T: Arithmetics + PartialCmp + Clone,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the chroma, calculated from `a`
+    /// and `b`, is at most `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        let chroma_squared = self.a.clone() * &self.a + self.b.clone() * &self.b;
+
+        chroma_squared.lt_eq(&(tolerance.clone() * tolerance))
+    }
+}
+
 impl_color_add!(Oklab, [l, a, b]);
 impl_color_sub!(Oklab, [l, a, b]);
 impl_color_mul!(Oklab, [l, a, b]);
 impl_color_div!(Oklab, [l, a, b]);
+impl_component_wise!(Oklab, [l, a, b]);
 
 impl_array_casts!(Oklab<T>, [T; 3]);
 impl_simd_array_conversion!(Oklab, [l, a, b]);
 impl_struct_of_array_traits!(Oklab, [l, a, b]);
 
 impl_eq!(Oklab, [l, a, b]);
+impl_select!(Oklab, [l, a, b]);
+impl_relative_luminance!(Oklab via crate::Xyz<crate::white_point::D65, T>);
 
 #[allow(deprecated)]
 impl<T> crate::RelativeContrast for Oklab<T>
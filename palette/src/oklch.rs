@@ -3,11 +3,12 @@
 pub use alpha::Oklcha;
 
 use crate::{
-    bool_mask::HasBoolMask,
+    angle::RealAngle,
+    bool_mask::{BitOps, HasBoolMask, LazySelect},
     convert::FromColorUnclamped,
-    num::{Hypot, One, Zero},
+    num::{Hypot, One, PartialCmp, Real, Zero},
     white_point::D65,
-    GetHue, Oklab, OklabHue,
+    GetHue, Oklab, OklabHue, TryGetHue,
 };
 
 pub use self::properties::Iter;
@@ -57,6 +58,9 @@ pub struct Oklch<T = f32> {
 
 impl<T> Oklch<T> {
     /// Create an `Oklch` color.
+    ///
+    /// The components are in `(L, C, h)` order. Use [`Oklch::from_hcl`] for
+    /// the reversed `(h, C, L)` order.
     pub fn new<H: Into<OklabHue<T>>>(l: T, chroma: T, hue: H) -> Self {
         Oklch {
             l,
@@ -71,6 +75,14 @@ impl<T> Oklch<T> {
         Oklch { l, chroma, hue }
     }
 
+    /// Create an `Oklch` color, with the hue in radians instead of degrees.
+    pub fn new_radians(l: T, chroma: T, hue: T) -> Self
+    where
+        T: RealAngle,
+    {
+        Self::new_const(l, chroma, OklabHue::from_radians(hue))
+    }
+
     /// Convert to a `(L, C, h)` tuple.
     pub fn into_components(self) -> (T, T, OklabHue<T>) {
         (self.l, self.chroma, self.hue)
@@ -80,6 +92,30 @@ impl<T> Oklch<T> {
     pub fn from_components<H: Into<OklabHue<T>>>((l, chroma, hue): (T, T, H)) -> Self {
         Self::new(l, chroma, hue)
     }
+
+    /// Create an `Oklch` color from components in `(h, C, L)` order.
+    ///
+    /// This matches the HCL component order in some JavaScript libraries and
+    /// makes it harder to accidentally swap the hue and lightness when porting
+    /// code from them.
+    ///
+    /// ```
+    /// use palette::Oklch;
+    ///
+    /// let color = Oklch::from_hcl(245.74, 0.0993, 0.588);
+    /// assert_eq!(color, Oklch::new(0.588, 0.0993, 245.74));
+    /// ```
+    #[doc(alias = "hcl")]
+    pub fn from_hcl<H: Into<OklabHue<T>>>(hue: H, chroma: T, l: T) -> Self {
+        Self::new(l, chroma, hue)
+    }
+
+    /// Convert to a `(h, C, L)` tuple, the reverse of
+    /// [`into_components`](Oklch::into_components).
+    #[doc(alias = "hcl")]
+    pub fn into_hcl(self) -> (OklabHue<T>, T, T) {
+        (self.hue, self.chroma, self.l)
+    }
 }
 
 impl<T> Oklch<T>
@@ -102,8 +138,61 @@ where
     }
 }
 
+impl<T> Oklch<T>
+where
+    T: Real,
+{
+    /// Return a `chroma` value maximum that includes the sRGB gamut, for use
+    /// in `Saturate` and `Desaturate`. Chroma doesn't have a well defined
+    /// upper bound.
+    pub(crate) fn max_srgb_chroma() -> T {
+        // The most chromatic sRGB color, magenta, has a chroma of about
+        // 0.3225, according to `print_min_max_srgb_chroma_of_all_hues` in
+        // `ok_utils`. This is rounded up to include it.
+        T::from_f64(0.33)
+    }
+}
+
+impl<T> Oklch<T>
+where
+    T: Real + PartialCmp + Clone,
+    T::Mask: BitOps + LazySelect<T>,
+{
+    /// Replace the hue with `reference_hue` if the color is achromatic.
+    ///
+    /// Converting an achromatic `Oklch` color to [`Oklab`] and back loses its
+    /// hue, since `Oklab` has no hue to store for gray colors. This
+    /// restores a known hue, such as the hue from before the conversion, to
+    /// keep animations through gray from jumping. The hue is replaced where
+    /// [`TryGetHue::get_hue_masked`] considers it to not be meaningful.
+    ///
+    /// ```
+    /// use palette::{FromColor, Oklab, Oklch};
+    ///
+    /// let gray = Oklch::new(0.5f32, 0.0, 120.0);
+    /// let roundtrip = Oklch::from_color(Oklab::from_color(gray));
+    /// assert_ne!(roundtrip.hue, gray.hue);
+    /// assert_eq!(roundtrip.with_reference_hue(gray.hue).hue, 120.0);
+    /// ```
+    #[must_use]
+    pub fn with_reference_hue<H: Into<OklabHue<T>>>(mut self, reference_hue: H) -> Self {
+        let (hue, has_hue) = self.get_hue_masked();
+        let hue = lazy_select! {
+            if has_hue => hue.into_inner(),
+            else => reference_hue.into().into_inner(),
+        };
+        self.hue = OklabHue::new(hue);
+        self
+    }
+}
+
 impl_reference_component_methods_hue!(Oklch, [l, chroma]);
 impl_struct_of_arrays_methods_hue!(Oklch, [l, chroma]);
+impl_sort_keys!(Oklch {
+    lightness: l,
+    chroma: chroma,
+    hue
+});
 
 impl<T> FromColorUnclamped<Oklch<T>> for Oklch<T> {
     fn from_color_unclamped(color: Oklch<T>) -> Self {
@@ -142,6 +231,8 @@ where
     }
 }
 
+impl_wcag21_relative_contrast!(Oklch);
+
 #[cfg(feature = "bytemuck")]
 unsafe impl<T> bytemuck::Zeroable for Oklch<T> where T: bytemuck::Zeroable {}
 
@@ -154,6 +245,71 @@ mod test {
 
     test_convert_into_from_xyz!(Oklch);
 
+    #[cfg(feature = "approx")]
+    #[test]
+    fn reference_hue_through_gray() {
+        use crate::{FromColor, Mix, Oklab};
+
+        let start = Oklch::<f64>::new(0.6, 0.15, 250.0);
+        let gray = Oklch::new(0.6, 0.0, 250.0);
+
+        for step in 0..=10 {
+            let color = start.mix(gray, f64::from(step) / 10.0);
+            let roundtrip =
+                Oklch::from_color(Oklab::from_color(color)).with_reference_hue(color.hue);
+            assert_relative_eq!(roundtrip, color, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn hcl_order() {
+        let color = Oklch::<f32>::from_hcl(245.74, 0.0993, 0.588);
+        assert_eq!(color, Oklch::new(0.588, 0.0993, 245.74));
+
+        let (hue, chroma, l) = color.into_hcl();
+        assert_eq!(hue, color.hue);
+        assert_eq!(chroma, 0.0993);
+        assert_eq!(l, 0.588);
+    }
+
+    #[test]
+    fn sort_keys() {
+        let ramp: Vec<_> = (0..8)
+            .map(|i| Oklch::new(i as f32 / 8.0, i as f32 / 20.0, i as f32 * 45.0))
+            .collect();
+        let mut shuffled = [
+            ramp[5], ramp[2], ramp[7], ramp[0], ramp[3], ramp[6], ramp[1], ramp[4],
+        ];
+
+        shuffled.sort_by_key(Oklch::sort_key_lightness);
+        assert_eq!(shuffled[..], ramp[..]);
+
+        shuffled.reverse();
+        shuffled.sort_by_key(Oklch::sort_key_chroma);
+        assert_eq!(shuffled[..], ramp[..]);
+
+        shuffled.reverse();
+        shuffled.sort_by_key(Oklch::sort_key_hue);
+        assert_eq!(shuffled[..], ramp[..]);
+    }
+
+    #[test]
+    fn sort_keys_nan() {
+        let mut colors = [
+            Oklch::new(0.5f32, 0.1, 90.0),
+            Oklch::new(f32::NAN, f32::NAN, f32::NAN),
+            Oklch::new(0.2, 0.3, -90.0),
+        ];
+
+        colors.sort_by_key(Oklch::sort_key_lightness);
+        assert_eq!(colors[0].l, 0.2);
+        assert!(colors[2].l.is_nan());
+
+        colors.sort_by_key(Oklch::sort_key_hue);
+        assert_eq!(colors[0].hue, 90.0);
+        assert_eq!(colors[1].hue, -90.0);
+    }
+
     #[cfg(feature = "approx")]
     mod conversion {
         use crate::{
@@ -231,6 +387,30 @@ mod test {
         }
     }
 
+    #[test]
+    fn try_get_hue() {
+        use crate::{OklabHue, Oklcha, TryGetHue};
+
+        assert_eq!(
+            Oklch::new(0.5f32, 0.1, 10.0).try_get_hue(),
+            Some(OklabHue::new(10.0))
+        );
+        assert_eq!(Oklch::new(0.5f32, 0.0, 10.0).try_get_hue(), None);
+        assert_eq!(Oklch::new(0.5f32, 0.000005, 10.0).try_get_hue(), None);
+        assert_eq!(Oklcha::new(0.5f32, 0.0, 10.0, 0.5).try_get_hue(), None);
+    }
+
+    #[test]
+    fn is_achromatic() {
+        use crate::{IsAchromatic, Oklcha};
+
+        assert!(Oklch::new(0.5f32, 0.0, 10.0).is_achromatic_within(0.0));
+        assert!(Oklch::new(0.5f32, 0.004, 10.0).is_achromatic_within(0.005));
+        assert!(!Oklch::new(0.5f32, 0.004, 10.0).is_achromatic_within(0.003));
+        assert!(!Oklch::new(0.5f32, 0.1, 10.0).is_achromatic_within(0.005));
+        assert!(!Oklcha::new(0.5f32, 0.1, 10.0, 0.5).is_achromatic_within(0.005));
+    }
+
     #[test]
     fn ranges() {
         // chroma: 0.0 => infinity
@@ -270,6 +450,32 @@ mod test {
         assert_eq!(deserialized, Oklch::new(0.3, 0.8, 0.1));
     }
 
+    #[test]
+    fn modify_struct_of_arrays() {
+        use crate::{LightenAssign, SaturateAssign, ShiftHueAssign};
+
+        let mut array_of_colors = [
+            Oklch::new(0.2f32, 0.1, -30.0),
+            Oklch::new(0.9, 0.3, 120.0),
+            Oklch::new(0.5, 0.0, 400.0),
+        ];
+        let mut l = array_of_colors.map(|color| color.l);
+        let mut chroma = array_of_colors.map(|color| color.chroma);
+        let mut hue = array_of_colors.map(|color| color.hue.into_inner());
+        let mut color_of_slices = Oklch::<&mut [f32]>::new(&mut l, &mut chroma, &mut hue[..]);
+
+        color_of_slices.lighten_assign(-0.3);
+        color_of_slices.saturate_fixed_assign(0.2);
+        color_of_slices.shift_hue_assign(-60.0);
+        array_of_colors.lighten_assign(-0.3);
+        array_of_colors.saturate_fixed_assign(0.2);
+        array_of_colors.shift_hue_assign(-60.0);
+
+        for (index, color) in array_of_colors.iter().enumerate() {
+            assert_eq!(Oklch::new(l[index], chroma[index], hue[index]), *color);
+        }
+    }
+
     struct_of_arrays_tests!(
         Oklch[l, chroma, hue],
         super::Oklcha::new(0.1f32, 0.2, 0.3, 0.4),
@@ -1,9 +1,9 @@
 use crate::{
-    bool_mask::LazySelect,
+    bool_mask::{BitOps, LazySelect},
     hues::OklabHueIter,
     num::{Arithmetics, One, PartialCmp, Real, Zero},
     white_point::D65,
-    FromColor, OklabHue, Xyz,
+    FromColor, IsAchromatic, OklabHue, TryGetHue, Xyz,
 };
 
 use super::Oklch;
@@ -13,6 +13,7 @@ impl_is_within_bounds! {
         l => [Self::min_l(), Self::max_l()],
         chroma => [Self::min_chroma(), None]
     }
+    new(l: T, chroma: T, hue: impl Into<OklabHue<T>>)
     where T: Zero + One
 }
 impl_clamp! {
@@ -26,8 +27,37 @@ impl_clamp! {
 
 impl_mix_hue!(Oklch { l, chroma });
 impl_lighten!(Oklch increase {l => [Self::min_l(), Self::max_l()]} other {hue, chroma} where T: Zero + One);
+impl_saturate!(Oklch increase {chroma => [Self::min_chroma(), Self::max_srgb_chroma()]} other {hue, l} where T: One);
 impl_hue_ops!(Oklch, OklabHue);
 
+impl<T> TryGetHue for Oklch<T>
+where
+    T: Real + PartialCmp + Clone,
+    T::Mask: BitOps,
+{
+    /// The hue is considered meaningful if the chroma is greater than
+    /// `0.00001`.
+    fn get_hue_masked(&self) -> (OklabHue<T>, T::Mask) {
+        (
+            self.hue.clone(),
+            !self.is_achromatic_within(T::from_f64(0.00001)),
+        )
+    }
+}
+
+impl<T> IsAchromatic for Oklch<T>
+where
+    T: PartialCmp,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the chroma is at most
+    /// `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        self.chroma.lt_eq(&tolerance)
+    }
+}
+
 impl_color_add!(Oklch, [l, chroma, hue]);
 impl_color_sub!(Oklch, [l, chroma, hue]);
 
@@ -36,6 +66,8 @@ impl_simd_array_conversion_hue!(Oklch, [l, chroma]);
 impl_struct_of_array_traits_hue!(Oklch, OklabHueIter, [l, chroma]);
 
 impl_eq_hue!(Oklch, OklabHue, [l, chroma, hue]);
+impl_select!(Oklch, [l, chroma, hue]);
+impl_relative_luminance!(Oklch via crate::Xyz<crate::white_point::D65, T>);
 
 #[allow(deprecated)]
 impl<T> crate::RelativeContrast for Oklch<T>
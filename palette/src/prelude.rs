@@ -0,0 +1,155 @@
+//! A collection of commonly used traits and types.
+//!
+//! Most of Palette's functionality is provided through traits, which have to
+//! be in scope before their methods can be called. This module re-exports the
+//! most commonly used ones, together with the most commonly used color types,
+//! so they can all be imported at once:
+//!
+//! ```
+//! use palette::prelude::*;
+//!
+//! let color = Srgb::new(0.8f32, 0.2, 0.1);
+//! let oklch: Oklch = color.into_linear().into_color();
+//!
+//! let shifted = Srgb::from_linear(oklch.shift_hue(180.0).lighten(0.1).into_color());
+//! let mixed = Oklab::from_color(color.into_linear()).mix(Oklab::new(0.5, 0.0, 0.0), 0.5);
+//!
+//! let components: [[f32; 3]; 2] = [shifted, color].into_arrays();
+//! ```
+//!
+//! The prelude includes:
+//!
+//! * The conversion traits from [`convert`](crate::convert), such as
+//!   [`FromColor`] and [`IntoColor`].
+//! * The color operation traits, such as [`Mix`], [`Lighten`], [`ShiftHue`]
+//!   and [`Clamp`].
+//! * The color difference, composition and color theory traits.
+//! * The extension traits from [`cast`](crate::cast), such as
+//!   [`ComponentsAs`] and [`IntoArrays`].
+//! * The color type aliases for sRGB, such as [`Srgb`] and [`LinSrgb`], and
+//!   the other color types that don't need any additional type parameters,
+//!   such as [`Oklab`] and [`Hsl`].
+//!
+//! Items that are easily mistaken for each other, or that would often collide
+//! with names in the user's code, are left out. This is why [`Srgb`] is the
+//! color type alias from the crate root and not the
+//! [`Srgb`](crate::encoding::Srgb) encoding, and why the generic types, such as
+//! [`Rgb`](crate::rgb::Rgb), the numeric traits from [`num`](crate::num) and
+//! the deprecated items aren't included. The [`Blend`](crate::blend::Blend)
+//! trait is also left out, since its `lighten` and `darken` methods would
+//! make calls to [`Lighten::lighten`] and [`Darken::darken`] ambiguous. They can
+//! still be imported explicitly, next to the prelude:
+//!
+//! ```
+//! use palette::{encoding, prelude::*, rgb::Rgb};
+//!
+//! let color: Rgb<encoding::Srgb, u8> = Srgb::new(255, 128, 0);
+//! ```
+
+#[doc(no_inline)]
+pub use crate::{
+    convert::{
+        FromColor, FromColorMut, FromColorRef, FromColorUnclamped, FromColorUnclampedRef,
+        IntoColor, IntoColorMut, IntoColorRef, IntoColorUnclamped,
+    },
+    Blacken, BlackenAssign, Clamp, ClampAssign, ComponentWise, Darken, DarkenAssign, Desaturate,
+    DesaturateAssign, GetHue, IsAchromatic, IsWithinBounds, Lighten, LightenAssign, Mix, MixAssign,
+    RelativeLuminance, Saturate, SaturateAssign, SetHue, ShiftHue, ShiftHueAssign, TryGetHue,
+    Whiten, WhitenAssign, WithAlpha, WithHue,
+};
+
+#[doc(no_inline)]
+pub use crate::{
+    blend::{BlendWith, Compose, Premultiply},
+    color_difference::{
+        Ciede2000, DeltaE, EuclideanDistance, HyAb, ImprovedCiede2000, ImprovedDeltaE,
+        Wcag21RelativeContrast,
+    },
+    color_theory::{Analogous, Complementary, SplitComplementary, Tetradic, Triadic},
+};
+
+#[doc(no_inline)]
+pub use crate::cast::{
+    ArraysAs, ArraysAsMut, ArraysFrom, ArraysInto, AsArrays, AsArraysMut, AsComponents,
+    AsComponentsMut, AsUints, AsUintsMut, ComponentsAs, ComponentsAsMut, ComponentsFrom,
+    ComponentsInto, FromArrays, FromComponents, FromUints, IntoArrays, IntoComponents, IntoUints,
+    TryComponentsAs, TryComponentsAsMut, TryComponentsInto, TryFromComponents, UintsAs, UintsAsMut,
+    UintsFrom, UintsInto,
+};
+
+#[doc(no_inline)]
+pub use crate::{
+    Alpha, Hsl, Hsla, Hsluv, Hsluva, Hsv, Hsva, Hwb, Hwba, Lab, Laba, Lch, Lcha, Lchuv, Lchuva,
+    LinLuma, LinLumaa, LinSrgb, LinSrgba, Luv, Luva, Okhsl, Okhsla, Okhsv, Okhsva, Okhwb, Okhwba,
+    Oklab, Oklaba, Oklch, Oklcha, Srgb, SrgbLuma, SrgbLumaa, Srgba, Xyz, Xyza, Yxy, Yxya,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn conversion() {
+        let color = Srgb::new(0.75f64, 0.25, 0.125);
+
+        let lch: Lch<_, f64> = color.into_linear().into_color();
+        let lch_ref: Lch<_, f64> = color.into_linear().into_color_ref();
+        let lab = Lab::from_color(lch);
+        let hsl: Hsl<_, f64> = color.into_color_unclamped();
+
+        assert_eq!(lch, lch_ref);
+        assert_eq!(lab, lch.into_color());
+        assert_eq!(Hsl::from_color_unclamped(color), hsl);
+    }
+
+    #[test]
+    fn operations() {
+        let color = Oklch::new(0.5f32, 0.1, 120.0);
+        let mut other = color;
+
+        other.lighten_assign(0.1);
+        assert_eq!(color.lighten(0.1), other);
+
+        other = color;
+        other.shift_hue_assign(10.0);
+        assert_eq!(color.shift_hue(10.0), other);
+
+        let mixed = color.mix(Oklch::new(0.7, 0.1, 120.0), 0.5);
+        assert!(mixed.is_within_bounds());
+        assert_eq!(mixed.get_hue(), color.get_hue());
+        assert_eq!(
+            color.complementary().get_hue(),
+            color.shift_hue(180.0).get_hue()
+        );
+
+        let transparent = color.with_alpha(0.5);
+        assert_eq!(transparent, Oklcha::new(0.5, 0.1, 120.0, 0.5));
+    }
+
+    #[test]
+    fn difference() {
+        let a: Lab = Lab::new(50.0, 10.0, -10.0);
+        let b = Lab::new(55.0, 10.0, -10.0);
+
+        assert!(a.difference(b) > 0.0);
+        assert!(a.delta_e(b) > 0.0);
+        assert!(a.improved_delta_e(b) > 0.0);
+        assert!(a.distance(b) > 0.0);
+        assert!(Srgb::new(1.0f32, 1.0, 1.0).has_min_contrast_text(Srgb::new(0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn cast() {
+        let mut buffer = [255u8, 0, 255, 0, 128, 0];
+
+        let colors: &mut [Srgb<u8>] = buffer.components_as_mut();
+        colors[0].blue = 0;
+
+        let arrays: &[[u8; 3]] = colors.as_arrays();
+        assert_eq!(arrays, [[255, 0, 0], [0, 128, 0]]);
+
+        let arrays: [[u8; 4]; 2] = [Srgba::new(1u8, 2, 3, 4), Srgba::new(5, 6, 7, 8)].into_arrays();
+        let colors: [Srgba<u8>; 2] = arrays.arrays_into();
+        assert_eq!(colors[1], Srgba::new(5, 6, 7, 8));
+    }
+}
@@ -0,0 +1,342 @@
+//! Soft proofing, or previewing how colors will look on another display.
+//!
+//! A display with a smaller gamut or a different white point can't show every
+//! color from a wider RGB space. [`proof`] simulates what happens when a color
+//! is sent to such a display, by
+//!
+//!  1. adapting it to the white point of the target RGB space, using the
+//!     [`Bradford`] chromatic adaptation,
+//!  2. reducing its chroma in [`Oklab`], while keeping the lightness and hue,
+//!     until it fits in the target RGB gamut,
+//!  3. and converting it back to the original RGB space and white point.
+//!
+//! The result can be shown as a preview on the original display. Colors that
+//! are already within the target gamut are only changed by rounding errors.
+//!
+//! ```
+//! use palette::{encoding::Srgb, proofing::proof, rgb::DisplayP3};
+//!
+//! // This green is outside the sRGB gamut.
+//! let green = DisplayP3::new(0.0f32, 1.0, 0.0);
+//! let preview = proof::<Srgb, _, _>(green);
+//!
+//! // It becomes less saturated on an sRGB display.
+//! assert!(preview.red > 0.1 && preview.blue > 0.1);
+//! ```
+
+use crate::{
+    bool_mask::{BoolMask, Select},
+    chromatic_adaptation::adaptation_matrix,
+    convert::FromColorUnclamped,
+    lms::matrix::Bradford,
+    matrix::multiply_3x3_and_vec3,
+    num::{Arithmetics, One, PartialCmp, Real, Zero},
+    rgb::{Rgb, RgbSpace, RgbStandard},
+    white_point::{WhitePoint, D65},
+    xyz::meta::HasXyzMeta,
+    Clamp, IsWithinBounds, Oklab, Xyz,
+};
+
+/// Simulate how `color` looks when it's shown on a display with the RGB
+/// standard `D`, and convert it back to its original RGB standard.
+///
+/// See the [module documentation](self) for the steps involved. The target
+/// standard is the first type parameter, so the others can be inferred:
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{encoding::Srgb, proofing::proof, rgb::DisplayP3};
+///
+/// // This color fits in both gamuts and stays the same.
+/// let gray = DisplayP3::new(0.5f32, 0.4, 0.45);
+/// assert_relative_eq!(proof::<Srgb, _, _>(gray), gray, epsilon = 0.0001);
+/// ```
+#[must_use]
+pub fn proof<D, S, T>(color: Rgb<S, T>) -> Rgb<S, T>
+where
+    S: RgbStandard,
+    D: RgbStandard,
+    <S::Space as RgbSpace>::WhitePoint:
+        WhitePoint<T> + HasXyzMeta<XyzMeta = <S::Space as RgbSpace>::WhitePoint>,
+    <D::Space as RgbSpace>::WhitePoint:
+        WhitePoint<T> + HasXyzMeta<XyzMeta = <D::Space as RgbSpace>::WhitePoint>,
+    T: Real + Zero + One + Arithmetics + PartialCmp + Clone,
+    T::Mask: Select<T> + Clone,
+    Xyz<<S::Space as RgbSpace>::WhitePoint, T>: FromColorUnclamped<Rgb<S, T>>,
+    Xyz<<D::Space as RgbSpace>::WhitePoint, T>: FromColorUnclamped<Rgb<D, T>>,
+    Xyz<D65, T>: FromColorUnclamped<Oklab<T>>,
+    Oklab<T>: FromColorUnclamped<Xyz<D65, T>>,
+    Rgb<S, T>: FromColorUnclamped<Xyz<<S::Space as RgbSpace>::WhitePoint, T>>,
+    Rgb<D, T>: FromColorUnclamped<Xyz<<D::Space as RgbSpace>::WhitePoint, T>>
+        + IsWithinBounds<Mask = T::Mask>
+        + Clamp,
+{
+    proof_with::<D, S, T>(color, &Transforms::new::<S, D>())
+}
+
+/// Simulate how all colors in `colors` look when they are shown on a display
+/// with the RGB standard `D`, and convert them back to their original RGB
+/// standard.
+///
+/// This is the same as calling [`proof`] for each color, but the chromatic
+/// adaptation matrices are only calculated once.
+///
+/// ```
+/// use palette::{encoding::Srgb, proofing::proof_slice, rgb::DisplayP3};
+///
+/// let mut image = [
+///     DisplayP3::new(1.0f32, 0.0, 0.0),
+///     DisplayP3::new(0.0, 1.0, 0.0),
+///     DisplayP3::new(0.5, 0.5, 0.5),
+/// ];
+/// proof_slice::<Srgb, _, _>(&mut image);
+/// ```
+pub fn proof_slice<D, S, T>(colors: &mut [Rgb<S, T>])
+where
+    S: RgbStandard,
+    D: RgbStandard,
+    <S::Space as RgbSpace>::WhitePoint:
+        WhitePoint<T> + HasXyzMeta<XyzMeta = <S::Space as RgbSpace>::WhitePoint>,
+    <D::Space as RgbSpace>::WhitePoint:
+        WhitePoint<T> + HasXyzMeta<XyzMeta = <D::Space as RgbSpace>::WhitePoint>,
+    T: Real + Zero + One + Arithmetics + PartialCmp + Clone,
+    T::Mask: Select<T> + Clone,
+    Xyz<<S::Space as RgbSpace>::WhitePoint, T>: FromColorUnclamped<Rgb<S, T>>,
+    Xyz<<D::Space as RgbSpace>::WhitePoint, T>: FromColorUnclamped<Rgb<D, T>>,
+    Xyz<D65, T>: FromColorUnclamped<Oklab<T>>,
+    Oklab<T>: FromColorUnclamped<Xyz<D65, T>>,
+    Rgb<S, T>: FromColorUnclamped<Xyz<<S::Space as RgbSpace>::WhitePoint, T>> + Clone,
+    Rgb<D, T>: FromColorUnclamped<Xyz<<D::Space as RgbSpace>::WhitePoint, T>>
+        + IsWithinBounds<Mask = T::Mask>
+        + Clamp,
+{
+    let transforms = Transforms::new::<S, D>();
+
+    for color in colors {
+        *color = proof_with::<D, S, T>(color.clone(), &transforms);
+    }
+}
+
+/// The chromatic adaptation matrices between the source, the target and the
+/// `D65` white point of `Oklab`.
+struct Transforms<T> {
+    source_to_target: [T; 9],
+    target_to_source: [T; 9],
+    target_to_d65: [T; 9],
+    d65_to_target: [T; 9],
+}
+
+impl<T> Transforms<T>
+where
+    T: Real + Zero + Arithmetics + Clone,
+{
+    fn new<S, D>() -> Self
+    where
+        S: RgbStandard,
+        D: RgbStandard,
+        <S::Space as RgbSpace>::WhitePoint:
+            WhitePoint<T> + HasXyzMeta<XyzMeta = <S::Space as RgbSpace>::WhitePoint>,
+        <D::Space as RgbSpace>::WhitePoint:
+            WhitePoint<T> + HasXyzMeta<XyzMeta = <D::Space as RgbSpace>::WhitePoint>,
+    {
+        type WhitePointOf<S> = <<S as RgbStandard>::Space as RgbSpace>::WhitePoint;
+
+        Transforms {
+            source_to_target: adaptation_matrix::<T, WhitePointOf<S>, WhitePointOf<D>, Bradford>(
+                None, None,
+            )
+            .into_array(),
+            target_to_source: adaptation_matrix::<T, WhitePointOf<D>, WhitePointOf<S>, Bradford>(
+                None, None,
+            )
+            .into_array(),
+            target_to_d65: adaptation_matrix::<T, WhitePointOf<D>, D65, Bradford>(None, None)
+                .into_array(),
+            d65_to_target: adaptation_matrix::<T, D65, WhitePointOf<D>, Bradford>(None, None)
+                .into_array(),
+        }
+    }
+}
+
+fn proof_with<D, S, T>(color: Rgb<S, T>, transforms: &Transforms<T>) -> Rgb<S, T>
+where
+    S: RgbStandard,
+    D: RgbStandard,
+    T: Real + Zero + One + Arithmetics + PartialCmp + Clone,
+    T::Mask: Select<T> + Clone,
+    Xyz<<S::Space as RgbSpace>::WhitePoint, T>: FromColorUnclamped<Rgb<S, T>>,
+    Xyz<<D::Space as RgbSpace>::WhitePoint, T>: FromColorUnclamped<Rgb<D, T>>,
+    Xyz<D65, T>: FromColorUnclamped<Oklab<T>>,
+    Oklab<T>: FromColorUnclamped<Xyz<D65, T>>,
+    Rgb<S, T>: FromColorUnclamped<Xyz<<S::Space as RgbSpace>::WhitePoint, T>>,
+    Rgb<D, T>: FromColorUnclamped<Xyz<<D::Space as RgbSpace>::WhitePoint, T>>
+        + IsWithinBounds<Mask = T::Mask>
+        + Clamp,
+{
+    let source: Xyz<<S::Space as RgbSpace>::WhitePoint, T> = Xyz::from_color_unclamped(color);
+    let target = apply_matrix(transforms.source_to_target.clone(), source);
+    let mapped = into_target_gamut::<D, T>(target, transforms);
+
+    let target: Xyz<<D::Space as RgbSpace>::WhitePoint, T> = Xyz::from_color_unclamped(mapped);
+    let source: Xyz<<S::Space as RgbSpace>::WhitePoint, T> =
+        apply_matrix(transforms.target_to_source.clone(), target);
+    Rgb::from_color_unclamped(source)
+}
+
+/// Convert to the target RGB space while keeping the lightness and hue, by
+/// reducing the chroma in `Oklab` until the color is within the gamut.
+fn into_target_gamut<D, T>(
+    color: Xyz<<D::Space as RgbSpace>::WhitePoint, T>,
+    transforms: &Transforms<T>,
+) -> Rgb<D, T>
+where
+    D: RgbStandard,
+    T: Real + Zero + One + Arithmetics + PartialCmp + Clone,
+    T::Mask: Select<T> + Clone,
+    Xyz<D65, T>: FromColorUnclamped<Oklab<T>>,
+    Oklab<T>: FromColorUnclamped<Xyz<D65, T>>,
+    Rgb<D, T>: FromColorUnclamped<Xyz<<D::Space as RgbSpace>::WhitePoint, T>>
+        + IsWithinBounds<Mask = T::Mask>
+        + Clamp,
+{
+    let oklab = Oklab::from_color_unclamped(apply_matrix::<_, D65, _>(
+        transforms.target_to_d65.clone(),
+        color.clone(),
+    ));
+    let from_oklab = |oklab: Oklab<T>| {
+        let xyz = apply_matrix(
+            transforms.d65_to_target.clone(),
+            Xyz::<D65, T>::from_color_unclamped(oklab),
+        );
+        Rgb::<D, T>::from_color_unclamped(xyz)
+    };
+
+    let rgb = Rgb::<D, T>::from_color_unclamped(color);
+    let is_within_bounds = rgb.is_within_bounds();
+
+    if is_within_bounds.is_true() {
+        return rgb;
+    }
+
+    let factor = crate::oklab::max_chroma_factor(is_within_bounds, |factor: T| {
+        from_oklab(Oklab::new(
+            oklab.l.clone(),
+            oklab.a.clone() * &factor,
+            oklab.b.clone() * factor,
+        ))
+        .is_within_bounds()
+    });
+
+    // The lightness itself may be out of range, so clamp what's left.
+    from_oklab(Oklab::new(oklab.l, oklab.a * &factor, oklab.b * factor)).clamp()
+}
+
+#[inline]
+fn apply_matrix<Wp1, Wp2, T>(matrix: [T; 9], color: Xyz<Wp1, T>) -> Xyz<Wp2, T>
+where
+    T: Arithmetics,
+{
+    let [x, y, z] = multiply_3x3_and_vec3(matrix, [color.x, color.y, color.z]);
+    Xyz::new(x, y, z)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{proof, proof_slice};
+    use crate::{
+        convert::FromColorUnclamped,
+        encoding::{DisplayP3, ProPhotoRgb, Srgb},
+        rgb::Rgb,
+        FromColor, IntoColor, IsWithinBounds, Oklch,
+    };
+
+    type P3<T = f64> = Rgb<DisplayP3, T>;
+
+    // The round trip through XYZ may leave the components slightly out of
+    // range.
+    fn assert_in_range(color: Rgb<Srgb, f64>) {
+        for component in [color.red, color.green, color.blue] {
+            assert!(
+                (-0.00001..=1.00001).contains(&component),
+                "{:?} is not within the sRGB gamut",
+                color
+            );
+        }
+    }
+
+    #[test]
+    fn in_gamut_is_unchanged() {
+        for &(red, green, blue) in &[
+            (0.5, 0.4, 0.45),
+            (0.0, 0.0, 0.0),
+            (1.0, 1.0, 1.0),
+            (0.8, 0.3, 0.2),
+            (0.1, 0.6, 0.9),
+        ] {
+            let srgb = Rgb::<Srgb, f64>::new(red, green, blue);
+            let color = P3::from_color(srgb);
+
+            // White may end up slightly outside sRGB after the conversions,
+            // and then goes through `Oklab`.
+            assert_relative_eq!(proof::<Srgb, _, _>(color), color, epsilon = 0.0001);
+            assert_relative_eq!(proof::<Srgb, _, _>(srgb), srgb, epsilon = 0.0001);
+            assert_relative_eq!(proof::<DisplayP3, _, _>(srgb), srgb, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn p3_into_srgb_keeps_hue() {
+        for &(red, green, blue) in &[
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (1.0, 1.0, 0.0),
+            (0.0, 1.0, 1.0),
+            (1.0, 0.0, 1.0),
+        ] {
+            let color = P3::new(red, green, blue);
+            assert!(!Rgb::<Srgb, f64>::from_color_unclamped(color).is_within_bounds());
+
+            let proofed = proof::<Srgb, _, _>(color);
+            let srgb = Rgb::<Srgb, f64>::from_color_unclamped(proofed);
+            assert_in_range(srgb);
+
+            // `Oklab` has a more direct conversion from sRGB than from P3,
+            // which gives small differences.
+            let original: Oklch<f64> = color.into_color();
+            let proofed: Oklch<f64> = srgb.into_color();
+            assert_relative_eq!(proofed.hue, original.hue, epsilon = 0.05);
+            assert_relative_eq!(proofed.l, original.l, epsilon = 0.001);
+            assert!(proofed.chroma < original.chroma);
+        }
+    }
+
+    #[test]
+    fn other_white_point() {
+        // ProPhoto RGB uses D50, so the color is adapted there and back.
+        let color = Rgb::<Srgb, f64>::new(0.8, 0.3, 0.2);
+        let proofed = proof::<ProPhotoRgb, _, _>(color);
+        assert_relative_eq!(proofed, color, epsilon = 0.00001);
+
+        // The green is outside sRGB, but proofing it again shouldn't change it
+        // any further.
+        let color = Rgb::<ProPhotoRgb, f64>::new(0.1, 0.9, 0.1);
+        let proofed = proof::<Srgb, _, _>(color);
+        assert!(proofed.green > proofed.red && proofed.green > proofed.blue);
+        assert!(proofed.green - proofed.red < color.green - color.red);
+        assert_relative_eq!(proof::<Srgb, _, _>(proofed), proofed, epsilon = 0.00001);
+    }
+
+    #[test]
+    fn slice() {
+        let mut colors = [
+            P3::new(1.0, 0.0, 0.0),
+            P3::new(0.0, 1.0, 0.0),
+            P3::new(0.5, 0.4, 0.45),
+        ];
+        let expected = colors.map(proof::<Srgb, _, _>);
+
+        proof_slice::<Srgb, _, _>(&mut colors);
+        assert_eq!(colors, expected);
+    }
+}
@@ -0,0 +1,684 @@
+//! Color quantization, for reducing a set of colors to a smaller palette.
+//!
+//! [`median_cut`] picks a number of representative colors from a buffer, and
+//! [`remap`] maps each color in a buffer to its closest palette entry. The
+//! palette can be refined with the k-means clustering in [`kmeans_refine`], or
+//! found directly with `kmeans`, when the `random` feature is enabled. The
+//! functions work in [`Oklab`], where the distances are closer to perceived
+//! differences than in RGB, and accept any color type that implements
+//! [`QuantizeColor`]. That includes the color types that can be converted to
+//! and from `Oklab` with [`IntoColor`], as well as RGB and luma colors with
+//! `u8` components.
+//!
+//! ```
+//! use palette::{quantize, Srgb};
+//!
+//! let pixels: Vec<Srgb<u8>> = vec![
+//!     Srgb::new(250, 10, 10),
+//!     Srgb::new(240, 20, 10),
+//!     Srgb::new(10, 10, 240),
+//!     Srgb::new(20, 10, 250),
+//! ];
+//!
+//! let palette = quantize::median_cut(&pixels, 2);
+//! let indices = quantize::remap(&pixels, &palette);
+//!
+//! assert_eq!(palette.len(), 2);
+//! assert_eq!(indices[0], indices[1]);
+//! assert_eq!(indices[2], indices[3]);
+//! assert_ne!(indices[0], indices[2]);
+//! ```
+
+use core::cmp::Ordering;
+
+use alloc::vec::Vec;
+
+use crate::{luma::Luma, num::Sqrt, rgb::Rgb, IntoColor, Oklab};
+
+/// A color that can be quantized.
+///
+/// The quantization functions in this module measure distances and compute
+/// averages in [`Oklab`]. This trait converts colors to and from it. It's
+/// implemented for all colors that can be converted to and from `Oklab` with
+/// [`IntoColor`], and for [`Rgb`] and [`Luma`] with `u8` components, which
+/// are converted via `f32`.
+pub trait QuantizeColor: Clone {
+    /// Convert the color to `Oklab`.
+    fn to_oklab(&self) -> Oklab;
+
+    /// Convert an `Oklab` color, such as an average, back to this color type.
+    fn from_oklab(oklab: Oklab) -> Self;
+}
+
+impl<C> QuantizeColor for C
+where
+    C: Clone + IntoColor<Oklab>,
+    Oklab: IntoColor<C>,
+{
+    #[inline]
+    fn to_oklab(&self) -> Oklab {
+        self.clone().into_color()
+    }
+
+    #[inline]
+    fn from_oklab(oklab: Oklab) -> Self {
+        oklab.into_color()
+    }
+}
+
+impl<S> QuantizeColor for Rgb<S, u8>
+where
+    Rgb<S, f32>: IntoColor<Oklab>,
+    Oklab: IntoColor<Rgb<S, f32>>,
+{
+    #[inline]
+    fn to_oklab(&self) -> Oklab {
+        self.into_format::<f32>().into_color()
+    }
+
+    #[inline]
+    fn from_oklab(oklab: Oklab) -> Self {
+        let color: Rgb<S, f32> = oklab.into_color();
+        color.into_format()
+    }
+}
+
+impl<S> QuantizeColor for Luma<S, u8>
+where
+    Luma<S, f32>: IntoColor<Oklab>,
+    Oklab: IntoColor<Luma<S, f32>>,
+{
+    #[inline]
+    fn to_oklab(&self) -> Oklab {
+        self.into_format::<f32>().into_color()
+    }
+
+    #[inline]
+    fn from_oklab(oklab: Oklab) -> Self {
+        let color: Luma<S, f32> = oklab.into_color();
+        color.into_format()
+    }
+}
+
+/// Pick up to `n` representative colors from `colors`, using the median cut
+/// algorithm.
+///
+/// The colors are first placed in a single box in [`Oklab`]. The box with the
+/// largest extent is then repeatedly split in two along its longest axis, at
+/// the median color, until there are `n` boxes. The average of each box
+/// becomes an entry in the returned palette.
+///
+/// Fewer than `n` colors are returned if `colors` has fewer than `n` distinct
+/// colors, and an empty palette is returned if `colors` is empty. The result
+/// is deterministic: ties are resolved by the order of `colors`.
+///
+/// ```
+/// use palette::{quantize::median_cut, Srgb};
+///
+/// let colors = [
+///     Srgb::new(1.0f32, 0.0, 0.0),
+///     Srgb::new(1.0, 0.0, 0.0),
+///     Srgb::new(0.0, 0.0, 1.0),
+/// ];
+///
+/// // There are only two distinct colors to pick from.
+/// assert_eq!(median_cut(&colors, 5).len(), 2);
+/// ```
+pub fn median_cut<C>(colors: &[C], n: usize) -> Vec<C>
+where
+    C: QuantizeColor,
+{
+    if n == 0 || colors.is_empty() {
+        return Vec::new();
+    }
+
+    let mut boxes = alloc::vec![ColorBox::new(to_points(colors))];
+
+    while boxes.len() < n {
+        // Pick the first box with the largest extent. A box with no extent
+        // only has one distinct color and can't be split.
+        let mut selected: Option<(usize, f32)> = None;
+        for (index, color_box) in boxes.iter().enumerate() {
+            let (_, extent) = color_box.longest_axis();
+            if extent > 0.0 && selected.map_or(true, |(_, largest)| extent > largest) {
+                selected = Some((index, extent));
+            }
+        }
+
+        let (index, _) = match selected {
+            Some(selected) => selected,
+            None => break,
+        };
+
+        let second = boxes[index].split();
+        boxes.insert(index + 1, second);
+    }
+
+    boxes
+        .iter()
+        .map(|color_box| from_point(color_box.average()))
+        .collect()
+}
+
+/// Map each color in `colors` to the index of its closest color in
+/// `palette`.
+///
+/// The distances are measured in [`Oklab`]. If two palette entries are equally
+/// close, the first one is chosen.
+///
+/// # Panics
+///
+/// Panics if `palette` is empty and `colors` isn't.
+///
+/// ```
+/// use palette::{quantize::remap, Srgb};
+///
+/// let palette = [Srgb::new(0.0f32, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0)];
+/// let colors = [Srgb::new(0.9f32, 0.8, 0.9), Srgb::new(0.1, 0.2, 0.1)];
+///
+/// assert_eq!(remap(&colors, &palette), [1, 0]);
+/// ```
+pub fn remap<C>(colors: &[C], palette: &[C]) -> Vec<usize>
+where
+    C: QuantizeColor,
+{
+    let palette = to_points(palette);
+
+    to_points(colors)
+        .iter()
+        .map(|point| {
+            let (index, _) = nearest(point, &palette).expect("the palette should not be empty");
+            index
+        })
+        .collect()
+}
+
+/// A cluster of colors, as found by [`kmeans_refine`] or `kmeans`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cluster<C> {
+    /// The average of the colors in the cluster.
+    pub center: C,
+
+    /// The number of colors in the cluster.
+    pub count: usize,
+
+    /// The average distance between `center` and the colors in the cluster,
+    /// in [`Oklab`]. It's `0.0` for an empty cluster.
+    pub average_error: f32,
+}
+
+/// Group `colors` into `k` clusters, using k-means clustering with k-means++
+/// initialization.
+///
+/// The initial cluster centers are picked from `colors` with `rng`, where
+/// colors that are far from the already picked centers are more likely to be
+/// picked. The clusters are then refined for at most `max_iters` iterations,
+/// or until they stop changing, the same way as in [`kmeans_refine`]. The
+/// result is deterministic for a given random number generator state, such as
+/// a fixed seed.
+///
+/// Fewer than `k` clusters are returned if `colors` has fewer than `k`
+/// distinct colors, and no clusters are returned if `colors` is empty.
+///
+/// ```
+/// use palette::{quantize::kmeans, Srgb};
+/// use rand::rngs::mock::StepRng;
+///
+/// // Any random number generator works, but a seeded one gives the same
+/// // result every time.
+/// let mut rng = StepRng::new(1, 7);
+///
+/// let colors = [
+///     Srgb::new(1.0f32, 0.0, 0.0),
+///     Srgb::new(0.9, 0.1, 0.0),
+///     Srgb::new(0.0, 0.0, 1.0),
+///     Srgb::new(0.0, 0.1, 0.9),
+/// ];
+///
+/// let clusters = kmeans(&colors, 2, 10, &mut rng);
+///
+/// assert_eq!(clusters.len(), 2);
+/// assert_eq!(clusters[0].count, 2);
+/// assert_eq!(clusters[1].count, 2);
+/// ```
+#[cfg(feature = "random")]
+pub fn kmeans<C, R>(colors: &[C], k: usize, max_iters: usize, rng: &mut R) -> Vec<Cluster<C>>
+where
+    C: QuantizeColor,
+    R: rand::Rng + ?Sized,
+{
+    let points = to_points(colors);
+    let centers = kmeans_plus_plus(&points, k, rng);
+
+    lloyd(&points, centers, max_iters)
+}
+
+/// Refine `palette` by grouping `colors` into one cluster per palette entry,
+/// using k-means clustering.
+///
+/// Each color is assigned to its closest cluster center, starting with the
+/// colors in `palette`, and each center is then moved to the average of its
+/// colors. This is repeated for at most `max_iters` iterations, or until the
+/// clusters stop changing. A center that ends up with no colors stays where it
+/// is.
+///
+/// The palette can, for example, come from [`median_cut`]:
+///
+/// ```
+/// use palette::{quantize, Srgb};
+///
+/// let colors = [
+///     Srgb::new(1.0f32, 0.0, 0.0),
+///     Srgb::new(0.8, 0.2, 0.0),
+///     Srgb::new(0.9, 0.1, 0.1),
+///     Srgb::new(0.0, 0.0, 1.0),
+/// ];
+///
+/// let palette = quantize::median_cut(&colors, 2);
+/// let clusters = quantize::kmeans_refine(&colors, &palette, 10);
+///
+/// assert_eq!(clusters.iter().map(|cluster| cluster.count).sum::<usize>(), 4);
+/// ```
+pub fn kmeans_refine<C>(colors: &[C], palette: &[C], max_iters: usize) -> Vec<Cluster<C>>
+where
+    C: QuantizeColor,
+{
+    lloyd(&to_points(colors), to_points(palette), max_iters)
+}
+
+/// Pick up to `k` initial cluster centers from `points`, using k-means++.
+#[cfg(feature = "random")]
+fn kmeans_plus_plus<R>(points: &[[f32; 3]], k: usize, rng: &mut R) -> Vec<[f32; 3]>
+where
+    R: rand::Rng + ?Sized,
+{
+    if k == 0 || points.is_empty() {
+        return Vec::new();
+    }
+
+    let first = points[rng.gen_range(0..points.len())];
+    let mut distances: Vec<f32> = points
+        .iter()
+        .map(|point| distance_squared(point, &first))
+        .collect();
+    let mut centers = alloc::vec![first];
+
+    while centers.len() < k {
+        let total: f64 = distances.iter().map(|&distance| f64::from(distance)).sum();
+
+        // Every point is already a center.
+        if total <= 0.0 {
+            break;
+        }
+
+        // Pick a point with a probability that is proportional to its squared
+        // distance to the closest center. Points that are already centers
+        // have a distance of 0 and can't be picked again.
+        let mut target = rng.gen::<f64>() * total;
+        let mut picked = None;
+        for (index, &distance) in distances.iter().enumerate() {
+            if distance > 0.0 {
+                picked = Some(index);
+                target -= f64::from(distance);
+
+                if target < 0.0 {
+                    break;
+                }
+            }
+        }
+
+        let center = points[picked.expect("the total distance should be positive")];
+        for (distance, point) in distances.iter_mut().zip(points) {
+            *distance = distance.min(distance_squared(point, &center));
+        }
+        centers.push(center);
+    }
+
+    centers
+}
+
+/// Run Lloyd's algorithm on `points`, starting from `centers`.
+fn lloyd<C>(points: &[[f32; 3]], mut centers: Vec<[f32; 3]>, max_iters: usize) -> Vec<Cluster<C>>
+where
+    C: QuantizeColor,
+{
+    let mut assignments = alloc::vec![usize::MAX; points.len()];
+
+    for _ in 0..max_iters {
+        let mut changed = false;
+        for (point, assignment) in points.iter().zip(&mut assignments) {
+            if let Some((index, _)) = nearest(point, &centers) {
+                changed |= *assignment != index;
+                *assignment = index;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+
+        let mut sums = alloc::vec![([0.0f64; 3], 0usize); centers.len()];
+        for (point, &assignment) in points.iter().zip(&assignments) {
+            let (sum, count) = &mut sums[assignment];
+            for (sum, &component) in sum.iter_mut().zip(point) {
+                *sum += f64::from(component);
+            }
+            *count += 1;
+        }
+
+        for (center, (sum, count)) in centers.iter_mut().zip(sums) {
+            if count > 0 {
+                *center = sum.map(|sum| (sum / count as f64) as f32);
+            }
+        }
+    }
+
+    let mut errors = alloc::vec![(0.0f64, 0usize); centers.len()];
+    for point in points {
+        if let Some((index, distance)) = nearest(point, &centers) {
+            let (error, count) = &mut errors[index];
+            *error += Sqrt::sqrt(f64::from(distance));
+            *count += 1;
+        }
+    }
+
+    centers
+        .into_iter()
+        .zip(errors)
+        .map(|(center, (error, count))| Cluster {
+            center: from_point(center),
+            count,
+            average_error: if count > 0 {
+                (error / count as f64) as f32
+            } else {
+                0.0
+            },
+        })
+        .collect()
+}
+
+fn to_points<C>(colors: &[C]) -> Vec<[f32; 3]>
+where
+    C: QuantizeColor,
+{
+    colors
+        .iter()
+        .map(|color| {
+            let Oklab { l, a, b } = color.to_oklab();
+            [l, a, b]
+        })
+        .collect()
+}
+
+fn from_point<C>(point: [f32; 3]) -> C
+where
+    C: QuantizeColor,
+{
+    let [l, a, b] = point;
+    C::from_oklab(Oklab::new(l, a, b))
+}
+
+fn distance_squared(a: &[f32; 3], b: &[f32; 3]) -> f32 {
+    let [l1, a1, b1] = *a;
+    let [l2, a2, b2] = *b;
+
+    (l1 - l2) * (l1 - l2) + (a1 - a2) * (a1 - a2) + (b1 - b2) * (b1 - b2)
+}
+
+/// Find the index of, and squared distance to, the first of the closest
+/// points in `candidates`.
+fn nearest(point: &[f32; 3], candidates: &[[f32; 3]]) -> Option<(usize, f32)> {
+    let mut closest: Option<(usize, f32)> = None;
+
+    for (index, candidate) in candidates.iter().enumerate() {
+        let distance = distance_squared(point, candidate);
+        if closest.map_or(true, |(_, closest)| distance < closest) {
+            closest = Some((index, distance));
+        }
+    }
+
+    closest
+}
+
+struct ColorBox {
+    points: Vec<[f32; 3]>,
+}
+
+impl ColorBox {
+    fn new(points: Vec<[f32; 3]>) -> Self {
+        ColorBox { points }
+    }
+
+    /// Find the axis with the largest extent, and the extent itself.
+    fn longest_axis(&self) -> (usize, f32) {
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+
+        for point in &self.points {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(point[axis]);
+                max[axis] = max[axis].max(point[axis]);
+            }
+        }
+
+        let mut longest = (0, max[0] - min[0]);
+        for axis in 1..3 {
+            let extent = max[axis] - min[axis];
+            if extent > longest.1 {
+                longest = (axis, extent);
+            }
+        }
+
+        longest
+    }
+
+    /// Split the box at the median of its longest axis, keep the lower half
+    /// and return the upper half.
+    ///
+    /// The split is moved to the closest change in value, so equal colors
+    /// always end up in the same box. The box must have a non-zero extent.
+    fn split(&mut self) -> ColorBox {
+        let (axis, _) = self.longest_axis();
+
+        // The sort is stable, to keep equal values in their original order.
+        self.points
+            .sort_by(|a, b| a[axis].partial_cmp(&b[axis]).unwrap_or(Ordering::Equal));
+
+        let is_boundary = |index: usize| self.points[index - 1][axis] < self.points[index][axis];
+        let median = self.points.len() / 2;
+        let split_at = (0..self.points.len())
+            .flat_map(|offset| [median + offset, median.wrapping_sub(offset + 1)])
+            .find(|&index| index > 0 && index < self.points.len() && is_boundary(index))
+            .expect("a box with a non-zero extent should have a boundary");
+
+        ColorBox::new(self.points.split_off(split_at))
+    }
+
+    fn average(&self) -> [f32; 3] {
+        let mut sum = [0.0f64; 3];
+
+        for point in &self.points {
+            for axis in 0..3 {
+                sum[axis] += f64::from(point[axis]);
+            }
+        }
+
+        let count = self.points.len() as f64;
+        sum.map(|sum| (sum / count) as f32)
+    }
+}
+
+#[cfg(feature = "approx")]
+#[cfg(test)]
+mod test {
+    use super::{kmeans_refine, median_cut, remap};
+    use crate::{IntoColor, Oklab, Srgb, SrgbLuma};
+
+    fn two_clusters() -> ([Oklab; 2], Vec<Oklab>) {
+        let centers = [Oklab::new(0.6, 0.2, 0.1), Oklab::new(0.4, -0.05, -0.2)];
+        let offsets = [
+            [0.0, 0.0, 0.0],
+            [0.01, 0.0, 0.0],
+            [-0.01, 0.0, 0.0],
+            [0.0, 0.01, 0.0],
+            [0.0, -0.01, 0.0],
+            [0.0, 0.0, 0.01],
+            [0.0, 0.0, -0.01],
+        ];
+
+        let colors = centers
+            .iter()
+            .flat_map(|center| {
+                offsets
+                    .iter()
+                    .map(move |&[l, a, b]| Oklab::new(center.l + l, center.a + a, center.b + b))
+            })
+            .collect();
+
+        (centers, colors)
+    }
+
+    #[test]
+    fn two_cluster_centers() {
+        let (centers, colors) = two_clusters();
+        let palette = median_cut(&colors, 2);
+
+        assert_eq!(palette.len(), 2);
+        assert_relative_eq!(palette[0], centers[1], epsilon = 0.00001);
+        assert_relative_eq!(palette[1], centers[0], epsilon = 0.00001);
+
+        let indices = remap(&colors, &palette);
+        assert_eq!(indices[..7], [1; 7]);
+        assert_eq!(indices[7..], [0; 7]);
+    }
+
+    #[test]
+    fn too_few_colors() {
+        let colors = [
+            Srgb::new(1.0f32, 0.0, 0.0),
+            Srgb::new(0.0, 1.0, 0.0),
+            Srgb::new(1.0, 0.0, 0.0),
+            Srgb::new(0.0, 1.0, 0.0),
+        ];
+        let palette = median_cut(&colors, 10);
+
+        assert_eq!(palette.len(), 2);
+        for color in colors {
+            assert!(palette
+                .iter()
+                .any(|&entry| relative_eq!(entry, color, epsilon = 0.0001)));
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(median_cut::<Srgb>(&[], 3).is_empty());
+        assert!(median_cut(&[Srgb::new(1.0f32, 1.0, 1.0)], 0).is_empty());
+        assert!(remap::<Srgb>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn deterministic() {
+        let (_, colors) = two_clusters();
+        let colors: Vec<Srgb> = colors.into_iter().map(IntoColor::into_color).collect();
+
+        assert_eq!(median_cut(&colors, 5), median_cut(&colors, 5));
+        assert_eq!(median_cut(&colors, 5).len(), 5);
+        assert_eq!(remap(&colors, &colors[..3]), remap(&colors, &colors[..3]));
+    }
+
+    #[test]
+    fn u8_colors() {
+        let colors = [
+            Srgb::new(250u8, 10, 10),
+            Srgb::new(240, 20, 10),
+            Srgb::new(10, 10, 240),
+            Srgb::new(20, 10, 250),
+        ];
+
+        let palette = median_cut(&colors, 2);
+        assert_eq!(palette.len(), 2);
+        assert_eq!(remap(&colors, &palette), [1, 1, 0, 0]);
+        assert_eq!(
+            median_cut(&[Srgb::new(200u8, 100, 50); 3], 2),
+            [Srgb::new(200, 100, 50)]
+        );
+
+        let clusters = kmeans_refine(&colors, &palette, 10);
+        assert_eq!(clusters[0].count, 2);
+        assert_eq!(clusters[1].count, 2);
+
+        let grays = [SrgbLuma::new(10u8), SrgbLuma::new(240), SrgbLuma::new(20)];
+        assert_eq!(remap(&grays, &grays[..2]), [0, 1, 0]);
+    }
+
+    #[test]
+    fn kmeans_refine_two_clusters() {
+        let (centers, colors) = two_clusters();
+        let clusters = kmeans_refine(&colors, &[colors[1], colors[8]], 10);
+
+        assert_eq!(clusters.len(), 2);
+        for (cluster, center) in clusters.iter().zip(centers) {
+            assert_relative_eq!(cluster.center, center, epsilon = 0.00001);
+            assert_eq!(cluster.count, 7);
+            assert_relative_eq!(cluster.average_error, 0.06 / 7.0, epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn kmeans_refine_empty_cluster() {
+        let (_, colors) = two_clusters();
+        let far_away = Oklab::new(0.0, 1.0, 1.0);
+        let clusters = kmeans_refine(&colors, &[colors[0], colors[7], far_away], 10);
+
+        assert_eq!(clusters[2].center, far_away);
+        assert_eq!(clusters[2].count, 0);
+        assert_eq!(clusters[2].average_error, 0.0);
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn kmeans_three_colors() {
+        use super::kmeans;
+
+        let colors = [
+            Srgb::new(0.9f32, 0.1, 0.1),
+            Srgb::new(0.1, 0.8, 0.2),
+            Srgb::new(0.2, 0.3, 0.9),
+        ];
+        let counts = [5, 12, 3];
+        let image: Vec<Srgb> = colors
+            .iter()
+            .zip(counts)
+            .flat_map(|(&color, count)| core::iter::repeat(color).take(count))
+            .collect();
+
+        for seed in 0..10 {
+            let mut rng = rand_mt::Mt::new(seed);
+            let clusters = kmeans(&image, 3, 10, &mut rng);
+
+            assert_eq!(clusters.len(), 3);
+            for (&color, count) in colors.iter().zip(counts) {
+                let cluster = clusters
+                    .iter()
+                    .find(|cluster| relative_eq!(cluster.center, color, epsilon = 0.0001))
+                    .expect("all colors should have a cluster");
+
+                assert_eq!(cluster.count, count);
+                assert_relative_eq!(cluster.average_error, 0.0, epsilon = 0.0001);
+            }
+        }
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn kmeans_too_few_colors() {
+        use super::kmeans;
+
+        let colors = [Srgb::new(1.0f32, 0.0, 0.0), Srgb::new(0.0, 1.0, 0.0)];
+        let mut rng = rand_mt::Mt::new(1234);
+
+        assert_eq!(kmeans(&colors, 5, 10, &mut rng).len(), 2);
+        assert!(kmeans::<Srgb, _>(&[], 5, 10, &mut rng).is_empty());
+    }
+}
@@ -65,15 +65,17 @@ use crate::{
     encoding::{self, FromLinear, Gamma, IntoLinear, Linear},
     stimulus::{FromStimulus, Stimulus},
     white_point::Any,
-    Mat3, Yxy,
+    Yxy,
 };
 
 pub use self::rgb::{FromHexError, Iter, Rgb, Rgba};
+pub use self::runtime_space::RuntimeSpace;
 
 pub mod channels;
 mod hex;
 #[allow(clippy::module_inception)]
 mod rgb;
+mod runtime_space;
 
 /// Non-linear sRGB, the most common RGB input/output format.
 ///
@@ -328,22 +330,22 @@ pub trait RgbSpace {
     type WhitePoint;
 
     /// Get a pre-defined matrix for converting an RGB value with this standard
-    /// into an XYZ value.
+    /// into an XYZ value. The matrix is an array in row-major order.
     ///
     /// Returning `None` (as in the default implementation) means that the
     /// matrix will be computed dynamically, which is significantly slower.
     #[inline(always)]
-    fn rgb_to_xyz_matrix() -> Option<Mat3<f64>> {
+    fn rgb_to_xyz_matrix() -> Option<[f64; 9]> {
         None
     }
 
     /// Get a pre-defined matrix for converting an XYZ value into an RGB value
-    /// with this standard.
+    /// with this standard. The matrix is an array in row-major order.
     ///
     /// Returning `None` (as in the default implementation) means that the
     /// matrix will be computed dynamically, which is significantly slower.
     #[inline(always)]
-    fn xyz_to_rgb_matrix() -> Option<Mat3<f64>> {
+    fn xyz_to_rgb_matrix() -> Option<[f64; 9]> {
         None
     }
 }
@@ -439,3 +441,56 @@ pub type PackedBgra<P = u32> = crate::cast::Packed<channels::Bgra, P>;
 
 /// A packed representation of RGBA in ABGR order.
 pub type PackedAbgr<P = u32> = crate::cast::Packed<channels::Abgr, P>;
+
+/// A packed representation of RGB in 16 bits, with 5 bits for red, 6 bits for
+/// green and 5 bits for blue. See [`channels::Rgb565`] for details.
+pub type PackedRgb565 = crate::cast::Packed<channels::Rgb565, u16>;
+
+/// View a framebuffer of `0xAARRGGBB` pixels as colors that convert to and
+/// from [`Rgba<S, u8>`](Rgba).
+///
+/// Many windowing and software rendering libraries represent the screen as a
+/// `&[u32]`, where each pixel is `0xAARRGGBB` (or `0x00RRGGBB`, where the
+/// alpha byte is ignored). This casts such a buffer to [`PackedArgb`] without
+/// copying, so each pixel can be read with `Rgba::from` or written with
+/// `into`.
+///
+/// The pixels are interpreted by their numeric value, so this works the same
+/// on little and big endian platforms. The bytes in memory are in `B, G, R, A`
+/// order on little endian and `A, R, G, B` order on big endian, which is why
+/// the buffer isn't cast directly to `&[Rgba<S, u8>]`. That would only give
+/// the correct channels on one of them.
+///
+/// ```
+/// use palette::{rgb, Srgba};
+///
+/// let framebuffer = [0xFF17C64Cu32, 0x805D12D6];
+/// let pixels = rgb::argb_slice_as_rgba(&framebuffer);
+///
+/// assert_eq!(Srgba::from(pixels[0]), Srgba::new(0x17, 0xC6, 0x4C, 0xFF));
+/// assert_eq!(Srgba::from(pixels[1]), Srgba::new(0x5D, 0x12, 0xD6, 0x80));
+/// ```
+#[inline]
+pub fn argb_slice_as_rgba(values: &[u32]) -> &[PackedArgb] {
+    crate::cast::packed_as(values)
+}
+
+/// View a mutable framebuffer of `0xAARRGGBB` pixels as colors that convert
+/// to and from [`Rgba<S, u8>`](Rgba).
+///
+/// See [`argb_slice_as_rgba`] for details about the format and endianness.
+///
+/// ```
+/// use palette::{rgb, Srgba};
+///
+/// let mut framebuffer = [0u32; 4];
+/// for pixel in rgb::argb_slice_as_rgba_mut(&mut framebuffer) {
+///     *pixel = Srgba::new(0x17, 0xC6, 0x4C, 0xFF).into();
+/// }
+///
+/// assert_eq!(framebuffer, [0xFF17C64C; 4]);
+/// ```
+#[inline]
+pub fn argb_slice_as_rgba_mut(values: &mut [u32]) -> &mut [PackedArgb] {
+    crate::cast::packed_as_mut(values)
+}
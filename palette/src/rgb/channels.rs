@@ -1,11 +1,16 @@
 //! Channel orders for packed RGBA types.
 
-use crate::{cast::ComponentOrder, rgb};
+use core::marker::PhantomData;
+
+use crate::{
+    cast::{ComponentOrder, Packed},
+    rgb, Alpha,
+};
 
 /// RGBA color packed in ABGR order.
 ///
 /// See [Packed](crate::cast::Packed) for more details.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Abgr;
 
 impl<S, T> ComponentOrder<rgb::Rgba<S, T>, [T; 4]> for Abgr {
@@ -25,7 +30,7 @@ impl<S, T> ComponentOrder<rgb::Rgba<S, T>, [T; 4]> for Abgr {
 /// RGBA color packed in ARGB order.
 ///
 /// See [Packed](crate::cast::Packed) for more details.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Argb;
 
 impl<S, T> ComponentOrder<rgb::Rgba<S, T>, [T; 4]> for Argb {
@@ -45,7 +50,7 @@ impl<S, T> ComponentOrder<rgb::Rgba<S, T>, [T; 4]> for Argb {
 /// RGBA color packed in BGRA order.
 ///
 /// See [Packed](crate::cast::Packed) for more details.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Bgra;
 
 impl<S, T> ComponentOrder<rgb::Rgba<S, T>, [T; 4]> for Bgra {
@@ -65,7 +70,7 @@ impl<S, T> ComponentOrder<rgb::Rgba<S, T>, [T; 4]> for Bgra {
 /// RGBA color packed in RGBA order.
 ///
 /// See [Packed](crate::cast::Packed) for more details.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Rgba;
 
 impl<S, T> ComponentOrder<rgb::Rgba<S, T>, [T; 4]> for Rgba {
@@ -82,12 +87,152 @@ impl<S, T> ComponentOrder<rgb::Rgba<S, T>, [T; 4]> for Rgba {
     }
 }
 
+macro_rules! impl_u16_channels {
+    ($($order: ident),+) => {
+        $(
+            impl<S> ComponentOrder<rgb::Rgba<S, u16>, [u8; 8]> for $order {
+                #[inline]
+                fn pack(color: rgb::Rgba<S, u16>) -> [u8; 8] {
+                    let [c1, c2, c3, c4]: [u16; 4] = Self::pack(color);
+                    let [c1, c2, c3, c4] = [c1, c2, c3, c4].map(u16::to_be_bytes);
+                    [c1[0], c1[1], c2[0], c2[1], c3[0], c3[1], c4[0], c4[1]]
+                }
+
+                #[inline]
+                fn unpack(packed: [u8; 8]) -> rgb::Rgba<S, u16> {
+                    let [c1h, c1l, c2h, c2l, c3h, c3l, c4h, c4l] = packed;
+                    Self::unpack([
+                        u16::from_be_bytes([c1h, c1l]),
+                        u16::from_be_bytes([c2h, c2l]),
+                        u16::from_be_bytes([c3h, c3l]),
+                        u16::from_be_bytes([c4h, c4l]),
+                    ])
+                }
+            }
+        )+
+    };
+}
+
+// 16 bit channels, packed into `[u8; 8]` and `u64`.
+impl_u16_channels!(Abgr, Argb, Bgra, Rgba);
+
+macro_rules! impl_const_pack {
+    ($($order: ident => [$c1: literal, $c2: literal, $c3: literal, $c4: literal]),+) => {
+        $(
+            impl Packed<$order, u32> {
+                /// Pack an RGBA color with `u8` components into a `u32`, in a
+                /// `const` context.
+                ///
+                /// This gives the same result as [`Packed::pack`].
+                #[inline]
+                pub const fn pack_const<S>(color: rgb::Rgba<S, u8>) -> Self {
+                    let Alpha {
+                        color: rgb::Rgb { red, green, blue, .. },
+                        alpha,
+                    } = color;
+                    let components = [red, green, blue, alpha];
+
+                    Packed {
+                        color: u32::from_be_bytes([
+                            components[$c1],
+                            components[$c2],
+                            components[$c3],
+                            components[$c4],
+                        ]),
+                        channel_order: PhantomData,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+// `const` packing of 8 bit channels, since `ComponentOrder` can't be used in
+// `const` functions. The indices are the positions in `[red, green, blue,
+// alpha]`.
+impl_const_pack!(
+    Abgr => [3, 2, 1, 0],
+    Argb => [3, 0, 1, 2],
+    Bgra => [2, 1, 0, 3],
+    Rgba => [0, 1, 2, 3]
+);
+
+/// RGB color packed into 16 bits, with 5 bits for red, 6 bits for green and 5
+/// bits for blue, from the most to the least significant bit.
+///
+/// This format, also known as RGB565, is common for small displays in embedded
+/// systems. The components are rounded to the nearest value when packing, and
+/// expanded to 8 bits by repeating their highest bits when unpacking. Every
+/// packed value is preserved when unpacking and packing it again.
+///
+/// The alpha value is ignored when packing and set to `255` when unpacking.
+///
+/// ```
+/// use palette::{rgb::PackedRgb565, Srgb};
+///
+/// let packed = PackedRgb565::from(Srgb::new(255u8, 128, 0));
+/// assert_eq!(packed.color, 0b11111_100000_00000);
+///
+/// let unpacked: Srgb<u8> = packed.into();
+/// assert_eq!(unpacked, Srgb::new(255, 130, 0));
+/// ```
+///
+/// See [Packed](crate::cast::Packed) for more details.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rgb565;
+
+impl<S> ComponentOrder<rgb::Rgba<S, u8>, u16> for Rgb565 {
+    #[inline]
+    fn pack(color: rgb::Rgba<S, u8>) -> u16 {
+        // Round to the nearest value with `max_out` steps.
+        fn reduce(value: u8, max_out: u16) -> u16 {
+            (u16::from(value) * max_out + 127) / 255
+        }
+
+        (reduce(color.red, 31) << 11) | (reduce(color.green, 63) << 5) | reduce(color.blue, 31)
+    }
+
+    #[inline]
+    fn unpack(packed: u16) -> rgb::Rgba<S, u8> {
+        let red = (packed >> 11) as u8;
+        let green = ((packed >> 5) & 0b11_1111) as u8;
+        let blue = (packed & 0b1_1111) as u8;
+
+        rgb::Rgba::new(
+            (red << 3) | (red >> 2),
+            (green << 2) | (green >> 4),
+            (blue << 3) | (blue >> 2),
+            u8::MAX,
+        )
+    }
+}
+
 #[cfg(feature = "approx")]
 #[cfg(test)]
 mod test {
-    use super::{Abgr, Argb, Bgra, Rgba};
+    use super::{Abgr, Argb, Bgra, Rgb565, Rgba};
     use crate::{cast::Packed, Srgb, Srgba};
 
+    #[test]
+    fn pack_const() {
+        use crate::rgb::{PackedAbgr, PackedArgb, PackedBgra, PackedRgba};
+
+        const COLOR: Srgba<u8> = Srgba::new(0x12, 0x34, 0x56, 0x78);
+        const ABGR: PackedAbgr = PackedAbgr::pack_const(COLOR);
+        const ARGB: PackedArgb = PackedArgb::pack_const(COLOR);
+        const BGRA: PackedBgra = PackedBgra::pack_const(COLOR);
+        const RGBA: PackedRgba = PackedRgba::pack_const(COLOR);
+
+        assert_eq!(ABGR, Packed::pack(COLOR));
+        assert_eq!(ARGB, Packed::pack(COLOR));
+        assert_eq!(BGRA, Packed::pack(COLOR));
+        assert_eq!(RGBA, Packed::pack(COLOR));
+        assert_eq!(ABGR.color, 0x7856_3412);
+        assert_eq!(ARGB.color, 0x7812_3456);
+        assert_eq!(BGRA.color, 0x5634_1278);
+        assert_eq!(RGBA.color, 0x1234_5678);
+    }
+
     #[test]
     fn rgba() {
         let a1: Packed<Rgba, u32> = Srgb::new(0.5, 0.0, 0.0).into_format().into();
@@ -253,4 +398,76 @@ mod test {
         assert_eq!(0xFFFF_FF80, u32::from(Srgb::new(255u8, 255, 128)));
         assert_eq!(0x7FFF_FF80, u32::from(Srgba::new(127u8, 255u8, 255, 128)));
     }
+
+    #[test]
+    fn u16_channels() {
+        let color = Srgba::new(0x0102u16, 0x0304, 0x0506, 0x0708);
+
+        assert_eq!(
+            Packed::<Rgba, u64>::from(color).color,
+            0x0102_0304_0506_0708
+        );
+        assert_eq!(
+            Packed::<Argb, u64>::from(color).color,
+            0x0708_0102_0304_0506
+        );
+        assert_eq!(
+            Packed::<Bgra, u64>::from(color).color,
+            0x0506_0304_0102_0708
+        );
+        assert_eq!(
+            Packed::<Abgr, u64>::from(color).color,
+            0x0708_0506_0304_0102
+        );
+        assert_eq!(
+            Packed::<Rgba, [u8; 8]>::from(color).color,
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+        );
+
+        let unpacked: Srgba<u16> = Packed::<Argb, u64>::from(0x0708_0102_0304_0506).into();
+        assert_eq!(unpacked, color);
+
+        let opaque: Packed<Argb, u64> = Srgb::new(0x0102u16, 0x0304, 0x0506).into();
+        assert_eq!(opaque.color, 0xFFFF_0102_0304_0506);
+        assert_eq!(Srgb::<u16>::from(opaque), color.color);
+    }
+
+    #[test]
+    fn rgb565_round_trip() {
+        for packed in 0..=u16::MAX {
+            let color: Srgb<u8> = Packed::<Rgb565, u16>::from(packed).into();
+            let repacked = Packed::<Rgb565, u16>::from(color);
+            assert_eq!(repacked.color, packed, "{:?}", color);
+        }
+    }
+
+    #[test]
+    fn rgb565_from_u8() {
+        for value in 0..=u8::MAX {
+            let color = Srgba::new(value, value, value, 0);
+            let unpacked: Srgba<u8> = Packed::<Rgb565, u16>::from(color).into();
+
+            assert!((i16::from(unpacked.red) - i16::from(value)).abs() <= 4);
+            assert!((i16::from(unpacked.green) - i16::from(value)).abs() <= 2);
+            assert!((i16::from(unpacked.blue) - i16::from(value)).abs() <= 4);
+            assert_eq!(unpacked.alpha, 255);
+        }
+
+        assert_eq!(
+            Packed::<Rgb565, u16>::from(Srgb::new(255u8, 255, 255)).color,
+            0xFFFF
+        );
+        assert_eq!(
+            Packed::<Rgb565, u16>::from(Srgb::new(255u8, 0, 0)).color,
+            0xF800
+        );
+        assert_eq!(
+            Packed::<Rgb565, u16>::from(Srgb::new(0u8, 255, 0)).color,
+            0x07E0
+        );
+        assert_eq!(
+            Packed::<Rgb565, u16>::from(Srgb::new(0u8, 0, 255)).color,
+            0x001F
+        );
+    }
 }
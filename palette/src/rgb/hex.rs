@@ -1,5 +1,47 @@
 use core::num::ParseIntError;
 
+use crate::stimulus::FromStimulus;
+
+use super::FromHexError;
+
+/// Strips the optional `#` from `hex` and checks that the rest of it only
+/// consists of hexadecimal digits.
+#[inline]
+pub(crate) fn strip_hex_prefix(hex: &str) -> Result<&str, FromHexError> {
+    let (offset, hex_code) = match hex.strip_prefix('#') {
+        Some(stripped) => (1, stripped),
+        None => (0, hex),
+    };
+
+    match hex_code.bytes().position(|byte| !byte.is_ascii_hexdigit()) {
+        Some(position) => Err(FromHexError::InvalidCharacter {
+            position: position + offset,
+        }),
+        None => Ok(hex_code),
+    }
+}
+
+/// Trims ASCII whitespace and an optional `0x` prefix from `hex`, and returns
+/// the remaining string together with its offset in `hex`.
+#[inline]
+pub(crate) fn trim_hex_lenient(hex: &str) -> Result<(&str, usize), FromHexError> {
+    let trimmed = hex.trim_start_matches(|c: char| c.is_ascii_whitespace());
+    let offset = hex.len() - trimmed.len();
+    let trimmed = trimmed.trim_end_matches(|c: char| c.is_ascii_whitespace());
+
+    match trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+    {
+        // Don't allow both `0x` and `#`.
+        Some(stripped) if stripped.starts_with('#') => Err(FromHexError::InvalidCharacter {
+            position: offset + 2,
+        }),
+        Some(stripped) => Ok((stripped, offset + 2)),
+        None => Ok((trimmed, offset)),
+    }
+}
+
 #[inline]
 pub(crate) fn rgb_from_hex_4bit(hex: &str) -> Result<(u8, u8, u8), ParseIntError> {
     let red = u8::from_str_radix(&hex[..1], 16)?;
@@ -67,3 +109,76 @@ pub(crate) fn rgba_from_hex_32bit(hex: &str) -> Result<(u32, u32, u32, u32), Par
 
     Ok((red, green, blue, alpha))
 }
+
+// The `FromStr` implementations for `Rgb` and `Rgba` are generic over the RGB
+// standard. The parsing is done in these non-generic functions, so it's only
+// compiled once for each component type, instead of once for each standard.
+// Each code length is parsed with the listed function and converted to the
+// target component type.
+macro_rules! impl_from_hex {
+    ($($rgb_fn: ident, $rgba_fn: ident: $ty: ident {
+        $($($rgb_len: literal)|+, $($rgba_len: literal)|+ => $rgb_parse: ident, $rgba_parse: ident;)+
+    })+) => {
+        $(
+            pub(crate) fn $rgb_fn(hex: &str) -> Result<($ty, $ty, $ty), FromHexError> {
+                let hex_code = strip_hex_prefix(hex)?;
+                match hex_code.len() {
+                    $(
+                        $($rgb_len)|+ => {
+                            let (red, green, blue) = $rgb_parse(hex_code)?;
+                            Ok((
+                                $ty::from_stimulus(red),
+                                $ty::from_stimulus(green),
+                                $ty::from_stimulus(blue),
+                            ))
+                        }
+                    )+
+                    _ => Err(FromHexError::HexFormatError("invalid hex code format")),
+                }
+            }
+
+            pub(crate) fn $rgba_fn(hex: &str) -> Result<($ty, $ty, $ty, $ty), FromHexError> {
+                let hex_code = strip_hex_prefix(hex)?;
+                match hex_code.len() {
+                    $(
+                        $($rgba_len)|+ => {
+                            let (red, green, blue, alpha) = $rgba_parse(hex_code)?;
+                            Ok((
+                                $ty::from_stimulus(red),
+                                $ty::from_stimulus(green),
+                                $ty::from_stimulus(blue),
+                                $ty::from_stimulus(alpha),
+                            ))
+                        }
+                    )+
+                    _ => Err(FromHexError::RgbaHexFormatError("invalid hex code format")),
+                }
+            }
+        )+
+    };
+}
+
+impl_from_hex! {
+    rgb_u8_from_hex, rgba_u8_from_hex: u8 {
+        3, 4 => rgb_from_hex_4bit, rgba_from_hex_4bit;
+        6, 8 => rgb_from_hex_8bit, rgba_from_hex_8bit;
+    }
+    rgb_u16_from_hex, rgba_u16_from_hex: u16 {
+        3 | 6, 4 | 8 => rgb_u8_from_hex, rgba_u8_from_hex;
+        12, 16 => rgb_from_hex_16bit, rgba_from_hex_16bit;
+    }
+    rgb_u32_from_hex, rgba_u32_from_hex: u32 {
+        3 | 6, 4 | 8 => rgb_u8_from_hex, rgba_u8_from_hex;
+        12, 16 => rgb_u16_from_hex, rgba_u16_from_hex;
+        24, 32 => rgb_from_hex_32bit, rgba_from_hex_32bit;
+    }
+    rgb_f32_from_hex, rgba_f32_from_hex: f32 {
+        3 | 6, 4 | 8 => rgb_u8_from_hex, rgba_u8_from_hex;
+        12, 16 => rgb_u16_from_hex, rgba_u16_from_hex;
+    }
+    rgb_f64_from_hex, rgba_f64_from_hex: f64 {
+        3 | 6, 4 | 8 => rgb_u8_from_hex, rgba_u8_from_hex;
+        12, 16 => rgb_u16_from_hex, rgba_u16_from_hex;
+        24, 32 => rgb_u32_from_hex, rgba_u32_from_hex;
+    }
+}
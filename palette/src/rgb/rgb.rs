@@ -4,35 +4,50 @@ use core::{
     fmt::Debug,
     marker::PhantomData,
     num::ParseIntError,
-    ops::{Add, Div},
+    ops::{Add, Div, Mul},
     str::FromStr,
 };
 
 use crate::{
     alpha::Alpha,
     angle::{RealAngle, UnsignedAngle},
-    bool_mask::{BitOps, HasBoolMask, LazySelect},
+    blend::PreAlpha,
+    bool_mask::{BitOps, BoolMask, HasBoolMask, LazySelect, Select},
     cast::{ComponentOrder, Packed},
+    chromatic_adaptation::adaptation_matrix,
     color_difference::Wcag21RelativeContrast,
     convert::{ConvertOnce, FromColorUnclamped, IntoColorUnclamped, Matrix3},
-    encoding::{linear::LinearFn, FromLinear, IntoLinear, Linear, Srgb},
-    luma::LumaStandard,
-    matrix::{matrix_inverse, matrix_map, rgb_to_xyz_matrix},
+    encoding::{
+        dither::{self, Dither},
+        linear::LinearFn,
+        srgb::srgb_u8_to_linear_f32,
+        FromLinear, IntoLinear, Linear, Srgb,
+    },
+    lms::{
+        matrix::{Bradford, LmsToXyz, WithLmsMatrix, XyzToLms},
+        Lms,
+    },
+    luma::{weights::LumaWeights, LumaStandard},
+    matrix::{matrix_inverse, matrix_map, rgb_to_xyz_matrix, Mat3},
     num::{
         Abs, Arithmetics, FromScalar, IsValidDivisor, MinMax, One, PartialCmp, Real, Recip, Round,
         Trigonometry, Zero,
     },
     oklab::oklab_to_linear_srgb,
     rgb::{RgbSpace, RgbStandard},
-    stimulus::{FromStimulus, Stimulus, StimulusColor},
+    stimulus::{mix_u8, FromStimulus, Stimulus, StimulusColor},
     white_point::{Any, WhitePoint, D65},
-    FromColor, GetHue, Hsl, Hsv, IntoColor, Luma, Oklab, RgbHue, Xyz, Yxy,
+    xyz::meta::HasXyzMeta,
+    Clamp, Darken, FromColor, GetHue, Hsl, Hsv, IntoColor, IsAchromatic, IsWithinBounds, Lighten,
+    Luma, Oklab, OklabHue, Oklch, RelativeLuminance, RgbHue, SetHue, ShiftHue, ShiftHueAssign,
+    TryGetHue, WithHue, Xyz, Yxy,
 };
 
 use super::{
     hex::{
-        rgb_from_hex_16bit, rgb_from_hex_32bit, rgb_from_hex_4bit, rgb_from_hex_8bit,
-        rgba_from_hex_16bit, rgba_from_hex_32bit, rgba_from_hex_4bit, rgba_from_hex_8bit,
+        rgb_f32_from_hex, rgb_f64_from_hex, rgb_u16_from_hex, rgb_u32_from_hex, rgb_u8_from_hex,
+        rgba_f32_from_hex, rgba_f64_from_hex, rgba_u16_from_hex, rgba_u32_from_hex,
+        rgba_u8_from_hex, trim_hex_lenient,
     },
     Primaries,
 };
@@ -267,6 +282,31 @@ impl<S, T> Rgb<S, T> {
         hex.parse()
     }
 
+    /// Parses a color hex code into an RGB value, while allowing surrounding
+    /// whitespace and a `0x` prefix.
+    ///
+    /// The string is trimmed from ASCII whitespace, and an optional `0x` or
+    /// `0X` prefix is removed, before parsing it like
+    /// [`from_hex`](Self::from_hex). The position of any invalid character is
+    /// relative to the original string.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// let rgb = Srgb::new(0xf0u8, 0x34, 0xe6);
+    /// assert_eq!(Srgb::from_str_lenient(" #f034e6\n").unwrap(), rgb);
+    /// assert_eq!(Srgb::from_str_lenient("0xf034e6").unwrap(), rgb);
+    /// ```
+    pub fn from_str_lenient(hex: &str) -> Result<Self, FromHexError>
+    where
+        Self: FromStr<Err = FromHexError>,
+    {
+        let (hex_code, offset) = trim_hex_lenient(hex)?;
+        hex_code
+            .parse()
+            .map_err(|error: FromHexError| error.with_offset(offset))
+    }
+
     /// Convert the RGB components into another number type.
     ///
     /// ```
@@ -317,6 +357,29 @@ impl<S, T> Rgb<S, T> {
     pub fn from_components((red, green, blue): (T, T, T)) -> Self {
         Self::new(red, green, blue)
     }
+
+    /// Calculate the weighted sum of the components, using the weights in
+    /// `W`.
+    ///
+    /// This is the luma (Y′) of the color when it's non-linear, and its
+    /// relative luminance (Y) when it's linear. See the
+    /// [`weights`](crate::luma::weights) module for more details.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{luma::weights::Bt601, Srgb};
+    ///
+    /// let luma = Srgb::new(0.8f32, 0.4, 0.2).relative_luma_with::<Bt601>();
+    /// assert_relative_eq!(luma, 0.4968);
+    /// ```
+    #[inline]
+    pub fn relative_luma_with<W>(self) -> T
+    where
+        W: LumaWeights<T>,
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        W::red() * self.red + W::green() * self.green + W::blue() * self.blue
+    }
 }
 
 impl<S, T> Rgb<S, T>
@@ -379,6 +442,152 @@ impl<S, T> Rgb<S, T> {
 
         Matrix3::from_array(matrix_map(transform_matrix, T::from_scalar))
     }
+
+    /// Produce a conversion matrix from another linear [`Rgb`] standard.
+    ///
+    /// The matrix combines the conversion from `S2` to [`Xyz`], chromatic
+    /// adaptation from the white point of `S2` to the white point of `S`, using
+    /// the [`Bradford`] matrix, and the conversion from [`Xyz`] to `S`. This
+    /// makes each conversion a single matrix multiplication, which is
+    /// especially useful when converting many colors between RGB spaces with
+    /// different white points.
+    ///
+    /// ```
+    /// use palette::{
+    ///     chromatic_adaptation::AdaptIntoUnclamped,
+    ///     convert::{Convert, FromColorUnclamped},
+    ///     encoding::{Linear, ProPhotoRgb},
+    ///     rgb::LinProPhotoRgb,
+    ///     white_point::{D50, D65},
+    ///     LinSrgb, Xyz,
+    /// };
+    /// use approx::assert_relative_eq;
+    ///
+    /// let matrix = LinSrgb::<f32>::matrix_from_rgb::<Linear<ProPhotoRgb>>();
+    ///
+    /// let prophoto = LinProPhotoRgb::new(0.2, 0.5, 0.3);
+    /// let srgb = matrix.convert(prophoto);
+    ///
+    /// // Converting in multiple steps for comparison:
+    /// let xyz: Xyz<D65, f32> = Xyz::<D50, f32>::from_color_unclamped(prophoto).adapt_into_unclamped();
+    /// assert_relative_eq!(srgb, LinSrgb::from_color_unclamped(xyz), epsilon = 0.000001);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    pub fn matrix_from_rgb<S2>() -> Matrix3<Rgb<S2, T>, Self>
+    where
+        S: RgbStandard<TransferFn = LinearFn>,
+        S2: RgbStandard<TransferFn = LinearFn>,
+        <S::Space as RgbSpace>::Primaries: Primaries<T::Scalar>,
+        <S2::Space as RgbSpace>::Primaries: Primaries<T::Scalar>,
+        <S::Space as RgbSpace>::WhitePoint:
+            WhitePoint<T::Scalar> + HasXyzMeta<XyzMeta = <S::Space as RgbSpace>::WhitePoint>,
+        <S2::Space as RgbSpace>::WhitePoint:
+            WhitePoint<T::Scalar> + HasXyzMeta<XyzMeta = <S2::Space as RgbSpace>::WhitePoint>,
+        T: FromScalar,
+        T::Scalar: Real
+            + Zero
+            + Recip
+            + IsValidDivisor<Mask = bool>
+            + Arithmetics
+            + Clone
+            + FromScalar<Scalar = T::Scalar>,
+        Bradford: XyzToLms<T::Scalar> + LmsToXyz<T::Scalar>,
+        Xyz<<S::Space as RgbSpace>::WhitePoint, T::Scalar>: IntoColorUnclamped<
+            Lms<WithLmsMatrix<<S::Space as RgbSpace>::WhitePoint, Bradford>, T::Scalar>,
+        >,
+        Xyz<<S2::Space as RgbSpace>::WhitePoint, T::Scalar>: IntoColorUnclamped<
+            Lms<WithLmsMatrix<<S2::Space as RgbSpace>::WhitePoint, Bradford>, T::Scalar>,
+        >,
+        Yxy<Any, T::Scalar>: IntoColorUnclamped<Xyz<Any, T::Scalar>>,
+    {
+        Self::matrix_from_rgb_with::<S2, Bradford>()
+    }
+
+    /// Produce a conversion matrix from another linear [`Rgb`] standard, using
+    /// the LMS matrix `M` for chromatic adaptation.
+    ///
+    /// See [`matrix_from_rgb`](Self::matrix_from_rgb) for more details.
+    ///
+    /// ```
+    /// use palette::{
+    ///     convert::Convert,
+    ///     encoding::{Linear, ProPhotoRgb},
+    ///     lms::matrix::VonKries,
+    ///     rgb::LinProPhotoRgb,
+    ///     LinSrgb,
+    /// };
+    ///
+    /// let matrix = LinSrgb::<f32>::matrix_from_rgb_with::<Linear<ProPhotoRgb>, VonKries>();
+    ///
+    /// let prophoto = LinProPhotoRgb::new(0.2, 0.5, 0.3);
+    /// let srgb = matrix.convert(prophoto);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    pub fn matrix_from_rgb_with<S2, M>() -> Matrix3<Rgb<S2, T>, Self>
+    where
+        S: RgbStandard<TransferFn = LinearFn>,
+        S2: RgbStandard<TransferFn = LinearFn>,
+        <S::Space as RgbSpace>::Primaries: Primaries<T::Scalar>,
+        <S2::Space as RgbSpace>::Primaries: Primaries<T::Scalar>,
+        <S::Space as RgbSpace>::WhitePoint:
+            WhitePoint<T::Scalar> + HasXyzMeta<XyzMeta = <S::Space as RgbSpace>::WhitePoint>,
+        <S2::Space as RgbSpace>::WhitePoint:
+            WhitePoint<T::Scalar> + HasXyzMeta<XyzMeta = <S2::Space as RgbSpace>::WhitePoint>,
+        T: FromScalar,
+        T::Scalar: Real
+            + Zero
+            + Recip
+            + IsValidDivisor<Mask = bool>
+            + Arithmetics
+            + Clone
+            + FromScalar<Scalar = T::Scalar>,
+        M: XyzToLms<T::Scalar> + LmsToXyz<T::Scalar>,
+        Xyz<<S::Space as RgbSpace>::WhitePoint, T::Scalar>: IntoColorUnclamped<
+            Lms<WithLmsMatrix<<S::Space as RgbSpace>::WhitePoint, M>, T::Scalar>,
+        >,
+        Xyz<<S2::Space as RgbSpace>::WhitePoint, T::Scalar>: IntoColorUnclamped<
+            Lms<WithLmsMatrix<<S2::Space as RgbSpace>::WhitePoint, M>, T::Scalar>,
+        >,
+        Yxy<Any, T::Scalar>: IntoColorUnclamped<Xyz<Any, T::Scalar>>,
+    {
+        let matrix = Xyz::matrix_from_rgb::<S2>()
+            .then(adaptation_matrix::<_, _, _, M>(None, None))
+            .then(Rgb::<S, T::Scalar>::matrix_from_xyz())
+            .into_array();
+
+        Matrix3::from_array(matrix_map(matrix, T::from_scalar))
+    }
+
+    /// Multiply the components with `matrix`, as if they were a column vector.
+    ///
+    /// This doesn't change the RGB standard type, so the matrix is expected
+    /// to map between colors within the same standard. The color should
+    /// usually be linear for the result to be meaningful.
+    ///
+    /// ```
+    /// use palette::{matrix::Mat3, LinSrgb};
+    ///
+    /// // Swap red and blue.
+    /// let matrix = Mat3::from_rows([
+    ///     [0.0, 0.0, 1.0],
+    ///     [0.0, 1.0, 0.0],
+    ///     [1.0, 0.0, 0.0],
+    /// ]);
+    ///
+    /// let rgb: LinSrgb = LinSrgb::new(0.2, 0.4, 0.6).transform_by(&matrix);
+    /// assert_eq!(rgb, LinSrgb::new(0.6, 0.4, 0.2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn transform_by(self, matrix: &Mat3<T>) -> Self
+    where
+        T: Arithmetics + Clone,
+    {
+        let [red, green, blue] = matrix.clone().mul_vector([self.red, self.green, self.blue]);
+        Rgb::new(red, green, blue)
+    }
 }
 
 impl<S> Rgb<S, u8> {
@@ -437,8 +646,295 @@ impl<S> Rgb<S, u8> {
     {
         O::unpack(color).color
     }
+
+    /// Convert to a packed `u32` in the `0xAARRGGBB` component order, with
+    /// alpha set to `0xFF`, in a `const` context.
+    ///
+    /// This gives the same result as `u32::from(color)`.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// const INTEGER: u32 = Srgb::new(96u8, 127, 0).into_u32_const();
+    /// assert_eq!(0xFF607F00, INTEGER);
+    /// ```
+    #[inline]
+    pub const fn into_u32_const(self) -> u32 {
+        0xFF00_0000 | (self.red as u32) << 16 | (self.green as u32) << 8 | self.blue as u32
+    }
+
+    /// Convert from a packed `u32` in the `0xAARRGGBB` component order, where
+    /// alpha is ignored, in a `const` context.
+    ///
+    /// This gives the same result as `Rgb::from(color)`.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// const BACKGROUND: Srgb<u8> = Srgb::from_u32_const(0x607F00);
+    /// assert_eq!(Srgb::new(96u8, 127, 0), BACKGROUND);
+    /// ```
+    #[inline]
+    pub const fn from_u32_const(color: u32) -> Self {
+        Rgb::new((color >> 16) as u8, (color >> 8) as u8, color as u8)
+    }
+
+    /// Convert the components to `f32`, in a `const` context.
+    ///
+    /// This gives the same result as `color.into_format::<f32>()`.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// const BACKGROUND: Srgb = Srgb::new(255u8, 51, 0).into_f32_const();
+    /// assert_eq!(BACKGROUND, Srgb::new(255u8, 51, 0).into_format());
+    /// ```
+    #[inline]
+    pub const fn into_f32_const(self) -> Rgb<S, f32> {
+        Rgb::new(
+            U8_TO_F32[self.red as usize],
+            U8_TO_F32[self.green as usize],
+            U8_TO_F32[self.blue as usize],
+        )
+    }
+
+    /// Mix the color with `other` by `factor`, using integer math.
+    ///
+    /// `factor` goes from `0`, which results in `self`, to `255`, which
+    /// results in `other`. Each component is calculated as `(self * (255 -
+    /// factor) + other * factor + 127) / 255`, with integer division, which
+    /// is the exact mix rounded to the nearest integer. Mixing `f32`
+    /// components with [`Mix`](crate::Mix) and converting them back to `u8`
+    /// may round differently, and give results that are off by one.
+    ///
+    /// The mixing happens in the encoded space of the color. Mixing
+    /// non-linear colors, such as [`Srgb`](crate::Srgb), is common in user
+    /// interfaces but makes the transitions look darker than mixing in linear
+    /// space, such as with [`Mix`](crate::Mix) on
+    /// [`LinSrgb`](crate::LinSrgb). Colors with a linear encoding are mixed
+    /// linearly.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// let a = Srgb::new(255u8, 0, 100);
+    /// let b = Srgb::new(0u8, 255, 100);
+    ///
+    /// assert_eq!(a.mix_u8(b, 0), a);
+    /// assert_eq!(a.mix_u8(b, 255), b);
+    /// assert_eq!(a.mix_u8(b, 128), Srgb::new(127, 128, 100));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mix_u8(self, other: Self, factor: u8) -> Self {
+        Rgb::new(
+            mix_u8(self.red, other.red, factor),
+            mix_u8(self.green, other.green, factor),
+            mix_u8(self.blue, other.blue, factor),
+        )
+    }
+
+    /// Mix each color in `colors` with the corresponding color in `others`
+    /// by `factor`, in place.
+    ///
+    /// This is the same as calling [`mix_u8`](Self::mix_u8) for each pair of
+    /// colors.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// let mut colors = [Srgb::new(255u8, 0, 100), Srgb::new(0u8, 255, 100)];
+    /// Srgb::mix_u8_slice(&mut colors, &[Srgb::new(0u8, 255, 100), Srgb::new(255u8, 0, 100)], 128);
+    /// assert_eq!(colors, [Srgb::new(127, 128, 100), Srgb::new(128, 127, 100)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` and `others` don't have the same length.
+    #[inline]
+    pub fn mix_u8_slice(colors: &mut [Self], others: &[Self], factor: u8) {
+        assert_eq!(
+            colors.len(),
+            others.len(),
+            "the color buffers have different lengths"
+        );
+
+        for (color, &other) in colors.iter_mut().zip(others) {
+            *color = color.mix_u8(other, factor);
+        }
+    }
+}
+
+impl<S> Rgb<S, i16> {
+    /// Calculate the signed difference `a - b` between two 8 bit colors,
+    /// channel by channel.
+    ///
+    /// Each channel of the result is in the range `-255..=255`, so the
+    /// difference can be stored without loss. Use
+    /// [`apply_difference`](Self::apply_difference) to reconstruct `a` from `b`.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// let a = Srgb::new(200u8, 10, 128);
+    /// let b = Srgb::new(50u8, 255, 128);
+    ///
+    /// let difference = Srgb::<i16>::signed_difference(a, b);
+    /// assert_eq!(difference, Srgb::new(150, -245, 0));
+    /// assert_eq!(difference.apply_difference(b), a);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn signed_difference(a: Rgb<S, u8>, b: Rgb<S, u8>) -> Self {
+        Rgb::new(
+            i16::from(a.red) - i16::from(b.red),
+            i16::from(a.green) - i16::from(b.green),
+            i16::from(a.blue) - i16::from(b.blue),
+        )
+    }
+
+    /// Add the difference to `base`, channel by channel.
+    ///
+    /// This is the inverse of [`signed_difference`](Self::signed_difference),
+    /// so `Rgb::signed_difference(a, b).apply_difference(b) == a`. Channels
+    /// that end up outside `0..=255`, which is only possible if the difference
+    /// wasn't calculated from `base`, are clamped.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// let difference = Srgb::new(100i16, -20, 0);
+    /// assert_eq!(
+    ///     difference.apply_difference(Srgb::new(200u8, 10, 128)),
+    ///     Srgb::new(255, 0, 128)
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn apply_difference(self, base: Rgb<S, u8>) -> Rgb<S, u8> {
+        fn apply(difference: i16, base: u8) -> u8 {
+            (i16::from(base) + difference).clamp(0, 255) as u8
+        }
+
+        Rgb::new(
+            apply(self.red, base.red),
+            apply(self.green, base.green),
+            apply(self.blue, base.blue),
+        )
+    }
+
+    /// Calculate the signed differences between two buffers of 8 bit colors
+    /// and write them to `output`.
+    ///
+    /// This is the same as calling
+    /// [`signed_difference`](Self::signed_difference) for each pair of colors
+    /// in `a` and `b`.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// let a = [Srgb::new(200u8, 10, 128), Srgb::new(0, 0, 0)];
+    /// let b = [Srgb::new(50u8, 255, 128), Srgb::new(255, 255, 255)];
+    /// let mut differences = [Srgb::new(0i16, 0, 0); 2];
+    ///
+    /// Srgb::signed_difference_slice(&a, &b, &mut differences);
+    /// assert_eq!(
+    ///     differences,
+    ///     [Srgb::new(150, -245, 0), Srgb::new(-255, -255, -255)]
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a`, `b` and `output` don't have the same length.
+    #[inline]
+    pub fn signed_difference_slice(a: &[Rgb<S, u8>], b: &[Rgb<S, u8>], output: &mut [Self]) {
+        assert_eq!(a.len(), b.len(), "the color buffers have different lengths");
+        assert_eq!(
+            a.len(),
+            output.len(),
+            "the output buffer has a different length than the color buffers"
+        );
+
+        for ((output, &a), &b) in output.iter_mut().zip(a).zip(b) {
+            *output = Self::signed_difference(a, b);
+        }
+    }
+
+    /// Add a buffer of differences to a buffer of 8 bit colors, in place.
+    ///
+    /// This is the same as calling
+    /// [`apply_difference`](Self::apply_difference) for each pair of
+    /// difference and color, and reconstructs the colors that were passed as
+    /// `a` to [`signed_difference_slice`](Self::signed_difference_slice).
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// let differences = [Srgb::new(150i16, -245, 0), Srgb::new(-255, -255, -255)];
+    /// let mut colors = [Srgb::new(50u8, 255, 128), Srgb::new(255, 255, 255)];
+    ///
+    /// Srgb::apply_difference_slice(&differences, &mut colors);
+    /// assert_eq!(colors, [Srgb::new(200, 10, 128), Srgb::new(0, 0, 0)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `differences` and `colors` don't have the same length.
+    #[inline]
+    pub fn apply_difference_slice(differences: &[Self], colors: &mut [Rgb<S, u8>]) {
+        assert_eq!(
+            differences.len(),
+            colors.len(),
+            "the difference and color buffers have different lengths"
+        );
+
+        for (color, &difference) in colors.iter_mut().zip(differences) {
+            *color = difference.apply_difference(*color);
+        }
+    }
+}
+
+impl Rgb<Srgb, u8> {
+    /// Convert the color to linear RGB with `f32` components, in a `const`
+    /// context.
+    ///
+    /// This gives the same result as `color.into_linear::<f32>()`. There's no
+    /// `const` conversion in the other direction, since it requires
+    /// floating point math.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, Srgb};
+    ///
+    /// const ACCENT: LinSrgb = Srgb::new(255u8, 128, 0).into_linear_const();
+    /// assert_eq!(ACCENT, Srgb::new(255u8, 128, 0).into_linear());
+    /// ```
+    #[inline]
+    pub const fn into_linear_const(self) -> Rgb<Linear<Srgb>, f32> {
+        Rgb::new(
+            srgb_u8_to_linear_f32(self.red),
+            srgb_u8_to_linear_f32(self.green),
+            srgb_u8_to_linear_f32(self.blue),
+        )
+    }
 }
 
+/// `u8` to `f32` component conversions, for use in `const` functions where
+/// floating point math isn't allowed. They are computed the same way as the
+/// `IntoStimulus<f32>` implementation for `u8`.
+const U8_TO_F32: [f32; 256] = {
+    let mut table = [0.0; 256];
+    let scale = 1.0 / 255.0;
+
+    let mut index = 0;
+    while index < 256 {
+        table[index] = index as f32 * scale;
+        index += 1;
+    }
+
+    table
+};
+
 impl<S: RgbStandard, T> Rgb<S, T> {
     /// Convert the color to linear RGB.
     ///
@@ -493,6 +989,75 @@ impl<S: RgbStandard, T> Rgb<S, T> {
     }
 }
 
+impl<S: RgbStandard> Rgb<S, u8> {
+    /// Convert linear RGB to non-linear RGB with `u8` components, with
+    /// dithering to reduce banding.
+    ///
+    /// The offset from `dither` is added to all three components in the
+    /// encoded domain, just before rounding, so each component is at most one
+    /// step away from what [`from_linear`][Rgb::from_linear] would give.
+    ///
+    /// ```
+    /// use palette::{encoding::dither::Bayer, Srgb, LinSrgb};
+    ///
+    /// let mut dither = Bayer::new_4x4();
+    /// let encoded = Srgb::<u8>::from_linear_dithered(LinSrgb::new(0.95f32, 0.90, 0.30), &mut dither);
+    /// ```
+    ///
+    /// See the [`dither`](crate::encoding::dither) module for more details.
+    #[inline]
+    pub fn from_linear_dithered<D>(color: Rgb<Linear<S::Space>, f32>, dither: &mut D) -> Self
+    where
+        S::TransferFn: FromLinear<f32, f32>,
+        D: Dither + ?Sized,
+    {
+        let offset = dither.next_offset();
+
+        Rgb::new(
+            dither::quantize_u8(S::TransferFn::from_linear(color.red), offset),
+            dither::quantize_u8(S::TransferFn::from_linear(color.green), offset),
+            dither::quantize_u8(S::TransferFn::from_linear(color.blue), offset),
+        )
+    }
+
+    /// Convert a row of linear RGB pixels to non-linear RGB with `u8`
+    /// components, with dithering to reduce banding.
+    ///
+    /// Each color in `colors` is converted with
+    /// [`from_linear_dithered`][Rgb::from_linear_dithered] and written to the
+    /// same position in `output`. Conversion stops at the end of the shorter
+    /// slice, after which `dither` is moved to the next row.
+    ///
+    /// ```
+    /// use palette::{encoding::dither::Bayer, Srgb, LinSrgb};
+    ///
+    /// let gradient: Vec<_> = (0..64)
+    ///     .map(|i| LinSrgb::new(0.2f32, 0.3, 0.4) * (i as f32 / 64.0))
+    ///     .collect();
+    /// let mut row = [Srgb::new(0u8, 0, 0); 64];
+    /// let mut dither = Bayer::new_8x8();
+    ///
+    /// for _ in 0..8 {
+    ///     Srgb::from_linear_dithered_slice(&gradient, &mut row, &mut dither);
+    ///     // Use the row...
+    /// }
+    /// ```
+    pub fn from_linear_dithered_slice<D>(
+        colors: &[Rgb<Linear<S::Space>, f32>],
+        output: &mut [Self],
+        dither: &mut D,
+    ) where
+        S::TransferFn: FromLinear<f32, f32>,
+        D: Dither + ?Sized,
+    {
+        for (&color, output) in colors.iter().zip(output) {
+            *output = Self::from_linear_dithered(color, dither);
+        }
+
+        dither.next_row();
+    }
+}
+
 impl<S: RgbSpace, T> Rgb<Linear<S>, T> {
     /// Convert a linear color to a different encoding.
     ///
@@ -558,20 +1123,431 @@ where
     }
 }
 
-/// <span id="Rgba"></span>[`Rgba`](crate::rgb::Rgba) implementations.
-impl<S, T, A> Alpha<Rgb<S, T>, A> {
-    /// Create an RGBA color.
-    pub const fn new(red: T, green: T, blue: T, alpha: A) -> Self {
-        Alpha {
-            color: Rgb::new(red, green, blue),
-            alpha,
-        }
+impl<S, T> Rgb<S, T>
+where
+    T: MinMax + Clone,
+{
+    /// Return the largest of the red, green and blue components.
+    ///
+    /// This is the value in [`Hsv`].
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// assert_eq!(Srgb::new(0.8f32, 0.2, 0.5).max_component(), 0.8);
+    /// ```
+    #[must_use]
+    pub fn max_component(&self) -> T {
+        self.red
+            .clone()
+            .max(self.green.clone())
+            .max(self.blue.clone())
     }
 
-    /// Parses a color hex code into an RGBA value.
+    /// Return the smallest of the red, green and blue components.
     ///
     /// ```
-    /// use palette::Srgba;
+    /// use palette::Srgb;
+    ///
+    /// assert_eq!(Srgb::new(0.8f32, 0.2, 0.5).min_component(), 0.2);
+    /// ```
+    #[must_use]
+    pub fn min_component(&self) -> T {
+        self.red
+            .clone()
+            .min(self.green.clone())
+            .min(self.blue.clone())
+    }
+}
+
+impl<S, T> Rgb<S, T>
+where
+    T: MinMax + Arithmetics + Clone,
+{
+    /// Return the chroma, which is the difference between the largest and
+    /// smallest component.
+    ///
+    /// This is the chroma of the hexagonal model that [`Hsl`] and [`Hsv`] are
+    /// based on, and not the same as the chroma in, for example,
+    /// [`Lch`](crate::Lch) or [`Oklch`].
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::Srgb;
+    ///
+    /// assert_relative_eq!(Srgb::new(0.8f32, 0.2, 0.5).chroma(), 0.6);
+    /// ```
+    #[must_use]
+    pub fn chroma(&self) -> T {
+        self.max_component() - self.min_component()
+    }
+}
+
+impl<S, T> Rgb<S, T>
+where
+    T: RealAngle + Zero + One + MinMax + Arithmetics + PartialCmp + Clone,
+    T::Mask: BoolMask + BitOps + LazySelect<T> + Clone + 'static,
+{
+    /// Calculate the `(hue, saturation, value)` components of [`Hsv`] directly.
+    ///
+    /// This is the same as converting to `Hsv` and calling
+    /// [`Hsv::into_components`], but can be used without naming the `Hsv`
+    /// type. Negative components are treated as `0`.
+    ///
+    /// ```
+    /// use palette::{Hsv, IntoColor, Srgb};
+    ///
+    /// let rgb = Srgb::new(0.8f32, 0.2, 0.5);
+    /// let hsv: Hsv = rgb.into_color();
+    /// assert_eq!(rgb.into_hsv_components(), hsv.into_components());
+    /// ```
+    #[must_use]
+    pub fn into_hsv_components(self) -> (RgbHue<T>, T, T) {
+        let (hue, max, min) = self.into_hexagonal_hue_max_min();
+
+        let chroma = max.clone() - min;
+        let saturation = lazy_select! {
+            if chroma.eq(&T::zero()) => T::zero(),
+            else => chroma.clone() / &max,
+        };
+
+        (hue, saturation, max)
+    }
+
+    /// Calculate the `(hue, saturation, lightness)` components of [`Hsl`]
+    /// directly.
+    ///
+    /// This is the same as converting to `Hsl` and calling
+    /// [`Hsl::into_components`], but can be used without naming the `Hsl`
+    /// type. Negative components are treated as `0`.
+    ///
+    /// ```
+    /// use palette::{Hsl, IntoColor, Srgb};
+    ///
+    /// let rgb = Srgb::new(0.8f32, 0.2, 0.5);
+    /// let hsl: Hsl = rgb.into_color();
+    /// assert_eq!(rgb.into_hsl_components(), hsl.into_components());
+    /// ```
+    #[must_use]
+    pub fn into_hsl_components(self) -> (RgbHue<T>, T, T) {
+        let (hue, max, min) = self.into_hexagonal_hue_max_min();
+
+        let sum = max.clone() + &min;
+        let lightness = sum.clone() / T::from_f64(2.0);
+
+        let chroma = max.clone() - &min;
+        let saturation = lazy_select! {
+            if min.eq(&max) => T::zero(),
+            else => chroma.clone() /
+                sum.gt(&T::one()).select(T::from_f64(2.0) - &sum, sum.clone()),
+        };
+
+        (hue, saturation, lightness)
+    }
+
+    /// Calculate the hue of the hexagonal model that [`Hsl`] and [`Hsv`] are
+    /// based on, together with the largest and smallest component. Negative
+    /// components are treated as `0`.
+    fn into_hexagonal_hue_max_min(self) -> (RgbHue<T>, T, T) {
+        // Avoid negative numbers
+        let red = self.red.max(T::zero());
+        let green = self.green.max(T::zero());
+        let blue = self.blue.max(T::zero());
+
+        // The SIMD optimized version showed significant slowdown for regular floats.
+        if TypeId::of::<T::Mask>() == TypeId::of::<bool>() {
+            let (max, min, sep, coeff) = {
+                let (max, min, sep, coeff) = if red.gt(&green).is_true() {
+                    (red.clone(), green.clone(), green.clone() - &blue, T::zero())
+                } else {
+                    (
+                        green.clone(),
+                        red.clone(),
+                        blue.clone() - &red,
+                        T::from_f64(2.0),
+                    )
+                };
+                if blue.gt(&max).is_true() {
+                    (blue, min, red - green, T::from_f64(4.0))
+                } else {
+                    let min_val = if blue.lt(&min).is_true() { blue } else { min };
+                    (max, min_val, sep, coeff)
+                }
+            };
+
+            let hue = if max.neq(&min).is_true() {
+                let d = max.clone() - &min;
+                ((sep / d) + coeff) * T::from_f64(60.0)
+            } else {
+                T::zero()
+            };
+
+            (hue.into(), max, min)
+        } else {
+            // Based on OPTIMIZED RGB TO HSV COLOR CONVERSION USING SSE TECHNOLOGY
+            // by KOBALICEK, Petr & BLIZNAK, Michal
+            //
+            // This implementation assumes less about the underlying mask and number
+            // representation. The hue is also multiplied by 6 to avoid rounding
+            // errors when using degrees.
+
+            let six = T::from_f64(6.0);
+
+            let max = red.clone().max(green.clone()).max(blue.clone());
+            let min = red.clone().min(green.clone()).min(blue.clone());
+
+            let chroma = max.clone() - &min;
+
+            // Each of these represents an RGB component. The maximum will be false
+            // while the two other will be true. They are later used for determining
+            // which branch in the hue equation we end up in.
+            let x = max.neq(&red);
+            let y = max.eq(&red) | max.neq(&green);
+            let z = max.eq(&red) | max.eq(&green);
+
+            // The hue base is the `1`, `2/6`, `4/6` or 0 part of the hue equation,
+            // except it's multiplied by 6 here.
+            let hue_base = x.clone().select(
+                z.clone().select(T::from_f64(-4.0), T::from_f64(4.0)),
+                T::zero(),
+            ) + &six;
+
+            // Each of these is a part of `G - B`, `B - R`, `R - G` or 0 from the
+            // hue equation. They become positive, negative or 0, depending on which
+            // branch we should be in. This makes the sum of all three combine as
+            // expected.
+            let red_m = lazy_select! {
+               if x => y.clone().select(red.clone(), -red),
+               else => T::zero(),
+            };
+            let green_m = lazy_select! {
+               if y.clone() => z.clone().select(green.clone(), -green),
+               else => T::zero(),
+            };
+            let blue_m = lazy_select! {
+               if z => y.select(-blue.clone(), blue),
+               else => T::zero(),
+            };
+
+            // This is the hue equation parts combined. The hue base is the constant
+            // and the RGB components are masked so up to two of them are non-zero.
+            // Once again, this is multiplied by 6, so the chroma isn't multiplied
+            // before dividing.
+            //
+            // We also avoid dividing by 0 for non-SIMD values.
+            let hue = lazy_select! {
+                if chroma.eq(&T::zero()) => T::zero(),
+                else => hue_base + (red_m + green_m + blue_m) / &chroma,
+            };
+
+            // hue will always be within [0, 12) (it's multiplied by 6, compared to
+            // the paper), so we can subtract by 6 instead of using % to get it
+            // within [0, 6).
+            let hue_sub = hue.gt_eq(&six).select(six, T::zero());
+            let hue = hue - hue_sub;
+
+            (RgbHue::from_degrees(hue * T::from_f64(60.0)), max, min)
+        }
+    }
+}
+
+impl<S, T> Rgb<S, T>
+where
+    T: Real + Zero + One + Arithmetics + PartialCmp + Clone,
+    T::Mask: Select<T> + Clone,
+    Oklab<T>: FromColorUnclamped<Self> + Lighten<Scalar = T> + Darken<Scalar = T>,
+    Self: FromColorUnclamped<Oklab<T>> + IsWithinBounds<Mask = T::Mask> + Clamp,
+{
+    /// Scale the perceived lightness towards white by `factor`, a value
+    /// ranging from `0.0` to `1.0`.
+    ///
+    /// The [`Lighten`] implementation for `Rgb` scales each channel towards its
+    /// maximum value, which also changes the saturation and hue of the color.
+    /// This method changes the lightness in [`Oklab`] instead, and reduces the
+    /// chroma if needed to fit the result in the RGB gamut. The hue stays the
+    /// same.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use palette::{IntoColor, Lighten, Oklch, Srgb};
+    ///
+    /// let orange = Srgb::new(0.9f32, 0.45, 0.1);
+    /// let original: Oklch = orange.into_color();
+    ///
+    /// // Scaling the channels pulls the hue towards yellow.
+    /// let scaled: Oklch = orange.lighten(0.4).into_color();
+    /// assert!((scaled.hue - original.hue).into_degrees().abs() > 5.0);
+    ///
+    /// // Lightening in Oklab keeps the hue.
+    /// let perceptual: Oklch = orange.lighten_perceptual(0.4).into_color();
+    /// assert_abs_diff_eq!(
+    ///     (perceptual.hue - original.hue).into_degrees(),
+    ///     0.0,
+    ///     epsilon = 0.1
+    /// );
+    /// assert!(perceptual.l > original.l);
+    /// ```
+    #[must_use]
+    pub fn lighten_perceptual(self, factor: T) -> Self {
+        Self::from_oklab_in_gamut(Oklab::from_color_unclamped(self).lighten(factor))
+    }
+
+    /// Scale the perceived lightness towards black by `factor`, a value
+    /// ranging from `0.0` to `1.0`.
+    ///
+    /// This is the opposite of [`lighten_perceptual`](Rgb::lighten_perceptual)
+    /// and keeps the hue in the same way.
+    ///
+    /// ```
+    /// use palette::{IntoColor, Oklch, Srgb};
+    ///
+    /// let orange = Srgb::new(0.9f32, 0.45, 0.1);
+    /// let original: Oklch = orange.into_color();
+    /// let darker: Oklch = orange.darken_perceptual(0.4).into_color();
+    ///
+    /// assert!(darker.l < original.l);
+    /// ```
+    #[must_use]
+    pub fn darken_perceptual(self, factor: T) -> Self {
+        Self::from_oklab_in_gamut(Oklab::from_color_unclamped(self).darken(factor))
+    }
+
+    /// Adjust the perceived lightness until the [WCAG 2.1 relative
+    /// contrast](Wcag21RelativeContrast::relative_contrast) to `background` is
+    /// at least `target_ratio`.
+    ///
+    /// The color is made darker or lighter with
+    /// [`darken_perceptual`](Rgb::darken_perceptual) or
+    /// [`lighten_perceptual`](Rgb::lighten_perceptual), so the hue stays the
+    /// same and the chroma is only reduced if it doesn't fit in the RGB gamut.
+    /// The direction that requires the smallest change is used if both would
+    /// work. `self` is returned as it is if it already has enough contrast,
+    /// and `None` is returned if neither black nor white would have enough
+    /// contrast.
+    ///
+    /// ```
+    /// use palette::{color_difference::Wcag21RelativeContrast, Srgb};
+    ///
+    /// let brand = Srgb::new(0.3f32, 0.6, 0.9);
+    /// let white = Srgb::new(1.0, 1.0, 1.0);
+    /// assert!(brand.relative_contrast(white) < 4.5);
+    ///
+    /// let text = brand.ensure_contrast(white, 4.5).unwrap();
+    /// assert!(text.relative_contrast(white) >= 4.5);
+    ///
+    /// // 21:1 is only possible between black and white.
+    /// assert_eq!(brand.ensure_contrast(white, 22.0), None);
+    /// ```
+    #[must_use]
+    pub fn ensure_contrast(self, background: Self, target_ratio: T) -> Option<Self>
+    where
+        Self: Wcag21RelativeContrast<Scalar = T> + Clone,
+        T: PartialOrd,
+    {
+        if self.clone().relative_contrast(background.clone()) >= target_ratio {
+            return Some(self);
+        }
+
+        let darker = self.contrast_factor(&background, &target_ratio, Self::darken_perceptual);
+        let lighter = self.contrast_factor(&background, &target_ratio, Self::lighten_perceptual);
+
+        match (darker, lighter) {
+            (Some(darker), Some(lighter)) => {
+                // Compare how much the lightness changes in each direction.
+                let lightness = Oklab::from_color_unclamped(self.clone()).l;
+                let darker_change = lightness.clone() * &darker;
+                let lighter_change = (T::one() - lightness) * &lighter;
+
+                if darker_change <= lighter_change {
+                    Some(self.darken_perceptual(darker))
+                } else {
+                    Some(self.lighten_perceptual(lighter))
+                }
+            }
+            (Some(darker), None) => Some(self.darken_perceptual(darker)),
+            (None, Some(lighter)) => Some(self.lighten_perceptual(lighter)),
+            (None, None) => None,
+        }
+    }
+
+    /// Find the smallest `adjust` factor that makes the contrast to
+    /// `background` at least `target_ratio`, if there is one.
+    fn contrast_factor(
+        &self,
+        background: &Self,
+        target_ratio: &T,
+        adjust: fn(Self, T) -> Self,
+    ) -> Option<T>
+    where
+        Self: Wcag21RelativeContrast<Scalar = T> + Clone,
+        T: PartialOrd,
+    {
+        // 24 halvings are enough to reach the precision of `f32`.
+        const SEARCH_STEPS: usize = 24;
+
+        let has_contrast = |factor: T| {
+            adjust(self.clone(), factor).relative_contrast(background.clone()) >= *target_ratio
+        };
+
+        if !has_contrast(T::one()) {
+            return None;
+        }
+
+        // `high` always has enough contrast, so it's the one to return.
+        let mut low = T::zero();
+        let mut high = T::one();
+
+        for _ in 0..SEARCH_STEPS {
+            let middle = (low.clone() + &high) * T::from_f64(0.5);
+
+            if has_contrast(middle.clone()) {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+
+        Some(high)
+    }
+
+    /// Convert from `Oklab` while keeping the lightness and hue, by reducing
+    /// the chroma until the color is within the RGB gamut.
+    fn from_oklab_in_gamut(oklab: Oklab<T>) -> Self {
+        let color = Self::from_color_unclamped(oklab.clone());
+        let is_within_bounds = color.is_within_bounds();
+
+        if is_within_bounds.is_true() {
+            return color;
+        }
+
+        let factor = crate::oklab::max_chroma_factor(is_within_bounds, |factor: T| {
+            Self::from_color_unclamped(Oklab::new(
+                oklab.l.clone(),
+                oklab.a.clone() * &factor,
+                oklab.b.clone() * factor,
+            ))
+            .is_within_bounds()
+        });
+
+        // The lightness itself may be out of range, so clamp what's left.
+        Self::from_color_unclamped(Oklab::new(oklab.l, oklab.a * &factor, oklab.b * factor)).clamp()
+    }
+}
+
+/// <span id="Rgba"></span>[`Rgba`](crate::rgb::Rgba) implementations.
+impl<S, T, A> Alpha<Rgb<S, T>, A> {
+    /// Create an RGBA color.
+    pub const fn new(red: T, green: T, blue: T, alpha: A) -> Self {
+        Alpha {
+            color: Rgb::new(red, green, blue),
+            alpha,
+        }
+    }
+
+    /// Parses a color hex code into an RGBA value.
+    ///
+    /// ```
+    /// use palette::Srgba;
     ///
     /// let rgba = Srgba::new(0xf0u8, 0x34, 0xe6, 0xff);
     /// let rgba_from_hex = Srgba::from_hex("#f034e6ff").unwrap();
@@ -602,6 +1578,31 @@ impl<S, T, A> Alpha<Rgb<S, T>, A> {
         hex.parse()
     }
 
+    /// Parses a color hex code into an RGBA value, while allowing surrounding
+    /// whitespace and a `0x` prefix.
+    ///
+    /// The string is trimmed from ASCII whitespace, and an optional `0x` or
+    /// `0X` prefix is removed, before parsing it like
+    /// [`from_hex`](Self::from_hex). The position of any invalid character is
+    /// relative to the original string.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// let rgba = Srgba::new(0xf0u8, 0x34, 0xe6, 0xff);
+    /// assert_eq!(Srgba::from_str_lenient(" #f034e6ff\n").unwrap(), rgba);
+    /// assert_eq!(Srgba::from_str_lenient("0xf034e6ff").unwrap(), rgba);
+    /// ```
+    pub fn from_str_lenient(hex: &str) -> Result<Self, FromHexError>
+    where
+        Self: FromStr<Err = FromHexError>,
+    {
+        let (hex_code, offset) = trim_hex_lenient(hex)?;
+        hex_code
+            .parse()
+            .map_err(|error: FromHexError| error.with_offset(offset))
+    }
+
     /// Convert the RGBA components into other number types.
     ///
     /// ```
@@ -713,6 +1714,237 @@ impl<S> Rgba<S, u8> {
     {
         O::unpack(color)
     }
+
+    /// Convert to a packed `u32` in the `0xRRGGBBAA` component order, in a
+    /// `const` context.
+    ///
+    /// This gives the same result as `u32::from(color)`.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// const INTEGER: u32 = Srgba::new(96u8, 127, 0, 255).into_u32_const();
+    /// assert_eq!(0x607F00FF, INTEGER);
+    /// ```
+    #[inline]
+    pub const fn into_u32_const(self) -> u32 {
+        (self.color.red as u32) << 24
+            | (self.color.green as u32) << 16
+            | (self.color.blue as u32) << 8
+            | self.alpha as u32
+    }
+
+    /// Convert from a packed `u32` in the `0xRRGGBBAA` component order, in a
+    /// `const` context.
+    ///
+    /// This gives the same result as `Rgba::from(color)`.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// const OVERLAY: Srgba<u8> = Srgba::from_u32_const(0x607F0080);
+    /// assert_eq!(Srgba::new(96u8, 127, 0, 128), OVERLAY);
+    /// ```
+    #[inline]
+    pub const fn from_u32_const(color: u32) -> Self {
+        Alpha {
+            color: Rgb::from_u32_const(color >> 8),
+            alpha: color as u8,
+        }
+    }
+
+    /// Convert to a `[red, green, blue, alpha]` array, in a `const` context.
+    ///
+    /// This gives the same result as `cast::into_array(color)`.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// const ARRAY: [u8; 4] = Srgba::new(96u8, 127, 0, 128).into_array_const();
+    /// assert_eq!([96, 127, 0, 128], ARRAY);
+    /// ```
+    #[inline]
+    pub const fn into_array_const(self) -> [u8; 4] {
+        [
+            self.color.red,
+            self.color.green,
+            self.color.blue,
+            self.alpha,
+        ]
+    }
+
+    /// Convert from a `[red, green, blue, alpha]` array, in a `const` context.
+    ///
+    /// This gives the same result as `cast::from_array(array)`.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// const OVERLAY: Srgba<u8> = Srgba::from_array_const([96, 127, 0, 128]);
+    /// assert_eq!(Srgba::new(96u8, 127, 0, 128), OVERLAY);
+    /// ```
+    #[inline]
+    pub const fn from_array_const(array: [u8; 4]) -> Self {
+        let [red, green, blue, alpha] = array;
+        Alpha {
+            color: Rgb::new(red, green, blue),
+            alpha,
+        }
+    }
+
+    /// Convert the components to `f32`, in a `const` context.
+    ///
+    /// This gives the same result as `color.into_format::<f32, f32>()`.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// const OVERLAY: Srgba = Srgba::new(255u8, 51, 0, 255).into_f32_const();
+    /// assert_eq!(OVERLAY, Srgba::new(255u8, 51, 0, 255).into_format());
+    /// ```
+    #[inline]
+    pub const fn into_f32_const(self) -> Rgba<S, f32> {
+        Alpha {
+            color: self.color.into_f32_const(),
+            alpha: U8_TO_F32[self.alpha as usize],
+        }
+    }
+
+    /// Mix the color with `other` by `factor`, using integer math.
+    ///
+    /// Each component, including the alpha, is mixed and rounded like in
+    /// [`Rgb::mix_u8`]. The alpha is mixed like the other components, so the
+    /// colors are expected to not be premultiplied.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// let a = Srgba::new(255u8, 0, 100, 255);
+    /// let b = Srgba::new(0u8, 255, 100, 0);
+    ///
+    /// assert_eq!(a.mix_u8(b, 0), a);
+    /// assert_eq!(a.mix_u8(b, 255), b);
+    /// assert_eq!(a.mix_u8(b, 128), Srgba::new(127, 128, 100, 127));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mix_u8(self, other: Self, factor: u8) -> Self {
+        Alpha {
+            color: self.color.mix_u8(other.color, factor),
+            alpha: mix_u8(self.alpha, other.alpha, factor),
+        }
+    }
+
+    /// Mix each color in `colors` with the corresponding color in `others`
+    /// by `factor`, in place.
+    ///
+    /// This is the same as calling [`mix_u8`](Self::mix_u8) for each pair of
+    /// colors.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// let mut colors = [Srgba::new(255u8, 0, 100, 255), Srgba::new(0u8, 255, 100, 0)];
+    /// Srgba::mix_u8_slice(&mut colors, &[Srgba::new(0u8, 255, 100, 0), Srgba::new(255u8, 0, 100, 255)], 128);
+    /// assert_eq!(colors, [Srgba::new(127, 128, 100, 127), Srgba::new(128, 127, 100, 128)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` and `others` don't have the same length.
+    #[inline]
+    pub fn mix_u8_slice(colors: &mut [Self], others: &[Self], factor: u8) {
+        assert_eq!(
+            colors.len(),
+            others.len(),
+            "the color buffers have different lengths"
+        );
+
+        for (color, &other) in colors.iter_mut().zip(others) {
+            *color = color.mix_u8(other, factor);
+        }
+    }
+}
+
+impl Rgba<Srgb, u8> {
+    /// Convert the color to linear RGB with `f32` components, in a `const`
+    /// context. The alpha component is only converted to `f32`.
+    ///
+    /// This gives the same result as `color.into_linear::<f32, f32>()`.
+    ///
+    /// ```
+    /// use palette::{LinSrgba, Srgba};
+    ///
+    /// const OVERLAY: LinSrgba = Srgba::new(255u8, 128, 0, 128).into_linear_const();
+    /// assert_eq!(OVERLAY, Srgba::new(255u8, 128, 0, 128).into_linear());
+    /// ```
+    #[inline]
+    pub const fn into_linear_const(self) -> Rgba<Linear<Srgb>, f32> {
+        Alpha {
+            color: self.color.into_linear_const(),
+            alpha: U8_TO_F32[self.alpha as usize],
+        }
+    }
+}
+
+impl<S: RgbStandard> Rgba<S, u8> {
+    /// Convert to a premultiplied `[red, green, blue, alpha]` array, where the
+    /// color is premultiplied in linear space before being encoded again.
+    ///
+    /// This is the same representation as `Color32` in `egui`, and it's
+    /// different from multiplying the encoded components by alpha directly.
+    /// The alpha component is kept as it is.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// let premultiplied = Srgba::new(255u8, 0, 0, 128).into_premultiplied_array();
+    /// assert_eq!(premultiplied, [188, 0, 0, 128]);
+    /// ```
+    ///
+    /// Linear colors, such as `LinearRgba` in `bevy`, can use the `From` and
+    /// `Into` implementations for `[T; 4]` directly, together with
+    /// [`premultiply`](crate::Alpha::premultiply) when needed.
+    pub fn into_premultiplied_array(self) -> [u8; 4]
+    where
+        S::TransferFn: IntoLinear<f32, u8> + FromLinear<f32, u8>,
+    {
+        let linear: Alpha<Rgb<Linear<S::Space>, f32>, f32> = self.into_linear();
+        let premultiplied = linear.premultiply();
+        let color = Rgb::<S, u8>::from_linear(premultiplied.color);
+
+        [color.red, color.green, color.blue, self.alpha]
+    }
+
+    /// Convert from a premultiplied `[red, green, blue, alpha]` array, where
+    /// the color was premultiplied in linear space before being encoded.
+    ///
+    /// This is the inverse of
+    /// [`into_premultiplied_array`](Self::into_premultiplied_array). Fully
+    /// transparent colors become transparent black, since their original color
+    /// can't be recovered.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// let color = Srgba::from_premultiplied_array([188, 0, 0, 128]);
+    /// assert_eq!(color, Srgba::new(255u8, 0, 0, 128));
+    /// ```
+    pub fn from_premultiplied_array([red, green, blue, alpha]: [u8; 4]) -> Self
+    where
+        S::TransferFn: IntoLinear<f32, u8> + FromLinear<f32, u8>,
+    {
+        let premultiplied = PreAlpha {
+            color: Rgb::<S, u8>::new(red, green, blue).into_linear::<f32>(),
+            alpha: f32::from_stimulus(alpha),
+        };
+        let linear = premultiplied.unpremultiply();
+
+        Alpha {
+            color: Rgb::from_linear(linear.color),
+            alpha,
+        }
+    }
 }
 
 impl<S: RgbStandard, T, A> Alpha<Rgb<S, T>, A> {
@@ -859,6 +2091,12 @@ where
 {
     #[inline]
     fn from_color_unclamped(color: Xyz<<S::Space as RgbSpace>::WhitePoint, T>) -> Self {
+        if let Some(transform_matrix) = S::Space::xyz_to_rgb_matrix() {
+            let (x, y, z) = color.into_components();
+            let [red, green, blue] = T::mul_f64_matrix(transform_matrix, [x, y, z]);
+            return Self::from_linear(Rgb::new(red, green, blue));
+        }
+
         let transform_matrix = Rgb::<Linear<S::Space>, T>::matrix_from_xyz();
         Self::from_linear(transform_matrix.convert_once(color))
     }
@@ -1033,6 +2271,7 @@ impl_is_within_bounds! {
         green => [Self::min_green(), Self::max_green()],
         blue => [Self::min_blue(), Self::max_blue()]
     }
+    new(red: T, green: T, blue: T)
     where T: Stimulus
 }
 impl_clamp! {
@@ -1074,6 +2313,149 @@ where
     }
 }
 
+impl<S, T> TryGetHue for Rgb<S, T>
+where
+    T: Real + RealAngle + Trigonometry + Arithmetics + MinMax + PartialCmp + Clone,
+    T::Mask: BitOps,
+{
+    /// The hue is considered meaningful if the chroma (the difference between
+    /// the largest and smallest component) is greater than `0.00001`.
+    fn get_hue_masked(&self) -> (RgbHue<T>, T::Mask) {
+        (
+            self.get_hue(),
+            !self.is_achromatic_within(T::from_f64(0.00001)),
+        )
+    }
+}
+
+impl<S, T> IsAchromatic for Rgb<S, T>
+where
+    T: Arithmetics + MinMax + PartialCmp + Clone,
+{
+    type Scalar = T;
+
+    /// The color is considered achromatic if the difference between the
+    /// largest and smallest component is at most `tolerance`.
+    fn is_achromatic_within(&self, tolerance: T) -> T::Mask {
+        self.chroma().lt_eq(&tolerance)
+    }
+}
+
+/// Calculates the relative luminance from the Y row of the RGB to XYZ matrix,
+/// without calculating X and Z.
+impl<S, T> RelativeLuminance for Rgb<S, T>
+where
+    T: Arithmetics + FromScalar + Clone,
+    T::Scalar: Real
+        + Recip
+        + IsValidDivisor<Mask = bool>
+        + Arithmetics
+        + FromScalar<Scalar = T::Scalar>
+        + Clone,
+    S: RgbStandard,
+    S::TransferFn: IntoLinear<T, T>,
+    <S::Space as RgbSpace>::WhitePoint: WhitePoint<T::Scalar>,
+    <S::Space as RgbSpace>::Primaries: Primaries<T::Scalar>,
+    Yxy<Any, T::Scalar>: IntoColorUnclamped<Xyz<Any, T::Scalar>>,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_relative_luminance(&self) -> T {
+        let matrix =
+            Xyz::<<S::Space as RgbSpace>::WhitePoint, T>::matrix_from_rgb::<Linear<S::Space>>();
+        let [_, _, _, red, green, blue, _, _, _] = matrix.into_array();
+        let linear = self.clone().into_linear();
+
+        red * linear.red + green * linear.green + blue * linear.blue
+    }
+}
+
+/// Changes the hue in [`Oklch`], as opposed to the HSV style hue from
+/// [`GetHue`], and reduces the chroma if needed to fit the result in the RGB
+/// gamut.
+///
+/// ```
+/// use palette::{OklabHue, Srgb, WithHue};
+///
+/// let blue = Srgb::new(1.0f32, 0.0, 0.0).with_hue(OklabHue::new(264.0));
+/// assert!(blue.blue > blue.red && blue.blue > blue.green);
+/// ```
+impl<S, T> WithHue<OklabHue<T>> for Rgb<S, T>
+where
+    T: Real + Zero + One + Arithmetics + PartialCmp + Clone,
+    T::Mask: Select<T> + Clone,
+    Oklab<T>: FromColorUnclamped<Self>
+        + FromColorUnclamped<Oklch<T>>
+        + Lighten<Scalar = T>
+        + Darken<Scalar = T>,
+    Oklch<T>: FromColorUnclamped<Oklab<T>> + WithHue<OklabHue<T>>,
+    Self: FromColorUnclamped<Oklab<T>> + IsWithinBounds<Mask = T::Mask> + Clamp,
+{
+    #[inline]
+    fn with_hue(self, hue: OklabHue<T>) -> Self {
+        let oklch = Oklch::from_color_unclamped(Oklab::from_color_unclamped(self)).with_hue(hue);
+        Self::from_oklab_in_gamut(Oklab::from_color_unclamped(oklch))
+    }
+}
+
+/// Changes the hue in [`Oklch`]. See the [`WithHue`] implementation for
+/// details.
+impl<S, T> SetHue<OklabHue<T>> for Rgb<S, T>
+where
+    Self: WithHue<OklabHue<T>> + Clone,
+{
+    #[inline]
+    fn set_hue(&mut self, hue: OklabHue<T>) {
+        *self = self.clone().with_hue(hue);
+    }
+}
+
+/// Shifts the hue in [`Oklch`], as opposed to the HSV style hue from
+/// [`GetHue`], and reduces the chroma if needed to fit the result in the RGB
+/// gamut.
+///
+/// ```
+/// use palette::{ShiftHue, Srgb};
+///
+/// let green = Srgb::new(1.0f32, 0.0, 0.0).shift_hue(120.0);
+/// assert!(green.green > green.red && green.green > green.blue);
+/// ```
+impl<S, T> ShiftHue for Rgb<S, T>
+where
+    T: Real + Zero + One + Arithmetics + PartialCmp + Clone,
+    T::Mask: Select<T> + Clone,
+    Oklab<T>: FromColorUnclamped<Self>
+        + FromColorUnclamped<Oklch<T>>
+        + Lighten<Scalar = T>
+        + Darken<Scalar = T>,
+    Oklch<T>: FromColorUnclamped<Oklab<T>> + ShiftHue<Scalar = T>,
+    Self: FromColorUnclamped<Oklab<T>> + IsWithinBounds<Mask = T::Mask> + Clamp,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn shift_hue(self, amount: T) -> Self {
+        let oklch =
+            Oklch::from_color_unclamped(Oklab::from_color_unclamped(self)).shift_hue(amount);
+        Self::from_oklab_in_gamut(Oklab::from_color_unclamped(oklch))
+    }
+}
+
+/// Shifts the hue in [`Oklch`]. See the [`ShiftHue`] implementation for
+/// details.
+impl<S, T> ShiftHueAssign for Rgb<S, T>
+where
+    Self: ShiftHue<Scalar = T> + Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn shift_hue_assign(&mut self, amount: T) {
+        *self = self.clone().shift_hue(amount);
+    }
+}
+
 impl_premultiply!(Rgb<S> {red, green, blue} phantom: standard);
 impl_euclidean_distance!(Rgb<S> {red, green, blue});
 
@@ -1099,6 +2481,7 @@ impl_color_add!(Rgb<S>, [red, green, blue], standard);
 impl_color_sub!(Rgb<S>, [red, green, blue], standard);
 impl_color_mul!(Rgb<S>, [red, green, blue], standard);
 impl_color_div!(Rgb<S>, [red, green, blue], standard);
+impl_component_wise!(Rgb<S>, [red, green, blue], standard);
 
 impl_tuple_conversion!(Rgb<S> as (T, T, T));
 impl_array_casts!(Rgb<S, T>, [T; 3]);
@@ -1106,6 +2489,7 @@ impl_simd_array_conversion!(Rgb<S>, [red, green, blue], standard);
 impl_struct_of_array_traits!(Rgb<S>, [red, green, blue], standard);
 
 impl_eq!(Rgb<S>, [red, green, blue]);
+impl_select!(Rgb<S>, [red, green, blue], standard);
 impl_copy_clone!(Rgb<S>, [red, green, blue], standard);
 
 impl<S, T> fmt::LowerHex for Rgb<S, T>
@@ -1151,6 +2535,24 @@ pub enum FromHexError {
     HexFormatError(&'static str),
     /// The hex value was not in a valid 4 or 8 character format.
     RgbaHexFormatError(&'static str),
+    /// The string contained a character that isn't a hexadecimal digit.
+    InvalidCharacter {
+        /// The byte offset of the first invalid character.
+        position: usize,
+    },
+}
+
+impl FromHexError {
+    /// Move the position of an invalid character by `offset` bytes, for when
+    /// the parsed string was a part of a longer string.
+    fn with_offset(self, offset: usize) -> Self {
+        match self {
+            FromHexError::InvalidCharacter { position } => FromHexError::InvalidCharacter {
+                position: position + offset,
+            },
+            error => error,
+        }
+    }
 }
 
 impl From<ParseIntError> for FromHexError {
@@ -1179,6 +2581,9 @@ impl core::fmt::Display for FromHexError {
                 "{}, please use format '#ffff', 'ffff', '#ffffffff', 'ffffffff', etc.",
                 s
             ),
+            FromHexError::InvalidCharacter { position } => {
+                write!(f, "invalid hex digit at position {}", position)
+            }
         }
     }
 }
@@ -1189,6 +2594,7 @@ impl std::error::Error for FromHexError {
         match self {
             FromHexError::HexFormatError(_s) => None,
             FromHexError::RgbaHexFormatError(_s) => None,
+            FromHexError::InvalidCharacter { .. } => None,
             FromHexError::ParseIntError(e) => Some(e),
         }
     }
@@ -1200,12 +2606,7 @@ impl<S> FromStr for Rgb<S, u8> {
     /// Parses a color hex code of format '#ff00bb' or '#abc' (with or without
     /// the leading '#') into an [`Rgb<S, u8>`] value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            3 => Ok(Self::from_components(rgb_from_hex_4bit(hex_code)?)),
-            6 => Ok(Self::from_components(rgb_from_hex_8bit(hex_code)?)),
-            _ => Err(FromHexError::HexFormatError("invalid hex code format")),
-        }
+        rgb_u8_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1215,12 +2616,7 @@ impl<S> FromStr for Rgba<S, u8> {
     /// Parses a color hex code of format '#ff00bbff' or '#abcd' (with or
     /// without the leading '#') into an [`Rgba<S, u8>`] value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            4 => Ok(Self::from_components(rgba_from_hex_4bit(hex_code)?)),
-            8 => Ok(Self::from_components(rgba_from_hex_8bit(hex_code)?)),
-            _ => Err(FromHexError::RgbaHexFormatError("invalid hex code format")),
-        }
+        rgba_u8_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1230,12 +2626,7 @@ impl<S> FromStr for Rgb<S, u16> {
     /// Parses a color hex code of format '#ffff0000bbbb', or shorter, (with or
     /// without the leading '#') into an [`Rgb<S, u16>`] value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            3 | 6 => Ok(Rgb::<S, u8>::from_str(hex_code)?.into_format()),
-            12 => Ok(Self::from_components(rgb_from_hex_16bit(hex_code)?)),
-            _ => Err(FromHexError::HexFormatError("invalid hex code format")),
-        }
+        rgb_u16_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1245,12 +2636,7 @@ impl<S> FromStr for Rgba<S, u16> {
     /// Parses a color hex code of format '#ffff0000bbbbffff', or shorter, (with
     /// or without the leading '#') into an [`Rgba<S, u16>`] value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            4 | 8 => Ok(Rgba::<S, u8>::from_str(hex_code)?.into_format()),
-            16 => Ok(Self::from_components(rgba_from_hex_16bit(hex_code)?)),
-            _ => Err(FromHexError::RgbaHexFormatError("invalid hex code format")),
-        }
+        rgba_u16_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1261,13 +2647,7 @@ impl<S> FromStr for Rgb<S, u32> {
     /// shorter, (with or without the leading '#') into an [`Rgb<S, u32>`]
     /// value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            3 | 6 => Ok(Rgb::<S, u8>::from_str(hex_code)?.into_format()),
-            12 => Ok(Rgb::<S, u16>::from_str(hex_code)?.into_format()),
-            24 => Ok(Self::from_components(rgb_from_hex_32bit(hex_code)?)),
-            _ => Err(FromHexError::HexFormatError("invalid hex code format")),
-        }
+        rgb_u32_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1278,13 +2658,7 @@ impl<S> FromStr for Rgba<S, u32> {
     /// or shorter, (with or without the leading '#') into an [`Rgba<S, u32>`]
     /// value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            4 | 8 => Ok(Rgba::<S, u8>::from_str(hex_code)?.into_format()),
-            16 => Ok(Rgba::<S, u16>::from_str(hex_code)?.into_format()),
-            32 => Ok(Self::from_components(rgba_from_hex_32bit(hex_code)?)),
-            _ => Err(FromHexError::RgbaHexFormatError("invalid hex code format")),
-        }
+        rgba_u32_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1293,12 +2667,7 @@ impl<S> FromStr for Rgb<S, f32> {
 
     /// Parses a color hex code for 16 bit components or less into an [`Rgb<S, f32>`] value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            3 | 6 => Ok(Rgb::<S, u8>::from_str(hex_code)?.into_format()),
-            12 => Ok(Rgb::<S, u16>::from_str(hex_code)?.into_format()),
-            _ => Err(FromHexError::HexFormatError("invalid hex code format")),
-        }
+        rgb_f32_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1308,12 +2677,7 @@ impl<S> FromStr for Rgba<S, f32> {
     /// Parses a color hex code for 16 bit components or less into an [`Rgba<S, f32>`]
     /// value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            4 | 8 => Ok(Rgba::<S, u8>::from_str(hex_code)?.into_format()),
-            16 => Ok(Rgba::<S, u16>::from_str(hex_code)?.into_format()),
-            _ => Err(FromHexError::RgbaHexFormatError("invalid hex code format")),
-        }
+        rgba_f32_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1322,13 +2686,7 @@ impl<S> FromStr for Rgb<S, f64> {
 
     /// Parses a color hex code for 32 bit components or less into an [`Rgb<S, f64>`] value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            3 | 6 => Ok(Rgb::<S, u8>::from_str(hex_code)?.into_format()),
-            12 => Ok(Rgb::<S, u16>::from_str(hex_code)?.into_format()),
-            24 => Ok(Rgb::<S, u32>::from_str(hex_code)?.into_format()),
-            _ => Err(FromHexError::HexFormatError("invalid hex code format")),
-        }
+        rgb_f64_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1338,13 +2696,7 @@ impl<S> FromStr for Rgba<S, f64> {
     /// Parses a color hex code for 32 bit components or less into an [`Rgba<S, f64>`]
     /// value.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            4 | 8 => Ok(Rgba::<S, u8>::from_str(hex_code)?.into_format()),
-            16 => Ok(Rgba::<S, u16>::from_str(hex_code)?.into_format()),
-            32 => Ok(Rgba::<S, u32>::from_str(hex_code)?.into_format()),
-            _ => Err(FromHexError::RgbaHexFormatError("invalid hex code format")),
-        }
+        rgba_f64_from_hex(hex).map(Self::from_components)
     }
 }
 
@@ -1369,9 +2721,9 @@ where
     }
 }
 
-impl<S, O, P> From<Packed<O, P>> for Rgb<S, u8>
+impl<S, T, O, P> From<Packed<O, P>> for Rgb<S, T>
 where
-    O: ComponentOrder<Rgba<S, u8>, P>,
+    O: ComponentOrder<Rgba<S, T>, P>,
 {
     #[inline]
     fn from(packed: Packed<O, P>) -> Self {
@@ -1501,6 +2853,36 @@ impl<S> From<Rgba<S, f64>> for Rgba<S, f32> {
     }
 }
 
+macro_rules! impl_from_smaller_uint {
+    ($($uint: ident => $($larger: ident),+;)+) => {
+        $(
+            $(
+                impl<S> From<Rgb<S, $uint>> for Rgb<S, $larger> {
+                    #[inline]
+                    fn from(color: Rgb<S, $uint>) -> Self {
+                        color.into_format()
+                    }
+                }
+
+                impl<S> From<Rgba<S, $uint>> for Rgba<S, $larger> {
+                    #[inline]
+                    fn from(color: Rgba<S, $uint>) -> Self {
+                        color.into_format()
+                    }
+                }
+            )+
+        )+
+    };
+}
+
+// Converting to larger integers is lossless, since the bits are repeated to
+// fill the larger range. For example, `0xAB` becomes `0xABABABAB` as `u32`.
+impl_from_smaller_uint!(
+    u8 => u16, u32, u64;
+    u16 => u32, u64;
+    u32 => u64;
+);
+
 #[allow(deprecated)]
 impl<S, T> crate::RelativeContrast for Rgb<S, T>
 where
@@ -1533,6 +2915,20 @@ where
     }
 }
 
+/// Calculates the relative luminance and contrast of 8 bit sRGB colors, such as
+/// the ones from hex codes, with `f32` as the working type.
+impl<S> Wcag21RelativeContrast for Rgb<S, u8>
+where
+    Rgb<S, f32>: Wcag21RelativeContrast<Scalar = f32>,
+{
+    type Scalar = f32;
+
+    #[inline]
+    fn relative_luminance(self) -> Luma<Linear<D65>, Self::Scalar> {
+        self.into_format::<f32>().relative_luminance()
+    }
+}
+
 impl_rand_traits_cartesian!(UniformRgb, Rgb<S> {red, green, blue} phantom: standard: PhantomData<S>);
 
 #[cfg(feature = "bytemuck")]
@@ -1552,6 +2948,274 @@ mod test {
 
     test_convert_into_from_xyz!(Rgb);
 
+    #[test]
+    fn const_conversions() {
+        const PACKED: u32 = Rgb::<Srgb, u8>::from_u32_const(0x12345678).into_u32_const();
+        const PACKED_ALPHA: u32 = Rgba::<Srgb, u8>::from_u32_const(0x12345678).into_u32_const();
+
+        assert_eq!(PACKED, u32::from(Rgb::<Srgb, u8>::from(0x12345678)));
+        assert_eq!(PACKED_ALPHA, u32::from(Rgba::<Srgb, u8>::from(0x12345678)));
+
+        for value in 0..=255u8 {
+            let color = Rgba::<Srgb, u8>::new(value, 255 - value, value / 2, value);
+
+            assert_eq!(color.into_f32_const(), color.into_format::<f32, f32>());
+            assert_eq!(color.into_linear_const(), color.into_linear::<f32, f32>());
+            assert_eq!(
+                Rgba::<Srgb, u8>::from_u32_const(color.into_u32_const()),
+                color
+            );
+            assert_eq!(color.into_u32_const(), u32::from(color));
+            assert_eq!(color.color.into_u32_const(), u32::from(color.color));
+            assert_eq!(color.into_array_const(), crate::cast::into_array(color));
+            assert_eq!(
+                Rgba::<Srgb, u8>::from_array_const(color.into_array_const()),
+                color
+            );
+        }
+    }
+
+    #[test]
+    fn mix_u8_matches_float() {
+        use crate::Mix;
+
+        // Every combination of `a` and `factor`, with a sample of `b`.
+        for a in 0..=255u8 {
+            for b in (0..=255u8).step_by(15).chain([1, 127, 128, 254]) {
+                let color_a = Rgb::<Srgb, u8>::new(a, b, a);
+                let color_b = Rgb::<Srgb, u8>::new(b, a, 255 - b);
+                let float_a = color_a.into_format::<f32>();
+                let float_b = color_b.into_format::<f32>();
+
+                for factor in 0..=255u8 {
+                    let mixed = color_a.mix_u8(color_b, factor);
+                    let reference = float_a
+                        .mix(float_b, f32::from(factor) / 255.0)
+                        .into_format::<u8>();
+
+                    let mixed_components: [u8; 3] = mixed.into();
+                    let reference_components: [u8; 3] = reference.into();
+                    for (&mixed, &reference) in mixed_components.iter().zip(&reference_components) {
+                        assert!(
+                            (i16::from(mixed) - i16::from(reference)).abs() <= 1,
+                            "{:?} and {:?} with factor {} became {:?}, expected {:?}",
+                            color_a,
+                            color_b,
+                            factor,
+                            mixed,
+                            reference
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mix_u8_alpha_and_slices() {
+        let a = Rgba::<Srgb, u8>::new(10, 20, 30, 40);
+        let b = Rgba::<Srgb, u8>::new(250, 240, 230, 220);
+        assert_eq!(a.mix_u8(b, 0), a);
+        assert_eq!(a.mix_u8(b, 255), b);
+        assert_eq!(a.mix_u8(b, 64), Rgba::new(70, 75, 80, 85));
+
+        let mut colors = [a, b, a];
+        Rgba::mix_u8_slice(&mut colors, &[b, a, a], 64);
+        assert_eq!(colors, [a.mix_u8(b, 64), b.mix_u8(a, 64), a]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the color buffers have different lengths")]
+    fn mix_u8_slice_length_mismatch() {
+        let mut colors = [Rgb::<Srgb, u8>::new(0, 0, 0); 2];
+        Rgb::mix_u8_slice(&mut colors, &[Rgb::new(255, 255, 255)], 128);
+    }
+
+    #[test]
+    fn draw_into_argb_framebuffer() {
+        const WIDTH: usize = 4;
+        const HEIGHT: usize = 3;
+
+        let mut framebuffer = [0xFF00_0000u32; WIDTH * HEIGHT];
+
+        // Draw a half transparent diagonal and a red last row.
+        {
+            let pixels = crate::rgb::argb_slice_as_rgba_mut(&mut framebuffer);
+            for (y, row) in pixels.chunks_mut(WIDTH).enumerate() {
+                row[y] = Rgba::<Srgb, u8>::new(0x10, 0x20, 0x30, 0x80).into();
+
+                if y == HEIGHT - 1 {
+                    for pixel in row {
+                        let mut color = Rgba::<Srgb, u8>::from(*pixel);
+                        color.red = 0xFF;
+                        *pixel = color.into();
+                    }
+                }
+            }
+        }
+
+        assert_eq!(
+            framebuffer,
+            [
+                0x8010_2030,
+                0xFF00_0000,
+                0xFF00_0000,
+                0xFF00_0000, //
+                0xFF00_0000,
+                0x8010_2030,
+                0xFF00_0000,
+                0xFF00_0000, //
+                0xFFFF_0000,
+                0xFFFF_0000,
+                0x80FF_2030,
+                0xFFFF_0000, //
+            ]
+        );
+
+        let pixels = crate::rgb::argb_slice_as_rgba(&framebuffer);
+        assert_eq!(
+            Rgba::<Srgb, u8>::from(pixels[WIDTH + 1]),
+            Rgba::new(0x10, 0x20, 0x30, 0x80)
+        );
+        assert_eq!(
+            crate::cast::packed_as::<channels::Argb>(&framebuffer),
+            pixels
+        );
+    }
+
+    #[test]
+    fn premultiplied_array() {
+        // Premultiplying in linear space gives brighter results than
+        // multiplying the encoded components.
+        let red = Rgba::<Srgb, u8>::new(255, 0, 0, 128);
+        assert_eq!(red.into_premultiplied_array(), [188, 0, 0, 128]);
+        assert_eq!(
+            Rgba::<Srgb, u8>::from_premultiplied_array([188, 0, 0, 128]),
+            red
+        );
+
+        let white = Rgba::<Srgb, u8>::new(255, 255, 255, 255);
+        assert_eq!(white.into_premultiplied_array(), [255, 255, 255, 255]);
+        assert_eq!(
+            Rgba::<Srgb, u8>::from_premultiplied_array([255, 255, 255, 255]),
+            white
+        );
+    }
+
+    #[test]
+    fn premultiplied_array_transparent() {
+        let transparent = Rgba::<Srgb, u8>::new(255, 100, 50, 0);
+        assert_eq!(transparent.into_premultiplied_array(), [0, 0, 0, 0]);
+        assert_eq!(
+            Rgba::<Srgb, u8>::from_premultiplied_array([0, 0, 0, 0]),
+            Rgba::new(0, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn premultiplied_array_roundtrip() {
+        for alpha in [64u8, 128, 200, 255].iter().copied() {
+            for value in (0..=255u8).step_by(17) {
+                let color = Rgba::<Srgb, u8>::new(value, 255 - value, 0, alpha);
+                let roundtrip =
+                    Rgba::<Srgb, u8>::from_premultiplied_array(color.into_premultiplied_array());
+
+                let difference = |a: u8, b: u8| (i16::from(a) - i16::from(b)).abs();
+                assert!(difference(color.red, roundtrip.red) <= 2, "{:?}", color);
+                assert!(difference(color.green, roundtrip.green) <= 2, "{:?}", color);
+                assert_eq!(color.alpha, roundtrip.alpha);
+            }
+        }
+    }
+
+    #[test]
+    fn try_get_hue() {
+        use crate::{FromColor, Lab, TryGetHue};
+
+        assert!(Rgb::<Srgb, f32>::new(0.8, 0.2, 0.2).try_get_hue().is_some());
+        assert!(Rgb::<Srgb, f32>::new(0.5, 0.5, 0.501)
+            .try_get_hue()
+            .is_some());
+        assert_eq!(Rgb::<Srgb, f32>::new(0.5, 0.5, 0.5).try_get_hue(), None);
+        assert_eq!(Rgb::<Srgb, f32>::new(0.0, 0.0, 0.0).try_get_hue(), None);
+
+        // Converted grays may not be exactly gray.
+        let near_gray = Rgb::<Srgb, f32>::from_color(Lab::new(50.0, 0.0, 0.0));
+        assert_eq!(near_gray.try_get_hue(), None);
+        let near_gray = Rgb::<Srgb, f32>::new(0.5, 0.5, 0.500001);
+        assert_eq!(near_gray.try_get_hue(), None);
+        assert_eq!(
+            Rgba::<Srgb, f32>::new(0.5, 0.5, 0.5, 0.8).try_get_hue(),
+            None
+        );
+    }
+
+    #[test]
+    fn is_achromatic() {
+        use crate::IsAchromatic;
+
+        assert!(Rgb::<Srgb, f32>::new(0.5, 0.5, 0.5).is_achromatic_within(0.0));
+        assert!(Rgb::<Srgb, f32>::new(0.5, 0.51, 0.5).is_achromatic_within(0.02));
+        assert!(!Rgb::<Srgb, f32>::new(0.5, 0.51, 0.5).is_achromatic_within(0.005));
+        assert!(!Rgb::<Srgb, f32>::new(0.8, 0.2, 0.2).is_achromatic_within(0.02));
+        assert!(Rgba::<Srgb, f32>::new(0.0, 0.0, 0.0, 0.5).is_achromatic_within(0.0));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn relative_luminance() {
+        use crate::{
+            encoding::Linear, FromColor, Hsl, Lab, Oklch, RelativeLuminance, SrgbLuma, Xyz,
+        };
+
+        let white = Rgb::<Srgb, f64>::new(1.0, 1.0, 1.0);
+        assert_relative_eq!(white.get_relative_luminance(), 1.0, epsilon = 1e-12);
+
+        let gray = Rgb::<Srgb, u8>::new(119, 119, 119).into_format::<f64>();
+        assert_relative_eq!(gray.get_relative_luminance(), 0.184, epsilon = 0.0005);
+        assert_relative_eq!(
+            gray.get_relative_luminance(),
+            SrgbLuma::new(119u8)
+                .into_format::<f64>()
+                .get_relative_luminance(),
+            epsilon = 1e-12
+        );
+
+        let color = Rgb::<Srgb, f64>::new(0.8, 0.3, 0.1);
+        let expected = Xyz::from_color(color).y;
+        assert_relative_eq!(color.get_relative_luminance(), expected, epsilon = 1e-12);
+        assert_relative_eq!(
+            color.into_linear().get_relative_luminance(),
+            expected,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Rgb::<Linear<Srgb>, f64>::from_color(color).get_relative_luminance(),
+            expected,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Hsl::from_color(color).get_relative_luminance(),
+            expected,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Lab::from_color(color).get_relative_luminance(),
+            expected,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Oklch::from_color(color).get_relative_luminance(),
+            expected,
+            epsilon = 1e-4
+        );
+        assert_relative_eq!(
+            Rgba::<Srgb, f64>::from_color(color).get_relative_luminance(),
+            expected,
+            epsilon = 1e-12
+        );
+    }
+
     #[test]
     fn ranges() {
         assert_ranges! {
@@ -1566,6 +3230,46 @@ mod test {
         }
     }
 
+    #[test]
+    fn try_new() {
+        assert_eq!(
+            Rgb::<Srgb, f32>::try_new(0.0, 0.5, 1.0).unwrap(),
+            Rgb::new(0.0, 0.5, 1.0)
+        );
+        assert_eq!(
+            Rgb::<Srgb, u8>::try_new(0, 128, 255).unwrap(),
+            Rgb::new(0, 128, 255)
+        );
+
+        let error = Rgb::<Srgb, f32>::try_new(0.0, 1.5, 1.0).unwrap_err();
+        assert_eq!(error.color(), Rgb::new(0.0, 1.5, 1.0));
+        assert!(Rgb::<Srgb, f32>::try_new(-0.1, 0.5, 1.0).is_err());
+        assert!(Rgb::<Srgb, f32>::try_new(0.0, 0.5, f32::NAN).is_err());
+    }
+
+    #[test]
+    fn clamp_struct_of_arrays() {
+        use crate::{ClampAssign, IsWithinBounds};
+
+        let mut colors =
+            Rgb::<Srgb, [f32; 3]>::new([-0.5, 0.5, 1.5], [0.0, 2.0, 1.0], [0.3, -1.0, 0.7]);
+        assert!(!colors.is_within_bounds());
+
+        colors.clamp_assign();
+        assert!(colors.is_within_bounds());
+        assert_eq!(colors.red, [0.0, 0.5, 1.0]);
+        assert_eq!(colors.green, [0.0, 1.0, 1.0]);
+        assert_eq!(colors.blue, [0.3, 0.0, 0.7]);
+
+        let mut red = [2.0f32, 0.5];
+        let mut green = [0.5f32, 0.5];
+        let mut blue = [0.5f32, -0.5];
+        let mut borrowed = Rgb::<Srgb, &mut [f32]>::new(&mut red, &mut green, &mut blue);
+        borrowed.clamp_assign();
+        assert_eq!(red, [1.0, 0.5]);
+        assert_eq!(blue, [0.5, 0.0]);
+    }
+
     raw_pixel_conversion_tests!(Rgb<Srgb>: red, green, blue);
     raw_pixel_conversion_fail_tests!(Rgb<Srgb>: red, green, blue);
 
@@ -1684,6 +3388,53 @@ mod test {
         );
     }
 
+    #[test]
+    fn from_smaller_uint() {
+        let color = Rgba::<Srgb, u8>::new(0xAB, 0x00, 0xFF, 0x12);
+
+        let wide: Rgba<Srgb, u32> = color.into();
+        assert_eq!(wide, Rgba::new(0xABAB_ABAB, 0, 0xFFFF_FFFF, 0x1212_1212));
+
+        let wider: Rgb<Srgb, u64> = Rgb::<Srgb, u16>::new(0xABCD, 0, 0xFFFF).into();
+        assert_eq!(wider, Rgb::new(0xABCD_ABCD_ABCD_ABCD, 0, u64::MAX));
+        let chained = Rgb::<Srgb, u64>::from(Rgb::<Srgb, u32>::from(color.color));
+        assert_eq!(chained, Rgb::new(0xABAB_ABAB_ABAB_ABAB, 0, u64::MAX));
+    }
+
+    #[test]
+    fn smaller_uint_round_trip() {
+        for n in 0..=u8::MAX {
+            let color = Rgb::<Srgb, u8>::new(n, n, n);
+
+            assert_eq!(Rgb::<Srgb, u16>::from(color).into_format::<u8>(), color);
+            assert_eq!(Rgb::<Srgb, u32>::from(color).into_format::<u8>(), color);
+            assert_eq!(Rgb::<Srgb, u64>::from(color).into_format::<u8>(), color);
+        }
+
+        for n in (0..=u16::MAX).step_by(7) {
+            let color = Rgb::<Srgb, u16>::new(n, n, n);
+
+            assert_eq!(Rgb::<Srgb, u32>::from(color).into_format::<u16>(), color);
+            assert_eq!(Rgb::<Srgb, u64>::from(color).into_format::<u16>(), color);
+        }
+    }
+
+    #[test]
+    fn wide_uint_accumulation() {
+        let frames = [
+            Rgb::<Srgb, u8>::new(10, 200, 255),
+            Rgb::new(20, 210, 255),
+            Rgb::new(30, 220, 255),
+        ];
+
+        let sum = frames
+            .iter()
+            .map(|&frame| Rgb::<Srgb, u32>::from(frame) / 3)
+            .fold(Rgb::new(0, 0, 0), |sum, frame| sum + frame);
+
+        assert_eq!(sum.into_format::<u8>(), Rgb::new(20, 210, 255));
+    }
+
     #[cfg(feature = "serializing")]
     #[test]
     fn serialize() {
@@ -1707,7 +3458,10 @@ mod test {
         assert!(c.is_ok());
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(255, 255, 255));
         let c = Rgb::<Srgb, u8>::from_str("#gggggg");
-        assert!(matches!(c, Err(FromHexError::ParseIntError(_))));
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 1 })
+        ));
         let c = Rgb::<Srgb, u8>::from_str("#fff");
         assert!(c.is_ok());
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(255, 255, 255));
@@ -1720,7 +3474,10 @@ mod test {
         assert!(c.is_ok());
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(18, 52, 86));
         let c = Rgb::<Srgb, u8>::from_str("#iii");
-        assert!(matches!(c, Err(FromHexError::ParseIntError(_))));
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 1 })
+        ));
         let c = Rgb::<Srgb, u8>::from_str("#08f");
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(0, 136, 255));
         let c = Rgb::<Srgb, u8>::from_str("08f");
@@ -1753,10 +3510,13 @@ mod test {
         let c = Rgba::<Srgb, u8>::from_str("#ffff");
         assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(255, 255, 255, 255));
         let c = Rgba::<Srgb, u8>::from_str("#gggggggg");
-        assert!(matches!(c, Err(FromHexError::ParseIntError(_))));
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 1 })
+        ));
         assert_eq!(
             format!("{}", c.err().unwrap()),
-            "invalid digit found in string"
+            "invalid hex digit at position 1"
         );
         let c = Rgba::<Srgb, u8>::from_str("#fff");
         assert!(matches!(c, Err(FromHexError::RgbaHexFormatError(_))));
@@ -1769,6 +3529,70 @@ mod test {
         assert!(matches!(c, Err(FromHexError::RgbaHexFormatError(_))));
     }
 
+    #[test]
+    fn from_str_invalid_character_position() {
+        let c = Rgb::<Srgb, u8>::from_str("#ffffff ");
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 7 })
+        ));
+        let c = Rgb::<Srgb, u8>::from_str("ff0g00");
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 3 })
+        ));
+        let c = Rgba::<Srgb, u16>::from_str("#ffff0000+bbbffff");
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 9 })
+        ));
+
+        // Multi-byte characters are reported instead of panicking.
+        let c = Rgb::<Srgb, f32>::from_str("#fféé");
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 3 })
+        ));
+        let c = Rgb::<Srgb, u8>::from_str("é");
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 0 })
+        ));
+    }
+
+    #[test]
+    fn from_str_lenient() {
+        let c = Rgb::<Srgb, u8>::from_str_lenient("  #ffffff\t");
+        assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(255, 255, 255));
+        let c = Rgb::<Srgb, u8>::from_str_lenient("0x08f");
+        assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(0, 136, 255));
+        let c = Rgb::<Srgb, u16>::from_str_lenient(" 0XFFFF8888BBBB ");
+        assert_eq!(c.unwrap(), Rgb::<Srgb, u16>::new(0xffff, 0x8888, 0xbbbb));
+        let c = Rgba::<Srgb, u8>::from_str_lenient("\n#08ff\n");
+        assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(0, 136, 255, 255));
+
+        // Positions are relative to the original string.
+        let c = Rgb::<Srgb, u8>::from_str_lenient("  0xffgfff");
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 6 })
+        ));
+        let c = Rgb::<Srgb, u8>::from_str_lenient(" #ff fff");
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 4 })
+        ));
+
+        // Only one prefix is allowed.
+        let c = Rgb::<Srgb, u8>::from_str_lenient("0x#fff");
+        assert!(matches!(
+            c,
+            Err(FromHexError::InvalidCharacter { position: 2 })
+        ));
+        let c = Rgb::<Srgb, u8>::from_str_lenient("   ");
+        assert!(matches!(c, Err(FromHexError::HexFormatError(_))));
+    }
+
     #[test]
     fn from_str_u16() {
         let c = Rgb::<Srgb, u16>::from_str("#f8b");
@@ -1908,6 +3732,506 @@ mod test {
         assert_eq!(Rgb::<Srgb, f32>::max_blue(), 1.0);
     }
 
+    #[test]
+    fn dithered_ramp() {
+        use crate::{
+            encoding::{dither::Bayer, FromLinear},
+            LinSrgb,
+        };
+
+        let mut dithered_error = 0.0;
+        let mut rounded_error = 0.0;
+
+        for i in 0..256 {
+            let linear = 0.05 + 0.1 * i as f32 / 256.0;
+            let exact = <Srgb as FromLinear<f32, f32>>::from_linear(linear) * 255.0;
+            let rounded = exact.round();
+
+            let color = LinSrgb::new(linear, linear, linear);
+            let mut row = [Rgb::<Srgb, u8>::new(0, 0, 0); 8];
+            let mut dither = Bayer::new_8x8();
+            let mut sum = 0.0;
+
+            for _ in 0..8 {
+                Rgb::from_linear_dithered_slice(&[color; 8], &mut row, &mut dither);
+
+                for pixel in &row {
+                    assert!((pixel.red as f32 - rounded).abs() <= 1.0);
+                    assert_eq!(pixel.red, pixel.green);
+                    assert_eq!(pixel.red, pixel.blue);
+                    sum += pixel.red as f32;
+                }
+            }
+
+            dithered_error += (sum / 64.0 - exact).abs();
+            rounded_error += (rounded - exact).abs();
+        }
+
+        assert!(dithered_error < rounded_error * 0.25);
+    }
+
+    #[test]
+    fn perceptual_lightness_keeps_hue() {
+        use crate::{IntoColor, IsWithinBounds, Oklch};
+
+        let colors = [
+            Rgb::<Srgb, f64>::new(0.9, 0.45, 0.1),
+            Rgb::new(0.1, 0.2, 0.8),
+            Rgb::new(0.2, 0.7, 0.3),
+            Rgb::new(0.6, 0.1, 0.5),
+            Rgb::new(1.0, 0.0, 0.0),
+            Rgb::new(0.0, 1.0, 1.0),
+        ];
+
+        for color in colors {
+            let original: Oklch<f64> = color.into_color();
+
+            for factor in [0.1, 0.5, 0.9] {
+                let lighter = color.lighten_perceptual(factor);
+                let darker = color.darken_perceptual(factor);
+                assert!(lighter.is_within_bounds());
+                assert!(darker.is_within_bounds());
+
+                let lighter: Oklch<f64> = lighter.into_color();
+                let darker: Oklch<f64> = darker.into_color();
+                assert!(lighter.l > original.l);
+                assert!(darker.l < original.l);
+
+                for result in [lighter, darker] {
+                    if result.chroma > 1e-3 {
+                        let difference = (result.hue - original.hue).into_degrees();
+                        assert!(
+                            difference.abs() < 0.5,
+                            "{:?} with factor {}: {}",
+                            color,
+                            factor,
+                            difference
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shift_hue_red_to_green() {
+        use crate::ShiftHue;
+
+        let green = Rgb::<Srgb, f64>::new(1.0, 0.0, 0.0).shift_hue(120.0);
+
+        // Pure red is at about 29 degrees in Oklch, so this lands a bit
+        // towards cyan from pure green.
+        assert!(green.green > 0.6);
+        assert!(green.blue < 0.3);
+        assert!(green.red < 0.01);
+    }
+
+    #[test]
+    fn hue_setters_keep_lightness() {
+        use crate::{IntoColor, OklabHue, Oklch, SetHue, ShiftHueAssign};
+
+        let color = Rgb::<Srgb, f64>::new(0.9, 0.45, 0.1);
+        let original: Oklch<f64> = color.into_color();
+
+        let mut shifted = [color, color];
+        shifted.shift_hue_assign(90.0);
+        let shifted: Oklch<f64> = shifted[1].into_color();
+        assert_relative_eq!(shifted.l, original.l, epsilon = 1e-6);
+        assert_relative_eq!(
+            (shifted.hue - original.hue).into_degrees(),
+            90.0,
+            epsilon = 1e-4
+        );
+
+        let mut with_hue = color;
+        with_hue.set_hue(OklabHue::new(200.0));
+        let with_hue: Oklch<f64> = with_hue.into_color();
+        assert_relative_eq!(with_hue.l, original.l, epsilon = 1e-6);
+        assert_relative_eq!(with_hue.hue.into_degrees(), -160.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn ensure_contrast() {
+        use crate::{color_difference::Wcag21RelativeContrast, IntoColor, Oklch};
+
+        let white = Rgb::<Srgb, f64>::new(1.0, 1.0, 1.0);
+        let gray = Rgb::<Srgb, f64>::new(0.5, 0.5, 0.5);
+
+        for &color in &[
+            Rgb::<Srgb, f64>::new(0.3, 0.6, 0.9),
+            Rgb::new(0.9, 0.45, 0.1),
+            Rgb::new(0.95, 0.9, 0.2),
+        ] {
+            for &(background, target) in &[(white, 4.5), (white, 7.0), (gray, 4.5)] {
+                let adjusted = color.ensure_contrast(background, target).unwrap();
+                let contrast = adjusted.relative_contrast(background);
+                assert!(contrast >= target);
+                assert!(contrast < target + 0.01);
+
+                let original: Oklch<f64> = color.into_color();
+                let adjusted: Oklch<f64> = adjusted.into_color();
+                assert_relative_eq!(
+                    (adjusted.hue - original.hue).into_degrees(),
+                    0.0,
+                    epsilon = 0.5
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ensure_contrast_limits() {
+        let color = Rgb::<Srgb, f64>::new(0.3, 0.6, 0.9);
+        let white = Rgb::new(1.0, 1.0, 1.0);
+        let gray = Rgb::new(0.5, 0.5, 0.5);
+
+        assert_eq!(color.ensure_contrast(white, 1.5), Some(color));
+        assert_eq!(color.ensure_contrast(white, 22.0), None);
+        assert_eq!(color.ensure_contrast(gray, 7.0), None);
+
+        // Only lighter colors can reach this contrast against a dark background.
+        let dark = Rgb::new(0.1, 0.1, 0.1);
+        let adjusted = color.ensure_contrast(dark, 10.0).unwrap();
+        assert!(adjusted.red > color.red);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_map_keys() {
+        use std::collections::HashMap;
+
+        use crate::{LinSrgb, Srgb as SrgbColor, SrgbLuma, Srgba};
+
+        let mut counts = HashMap::new();
+        for color in [
+            SrgbColor::new(255u8, 0, 0),
+            SrgbColor::new(0, 255, 0),
+            SrgbColor::new(255, 0, 0),
+        ] {
+            *counts.entry(color).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&SrgbColor::new(255, 0, 0)], 2);
+        assert_eq!(counts[&SrgbColor::new(0, 255, 0)], 1);
+
+        let mut names = HashMap::new();
+        names.insert(Srgba::new(0u8, 0, 0, 255), "black");
+        names.insert(Srgba::new(0, 0, 0, 0), "transparent");
+        assert_eq!(names[&Srgba::new(0, 0, 0, 255)], "black");
+        assert_eq!(names[&Srgba::new(0, 0, 0, 0)], "transparent");
+
+        let mut grays = HashMap::new();
+        grays.insert(SrgbLuma::new(128u8), "gray");
+        assert_eq!(grays[&SrgbLuma::new(128)], "gray");
+
+        // The RGB standard is only a type parameter and doesn't affect the hash.
+        fn hash_of(value: impl core::hash::Hash) -> u64 {
+            use core::hash::Hasher;
+
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        assert_eq!(
+            hash_of(SrgbColor::new(1u8, 2, 3)),
+            hash_of(LinSrgb::new(1u8, 2, 3))
+        );
+    }
+
+    #[test]
+    fn float_components_are_not_eq_or_hash() {
+        // Makes the call to `some_item` ambiguous, and fails to compile, if
+        // `$ty` implements `$trait`.
+        macro_rules! assert_not_impl {
+            ($ty: ty, $trait: path) => {{
+                trait AmbiguousIfImpl<A> {
+                    fn some_item() {}
+                }
+                impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+
+                #[allow(dead_code)]
+                struct Invalid;
+                impl<T: ?Sized + $trait> AmbiguousIfImpl<Invalid> for T {}
+
+                <$ty as AmbiguousIfImpl<_>>::some_item();
+            }};
+        }
+
+        assert_not_impl!(Rgb<Srgb, f32>, Eq);
+        assert_not_impl!(Rgb<Srgb, f32>, core::hash::Hash);
+        assert_not_impl!(crate::Srgba<f64>, Eq);
+        assert_not_impl!(crate::Srgba<f64>, core::hash::Hash);
+        assert_not_impl!(crate::Oklab<f32>, core::hash::Hash);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn signed_difference_round_trip() {
+        let mut seen = [false; 511];
+
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                let color_a = Rgb::<Srgb, u8>::new(a, b, 128);
+                let color_b = Rgb::<Srgb, u8>::new(b, a, a);
+
+                let difference = Rgb::signed_difference(color_a, color_b);
+                assert_eq!(difference.red, i16::from(a) - i16::from(b));
+                assert_eq!(difference.green, i16::from(b) - i16::from(a));
+                assert_eq!(difference.blue, 128 - i16::from(a));
+                assert_eq!(difference.apply_difference(color_b), color_a);
+
+                seen[(difference.red + 255) as usize] = true;
+            }
+        }
+
+        assert!(seen.iter().all(|&seen| seen));
+    }
+
+    #[test]
+    fn apply_difference_clamps() {
+        let difference = Rgb::<Srgb, i16>::new(255, -255, 10);
+        assert_eq!(
+            difference.apply_difference(Rgb::new(1, 254, 250)),
+            Rgb::new(255, 0, 255)
+        );
+    }
+
+    #[test]
+    fn signed_difference_slices() {
+        let a = [
+            Rgb::<Srgb, u8>::new(0, 128, 255),
+            Rgb::new(255, 255, 255),
+            Rgb::new(12, 34, 56),
+        ];
+        let b = [
+            Rgb::<Srgb, u8>::new(255, 128, 0),
+            Rgb::new(0, 0, 0),
+            Rgb::new(65, 43, 21),
+        ];
+        let mut differences = [Rgb::new(0, 0, 0); 3];
+
+        Rgb::signed_difference_slice(&a, &b, &mut differences);
+        assert_eq!(
+            differences,
+            [
+                Rgb::new(-255, 0, 255),
+                Rgb::new(255, 255, 255),
+                Rgb::new(-53, -9, 35)
+            ]
+        );
+
+        let mut colors = b;
+        Rgb::apply_difference_slice(&differences, &mut colors);
+        assert_eq!(colors, a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_difference_slice_length_mismatch() {
+        let a = [Rgb::<Srgb, u8>::new(0, 0, 0); 2];
+        let b = [Rgb::<Srgb, u8>::new(0, 0, 0); 3];
+        let mut differences = [Rgb::new(0, 0, 0); 2];
+
+        Rgb::signed_difference_slice(&a, &b, &mut differences);
+    }
+
+    #[test]
+    fn perceptual_lightness_of_gray() {
+        let gray = Rgb::<Srgb, f32>::new(0.5, 0.5, 0.5);
+        let lighter = gray.lighten_perceptual(0.5);
+
+        assert_relative_eq!(lighter.red, lighter.green, epsilon = 1e-5);
+        assert_relative_eq!(lighter.green, lighter.blue, epsilon = 1e-5);
+        assert!(lighter.red > gray.red);
+    }
+
+    #[cfg(feature = "wide")]
+    #[test]
+    fn perceptual_lightness_simd() {
+        let colors = [
+            Rgb::<Srgb, f32>::new(0.9, 0.45, 0.1),
+            Rgb::new(0.1, 0.2, 0.8),
+            Rgb::new(1.0, 0.0, 0.0),
+            Rgb::new(0.5, 0.5, 0.5),
+        ];
+
+        let simd =
+            Rgb::<Srgb, wide::f32x4>::from(colors).lighten_perceptual(wide::f32x4::splat(0.5));
+        let simd: [Rgb<Srgb, f32>; 4] = simd.into();
+
+        for (color, simd) in IntoIterator::into_iter(colors).zip(simd) {
+            assert_relative_eq!(color.lighten_perceptual(0.5), simd, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn hexagonal_components() {
+        use crate::{convert::FromColorUnclamped, Hsl, Hsv};
+
+        let colors = [
+            Rgb::<Srgb, f64>::new(0.8, 0.2, 0.5),
+            Rgb::new(0.1, 0.9, 0.3),
+            Rgb::new(0.3, 0.2, 0.7),
+            Rgb::new(0.5, 0.5, 0.5),
+            Rgb::new(0.0, 0.0, 0.0),
+            Rgb::new(1.0, 1.0, 1.0),
+            Rgb::new(-0.2, 0.4, 1.2),
+        ];
+
+        for rgb in colors {
+            assert_eq!(rgb.chroma(), rgb.max_component() - rgb.min_component());
+            assert_eq!(
+                rgb.into_hsv_components(),
+                Hsv::from_color_unclamped(rgb).into_components()
+            );
+            assert_eq!(
+                rgb.into_hsl_components(),
+                Hsl::from_color_unclamped(rgb).into_components()
+            );
+        }
+
+        let rgb = Rgb::<Srgb, f64>::new(0.8, 0.2, 0.5);
+        assert_eq!(rgb.max_component(), 0.8);
+        assert_eq!(rgb.min_component(), 0.2);
+        assert_eq!(rgb.into_hsv_components().2, 0.8);
+        assert_eq!(rgb.into_hsl_components().2, 0.5);
+    }
+
+    #[cfg(feature = "wide")]
+    #[test]
+    fn hexagonal_components_simd() {
+        let colors = [
+            Rgb::<Srgb, f32>::new(0.8, 0.2, 0.5),
+            Rgb::new(0.1, 0.9, 0.3),
+            Rgb::new(0.3, 0.2, 0.7),
+            Rgb::new(0.5, 0.5, 0.5),
+        ];
+
+        let simd = Rgb::<Srgb, wide::f32x4>::from(colors);
+        let (hsv_hue, saturation, value) = simd.into_hsv_components();
+        let hsv_hue = hsv_hue.into_positive_degrees().to_array();
+        let (saturation, value) = (saturation.to_array(), value.to_array());
+        let chroma = simd.chroma().to_array();
+
+        for (i, color) in IntoIterator::into_iter(colors).enumerate() {
+            let (hue, expected_saturation, expected_value) = color.into_hsv_components();
+            assert_relative_eq!(hsv_hue[i], hue.into_positive_degrees(), epsilon = 1e-4);
+            assert_relative_eq!(saturation[i], expected_saturation);
+            assert_relative_eq!(value[i], expected_value);
+            assert_relative_eq!(chroma[i], color.chroma());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn interleaved_component_buffer() {
+        let buffer = [1u8, 2, 3, 4, 5, 6, 7, 8, 9];
+        let colors: &[Rgb<Srgb, u8>] = crate::cast::from_component_slice(&buffer);
+
+        let planar = Rgb::<Srgb, Vec<u8>>::from_interleaved(colors);
+        assert_eq!(planar.red, [1, 4, 7]);
+        assert_eq!(planar.green, [2, 5, 8]);
+        assert_eq!(planar.blue, [3, 6, 9]);
+
+        let colors = planar.into_interleaved();
+        assert_eq!(crate::cast::into_component_slice(&colors), buffer);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter_mut_lighten() {
+        use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+        use crate::{Lighten, LinSrgb};
+
+        let colors: Vec<LinSrgb> = (0..100)
+            .map(|index| {
+                let x = index as f32 / 100.0;
+                LinSrgb::new(x, 1.0 - x, x * 0.5)
+            })
+            .collect();
+
+        let mut parallel: LinSrgb<Vec<f32>> = colors.iter().copied().collect();
+        parallel
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(index, mut color)| color.set(color.copied().lighten(index as f32 / 200.0)));
+
+        let serial: Vec<_> = colors
+            .into_iter()
+            .enumerate()
+            .map(|(index, color)| color.lighten(index as f32 / 200.0))
+            .collect();
+        let parallel: Vec<_> = parallel.into_iter().collect();
+
+        assert_eq!(parallel, serial);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn struct_of_arrays_with_u8_alpha() {
+        use crate::Alpha;
+
+        type FloatRgbByteAlpha = Alpha<Rgb<Srgb, f32>, u8>;
+
+        let colors = [
+            FloatRgbByteAlpha::new(0.1, 0.2, 0.3, 40),
+            FloatRgbByteAlpha::new(0.2, 0.3, 0.4, 50),
+            FloatRgbByteAlpha::new(0.3, 0.4, 0.5, 60),
+        ];
+
+        let mut planes: Alpha<Rgb<Srgb, Vec<f32>>, Vec<u8>> = colors.iter().copied().collect();
+        assert_eq!(planes.alpha, vec![40, 50, 60]);
+
+        let borrowed: Vec<_> = planes
+            .iter()
+            .map(|color| (*color.color.red, *color.alpha))
+            .collect();
+        assert_eq!(borrowed, vec![(0.1, 40), (0.2, 50), (0.3, 60)]);
+
+        for color in planes.iter_mut() {
+            *color.alpha /= 10;
+        }
+
+        let last = planes.pop().unwrap();
+        assert_eq!(last, FloatRgbByteAlpha::new(0.3, 0.4, 0.5, 6));
+        planes.push(last);
+        planes.extend(Some(FloatRgbByteAlpha::new(0.4, 0.5, 0.6, 70)));
+
+        let slices: Vec<_> = planes
+            .as_slices()
+            .into_iter()
+            .map(|color| *color.alpha)
+            .collect();
+        assert_eq!(slices, vec![4, 5, 6, 70]);
+
+        let colors: Vec<_> = planes.into_iter().collect();
+        assert_eq!(
+            colors,
+            vec![
+                FloatRgbByteAlpha::new(0.1, 0.2, 0.3, 4),
+                FloatRgbByteAlpha::new(0.2, 0.3, 0.4, 5),
+                FloatRgbByteAlpha::new(0.3, 0.4, 0.5, 6),
+                FloatRgbByteAlpha::new(0.4, 0.5, 0.6, 70),
+            ]
+        );
+
+        let mut array_planes = Alpha {
+            color: Rgb::<Srgb, [f32; 2]>::new([0.1, 0.2], [0.3, 0.4], [0.5, 0.6]),
+            alpha: [255u8, 128],
+        };
+        for color in &mut array_planes {
+            *color.alpha = 255 - *color.alpha;
+        }
+        let colors: Vec<_> = array_planes.into_iter().collect();
+        assert_eq!(
+            colors,
+            vec![
+                FloatRgbByteAlpha::new(0.1, 0.3, 0.5, 0),
+                FloatRgbByteAlpha::new(0.2, 0.4, 0.6, 127)
+            ]
+        );
+    }
+
     struct_of_arrays_tests!(
         Rgb<Srgb>[red, green, blue] phantom: standard,
         Rgba::new(0.1f32, 0.2, 0.3, 0.4),
@@ -0,0 +1,327 @@
+use crate::{
+    convert::{ConvertOnce, IntoColorUnclamped, Matrix3},
+    encoding::Linear,
+    matrix::rgb_to_xyz_matrix_from_primaries,
+    num::{Arithmetics, FromScalar, IsValidDivisor, One, Recip},
+    white_point::{Any, WhitePoint},
+    Xyz, Yxy,
+};
+
+use super::{Primaries, Rgb, RgbSpace};
+
+/// An RGB space with primaries and a white point that are only known at
+/// runtime.
+///
+/// The types in [`encoding`](crate::encoding) describe their primaries and
+/// white points at compile time, which is what makes it possible to convert
+/// between them with [`FromColor`](crate::FromColor). Some sources, such as ICC
+/// profiles and video container metadata, may instead have arbitrary
+/// primaries. `RuntimeSpace` can represent those and produce the conversion
+/// matrices between linear RGB and XYZ.
+///
+/// The RGB values are represented as `Rgb<Linear<Any>, T>`, since the color
+/// space isn't part of the type. Make sure they are linear before converting
+/// them.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{
+///     convert::Convert,
+///     encoding::{DisplayP3, Linear},
+///     rgb::{Rgb, RuntimeSpace},
+///     white_point::Any,
+///     FromColor, Xyz,
+/// };
+///
+/// // The Display P3 chromaticities, as they may appear in metadata.
+/// let display_p3 = RuntimeSpace::from_chromaticities(
+///     [0.680f32, 0.320],
+///     [0.265, 0.690],
+///     [0.150, 0.060],
+///     [0.3127, 0.3290],
+/// );
+///
+/// let rgb_to_xyz = display_p3.matrix_to_xyz();
+/// let xyz = rgb_to_xyz.convert(Rgb::<Linear<Any>, f32>::new(0.2, 0.5, 0.8));
+///
+/// // Close to the compile time Display P3 space, which has a more precise
+/// // white point.
+/// let expected = Xyz::from_color(Rgb::<Linear<DisplayP3>, f32>::new(0.2, 0.5, 0.8));
+/// assert_relative_eq!(xyz, expected.with_white_point(), epsilon = 0.001);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RuntimeSpace<T = f32> {
+    /// Primary red.
+    pub red: Yxy<Any, T>,
+
+    /// Primary green.
+    pub green: Yxy<Any, T>,
+
+    /// Primary blue.
+    pub blue: Yxy<Any, T>,
+
+    /// The white point.
+    pub white_point: Xyz<Any, T>,
+}
+
+impl<T> RuntimeSpace<T> {
+    /// Create an RGB space from its primaries and white point.
+    pub const fn new(
+        red: Yxy<Any, T>,
+        green: Yxy<Any, T>,
+        blue: Yxy<Any, T>,
+        white_point: Xyz<Any, T>,
+    ) -> Self {
+        RuntimeSpace {
+            red,
+            green,
+            blue,
+            white_point,
+        }
+    }
+
+    /// Create an RGB space from the `[x, y]` chromaticity coordinates of its
+    /// primaries and white point.
+    ///
+    /// The white point is given a luminance of 1.
+    pub fn from_chromaticities(
+        red: [T; 2],
+        green: [T; 2],
+        blue: [T; 2],
+        white_point: [T; 2],
+    ) -> Self
+    where
+        T: One,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    {
+        let [red_x, red_y] = red;
+        let [green_x, green_y] = green;
+        let [blue_x, blue_y] = blue;
+        let [white_x, white_y] = white_point;
+
+        RuntimeSpace {
+            red: Yxy::new(red_x, red_y, T::one()),
+            green: Yxy::new(green_x, green_y, T::one()),
+            blue: Yxy::new(blue_x, blue_y, T::one()),
+            white_point: Yxy::new(white_x, white_y, T::one()).into_color_unclamped(),
+        }
+    }
+
+    /// Create a runtime representation of the compile time RGB space `S`.
+    ///
+    /// ```
+    /// use palette::{
+    ///     encoding::Srgb,
+    ///     rgb::{Primaries, RuntimeSpace},
+    ///     white_point::{WhitePoint, D65},
+    /// };
+    ///
+    /// let srgb = RuntimeSpace::<f32>::from_space::<Srgb>();
+    ///
+    /// assert_eq!(srgb.red, Srgb::red());
+    /// assert_eq!(srgb.green, Srgb::green());
+    /// assert_eq!(srgb.blue, Srgb::blue());
+    /// assert_eq!(srgb.white_point, D65::get_xyz());
+    /// ```
+    pub fn from_space<S>() -> Self
+    where
+        S: RgbSpace,
+        S::Primaries: Primaries<T>,
+        S::WhitePoint: WhitePoint<T>,
+    {
+        RuntimeSpace {
+            red: S::Primaries::red(),
+            green: S::Primaries::green(),
+            blue: S::Primaries::blue(),
+            white_point: S::WhitePoint::get_xyz(),
+        }
+    }
+
+    /// Produce a conversion matrix from linear RGB in this space to [`Xyz`].
+    pub fn matrix_to_xyz(&self) -> Matrix3<Rgb<Linear<Any>, T>, Xyz<Any, T>>
+    where
+        T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone + FromScalar<Scalar = T>,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    {
+        Matrix3::from_array(rgb_to_xyz_matrix_from_primaries(
+            self.red.clone(),
+            self.green.clone(),
+            self.blue.clone(),
+            self.white_point.clone(),
+        ))
+    }
+
+    /// Produce a conversion matrix from [`Xyz`] to linear RGB in this space.
+    pub fn matrix_from_xyz(&self) -> Matrix3<Xyz<Any, T>, Rgb<Linear<Any>, T>>
+    where
+        T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone + FromScalar<Scalar = T>,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    {
+        self.matrix_to_xyz().invert()
+    }
+
+    /// Convert each linear RGB color in `rgb` to [`Xyz`] and write them to
+    /// `xyz`.
+    ///
+    /// The conversion matrix is only computed once, making this faster than
+    /// converting the colors one by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rgb` and `xyz` have different lengths.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{encoding::Linear, rgb::{Rgb, RuntimeSpace}, white_point::Any, Xyz};
+    ///
+    /// let space = RuntimeSpace::from_chromaticities(
+    ///     [0.64f32, 0.33],
+    ///     [0.30, 0.60],
+    ///     [0.15, 0.06],
+    ///     [0.3127, 0.3290],
+    /// );
+    ///
+    /// let rgb = [Rgb::<Linear<Any>, f32>::new(1.0, 1.0, 1.0); 4];
+    /// let mut xyz = [Xyz::new(0.0, 0.0, 0.0); 4];
+    /// space.rgb_slice_to_xyz(&rgb, &mut xyz);
+    ///
+    /// // White is converted to the white point.
+    /// for xyz in xyz {
+    ///     assert_relative_eq!(xyz, space.white_point, epsilon = 0.00001);
+    /// }
+    /// ```
+    pub fn rgb_slice_to_xyz(&self, rgb: &[Rgb<Linear<Any>, T>], xyz: &mut [Xyz<Any, T>])
+    where
+        T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone + FromScalar<Scalar = T>,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    {
+        assert_eq!(
+            rgb.len(),
+            xyz.len(),
+            "the input and output slices have different lengths"
+        );
+
+        let matrix = self.matrix_to_xyz();
+
+        for (rgb, xyz) in rgb.iter().zip(xyz) {
+            *xyz = matrix.clone().convert_once(rgb.clone());
+        }
+    }
+
+    /// Convert each color in `xyz` to linear RGB in this space and write them
+    /// to `rgb`.
+    ///
+    /// The conversion matrix is only computed once, making this faster than
+    /// converting the colors one by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xyz` and `rgb` have different lengths.
+    pub fn xyz_slice_to_rgb(&self, xyz: &[Xyz<Any, T>], rgb: &mut [Rgb<Linear<Any>, T>])
+    where
+        T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone + FromScalar<Scalar = T>,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    {
+        assert_eq!(
+            xyz.len(),
+            rgb.len(),
+            "the input and output slices have different lengths"
+        );
+
+        let matrix = self.matrix_from_xyz();
+
+        for (xyz, rgb) in xyz.iter().zip(rgb) {
+            *rgb = matrix.clone().convert_once(xyz.clone());
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+#[cfg(test)]
+mod test {
+    use super::RuntimeSpace;
+    use crate::{
+        convert::{Convert, FromColorUnclamped},
+        encoding::{self, Linear},
+        rgb::Rgb,
+        white_point::{Any, D65},
+        LinSrgb, Xyz,
+    };
+
+    #[test]
+    fn srgb_matrix() {
+        let runtime = RuntimeSpace::<f64>::from_space::<encoding::Srgb>();
+        let built_in = Xyz::<D65, f64>::matrix_from_rgb::<Linear<encoding::Srgb>>();
+
+        assert_relative_eq!(
+            runtime.matrix_to_xyz().into_array()[..],
+            built_in.into_array()[..],
+            epsilon = 0.0000001
+        );
+    }
+
+    #[test]
+    fn srgb_from_chromaticities() {
+        let runtime = RuntimeSpace::<f64>::from_chromaticities(
+            [0.64, 0.33],
+            [0.30, 0.60],
+            [0.15, 0.06],
+            [0.3127, 0.3290],
+        );
+        let from_space = RuntimeSpace::<f64>::from_space::<encoding::Srgb>();
+
+        // The D65 constant is slightly different from the one that's derived
+        // from the rounded chromaticity coordinates.
+        assert_relative_eq!(
+            runtime.matrix_to_xyz().into_array()[..],
+            from_space.matrix_to_xyz().into_array()[..],
+            epsilon = 0.0005
+        );
+    }
+
+    #[test]
+    fn srgb_slice() {
+        let runtime = RuntimeSpace::<f64>::from_space::<encoding::Srgb>();
+
+        let srgb = [
+            LinSrgb::new(0.8, 0.1, 0.3),
+            LinSrgb::new(0.2, 0.6, 0.3),
+            LinSrgb::new(0.4, 0.4, 0.9),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ];
+        let rgb =
+            srgb.map(|color| Rgb::<Linear<Any>, f64>::new(color.red, color.green, color.blue));
+
+        let mut xyz = [Xyz::new(0.0, 0.0, 0.0); 4];
+        runtime.rgb_slice_to_xyz(&rgb, &mut xyz);
+
+        for (srgb, xyz) in srgb.iter().zip(xyz.iter()) {
+            let expected = Xyz::<D65, f64>::from_color_unclamped(*srgb);
+            assert_relative_eq!(
+                *xyz,
+                Xyz::<Any, f64>::new(expected.x, expected.y, expected.z),
+                epsilon = 0.000001
+            );
+        }
+
+        let mut round_trip = [Rgb::new(0.0, 0.0, 0.0); 4];
+        runtime.xyz_slice_to_rgb(&xyz, &mut round_trip);
+
+        for (rgb, round_trip) in rgb.iter().zip(round_trip.iter()) {
+            assert_relative_eq!(*rgb, *round_trip, epsilon = 0.000001);
+        }
+
+        let single = runtime.matrix_to_xyz().convert(rgb[0]);
+        assert_relative_eq!(single, xyz[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the input and output slices have different lengths")]
+    fn slice_length_mismatch() {
+        let runtime = RuntimeSpace::<f64>::from_space::<encoding::Srgb>();
+
+        let rgb = [Rgb::<Linear<Any>, f64>::new(0.0, 0.0, 0.0); 2];
+        let mut xyz = [Xyz::new(0.0, 0.0, 0.0); 3];
+        runtime.rgb_slice_to_xyz(&rgb, &mut xyz);
+    }
+}
@@ -0,0 +1,384 @@
+//! Spectral power distributions.
+//!
+//! A [`Spectral`] value holds the power of a light source, or the reflectance
+//! of a surface, sampled at evenly spaced wavelengths. It can be integrated
+//! against the CIE 1931 2° standard observer to get its tristimulus values as
+//! [`Xyz`]:
+//!
+//! ```
+//! use palette::{convert::FromColorUnclamped, spectral::Spectral, white_point::Any, Xyz};
+//!
+//! // A spectrum that was measured every 10 nm, from 400 nm to 700 nm.
+//! let measured = Spectral::new(
+//!     400.0f64,
+//!     10.0,
+//!     [
+//!         0.11, 0.14, 0.18, 0.23, 0.29, 0.35, 0.40, 0.44, 0.46, 0.48, 0.51, 0.55, 0.60, 0.65,
+//!         0.69, 0.72, 0.74, 0.75, 0.76, 0.77, 0.78, 0.79, 0.79, 0.80, 0.80, 0.81, 0.81, 0.81,
+//!         0.82, 0.82, 0.82,
+//!     ],
+//! );
+//!
+//! let xyz = Xyz::<Any, f64>::from_color_unclamped(measured);
+//! ```
+//!
+//! The color matching functions of the CIE 1931 2° and CIE 1964 10° standard
+//! observers are available as [`CIE_1931_2`] and [`CIE_1964_10`]. They are
+//! embedded as the tables with 5 nm resolution, from 380 nm to 780 nm, that
+//! are published in CIE 15:2004.
+
+use crate::{
+    convert::FromColorUnclamped,
+    num::{Arithmetics, Exp, One, Powi, Real},
+    white_point::Any,
+    Xyz,
+};
+
+mod codegen;
+
+/// The color matching functions of the CIE 1931 2° standard observer.
+pub const CIE_1931_2: ColorMatchingFunctions = ColorMatchingFunctions {
+    start: codegen::CIE_1931_2_START,
+    step: codegen::CIE_1931_2_STEP,
+    values: &codegen::CIE_1931_2,
+};
+
+/// The color matching functions of the CIE 1964 10° standard observer.
+pub const CIE_1964_10: ColorMatchingFunctions = ColorMatchingFunctions {
+    start: codegen::CIE_1964_10_START,
+    step: codegen::CIE_1964_10_STEP,
+    values: &codegen::CIE_1964_10,
+};
+
+/// A table of the `[x̄, ȳ, z̄]` color matching functions of a standard
+/// observer.
+///
+/// The values are spaced `step` nanometers apart, starting at `start`
+/// nanometers. See [`CIE_1931_2`] and [`CIE_1964_10`] for the built-in
+/// observers.
+///
+/// ```
+/// use palette::spectral::CIE_1931_2;
+///
+/// // Integrate ȳ over the visible range.
+/// let y_integral: f64 = CIE_1931_2.iter().map(|(_, [_, y, _])| y * CIE_1931_2.step).sum();
+/// assert!((y_integral - 106.857).abs() < 0.001);
+///
+/// // Look up values between the table entries.
+/// let [x, y, z] = CIE_1931_2.interpolate(556.0);
+/// assert!(y > 0.99 && y <= 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorMatchingFunctions {
+    /// The wavelength of the first entry, in nanometers.
+    pub start: f64,
+
+    /// The distance between two entries, in nanometers.
+    pub step: f64,
+
+    /// The `[x̄, ȳ, z̄]` values at each wavelength.
+    pub values: &'static [[f64; 3]],
+}
+
+impl ColorMatchingFunctions {
+    /// Get the wavelength of the entry at `index`, in nanometers.
+    #[inline]
+    pub fn wavelength(&self, index: usize) -> f64 {
+        self.start + self.step * index as f64
+    }
+
+    /// Iterate over the entries as `(wavelength, [x̄, ȳ, z̄])` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (f64, [f64; 3])> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(index, &values)| (self.wavelength(index), values))
+    }
+
+    /// Linearly interpolate the color matching functions at `wavelength`
+    /// nanometers.
+    ///
+    /// The functions are assumed to be zero outside of the table.
+    pub fn interpolate(&self, wavelength: f64) -> [f64; 3] {
+        let position = (wavelength - self.start) / self.step;
+        let last = self.values.len().saturating_sub(1);
+
+        if self.values.is_empty() || !(position >= 0.0 && position <= last as f64) {
+            return [0.0; 3];
+        }
+
+        let index = position as usize;
+        let first = self.values[index];
+
+        match self.values.get(index + 1) {
+            Some(second) => {
+                let factor = position - index as f64;
+                [
+                    first[0] + (second[0] - first[0]) * factor,
+                    first[1] + (second[1] - first[1]) * factor,
+                    first[2] + (second[2] - first[2]) * factor,
+                ]
+            }
+            None => first,
+        }
+    }
+}
+
+/// A spectral power distribution with `N` samples.
+///
+/// The samples are spaced `step` nanometers apart, starting at `start`
+/// nanometers. The spectrum is linearly interpolated between the samples,
+/// and assumed to be zero outside of them.
+///
+/// See the [module documentation](crate::spectral) for more details.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spectral<T, const N: usize> {
+    /// The wavelength of the first sample, in nanometers.
+    pub start: T,
+
+    /// The distance between two samples, in nanometers. It should be larger
+    /// than 0.
+    pub step: T,
+
+    /// The power, or reflectance, at each wavelength.
+    pub samples: [T; N],
+}
+
+impl<T, const N: usize> Spectral<T, N> {
+    /// Create a spectral power distribution from `samples`, where the first
+    /// sample is at `start` nanometers and the rest are `step` nanometers
+    /// apart.
+    pub const fn new(start: T, step: T, samples: [T; N]) -> Self {
+        Spectral {
+            start,
+            step,
+            samples,
+        }
+    }
+}
+
+impl<T, const N: usize> Spectral<T, N>
+where
+    T: Real + Exp + Powi + One + Arithmetics + Clone,
+{
+    /// Create the spectrum of a blackbody radiator at `temperature` Kelvin,
+    /// using Planck's law.
+    ///
+    /// The spectrum is relative and normalized to `1.0` at 560 nm, like the
+    /// CIE illuminants.
+    ///
+    /// ```
+    /// use palette::{convert::FromColorUnclamped, spectral::Spectral, white_point::Any, Xyz, Yxy};
+    ///
+    /// let warm_white = Spectral::<f64, 81>::blackbody(2700.0, 380.0, 5.0);
+    /// let chromaticity = Yxy::<Any, f64>::from_color_unclamped(Xyz::from_color_unclamped(warm_white));
+    ///
+    /// assert!(chromaticity.x > chromaticity.y);
+    /// ```
+    pub fn blackbody(temperature: T, start: T, step: T) -> Self {
+        // The second radiation constant, in nanometer Kelvin.
+        let c2 = T::from_f64(1.4388e7);
+        let reference = T::from_f64(560.0);
+        let reference_power = (c2.clone() / (reference.clone() * &temperature)).exp() - T::one();
+
+        let mut index = 0;
+        let samples = [(); N].map(|_| {
+            let wavelength = start.clone() + step.clone() * T::from_f64(index as f64);
+            index += 1;
+
+            let power = (c2.clone() / (wavelength.clone() * &temperature)).exp() - T::one();
+            (reference.clone() / wavelength).powi(5) * reference_power.clone() / power
+        });
+
+        Spectral {
+            start,
+            step,
+            samples,
+        }
+    }
+}
+
+impl<T, const N: usize> Spectral<T, N>
+where
+    T: Real + Clone + Into<f64>,
+{
+    /// Integrate the spectrum against the color matching functions of
+    /// `observer`.
+    ///
+    /// The result is normalized so that a spectrum with a constant power of
+    /// `1.0` gets `Y = 1.0`. Converting with
+    /// [`FromColorUnclamped`] is the same as using [`CIE_1931_2`].
+    ///
+    /// ```
+    /// use palette::spectral::{Spectral, CIE_1964_10};
+    ///
+    /// let equal_energy = Spectral::new(380.0f64, 5.0, [1.0; 81]);
+    /// let xyz = equal_energy.into_xyz_with(&CIE_1964_10);
+    ///
+    /// assert!((xyz.y - 1.0).abs() < 1e-10);
+    /// assert!((xyz.x - 1.0).abs() < 0.02 && (xyz.z - 1.0).abs() < 0.02);
+    /// ```
+    pub fn into_xyz_with(self, observer: &ColorMatchingFunctions) -> Xyz<Any, T> {
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let mut z = 0.0;
+        let mut y_sum = 0.0;
+
+        for (wavelength, [x_bar, y_bar, z_bar]) in observer.iter() {
+            let power = self.sample(wavelength);
+
+            x += power * x_bar;
+            y += power * y_bar;
+            z += power * z_bar;
+            y_sum += y_bar;
+        }
+
+        Xyz::new(
+            T::from_f64(x / y_sum),
+            T::from_f64(y / y_sum),
+            T::from_f64(z / y_sum),
+        )
+    }
+
+    /// Linearly interpolate the spectrum at `wavelength` nanometers.
+    fn sample(&self, wavelength: f64) -> f64 {
+        let start: f64 = self.start.clone().into();
+        let step: f64 = self.step.clone().into();
+        let position = (wavelength - start) / step;
+
+        if N == 0 || !(position >= 0.0 && position <= (N - 1) as f64) {
+            return 0.0;
+        }
+
+        let index = position as usize;
+        let first: f64 = self.samples[index].clone().into();
+
+        match self.samples.get(index + 1) {
+            Some(second) => {
+                let factor = position - index as f64;
+                first + (second.clone().into() - first) * factor
+            }
+            None => first,
+        }
+    }
+}
+
+impl<T, const N: usize> FromColorUnclamped<Spectral<T, N>> for Xyz<Any, T>
+where
+    T: Real + Clone + Into<f64>,
+{
+    /// Integrate `spectral` against the CIE 1931 2° standard observer.
+    ///
+    /// The result is normalized so that a spectrum with a constant power of
+    /// `1.0` gets `Y = 1.0`.
+    fn from_color_unclamped(spectral: Spectral<T, N>) -> Self {
+        spectral.into_xyz_with(&CIE_1931_2)
+    }
+}
+
+#[cfg(feature = "approx")]
+#[cfg(test)]
+mod test {
+    use super::{Spectral, CIE_1931_2, CIE_1964_10};
+    use crate::{convert::FromColorUnclamped, white_point::Any, Xyz, Yxy};
+
+    #[test]
+    fn equal_energy() {
+        let spectral = Spectral::new(380.0f64, 5.0, [1.0; 81]);
+        let xyz = Xyz::<Any, f64>::from_color_unclamped(spectral);
+
+        assert_relative_eq!(xyz.y, 1.0);
+        assert_relative_eq!(xyz, Xyz::new(1.0, 1.0, 1.0), epsilon = 0.002);
+    }
+
+    #[test]
+    fn equal_energy_interpolated() {
+        let coarse = Spectral::new(370.0f64, 20.0, [1.0; 22]);
+        let fine = Spectral::new(380.0f64, 5.0, [1.0; 81]);
+
+        assert_relative_eq!(
+            Xyz::<Any, f64>::from_color_unclamped(coarse),
+            Xyz::from_color_unclamped(fine)
+        );
+    }
+
+    #[test]
+    fn outside_range() {
+        let spectral = Spectral::new(800.0f32, 10.0, [1.0; 10]);
+        let xyz = Xyz::<Any, f32>::from_color_unclamped(spectral);
+
+        assert_eq!(xyz, Xyz::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn blackbody_6500k() {
+        let spectral = Spectral::<f64, 81>::blackbody(6500.0, 380.0, 5.0);
+        let xyz = Xyz::<Any, f64>::from_color_unclamped(spectral);
+        let yxy = Yxy::<Any, f64>::from_color_unclamped(xyz);
+
+        // D65 is slightly green compared to the Planckian locus.
+        assert_relative_eq!(yxy.x, 0.3127, epsilon = 0.002);
+        assert_relative_eq!(yxy.y, 0.3290, epsilon = 0.006);
+
+        assert_relative_eq!(spectral.samples[36], 1.0);
+    }
+
+    #[test]
+    fn y_bar_normalization() {
+        // The published sums of the 5 nm tables, from 380 nm to 780 nm,
+        // times the step.
+        let integral_2 = CIE_1931_2.iter().map(|(_, [_, y, _])| y).sum::<f64>() * CIE_1931_2.step;
+        let integral_10 =
+            CIE_1964_10.iter().map(|(_, [_, y, _])| y).sum::<f64>() * CIE_1964_10.step;
+
+        assert_relative_eq!(integral_2, 106.857, epsilon = 0.001);
+        assert_relative_eq!(integral_10, 116.660, epsilon = 0.001);
+    }
+
+    #[test]
+    fn published_values() {
+        assert_eq!(CIE_1931_2.interpolate(555.0), [0.512050, 1.0, 0.005750]);
+        assert_eq!(
+            CIE_1931_2.interpolate(450.0),
+            [0.336200, 0.038000, 1.772110]
+        );
+        assert_eq!(
+            CIE_1931_2.interpolate(600.0),
+            [1.062200, 0.631000, 0.000800]
+        );
+
+        assert_eq!(
+            CIE_1964_10.interpolate(555.0),
+            [0.616053, 0.999110, 0.001091]
+        );
+        assert_eq!(
+            CIE_1964_10.interpolate(445.0),
+            [0.386726, 0.074704, 2.027300]
+        );
+        assert_eq!(CIE_1964_10.interpolate(595.0), [1.134300, 0.720353, 0.0]);
+    }
+
+    #[test]
+    fn interpolate() {
+        assert_eq!(CIE_1931_2.interpolate(380.0), CIE_1931_2.values[0]);
+        assert_eq!(CIE_1931_2.interpolate(780.0), CIE_1931_2.values[80]);
+        assert_eq!(CIE_1931_2.interpolate(379.0), [0.0; 3]);
+        assert_eq!(CIE_1931_2.interpolate(781.0), [0.0; 3]);
+
+        let [x1, y1, z1] = CIE_1964_10.values[20];
+        let [x2, y2, z2] = CIE_1964_10.values[21];
+        let [x, y, z] = CIE_1964_10.interpolate(481.0);
+        assert_relative_eq!(x, x1 + (x2 - x1) * 0.2);
+        assert_relative_eq!(y, y1 + (y2 - y1) * 0.2);
+        assert_relative_eq!(z, z1 + (z2 - z1) * 0.2);
+    }
+
+    #[test]
+    fn equal_energy_degree_10() {
+        let spectral = Spectral::new(380.0f64, 5.0, [1.0; 81]);
+        let xyz = spectral.into_xyz_with(&CIE_1964_10);
+
+        assert_relative_eq!(xyz.y, 1.0);
+        assert_relative_eq!(xyz, Xyz::new(1.0, 1.0, 1.0), epsilon = 0.01);
+    }
+}
@@ -0,0 +1,176 @@
+// This file is auto-generated and any manual changes to it will be overwritten.
+//
+// Run `cargo run -p codegen` from the project root to regenerate it.
+
+pub const CIE_1931_2_START: f64 = 380f64;
+pub const CIE_1931_2_STEP: f64 = 5f64;
+pub const CIE_1931_2: [[f64; 3]; 81] = [
+    [0.001368f64, 0.000039f64, 0.00645f64],
+    [0.002236f64, 0.000064f64, 0.01055f64],
+    [0.004243f64, 0.00012f64, 0.02005f64],
+    [0.00765f64, 0.000217f64, 0.03621f64],
+    [0.01431f64, 0.000396f64, 0.06785f64],
+    [0.02319f64, 0.00064f64, 0.1102f64],
+    [0.04351f64, 0.00121f64, 0.2074f64],
+    [0.07763f64, 0.00218f64, 0.3713f64],
+    [0.13438f64, 0.004f64, 0.6456f64],
+    [0.21477f64, 0.0073f64, 1.03905f64],
+    [0.2839f64, 0.0116f64, 1.3856f64],
+    [0.3285f64, 0.01684f64, 1.62296f64],
+    [0.34828f64, 0.023f64, 1.74706f64],
+    [0.34806f64, 0.0298f64, 1.7826f64],
+    [0.3362f64, 0.038f64, 1.77211f64],
+    [0.3187f64, 0.048f64, 1.7441f64],
+    [0.2908f64, 0.06f64, 1.6692f64],
+    [0.2511f64, 0.0739f64, 1.5281f64],
+    [0.19536f64, 0.09098f64, 1.28764f64],
+    [0.1421f64, 0.1126f64, 1.0419f64],
+    [0.09564f64, 0.13902f64, 0.81295f64],
+    [0.05795f64, 0.1693f64, 0.6162f64],
+    [0.03201f64, 0.20802f64, 0.46518f64],
+    [0.0147f64, 0.2586f64, 0.3533f64],
+    [0.0049f64, 0.323f64, 0.272f64],
+    [0.0024f64, 0.4073f64, 0.2123f64],
+    [0.0093f64, 0.503f64, 0.1582f64],
+    [0.0291f64, 0.6082f64, 0.1117f64],
+    [0.06327f64, 0.71f64, 0.07825f64],
+    [0.1096f64, 0.7932f64, 0.05725f64],
+    [0.1655f64, 0.862f64, 0.04216f64],
+    [0.22575f64, 0.91485f64, 0.02984f64],
+    [0.2904f64, 0.954f64, 0.0203f64],
+    [0.3597f64, 0.9803f64, 0.0134f64],
+    [0.43345f64, 0.99495f64, 0.00875f64],
+    [0.51205f64, 1f64, 0.00575f64],
+    [0.5945f64, 0.995f64, 0.0039f64],
+    [0.6784f64, 0.9786f64, 0.00275f64],
+    [0.7621f64, 0.952f64, 0.0021f64],
+    [0.8425f64, 0.9154f64, 0.0018f64],
+    [0.9163f64, 0.87f64, 0.00165f64],
+    [0.9786f64, 0.8163f64, 0.0014f64],
+    [1.0263f64, 0.757f64, 0.0011f64],
+    [1.0567f64, 0.6949f64, 0.001f64],
+    [1.0622f64, 0.631f64, 0.0008f64],
+    [1.0456f64, 0.5668f64, 0.0006f64],
+    [1.0026f64, 0.503f64, 0.00034f64],
+    [0.9384f64, 0.4412f64, 0.00024f64],
+    [0.85445f64, 0.381f64, 0.00019f64],
+    [0.7514f64, 0.321f64, 0.0001f64],
+    [0.6424f64, 0.265f64, 0.00005f64],
+    [0.5419f64, 0.217f64, 0.00003f64],
+    [0.4479f64, 0.175f64, 0.00002f64],
+    [0.3608f64, 0.1382f64, 0.00001f64],
+    [0.2835f64, 0.107f64, 0f64],
+    [0.2187f64, 0.0816f64, 0f64],
+    [0.1649f64, 0.061f64, 0f64],
+    [0.1212f64, 0.04458f64, 0f64],
+    [0.0874f64, 0.032f64, 0f64],
+    [0.0636f64, 0.0232f64, 0f64],
+    [0.04677f64, 0.017f64, 0f64],
+    [0.0329f64, 0.01192f64, 0f64],
+    [0.0227f64, 0.00821f64, 0f64],
+    [0.01584f64, 0.005723f64, 0f64],
+    [0.011359f64, 0.004102f64, 0f64],
+    [0.008111f64, 0.002929f64, 0f64],
+    [0.00579f64, 0.002091f64, 0f64],
+    [0.004109f64, 0.001484f64, 0f64],
+    [0.002899f64, 0.001047f64, 0f64],
+    [0.002049f64, 0.00074f64, 0f64],
+    [0.00144f64, 0.00052f64, 0f64],
+    [0.001f64, 0.000361f64, 0f64],
+    [0.00069f64, 0.000249f64, 0f64],
+    [0.000476f64, 0.000172f64, 0f64],
+    [0.000332f64, 0.00012f64, 0f64],
+    [0.000235f64, 0.000085f64, 0f64],
+    [0.000166f64, 0.00006f64, 0f64],
+    [0.000117f64, 0.000042f64, 0f64],
+    [0.000083f64, 0.00003f64, 0f64],
+    [0.000059f64, 0.000021f64, 0f64],
+    [0.000042f64, 0.000015f64, 0f64],
+];
+
+pub const CIE_1964_10_START: f64 = 380f64;
+pub const CIE_1964_10_STEP: f64 = 5f64;
+pub const CIE_1964_10: [[f64; 3]; 81] = [
+    [0.00016f64, 0.000017f64, 0.000705f64],
+    [0.000662f64, 0.000072f64, 0.002928f64],
+    [0.002362f64, 0.000253f64, 0.010482f64],
+    [0.007242f64, 0.000769f64, 0.032344f64],
+    [0.01911f64, 0.002004f64, 0.086011f64],
+    [0.0434f64, 0.004509f64, 0.19712f64],
+    [0.084736f64, 0.008756f64, 0.389366f64],
+    [0.140638f64, 0.014456f64, 0.65676f64],
+    [0.204492f64, 0.021391f64, 0.972542f64],
+    [0.264737f64, 0.029497f64, 1.2825f64],
+    [0.314679f64, 0.038676f64, 1.55348f64],
+    [0.357719f64, 0.049602f64, 1.7985f64],
+    [0.383734f64, 0.062077f64, 1.96728f64],
+    [0.386726f64, 0.074704f64, 2.0273f64],
+    [0.370702f64, 0.089456f64, 1.9948f64],
+    [0.342957f64, 0.106256f64, 1.9007f64],
+    [0.302273f64, 0.128201f64, 1.74537f64],
+    [0.254085f64, 0.152761f64, 1.5549f64],
+    [0.195618f64, 0.18519f64, 1.31756f64],
+    [0.132349f64, 0.21994f64, 1.0302f64],
+    [0.080507f64, 0.253589f64, 0.772125f64],
+    [0.041072f64, 0.297665f64, 0.57006f64],
+    [0.016172f64, 0.339133f64, 0.415254f64],
+    [0.005132f64, 0.395379f64, 0.302356f64],
+    [0.003816f64, 0.460777f64, 0.218502f64],
+    [0.015444f64, 0.53136f64, 0.159249f64],
+    [0.037465f64, 0.606741f64, 0.112044f64],
+    [0.071358f64, 0.68566f64, 0.082248f64],
+    [0.117749f64, 0.761757f64, 0.060709f64],
+    [0.172953f64, 0.82333f64, 0.04305f64],
+    [0.236491f64, 0.875211f64, 0.030451f64],
+    [0.304213f64, 0.92381f64, 0.020584f64],
+    [0.376772f64, 0.961988f64, 0.013676f64],
+    [0.451584f64, 0.9822f64, 0.007918f64],
+    [0.529826f64, 0.991761f64, 0.003988f64],
+    [0.616053f64, 0.99911f64, 0.001091f64],
+    [0.705224f64, 0.99734f64, 0f64],
+    [0.793832f64, 0.98238f64, 0f64],
+    [0.878655f64, 0.955552f64, 0f64],
+    [0.951162f64, 0.915175f64, 0f64],
+    [1.01416f64, 0.868934f64, 0f64],
+    [1.0743f64, 0.825623f64, 0f64],
+    [1.11852f64, 0.777405f64, 0f64],
+    [1.1343f64, 0.720353f64, 0f64],
+    [1.12399f64, 0.658341f64, 0f64],
+    [1.0891f64, 0.593878f64, 0f64],
+    [1.03048f64, 0.527963f64, 0f64],
+    [0.95074f64, 0.461834f64, 0f64],
+    [0.856297f64, 0.398057f64, 0f64],
+    [0.75493f64, 0.339554f64, 0f64],
+    [0.647467f64, 0.283493f64, 0f64],
+    [0.53511f64, 0.228254f64, 0f64],
+    [0.431567f64, 0.179828f64, 0f64],
+    [0.34369f64, 0.140211f64, 0f64],
+    [0.268329f64, 0.107633f64, 0f64],
+    [0.2043f64, 0.081187f64, 0f64],
+    [0.152568f64, 0.060281f64, 0f64],
+    [0.11221f64, 0.044096f64, 0f64],
+    [0.081261f64, 0.0318f64, 0f64],
+    [0.05793f64, 0.022602f64, 0f64],
+    [0.040851f64, 0.015905f64, 0f64],
+    [0.028623f64, 0.01113f64, 0f64],
+    [0.019941f64, 0.007749f64, 0f64],
+    [0.013842f64, 0.005375f64, 0f64],
+    [0.009577f64, 0.003718f64, 0f64],
+    [0.006605f64, 0.002565f64, 0f64],
+    [0.004553f64, 0.001768f64, 0f64],
+    [0.003145f64, 0.001222f64, 0f64],
+    [0.002175f64, 0.000846f64, 0f64],
+    [0.001506f64, 0.000586f64, 0f64],
+    [0.001045f64, 0.000407f64, 0f64],
+    [0.000727f64, 0.000284f64, 0f64],
+    [0.000508f64, 0.000199f64, 0f64],
+    [0.000356f64, 0.00014f64, 0f64],
+    [0.000251f64, 0.000098f64, 0f64],
+    [0.000178f64, 0.00007f64, 0f64],
+    [0.000126f64, 0.00005f64, 0f64],
+    [0.00009f64, 0.000036f64, 0f64],
+    [0.000065f64, 0.000025f64, 0f64],
+    [0.000046f64, 0.000018f64, 0f64],
+    [0.000033f64, 0.000013f64, 0f64],
+];
+
@@ -0,0 +1,532 @@
+//! Statistics over collections of colors.
+//!
+//! [`ColorStats`] accumulates colors one by one and keeps track of their
+//! average in linear RGB and in [`Oklab`], their variance in [`Oklab`], and
+//! the distribution of their hues. It can be filled from any iterator of colors
+//! that can be converted to linear sRGB, including the colors in a struct of
+//! arrays buffer:
+//!
+//! ```
+//! use palette::{stats::ColorStats, Srgb};
+//!
+//! let buffer = Srgb::new(vec![1.0f32, 0.8, 0.9], vec![0.1, 0.2, 0.1], vec![0.0, 0.1, 0.1]);
+//!
+//! let mut stats = ColorStats::<f32>::new();
+//! stats.extend(buffer.iter().map(|color| color.copied()));
+//!
+//! assert_eq!(stats.count(), 3);
+//! ```
+//!
+//! The statistics from separate chunks of colors can be combined with
+//! [`ColorStats::merge`], for example to accumulate them in parallel. The
+//! result is the same as if all colors were accumulated by one `ColorStats`,
+//! up to rounding errors.
+
+use core::iter::FromIterator;
+
+use crate::{
+    convert::FromColorUnclamped,
+    num::{Arithmetics, IsValidDivisor, PartialCmp, Real, Sqrt, Zero},
+    GetHue, IntoColor, IsAchromatic, LinSrgb, Oklab, OklabHue,
+};
+
+/// The largest [`Oklab`] chroma that is considered gray.
+const ACHROMATIC_TOLERANCE: f64 = 0.00001;
+
+/// Accumulated statistics for a collection of colors.
+///
+/// Each color is converted to linear sRGB and to [`Oklab`] when it's
+/// accumulated. The hue statistics are calculated from the [`Oklab`] hues,
+/// and colors without a meaningful hue, such as gray colors, don't count
+/// towards them. The hues are treated as angles, so 350° and 10° average to
+/// 0° rather than 180°.
+///
+/// ```
+/// use palette::{stats::ColorStats, LinSrgb};
+///
+/// let colors = [LinSrgb::new(1.0f32, 0.0, 0.0), LinSrgb::new(0.0, 0.0, 1.0)];
+/// let stats: ColorStats = colors.iter().copied().collect();
+///
+/// assert_eq!(stats.linear_mean(), Some(LinSrgb::new(0.5, 0.0, 0.5)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorStats<T = f32> {
+    count: usize,
+    linear_sum: [T; 3],
+    oklab_mean: [T; 3],
+    oklab_square_deviations: [T; 3],
+    hue_count: usize,
+    hue_sum: [T; 2],
+}
+
+impl<T> ColorStats<T>
+where
+    T: Zero,
+{
+    /// Create an empty set of statistics.
+    pub fn new() -> Self {
+        ColorStats {
+            count: 0,
+            linear_sum: [T::zero(), T::zero(), T::zero()],
+            oklab_mean: [T::zero(), T::zero(), T::zero()],
+            oklab_square_deviations: [T::zero(), T::zero(), T::zero()],
+            hue_count: 0,
+            hue_sum: [T::zero(), T::zero()],
+        }
+    }
+}
+
+impl<T> ColorStats<T> {
+    /// The number of accumulated colors.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The number of accumulated colors with a meaningful hue.
+    pub fn hue_count(&self) -> usize {
+        self.hue_count
+    }
+}
+
+impl<T> ColorStats<T>
+where
+    T: Real + Zero + Sqrt + PartialCmp<Mask = bool> + Arithmetics + Clone,
+    Oklab<T>: FromColorUnclamped<LinSrgb<T>>,
+{
+    /// Add `color` to the statistics.
+    ///
+    /// Colors with an [`Oklab`] chroma of at most `0.00001` are considered
+    /// gray, and don't count towards the hue statistics. This is the same
+    /// limit as in [`TryGetHue`](crate::TryGetHue) for `Oklab`, and it covers
+    /// the small rounding errors that gray colors get from the conversion.
+    pub fn accumulate<C>(&mut self, color: C)
+    where
+        C: IntoColor<LinSrgb<T>>,
+    {
+        let linear: LinSrgb<T> = color.into_color();
+        let oklab = Oklab::from_color_unclamped(linear.clone());
+
+        self.count += 1;
+        add_assign(
+            &mut self.linear_sum,
+            [linear.red, linear.green, linear.blue],
+        );
+
+        if !oklab.is_achromatic_within(T::from_f64(ACHROMATIC_TOLERANCE)) {
+            let chroma = (oklab.a.clone() * &oklab.a + oklab.b.clone() * &oklab.b).sqrt();
+            self.hue_count += 1;
+            add_assign(
+                &mut self.hue_sum,
+                [oklab.a.clone() / &chroma, oklab.b.clone() / chroma],
+            );
+        }
+
+        // Welford's algorithm, which avoids the cancellation in the sum of
+        // squares minus the squared sum.
+        let count = T::from_f64(self.count as f64);
+        let values = [oklab.l, oklab.a, oklab.b];
+        for ((mean, square_deviations), value) in self
+            .oklab_mean
+            .iter_mut()
+            .zip(&mut self.oklab_square_deviations)
+            .zip(values)
+        {
+            let delta = value.clone() - &*mean;
+            *mean = mean.clone() + delta.clone() / &count;
+            *square_deviations = square_deviations.clone() + delta * (value - &*mean);
+        }
+    }
+}
+
+impl<T> ColorStats<T>
+where
+    T: Real + Arithmetics + Clone,
+{
+    /// Combine the statistics in `self` and `other`.
+    ///
+    /// This is the same as accumulating the colors from both of them into a
+    /// single `ColorStats`, which makes it useful for combining the results
+    /// of parallel computations:
+    ///
+    /// ```
+    /// use palette::{stats::ColorStats, Srgb};
+    ///
+    /// let colors = [
+    ///     Srgb::new(0.9f32, 0.2, 0.1),
+    ///     Srgb::new(0.1, 0.5, 0.6),
+    ///     Srgb::new(0.4, 0.4, 0.4),
+    ///     Srgb::new(0.2, 0.7, 0.1),
+    /// ];
+    ///
+    /// let stats = colors
+    ///     .chunks(2)
+    ///     .map(|chunk| chunk.iter().copied().collect::<ColorStats>())
+    ///     .fold(ColorStats::new(), ColorStats::merge);
+    ///
+    /// assert_eq!(stats.count(), 4);
+    /// ```
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        let count = self.count + other.count;
+        if count == 0 {
+            return self;
+        }
+
+        // Chan's method for combining the means and the squared deviations.
+        let self_count = T::from_f64(self.count as f64);
+        let other_count = T::from_f64(other.count as f64);
+        let total_count = T::from_f64(count as f64);
+
+        let mut oklab_mean = self.oklab_mean.clone();
+        let mut oklab_square_deviations =
+            add(self.oklab_square_deviations, other.oklab_square_deviations);
+
+        for ((mean, square_deviations), (self_mean, other_mean)) in oklab_mean
+            .iter_mut()
+            .zip(&mut oklab_square_deviations)
+            .zip(self.oklab_mean.iter().zip(other.oklab_mean))
+        {
+            let delta = other_mean - self_mean;
+            *mean = self_mean.clone() + delta.clone() * &other_count / &total_count;
+            *square_deviations = square_deviations.clone()
+                + delta.clone() * delta * &self_count * &other_count / &total_count;
+        }
+
+        ColorStats {
+            count,
+            linear_sum: add(self.linear_sum, other.linear_sum),
+            oklab_mean,
+            oklab_square_deviations,
+            hue_count: self.hue_count + other.hue_count,
+            hue_sum: add(self.hue_sum, other.hue_sum),
+        }
+    }
+}
+
+impl<T> ColorStats<T>
+where
+    T: Real + Arithmetics + Clone,
+{
+    /// The average of the accumulated colors in linear sRGB, or `None` if no
+    /// colors have been accumulated.
+    pub fn linear_mean(&self) -> Option<LinSrgb<T>> {
+        let [red, green, blue] = self.mean(&self.linear_sum)?;
+        Some(LinSrgb::new(red, green, blue))
+    }
+
+    /// The average of the accumulated colors in [`Oklab`], or `None` if no
+    /// colors have been accumulated.
+    pub fn oklab_mean(&self) -> Option<Oklab<T>> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let [l, a, b] = self.oklab_mean.clone();
+        Some(Oklab::new(l, a, b))
+    }
+
+    /// The variance of each of the [`Oklab`] components, as `[l, a, b]`, or
+    /// `None` if no colors have been accumulated.
+    ///
+    /// This is the population variance, meaning that the sum of the squared
+    /// differences from the mean is divided by the number of colors.
+    pub fn oklab_variance(&self) -> Option<[T; 3]> {
+        self.mean(&self.oklab_square_deviations)
+    }
+
+    fn mean<const N: usize>(&self, sum: &[T; N]) -> Option<[T; N]> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let count = T::from_f64(self.count as f64);
+        Some(sum.clone().map(|sum| sum / count.clone()))
+    }
+}
+
+impl<T> ColorStats<T>
+where
+    T: Real + Sqrt + IsValidDivisor<Mask = bool> + Arithmetics + Clone,
+    Oklab<T>: GetHue<Hue = OklabHue<T>>,
+{
+    /// The circular mean of the [`Oklab`] hues, or `None` if there are no
+    /// colors with a meaningful hue, or if their hues cancel each other out.
+    ///
+    /// ```
+    /// use palette::{stats::ColorStats, Oklch};
+    ///
+    /// let colors = [Oklch::new(0.6f32, 0.1, 350.0), Oklch::new(0.6, 0.1, 10.0)];
+    /// let stats: ColorStats = colors.iter().copied().collect();
+    ///
+    /// let hue = stats.hue_mean().unwrap();
+    /// assert!(hue.into_degrees().abs() < 0.01);
+    /// ```
+    pub fn hue_mean(&self) -> Option<OklabHue<T>> {
+        let [a, b] = self.hue_sum.clone();
+        let length = (a.clone() * &a + b.clone() * &b).sqrt();
+
+        if self.hue_count == 0 || !length.is_valid_divisor() {
+            return None;
+        }
+
+        Some(Oklab::new(T::from_f64(0.0), a, b).get_hue())
+    }
+
+    /// The mean resultant length of the [`Oklab`] hues, or `None` if there
+    /// are no colors with a meaningful hue.
+    ///
+    /// It's a value from `0.0` to `1.0`, where `1.0` means that all hues are
+    /// the same, and values closer to `0.0` mean that they are more spread
+    /// out around the hue circle.
+    pub fn hue_mean_resultant_length(&self) -> Option<T> {
+        if self.hue_count == 0 {
+            return None;
+        }
+
+        let [a, b] = self.hue_sum.clone();
+        let length = (a.clone() * &a + b.clone() * b).sqrt();
+
+        Some(length / T::from_f64(self.hue_count as f64))
+    }
+}
+
+impl<T> Default for ColorStats<T>
+where
+    T: Zero,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C> Extend<C> for ColorStats<T>
+where
+    T: Real + Zero + Sqrt + PartialCmp<Mask = bool> + Arithmetics + Clone,
+    Oklab<T>: FromColorUnclamped<LinSrgb<T>>,
+    C: IntoColor<LinSrgb<T>>,
+{
+    fn extend<I: IntoIterator<Item = C>>(&mut self, iter: I) {
+        for color in iter {
+            self.accumulate(color);
+        }
+    }
+}
+
+impl<T, C> FromIterator<C> for ColorStats<T>
+where
+    T: Real + Zero + Sqrt + PartialCmp<Mask = bool> + Arithmetics + Clone,
+    Oklab<T>: FromColorUnclamped<LinSrgb<T>>,
+    C: IntoColor<LinSrgb<T>>,
+{
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+fn add<T: Arithmetics, const N: usize>(a: [T; N], b: [T; N]) -> [T; N] {
+    let mut b = IntoIterator::into_iter(b);
+    a.map(|a| a + b.next().expect("the arrays should have the same length"))
+}
+
+fn add_assign<T: Arithmetics + Clone, const N: usize>(sum: &mut [T; N], value: [T; N]) {
+    *sum = add(sum.clone(), value);
+}
+
+#[cfg(feature = "approx")]
+#[cfg(test)]
+mod test {
+    use super::ColorStats;
+    use crate::{convert::FromColorUnclamped, LinSrgb, Oklab, OklabHue, Oklch, Srgb};
+
+    #[test]
+    fn empty() {
+        let stats = ColorStats::<f32>::new();
+
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.linear_mean(), None);
+        assert_eq!(stats.oklab_mean(), None);
+        assert_eq!(stats.oklab_variance(), None);
+        assert_eq!(stats.hue_mean(), None);
+        assert_eq!(stats.hue_mean_resultant_length(), None);
+    }
+
+    #[test]
+    fn means() {
+        let colors = [
+            LinSrgb::new(0.8f64, 0.1, 0.2),
+            LinSrgb::new(0.2, 0.5, 0.4),
+            LinSrgb::new(0.5, 0.3, 0.9),
+        ];
+        let stats: ColorStats<f64> = colors.iter().copied().collect();
+
+        assert_eq!(stats.count(), 3);
+        assert_relative_eq!(stats.linear_mean().unwrap(), LinSrgb::new(0.5, 0.3, 0.5));
+
+        let oklab = colors.map(Oklab::from_color_unclamped);
+        let oklab_mean = Oklab::new(
+            (oklab[0].l + oklab[1].l + oklab[2].l) / 3.0,
+            (oklab[0].a + oklab[1].a + oklab[2].a) / 3.0,
+            (oklab[0].b + oklab[1].b + oklab[2].b) / 3.0,
+        );
+        assert_relative_eq!(stats.oklab_mean().unwrap(), oklab_mean, epsilon = 1e-12);
+
+        let variance_l = oklab
+            .iter()
+            .map(|color| (color.l - oklab_mean.l).powi(2))
+            .sum::<f64>()
+            / 3.0;
+        assert_relative_eq!(
+            stats.oklab_variance().unwrap()[0],
+            variance_l,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn circular_hue_mean() {
+        let colors = [
+            Oklch::new(0.6, 0.1, 340.0),
+            Oklch::new(0.7, 0.05, 0.0),
+            Oklch::new(0.5, 0.12, 20.0),
+        ];
+        let stats: ColorStats<f64> = colors.iter().copied().collect();
+
+        assert_eq!(stats.hue_count(), 3);
+        assert_relative_eq!(
+            stats.hue_mean().unwrap(),
+            OklabHue::new(0.0),
+            epsilon = 0.01
+        );
+
+        let expected_length = (1.0 + 2.0 * 20.0f64.to_radians().cos()) / 3.0;
+        assert_relative_eq!(
+            stats.hue_mean_resultant_length().unwrap(),
+            expected_length,
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn opposite_hues() {
+        let colors = [Oklch::new(0.6, 0.1, 90.0), Oklch::new(0.6, 0.1, 270.0)];
+        let stats: ColorStats<f64> = colors.iter().copied().collect();
+
+        assert_relative_eq!(
+            stats.hue_mean_resultant_length().unwrap(),
+            0.0,
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn gray_has_no_hue() {
+        let colors = [LinSrgb::new(0.5f32, 0.5, 0.5), LinSrgb::new(0.0, 0.0, 0.0)];
+        let stats: ColorStats = colors.iter().copied().collect();
+
+        assert_eq!(stats.count(), 2);
+        assert_eq!(stats.hue_count(), 0);
+        assert_eq!(stats.hue_mean(), None);
+    }
+
+    #[test]
+    fn converted_grays_have_no_hue() {
+        let colors = [
+            Srgb::new(0.2f32, 0.2, 0.2),
+            Srgb::new(0.5, 0.5, 0.5),
+            Srgb::new(0.8, 0.8, 0.8),
+            Srgb::new(1.0, 1.0, 1.0),
+        ];
+        let stats: ColorStats = colors.iter().copied().collect();
+
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.hue_count(), 0);
+        assert_eq!(stats.hue_mean(), None);
+        assert_eq!(stats.hue_mean_resultant_length(), None);
+
+        let stats: ColorStats<f64> = colors
+            .iter()
+            .map(|color| color.into_format::<f64>())
+            .collect();
+        assert_eq!(stats.hue_count(), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn small_variance_f32() {
+        let colors: Vec<_> = (0..100)
+            .map(|i| {
+                let value = 0.9 + (i % 5) as f32 * 0.0001;
+                LinSrgb::new(value, value, value)
+            })
+            .collect();
+        let stats: ColorStats = colors.iter().copied().collect();
+
+        let lightness: Vec<f64> = colors
+            .iter()
+            .map(|&color| Oklab::from_color_unclamped(color).l.into())
+            .collect();
+        let mean = lightness.iter().sum::<f64>() / 100.0;
+        let variance = lightness.iter().map(|l| (l - mean).powi(2)).sum::<f64>() / 100.0;
+
+        assert_relative_eq!(
+            f64::from(stats.oklab_variance().unwrap()[0]),
+            variance,
+            max_relative = 0.01
+        );
+    }
+
+    #[test]
+    fn merge_equals_single_pass() {
+        let colors = [
+            Srgb::new(0.9f64, 0.2, 0.1),
+            Srgb::new(0.1, 0.5, 0.6),
+            Srgb::new(0.4, 0.4, 0.4),
+            Srgb::new(0.2, 0.7, 0.1),
+            Srgb::new(0.8, 0.8, 0.3),
+        ];
+
+        let single: ColorStats<f64> = colors.iter().copied().collect();
+        let merged = colors
+            .chunks(2)
+            .map(|chunk| chunk.iter().copied().collect::<ColorStats<f64>>())
+            .fold(ColorStats::new(), ColorStats::merge);
+
+        assert_eq!(merged.count(), single.count());
+        assert_eq!(merged.hue_count(), single.hue_count());
+        assert_relative_eq!(
+            merged.linear_mean().unwrap(),
+            single.linear_mean().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            merged.oklab_mean().unwrap(),
+            single.oklab_mean().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            merged.oklab_variance().unwrap()[..],
+            single.oklab_variance().unwrap()[..],
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            merged.hue_mean().unwrap(),
+            single.hue_mean().unwrap(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn struct_of_arrays() {
+        let buffer = Srgb::new(vec![1.0f32, 0.0], vec![0.0, 0.0], vec![0.0, 1.0]);
+
+        let mut stats = ColorStats::<f32>::new();
+        stats.extend(buffer.iter().map(|color| color.copied()));
+
+        let colors = [Srgb::new(1.0f32, 0.0, 0.0), Srgb::new(0.0, 0.0, 1.0)];
+        let expected: ColorStats = colors.iter().copied().collect();
+        assert_eq!(stats, expected);
+    }
+}
@@ -26,6 +26,33 @@ use crate::{
 ///    `0u16` to `65535u16`.
 ///  * Real values (`f32`, `f64`, fixed point types, etc.) have a range from
 ///    `0.0` to `1.0`.
+///  * Signed integer values (`i8` and `i16`) are normalized like the SNORM
+///    formats of graphics APIs, where `0` to the largest representable value is
+///    `0.0` to `1.0`. The negative range is only used when converting to and
+///    from other component types.
+///
+/// ## Signed Integers
+///
+/// The signed integer implementations are meant for reading and writing
+/// texture data, such as SNORM formats, and not for displaying colors. The
+/// negative half has one more value than the positive half, so the smallest
+/// value (for example `-128i8`) is treated as `-1.0`, the same as the value
+/// after it (`-127i8`). Converting to a signed integer clamps the value to
+/// `[-1.0, 1.0]`, so `-1.0` always becomes `-127i8`:
+///
+/// ```
+/// use palette::{stimulus::IntoStimulus, LinSrgb};
+///
+/// let snorm = LinSrgb::new(-128i8, 0, 127);
+/// let float: LinSrgb<f32> = snorm.into_format();
+/// assert_eq!(float, LinSrgb::new(-1.0, 0.0, 1.0));
+///
+/// let back: LinSrgb<i8> = float.into_format();
+/// assert_eq!(back, LinSrgb::new(-127, 0, 127));
+///
+/// let clamped: i16 = 1.5f32.into_stimulus();
+/// assert_eq!(clamped, i16::MAX);
+/// ```
 pub trait Stimulus: Zero {
     /// The highest displayable value this component type can reach. Integers
     /// types are expected to return their maximum value, while real numbers
@@ -58,7 +85,7 @@ macro_rules! impl_uint_components {
     };
 }
 
-impl_uint_components!(u8, u16, u32, u64, u128);
+impl_uint_components!(u8, u16, u32, u64, u128, i8, i16);
 
 /// A marker trait for colors where all components are stimuli.
 ///
@@ -291,9 +318,119 @@ convert_uint_to_larger_uint!(u64; next u128 ());
 convert_uint_to_float!(u128; via f64 (f32, f64););
 convert_uint_to_uint!(u128; via f64 (u8, u16, u32, u64););
 
+// `f16` is converted via `f32`, which can represent all of its values.
+#[cfg(feature = "half")]
+macro_rules! convert_half {
+    ($($other: ident),+) => {
+        $(
+            impl IntoStimulus<$other> for half::f16 {
+                #[inline]
+                fn into_stimulus(self) -> $other {
+                    self.to_f32().into_stimulus()
+                }
+            }
+
+            impl IntoStimulus<half::f16> for $other {
+                #[inline]
+                fn into_stimulus(self) -> half::f16 {
+                    half::f16::from_f32(self.into_stimulus())
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "half")]
+impl IntoStimulus<f32> for half::f16 {
+    #[inline]
+    fn into_stimulus(self) -> f32 {
+        self.to_f32()
+    }
+}
+
+#[cfg(feature = "half")]
+impl IntoStimulus<half::f16> for f32 {
+    #[inline]
+    fn into_stimulus(self) -> half::f16 {
+        half::f16::from_f32(self)
+    }
+}
+
+#[cfg(feature = "half")]
+impl IntoStimulus<f64> for half::f16 {
+    #[inline]
+    fn into_stimulus(self) -> f64 {
+        self.to_f64()
+    }
+}
+
+#[cfg(feature = "half")]
+impl IntoStimulus<half::f16> for f64 {
+    #[inline]
+    fn into_stimulus(self) -> half::f16 {
+        half::f16::from_f64(self)
+    }
+}
+
+#[cfg(feature = "half")]
+convert_half!(u8, u16, u32, u64, u128);
+
+// SNORM conversions. The smallest signed value is clamped to `-1.0`, and
+// floats are clamped to `[-1.0, 1.0]` before they are scaled and rounded.
+macro_rules! convert_snorm {
+    ($($snorm: ident),+; $float: ident) => {
+        $(
+            impl IntoStimulus<$float> for $snorm {
+                #[inline]
+                fn into_stimulus(self) -> $float {
+                    let max = $snorm::MAX as $float;
+                    $float::max(self as $float / max, -1.0)
+                }
+            }
+
+            impl IntoStimulus<$snorm> for $float {
+                #[inline]
+                fn into_stimulus(self) -> $snorm {
+                    let max = $snorm::MAX as $float;
+                    Round::round(clamp(self, -1.0, 1.0) * max) as $snorm
+                }
+            }
+        )+
+    };
+}
+
+convert_snorm!(i8, i16; f32);
+convert_snorm!(i8, i16; f64);
+
+impl IntoStimulus<i16> for i8 {
+    #[inline]
+    fn into_stimulus(self) -> i16 {
+        f32::from_stimulus(self).into_stimulus()
+    }
+}
+
+impl IntoStimulus<i8> for i16 {
+    #[inline]
+    fn into_stimulus(self) -> i8 {
+        f32::from_stimulus(self).into_stimulus()
+    }
+}
+
+/// Mix two `u8` components by `factor / 255`, rounded to the nearest integer.
+///
+/// This is the rounding that is documented for `Rgb::mix_u8`. The intermediate
+/// value is at most `255 * 255 + 127`, which fits in `u16`.
+#[inline]
+pub(crate) fn mix_u8(a: u8, b: u8, factor: u8) -> u8 {
+    let a = u16::from(a) * u16::from(255 - factor);
+    let b = u16::from(b) * u16::from(factor);
+
+    ((a + b + 127) / 255) as u8
+}
+
 #[cfg(test)]
 mod test {
-    use crate::stimulus::IntoStimulus;
+    use crate::stimulus::{FromStimulus, IntoStimulus};
 
     #[test]
     fn float_to_uint() {
@@ -373,6 +510,49 @@ mod test {
         }
     }
 
+    #[test]
+    fn snorm_round_trip() {
+        for n in i8::MIN..=i8::MAX {
+            let float: f32 = n.into_stimulus();
+            let double: f64 = n.into_stimulus();
+            let wide: i16 = n.into_stimulus();
+
+            // -128 and -127 are both -1.0, and -1.0 becomes -127.
+            let expected = n.max(-i8::MAX);
+            assert_eq!(i8::from_stimulus(float), expected);
+            assert_eq!(i8::from_stimulus(double), expected);
+            assert_eq!(i8::from_stimulus(wide), expected);
+        }
+
+        for n in i16::MIN..=i16::MAX {
+            let float: f32 = n.into_stimulus();
+            assert_eq!(i16::from_stimulus(float), n.max(-i16::MAX));
+        }
+    }
+
+    #[test]
+    fn snorm_range() {
+        assert_eq!(f32::from_stimulus(i8::MIN), -1.0);
+        assert_eq!(f32::from_stimulus(-i8::MAX), -1.0);
+        assert_eq!(f32::from_stimulus(0i8), 0.0);
+        assert_eq!(f32::from_stimulus(i8::MAX), 1.0);
+        assert_eq!(f64::from_stimulus(i16::MIN), -1.0);
+        assert_eq!(f64::from_stimulus(i16::MAX), 1.0);
+
+        assert_eq!(i8::from_stimulus(2.0f32), i8::MAX);
+        assert_eq!(i8::from_stimulus(-2.0f32), -i8::MAX);
+        assert_eq!(i8::from_stimulus(f32::INFINITY), i8::MAX);
+        assert_eq!(i8::from_stimulus(f32::NEG_INFINITY), -i8::MAX);
+        assert_eq!(i16::from_stimulus(0.5f64), 16384);
+        assert_eq!(i16::from_stimulus(-0.5f64), -16384);
+
+        assert_eq!(i16::from_stimulus(i8::MAX), i16::MAX);
+        assert_eq!(i16::from_stimulus(i8::MIN), -i16::MAX);
+        assert_eq!(i8::from_stimulus(i16::MAX), i8::MAX);
+        assert_eq!(i8::from_stimulus(i16::MIN), -i8::MAX);
+        assert_eq!(i8::from_stimulus(1i16), 0);
+    }
+
     #[cfg(feature = "approx")]
     #[test]
     fn uint_to_float() {
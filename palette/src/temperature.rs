@@ -0,0 +1,245 @@
+//! Correlated color temperature (CCT) and distance from the Planckian locus
+//! (Duv).
+//!
+//! The correlated color temperature of a light source is the temperature of
+//! the blackbody radiator with the most similar chromaticity, and Duv is the
+//! signed distance from that blackbody radiator's chromaticity in the CIE 1960
+//! uv diagram. A positive Duv is above the Planckian locus, towards green, and a
+//! negative Duv is below it, towards magenta.
+//!
+//! ```
+//! use palette::{temperature::cct_duv, white_point::{WhitePoint, D65}, Xyz};
+//!
+//! let white: Xyz<D65, f64> = D65::get_xyz().with_white_point();
+//! let cct_duv = cct_duv(white).unwrap();
+//!
+//! assert!((cct_duv.cct - 6504.0).abs() < 10.0);
+//! assert!((cct_duv.duv - 0.0032).abs() < 0.0003);
+//! ```
+//!
+//! The calculations use Ohno's combined triangular and parabolic method from
+//! ["Practical Use and Calculation of CCT and
+//! Duv"](https://doi.org/10.1080/15502724.2014.839020) (2014), with an
+//! embedded table of the Planckian locus in 1% steps from 1000 K to 15000 K.
+//! The locus is approximated with the functions by Krystek (1985), which are
+//! within about 0.0001 of the exact coordinates.
+
+use crate::{
+    num::{Arithmetics, Real, Sqrt, Zero},
+    xyz::UvChromaticity,
+};
+
+use self::codegen::PLANCKIAN_LOCUS;
+
+mod codegen;
+
+/// A correlated color temperature (CCT) and the distance from the Planckian
+/// locus (Duv).
+///
+/// See the [module documentation](crate::temperature) for more details.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CctDuv<T = f32> {
+    /// The correlated color temperature, in Kelvin.
+    pub cct: T,
+
+    /// The signed distance from the Planckian locus, in the CIE 1960 uv
+    /// diagram. Positive values are above the locus, and negative values are
+    /// below it.
+    pub duv: T,
+}
+
+/// Calculate the correlated color temperature (CCT) and the distance from the
+/// Planckian locus (Duv) of a color.
+///
+/// The input can be anything that can be converted into
+/// [`UvChromaticity`], such as [`Xyz`](crate::Xyz) and
+/// [`Yxy`](crate::Yxy). Returns `None` if the closest point on the Planckian
+/// locus is outside the range of the embedded table, 1000 K to 15000 K, or if
+/// any component is NaN.
+///
+/// ```
+/// use palette::{temperature::cct_duv, white_point::Any, Yxy};
+///
+/// // The center of the ANSI C78.377 4000 K bin.
+/// let color = Yxy::<Any, f64>::new(0.3818, 0.3797, 1.0);
+/// let cct_duv = cct_duv(color).unwrap();
+///
+/// assert!((cct_duv.cct - 3985.0).abs() < 10.0);
+/// assert!((cct_duv.duv - 0.001).abs() < 0.0003);
+/// ```
+pub fn cct_duv<T>(chromaticity: impl Into<UvChromaticity<T>>) -> Option<CctDuv<T>>
+where
+    T: Real + Zero + Sqrt + Arithmetics + PartialOrd + Clone,
+{
+    let UvChromaticity { u, v } = chromaticity.into();
+
+    // The CIE 1960 v coordinate is 2/3 of the CIE 1976 v' coordinate.
+    let v = v * T::from_f64(2.0 / 3.0);
+
+    let distance = |&[_, locus_u, locus_v]: &[f64; 3]| {
+        let delta_u = u.clone() - T::from_f64(locus_u);
+        let delta_v = v.clone() - T::from_f64(locus_v);
+
+        (delta_u.clone() * delta_u + delta_v.clone() * delta_v).sqrt()
+    };
+
+    let mut closest_index = 0;
+    let mut closest_distance = distance(&PLANCKIAN_LOCUS[0]);
+
+    for (index, point) in PLANCKIAN_LOCUS.iter().enumerate().skip(1) {
+        let distance = distance(point);
+
+        if distance < closest_distance {
+            closest_index = index;
+            closest_distance = distance;
+        }
+    }
+
+    // The closest point has to have a neighbor on each side, or the color is
+    // outside the table. This is also the case for NaN, since it will never be
+    // closer than the first point.
+    if closest_index == 0 || closest_index == PLANCKIAN_LOCUS.len() - 1 {
+        return None;
+    }
+
+    let [previous_t, previous_u, previous_v] = PLANCKIAN_LOCUS[closest_index - 1];
+    let [next_t, next_u, next_v] = PLANCKIAN_LOCUS[closest_index + 1];
+    let previous_distance = distance(&PLANCKIAN_LOCUS[closest_index - 1]);
+    let next_distance = distance(&PLANCKIAN_LOCUS[closest_index + 1]);
+
+    // Triangular solution, which is accurate close to the locus.
+    let length_u = T::from_f64(next_u - previous_u);
+    let length_v = T::from_f64(next_v - previous_v);
+    let length = (length_u.clone() * length_u + length_v.clone() * length_v).sqrt();
+    let offset = (previous_distance.clone() * &previous_distance
+        - next_distance.clone() * &next_distance
+        + length.clone() * &length)
+        / (T::from_f64(2.0) * &length);
+    let offset_ratio = offset.clone() / length;
+
+    let triangular_cct = T::from_f64(previous_t) + T::from_f64(next_t - previous_t) * &offset_ratio;
+    let locus_v = T::from_f64(previous_v) + T::from_f64(next_v - previous_v) * offset_ratio;
+
+    let squared_duv = previous_distance.clone() * &previous_distance - offset.clone() * offset;
+    let triangular_duv = if squared_duv > T::zero() {
+        squared_duv.sqrt()
+    } else {
+        T::zero()
+    };
+
+    let is_below = v < locus_v;
+
+    if triangular_duv < T::from_f64(0.002) {
+        return Some(CctDuv {
+            cct: triangular_cct,
+            duv: if is_below {
+                -triangular_duv
+            } else {
+                triangular_duv
+            },
+        });
+    }
+
+    // Parabolic solution, which is accurate further away from the locus.
+    let [closest_t, _, _] = PLANCKIAN_LOCUS[closest_index];
+    let denominator = (next_t - closest_t) * (previous_t - next_t) * (closest_t - previous_t);
+
+    let previous_factor = T::from_f64(previous_t / denominator);
+    let closest_factor = T::from_f64(closest_t / denominator);
+    let next_factor = T::from_f64(next_t / denominator);
+
+    let a = previous_factor.clone() * (next_distance.clone() - &closest_distance)
+        + closest_factor.clone() * (previous_distance.clone() - &next_distance)
+        + next_factor.clone() * (closest_distance.clone() - &previous_distance);
+    let b = -(previous_factor
+        * T::from_f64(previous_t)
+        * (next_distance.clone() - &closest_distance)
+        + closest_factor * T::from_f64(closest_t) * (previous_distance.clone() - &next_distance)
+        + next_factor * T::from_f64(next_t) * (closest_distance.clone() - &previous_distance));
+    let c = -(previous_distance
+        * T::from_f64((next_t - closest_t) * closest_t * next_t / denominator)
+        + closest_distance
+            * T::from_f64((previous_t - next_t) * previous_t * next_t / denominator)
+        + next_distance
+            * T::from_f64((closest_t - previous_t) * previous_t * closest_t / denominator));
+
+    let parabolic_cct = -b.clone() / (T::from_f64(2.0) * &a);
+    let parabolic_duv = a * &parabolic_cct * &parabolic_cct + b * &parabolic_cct + c;
+
+    Some(CctDuv {
+        // Ohno's correction of the systematic error in the parabolic solution.
+        cct: parabolic_cct * T::from_f64(0.99991),
+        duv: if is_below {
+            -parabolic_duv
+        } else {
+            parabolic_duv
+        },
+    })
+}
+
+/// Find the CIE 1960 uv coordinates of the Planckian locus at `temperature`,
+/// and the direction of increasing temperature, by interpolating the embedded
+/// table.
+pub(crate) fn planckian_locus_uv(temperature: f64) -> ([f64; 2], [f64; 2]) {
+    // The table segment that contains `temperature`, or the closest one if
+    // it's outside the table.
+    let next_index = PLANCKIAN_LOCUS
+        .partition_point(|&[t, _, _]| t < temperature)
+        .clamp(1, PLANCKIAN_LOCUS.len() - 1);
+    let [previous_t, previous_u, previous_v] = PLANCKIAN_LOCUS[next_index - 1];
+    let [next_t, next_u, next_v] = PLANCKIAN_LOCUS[next_index];
+
+    let factor = (temperature - previous_t) / (next_t - previous_t);
+    let delta_u = next_u - previous_u;
+    let delta_v = next_v - previous_v;
+
+    (
+        [previous_u + delta_u * factor, previous_v + delta_v * factor],
+        [delta_u, delta_v],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::cct_duv;
+    use crate::{white_point::Any, Xyz, Yxy};
+
+    fn assert_cct_duv(x: f64, y: f64, cct: f64, duv: f64) {
+        let result = cct_duv(Yxy::<Any, f64>::new(x, y, 1.0)).unwrap();
+
+        // The chromaticity coordinates of the bin centers are rounded to four
+        // decimals, which limits the precision.
+        assert!((result.cct - cct).abs() < 10.0, "{:?} != {}", result, cct);
+        assert!((result.duv - duv).abs() < 0.0003, "{:?} != {}", result, duv);
+    }
+
+    #[test]
+    fn ansi_c78_377_bin_centers() {
+        assert_cct_duv(0.4578, 0.4101, 2725.0, 0.0);
+        assert_cct_duv(0.4338, 0.4030, 3045.0, 0.0001);
+        assert_cct_duv(0.3818, 0.3797, 3985.0, 0.001);
+        assert_cct_duv(0.3447, 0.3553, 5028.0, 0.002);
+        assert_cct_duv(0.3123, 0.3282, 6530.0, 0.0031);
+    }
+
+    #[test]
+    fn parabolic() {
+        // Points at known distances from the locus, far enough away to use the
+        // parabolic solution.
+        let result = cct_duv(Yxy::<Any, f64>::new(0.395364, 0.432604, 1.0)).unwrap();
+        assert!((result.cct - 4000.0).abs() < 1.0, "{:?}", result);
+        assert!((result.duv - 0.02).abs() < 0.00001, "{:?}", result);
+
+        let result = cct_duv(Yxy::<Any, f64>::new(0.416707, 0.362235, 1.0)).unwrap();
+        assert!((result.cct - 3000.0).abs() < 1.0, "{:?}", result);
+        assert!((result.duv + 0.015).abs() < 0.00001, "{:?}", result);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(cct_duv(Yxy::<Any, f64>::new(0.7, 0.29, 1.0)), None);
+        assert_eq!(cct_duv(Yxy::<Any, f64>::new(0.2, 0.2, 1.0)), None);
+        assert_eq!(cct_duv(Xyz::<Any, f64>::new(f64::NAN, 1.0, 1.0)), None);
+    }
+}
@@ -0,0 +1,1352 @@
+// This file is auto-generated and any manual changes to it will be overwritten.
+//
+// Run `cargo run -p codegen` from the project root to regenerate it.
+
+pub const PLANCKIAN_LOCUS: [[f64; 3]; 273] = [
+    [1000f64, 0.4480877941401446f64, 0.3547319650277273f64],
+    [1010f64, 0.44574603604027435f64, 0.35492533130855863f64],
+    [1020.1f64, 0.44340785720356374f64, 0.355116885950333f64],
+    [1030.301f64, 0.4410735024186194f64, 0.35530654902890496f64],
+    [1040.60401f64, 0.4387432158525768f64, 0.35549423951953046f64],
+    [
+        1051.0100501f64,
+        0.43641724094358314f64,
+        0.35567987531813383f64,
+    ],
+    [
+        1061.5201506009998f64,
+        0.434095820293405f64,
+        0.35586337326434425f64,
+    ],
+    [
+        1072.1353521070098f64,
+        0.43177919556026245f64,
+        0.3560446491663406f64,
+    ],
+    [
+        1082.85670562808f64,
+        0.4294676073519893f64,
+        0.35622361782754336f64,
+    ],
+    [
+        1093.6852726843608f64,
+        0.42716129511962353f64,
+        0.3564001930751877f64,
+    ],
+    [
+        1104.6221254112045f64,
+        0.4248604970515266f64,
+        0.35657428779081346f64,
+    ],
+    [
+        1115.6683466653164f64,
+        0.42256544996813417f64,
+        0.3567458139427033f64,
+    ],
+    [
+        1126.8250301319697f64,
+        0.42027638921743726f64,
+        0.35691468262029963f64,
+    ],
+    [
+        1138.0932804332895f64,
+        0.4179935485712964f64,
+        0.35708080407062637f64,
+    ],
+    [
+        1149.4742132376223f64,
+        0.415717160122684f64,
+        0.35724408773674265f64,
+    ],
+    [
+        1160.9689553699984f64,
+        0.4134474541839555f64,
+        0.35740444229824814f64,
+    ],
+    [
+        1172.5786449236984f64,
+        0.4111846591862465f64,
+        0.357561775713862f64,
+    ],
+    [
+        1184.3044313729354f64,
+        0.40892900158009027f64,
+        0.35771599526608927f64,
+    ],
+    [
+        1196.1474756866646f64,
+        0.4066807057373492f64,
+        0.35786700760798823f64,
+    ],
+    [
+        1208.1089504435313f64,
+        0.4044399938545562f64,
+        0.3580147188120494f64,
+    ],
+    [
+        1220.1900399479666f64,
+        0.4022070858577518f64,
+        0.3581590344211894f64,
+    ],
+    [
+        1232.3919403474463f64,
+        0.3999821993089114f64,
+        0.3582998595018633f64,
+    ],
+    [
+        1244.7158597509208f64,
+        0.3977655493140456f64,
+        0.35843709869929213f64,
+    ],
+    [
+        1257.1630183484301f64,
+        0.3955573484330597f64,
+        0.35857065629479956f64,
+    ],
+    [
+        1269.7346485319144f64,
+        0.3933578065914553f64,
+        0.358700436265246f64,
+    ],
+    [
+        1282.4319950172337f64,
+        0.3911671309939535f64,
+        0.3588263423445453f64,
+    ],
+    [
+        1295.256314967406f64,
+        0.3889855260401174f64,
+        0.3589482780872435f64,
+    ],
+    [
+        1308.20887811708f64,
+        0.38681319324204855f64,
+        0.3590661469341327f64,
+    ],
+    [
+        1321.2909668982509f64,
+        0.3846503311442282f64,
+        0.35917985227987265f64,
+    ],
+    [
+        1334.5038765672334f64,
+        0.3824971352455757f64,
+        0.3592892975425819f64,
+    ],
+    [
+        1347.8489153329058f64,
+        0.38035379792378565f64,
+        0.35939438623535835f64,
+    ],
+    [
+        1361.3274044862349f64,
+        0.3782205083620099f64,
+        0.359495022039683f64,
+    ],
+    [
+        1374.9406785310973f64,
+        0.3760974524779436f64,
+        0.3595911088806542f64,
+    ],
+    [
+        1388.6900853164084f64,
+        0.37398481285537105f64,
+        0.35968255100399565f64,
+    ],
+    [
+        1402.5769861695726f64,
+        0.37188276867822423f64,
+        0.3597692530547735f64,
+    ],
+    [
+        1416.6027560312684f64,
+        0.36979149566720504f64,
+        0.3598511201577535f64,
+    ],
+    [
+        1430.768783591581f64,
+        0.36771116601901643f64,
+        0.35992805799932437f64,
+    ],
+    [
+        1445.076471427497f64,
+        0.36564194834824526f64,
+        0.35999997291090396f64,
+    ],
+    [
+        1459.527236141772f64,
+        0.36358400763193643f64,
+        0.3600667719537426f64,
+    ],
+    [
+        1474.1225085031897f64,
+        0.36153750515689304f64,
+        0.3601283630050311f64,
+    ],
+    [
+        1488.8637335882215f64,
+        0.3595025984697355f64,
+        0.3601846548452127f64,
+    ],
+    [
+        1503.7523709241038f64,
+        0.35747944132974635f64,
+        0.3602355572463967f64,
+    ],
+    [
+        1518.7898946333448f64,
+        0.3554681836645271f64,
+        0.360280981061761f64,
+    ],
+    [
+        1533.9777935796783f64,
+        0.35346897152848594f64,
+        0.3603208383158293f64,
+    ],
+    [
+        1549.317571515475f64,
+        0.35148194706417507f64,
+        0.3603550422955001f64,
+    ],
+    [
+        1564.8107472306299f64,
+        0.3495072484664903f64,
+        0.36038350764170046f64,
+    ],
+    [
+        1580.4588547029362f64,
+        0.34754500994974263f64,
+        0.3604061504415336f64,
+    ],
+    [
+        1596.2634432499656f64,
+        0.34559536171760874f64,
+        0.3604228883207801f64,
+    ],
+    [
+        1612.2260776824653f64,
+        0.3436584299359619f64,
+        0.36043364053661364f64,
+    ],
+    [
+        1628.34833845929f64,
+        0.34173433670858233f64,
+        0.360438328070382f64,
+    ],
+    [
+        1644.631821843883f64,
+        0.339823200055744f64,
+        0.3604368737203036f64,
+    ],
+    [
+        1661.0781400623218f64,
+        0.3379251338956672f64,
+        0.3604292021939228f64,
+    ],
+    [
+        1677.688921462945f64,
+        0.3360402480288275f64,
+        0.3604152402001669f64,
+    ],
+    [
+        1694.4658106775744f64,
+        0.3341686481251052f64,
+        0.36039491654083977f64,
+    ],
+    [
+        1711.41046878435f64,
+        0.33231043571375685f64,
+        0.3603681622013903f64,
+    ],
+    [
+        1728.5245734721937f64,
+        0.3304657081761888f64,
+        0.36033491044078353f64,
+    ],
+    [
+        1745.8098192069156f64,
+        0.32863455874150593f64,
+        0.3602950968803064f64,
+    ],
+    [
+        1763.2679173989848f64,
+        0.32681707648480995f64,
+        0.36024865959113644f64,
+    ],
+    [
+        1780.9005965729746f64,
+        0.32501334632821527f64,
+        0.3601955391804976f64,
+    ],
+    [
+        1798.7096025387043f64,
+        0.3232234490445498f64,
+        0.36013567887623044f64,
+    ],
+    [
+        1816.6966985640913f64,
+        0.3214474612637038f64,
+        0.36006902460960133f64,
+    ],
+    [
+        1834.8636655497323f64,
+        0.319685455481587f64,
+        0.35999552509617616f64,
+    ],
+    [
+        1853.2123022052297f64,
+        0.3179375000716542f64,
+        0.3599151319145824f64,
+    ],
+    [
+        1871.744425227282f64,
+        0.3162036592989536f64,
+        0.35982779958298966f64,
+    ],
+    [
+        1890.4618694795547f64,
+        0.31448399333665134f64,
+        0.3597334856331343f64,
+    ],
+    [
+        1909.3664881743503f64,
+        0.31277855828498496f64,
+        0.35963215068171955f64,
+    ],
+    [
+        1928.4601530560938f64,
+        0.31108740619259245f64,
+        0.3595237584990259f64,
+    ],
+    [
+        1947.7447545866546f64,
+        0.30941058508016656f64,
+        0.3594082760745662f64,
+    ],
+    [
+        1967.2222021325213f64,
+        0.30774813896637654f64,
+        0.35928567367962744f64,
+    ],
+    [
+        1986.8944241538466f64,
+        0.3061001078960037f64,
+        0.35915592492654425f64,
+    ],
+    [
+        2006.763368395385f64,
+        0.30446652797022944f64,
+        0.3590190068245522f64,
+    ],
+    [
+        2026.8310020793388f64,
+        0.30284743137901765f64,
+        0.3588748998320785f64,
+    ],
+    [
+        2047.0993121001322f64,
+        0.3012428464355298f64,
+        0.3587235879053296f64,
+    ],
+    [
+        2067.5703052211334f64,
+        0.29965279761250896f64,
+        0.3585650585430441f64,
+    ],
+    [
+        2088.2460082733446f64,
+        0.29807730558057055f64,
+        0.35839930282728566f64,
+    ],
+    [
+        2109.128468356078f64,
+        0.29651638724833274f64,
+        0.3582263154601585f64,
+    ],
+    [
+        2130.219753039639f64,
+        0.29497005580432223f64,
+        0.35804609479633503f64,
+    ],
+    [
+        2151.521950570035f64,
+        0.29343832076058823f64,
+        0.3578586428712954f64,
+    ],
+    [
+        2173.0371700757355f64,
+        0.2919211879979563f64,
+        0.3576639654251844f64,
+    ],
+    [
+        2194.767541776493f64,
+        0.2904186598128546f64,
+        0.357462071922206f64,
+    ],
+    [
+        2216.715217194258f64,
+        0.2889307349656446f64,
+        0.35725297556547675f64,
+    ],
+    [
+        2238.8823693662007f64,
+        0.2874574087303855f64,
+        0.35703669330727955f64,
+    ],
+    [
+        2261.2711930598625f64,
+        0.2859986729459649f64,
+        0.3568132458546604f64,
+    ],
+    [
+        2283.8839049904614f64,
+        0.284554516068525f64,
+        0.3565826576703262f64,
+    ],
+    [
+        2306.722744040366f64,
+        0.2831249232251161f64,
+        0.3563449569688124f64,
+    ],
+    [
+        2329.7899714807695f64,
+        0.281709876268507f64,
+        0.3561001757078965f64,
+    ],
+    [
+        2353.087871195577f64,
+        0.28030935383308325f64,
+        0.3558483495752493f64,
+    ],
+    [
+        2376.6187499075327f64,
+        0.2789233313917648f64,
+        0.3555895179703229f64,
+    ],
+    [
+        2400.384937406608f64,
+        0.2775517813138738f64,
+        0.3553237239814883f64,
+    ],
+    [
+        2424.3887867806743f64,
+        0.2761946729238852f64,
+        0.3550510143584455f64,
+    ],
+    [
+        2448.632674648481f64,
+        0.2748519725609911f64,
+        0.3547714394799391f64,
+    ],
+    [
+        2473.119001394966f64,
+        0.27352364363941306f64,
+        0.3544850533168276f64,
+    ],
+    [
+        2497.8501914089156f64,
+        0.272209646709396f64,
+        0.3541919133905605f64,
+    ],
+    [
+        2522.8286933230047f64,
+        0.2709099395188167f64,
+        0.35389208072713146f64,
+    ],
+    [
+        2548.056980256235f64,
+        0.26962447707534404f64,
+        0.3535856198065855f64,
+    ],
+    [
+        2573.5375500587975f64,
+        0.26835321170908616f64,
+        0.3532725985081701f64,
+    ],
+    [
+        2599.2729255593854f64,
+        0.26709609313566157f64,
+        0.35295308805122694f64,
+    ],
+    [
+        2625.2656548149794f64,
+        0.2658530685196326f64,
+        0.35262716293193497f64,
+    ],
+    [
+        2651.518311363129f64,
+        0.2646240825382408f64,
+        0.3522949008560219f64,
+    ],
+    [
+        2678.0334944767606f64,
+        0.26340907744538566f64,
+        0.3519563826675733f64,
+    ],
+    [
+        2704.813829421528f64,
+        0.26220799313578486f64,
+        0.351611692274073f64,
+    ],
+    [
+        2731.8619677157435f64,
+        0.2610207672092637f64,
+        0.35126091656782327f64,
+    ],
+    [
+        2759.180587392901f64,
+        0.2598473350351148f64,
+        0.3509041453438934f64,
+    ],
+    [
+        2786.77239326683f64,
+        0.25868762981647464f64,
+        0.3505414712147597f64,
+    ],
+    [
+        2814.6401171994985f64,
+        0.257541582654664f64,
+        0.350172989521803f64,
+    ],
+    [
+        2842.7865183714935f64,
+        0.2564091226134405f64,
+        0.3497987982438345f64,
+    ],
+    [
+        2871.2143835552083f64,
+        0.2552901767831137f64,
+        0.3494189979028335f64,
+    ],
+    [
+        2899.9265273907604f64,
+        0.2541846703444733f64,
+        0.34903369146707597f64,
+    ],
+    [
+        2928.925792664668f64,
+        0.2530925266324835f64,
+        0.3486429842518461f64,
+    ],
+    [
+        2958.2150505913146f64,
+        0.2520136671996983f64,
+        0.34824698381792174f64,
+    ],
+    [
+        2987.7972010972276f64,
+        0.2509480118793538f64,
+        0.34784579986803055f64,
+    ],
+    [
+        3017.6751731082f64,
+        0.24989547884809313f64,
+        0.34743954414147377f64,
+    ],
+    [
+        3047.8519248392818f64,
+        0.2488559846882862f64,
+        0.34702833030712066f64,
+    ],
+    [
+        3078.3304440876746f64,
+        0.24782944444990146f64,
+        0.3466122738549739f64,
+    ],
+    [
+        3109.1137485285512f64,
+        0.2468157717118946f64,
+        0.3461914919865097f64,
+    ],
+    [
+        3140.2048860138366f64,
+        0.24581487864307558f64,
+        0.34576610350399534f64,
+    ],
+    [
+        3171.606934873975f64,
+        0.2448266760624216f64,
+        0.3453362286989882f64,
+    ],
+    [
+        3203.323004222715f64,
+        0.24385107349880036f64,
+        0.34490198924021653f64,
+    ],
+    [
+        3235.3562342649425f64,
+        0.2428879792500745f64,
+        0.344463508061044f64,
+    ],
+    [
+        3267.709796607592f64,
+        0.241937300441555f64,
+        0.3440209092467144f64,
+    ],
+    [
+        3300.386894573668f64,
+        0.24099894308377665f64,
+        0.3435743179215747f64,
+    ],
+    [
+        3333.3907635194046f64,
+        0.24007281212956794f64,
+        0.3431238601364661f64,
+    ],
+    [
+        3366.7246711545986f64,
+        0.23915881153038948f64,
+        0.3426696627564741f64,
+    ],
+    [
+        3400.3919178661445f64,
+        0.23825684429191832f64,
+        0.3422118533492208f64,
+    ],
+    [
+        3434.395837044806f64,
+        0.23736681252885428f64,
+        0.3417505600738806f64,
+    ],
+    [
+        3468.739795415254f64,
+        0.2364886175189281f64,
+        0.3412859115710939f64,
+    ],
+    [
+        3503.4271933694063f64,
+        0.2356221597560914f64,
+        0.3408180368539474f64,
+    ],
+    [
+        3538.4614653031003f64,
+        0.23476733900287117f64,
+        0.3403470652001875f64,
+    ],
+    [
+        3573.8460799561312f64,
+        0.2339240543418704f64,
+        0.3398731260458209f64,
+    ],
+    [
+        3609.5845407556926f64,
+        0.23309220422640115f64,
+        0.33939634888025716f64,
+    ],
+    [
+        3645.6803861632498f64,
+        0.2322716865302347f64,
+        0.33891686314313574f64,
+    ],
+    [
+        3682.1371900248823f64,
+        0.23146239859645726f64,
+        0.3384347981229752f64,
+    ],
+    [
+        3718.958561925131f64,
+        0.2306642372854181f64,
+        0.337950282857777f64,
+    ],
+    [
+        3756.1481475443825f64,
+        0.2298770990217615f64,
+        0.3374634460377038f64,
+    ],
+    [
+        3793.7096290198265f64,
+        0.2291008798405326f64,
+        0.33697441590995064f64,
+    ],
+    [
+        3831.646725310025f64,
+        0.22833547543234936f64,
+        0.33648332018591637f64,
+    ],
+    [
+        3869.963192563125f64,
+        0.22758078118763433f64,
+        0.33599028595077435f64,
+    ],
+    [
+        3908.6628244887565f64,
+        0.22683669223990016f64,
+        0.33549543957553724f64,
+    ],
+    [
+        3947.749452733644f64,
+        0.2261031035080849f64,
+        0.33499890663169796f64,
+    ],
+    [
+        3987.2269472609805f64,
+        0.22537990973793343f64,
+        0.3345008118085259f64,
+    ],
+    [
+        4027.0992167335903f64,
+        0.22466700554242222f64,
+        0.33400127883308744f64,
+    ],
+    [
+        4067.370208900926f64,
+        0.22396428544122693f64,
+        0.33350043039305f64,
+    ],
+    [
+        4108.043910989935f64,
+        0.22327164389923146f64,
+        0.33299838806232646f64,
+    ],
+    [
+        4149.124350099834f64,
+        0.22258897536407946f64,
+        0.33249527222960423f64,
+    ],
+    [
+        4190.615593600833f64,
+        0.22191617430276941f64,
+        0.33199120202979815f64,
+    ],
+    [
+        4232.521749536841f64,
+        0.2212531352372958f64,
+        0.3314862952784589f64,
+    ],
+    [
+        4274.846967032209f64,
+        0.22059975277933866f64,
+        0.33098066840916207f64,
+    ],
+    [
+        4317.5954367025315f64,
+        0.21995592166400602f64,
+        0.3304744364138928f64,
+    ],
+    [
+        4360.771391069557f64,
+        0.2193215367826338f64,
+        0.3299677127864401f64,
+    ],
+    [
+        4404.379104980252f64,
+        0.21869649321464749f64,
+        0.3294606094688025f64,
+    ],
+    [
+        4448.422896030054f64,
+        0.2180806862584927f64,
+        0.3289532368006029f64,
+    ],
+    [
+        4492.9071249903545f64,
+        0.21747401146164044f64,
+        0.3284457034715061f64,
+    ],
+    [
+        4537.836196240258f64,
+        0.21687636464967486f64,
+        0.3279381164766229f64,
+    ],
+    [
+        4583.214558202661f64,
+        0.21628764195447017f64,
+        0.32743058107488165f64,
+    ],
+    [
+        4629.046703784687f64,
+        0.21570773984146682f64,
+        0.3269232007503423f64,
+    ],
+    [
+        4675.337170822534f64,
+        0.21513655513605418f64,
+        0.3264160771764234f64,
+    ],
+    [
+        4722.090542530759f64,
+        0.21457398504906974f64,
+        0.3259093101830053f64,
+    ],
+    [
+        4769.311447956067f64,
+        0.21401992720142538f64,
+        0.32540299772637166f64,
+    ],
+    [
+        4817.004562435628f64,
+        0.21347427964786908f64,
+        0.3248972358619455f64,
+    ],
+    [
+        4865.174608059984f64,
+        0.21293694089989512f64,
+        0.32439211871977097f64,
+    ],
+    [
+        4913.826354140584f64,
+        0.21240780994781153f64,
+        0.32388773848269015f64,
+    ],
+    [
+        4962.96461768199f64,
+        0.21188678628197818f64,
+        0.3233841853671606f64,
+    ],
+    [
+        5012.594263858809f64,
+        0.21137376991322587f64,
+        0.3228815476066557f64,
+    ],
+    [
+        5062.720206497397f64,
+        0.21086866139246885f64,
+        0.3223799114375854f64,
+    ],
+    [
+        5113.347408562371f64,
+        0.2103713618295234f64,
+        0.32187936108767673f64,
+    ],
+    [
+        5164.480882647995f64,
+        0.20988177291114454f64,
+        0.3213799787667465f64,
+    ],
+    [
+        5216.1256914744745f64,
+        0.20939979691829427f64,
+        0.3208818446597994f64,
+    ],
+    [
+        5268.286948389219f64,
+        0.2089253367426532f64,
+        0.32038503692238185f64,
+    ],
+    [
+        5320.969817873111f64,
+        0.20845829590239037f64,
+        0.3198896316781201f64,
+    ],
+    [
+        5374.179516051842f64,
+        0.2079985785572029f64,
+        0.31939570301837206f64,
+    ],
+    [
+        5427.921311212361f64,
+        0.20754608952264045f64,
+        0.3189033230039165f64,
+    ],
+    [
+        5482.200524324484f64,
+        0.20710073428372675f64,
+        0.31841256166860893f64,
+    ],
+    [
+        5537.022529567729f64,
+        0.20666241900789273f64,
+        0.31792348702492557f64,
+    ],
+    [
+        5592.3927548634065f64,
+        0.20623105055723515f64,
+        0.3174361650713239f64,
+    ],
+    [
+        5648.316682412041f64,
+        0.20580653650011374f64,
+        0.3169506598013407f64,
+    ],
+    [
+        5704.799849236161f64,
+        0.2053887851221015f64,
+        0.3164670332143549f64,
+    ],
+    [
+        5761.8478477285225f64,
+        0.20497770543630156f64,
+        0.31598534532793787f64,
+    ],
+    [
+        5819.4663262058075f64,
+        0.20457320719304514f64,
+        0.3155056541917169f64,
+    ],
+    [
+        5877.660989467866f64,
+        0.20417520088898394f64,
+        0.3150280159026769f64,
+    ],
+    [
+        5936.4375993625445f64,
+        0.20378359777559119f64,
+        0.3145524846218268f64,
+    ],
+    [
+        5995.80197535617f64,
+        0.20339830986708493f64,
+        0.31407911259215543f64,
+    ],
+    [
+        6055.759995109732f64,
+        0.20301924994778833f64,
+        0.3136079501578079f64,
+    ],
+    [
+        6116.317595060829f64,
+        0.20264633157893913f64,
+        0.3131390457844072f64,
+    ],
+    [
+        6177.480771011437f64,
+        0.20227946910496383f64,
+        0.3126724460804533f64,
+    ],
+    [
+        6239.2555787215515f64,
+        0.20191857765922885f64,
+        0.3122081958197291f64,
+    ],
+    [
+        6301.648134508767f64,
+        0.2015635731692832f64,
+        0.31174633796464696f64,
+    ],
+    [
+        6364.664615853854f64,
+        0.2012143723616051f64,
+        0.3112869136904677f64,
+    ],
+    [
+        6428.311262012393f64,
+        0.20087089276586734f64,
+        0.310829962410328f64,
+    ],
+    [
+        6492.5943746325165f64,
+        0.2005330527187329f64,
+        0.31037552180101297f64,
+    ],
+    [
+        6557.520318378842f64,
+        0.2002007713671956f64,
+        0.30992362782941124f64,
+    ],
+    [
+        6623.09552156263f64,
+        0.19987396867147783f64,
+        0.3094743147795931f64,
+    ],
+    [
+        6689.326476778257f64,
+        0.199552565407498f64,
+        0.30902761528045314f64,
+    ],
+    [
+        6756.21974154604f64,
+        0.19923648316892154f64,
+        0.30858356033385953f64,
+    ],
+    [
+        6823.7819389615f64,
+        0.19892564436880658f64,
+        0.30814217934325755f64,
+    ],
+    [
+        6892.019758351114f64,
+        0.19861997224085823f64,
+        0.3077035001426703f64,
+    ],
+    [
+        6960.939955934626f64,
+        0.19831939084030195f64,
+        0.30726754902604886f64,
+    ],
+    [
+        7030.549355493972f64,
+        0.19802382504438987f64,
+        0.30683435077692034f64,
+    ],
+    [
+        7100.854849048912f64,
+        0.19773320055255078f64,
+        0.3064039286982861f64,
+    ],
+    [
+        7171.863397539401f64,
+        0.19744744388619528f64,
+        0.3059763046427251f64,
+    ],
+    [
+        7243.582031514796f64,
+        0.19716648238818957f64,
+        0.3055514990426574f64,
+    ],
+    [
+        7316.0178518299435f64,
+        0.19689024422200596f64,
+        0.30512953094072576f64,
+    ],
+    [
+        7389.178030348243f64,
+        0.19661865837056486f64,
+        0.30471041802025556f64,
+    ],
+    [
+        7463.069810651726f64,
+        0.19635165463477647f64,
+        0.3042941766357531f64,
+    ],
+    [
+        7537.700508758243f64,
+        0.19608916363179443f64,
+        0.303880821843406f64,
+    ],
+    [
+        7613.077513845826f64,
+        0.195831116792991f64,
+        0.3034703674315515f64,
+    ],
+    [
+        7689.208288984284f64,
+        0.1955774463616657f64,
+        0.30306282595107703f64,
+    ],
+    [
+        7766.100371874127f64,
+        0.1953280853904958f64,
+        0.3026582087457237f64,
+    ],
+    [
+        7843.761375592868f64,
+        0.19508296773873968f64,
+        0.3022565259822605f64,
+    ],
+    [
+        7922.198989348796f64,
+        0.19484202806920306f64,
+        0.3018577866805028f64,
+    ],
+    [
+        8001.420979242284f64,
+        0.1946052018449767f64,
+        0.3014619987431471f64,
+    ],
+    [
+        8081.435189034707f64,
+        0.1943724253259563f64,
+        0.3010691689853964f64,
+    ],
+    [
+        8162.249540925054f64,
+        0.19414363556515216f64,
+        0.30067930316435415f64,
+    ],
+    [
+        8243.872036334305f64,
+        0.19391877040479952f64,
+        0.3002924060081634f64,
+    ],
+    [
+        8326.310756697649f64,
+        0.1936977684722765f64,
+        0.29990848124486996f64,
+    ],
+    [
+        8409.573864264625f64,
+        0.19348056917584017f64,
+        0.29952753163099094f64,
+    ],
+    [
+        8493.669602907272f64,
+        0.19326711270018657f64,
+        0.2991495589797714f64,
+    ],
+    [
+        8578.606298936345f64,
+        0.19305734000184605f64,
+        0.2987745641891111f64,
+    ],
+    [
+        8664.39236192571f64,
+        0.19285119280441923f64,
+        0.29840254726914567f64,
+    ],
+    [
+        8751.036285544966f64,
+        0.19264861359366303f64,
+        0.29803350736947076f64,
+    ],
+    [
+        8838.546648400416f64,
+        0.19244954561243355f64,
+        0.29766744280599283f64,
+    ],
+    [
+        8926.93211488442f64,
+        0.19225393285549366f64,
+        0.29730435108739706f64,
+    ],
+    [
+        9016.201436033263f64,
+        0.19206172006419242f64,
+        0.29694422894122086f64,
+    ],
+    [
+        9106.363450393595f64,
+        0.1918728527210234f64,
+        0.2965870723395241f64,
+    ],
+    [
+        9197.427084897532f64,
+        0.19168727704406865f64,
+        0.2962328765241457f64,
+    ],
+    [
+        9289.401355746508f64,
+        0.19150493998133525f64,
+        0.29588163603154216f64,
+    ],
+    [
+        9382.295369303973f64,
+        0.19132578920499074f64,
+        0.29553334471719733f64,
+    ],
+    [
+        9476.118322997012f64,
+        0.19114977310550346f64,
+        0.29518799577960214f64,
+    ],
+    [
+        9570.879506226982f64,
+        0.19097684078569527f64,
+        0.2948455817837951f64,
+    ],
+    [
+        9666.588301289252f64,
+        0.19080694205471035f64,
+        0.2945060946844629f64,
+    ],
+    [
+        9763.254184302144f64,
+        0.19064002742190833f64,
+        0.29416952584859596f64,
+    ],
+    [
+        9860.886726145167f64,
+        0.19047604809068527f64,
+        0.29383586607769685f64,
+    ],
+    [
+        9959.495593406618f64,
+        0.1903149559522299f64,
+        0.2935051056295403f64,
+    ],
+    [
+        10059.090549340684f64,
+        0.19015670357921852f64,
+        0.2931772342394828f64,
+    ],
+    [
+        10159.681454834092f64,
+        0.1900012442194551f64,
+        0.2928522411413214f64,
+    ],
+    [
+        10261.278269382432f64,
+        0.18984853178946093f64,
+        0.2925301150877031f64,
+    ],
+    [
+        10363.891052076257f64,
+        0.18969852086801872f64,
+        0.2922108443700825f64,
+    ],
+    [
+        10467.529962597018f64,
+        0.189551166689676f64,
+        0.2918944168382333f64,
+    ],
+    [
+        10572.20526222299f64,
+        0.1894064251382121f64,
+        0.2915808199193106f64,
+    ],
+    [
+        10677.92731484522f64,
+        0.18926425274007283f64,
+        0.2912700406364693f64,
+    ],
+    [
+        10784.70658799367f64,
+        0.18912460665777828f64,
+        0.29096206562704047f64,
+    ],
+    [
+        10892.553653873607f64,
+        0.18898744468330594f64,
+        0.2906568811602678f64,
+    ],
+    [
+        11001.479190412343f64,
+        0.18885272523145474f64,
+        0.2903544731546086f64,
+    ],
+    [
+        11111.493982316466f64,
+        0.18872040733319287f64,
+        0.29005482719460224f64,
+    ],
+    [
+        11222.60892213963f64,
+        0.18859045062899368f64,
+        0.2897579285473109f64,
+    ],
+    [
+        11334.835011361027f64,
+        0.18846281536216217f64,
+        0.28946376217833614f64,
+    ],
+    [
+        11448.183361474637f64,
+        0.18833746237215682f64,
+        0.28917231276741656f64,
+    ],
+    [
+        11562.665195089385f64,
+        0.18821435308790896f64,
+        0.288883564723611f64,
+    ],
+    [
+        11678.291847040278f64,
+        0.18809344952114349f64,
+        0.28859750220007313f64,
+    ],
+    [
+        11795.07476551068f64,
+        0.18797471425970352f64,
+        0.2883141091084216f64,
+    ],
+    [
+        11913.025513165787f64,
+        0.18785811046088213f64,
+        0.2880333691327124f64,
+    ],
+    [
+        12032.155768297445f64,
+        0.18774360184476413f64,
+        0.28775526574301863f64,
+    ],
+    [
+        12152.47732598042f64,
+        0.1876311526875801f64,
+        0.2874797822086241f64,
+    ],
+    [
+        12274.002099240224f64,
+        0.18752072781507567f64,
+        0.28720690161083495f64,
+    ],
+    [
+        12396.742120232626f64,
+        0.1874122925958987f64,
+        0.28693660685541866f64,
+    ],
+    [
+        12520.709541434951f64,
+        0.18730581293500584f64,
+        0.28666888068467344f64,
+    ],
+    [
+        12645.9166368493f64,
+        0.18720125526709178f64,
+        0.28640370568913626f64,
+    ],
+    [
+        12772.375803217794f64,
+        0.18709858655004277f64,
+        0.28614106431893543f64,
+    ],
+    [
+        12900.099561249972f64,
+        0.1869977742584168f64,
+        0.28588093889479416f64,
+    ],
+    [
+        13029.100556862471f64,
+        0.18689878637695173f64,
+        0.2856233116186913f64,
+    ],
+    [
+        13159.391562431096f64,
+        0.18680159139410452f64,
+        0.285368164584187f64,
+    ],
+    [
+        13290.985478055407f64,
+        0.18670615829562207f64,
+        0.2851154797864181f64,
+    ],
+    [
+        13423.89533283596f64,
+        0.18661245655814596f64,
+        0.28486523913177164f64,
+    ],
+    [
+        13558.13428616432f64,
+        0.18652045614285317f64,
+        0.2846174244472419f64,
+    ],
+    [
+        13693.715629025965f64,
+        0.18643012748913335f64,
+        0.28437201748947816f64,
+    ],
+    [
+        13830.652785316224f64,
+        0.18634144150830464f64,
+        0.2841289999535295f64,
+    ],
+    [
+        13968.959313169387f64,
+        0.1862543695773699f64,
+        0.28388835348129393f64,
+    ],
+    [
+        14108.648906301081f64,
+        0.1861688835328136f64,
+        0.28365005966967666f64,
+    ],
+    [
+        14249.735395364092f64,
+        0.18608495566444153f64,
+        0.28341410007846696f64,
+    ],
+    [
+        14392.232749317733f64,
+        0.18600255870926402f64,
+        0.2831804562379368f64,
+    ],
+    [
+        14536.15507681091f64,
+        0.18592166584542413f64,
+        0.28294910965617015f64,
+    ],
+    [
+        14681.51662757902f64,
+        0.18584225068617125f64,
+        0.28272004182612875f64,
+    ],
+    [
+        14828.33179385481f64,
+        0.18576428727388203f64,
+        0.2824932342324597f64,
+    ],
+    [
+        14976.615111793359f64,
+        0.1856877500741286f64,
+        0.28226866835805225f64,
+    ],
+];
+
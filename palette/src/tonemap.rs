@@ -0,0 +1,376 @@
+//! Tone mapping operators, for bringing high dynamic range (HDR) colors into
+//! the `0.0..=1.0` range.
+//!
+//! The operators work on linear light values, such as [`LinSrgb`], and are
+//! applied using the [`ToneMap`] trait. Each of them maps the red, green and
+//! blue channels independently by default, which desaturates very bright
+//! colors. Wrap an operator in [`Luminance`] to map the luminance instead and
+//! keep the chromaticity of the color.
+//!
+//! ```
+//! use palette::{
+//!     tonemap::{AcesFilmic, Luminance, Reinhard, ToneMap},
+//!     LinSrgb,
+//! };
+//!
+//! let bright = LinSrgb::new(4.0f32, 2.0, 0.5);
+//!
+//! let per_channel = Reinhard.tone_map(bright);
+//! assert_eq!(per_channel, LinSrgb::new(0.8, 2.0 / 3.0, 1.0 / 3.0));
+//!
+//! let filmic = Luminance(AcesFilmic).tone_map(bright);
+//! assert!((filmic.red / filmic.green - 2.0).abs() < 0.00001);
+//! ```
+//!
+//! Negative input values are treated as `0.0`.
+
+use crate::{
+    bool_mask::LazySelect,
+    num::{Arithmetics, Clamp, IsValidDivisor, One, Real, Zero},
+    LinSrgb, RelativeLuminance, Xyz,
+};
+
+/// A tone mapping operator.
+///
+/// Implementors only have to provide [`tone_map_value`][ToneMap::tone_map_value],
+/// which maps a single linear value, and get per-channel mapping of
+/// [`LinSrgb`] colors from the default implementation of
+/// [`tone_map`][ToneMap::tone_map].
+pub trait ToneMap<T> {
+    /// Map a single linear value, such as a color channel or a luminance
+    /// value.
+    #[must_use]
+    fn tone_map_value(&self, value: T) -> T;
+
+    /// Map a linear sRGB color.
+    ///
+    /// The default implementation maps each channel separately, using
+    /// [`tone_map_value`][ToneMap::tone_map_value].
+    #[must_use]
+    fn tone_map(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        LinSrgb::new(
+            self.tone_map_value(color.red),
+            self.tone_map_value(color.green),
+            self.tone_map_value(color.blue),
+        )
+    }
+}
+
+impl<M, T> ToneMap<T> for &M
+where
+    M: ToneMap<T> + ?Sized,
+{
+    #[inline]
+    fn tone_map_value(&self, value: T) -> T {
+        (*self).tone_map_value(value)
+    }
+
+    #[inline]
+    fn tone_map(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        (*self).tone_map(color)
+    }
+}
+
+/// Map all colors in `colors` with `operator`.
+///
+/// This is the same as calling [`ToneMap::tone_map`] for each color.
+///
+/// ```
+/// use palette::{
+///     tonemap::{tone_map_slice, ExtendedReinhard},
+///     LinSrgb,
+/// };
+///
+/// let mut image = [
+///     LinSrgb::new(8.0f32, 0.5, 0.0),
+///     LinSrgb::new(1.0, 2.0, 4.0),
+///     LinSrgb::new(0.0, 0.0, 0.0),
+/// ];
+/// tone_map_slice(&mut image, ExtendedReinhard::new(4.0));
+///
+/// assert_eq!(image[1].blue, 1.0);
+/// ```
+pub fn tone_map_slice<M, T>(colors: &mut [LinSrgb<T>], operator: M)
+where
+    M: ToneMap<T>,
+    T: Clone,
+{
+    for color in colors {
+        *color = operator.tone_map(color.clone());
+    }
+}
+
+/// The simple Reinhard operator, `x / (1 + x)`.
+///
+/// It maps `0.0..` to `0.0..1.0`, but never reaches `1.0`. See
+/// [`ExtendedReinhard`] for a variant where a chosen value becomes white.
+///
+/// The operator is from "Photographic Tone Reproduction for Digital Images"
+/// by Reinhard, Stark, Shirley and Ferwerda (2002).
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Reinhard;
+
+impl<T> ToneMap<T> for Reinhard
+where
+    T: One + Zero + Clamp + Arithmetics + Clone,
+{
+    #[inline]
+    fn tone_map_value(&self, value: T) -> T {
+        let value = crate::clamp_min(value, T::zero());
+        value.clone() / (T::one() + value)
+    }
+}
+
+/// The extended Reinhard operator, `x * (1 + x / white²) / (1 + x)`.
+///
+/// This variant maps `white` to `1.0`, which lets the brightest parts of an
+/// image become white. Values above `white` are clamped to `1.0`.
+///
+/// The operator is from "Photographic Tone Reproduction for Digital Images"
+/// by Reinhard, Stark, Shirley and Ferwerda (2002).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ExtendedReinhard<T> {
+    /// The smallest value that is mapped to `1.0`. It's expected to be
+    /// positive.
+    pub white: T,
+}
+
+impl<T> ExtendedReinhard<T> {
+    /// Create an extended Reinhard operator that maps `white` to `1.0`.
+    pub const fn new(white: T) -> Self {
+        ExtendedReinhard { white }
+    }
+}
+
+impl<T> ToneMap<T> for ExtendedReinhard<T>
+where
+    T: One + Zero + Clamp + Arithmetics + Clone,
+{
+    #[inline]
+    fn tone_map_value(&self, value: T) -> T {
+        let value = crate::clamp_min(value, T::zero());
+        let white_squared = self.white.clone() * &self.white;
+        let mapped =
+            value.clone() * (T::one() + value.clone() / white_squared) / (T::one() + value);
+
+        crate::clamp(mapped, T::zero(), T::one())
+    }
+}
+
+/// A filmic curve that approximates the ACES reference rendering and output
+/// transforms.
+///
+/// This is the rational polynomial fit by Krzysztof Narkowicz, from "ACES
+/// Filmic Tone Mapping Curve" (2016), with the result clamped to `0.0..=1.0`:
+///
+/// ```text
+/// x * (2.51 * x + 0.03) / (x * (2.43 * x + 0.59) + 0.14)
+/// ```
+///
+/// It's a single curve that is applied to each channel, which is cheaper but
+/// less accurate than the matrix based fit by Stephen Hill. The curve reaches
+/// `1.0` at about `7.24`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AcesFilmic;
+
+impl<T> ToneMap<T> for AcesFilmic
+where
+    T: Real + Zero + One + Clamp + Arithmetics + Clone,
+{
+    #[inline]
+    fn tone_map_value(&self, value: T) -> T {
+        let value = crate::clamp_min(value, T::zero());
+        let numerator = value.clone() * (T::from_f64(2.51) * &value + T::from_f64(0.03));
+        let denominator =
+            value.clone() * (T::from_f64(2.43) * value + T::from_f64(0.59)) + T::from_f64(0.14);
+
+        crate::clamp(numerator / denominator, T::zero(), T::one())
+    }
+}
+
+/// Apply a tone mapping operator to the luminance of a color, instead of to
+/// each channel.
+///
+/// The color is scaled by the ratio between the mapped and the original
+/// luminance, which keeps its chromaticity. The luminance stays within the
+/// range of the operator, but individual channels of saturated colors may end
+/// up above `1.0`.
+///
+/// ```
+/// use palette::{
+///     tonemap::{Luminance, Reinhard, ToneMap},
+///     LinSrgb, RelativeLuminance, Xyz,
+/// };
+///
+/// let mapped = Luminance(Reinhard).tone_map(LinSrgb::new(3.0f32, 3.0, 3.0));
+/// assert_eq!(mapped.get_relative_luminance(), 0.75);
+///
+/// let xyz: Xyz = Xyz::new(0.5, 1.0, 2.0);
+/// let mapped = Luminance(Reinhard).tone_map_xyz(xyz);
+/// assert_eq!(mapped, Xyz::new(0.25, 0.5, 1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Luminance<M>(pub M);
+
+impl<M> Luminance<M> {
+    /// Map the luminance of a CIE XYZ color, keeping its chromaticity.
+    #[must_use]
+    pub fn tone_map_xyz<Wp, T>(&self, color: Xyz<Wp, T>) -> Xyz<Wp, T>
+    where
+        M: ToneMap<T>,
+        T: Zero + IsValidDivisor + Arithmetics + Clone,
+        T::Mask: LazySelect<T>,
+    {
+        let ratio = self.luminance_ratio(color.y.clone());
+        color * ratio
+    }
+
+    #[inline]
+    fn luminance_ratio<T>(&self, luminance: T) -> T
+    where
+        M: ToneMap<T>,
+        T: Zero + IsValidDivisor + Arithmetics + Clone,
+        T::Mask: LazySelect<T>,
+    {
+        lazy_select! {
+            if luminance.is_valid_divisor() => self.0.tone_map_value(luminance.clone()) / luminance,
+            else => T::zero(),
+        }
+    }
+}
+
+impl<M, T> ToneMap<T> for Luminance<M>
+where
+    M: ToneMap<T>,
+    T: Zero + IsValidDivisor + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+    LinSrgb<T>: RelativeLuminance<Scalar = T>,
+{
+    #[inline]
+    fn tone_map_value(&self, value: T) -> T {
+        self.0.tone_map_value(value)
+    }
+
+    #[inline]
+    fn tone_map(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        let ratio = self.luminance_ratio(color.get_relative_luminance());
+        color * ratio
+    }
+}
+
+#[cfg(feature = "approx")]
+#[cfg(test)]
+mod test {
+    use super::{tone_map_slice, AcesFilmic, ExtendedReinhard, Luminance, Reinhard, ToneMap};
+    use crate::{LinSrgb, RelativeLuminance, Xyz};
+
+    fn inputs() -> impl Iterator<Item = f64> {
+        (0..=64).map(|step| step as f64 * 0.25)
+    }
+
+    #[test]
+    fn reinhard() {
+        assert_relative_eq!(Reinhard.tone_map_value(0.0f64), 0.0);
+        assert_relative_eq!(Reinhard.tone_map_value(1.0f64), 0.5);
+        assert_relative_eq!(Reinhard.tone_map_value(3.0f64), 0.75);
+        assert_relative_eq!(Reinhard.tone_map_value(-2.0f64), 0.0);
+    }
+
+    #[test]
+    fn extended_reinhard() {
+        let operator = ExtendedReinhard::new(4.0f64);
+        assert_relative_eq!(operator.tone_map_value(0.0), 0.0);
+        assert_relative_eq!(operator.tone_map_value(1.0), 0.53125);
+        assert_relative_eq!(operator.tone_map_value(4.0), 1.0);
+        assert_relative_eq!(operator.tone_map_value(8.0), 1.0);
+    }
+
+    #[test]
+    fn aces_filmic() {
+        assert_relative_eq!(AcesFilmic.tone_map_value(0.0f64), 0.0);
+        assert_relative_eq!(
+            AcesFilmic.tone_map_value(0.18f64),
+            0.2668989203894968,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            AcesFilmic.tone_map_value(1.0f64),
+            0.8037974683544302,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(AcesFilmic.tone_map_value(16.0f64), 1.0);
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let extended = ExtendedReinhard::new(4.0);
+        let operators: [&dyn ToneMap<f64>; 3] = [&Reinhard, &extended, &AcesFilmic];
+
+        for operator in operators {
+            for input in inputs() {
+                let color = operator.tone_map(LinSrgb::new(input, input * 0.5, input * 0.1));
+
+                for channel in [color.red, color.green, color.blue] {
+                    assert!(
+                        (0.0..=1.0).contains(&channel),
+                        "{} was mapped to {}",
+                        input,
+                        channel
+                    );
+                }
+
+                let luminance = Luminance(operator)
+                    .tone_map(LinSrgb::new(input, input, input))
+                    .get_relative_luminance();
+                assert!((0.0..=1.0 + 1e-12).contains(&luminance));
+            }
+        }
+    }
+
+    #[test]
+    fn luminance_keeps_chromaticity() {
+        let color = LinSrgb::new(6.0f64, 3.0, 1.5);
+        let mapped = Luminance(AcesFilmic).tone_map(color);
+
+        assert_relative_eq!(mapped.red / mapped.green, 2.0, epsilon = 0.000001);
+        assert_relative_eq!(mapped.green / mapped.blue, 2.0, epsilon = 0.000001);
+        assert_relative_eq!(
+            mapped.get_relative_luminance(),
+            AcesFilmic.tone_map_value(color.get_relative_luminance()),
+            epsilon = 0.000001
+        );
+
+        assert_eq!(
+            Luminance(Reinhard).tone_map(LinSrgb::new(0.0f64, 0.0, 0.0)),
+            LinSrgb::new(0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn luminance_xyz() {
+        let color: Xyz<crate::white_point::D65, f64> = Xyz::new(4.0, 3.0, 2.0);
+        let mapped = Luminance(Reinhard).tone_map_xyz(color);
+
+        assert_relative_eq!(mapped, Xyz::new(1.0, 0.75, 0.5));
+        assert_eq!(
+            Luminance(Reinhard).tone_map_xyz(Xyz::<crate::white_point::D65, _>::new(0.0, 0.0, 0.0)),
+            Xyz::new(0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn slice() {
+        let colors = [
+            LinSrgb::new(8.0f64, 0.5, 0.0),
+            LinSrgb::new(1.0, 2.0, 4.0),
+            LinSrgb::new(0.2, 0.1, 16.0),
+        ];
+
+        let mut mapped = colors;
+        tone_map_slice(&mut mapped, AcesFilmic);
+
+        for (color, mapped) in colors.iter().zip(mapped.iter()) {
+            assert_relative_eq!(*mapped, AcesFilmic.tone_map(*color));
+        }
+    }
+}
@@ -5,8 +5,22 @@
 //! may be lit by incandescent lights, which are relatively orange compared to
 //! daylight. Defining "white" as daylight will give unacceptable results when
 //! attempting to color-correct a photograph taken with incandescent lighting.
-
-use crate::{num::Real, xyz::meta::HasXyzMeta, Xyz};
+//!
+//! The white points are defined for the CIE 1931 2° standard observer, except
+//! for the ones with a `Degree10` suffix, such as [`D65Degree10`], which are
+//! for the CIE 1964 10° standard observer. The formulas of color spaces like
+//! [`Lab`][crate::Lab] and [`Luv`][crate::Luv] are the same for both
+//! observers, so using a 10° white point is enough to get 10° values, as long
+//! as the [`Xyz`] input is measured with the 10° color matching functions too.
+//! Some color spaces, such as [`Oklab`][crate::Oklab], are defined for a
+//! specific white point and observer and can't be used with any other.
+
+use crate::{
+    convert::FromColorUnclamped,
+    num::{Abs, Arithmetics, Real},
+    xyz::meta::HasXyzMeta,
+    Xyz, Yxy,
+};
 
 /// Represents an unspecified reference white point.
 ///
@@ -33,6 +47,181 @@ impl HasXyzMeta for Any {
 pub trait WhitePoint<T>: 'static {
     /// Get the Xyz chromaticity co-ordinates for the white point.
     fn get_xyz() -> Xyz<Any, T>;
+
+    /// Get a human readable name for the white point.
+    ///
+    /// The default is the name of the implementing type, as given by
+    /// [`core::any::type_name`].
+    #[inline]
+    fn name() -> &'static str {
+        core::any::type_name::<Self>()
+    }
+
+    /// Get the name and Xyz co-ordinates of the white point as a runtime
+    /// value.
+    ///
+    /// ```
+    /// use palette::white_point::{WhitePoint, D65};
+    ///
+    /// let value = <D65 as WhitePoint<f64>>::value();
+    /// assert_eq!(value.name, "D65");
+    /// assert_eq!(value.xyz, D65::get_xyz());
+    /// ```
+    #[inline]
+    fn value() -> WhitePointValue<T>
+    where
+        Self: Sized,
+    {
+        WhitePointValue {
+            name: Self::name(),
+            xyz: Self::get_xyz(),
+        }
+    }
+}
+
+/// A white point's name and Xyz co-ordinates, for selecting white points at
+/// runtime.
+///
+/// The type level white points, such as [`D65`], are what the color types use,
+/// but they can't be listed or picked from a user interface. A
+/// `WhitePointValue` can be created from any of them with
+/// [`WhitePoint::value`], and [`built_in`] lists the ones in this module.
+///
+/// Two values are equal if their Xyz co-ordinates are within `0.00001` of each
+/// other, which is the precision of the built-in white points. The names are
+/// not compared.
+///
+/// ```
+/// use palette::white_point::{built_in, WhitePoint, D65};
+///
+/// let names: Vec<_> = built_in().iter().map(|white_point| white_point.name).collect();
+/// assert!(names.contains(&"D65"));
+///
+/// let d65 = built_in().iter().find(|white_point| white_point.name == "D65").unwrap();
+/// assert_eq!(*d65, D65::value());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct WhitePointValue<T> {
+    /// The name of the white point, such as `"D65"`.
+    pub name: &'static str,
+
+    /// The Xyz co-ordinates of the white point, normalized to `y = 1.0`.
+    pub xyz: Xyz<Any, T>,
+}
+
+impl<T> WhitePointValue<T> {
+    /// Get the chromaticity co-ordinates of the white point as `Yxy`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::white_point::{WhitePoint, D65};
+    ///
+    /// let yxy = <D65 as WhitePoint<f64>>::value().chromaticity();
+    /// assert_relative_eq!(yxy.x, 0.31271, epsilon = 0.0001);
+    /// assert_relative_eq!(yxy.y, 0.32902, epsilon = 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn chromaticity(self) -> Yxy<Any, T>
+    where
+        Yxy<Any, T>: FromColorUnclamped<Xyz<Any, T>>,
+    {
+        Yxy::from_color_unclamped(self.xyz)
+    }
+}
+
+impl<T> PartialEq for WhitePointValue<T>
+where
+    T: Real + Abs + Arithmetics + PartialOrd + Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let tolerance = T::from_f64(0.00001);
+        let is_close = |a: &T, b: &T| (a.clone() - b.clone()).abs() <= tolerance;
+
+        is_close(&self.xyz.x, &other.xyz.x)
+            && is_close(&self.xyz.y, &other.xyz.y)
+            && is_close(&self.xyz.z, &other.xyz.z)
+    }
+}
+
+/// Get the name and Xyz co-ordinates of all white points in this module.
+///
+/// This is useful for letting users pick a white point at runtime. The values
+/// are the same as from [`WhitePoint::value`].
+///
+/// ```
+/// use palette::white_point::built_in;
+///
+/// for white_point in built_in() {
+///     println!("{}: {:?}", white_point.name, white_point.chromaticity());
+/// }
+/// ```
+#[must_use]
+pub fn built_in() -> &'static [WhitePointValue<f64>] {
+    const BUILT_IN: &[WhitePointValue<f64>] = &[
+        WhitePointValue {
+            name: "A",
+            xyz: Xyz::new(1.09850, 1.0, 0.35585),
+        },
+        WhitePointValue {
+            name: "B",
+            xyz: Xyz::new(0.99093, 1.0, 0.85313),
+        },
+        WhitePointValue {
+            name: "C",
+            xyz: Xyz::new(0.98074, 1.0, 1.18232),
+        },
+        WhitePointValue {
+            name: "D50",
+            xyz: Xyz::new(0.96422, 1.0, 0.82521),
+        },
+        WhitePointValue {
+            name: "D55",
+            xyz: Xyz::new(0.95682, 1.0, 0.92149),
+        },
+        WhitePointValue {
+            name: "D65",
+            xyz: Xyz::new(0.95047, 1.0, 1.08883),
+        },
+        WhitePointValue {
+            name: "D75",
+            xyz: Xyz::new(0.94972, 1.0, 1.22638),
+        },
+        WhitePointValue {
+            name: "E",
+            xyz: Xyz::new(1.0, 1.0, 1.0),
+        },
+        WhitePointValue {
+            name: "F2",
+            xyz: Xyz::new(0.99145, 1.0, 0.67316),
+        },
+        WhitePointValue {
+            name: "F7",
+            xyz: Xyz::new(0.95017, 1.0, 1.08630),
+        },
+        WhitePointValue {
+            name: "F11",
+            xyz: Xyz::new(1.00899, 1.0, 0.64262),
+        },
+        WhitePointValue {
+            name: "D50 (10°)",
+            xyz: Xyz::new(0.96720, 1.0, 0.81427),
+        },
+        WhitePointValue {
+            name: "D55 (10°)",
+            xyz: Xyz::new(0.95799, 1.0, 0.90926),
+        },
+        WhitePointValue {
+            name: "D65 (10°)",
+            xyz: Xyz::new(0.94811, 1.0, 1.07304),
+        },
+        WhitePointValue {
+            name: "D75 (10°)",
+            xyz: Xyz::new(0.94416, 1.0, 1.20641),
+        },
+    ];
+
+    BUILT_IN
 }
 
 /// CIE standard illuminant A
@@ -48,6 +237,11 @@ impl<T: Real> WhitePoint<T> for A {
     fn get_xyz() -> Xyz<Any, T> {
         Xyz::new(T::from_f64(1.09850), T::from_f64(1.0), T::from_f64(0.35585))
     }
+
+    #[inline]
+    fn name() -> &'static str {
+        "A"
+    }
 }
 
 impl HasXyzMeta for A {
@@ -62,7 +256,12 @@ pub struct B;
 impl<T: Real> WhitePoint<T> for B {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.99072), T::from_f64(1.0), T::from_f64(0.85223))
+        Xyz::new(T::from_f64(0.99093), T::from_f64(1.0), T::from_f64(0.85313))
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "B"
     }
 }
 
@@ -81,6 +280,11 @@ impl<T: Real> WhitePoint<T> for C {
     fn get_xyz() -> Xyz<Any, T> {
         Xyz::new(T::from_f64(0.98074), T::from_f64(1.0), T::from_f64(1.18232))
     }
+
+    #[inline]
+    fn name() -> &'static str {
+        "C"
+    }
 }
 
 impl HasXyzMeta for C {
@@ -98,6 +302,11 @@ impl<T: Real> WhitePoint<T> for D50 {
     fn get_xyz() -> Xyz<Any, T> {
         Xyz::new(T::from_f64(0.96422), T::from_f64(1.0), T::from_f64(0.82521))
     }
+
+    #[inline]
+    fn name() -> &'static str {
+        "D50"
+    }
 }
 
 impl HasXyzMeta for D50 {
@@ -115,6 +324,11 @@ impl<T: Real> WhitePoint<T> for D55 {
     fn get_xyz() -> Xyz<Any, T> {
         Xyz::new(T::from_f64(0.95682), T::from_f64(1.0), T::from_f64(0.92149))
     }
+
+    #[inline]
+    fn name() -> &'static str {
+        "D55"
+    }
 }
 
 impl HasXyzMeta for D55 {
@@ -132,6 +346,11 @@ impl<T: Real> WhitePoint<T> for D65 {
     fn get_xyz() -> Xyz<Any, T> {
         Xyz::new(T::from_f64(0.95047), T::from_f64(1.0), T::from_f64(1.08883))
     }
+
+    #[inline]
+    fn name() -> &'static str {
+        "D65"
+    }
 }
 
 impl HasXyzMeta for D65 {
@@ -149,6 +368,11 @@ impl<T: Real> WhitePoint<T> for D75 {
     fn get_xyz() -> Xyz<Any, T> {
         Xyz::new(T::from_f64(0.94972), T::from_f64(1.0), T::from_f64(1.22638))
     }
+
+    #[inline]
+    fn name() -> &'static str {
+        "D75"
+    }
 }
 
 impl HasXyzMeta for D75 {
@@ -166,6 +390,11 @@ impl<T: Real> WhitePoint<T> for E {
     fn get_xyz() -> Xyz<Any, T> {
         Xyz::new(T::from_f64(1.0), T::from_f64(1.0), T::from_f64(1.0))
     }
+
+    #[inline]
+    fn name() -> &'static str {
+        "E"
+    }
 }
 
 impl HasXyzMeta for E {
@@ -180,7 +409,12 @@ pub struct F2;
 impl<T: Real> WhitePoint<T> for F2 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.99186), T::from_f64(1.0), T::from_f64(0.67393))
+        Xyz::new(T::from_f64(0.99145), T::from_f64(1.0), T::from_f64(0.67316))
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "F2"
     }
 }
 
@@ -196,7 +430,12 @@ pub struct F7;
 impl<T: Real> WhitePoint<T> for F7 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.95041), T::from_f64(1.0), T::from_f64(1.08747))
+        Xyz::new(T::from_f64(0.95017), T::from_f64(1.0), T::from_f64(1.08630))
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "F7"
     }
 }
 
@@ -212,7 +451,12 @@ pub struct F11;
 impl<T: Real> WhitePoint<T> for F11 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(1.00962), T::from_f64(1.0), T::from_f64(0.64350))
+        Xyz::new(T::from_f64(1.00899), T::from_f64(1.0), T::from_f64(0.64262))
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "F11"
     }
 }
 
@@ -229,7 +473,12 @@ pub struct D50Degree10;
 impl<T: Real> WhitePoint<T> for D50Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.9672), T::from_f64(1.0), T::from_f64(0.8143))
+        Xyz::new(T::from_f64(0.96720), T::from_f64(1.0), T::from_f64(0.81427))
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "D50 (10°)"
     }
 }
 
@@ -246,7 +495,12 @@ pub struct D55Degree10;
 impl<T: Real> WhitePoint<T> for D55Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.958), T::from_f64(1.0), T::from_f64(0.9093))
+        Xyz::new(T::from_f64(0.95799), T::from_f64(1.0), T::from_f64(0.90926))
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "D55 (10°)"
     }
 }
 
@@ -263,7 +517,12 @@ pub struct D65Degree10;
 impl<T: Real> WhitePoint<T> for D65Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.9481), T::from_f64(1.0), T::from_f64(1.073))
+        Xyz::new(T::from_f64(0.94811), T::from_f64(1.0), T::from_f64(1.07304))
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "D65 (10°)"
     }
 }
 
@@ -280,10 +539,131 @@ pub struct D75Degree10;
 impl<T: Real> WhitePoint<T> for D75Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.94416), T::from_f64(1.0), T::from_f64(1.2064))
+        Xyz::new(T::from_f64(0.94416), T::from_f64(1.0), T::from_f64(1.20641))
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "D75 (10°)"
     }
 }
 
 impl HasXyzMeta for D75Degree10 {
     type XyzMeta = Self;
 }
+
+#[cfg(test)]
+mod test {
+    use super::{built_in, WhitePoint, WhitePointValue};
+
+    // CIE 1931 and 1964 chromaticity co-ordinates of the standard illuminants.
+    const CIE_CHROMATICITIES: &[(&str, f64, f64)] = &[
+        ("A", 0.44757, 0.40745),
+        ("B", 0.34842, 0.35161),
+        ("C", 0.31006, 0.31616),
+        ("D50", 0.34567, 0.35850),
+        ("D55", 0.33242, 0.34743),
+        ("D65", 0.31271, 0.32902),
+        ("D75", 0.29902, 0.31485),
+        ("E", 1.0 / 3.0, 1.0 / 3.0),
+        ("F2", 0.37208, 0.37529),
+        ("F7", 0.31292, 0.32933),
+        ("F11", 0.38052, 0.37713),
+        ("D50 (10°)", 0.34773, 0.35952),
+        ("D55 (10°)", 0.33411, 0.34877),
+        ("D65 (10°)", 0.31382, 0.33100),
+        ("D75 (10°)", 0.29968, 0.31740),
+    ];
+
+    #[test]
+    fn built_in_chromaticities() {
+        assert_eq!(built_in().len(), CIE_CHROMATICITIES.len());
+
+        for &(name, x, y) in CIE_CHROMATICITIES {
+            let white_point = built_in()
+                .iter()
+                .find(|white_point| white_point.name == name)
+                .unwrap_or_else(|| panic!("{} is missing", name));
+            let yxy = white_point.chromaticity();
+
+            assert_relative_eq!(yxy.x, x, epsilon = 0.0001);
+            assert_relative_eq!(yxy.y, y, epsilon = 0.0001);
+            assert_relative_eq!(yxy.luma, 1.0);
+        }
+    }
+
+    #[test]
+    fn degree_10_tristimulus_values() {
+        // ASTM E308 tristimulus values for the CIE 1964 10° observer, with Y
+        // normalized to 100.
+        fn check<W: WhitePoint<f64>>(x: f64, z: f64) {
+            let xyz = W::get_xyz();
+            assert_relative_eq!(xyz.x * 100.0, x, epsilon = 0.0000001);
+            assert_relative_eq!(xyz.y * 100.0, 100.0);
+            assert_relative_eq!(xyz.z * 100.0, z, epsilon = 0.0000001);
+        }
+
+        check::<super::D50Degree10>(96.720, 81.427);
+        check::<super::D55Degree10>(95.799, 90.926);
+        check::<super::D65Degree10>(94.811, 107.304);
+        check::<super::D75Degree10>(94.416, 120.641);
+    }
+
+    #[test]
+    fn built_in_matches_types() {
+        fn check<W: WhitePoint<f64>>(built_in: &WhitePointValue<f64>) {
+            let value = W::value();
+            assert_eq!(built_in.name, value.name);
+            assert_eq!(*built_in, value);
+        }
+
+        let all = built_in();
+        check::<super::A>(&all[0]);
+        check::<super::B>(&all[1]);
+        check::<super::C>(&all[2]);
+        check::<super::D50>(&all[3]);
+        check::<super::D55>(&all[4]);
+        check::<super::D65>(&all[5]);
+        check::<super::D75>(&all[6]);
+        check::<super::E>(&all[7]);
+        check::<super::F2>(&all[8]);
+        check::<super::F7>(&all[9]);
+        check::<super::F11>(&all[10]);
+        check::<super::D50Degree10>(&all[11]);
+        check::<super::D55Degree10>(&all[12]);
+        check::<super::D65Degree10>(&all[13]);
+        check::<super::D75Degree10>(&all[14]);
+    }
+
+    #[test]
+    fn value_equality_tolerance() {
+        let d65 = <super::D65 as WhitePoint<f64>>::value();
+
+        let mut close = d65;
+        close.name = "almost D65";
+        close.xyz.x += 0.000005;
+        assert_eq!(d65, close);
+
+        let mut far = d65;
+        far.xyz.z += 0.0001;
+        assert_ne!(d65, far);
+
+        assert_ne!(d65, <super::D50 as WhitePoint<f64>>::value());
+    }
+
+    #[test]
+    fn default_name() {
+        struct Custom;
+        impl WhitePoint<f64> for Custom {
+            fn get_xyz() -> crate::Xyz<super::Any, f64> {
+                crate::Xyz::new(1.0, 1.0, 1.0)
+            }
+        }
+
+        assert!(Custom::name().ends_with("Custom"));
+        assert_eq!(
+            <crate::encoding::p3::DciP3 as WhitePoint<f64>>::name(),
+            "DCI-P3"
+        );
+    }
+}
@@ -1,7 +1,11 @@
 //! Types for the CIE 1931 XYZ color space.
 
+pub use self::chromaticity::UvChromaticity;
+
 pub mod meta;
 
+mod chromaticity;
+
 use core::{
     any::TypeId,
     marker::PhantomData,
@@ -19,13 +23,13 @@ use crate::{
         Lms,
     },
     luma::LumaStandard,
-    matrix::{matrix_map, multiply_3x3_and_vec3, rgb_to_xyz_matrix},
+    matrix::{matrix_map, multiply_3x3_and_vec3, rgb_to_xyz_matrix, Mat3},
     num::{Arithmetics, FromScalar, IsValidDivisor, One, PartialCmp, Powi, Real, Recip, Zero},
     oklab,
     rgb::{Primaries, Rgb, RgbSpace, RgbStandard},
     stimulus::{Stimulus, StimulusColor},
     white_point::{Any, WhitePoint, D65},
-    Alpha, Lab, Luma, Luv, Oklab, Yxy,
+    Alpha, Lab, Luma, Luv, Oklab, RelativeLuminance, Yxy,
 };
 
 use self::meta::HasXyzMeta;
@@ -106,6 +110,51 @@ impl<Wp, T> Xyz<Wp, T> {
         self / y
     }
 
+    /// Create a CIE XYZ color from absolute tristimulus values, such as in
+    /// cd/m², relative to the luminance of the reference white.
+    ///
+    /// All components are divided by `reference_white`, so a color with
+    /// `y == reference_white` gets `y == 1.0`. This is purely arithmetical and
+    /// doesn't change the meaning of the color type. The reference white is
+    /// often 100 cd/m² for standard dynamic range content and 203 cd/m² for
+    /// the diffuse white in high dynamic range content.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::Xyz;
+    ///
+    /// let color: Xyz = Xyz::from_absolute_luminance(47.5, 50.0, 54.4, 100.0);
+    /// assert_relative_eq!(color, Xyz::new(0.475, 0.5, 0.544));
+    /// ```
+    #[inline]
+    pub fn from_absolute_luminance(x: T, y: T, z: T, reference_white: T) -> Self
+    where
+        T: Div<Output = T> + Clone,
+    {
+        Self::new(x, y, z) / reference_white
+    }
+
+    /// Get the absolute luminance, such as in cd/m², given the luminance of
+    /// the reference white.
+    ///
+    /// This is `y * reference_white` and the inverse of
+    /// [`from_absolute_luminance`](Xyz::from_absolute_luminance).
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::Xyz;
+    ///
+    /// let color: Xyz = Xyz::new(0.475, 0.5, 0.544);
+    /// assert_relative_eq!(color.absolute_luminance(100.0), 50.0);
+    /// ```
+    #[inline]
+    pub fn absolute_luminance(self, reference_white: T) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.y * reference_white
+    }
+
     /// Changes the reference white point without changing the color value.
     ///
     /// This function doesn't change the numerical values, and thus the color it
@@ -119,6 +168,68 @@ impl<Wp, T> Xyz<Wp, T> {
     pub fn with_white_point<NewWp>(self) -> Xyz<NewWp, T> {
         Xyz::new(self.x, self.y, self.z)
     }
+
+    /// Calculate the CIE 1976 u'v' chromaticity coordinates of this color.
+    ///
+    /// The result is `(0, 0)` if `X + 15Y + 3Z` is zero or not finite.
+    ///
+    /// ```
+    /// use palette::{white_point::{WhitePoint, D65}, Xyz};
+    ///
+    /// let white: Xyz<D65, f64> = D65::get_xyz().with_white_point();
+    /// let uv = white.chromaticity_uv();
+    ///
+    /// assert!((uv.u - 0.1978).abs() < 0.0001);
+    /// assert!((uv.v - 0.4683).abs() < 0.0001);
+    /// ```
+    pub fn chromaticity_uv(self) -> UvChromaticity<T>
+    where
+        T: Real + Zero + IsValidDivisor + Arithmetics + Clone,
+        T::Mask: LazySelect<T> + Clone,
+    {
+        let Xyz { x, y, z, .. } = self;
+        let denominator = x.clone() + T::from_f64(15.0) * &y + T::from_f64(3.0) * z;
+
+        let mask = denominator.is_valid_divisor();
+        UvChromaticity {
+            u: lazy_select! {
+                if mask.clone() => T::from_f64(4.0) * x / &denominator,
+                else => T::zero(),
+            },
+            v: lazy_select! {
+                if mask => T::from_f64(9.0) * y / denominator,
+                else => T::zero(),
+            },
+        }
+    }
+
+    /// Create a CIE XYZ color from CIE 1976 u'v' chromaticity coordinates and
+    /// a luminance (Y) value.
+    ///
+    /// The result is black if `v` is zero or not finite.
+    pub fn from_uv_luminance(u: T, v: T, y: T) -> Self
+    where
+        T: Real + Zero + IsValidDivisor + Arithmetics + Clone,
+        T::Mask: LazySelect<T> + Clone,
+    {
+        let denominator = T::from_f64(4.0) * &v;
+
+        let mask = denominator.is_valid_divisor();
+        let y_ratio = lazy_select! {
+            if mask.clone() => y.clone() / &denominator,
+            else => T::zero(),
+        };
+
+        Xyz {
+            x: T::from_f64(9.0) * &u * &y_ratio,
+            z: (T::from_f64(12.0) - T::from_f64(3.0) * u - T::from_f64(20.0) * v) * y_ratio,
+            y: lazy_select! {
+                if mask => y,
+                else => T::zero(),
+            },
+            white_point: PhantomData,
+        }
+    }
 }
 
 impl<Wp, T> Xyz<Wp, T>
@@ -183,6 +294,33 @@ impl<Wp, T> Xyz<Wp, T> {
         Matrix3::from_array(matrix_map(transform_matrix, T::from_scalar))
     }
 
+    /// Multiply the components with `matrix`, as if they were a column vector.
+    ///
+    /// This doesn't change the white point type, so any change of white point
+    /// needs to be represented in the matrix as well as in the resulting type.
+    ///
+    /// ```
+    /// use palette::{matrix::Mat3, Xyz};
+    ///
+    /// let matrix = Mat3::from_rows([
+    ///     [0.5, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 2.0],
+    /// ]);
+    ///
+    /// let xyz: Xyz = Xyz::new(0.2, 0.4, 0.6).transform_by(&matrix);
+    /// assert_eq!(xyz, Xyz::new(0.1, 0.4, 1.2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn transform_by(self, matrix: &Mat3<T>) -> Self
+    where
+        T: Arithmetics + Clone,
+    {
+        let [x, y, z] = matrix.clone().mul_vector([self.x, self.y, self.z]);
+        Xyz::new(x, y, z)
+    }
+
     /// Produce a conversion matrix from [`Lms`] to [`Xyz`].
     #[inline]
     pub fn matrix_from_lms<M>() -> Matrix3<Lms<M, T>, Self>
@@ -278,8 +416,16 @@ where
 {
     #[inline]
     fn from_color_unclamped(color: Rgb<S, T>) -> Self {
+        let color = color.into_linear();
+
+        if let Some(transform_matrix) = S::Space::rgb_to_xyz_matrix() {
+            let (red, green, blue) = color.into_components();
+            let [x, y, z] = T::mul_f64_matrix(transform_matrix, [red, green, blue]);
+            return Xyz::new(x, y, z);
+        }
+
         let transform_matrix = Self::matrix_from_rgb::<Linear<S::Space>>();
-        transform_matrix.convert_once(color.into_linear())
+        transform_matrix.convert_once(color)
     }
 }
 
@@ -434,12 +580,25 @@ where
 
 impl_tuple_conversion!(Xyz<Wp> as (T, T, T));
 
+impl<Wp, T> RelativeLuminance for Xyz<Wp, T>
+where
+    T: Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_relative_luminance(&self) -> T {
+        self.y.clone()
+    }
+}
+
 impl_is_within_bounds! {
     Xyz<Wp> {
         x => [Self::min_x(), Self::max_x()],
         y => [Self::min_y(), Self::max_y()],
         z => [Self::min_z(), Self::max_z()]
     }
+    new(x: T, y: T, z: T)
     where
         T: Zero,
         Wp: WhitePoint<T>
@@ -493,6 +652,7 @@ impl_color_add!(Xyz<Wp>, [x, y, z], white_point);
 impl_color_sub!(Xyz<Wp>, [x, y, z], white_point);
 impl_color_mul!(Xyz<Wp>, [x, y, z], white_point);
 impl_color_div!(Xyz<Wp>, [x, y, z], white_point);
+impl_component_wise!(Xyz<Wp>, [x, y, z], white_point);
 
 impl_array_casts!(Xyz<Wp, T>, [T; 3]);
 impl_simd_array_conversion!(Xyz<Wp>, [x, y, z], white_point);
@@ -500,6 +660,7 @@ impl_struct_of_array_traits!(Xyz<Wp>, [x, y, z], white_point);
 
 impl_copy_clone!(Xyz<Wp>, [x, y, z], white_point);
 impl_eq!(Xyz<Wp>, [x, y, z]);
+impl_select!(Xyz<Wp>, [x, y, z], white_point);
 
 #[allow(deprecated)]
 impl<Wp, T> crate::RelativeContrast for Xyz<Wp, T>
@@ -0,0 +1,269 @@
+//! CIE 1976 u'v' chromaticity coordinates.
+
+use crate::{
+    bool_mask::LazySelect,
+    num::{Arithmetics, Clamp, IsValidDivisor, One, Real, Zero},
+    white_point::Any,
+    Mix, MixAssign, Xyz, Yxy,
+};
+
+/// CIE 1976 u'v' chromaticity coordinates.
+///
+/// The u'v' chromaticity diagram is a more perceptually uniform alternative to
+/// the CIE 1931 xy diagram, where distances correspond better to perceived
+/// differences. It's commonly used for correlated color temperature (CCT) and
+/// Duv calculations, as well as for display calibration.
+///
+/// The coordinates can be calculated from [`Xyz`](crate::Xyz) with
+/// [`Xyz::chromaticity_uv`](crate::Xyz::chromaticity_uv), and converted back
+/// into [`Yxy`] or [`Xyz`](crate::Xyz) when combined with a luminance value.
+///
+/// ```
+/// use palette::{white_point::{WhitePoint, D65}, Xyz};
+///
+/// let white: Xyz<D65, f64> = D65::get_xyz().with_white_point();
+/// let uv = white.chromaticity_uv();
+///
+/// assert!((uv.u - 0.1978).abs() < 0.0001);
+/// assert!((uv.v - 0.4683).abs() < 0.0001);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct UvChromaticity<T = f32> {
+    /// The u' coordinate, calculated as 4X/(X+15Y+3Z).
+    pub u: T,
+
+    /// The v' coordinate, calculated as 9Y/(X+15Y+3Z).
+    pub v: T,
+}
+
+impl<T> UvChromaticity<T> {
+    /// Create u'v' chromaticity coordinates.
+    pub const fn new(u: T, v: T) -> Self {
+        UvChromaticity { u, v }
+    }
+
+    /// Convert to a `(u', v')` tuple.
+    pub fn into_components(self) -> (T, T) {
+        (self.u, self.v)
+    }
+
+    /// Convert from a `(u', v')` tuple.
+    pub fn from_components((u, v): (T, T)) -> Self {
+        Self::new(u, v)
+    }
+
+    /// Calculate the u'v' coordinates from CIE 1931 xy chromaticity
+    /// coordinates.
+    ///
+    /// The result is `(0, 0)` if the coordinates can't be converted.
+    pub fn from_xy(x: T, y: T) -> Self
+    where
+        T: Real + Zero + IsValidDivisor + Arithmetics + Clone,
+        T::Mask: LazySelect<T> + Clone,
+    {
+        let denominator = T::from_f64(12.0) * &y - T::from_f64(2.0) * &x + T::from_f64(3.0);
+
+        let mask = denominator.is_valid_divisor();
+        UvChromaticity {
+            u: lazy_select! {
+                if mask.clone() => T::from_f64(4.0) * x / &denominator,
+                else => T::zero(),
+            },
+            v: lazy_select! {
+                if mask => T::from_f64(9.0) * y / denominator,
+                else => T::zero(),
+            },
+        }
+    }
+
+    /// Calculate the CIE 1931 xy chromaticity coordinates, as an `(x, y)`
+    /// tuple.
+    ///
+    /// The result is `(0, 0)` if the coordinates can't be converted.
+    pub fn into_xy(self) -> (T, T)
+    where
+        T: Real + Zero + IsValidDivisor + Arithmetics + Clone,
+        T::Mask: LazySelect<T> + Clone,
+    {
+        let UvChromaticity { u, v } = self;
+        let denominator = T::from_f64(6.0) * &u - T::from_f64(16.0) * &v + T::from_f64(12.0);
+
+        let mask = denominator.is_valid_divisor();
+        let x = lazy_select! {
+            if mask.clone() => T::from_f64(9.0) * u / &denominator,
+            else => T::zero(),
+        };
+        let y = lazy_select! {
+            if mask => T::from_f64(4.0) * v / denominator,
+            else => T::zero(),
+        };
+
+        (x, y)
+    }
+
+    /// Convert into [`Yxy`], with the provided luminance.
+    ///
+    /// ```
+    /// use palette::{xyz::UvChromaticity, Yxy};
+    ///
+    /// let yxy = UvChromaticity::new(0.2f32, 0.45).into_yxy(0.5);
+    /// let uv = UvChromaticity::from_xy(yxy.x, yxy.y);
+    ///
+    /// assert!((uv.u - 0.2).abs() < 0.0001);
+    /// assert!((uv.v - 0.45).abs() < 0.0001);
+    /// assert_eq!(yxy.luma, 0.5);
+    /// ```
+    pub fn into_yxy(self, luma: T) -> Yxy<Any, T>
+    where
+        T: Real + Zero + IsValidDivisor + Arithmetics + Clone,
+        T::Mask: LazySelect<T> + Clone,
+    {
+        let (x, y) = self.into_xy();
+        Yxy::new(x, y, luma)
+    }
+}
+
+impl<T> From<UvChromaticity<T>> for Yxy<Any, T>
+where
+    T: Real + Zero + One + IsValidDivisor + Arithmetics + Clone,
+    T::Mask: LazySelect<T> + Clone,
+{
+    /// Convert into [`Yxy`], with a luminance of 1.
+    #[inline]
+    fn from(chromaticity: UvChromaticity<T>) -> Self {
+        chromaticity.into_yxy(T::one())
+    }
+}
+
+impl<Wp, T> From<Xyz<Wp, T>> for UvChromaticity<T>
+where
+    T: Real + Zero + IsValidDivisor + Arithmetics + Clone,
+    T::Mask: LazySelect<T> + Clone,
+{
+    #[inline]
+    fn from(color: Xyz<Wp, T>) -> Self {
+        color.chromaticity_uv()
+    }
+}
+
+impl<Wp, T> From<Yxy<Wp, T>> for UvChromaticity<T>
+where
+    T: Real + Zero + IsValidDivisor + Arithmetics + Clone,
+    T::Mask: LazySelect<T> + Clone,
+{
+    #[inline]
+    fn from(color: Yxy<Wp, T>) -> Self {
+        Self::from_xy(color.x, color.y)
+    }
+}
+
+impl<T> From<(T, T)> for UvChromaticity<T> {
+    #[inline]
+    fn from(components: (T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T> From<UvChromaticity<T>> for (T, T) {
+    #[inline]
+    fn from(chromaticity: UvChromaticity<T>) -> (T, T) {
+        chromaticity.into_components()
+    }
+}
+
+impl<T> Mix for UvChromaticity<T>
+where
+    T: Zero + One + Arithmetics + Clamp + Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix(self, other: Self, factor: T) -> Self {
+        let factor = crate::clamp(factor, T::zero(), T::one());
+
+        UvChromaticity {
+            u: self.u.clone() + (other.u - self.u) * &factor,
+            v: self.v.clone() + (other.v - self.v) * factor,
+        }
+    }
+}
+
+impl<T> MixAssign for UvChromaticity<T>
+where
+    T: Zero + One + Arithmetics + Clamp + Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix_assign(&mut self, other: Self, factor: T) {
+        *self = self.clone().mix(other, factor);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::UvChromaticity;
+    use crate::{white_point::D65, Mix, Xyz};
+
+    #[cfg(feature = "approx")]
+    mod conversion {
+        use crate::{
+            convert::FromColorUnclamped,
+            white_point::{Any, WhitePoint, D50, D65},
+            xyz::UvChromaticity,
+            Xyz, Yxy,
+        };
+
+        #[test]
+        fn d65_uv() {
+            let white: Xyz<D65, f64> = D65::get_xyz().with_white_point();
+            let uv = white.chromaticity_uv();
+
+            assert_relative_eq!(uv.u, 0.1978, epsilon = 0.0001);
+            assert_relative_eq!(uv.v, 0.4683, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn xyz_roundtrip() {
+            let xyz = Xyz::<D50, f64>::new(0.3, 0.4, 0.5);
+            let uv = xyz.chromaticity_uv();
+
+            assert_relative_eq!(Xyz::from_uv_luminance(uv.u, uv.v, xyz.y), xyz);
+        }
+
+        #[test]
+        fn yxy_roundtrip() {
+            let white: Xyz<D65, f64> = D65::get_xyz().with_white_point();
+            let yxy: Yxy<Any, f64> = white.chromaticity_uv().into();
+            let expected = Yxy::<D65, f64>::from_color_unclamped(white);
+
+            assert_relative_eq!(yxy.x, expected.x, epsilon = 0.000001);
+            assert_relative_eq!(yxy.y, expected.y, epsilon = 0.000001);
+            assert_relative_eq!(yxy.luma, 1.0);
+
+            let (x, y) = expected.chromaticity();
+            let uv = UvChromaticity::from_xy(x, y);
+            assert_relative_eq!(uv.u, 0.1978, epsilon = 0.0001);
+            assert_relative_eq!(uv.v, 0.4683, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn black() {
+        let uv = Xyz::<D65, f64>::new(0.0, 0.0, 0.0).chromaticity_uv();
+        assert_eq!(uv, UvChromaticity::new(0.0, 0.0));
+
+        let xyz = Xyz::<D65, f64>::from_uv_luminance(0.2, 0.0, 0.5);
+        assert_eq!(xyz, Xyz::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn mix() {
+        let a = UvChromaticity::new(0.1f64, 0.2);
+        let b = UvChromaticity::new(0.3, 0.6);
+
+        assert_eq!(a.mix(b, 0.5), UvChromaticity::new(0.2, 0.4));
+        assert_eq!(a.mix(b, 2.0), b);
+    }
+}
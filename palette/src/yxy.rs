@@ -1,6 +1,9 @@
 //! Types for the CIE 1931 Yxy (xyY) color space.
 
-use core::marker::PhantomData;
+use core::{
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
 
 use crate::{
     bool_mask::{HasBoolMask, LazySelect},
@@ -9,7 +12,7 @@ use crate::{
     luma::LumaStandard,
     num::{Arithmetics, IsValidDivisor, One, PartialCmp, Real, Zero},
     white_point::{WhitePoint, D65},
-    Alpha, Luma, Xyz,
+    Alpha, Luma, RelativeLuminance, Xyz,
 };
 
 /// CIE 1931 Yxy (xyY) with an alpha component. See the [`Yxya` implementation
@@ -88,6 +91,68 @@ impl<Wp, T> Yxy<Wp, T> {
     pub fn with_white_point<NewWp>(self) -> Yxy<NewWp, T> {
         Yxy::new(self.x, self.y, self.luma)
     }
+
+    /// Get the `(x, y)` chromaticity coordinates.
+    #[inline]
+    pub fn chromaticity(self) -> (T, T) {
+        (self.x, self.y)
+    }
+
+    /// Create a CIE Yxy color from `(x, y)` chromaticity coordinates and a
+    /// luminance value.
+    ///
+    /// Chromaticity coordinates in the CIE 1976 u'v' diagram can be converted
+    /// with [`UvChromaticity::into_xy`](crate::xyz::UvChromaticity::into_xy).
+    #[inline]
+    pub fn from_chromaticity(x: T, y: T, luma: T) -> Self {
+        Self::new(x, y, luma)
+    }
+
+    /// Create a CIE Yxy color from `(x, y)` chromaticity coordinates and an
+    /// absolute luminance, such as in cd/m².
+    ///
+    /// The luminance is divided by `reference_white`, the luminance of the
+    /// reference white, so `luma` is `1.0` when they are equal. This is purely
+    /// arithmetical and doesn't change the meaning of the color type. The
+    /// reference white is often 100 cd/m² for standard dynamic range content
+    /// and 203 cd/m² for the diffuse white in high dynamic range content.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::Yxy;
+    ///
+    /// // A 250 cd/m² highlight is 2.5 times brighter than a 100 cd/m² white.
+    /// let highlight: Yxy = Yxy::from_absolute_luminance(0.3127, 0.329, 250.0, 100.0);
+    /// assert_relative_eq!(highlight.luma, 2.5);
+    /// ```
+    #[inline]
+    pub fn from_absolute_luminance(x: T, y: T, luminance: T, reference_white: T) -> Self
+    where
+        T: Div<Output = T>,
+    {
+        Self::new(x, y, luminance / reference_white)
+    }
+
+    /// Get the absolute luminance, such as in cd/m², given the luminance of
+    /// the reference white.
+    ///
+    /// This is `luma * reference_white` and the inverse of
+    /// [`from_absolute_luminance`](Yxy::from_absolute_luminance).
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::Yxy;
+    ///
+    /// let color: Yxy = Yxy::new(0.3127, 0.329, 0.5);
+    /// assert_relative_eq!(color.absolute_luminance(100.0), 50.0);
+    /// ```
+    #[inline]
+    pub fn absolute_luminance(self, reference_white: T) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.luma * reference_white
+    }
 }
 
 impl<Wp, T> Yxy<Wp, T>
@@ -165,6 +230,18 @@ impl_struct_of_arrays_methods!(Yxy<Wp>, [x, y, luma], white_point);
 
 impl_tuple_conversion!(Yxy<Wp> as (T, T, T));
 
+impl<Wp, T> RelativeLuminance for Yxy<Wp, T>
+where
+    T: Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_relative_luminance(&self) -> T {
+        self.luma.clone()
+    }
+}
+
 impl<Wp, T> FromColorUnclamped<Yxy<Wp, T>> for Yxy<Wp, T> {
     fn from_color_unclamped(color: Yxy<Wp, T>) -> Self {
         color
@@ -218,6 +295,7 @@ impl_is_within_bounds! {
         y => [Self::min_y(), Self::max_y()],
         luma => [Self::min_luma(), Self::max_luma()]
     }
+    new(x: T, y: T, luma: T)
     where T: Zero + One
 }
 impl_clamp! {
@@ -264,12 +342,14 @@ impl_color_add!(Yxy<Wp>, [x, y, luma], white_point);
 impl_color_sub!(Yxy<Wp>, [x, y, luma], white_point);
 impl_color_mul!(Yxy<Wp>, [x, y, luma], white_point);
 impl_color_div!(Yxy<Wp>, [x, y, luma], white_point);
+impl_component_wise!(Yxy<Wp>, [x, y, luma], white_point);
 
 impl_array_casts!(Yxy<Wp, T>, [T; 3]);
 impl_simd_array_conversion!(Yxy<Wp>, [x, y, luma], white_point);
 impl_struct_of_array_traits!(Yxy<Wp>, [x, y, luma], white_point);
 
 impl_eq!(Yxy<Wp>, [x, y, luma]);
+impl_select!(Yxy<Wp>, [x, y, luma], white_point);
 impl_copy_clone!(Yxy<Wp>, [x, y, luma], white_point);
 
 #[allow(deprecated)]
@@ -332,6 +412,37 @@ mod test {
             let b = Yxy::new(0.15, 0.06, 0.072175);
             assert_relative_eq!(a, b, epsilon = 0.000001);
         }
+
+        #[test]
+        fn absolute_luminance() {
+            use crate::Xyz;
+
+            // A 100 cd/m² reference white.
+            let reference = 100.0;
+
+            let yxy = Yxy::<D65, f64>::from_absolute_luminance(0.3127, 0.329, 50.0, reference);
+            assert_relative_eq!(yxy, Yxy::new(0.3127, 0.329, 0.5));
+            assert_relative_eq!(yxy.absolute_luminance(reference), 50.0);
+
+            let xyz = Xyz::<D65, f64>::from_absolute_luminance(47.5, 50.0, 54.4, reference);
+            assert_relative_eq!(xyz, Xyz::new(0.475, 0.5, 0.544));
+            assert_relative_eq!(xyz.absolute_luminance(reference), 50.0);
+            assert_relative_eq!(Yxy::from_color(xyz).absolute_luminance(reference), 50.0);
+
+            let luma = LinLuma::<D65, f64>::from_absolute_luminance(50.0, reference);
+            assert_relative_eq!(luma.luma, 0.5);
+            assert_relative_eq!(luma.absolute_luminance(reference), 50.0);
+            assert_relative_eq!(
+                Yxy::<D65, f64>::from_color(luma).absolute_luminance(reference),
+                50.0,
+                epsilon = 0.000001
+            );
+
+            // HDR highlights are allowed to go above the reference white.
+            let highlight =
+                Yxy::<D65, f64>::from_absolute_luminance(0.3127, 0.329, 400.0, reference);
+            assert_relative_eq!(highlight.luma, 4.0);
+        }
     }
 
     #[test]
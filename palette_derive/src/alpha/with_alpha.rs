@@ -20,10 +20,17 @@ pub fn derive(item: TokenStream) -> ::std::result::Result<TokenStream, Vec<::syn
         attrs,
         ..
     } = syn::parse(item).map_err(|error| vec![error])?;
-    let generics = original_generics;
+    let mut generics = original_generics;
 
     let (item_meta, item_errors) = parse_namespaced_attributes::<TypeItemAttributes>(attrs);
 
+    if !item_meta.where_predicates.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(item_meta.where_predicates.iter().cloned());
+    }
+
     let (fields_meta, field_errors) = if let syn::Data::Struct(struct_data) = data {
         parse_field_attributes::<FieldAttributes>(struct_data.fields)
     } else {
@@ -33,8 +40,15 @@ pub fn derive(item: TokenStream) -> ::std::result::Result<TokenStream, Vec<::syn
         )]);
     };
 
-    let implementation = if let Some((alpha_property, alpha_type)) = fields_meta.alpha_property {
-        implement_for_internal_alpha(&ident, &generics, &alpha_property, &alpha_type, &item_meta)
+    let implementation = if let Some((alpha_property, field_type)) = fields_meta.alpha_property {
+        implement_for_internal_alpha(
+            &ident,
+            &generics,
+            &alpha_property,
+            &field_type,
+            fields_meta.alpha_type.as_ref(),
+            &item_meta,
+        )
     } else {
         implement_for_external_alpha(&ident, &generics, &item_meta)
     };
@@ -59,11 +73,25 @@ fn implement_for_internal_alpha(
     ident: &Ident,
     generics: &Generics,
     alpha_property: &IdentOrIndex,
-    alpha_type: &Type,
+    field_type: &Type,
+    alpha_type: Option<&Type>,
     item_meta: &TypeItemAttributes,
 ) -> TokenStream2 {
     let with_alpha_trait_path = util::path(["WithAlpha"], item_meta.internal);
     let stimulus_trait_path = util::path(["stimulus", "Stimulus"], item_meta.internal);
+    let into_stimulus_trait_path = util::path(["stimulus", "IntoStimulus"], item_meta.internal);
+
+    // The alpha value is converted to and from the field type when a
+    // different alpha type is specified.
+    let (alpha_type, into_field, from_field) = if let Some(alpha_type) = alpha_type {
+        (
+            alpha_type,
+            quote!(#into_stimulus_trait_path::<#field_type>::into_stimulus(alpha)),
+            quote!(#into_stimulus_trait_path::<#alpha_type>::into_stimulus(alpha)),
+        )
+    } else {
+        (field_type, quote!(alpha), quote!(alpha))
+    };
 
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
@@ -74,7 +102,7 @@ fn implement_for_internal_alpha(
             type WithAlpha = Self;
 
             fn with_alpha(mut self, alpha: #alpha_type) -> Self::WithAlpha {
-                self.#alpha_property = alpha;
+                self.#alpha_property = #into_field;
                 self
             }
 
@@ -86,7 +114,7 @@ fn implement_for_internal_alpha(
             fn split(mut self) -> (Self::Color, #alpha_type) {
                 let opaque_alpha = #stimulus_trait_path::max_intensity();
                 let alpha = core::mem::replace(&mut self.#alpha_property, opaque_alpha);
-                (self, alpha)
+                (self, #from_field)
             }
         }
     }
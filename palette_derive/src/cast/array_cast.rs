@@ -104,10 +104,17 @@ pub fn derive(tokens: TokenStream) -> std::result::Result<TokenStream, Vec<syn::
     let mut implementation = if let Some(field_type) = field_type {
         let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
+        // A single array field, such as `[T; N]`, is used as the array type
+        // directly. This allows the length to be a const generic parameter.
+        let array_type = match field_type {
+            Type::Array(array_type) if number_of_channels == 1 => quote!(#array_type),
+            field_type => quote!([#field_type; #number_of_channels]),
+        };
+
         quote! {
             #[automatically_derived]
             unsafe impl #impl_generics #array_cast_trait_path for #ident #type_generics #where_clause {
-                type Array = [#field_type; #number_of_channels];
+                type Array = #array_type;
             }
         }
     } else {
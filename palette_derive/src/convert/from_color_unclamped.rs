@@ -18,7 +18,7 @@ use super::util::{component_type, find_nearest_color, get_convert_color_type, wh
 pub fn derive(item: TokenStream) -> ::std::result::Result<TokenStream, Vec<::syn::parse::Error>> {
     let DeriveInput {
         ident,
-        generics,
+        mut generics,
         data,
         attrs,
         ..
@@ -26,6 +26,13 @@ pub fn derive(item: TokenStream) -> ::std::result::Result<TokenStream, Vec<::syn
 
     let (mut item_meta, item_errors) = parse_namespaced_attributes::<TypeItemAttributes>(attrs);
 
+    if !item_meta.where_predicates.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(item_meta.where_predicates.iter().cloned());
+    }
+
     let (fields_meta, field_errors) = if let syn::Data::Struct(struct_data) = data {
         parse_field_attributes::<FieldAttributes>(struct_data.fields)
     } else {
@@ -62,13 +69,14 @@ pub fn derive(item: TokenStream) -> ::std::result::Result<TokenStream, Vec<::syn
     let mut implementations =
         generate_from_implementations(&ident, &generics, &item_meta, &all_from_impl_params);
 
-    if let Some((alpha_property, alpha_type)) = alpha_field {
+    if let Some((alpha_property, field_type)) = alpha_field {
         implementations.push(generate_from_alpha_implementation_with_internal(
             &ident,
             &generics,
             &item_meta,
             &alpha_property,
-            &alpha_type,
+            &field_type,
+            fields_meta.alpha_type.as_ref(),
         ));
     } else {
         implementations.push(generate_from_alpha_implementation(
@@ -327,12 +335,23 @@ fn generate_from_alpha_implementation_with_internal(
     generics: &Generics,
     meta: &TypeItemAttributes,
     alpha_property: &IdentOrIndex,
-    alpha_type: &Type,
+    field_type: &Type,
+    alpha_type: Option<&Type>,
 ) -> TokenStream2 {
     let from_trait_path = util::path(["convert", "FromColorUnclamped"], meta.internal);
     let into_trait_path = util::path(["convert", "IntoColorUnclamped"], meta.internal);
+    let into_stimulus_trait_path = util::path(["stimulus", "IntoStimulus"], meta.internal);
     let alpha_path = util::path(["Alpha"], meta.internal);
 
+    let (alpha_type, into_field) = if let Some(alpha_type) = alpha_type {
+        (
+            alpha_type,
+            quote!(#into_stimulus_trait_path::<#field_type>::into_stimulus(alpha)),
+        )
+    } else {
+        (field_type, quote!(alpha))
+    };
+
     let (_, type_generics, _) = generics.split_for_impl();
     let mut impl_generics = generics.clone();
     impl_generics.params.push(parse_quote!(_C));
@@ -354,7 +373,7 @@ fn generate_from_alpha_implementation_with_internal(
                 let #alpha_path { color, alpha } = color;
 
                 let mut result: Self = color.into_color_unclamped();
-                result.#alpha_property = alpha;
+                result.#alpha_property = #into_field;
 
                 result
             }
@@ -36,6 +36,7 @@ mod cast;
 mod color_types;
 mod convert;
 mod meta;
+mod properties;
 mod util;
 
 #[proc_macro_derive(WithAlpha, attributes(palette))]
@@ -52,3 +53,18 @@ pub fn derive_from_color_unclamped(tokens: TokenStream) -> TokenStream {
 pub fn derive_array_cast(tokens: TokenStream) -> TokenStream {
     syn_try!(cast::derive_array_cast(tokens))
 }
+
+#[proc_macro_derive(Mix, attributes(palette))]
+pub fn derive_mix(tokens: TokenStream) -> TokenStream {
+    syn_try!(properties::derive_mix(tokens))
+}
+
+#[proc_macro_derive(Lighten, attributes(palette))]
+pub fn derive_lighten(tokens: TokenStream) -> TokenStream {
+    syn_try!(properties::derive_lighten(tokens))
+}
+
+#[proc_macro_derive(Saturate, attributes(palette))]
+pub fn derive_saturate(tokens: TokenStream) -> TokenStream {
+    syn_try!(properties::derive_saturate(tokens))
+}
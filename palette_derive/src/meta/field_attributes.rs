@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
-use syn::{spanned::Spanned, Expr, ExprLit};
+use proc_macro2::TokenStream;
+use syn::{punctuated::Punctuated, spanned::Spanned, token::Comma, Expr, ExprLit};
 use syn::{Lit, Meta, MetaNameValue, Type};
 
 use super::{assert_path_meta, FieldAttributeArgumentParser, IdentOrIndex};
@@ -8,8 +9,18 @@ use super::{assert_path_meta, FieldAttributeArgumentParser, IdentOrIndex};
 #[derive(Default)]
 pub struct FieldAttributes {
     pub alpha_property: Option<(IdentOrIndex, Type)>,
+    pub alpha_type: Option<Type>,
     pub zero_size_fields: HashSet<IdentOrIndex>,
     pub type_substitutes: HashMap<IdentOrIndex, Type>,
+    pub lightness_fields: Vec<(IdentOrIndex, BoundedField)>,
+    pub saturation_fields: Vec<(IdentOrIndex, BoundedField)>,
+}
+
+/// A field that is increased or decreased within a `[min, max]` range.
+pub struct BoundedField {
+    pub min: TokenStream,
+    pub max: TokenStream,
+    pub inverted: bool,
 }
 
 impl FieldAttributeArgumentParser for FieldAttributes {
@@ -23,7 +34,20 @@ impl FieldAttributeArgumentParser for FieldAttributes {
 
         match argument_name.as_deref() {
             Some("alpha") => {
-                assert_path_meta(&argument).map_err(|error| vec![error])?;
+                if let Meta::NameValue(MetaNameValue {
+                    value:
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(string),
+                            ..
+                        }),
+                    ..
+                }) = argument
+                {
+                    self.alpha_type = Some(string.parse().map_err(|error| vec![error])?);
+                } else {
+                    assert_path_meta(&argument).map_err(|error| vec![error])?;
+                }
+
                 self.alpha_property = Some((field_name.clone(), ty.clone()));
             }
             Some("unsafe_same_layout_as") => {
@@ -46,6 +70,14 @@ impl FieldAttributeArgumentParser for FieldAttributes {
 
                 self.type_substitutes.insert(field_name.clone(), substitute);
             }
+            Some("lightness") => {
+                let field = parse_bounded_field(argument)?;
+                self.lightness_fields.push((field_name.clone(), field));
+            }
+            Some("saturation") => {
+                let field = parse_bounded_field(argument)?;
+                self.saturation_fields.push((field_name.clone(), field));
+            }
             Some("unsafe_zero_sized") => {
                 assert_path_meta(&argument).map_err(|error| vec![error])?;
                 self.zero_size_fields.insert(field_name.clone());
@@ -61,3 +93,66 @@ impl FieldAttributeArgumentParser for FieldAttributes {
         Ok(())
     }
 }
+
+fn parse_bounded_field(argument: Meta) -> Result<BoundedField, Vec<syn::Error>> {
+    let name = argument.path().get_ident().unwrap().to_string();
+    let expected_format = || {
+        vec![::syn::parse::Error::new(
+            argument.span(),
+            format!("expected `{name}(min = \"...\", max = \"...\")`, optionally with `inverted`"),
+        )]
+    };
+
+    let list = if let Meta::List(list) = &argument {
+        list
+    } else {
+        return Err(expected_format());
+    };
+
+    let arguments = list
+        .parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+        .map_err(|error| vec![error])?;
+
+    let mut min = None;
+    let mut max = None;
+    let mut inverted = false;
+    let mut errors = Vec::new();
+
+    for argument in arguments {
+        let argument_name = argument.path().get_ident().map(ToString::to_string);
+
+        match (argument_name.as_deref(), argument) {
+            (
+                Some(name @ ("min" | "max")),
+                Meta::NameValue(MetaNameValue {
+                    value:
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(string),
+                            ..
+                        }),
+                    ..
+                }),
+            ) => {
+                let target = if name == "min" { &mut min } else { &mut max };
+                match string.parse::<TokenStream>() {
+                    Ok(expression) => *target = Some(expression),
+                    Err(error) => errors.push(error),
+                }
+            }
+            (Some("inverted"), Meta::Path(_)) => inverted = true,
+            (_, argument) => errors.push(::syn::parse::Error::new(
+                argument.span(),
+                "expected `min = \"...\"`, `max = \"...\"` or `inverted`",
+            )),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    match (min, max) {
+        (Some(min), Some(max)) => Ok(BoundedField { min, max, inverted }),
+        _ => Err(expected_format()),
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use by_address::ByAddress;
 use quote::quote;
 use syn::{punctuated::Punctuated, spanned::Spanned, token::Comma, Expr, ExprLit};
-use syn::{Ident, Lit, Meta, MetaNameValue, Type};
+use syn::{Ident, Lit, Meta, MetaNameValue, Type, WherePredicate};
 
 use crate::color_types::{ColorGroup, COLOR_GROUPS};
 
@@ -18,6 +18,7 @@ pub struct TypeItemAttributes {
     pub white_point: Option<Type>,
     pub rgb_standard: Option<Type>,
     pub luma_standard: Option<Type>,
+    pub where_predicates: Vec<WherePredicate>,
     pub(crate) color_groups: HashSet<ByAddress<&'static ColorGroup>>,
 }
 
@@ -82,6 +83,27 @@ impl AttributeArgumentParser for TypeItemAttributes {
             Some("luma_standard") => {
                 get_meta_type_argument(argument, &mut self.luma_standard)?;
             }
+            Some("bound") => {
+                if let Meta::NameValue(MetaNameValue {
+                    value:
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(predicates),
+                            ..
+                        }),
+                    ..
+                }) = argument
+                {
+                    let predicates = predicates
+                        .parse_with(Punctuated::<WherePredicate, Comma>::parse_terminated)
+                        .map_err(|error| vec![error])?;
+                    self.where_predicates.extend(predicates);
+                } else {
+                    return Err(vec![syn::Error::new(
+                        argument.span(),
+                        "expected `bound` to have a list of bounds in a string, like `bound = \"Wp: WhitePoint<T>\"`",
+                    )]);
+                }
+            }
             Some("palette_internal") => {
                 if let Meta::Path(_) = argument {
                     self.internal = true;
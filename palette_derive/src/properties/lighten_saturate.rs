@@ -0,0 +1,330 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Generics, Ident, Type};
+
+use crate::{
+    convert::util::component_type,
+    meta::{
+        parse_field_attributes, parse_namespaced_attributes, BoundedField, FieldAttributes,
+        IdentOrIndex, TypeItemAttributes,
+    },
+    util,
+};
+
+pub fn derive_lighten(
+    item: TokenStream,
+) -> ::std::result::Result<TokenStream, Vec<::syn::parse::Error>> {
+    derive(
+        item,
+        IncreaseTrait {
+            trait_name: "Lighten",
+            assign_trait_name: "LightenAssign",
+            attribute_name: "lightness",
+            method: "lighten",
+            method_fixed: "lighten_fixed",
+            assign_method: "lighten_assign",
+            assign_method_fixed: "lighten_fixed_assign",
+        },
+        |fields_meta| fields_meta.lightness_fields,
+    )
+}
+
+pub fn derive_saturate(
+    item: TokenStream,
+) -> ::std::result::Result<TokenStream, Vec<::syn::parse::Error>> {
+    derive(
+        item,
+        IncreaseTrait {
+            trait_name: "Saturate",
+            assign_trait_name: "SaturateAssign",
+            attribute_name: "saturation",
+            method: "saturate",
+            method_fixed: "saturate_fixed",
+            assign_method: "saturate_assign",
+            assign_method_fixed: "saturate_fixed_assign",
+        },
+        |fields_meta| fields_meta.saturation_fields,
+    )
+}
+
+/// The names that differ between `Lighten` and `Saturate`.
+struct IncreaseTrait {
+    trait_name: &'static str,
+    assign_trait_name: &'static str,
+    attribute_name: &'static str,
+    method: &'static str,
+    method_fixed: &'static str,
+    assign_method: &'static str,
+    assign_method_fixed: &'static str,
+}
+
+fn derive(
+    item: TokenStream,
+    increase_trait: IncreaseTrait,
+    select_fields: fn(FieldAttributes) -> Vec<(IdentOrIndex, BoundedField)>,
+) -> ::std::result::Result<TokenStream, Vec<::syn::parse::Error>> {
+    let DeriveInput {
+        ident,
+        mut generics,
+        data,
+        attrs,
+        ..
+    } = syn::parse(item).map_err(|error| vec![error])?;
+
+    let (item_meta, item_errors) = parse_namespaced_attributes::<TypeItemAttributes>(attrs);
+
+    let (fields_meta, field_errors) = if let syn::Data::Struct(struct_data) = data {
+        parse_field_attributes::<FieldAttributes>(struct_data.fields)
+    } else {
+        return Err(vec![syn::Error::new(
+            Span::call_site(),
+            "only structs are supported",
+        )]);
+    };
+
+    let fields = select_fields(fields_meta);
+
+    if fields.is_empty() {
+        return Err(vec![syn::Error::new(
+            Span::call_site(),
+            format!(
+                "`{}` requires at least one field with a `#[palette({}(min = \"...\", max = \"...\"))]` attribute",
+                increase_trait.trait_name, increase_trait.attribute_name
+            ),
+        )]);
+    }
+
+    if !item_meta.where_predicates.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(item_meta.where_predicates.iter().cloned());
+    }
+
+    let component = component_type(item_meta.component.clone());
+
+    let implementation = implement_increase(
+        &ident,
+        &generics,
+        &component,
+        &fields,
+        &increase_trait,
+        &item_meta,
+    );
+    let assign_implementation = implement_increase_assign(
+        &ident,
+        &generics,
+        &component,
+        &fields,
+        &increase_trait,
+        &item_meta,
+    );
+
+    let item_errors = item_errors
+        .into_iter()
+        .map(|error| error.into_compile_error());
+    let field_errors = field_errors
+        .into_iter()
+        .map(|error| error.into_compile_error());
+
+    Ok(quote! {
+        #(#item_errors)*
+        #(#field_errors)*
+
+        #implementation
+        #assign_implementation
+    }
+    .into())
+}
+
+fn implement_increase(
+    ident: &Ident,
+    generics: &Generics,
+    component: &Type,
+    fields: &[(IdentOrIndex, BoundedField)],
+    increase_trait: &IncreaseTrait,
+    item_meta: &TypeItemAttributes,
+) -> TokenStream2 {
+    let trait_path = util::path([increase_trait.trait_name], item_meta.internal);
+    let num_path = util::path(["num"], item_meta.internal);
+    let lazy_select_path = util::path(["bool_mask", "LazySelect"], item_meta.internal);
+    let bool_mask_path = util::path(["bool_mask", "HasBoolMask"], item_meta.internal);
+    let method = Ident::new(increase_trait.method, Span::call_site());
+    let method_fixed = Ident::new(increase_trait.method_fixed, Span::call_site());
+
+    let (_, type_generics, _) = generics.split_for_impl();
+    let mut generics = generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        where_clause.predicates.push(parse_quote!(
+            #component: #num_path::Real
+                + #num_path::Zero
+                + #num_path::MinMax
+                + #num_path::Clamp
+                + #num_path::Arithmetics
+                + #num_path::PartialCmp
+                + Clone
+        ));
+        where_clause.predicates.push(
+            parse_quote!(<#component as #bool_mask_path>::Mask: #lazy_select_path<#component>),
+        );
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let relative = fields.iter().map(|(name, field)| {
+        let BoundedField { min, max, inverted } = field;
+
+        if *inverted {
+            quote! {
+                let difference = #lazy_select_path::lazy_select(
+                    #num_path::PartialCmp::gt_eq(&factor, &#num_path::Zero::zero()),
+                    || self.#name.clone(),
+                    || (#max) - &self.#name,
+                );
+                let delta = #num_path::MinMax::max(difference, #num_path::Zero::zero()) * &factor;
+                self.#name = #num_path::Clamp::clamp(self.#name - delta, #min, #max);
+            }
+        } else {
+            quote! {
+                let difference = #lazy_select_path::lazy_select(
+                    #num_path::PartialCmp::gt_eq(&factor, &#num_path::Zero::zero()),
+                    || (#max) - &self.#name,
+                    || self.#name.clone(),
+                );
+                let delta = #num_path::MinMax::max(difference, #num_path::Zero::zero()) * &factor;
+                self.#name = #num_path::Clamp::clamp(self.#name + delta, #min, #max);
+            }
+        }
+    });
+
+    let fixed = fields.iter().map(|(name, field)| {
+        let BoundedField { min, max, inverted } = field;
+
+        if *inverted {
+            quote! {
+                self.#name = #num_path::Clamp::clamp(self.#name - (#max) * &amount, #min, #max);
+            }
+        } else {
+            quote! {
+                self.#name = #num_path::Clamp::clamp(self.#name + (#max) * &amount, #min, #max);
+            }
+        }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #ident #type_generics #where_clause {
+            type Scalar = #component;
+
+            #[inline]
+            fn #method(mut self, factor: #component) -> Self {
+                #({#relative})*
+                self
+            }
+
+            #[inline]
+            fn #method_fixed(mut self, amount: #component) -> Self {
+                #(#fixed)*
+                self
+            }
+        }
+    }
+}
+
+fn implement_increase_assign(
+    ident: &Ident,
+    generics: &Generics,
+    component: &Type,
+    fields: &[(IdentOrIndex, BoundedField)],
+    increase_trait: &IncreaseTrait,
+    item_meta: &TypeItemAttributes,
+) -> TokenStream2 {
+    let trait_path = util::path([increase_trait.assign_trait_name], item_meta.internal);
+    let num_path = util::path(["num"], item_meta.internal);
+    let lazy_select_path = util::path(["bool_mask", "LazySelect"], item_meta.internal);
+    let bool_mask_path = util::path(["bool_mask", "HasBoolMask"], item_meta.internal);
+    let method = Ident::new(increase_trait.assign_method, Span::call_site());
+    let method_fixed = Ident::new(increase_trait.assign_method_fixed, Span::call_site());
+
+    let (_, type_generics, _) = generics.split_for_impl();
+    let mut generics = generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        where_clause.predicates.push(parse_quote!(
+            #component: #num_path::Real
+                + #num_path::Zero
+                + #num_path::MinMax
+                + #num_path::ClampAssign
+                + core::ops::AddAssign
+                + core::ops::SubAssign
+                + #num_path::Arithmetics
+                + #num_path::PartialCmp
+                + Clone
+        ));
+        where_clause.predicates.push(
+            parse_quote!(<#component as #bool_mask_path>::Mask: #lazy_select_path<#component>),
+        );
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let relative = fields.iter().map(|(name, field)| {
+        let BoundedField { min, max, inverted } = field;
+
+        if *inverted {
+            quote! {
+                let difference = #lazy_select_path::lazy_select(
+                    #num_path::PartialCmp::gt_eq(&factor, &#num_path::Zero::zero()),
+                    || self.#name.clone(),
+                    || (#max) - &self.#name,
+                );
+                self.#name -= #num_path::MinMax::max(difference, #num_path::Zero::zero()) * &factor;
+                #num_path::ClampAssign::clamp_assign(&mut self.#name, #min, #max);
+            }
+        } else {
+            quote! {
+                let difference = #lazy_select_path::lazy_select(
+                    #num_path::PartialCmp::gt_eq(&factor, &#num_path::Zero::zero()),
+                    || (#max) - &self.#name,
+                    || self.#name.clone(),
+                );
+                self.#name += #num_path::MinMax::max(difference, #num_path::Zero::zero()) * &factor;
+                #num_path::ClampAssign::clamp_assign(&mut self.#name, #min, #max);
+            }
+        }
+    });
+
+    let fixed = fields.iter().map(|(name, field)| {
+        let BoundedField { min, max, inverted } = field;
+
+        if *inverted {
+            quote! {
+                self.#name -= (#max) * &amount;
+                #num_path::ClampAssign::clamp_assign(&mut self.#name, #min, #max);
+            }
+        } else {
+            quote! {
+                self.#name += (#max) * &amount;
+                #num_path::ClampAssign::clamp_assign(&mut self.#name, #min, #max);
+            }
+        }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #ident #type_generics #where_clause {
+            type Scalar = #component;
+
+            #[inline]
+            fn #method(&mut self, factor: #component) {
+                #({#relative})*
+            }
+
+            #[inline]
+            fn #method_fixed(&mut self, amount: #component) {
+                #(#fixed)*
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Generics, Ident, Type};
+
+use crate::{
+    convert::util::component_type,
+    meta::{parse_namespaced_attributes, IdentOrIndex, TypeItemAttributes},
+    util,
+};
+
+use super::{component_fields, ComponentField};
+
+pub fn derive(item: TokenStream) -> ::std::result::Result<TokenStream, Vec<::syn::parse::Error>> {
+    let DeriveInput {
+        ident,
+        mut generics,
+        data,
+        attrs,
+        ..
+    } = syn::parse(item).map_err(|error| vec![error])?;
+
+    let (item_meta, item_errors) = parse_namespaced_attributes::<TypeItemAttributes>(attrs);
+
+    let fields = if let syn::Data::Struct(struct_data) = data {
+        component_fields(struct_data.fields)
+    } else {
+        return Err(vec![syn::Error::new(
+            Span::call_site(),
+            "only structs are supported",
+        )]);
+    };
+
+    if !item_meta.where_predicates.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(item_meta.where_predicates.iter().cloned());
+    }
+
+    let component = component_type(item_meta.component.clone());
+
+    let mix = implement_mix(&ident, &generics, &component, &fields, &item_meta);
+    let mix_assign = implement_mix_assign(&ident, &generics, &component, &fields, &item_meta);
+
+    let item_errors = item_errors
+        .into_iter()
+        .map(|error| error.into_compile_error());
+
+    Ok(quote! {
+        #(#item_errors)*
+
+        #mix
+        #mix_assign
+    }
+    .into())
+}
+
+fn implement_mix(
+    ident: &Ident,
+    generics: &Generics,
+    component: &Type,
+    fields: &[ComponentField],
+    item_meta: &TypeItemAttributes,
+) -> TokenStream2 {
+    let mix_trait_path = util::path(["Mix"], item_meta.internal);
+    let num_path = util::path(["num"], item_meta.internal);
+
+    let (_, type_generics, _) = generics.split_for_impl();
+    let mut generics = generics.clone();
+    generics.make_where_clause().predicates.push(parse_quote!(
+        #component: #num_path::Real
+            + #num_path::Zero
+            + #num_path::One
+            + #num_path::Arithmetics
+            + #num_path::Clamp
+            + Clone
+    ));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let field_values = fields.iter().map(|field| {
+        let name = &field.name;
+
+        if field.is_phantom {
+            quote!(#name: self.#name)
+        } else {
+            quote!(#name: self.#name.clone() + (other.#name - self.#name) * factor.clone())
+        }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #mix_trait_path for #ident #type_generics #where_clause {
+            type Scalar = #component;
+
+            #[inline]
+            fn mix(self, other: Self, factor: #component) -> Self {
+                let factor = #num_path::Clamp::clamp(
+                    factor,
+                    #num_path::Zero::zero(),
+                    #num_path::One::one(),
+                );
+
+                #ident {
+                    #(#field_values,)*
+                }
+            }
+        }
+    }
+}
+
+fn implement_mix_assign(
+    ident: &Ident,
+    generics: &Generics,
+    component: &Type,
+    fields: &[ComponentField],
+    item_meta: &TypeItemAttributes,
+) -> TokenStream2 {
+    let mix_assign_trait_path = util::path(["MixAssign"], item_meta.internal);
+    let num_path = util::path(["num"], item_meta.internal);
+
+    let (_, type_generics, _) = generics.split_for_impl();
+    let mut generics = generics.clone();
+    generics.make_where_clause().predicates.push(parse_quote!(
+        #component: #num_path::Real
+            + #num_path::Zero
+            + #num_path::One
+            + core::ops::AddAssign
+            + #num_path::Arithmetics
+            + #num_path::Clamp
+            + Clone
+    ));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let field_names: Vec<&IdentOrIndex> = fields
+        .iter()
+        .filter(|field| !field.is_phantom)
+        .map(|field| &field.name)
+        .collect();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #mix_assign_trait_path for #ident #type_generics #where_clause {
+            type Scalar = #component;
+
+            #[inline]
+            fn mix_assign(&mut self, other: Self, factor: #component) {
+                let factor = #num_path::Clamp::clamp(
+                    factor,
+                    #num_path::Zero::zero(),
+                    #num_path::One::one(),
+                );
+
+                #(
+                    self.#field_names += (other.#field_names - self.#field_names.clone()) * factor.clone();
+                )*
+            }
+        }
+    }
+}
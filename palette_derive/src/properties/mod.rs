@@ -0,0 +1,41 @@
+use syn::{Fields, Type};
+
+use crate::meta::IdentOrIndex;
+
+pub use self::lighten_saturate::{derive_lighten, derive_saturate};
+pub use self::mix::derive as derive_mix;
+
+mod lighten_saturate;
+mod mix;
+
+pub(crate) struct ComponentField {
+    pub name: IdentOrIndex,
+    pub is_phantom: bool,
+}
+
+/// Lists the fields of a struct and marks any `PhantomData` fields, since
+/// they have no value to modify.
+pub(crate) fn component_fields(fields: Fields) -> Vec<ComponentField> {
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(index, field)| ComponentField {
+            name: field
+                .ident
+                .map(IdentOrIndex::Ident)
+                .unwrap_or_else(|| IdentOrIndex::Index(index.into())),
+            is_phantom: is_phantom_data(&field.ty),
+        })
+        .collect()
+}
+
+fn is_phantom_data(ty: &Type) -> bool {
+    if let Type::Path(path) = ty {
+        path.path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "PhantomData")
+    } else {
+        false
+    }
+}